@@ -1,16 +1,19 @@
 use crate::audio_feedback::{SoundType, play_feedback_sound};
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::history::HistoryManager;
+use crate::managers::meeting::{default_meeting_name, MeetingManager, MeetingStatus};
 use crate::managers::transcription::TranscriptionManager;
 use crate::overlay::{show_recording_overlay, show_transcribing_overlay};
 use crate::settings::get_settings;
+use crate::storage::db::MeetingDb;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils;
+use crate::events::{self, AppEvent};
 use log::{debug, error};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 use tauri::Manager;
 
@@ -18,6 +21,10 @@ use tauri::Manager;
 pub trait ShortcutAction: Send + Sync {
     fn start(&self, app: &AppHandle, binding_id: &str, shortcut_str: &str);
     fn stop(&self, app: &AppHandle, binding_id: &str, shortcut_str: &str);
+    /// Instant actions run `start` on every key press, ignoring push-to-talk and toggle state
+    fn is_instant(&self) -> bool {
+        false
+    }
 }
 
 // Transcribe Action
@@ -172,6 +179,163 @@ impl ShortcutAction for TranscribeAction {
     }
 }
 
+// Meeting Actions
+
+fn emit_meeting_state(app: &AppHandle, meeting_id: &str, status: &str) {
+    events::emit(app, AppEvent::meeting_state(meeting_id, status, "shortcut"));
+}
+
+/// Starts a meeting, or ends the one in progress
+struct ToggleMeetingAction;
+
+impl ShortcutAction for ToggleMeetingAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        let mm = Arc::clone(&app.state::<Arc<MeetingManager>>());
+        let ah = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let result = match mm.current_meeting().await {
+                Some(meeting) => mm
+                    .end_meeting(&meeting.id)
+                    .await
+                    .map(|_| (meeting.id, "completed")),
+                None => mm
+                    .start_meeting(default_meeting_name())
+                    .await
+                    .map(|id| (id, "recording")),
+            };
+            match result {
+                Ok((meeting_id, status)) => {
+                    play_feedback_sound(
+                        &ah,
+                        if status == "recording" {
+                            SoundType::Start
+                        } else {
+                            SoundType::Stop
+                        },
+                    );
+                    emit_meeting_state(&ah, &meeting_id, status);
+                }
+                Err(e) => error!("Toggle meeting shortcut failed: {}", e),
+            }
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {}
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+/// Pauses the meeting in progress, or resumes it
+struct PauseMeetingAction;
+
+impl ShortcutAction for PauseMeetingAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        let mm = Arc::clone(&app.state::<Arc<MeetingManager>>());
+        let ah = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let Some(meeting) = mm.current_meeting().await else {
+                debug!("Pause meeting shortcut: no meeting in progress");
+                return;
+            };
+            let result = if meeting.status == MeetingStatus::Paused {
+                mm.resume_meeting(&meeting.id).await.map(|_| "recording")
+            } else {
+                mm.pause_meeting(&meeting.id).await.map(|_| "paused")
+            };
+            match result {
+                Ok(status) => emit_meeting_state(&ah, &meeting.id, status),
+                Err(e) => error!("Pause meeting shortcut failed: {}", e),
+            }
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {}
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+/// Repeated bookmark presses within this window relabel the marker just placed
+const BOOKMARK_CYCLE_WINDOW: Duration = Duration::from_secs(2);
+
+/// Marker placed by the last bookmark press, and which preset label it carries
+struct LastBookmark {
+    meeting_id: String,
+    marker_id: i64,
+    preset: Option<usize>,
+    at: Instant,
+}
+
+static LAST_BOOKMARK: Lazy<Mutex<Option<LastBookmark>>> = Lazy::new(|| Mutex::new(None));
+
+/// Bookmarks the current moment of the meeting in progress. Pressing again shortly
+/// after cycles the new marker's label through the configured presets.
+struct BookmarkMeetingAction;
+
+impl ShortcutAction for BookmarkMeetingAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        let mm = Arc::clone(&app.state::<Arc<MeetingManager>>());
+        let ah = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let Some(meeting) = mm.current_meeting().await else {
+                debug!("Bookmark shortcut: no meeting in progress");
+                return;
+            };
+            let presets = get_settings(&ah).marker_label_presets;
+            let previous = LAST_BOOKMARK.lock().unwrap().take().filter(|last| {
+                last.meeting_id == meeting.id
+                    && last.at.elapsed() < BOOKMARK_CYCLE_WINDOW
+                    && !presets.is_empty()
+            });
+
+            let result = match previous {
+                Some(last) => {
+                    let preset = last.preset.map(|i| (i + 1) % presets.len()).unwrap_or(0);
+                    let label = &presets[preset];
+                    ah.state::<Arc<MeetingDb>>()
+                        .set_marker_label(last.marker_id, label)
+                        .map(|_| {
+                            events::emit(
+                                &ah,
+                                AppEvent::MeetingMarkerUpdated {
+                                    id: last.marker_id,
+                                    meeting_id: meeting.id.clone(),
+                                    label: label.clone(),
+                                },
+                            );
+                            (last.marker_id, Some(preset))
+                        })
+                }
+                None => mm.add_marker(&meeting.id, "").await.map(|marker| {
+                    events::emit(&ah, AppEvent::MeetingMarkerAdded(marker.clone()));
+                    (marker.id, None)
+                }),
+            };
+            match result {
+                Ok((marker_id, preset)) => {
+                    *LAST_BOOKMARK.lock().unwrap() = Some(LastBookmark {
+                        meeting_id: meeting.id,
+                        marker_id,
+                        preset,
+                        at: Instant::now(),
+                    });
+                    play_feedback_sound(&ah, SoundType::Start);
+                }
+                Err(e) => error!("Bookmark shortcut failed: {}", e),
+            }
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {}
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
 // Test Action
 struct TestAction;
 
@@ -202,6 +366,18 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "transcribe".to_string(),
         Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "meeting_toggle".to_string(),
+        Arc::new(ToggleMeetingAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "meeting_pause".to_string(),
+        Arc::new(PauseMeetingAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "meeting_bookmark".to_string(),
+        Arc::new(BookmarkMeetingAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "test".to_string(),
         Arc::new(TestAction) as Arc<dyn ShortcutAction>,
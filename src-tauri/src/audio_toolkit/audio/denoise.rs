@@ -0,0 +1,202 @@
+//! DTLN noise suppression (dual-signal transformation LSTM network) for a 16 kHz mono
+//! stream. Two small ONNX models run on 32 ms blocks every 8 ms: the first masks the
+//! magnitude spectrum, the second refines the result in the time domain, so keyboard
+//! clatter and fan hum are attenuated before VAD and transcription.
+
+use anyhow::{anyhow, Result};
+use ort::session::Session;
+use ort::value::Tensor;
+use rustfft::{num_complex::Complex32, Fft, FftPlanner};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+
+const BLOCK_LEN: usize = 512;
+const BLOCK_SHIFT: usize = 128;
+const BINS: usize = BLOCK_LEN / 2 + 1;
+/// LSTM state each stage carries from one block to the next
+const STATE_SHAPE: [usize; 4] = [1, 2, 128, 2];
+const STATE_LEN: usize = 2 * 128 * 2;
+
+/// Overlap-add over 512-sample blocks every 128 samples. Output trails the input by one
+/// block shift on top of the overlap, so every call returns as many samples as it got.
+struct Framer {
+    pending: Vec<f32>,
+    input: Vec<f32>,
+    output: Vec<f32>,
+    ready: VecDeque<f32>,
+}
+
+impl Framer {
+    fn new() -> Self {
+        Self {
+            pending: Vec::with_capacity(BLOCK_SHIFT),
+            input: vec![0.0; BLOCK_LEN],
+            output: vec![0.0; BLOCK_LEN],
+            ready: VecDeque::from(vec![0.0; BLOCK_SHIFT]),
+        }
+    }
+
+    fn process(
+        &mut self,
+        mut samples: &[f32],
+        mut enhance: impl FnMut(&[f32]) -> Vec<f32>,
+    ) -> Vec<f32> {
+        let wanted = samples.len();
+        while !samples.is_empty() {
+            let take = (BLOCK_SHIFT - self.pending.len()).min(samples.len());
+            self.pending.extend_from_slice(&samples[..take]);
+            samples = &samples[take..];
+            if self.pending.len() < BLOCK_SHIFT {
+                continue;
+            }
+            self.input.copy_within(BLOCK_SHIFT.., 0);
+            self.input[BLOCK_LEN - BLOCK_SHIFT..].copy_from_slice(&self.pending);
+            self.pending.clear();
+
+            let block = enhance(&self.input);
+            self.output.copy_within(BLOCK_SHIFT.., 0);
+            self.output[BLOCK_LEN - BLOCK_SHIFT..].fill(0.0);
+            for (out, s) in self.output.iter_mut().zip(&block) {
+                *out += s;
+            }
+            self.ready.extend(&self.output[..BLOCK_SHIFT]);
+        }
+        self.ready.drain(..wanted).collect()
+    }
+}
+
+/// The two DTLN stages and the state they carry between blocks
+struct Dtln {
+    stages: [Session; 2],
+    states: [Vec<f32>; 2],
+    forward: Arc<dyn Fft<f32>>,
+    inverse: Arc<dyn Fft<f32>>,
+    spectrum: Vec<Complex32>,
+}
+
+fn load_stage(path: &Path) -> Result<Session> {
+    Session::builder()?
+        .with_intra_threads(1)?
+        .commit_from_file(path)
+        .map_err(|e| anyhow!("Failed to load noise model {:?}: {}", path, e))
+}
+
+impl Dtln {
+    /// Run one stage on `input`, keeping its new state for the next block
+    fn run_stage(&mut self, stage: usize, input: ([usize; 3], Vec<f32>)) -> Result<Vec<f32>> {
+        let input = Tensor::from_array(input)?;
+        let state = Tensor::from_array((STATE_SHAPE, self.states[stage].clone()))?;
+        let outputs = self.stages[stage].run(ort::inputs![input, state])?;
+        let (_, result) = outputs[0].try_extract_tensor::<f32>()?;
+        let (_, state) = outputs[1].try_extract_tensor::<f32>()?;
+        self.states[stage] = state.to_vec();
+        Ok(result.to_vec())
+    }
+
+    fn enhance(&mut self, block: &[f32]) -> Result<Vec<f32>> {
+        for (bin, &s) in self.spectrum.iter_mut().zip(block) {
+            *bin = Complex32::new(s, 0.0);
+        }
+        self.forward.process(&mut self.spectrum);
+        let magnitude = self.spectrum[..BINS].iter().map(|c| c.norm()).collect();
+        let mask = self.run_stage(0, ([1, 1, BINS], magnitude))?;
+        // Only magnitudes are scaled; the noisy phase is kept
+        for (bin, m) in self.spectrum[..BINS].iter_mut().zip(&mask) {
+            *bin *= *m;
+        }
+        for k in 1..BINS - 1 {
+            self.spectrum[BLOCK_LEN - k] = self.spectrum[k].conj();
+        }
+        self.inverse.process(&mut self.spectrum);
+        let estimate = self
+            .spectrum
+            .iter()
+            .map(|c| c.re / BLOCK_LEN as f32)
+            .collect();
+        self.run_stage(1, ([1, 1, BLOCK_LEN], estimate))
+    }
+}
+
+/// Noise suppression for a 16 kHz mono stream, delaying it by 32 ms
+pub struct NoiseSuppressor {
+    model: Dtln,
+    framer: Framer,
+    failed: bool,
+}
+
+impl NoiseSuppressor {
+    /// Model files in the order the stages run
+    pub const MODEL_FILES: [&'static str; 2] = ["dtln_1.onnx", "dtln_2.onnx"];
+
+    /// Load both stages from `model_dir`, which holds `MODEL_FILES`
+    pub fn new(model_dir: &Path) -> Result<Self> {
+        let mut planner = FftPlanner::<f32>::new();
+        Ok(Self {
+            model: Dtln {
+                stages: [
+                    load_stage(&model_dir.join(Self::MODEL_FILES[0]))?,
+                    load_stage(&model_dir.join(Self::MODEL_FILES[1]))?,
+                ],
+                states: [vec![0.0; STATE_LEN], vec![0.0; STATE_LEN]],
+                forward: planner.plan_fft_forward(BLOCK_LEN),
+                inverse: planner.plan_fft_inverse(BLOCK_LEN),
+                spectrum: vec![Complex32::default(); BLOCK_LEN],
+            },
+            framer: Framer::new(),
+            failed: false,
+        })
+    }
+
+    /// Whether both model files are in `model_dir`
+    pub fn models_present(model_dir: &Path) -> bool {
+        Self::MODEL_FILES
+            .iter()
+            .all(|f| model_dir.join(f).is_file())
+    }
+
+    /// Denoise `samples`, returning the same number of samples of the delayed stream.
+    /// Blocks the models fail on pass through unchanged.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        let (model, failed) = (&mut self.model, &mut self.failed);
+        self.framer
+            .process(samples, |block| match model.enhance(block) {
+                Ok(out) => out,
+                Err(e) => {
+                    if !*failed {
+                        log::warn!("Noise suppression failed, passing audio through: {}", e);
+                        *failed = true;
+                    }
+                    // Overlapping quarters add back up to the input
+                    block
+                        .iter()
+                        .map(|s| s * BLOCK_SHIFT as f32 / BLOCK_LEN as f32)
+                        .collect()
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_framer_returns_input_length_with_fixed_delay() {
+        let mut framer = Framer::new();
+        let input: Vec<f32> = (0..1600).map(|i| (i as f32 * 0.01).sin()).collect();
+        // Scaling each block by a quarter makes the overlap-add reproduce the input
+        let quarter = |block: &[f32]| block.iter().map(|s| s * 0.25).collect::<Vec<f32>>();
+        let mut output = Vec::new();
+        for chunk in [&input[..100], &input[100..800], &input[800..]] {
+            let out = framer.process(chunk, quarter);
+            assert_eq!(out.len(), chunk.len());
+            output.extend(out);
+        }
+        let delay = BLOCK_LEN;
+        assert!(output[..delay].iter().all(|s| s.abs() < 1e-6));
+        for (i, s) in output[delay..].iter().enumerate() {
+            assert!((s - input[i]).abs() < 1e-5, "sample {}", i + delay);
+        }
+    }
+}
@@ -1,14 +1,18 @@
 // Re-export all audio components
+mod denoise;
 mod device;
 mod recorder;
 mod resampler;
 mod utils;
 mod loader;
 mod visualizer;
+mod waveform;
 
+pub use denoise::NoiseSuppressor;
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
 pub use recorder::AudioRecorder;
 pub use resampler::FrameResampler;
 pub use utils::save_wav_file;
 pub use visualizer::AudioVisualiser;
+pub use waveform::WaveformSampler;
 pub use loader::load_audio_file_to_mono_16k;
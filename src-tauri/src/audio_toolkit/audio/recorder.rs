@@ -1,6 +1,9 @@
 use std::{
     io::Error,
-    sync::{mpsc, Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
     time::Duration,
 };
 
@@ -10,7 +13,7 @@ use cpal::{
 };
 
 use crate::audio_toolkit::{
-    audio::{AudioVisualiser, FrameResampler},
+    audio::{AudioVisualiser, FrameResampler, NoiseSuppressor, WaveformSampler},
     constants,
     vad::{self, VadFrame},
     VoiceActivityDetector,
@@ -22,12 +25,18 @@ enum Cmd {
     Shutdown,
 }
 
+/// Flag that switches suppression on, and how to load a suppressor when it is
+type NoiseSuppression = (&'static AtomicBool, fn() -> Option<NoiseSuppressor>);
+
 pub struct AudioRecorder {
     device: Option<Device>,
     cmd_tx: Option<mpsc::Sender<Cmd>>,
     worker_handle: Option<std::thread::JoinHandle<()>>,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    waveform_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    frame_cb: Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
+    noise_suppression: Option<NoiseSuppression>,
 }
 
 impl AudioRecorder {
@@ -38,6 +47,9 @@ impl AudioRecorder {
             worker_handle: None,
             vad: None,
             level_cb: None,
+            waveform_cb: None,
+            frame_cb: None,
+            noise_suppression: None,
         })
     }
 
@@ -54,6 +66,36 @@ impl AudioRecorder {
         self
     }
 
+    pub fn with_waveform_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(Vec<f32>) + Send + Sync + 'static,
+    {
+        self.waveform_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Receives every 16 kHz frame while the stream is open, whether or not a
+    /// recording is in progress and before VAD filtering
+    pub fn with_frame_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(&[f32]) + Send + Sync + 'static,
+    {
+        self.frame_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Denoise 16 kHz frames before the frame callback and VAD whenever `enabled` is set,
+    /// with a suppressor from `load` (None passes audio through, e.g. before the models
+    /// are downloaded)
+    pub fn with_noise_suppression(
+        mut self,
+        enabled: &'static AtomicBool,
+        load: fn() -> Option<NoiseSuppressor>,
+    ) -> Self {
+        self.noise_suppression = Some((enabled, load));
+        self
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         if self.worker_handle.is_some() {
             return Ok(()); // already open
@@ -74,6 +116,9 @@ impl AudioRecorder {
         let vad = self.vad.clone();
         // Move the optional level callback into the worker thread
         let level_cb = self.level_cb.clone();
+        let waveform_cb = self.waveform_cb.clone();
+        let frame_cb = self.frame_cb.clone();
+        let noise_suppression = self.noise_suppression;
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -117,7 +162,16 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb);
+            run_consumer(
+                sample_rate,
+                vad,
+                sample_rx,
+                cmd_rx,
+                level_cb,
+                waveform_cb,
+                frame_cb,
+                noise_suppression,
+            );
             // stream is dropped here, after run_consumer returns
         });
 
@@ -228,6 +282,9 @@ fn run_consumer(
     sample_rx: mpsc::Receiver<Vec<f32>>,
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    waveform_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    frame_cb: Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
+    noise_suppression: Option<NoiseSuppression>,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
@@ -237,6 +294,9 @@ fn run_consumer(
 
     let mut processed_samples = Vec::<f32>::new();
     let mut recording = false;
+    // Loaded when suppression is switched on, dropped (and its state with it) when off
+    let mut suppressor: Option<NoiseSuppressor> = None;
+    let mut suppressing = false;
 
     // ---------- spectrum visualisation setup ---------------------------- //
     const BUCKETS: usize = 16;
@@ -248,6 +308,13 @@ fn run_consumer(
         400.0,  // vocal_min_hz
         4000.0, // vocal_max_hz
     );
+    const WAVEFORM_POINTS: usize = 32;
+    let mut waveform = WaveformSampler::new(
+        in_sample_rate,
+        WAVEFORM_POINTS,
+        Duration::from_millis(1500),
+        Duration::from_millis(50),
+    );
 
     fn handle_frame(
         samples: &[f32],
@@ -282,9 +349,26 @@ fn run_consumer(
                 cb(buckets);
             }
         }
+        if let Some(cb) = &waveform_cb {
+            if let Some(peaks) = waveform.feed(&raw) {
+                cb(peaks);
+            }
+        }
 
         // ---------- existing pipeline ------------------------------------ //
+        if let Some((enabled, load)) = noise_suppression {
+            let denoise = enabled.load(Ordering::Relaxed);
+            if denoise != suppressing {
+                suppressing = denoise;
+                suppressor = if denoise { load() } else { None };
+            }
+        }
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
+            let denoised = suppressor.as_mut().map(|s| s.process(frame));
+            let frame = denoised.as_deref().unwrap_or(frame);
+            if let Some(cb) = &frame_cb {
+                cb(frame);
+            }
             handle_frame(frame, recording, &vad, &mut processed_samples)
         });
 
@@ -295,6 +379,7 @@ fn run_consumer(
                     processed_samples.clear();
                     recording = true;
                     visualizer.reset(); // Reset visualization buffer
+                    waveform.reset();
                     if let Some(v) = &vad {
                         v.lock().unwrap().reset();
                     }
@@ -304,6 +389,8 @@ fn run_consumer(
 
                     frame_resampler.finish(&mut |frame: &[f32]| {
                         // we still want to process the last few frames
+                        let denoised = suppressor.as_mut().map(|s| s.process(frame));
+                        let frame = denoised.as_deref().unwrap_or(frame);
                         handle_frame(frame, true, &vad, &mut processed_samples)
                     });
 
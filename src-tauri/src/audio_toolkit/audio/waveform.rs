@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Peak envelope of the most recent audio, produced at a fixed rate for drawing a
+/// scrolling waveform
+pub struct WaveformSampler {
+    history: VecDeque<f32>,
+    window_len: usize,
+    hop_len: usize,
+    since_emit: usize,
+    points: usize,
+}
+
+impl WaveformSampler {
+    /// `window` of audio is drawn as `points` peaks, refreshed every `hop`
+    pub fn new(sample_rate: u32, points: usize, window: Duration, hop: Duration) -> Self {
+        let points = points.max(1);
+        let window_len = ((sample_rate as f64 * window.as_secs_f64()) as usize).max(points);
+        let hop_len = ((sample_rate as f64 * hop.as_secs_f64()) as usize).max(1);
+        Self {
+            history: VecDeque::from(vec![0.0; window_len]),
+            window_len,
+            hop_len,
+            since_emit: 0,
+            points,
+        }
+    }
+
+    /// Returns the peaks (0..=1, oldest first) once per hop
+    pub fn feed(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        for &s in samples {
+            if self.history.len() == self.window_len {
+                self.history.pop_front();
+            }
+            self.history.push_back(s.abs().min(1.0));
+        }
+        self.since_emit += samples.len();
+        if self.since_emit < self.hop_len {
+            return None;
+        }
+        self.since_emit = 0;
+
+        let bin = self.history.len() / self.points;
+        let peaks = (0..self.points)
+            .map(|i| {
+                self.history
+                    .range(i * bin..(i + 1) * bin)
+                    .copied()
+                    .fold(0.0f32, f32::max)
+            })
+            .collect();
+        Some(peaks)
+    }
+
+    pub fn reset(&mut self) {
+        self.history.iter_mut().for_each(|s| *s = 0.0);
+        self.since_emit = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waveform_peaks_per_hop() {
+        let mut sampler = WaveformSampler::new(
+            1000,
+            4,
+            Duration::from_millis(400),
+            Duration::from_millis(100),
+        );
+        assert!(sampler.feed(&[0.5; 50]).is_none());
+
+        // Loud burst at the end lands in the newest bin, then scrolls back
+        let mut chunk = vec![0.1; 40];
+        chunk.extend([-0.9; 10]);
+        assert_eq!(sampler.feed(&chunk).unwrap(), vec![0.0, 0.0, 0.0, 0.9]);
+        assert_eq!(sampler.feed(&[0.2; 100]).unwrap(), vec![0.0, 0.0, 0.9, 0.2]);
+    }
+}
@@ -7,6 +7,6 @@ pub mod vad;
 pub use audio::{
     list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
 };
-pub use text::apply_custom_words;
+pub use text::{apply_custom_words, build_initial_prompt};
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
@@ -132,6 +132,50 @@ fn extract_punctuation(word: &str) -> (&str, &str) {
     (prefix, suffix)
 }
 
+/// Most characters of earlier text carried into a Whisper prompt. Whisper keeps at most
+/// 224 prompt tokens and the custom words share them.
+const PROMPT_CONTEXT_CHARS: usize = 200;
+
+/// Builds an initial prompt for Whisper from the custom words and the text transcribed
+/// just before the audio, so spelling and casing carry over between chunks
+///
+/// Only the end of `previous_text` is used, starting at a word boundary. Returns `None`
+/// when there is nothing to prompt with.
+pub fn build_initial_prompt(
+    custom_words: &[String],
+    previous_text: Option<&str>,
+) -> Option<String> {
+    let words: Vec<&str> = custom_words
+        .iter()
+        .map(|w| w.trim())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let previous = previous_text.map(str::trim).unwrap_or("");
+    let char_count = previous.chars().count();
+    let tail = if char_count > PROMPT_CONTEXT_CHARS {
+        let start = previous
+            .char_indices()
+            .nth(char_count - PROMPT_CONTEXT_CHARS)
+            .map_or(0, |(i, _)| i);
+        let cut = &previous[start..];
+        match cut.find(char::is_whitespace) {
+            Some(i) => cut[i..].trim_start(),
+            None => cut,
+        }
+    } else {
+        previous
+    };
+
+    let mut parts = Vec::new();
+    if !words.is_empty() {
+        parts.push(format!("{}.", words.join(", ")));
+    }
+    if !tail.is_empty() {
+        parts.push(tail.to_string());
+    }
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +210,21 @@ mod tests {
         assert_eq!(extract_punctuation("...hello..."), ("...", "..."));
     }
 
+    #[test]
+    fn test_build_initial_prompt() {
+        let words = vec!["Kubernetes".to_string(), " ".to_string(), "Acme".to_string()];
+        assert_eq!(
+            build_initial_prompt(&words, Some("  we moved the cluster. ")),
+            Some("Kubernetes, Acme. we moved the cluster.".to_string())
+        );
+        assert_eq!(build_initial_prompt(&[], Some("")), None);
+
+        let long = format!("{} final words", "word ".repeat(100));
+        let prompt = build_initial_prompt(&[], Some(&long)).unwrap();
+        assert!(prompt.chars().count() <= PROMPT_CONTEXT_CHARS);
+        assert!(prompt.starts_with("word ") && prompt.ends_with("final words"));
+    }
+
     #[test]
     fn test_empty_custom_words() {
         let text = "hello world";
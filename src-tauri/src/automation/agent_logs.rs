@@ -0,0 +1,120 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// Directory (relative to the project root) holding captured agent sessions
+pub const AGENT_LOGS_DIR: &str = ".claude/agent-logs";
+
+/// One captured agent terminal session, recorded in `.claude/agent-logs/index.jsonl`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentLogEntry {
+    pub meeting_id: String,
+    /// Meeting update that triggered the session (None for manual sessions)
+    #[serde(default)]
+    pub update_id: Option<u32>,
+    /// What launched the session: "update", "prompt", or "terminal"
+    pub kind: String,
+    /// Log file path relative to the project root
+    pub log_path: String,
+    pub started_at: String, // RFC3339
+}
+
+/// Allocate a relative log path for a new session
+pub fn new_log_path(label: &str) -> String {
+    format!(
+        "{}/{}-{}.log",
+        AGENT_LOGS_DIR,
+        label,
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    )
+}
+
+/// Wrap a shell command so its terminal output is captured to `rel_log`.
+/// Uses BSD `script`, which keeps the session interactive while recording it.
+/// With no command, the recorded session is an interactive shell.
+pub fn wrap_with_capture(rel_log: &str, command: Option<&str>) -> String {
+    match command {
+        Some(cmd) => format!("mkdir -p {} && script -q {} {}", AGENT_LOGS_DIR, rel_log, cmd),
+        None => format!("mkdir -p {} && script -q {}", AGENT_LOGS_DIR, rel_log),
+    }
+}
+
+/// Append a session entry to the project's agent log index
+pub fn record_session(
+    project_path: &str,
+    meeting_id: &str,
+    update_id: Option<u32>,
+    kind: &str,
+    log_path: &str,
+) -> Result<()> {
+    let dir = Path::new(project_path).join(AGENT_LOGS_DIR);
+    fs::create_dir_all(&dir)?;
+    let entry = AgentLogEntry {
+        meeting_id: meeting_id.to_string(),
+        update_id,
+        kind: kind.to_string(),
+        log_path: log_path.to_string(),
+        started_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let line = serde_json::to_string(&entry)? + "\n";
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join("index.jsonl"))?;
+    file.write_all(line.as_bytes())?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Read all recorded sessions for a project, oldest first
+pub fn list_sessions(project_path: &str) -> Vec<AgentLogEntry> {
+    let path = Path::new(project_path).join(AGENT_LOGS_DIR).join("index.jsonl");
+    let Ok(content) = fs::read_to_string(path) else { return Vec::new(); };
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| serde_json::from_str::<AgentLogEntry>(l).ok())
+        .collect()
+}
+
+/// Read a captured log, refusing paths outside the agent log directory
+pub fn read_log(project_path: &str, log_path: &str) -> Result<String> {
+    let rel = Path::new(log_path);
+    if !rel.starts_with(AGENT_LOGS_DIR)
+        || rel.components().any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err(anyhow!("Not an agent log: {}", log_path));
+    }
+    let bytes = fs::read(Path::new(project_path).join(rel))?;
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_and_list_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_str().unwrap();
+
+        record_session(root, "m1", Some(3), "update", ".claude/agent-logs/update-3.log").unwrap();
+        record_session(root, "m1", None, "terminal", ".claude/agent-logs/terminal.log").unwrap();
+
+        let sessions = list_sessions(root);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].update_id, Some(3));
+        assert_eq!(sessions[1].kind, "terminal");
+    }
+
+    #[test]
+    fn test_read_log_rejects_outside_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_str().unwrap();
+        assert!(read_log(root, ".claude/agent-logs/../../secrets.txt").is_err());
+        assert!(read_log(root, "src/main.rs").is_err());
+    }
+}
@@ -1,8 +1,11 @@
-use crate::settings;
+use crate::automation::agent_logs;
+use crate::automation::guardrails::{self, AgentGuardrails};
+use crate::automation::repo_config;
+use crate::events::{self, AppEvent};
 use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -13,6 +16,14 @@ use tauri::AppHandle;
 static LAST_TRIGGERS: Lazy<Mutex<HashMap<String, (u32, Instant)>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Meetings whose last agent session went over the guardrails; auto-accept stays off
+/// for them until a later session finishes within budget
+static OVER_BUDGET: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// How often, and for how long, a finished agent session is waited for
+const SESSION_POLL: Duration = Duration::from_secs(5);
+const SESSION_MAX_WAIT: Duration = Duration::from_secs(6 * 60 * 60);
+
 #[derive(Serialize, Deserialize, Default, Clone)]
 struct AutomationState {
     last_trigger_update_id: u32,
@@ -97,7 +108,7 @@ pub fn trigger_meeting_update(
     meeting_id: &str,
     update_id: u32,
 ) -> Result<bool> {
-    let settings = settings::get_settings(app);
+    let settings = repo_config::effective_settings(app, project_path);
     if !settings.auto_trigger_meeting_command {
         return Ok(false);
     }
@@ -134,18 +145,40 @@ pub fn trigger_meeting_update(
     let validated_path = validate_project_path(project_path)?;
     let escaped = escape_path_for_applescript(&validated_path);
 
+    // Capture the agent session's terminal output for later review
+    let log_path = agent_logs::new_log_path(&format!("update-{}", update_id));
+    // Marker touched when the session exits, so its changes can be checked afterwards
+    let done_marker = format!("{}.done", log_path);
+    let meeting_cmd = format!(
+        "{}; touch {}",
+        agent_logs::wrap_with_capture(
+            &log_path,
+            Some("sh -c '[ -x ./.claude/bin/meeting ] && ./.claude/bin/meeting || meeting'"),
+        ),
+        done_marker
+    );
+
+    // Snapshot before launch so only the session's own changes count against the budgets
+    let snapshot = match guardrails::snapshot_working_tree(&validated_path) {
+        Ok(oid) => Some(oid),
+        Err(e) => {
+            log::debug!("AUTOMATION guardrail snapshot skipped: {}", e);
+            None
+        }
+    };
+
     // Prepare AppleScript for Terminal
     // Prefer reusing the selected tab if a window exists, else open new.
     let script_terminal = format!(
         r#"tell application "Terminal"
 activate
 if (count of windows) > 0 then
-    do script "cd {} && ( [ -x ./.claude/bin/meeting ] && ./.claude/bin/meeting || meeting )" in selected tab of front window
+    do script "cd {} && {}" in selected tab of front window
 else
-    do script "cd {} && ( [ -x ./.claude/bin/meeting ] && ./.claude/bin/meeting || meeting )"
+    do script "cd {} && {}"
 end if
 end tell"#,
-        escaped, escaped
+        escaped, meeting_cmd, escaped, meeting_cmd
     );
 
     let mut used_app = "Terminal";
@@ -161,17 +194,38 @@ on error
     set newWindow to current window
 end try
 tell current session of newWindow
-    write text "cd {} && ( [ -x ./.claude/bin/meeting ] && ./.claude/bin/meeting || meeting )"
+    write text "cd {} && {}"
 end tell
 end tell"#,
-            escaped
+            escaped, meeting_cmd
         );
         run_osascript(&script_iterm)?;
         used_app = "iTerm";
     }
 
+    let log_update_id = if is_forced { None } else { Some(update_id) };
+    if let Err(e) = agent_logs::record_session(&validated_path, meeting_id, log_update_id, "update", &log_path) {
+        log::warn!("AUTOMATION agent log index write failed: {}", e);
+    }
+
+    if let Some(snapshot) = snapshot {
+        let app = app.clone();
+        let guardrails = AgentGuardrails::from_settings(&settings);
+        let (project, meeting) = (validated_path.clone(), meeting_id.to_string());
+        std::thread::spawn(move || {
+            check_session_when_done(&app, &project, &meeting, update_id, &done_marker, snapshot, &guardrails)
+        });
+    }
+
+    // Guardrails: keep auto-accept off while the meeting's last session is over budget
+    let mut auto_accept = settings.auto_accept_changes;
+    if auto_accept && OVER_BUDGET.lock().unwrap().contains(meeting_id) {
+        log::warn!("AUTOMATION auto-accept withheld: last agent session exceeded the guardrails");
+        auto_accept = false;
+    }
+
     // Optional auto-accept via System Events
-    if settings.auto_accept_changes {
+    if auto_accept {
         let script_accept = format!(
             r#"tell application "{}" to activate
 delay 0.6
@@ -207,18 +261,139 @@ end tell"#,
     Ok(true)
 }
 
-pub fn open_project_in_terminal(_app: &AppHandle, project_path: &str) -> Result<()> {
+/// Wait for an agent session to exit, then check the changes it made since `snapshot`.
+/// Violations emit `agent-change-blocked` and hold back auto-accept for the meeting.
+fn check_session_when_done(
+    app: &AppHandle,
+    project_path: &str,
+    meeting_id: &str,
+    update_id: u32,
+    done_marker: &str,
+    snapshot: git2::Oid,
+    limits: &AgentGuardrails,
+) {
+    let marker = Path::new(project_path).join(done_marker);
+    let started = Instant::now();
+    while !marker.exists() {
+        if started.elapsed() > SESSION_MAX_WAIT {
+            log::debug!("AUTOMATION gave up waiting for agent session {}", done_marker);
+            return;
+        }
+        std::thread::sleep(SESSION_POLL);
+    }
+    let _ = fs::remove_file(&marker);
+
+    match guardrails::evaluate_since_snapshot(project_path, snapshot, limits) {
+        Ok(report) if !report.is_allowed() => {
+            log::warn!("AUTOMATION agent changes over budget: {}", report.violations.join("; "));
+            OVER_BUDGET.lock().unwrap().insert(meeting_id.to_string());
+            events::emit(
+                app,
+                AppEvent::AgentChangeBlocked {
+                    meeting_id: meeting_id.to_string(),
+                    update_id,
+                    reasons: report.violations,
+                    files_touched: report.files_touched,
+                    lines_changed: report.lines_changed,
+                    untested_files: report.untested_files,
+                },
+            );
+        }
+        Ok(_) => {
+            OVER_BUDGET.lock().unwrap().remove(meeting_id);
+        }
+        Err(e) => log::debug!("AUTOMATION guardrail check skipped: {}", e),
+    }
+}
+
+/// Write a focused prompt to `.claude/prompts/` and launch the agent on it in a terminal.
+/// Returns the path of the prompt file relative to the project root.
+pub fn trigger_agent_prompt(
+    project_path: &str,
+    meeting_id: &str,
+    label: &str,
+    prompt: &str,
+) -> Result<String> {
     // Security: Validate and sanitize project path to prevent command injection
     let validated_path = validate_project_path(project_path)?;
     let escaped = escape_path_for_applescript(&validated_path);
 
+    // File name is built only from [a-z0-9-] so it is safe to splice into the command
+    let slug: String = label
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let file_name = format!(
+        "{}-{}.md",
+        if slug.is_empty() { "prompt" } else { slug.as_str() },
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    );
+    let rel_path = format!(".claude/prompts/{}", file_name);
+    let prompt_path = Path::new(&validated_path).join(&rel_path);
+    if let Some(parent) = prompt_path.parent() { fs::create_dir_all(parent)?; }
+    fs::write(&prompt_path, prompt)?;
+
+    let log_path = agent_logs::new_log_path("prompt");
+    let agent_cmd = agent_logs::wrap_with_capture(
+        &log_path,
+        Some(&format!("claude \\\"$(cat {})\\\"", rel_path)),
+    );
+
+    let script_terminal = format!(
+        r#"tell application "Terminal"
+activate
+do script "cd {} && {}"
+end tell"#,
+        escaped, agent_cmd
+    );
+    if let Err(e) = run_osascript(&script_terminal) {
+        log::warn!("AUTOMATION Terminal prompt launch failed: {}", e);
+        let script_iterm = format!(
+            r#"tell application "iTerm"
+activate
+try
+    set newWindow to (create window with default profile)
+on error
+    set newWindow to current window
+end try
+tell current session of newWindow
+    write text "cd {} && {}"
+end tell
+end tell"#,
+            escaped, agent_cmd
+        );
+        run_osascript(&script_iterm)?;
+    }
+
+    if let Err(e) = agent_logs::record_session(&validated_path, meeting_id, None, "prompt", &log_path) {
+        log::warn!("AUTOMATION agent log index write failed: {}", e);
+    }
+
+    log::info!("AUTOMATION launched agent prompt {}", rel_path);
+    Ok(rel_path)
+}
+
+pub fn open_project_in_terminal(_app: &AppHandle, project_path: &str, meeting_id: &str) -> Result<()> {
+    // Security: Validate and sanitize project path to prevent command injection
+    let validated_path = validate_project_path(project_path)?;
+    let escaped = escape_path_for_applescript(&validated_path);
+
+    // Record the interactive session so agent runs started from it are captured
+    let log_path = agent_logs::new_log_path("terminal");
+    let shell_cmd = agent_logs::wrap_with_capture(&log_path, None);
+
     // Open Terminal or iTerm in the project directory without running /meeting
     let script = format!(
         r#"tell application "Terminal"
 activate
-do script "cd {}"
+do script "cd {} && {}"
 end tell"#,
-        escaped
+        escaped, shell_cmd
     );
     if let Err(e) = run_osascript(&script) {
         log::warn!("AUTOMATION Terminal open failed: {}", e);
@@ -231,13 +406,16 @@ on error
     set newWindow to current window
 end try
 tell current session of newWindow
-    write text "cd {}"
+    write text "cd {} && {}"
 end tell
 end tell"#,
-            escaped
+            escaped, shell_cmd
         );
         run_osascript(&script_iterm)?;
     }
+    if let Err(e) = agent_logs::record_session(&validated_path, meeting_id, None, "terminal", &log_path) {
+        log::warn!("AUTOMATION agent log index write failed: {}", e);
+    }
     Ok(())
 }
 
@@ -0,0 +1,417 @@
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::TranscriptSegment;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+/// A shell command suggested from something said in the meeting.
+/// Suggestions are only ever surfaced to the user; MeetingCoder never executes them.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SuggestedCommand {
+    pub command: String,
+    /// "run", "test", "build", "lint", "install", "migrate", or "deploy"
+    pub kind: String,
+    /// The sentence the suggestion was derived from
+    pub source_text: String,
+    pub speaker: String,
+    pub start_time: f64,
+}
+
+/// Tooling detected in the project, used to turn spoken intent into concrete commands
+#[derive(Debug, Clone, Default)]
+pub struct ProjectTooling {
+    /// "npm", "yarn", or "pnpm" when a package.json is present
+    pub node_package_manager: Option<String>,
+    pub npm_scripts: Vec<String>,
+    pub cargo: bool,
+    pub python: bool,
+    pub django: bool,
+    pub go: bool,
+    pub make_targets: Vec<String>,
+    pub fly: bool,
+    pub vercel: bool,
+}
+
+impl ProjectTooling {
+    pub fn detect(project_path: &Path) -> Self {
+        let mut tooling = ProjectTooling::default();
+
+        let package_json = project_path.join("package.json");
+        if let Ok(content) = fs::read_to_string(&package_json) {
+            let pm = if project_path.join("pnpm-lock.yaml").exists() {
+                "pnpm"
+            } else if project_path.join("yarn.lock").exists() {
+                "yarn"
+            } else {
+                "npm"
+            };
+            tooling.node_package_manager = Some(pm.to_string());
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                if let Some(scripts) = json.get("scripts").and_then(|s| s.as_object()) {
+                    tooling.npm_scripts = scripts.keys().cloned().collect();
+                }
+            }
+        }
+
+        tooling.cargo = project_path.join("Cargo.toml").exists();
+        tooling.python = project_path.join("pyproject.toml").exists()
+            || project_path.join("requirements.txt").exists();
+        tooling.django = project_path.join("manage.py").exists();
+        tooling.go = project_path.join("go.mod").exists();
+        tooling.fly = project_path.join("fly.toml").exists();
+        tooling.vercel = project_path.join("vercel.json").exists();
+
+        if let Ok(makefile) = fs::read_to_string(project_path.join("Makefile")) {
+            tooling.make_targets = makefile
+                .lines()
+                .filter(|l| !l.starts_with('\t') && !l.starts_with('.') && !l.starts_with('#'))
+                .filter_map(|l| l.split_once(':'))
+                .filter(|(target, rest)| !target.contains(' ') && !target.contains('=') && !rest.starts_with('='))
+                .map(|(target, _)| target.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+        }
+
+        tooling
+    }
+
+    fn npm_run(&self, script: &str) -> Option<String> {
+        let pm = self.node_package_manager.as_deref()?;
+        if !self.npm_scripts.iter().any(|s| s == script) {
+            return None;
+        }
+        Some(match (pm, script) {
+            ("npm", "test") => "npm test".to_string(),
+            ("npm", _) => format!("npm run {}", script),
+            (pm, _) => format!("{} {}", pm, script),
+        })
+    }
+
+    fn make(&self, target: &str) -> Option<String> {
+        self.make_targets
+            .iter()
+            .any(|t| t == target)
+            .then(|| format!("make {}", target))
+    }
+
+    fn test_command(&self) -> Option<String> {
+        self.npm_run("test")
+            .or_else(|| self.cargo.then(|| "cargo test".to_string()))
+            .or_else(|| self.django.then(|| "python manage.py test".to_string()))
+            .or_else(|| self.python.then(|| "pytest".to_string()))
+            .or_else(|| self.go.then(|| "go test ./...".to_string()))
+            .or_else(|| self.make("test"))
+    }
+
+    fn build_command(&self) -> Option<String> {
+        self.npm_run("build")
+            .or_else(|| self.cargo.then(|| "cargo build".to_string()))
+            .or_else(|| self.go.then(|| "go build ./...".to_string()))
+            .or_else(|| self.make("build"))
+    }
+
+    fn lint_command(&self) -> Option<String> {
+        self.npm_run("lint")
+            .or_else(|| self.cargo.then(|| "cargo clippy".to_string()))
+            .or_else(|| self.go.then(|| "go vet ./...".to_string()))
+            .or_else(|| self.make("lint"))
+    }
+
+    fn migrate_command(&self) -> Option<String> {
+        self.npm_run("migrate")
+            .or_else(|| self.django.then(|| "python manage.py migrate".to_string()))
+            .or_else(|| self.make("migrate"))
+    }
+
+    fn deploy_command(&self, target: Option<&str>) -> Option<String> {
+        if let Some(target) = target {
+            let scoped = format!("deploy:{}", target);
+            if let Some(cmd) = self.npm_run(&scoped) {
+                return Some(cmd);
+            }
+        }
+        let production = matches!(target, Some("prod") | Some("production"));
+        self.npm_run("deploy")
+            .or_else(|| self.make("deploy"))
+            .or_else(|| self.fly.then(|| "fly deploy".to_string()))
+            .or_else(|| {
+                self.vercel.then(|| {
+                    if production { "vercel --prod".to_string() } else { "vercel".to_string() }
+                })
+            })
+    }
+
+    fn install_command(&self, package: &str) -> Option<String> {
+        if let Some(pm) = self.node_package_manager.as_deref() {
+            let verb = if pm == "npm" { "install" } else { "add" };
+            return Some(format!("{} {} {}", pm, verb, package));
+        }
+        if self.cargo {
+            return Some(format!("cargo add {}", package));
+        }
+        if self.python {
+            return Some(format!("pip install {}", package));
+        }
+        if self.go {
+            return Some(format!("go get {}", package));
+        }
+        None
+    }
+}
+
+/// CLI tools whose invocation can be taken verbatim when spoken after "run"
+const KNOWN_TOOLS: &[&str] = &[
+    "npm", "npx", "yarn", "pnpm", "cargo", "make", "git", "docker", "pytest", "python", "go",
+    "terraform", "kubectl",
+];
+
+const FILLER_WORDS: &[&str] = &["the", "a", "an", "our", "all", "some", "those", "these"];
+
+/// Word standing in for a comma, so a spoken command ends with its clause
+const CLAUSE_BREAK: &str = ",";
+
+/// Words that start the next clause rather than continue a spoken command
+const CLAUSE_WORDS: &[&str] = &[
+    "and", "then", "but", "or", "so", "because", "before", "after", "while", "once", "until",
+    "if", "please",
+];
+
+fn words(sentence: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for raw in sentence.split_whitespace() {
+        let word = raw
+            .trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_' && c != '@' && c != '/' && c != '.' && c != ':')
+            .trim_end_matches('.')
+            .to_lowercase();
+        if !word.is_empty() {
+            out.push(word);
+        }
+        if raw.ends_with(',') {
+            out.push(CLAUSE_BREAK.to_string());
+        }
+    }
+    out
+}
+
+/// A tool invocation spoken after "run", up to the end of its clause
+fn verbatim_command(rest: &[String]) -> String {
+    rest.iter()
+        .take_while(|w| w.as_str() != CLAUSE_BREAK && !CLAUSE_WORDS.contains(&w.as_str()))
+        .map(|w| w.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn skip_fillers(words: &[String]) -> &[String] {
+    let start = words
+        .iter()
+        .position(|w| !FILLER_WORDS.contains(&w.as_str()))
+        .unwrap_or(words.len());
+    &words[start..]
+}
+
+/// Derive command suggestions from a single sentence
+pub fn suggest_from_sentence(sentence: &str, tooling: &ProjectTooling) -> Vec<(String, String)> {
+    let words = words(sentence);
+    let mut out: Vec<(String, String)> = Vec::new();
+
+    for (i, word) in words.iter().enumerate() {
+        let rest = skip_fillers(&words[i + 1..]);
+        let next = rest.first().map(|s| s.as_str());
+        match word.as_str() {
+            "run" | "rerun" | "execute" => {
+                let Some(next) = next else { continue; };
+                if KNOWN_TOOLS.contains(&next) {
+                    out.push(("run".to_string(), verbatim_command(rest)));
+                    continue;
+                }
+                let suggestion = match next {
+                    "test" | "tests" | "test-suite" | "specs" => {
+                        tooling.test_command().map(|c| ("test", c))
+                    }
+                    "build" => tooling.build_command().map(|c| ("build", c)),
+                    "linter" | "lint" | "lints" => tooling.lint_command().map(|c| ("lint", c)),
+                    "migration" | "migrations" => tooling.migrate_command().map(|c| ("migrate", c)),
+                    "deploy" => tooling.deploy_command(None).map(|c| ("deploy", c)),
+                    script => tooling.npm_run(script).map(|c| ("run", c)),
+                };
+                if let Some((kind, cmd)) = suggestion {
+                    out.push((kind.to_string(), cmd));
+                }
+            }
+            "deploy" | "ship" => {
+                let target = rest
+                    .iter()
+                    .find(|w| !matches!(w.as_str(), "it" | "this" | "that" | "to" | "on" | "the"))
+                    .map(|s| s.as_str());
+                if let Some(cmd) = tooling.deploy_command(target) {
+                    out.push(("deploy".to_string(), cmd));
+                }
+            }
+            "install" | "add" => {
+                let Some(package) = next else { continue; };
+                // "add a test", "install it" etc. are not package names
+                if matches!(package, "it" | "that" | "this" | "them" | "test" | "tests" | "support" | "feature")
+                    || (word == "add" && !rest.get(1).map(|w| w == "package" || w == "dependency" || w == "library").unwrap_or(false))
+                {
+                    continue;
+                }
+                if let Some(cmd) = tooling.install_command(package) {
+                    out.push(("install".to_string(), cmd));
+                }
+            }
+            "migrate" => {
+                if let Some(cmd) = tooling.migrate_command() {
+                    out.push(("migrate".to_string(), cmd));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut seen = HashSet::new();
+    out.retain(|(_, cmd)| seen.insert(cmd.clone()));
+    out
+}
+
+/// Split on sentence-ending punctuation, keeping dots inside tokens like "package.json"
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let at_boundary = chars.peek().map(|(_, n)| n.is_whitespace()).unwrap_or(true);
+        if matches!(c, '.' | '!' | '?' | ';') && at_boundary {
+            sentences.push(text[start..i].trim());
+            start = i + c.len_utf8();
+        }
+    }
+    sentences.push(text[start..].trim());
+    sentences.retain(|s| !s.is_empty());
+    sentences
+}
+
+/// Derive deduplicated command suggestions from transcript segments
+pub fn suggest_commands(segments: &[TranscriptSegment], tooling: &ProjectTooling) -> Vec<SuggestedCommand> {
+    let mut seen = HashSet::new();
+    let mut suggestions = Vec::new();
+    for seg in segments {
+        for sentence in split_sentences(&seg.text) {
+            for (kind, command) in suggest_from_sentence(sentence, tooling) {
+                if seen.insert(command.clone()) {
+                    suggestions.push(SuggestedCommand {
+                        command,
+                        kind,
+                        source_text: sentence.to_string(),
+                        speaker: seg.speaker.clone(),
+                        start_time: seg.start_time,
+                    });
+                }
+            }
+        }
+    }
+    suggestions
+}
+
+// Map: meeting_id -> commands already surfaced via event
+static EMITTED: Lazy<Mutex<HashMap<String, HashSet<String>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Drop what was surfaced for a meeting once it ends
+pub fn forget_meeting(meeting_id: &str) {
+    EMITTED.lock().unwrap().remove(meeting_id);
+}
+
+/// Check the newest segment for actionable requests and emit any new suggestions.
+/// `segments` is the meeting transcript with the new segment last.
+pub fn observe_segment(app: &AppHandle, project_path: &str, meeting_id: &str, segments: &[TranscriptSegment]) {
+    let Some(latest) = segments.last() else { return; };
+    let tooling = ProjectTooling::detect(Path::new(project_path));
+    let mut suggestions = suggest_commands(std::slice::from_ref(latest), &tooling);
+    {
+        let mut emitted = EMITTED.lock().unwrap();
+        let seen = emitted.entry(meeting_id.to_string()).or_default();
+        suggestions.retain(|s| seen.insert(s.command.clone()));
+    }
+    if suggestions.is_empty() {
+        return;
+    }
+
+    log::info!(
+        "AUTOMATION suggested {} command(s) for meeting {}",
+        suggestions.len(),
+        meeting_id
+    );
+    events::emit(
+        app,
+        AppEvent::SuggestedCommands {
+            meeting_id: meeting_id.to_string(),
+            suggestions,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn node_tooling() -> ProjectTooling {
+        ProjectTooling {
+            node_package_manager: Some("npm".to_string()),
+            npm_scripts: vec!["test".to_string(), "build".to_string(), "dev".to_string()],
+            vercel: true,
+            ..Default::default()
+        }
+    }
+
+    fn segment(text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: "Speaker 1".to_string(),
+            start_time: 5.0,
+            end_time: 9.0,
+            text: text.to_string(),
+            confidence: 0.9,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_maps_spoken_intent_to_project_commands() {
+        let t = node_tooling();
+        let cmds: Vec<String> = suggest_from_sentence("Can you run the tests and then deploy to production", &t)
+            .into_iter()
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(cmds, vec!["npm test".to_string(), "vercel --prod".to_string()]);
+        assert_eq!(suggest_from_sentence("let's run the dev server", &t)[0].1, "npm run dev");
+    }
+
+    #[test]
+    fn test_verbatim_tool_invocation_and_install() {
+        let t = ProjectTooling { cargo: true, ..Default::default() };
+        assert_eq!(suggest_from_sentence("just run cargo fmt", &t)[0].1, "cargo fmt");
+        assert_eq!(suggest_from_sentence("run cargo fmt, then push", &t)[0].1, "cargo fmt");
+        assert_eq!(
+            suggest_from_sentence("can you run git pull and check the logs", &t)[0].1,
+            "git pull"
+        );
+        assert_eq!(suggest_from_sentence("we should install serde", &t)[0].1, "cargo add serde");
+        assert!(suggest_from_sentence("add a test for the parser", &t).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_commands_dedupes_across_segments() {
+        let t = node_tooling();
+        let segs = vec![segment("Run the tests."), segment("Yeah, rerun the tests please.")];
+        let suggestions = suggest_commands(&segs, &t);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].kind, "test");
+        assert_eq!(suggestions[0].source_text, "Run the tests");
+    }
+}
@@ -1,5 +1,5 @@
+use crate::automation::repo_config;
 use crate::integrations::github;
-use crate::settings;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -34,7 +34,7 @@ pub async fn auto_create_branch(
     meeting_id: &str,
     meeting_name: &str,
 ) -> Result<String> {
-    let settings = settings::get_settings(app);
+    let settings = repo_config::effective_settings(app, project_path);
 
     // Only proceed if GitHub is enabled
     if !settings.github_enabled {
@@ -116,7 +116,7 @@ pub async fn auto_commit_and_push(
     meeting_name: &str,
     update_id: u32,
 ) -> Result<String> {
-    let settings = settings::get_settings(app);
+    let settings = repo_config::effective_settings(app, project_path);
 
     // Check if auto-commit-push is enabled
     if !settings.github_enabled || !settings.github_auto_commit_push {
@@ -170,11 +170,18 @@ pub async fn auto_commit_and_push(
         update_id
     );
 
-    github::commit_meeting_files(&repo_obj, &commit_message, "Handy", "noreply@handy.computer")
+    github::commit_meeting_files(
+        &repo_obj,
+        &repo_config::commit_paths(project_path),
+        &commit_message,
+        "Handy",
+        "noreply@handy.computer",
+    )
         .map_err(|e| anyhow!("Failed to commit: {}", e))?;
 
     // Push to remote
-    github::push_to_remote(project_path, &branch_name, &token, owner, repo)
+    let auth = github::GitAuth::from_settings(&settings, &token);
+    github::push_to_remote(project_path, &branch_name, &auth, owner, repo)
         .map_err(|e| anyhow!("Failed to push: {}", e))?;
 
     // Update GitHub state
@@ -201,7 +208,7 @@ pub async fn auto_create_or_update_pr(
     meeting_name: &str,
     is_first_update: bool,
 ) -> Result<(u32, String)> {
-    let settings = settings::get_settings(app);
+    let settings = repo_config::effective_settings(app, project_path);
 
     // Check if auto-PR is enabled
     if !settings.github_enabled {
@@ -291,6 +298,23 @@ pub async fn auto_create_or_update_pr(
             new_pr.number,
             new_pr.html_url
         );
+        crate::notifications::notify(
+            app,
+            crate::notifications::Milestone::PrCreated,
+            &format!("Pull request #{} created", new_pr.number),
+            &pr_title,
+        );
+        crate::integrations::webhooks::dispatch(
+            app,
+            crate::settings::WebhookEvent::PrCreated,
+            serde_json::json!({
+                "meeting_id": meeting_id,
+                "project_path": project_path,
+                "pr_number": new_pr.number,
+                "pr_url": new_pr.html_url,
+                "title": pr_title,
+            }),
+        );
 
         new_pr
     };
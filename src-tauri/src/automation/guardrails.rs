@@ -0,0 +1,272 @@
+use crate::settings::AppSettings;
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+/// Limits on the changes one agent session may make; a session over them holds back
+/// auto-accept for its meeting until a later session stays within budget
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentGuardrails {
+    /// Maximum number of files the agent may touch (0 disables the check)
+    pub max_files_touched: u32,
+    /// Maximum lines added + removed across all files (0 disables the check)
+    pub max_loc_changed: u32,
+    /// Glob patterns (relative to the project root) the agent may modify.
+    /// An empty list allows every path.
+    pub allowed_path_globs: Vec<String>,
+}
+
+impl AgentGuardrails {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            max_files_touched: settings.agent_max_files_touched,
+            max_loc_changed: settings.agent_max_loc_changed,
+            allowed_path_globs: settings.agent_allowed_path_globs.clone(),
+        }
+    }
+}
+
+/// Summary of pending working-tree changes and any guardrail violations
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GuardrailReport {
+    pub files_touched: Vec<String>,
+    pub lines_changed: u64,
+    pub violations: Vec<String>,
+    /// Touched source files with no mapped tests (advisory; not a violation)
+    pub untested_files: Vec<String>,
+}
+
+impl GuardrailReport {
+    pub fn is_allowed(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Paths MeetingCoder itself writes during a meeting; never counted against the agent
+const INTERNAL_PATHS: &[&str] = &[
+    ".meeting-updates.jsonl",
+    ".transcript.jsonl",
+    ".claude/.meeting-state.json",
+    ".claude/.automation-state.json",
+    ".claude/.github-state.json",
+    ".claude/agent-activity.jsonl",
+    ".claude/agent-logs/**",
+    ".claude/hooks/meetingcoder-log.sh",
+    ".claude/prompts/**",
+    "audio_segments/**",
+];
+
+/// Match a relative path against a simple glob (`*`, `?`, and `**` for any depth)
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    fn matches(p: &[u8], s: &[u8]) -> bool {
+        if p.is_empty() {
+            return s.is_empty();
+        }
+        if p.starts_with(b"**") {
+            let rest = p[2..].strip_prefix(b"/").unwrap_or(&p[2..]);
+            // `**` may consume zero or more whole path components
+            return (0..=s.len()).any(|i| (i == 0 || s[i - 1] == b'/') && matches(rest, &s[i..]))
+                || (rest.is_empty());
+        }
+        match p[0] {
+            b'*' => (0..=s.len())
+                .take_while(|&i| i == 0 || s[i - 1] != b'/')
+                .any(|i| matches(&p[1..], &s[i..])),
+            b'?' => !s.is_empty() && s[0] != b'/' && matches(&p[1..], &s[1..]),
+            c => !s.is_empty() && s[0] == c && matches(&p[1..], &s[1..]),
+        }
+    }
+    let pattern = pattern.trim().trim_start_matches("./");
+    matches(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Check a set of changed files against the configured limits
+pub fn check_changes(
+    guardrails: &AgentGuardrails,
+    files_touched: Vec<String>,
+    lines_changed: u64,
+) -> GuardrailReport {
+    let mut violations = Vec::new();
+
+    if guardrails.max_files_touched > 0 && files_touched.len() as u32 > guardrails.max_files_touched {
+        violations.push(format!(
+            "{} files touched (limit {})",
+            files_touched.len(),
+            guardrails.max_files_touched
+        ));
+    }
+
+    if guardrails.max_loc_changed > 0 && lines_changed > guardrails.max_loc_changed as u64 {
+        violations.push(format!(
+            "{} lines changed (limit {})",
+            lines_changed, guardrails.max_loc_changed
+        ));
+    }
+
+    if !guardrails.allowed_path_globs.is_empty() {
+        for file in &files_touched {
+            let allowed = guardrails
+                .allowed_path_globs
+                .iter()
+                .any(|g| glob_matches(g, file));
+            if !allowed {
+                violations.push(format!("{} is outside the allowed paths", file));
+            }
+        }
+    }
+
+    GuardrailReport {
+        files_touched,
+        lines_changed,
+        violations,
+        untested_files: Vec::new(),
+    }
+}
+
+/// Inspect uncommitted changes (staged, unstaged, and untracked) in the project and
+/// evaluate them against the guardrails
+pub fn evaluate_working_tree(project_path: &str, guardrails: &AgentGuardrails) -> Result<GuardrailReport> {
+    let repo = git2::Repository::open(Path::new(project_path))?;
+    let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+
+    let mut opts = git2::DiffOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true);
+    let diff = repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))?;
+    report_for_diff(project_path, &diff, guardrails)
+}
+
+/// Record the current working tree (tracked, modified, and untracked files) as a tree
+/// object so the changes an agent session makes can later be told apart from those
+/// already pending. The repository's index file is left untouched.
+pub fn snapshot_working_tree(project_path: &str) -> Result<git2::Oid> {
+    let repo = git2::Repository::open(Path::new(project_path))?;
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
+    index.update_all(["*"].iter(), None)?;
+    Ok(index.write_tree_to(&repo)?)
+}
+
+/// Evaluate only what changed in the working tree since `snapshot` was taken
+pub fn evaluate_since_snapshot(
+    project_path: &str,
+    snapshot: git2::Oid,
+    guardrails: &AgentGuardrails,
+) -> Result<GuardrailReport> {
+    let repo = git2::Repository::open(Path::new(project_path))?;
+    let before = repo.find_tree(snapshot)?;
+    let after = repo.find_tree(snapshot_working_tree(project_path)?)?;
+    let diff = repo.diff_tree_to_tree(Some(&before), Some(&after), None)?;
+    report_for_diff(project_path, &diff, guardrails)
+}
+
+fn report_for_diff(
+    project_path: &str,
+    diff: &git2::Diff,
+    guardrails: &AgentGuardrails,
+) -> Result<GuardrailReport> {
+    let mut files_touched = Vec::new();
+    for delta in diff.deltas() {
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|p| p.to_string_lossy().replace('\\', "/"));
+        if let Some(path) = path {
+            if INTERNAL_PATHS.iter().any(|g| glob_matches(g, &path)) {
+                continue;
+            }
+            files_touched.push(path);
+        }
+    }
+
+    let stats = diff.stats()?;
+    let lines_changed = (stats.insertions() + stats.deletions()) as u64;
+
+    let mut report = check_changes(guardrails, files_touched, lines_changed);
+    if let Some(manifest) = crate::codebase::load_manifest_from_state(Path::new(project_path)) {
+        report.untested_files = report
+            .files_touched
+            .iter()
+            .filter(|f| {
+                let path = Path::new(f.as_str());
+                crate::codebase::symbols::language_for(path).is_some()
+                    && !crate::codebase::coverage::is_test_file(path)
+                    && !manifest.test_coverage.is_tested(path)
+            })
+            .cloned()
+            .collect();
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guardrails(max_files: u32, max_loc: u32, globs: &[&str]) -> AgentGuardrails {
+        AgentGuardrails {
+            max_files_touched: max_files,
+            max_loc_changed: max_loc,
+            allowed_path_globs: globs.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("experiments/**", "experiments/m1/src/app.ts"));
+        assert!(glob_matches("**/*.md", "docs/notes/readme.md"));
+        assert!(glob_matches("**/*.md", "readme.md"));
+        assert!(glob_matches("src/*.rs", "src/lib.rs"));
+        assert!(!glob_matches("src/*.rs", "src/nested/lib.rs"));
+        assert!(!glob_matches("experiments/**", "src/app.ts"));
+    }
+
+    #[test]
+    fn test_check_changes_within_limits() {
+        let g = guardrails(5, 100, &["experiments/**"]);
+        let report = check_changes(&g, vec!["experiments/m1/a.ts".to_string()], 40);
+        assert!(report.is_allowed());
+    }
+
+    #[test]
+    fn test_check_changes_reports_each_violation() {
+        let g = guardrails(1, 10, &["experiments/**"]);
+        let report = check_changes(
+            &g,
+            vec!["experiments/m1/a.ts".to_string(), "src/main.ts".to_string()],
+            50,
+        );
+        assert!(!report.is_allowed());
+        assert_eq!(report.violations.len(), 3);
+        assert!(report.violations.iter().any(|v| v.contains("src/main.ts")));
+    }
+
+    #[test]
+    fn test_zero_limits_disable_checks() {
+        let g = guardrails(0, 0, &[]);
+        let files = (0..100).map(|i| format!("src/{}.rs", i)).collect();
+        assert!(check_changes(&g, files, 10_000).is_allowed());
+    }
+
+    #[test]
+    fn test_evaluate_since_snapshot_ignores_pending_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        git2::Repository::init(root).unwrap();
+        std::fs::write(dir.path().join("before.rs"), "fn a() {}\n").unwrap();
+
+        let snapshot = snapshot_working_tree(root).unwrap();
+        std::fs::write(dir.path().join("after.rs"), "fn b() {}\nfn c() {}\n").unwrap();
+        std::fs::write(dir.path().join(".transcript.jsonl"), "{}\n").unwrap();
+
+        let report = evaluate_since_snapshot(root, snapshot, &guardrails(1, 0, &[])).unwrap();
+        assert_eq!(report.files_touched, vec!["after.rs".to_string()]);
+        assert_eq!(report.lines_changed, 2);
+        assert!(report.is_allowed());
+        // The full working-tree check still counts the file that was already pending
+        let pending = evaluate_working_tree(root, &guardrails(1, 0, &[])).unwrap();
+        assert_eq!(pending.files_touched.len(), 2);
+    }
+}
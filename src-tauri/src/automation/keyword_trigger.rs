@@ -0,0 +1,211 @@
+use crate::automation::repo_config;
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::TranscriptSegment;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+/// Number of preceding segments included as context in a keyword-triggered prompt
+const CONTEXT_SEGMENTS: usize = 4;
+
+/// Number of code snippets retrieved from the embedding index, when enabled
+const CODE_SNIPPETS: usize = 3;
+
+/// Minimum gap before the same phrase can fire again for a meeting
+const PHRASE_COOLDOWN: Duration = Duration::from_secs(30);
+
+// Map: (meeting_id, normalized phrase) -> last fire time
+static LAST_FIRED: Lazy<Mutex<HashMap<(String, String), Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Record a fire of `key` at `now` unless it fired within the cooldown. Expired entries are
+/// dropped so the map only holds phrases still cooling down.
+fn claim_phrase(
+    fired: &mut HashMap<(String, String), Instant>,
+    key: (String, String),
+    now: Instant,
+) -> bool {
+    fired.retain(|_, last| now.duration_since(*last) < PHRASE_COOLDOWN);
+    if fired.contains_key(&key) {
+        return false;
+    }
+    fired.insert(key, now);
+    true
+}
+
+/// Lowercase, drop apostrophes, and collapse punctuation/whitespace to single spaces
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| *c != '\'' && *c != '\u{2019}')
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Return the first configured phrase spoken in `text` (whole-word match, case-insensitive)
+pub fn find_trigger_phrase<'a>(text: &str, phrases: &'a [String]) -> Option<&'a str> {
+    let haystack = format!(" {} ", normalize(text));
+    phrases.iter().map(|p| p.as_str()).find(|p| {
+        let needle = normalize(p);
+        !needle.is_empty() && haystack.contains(&format!(" {} ", needle))
+    })
+}
+
+/// Build the agent prompt for a detected phrase from the surrounding transcript
+pub fn build_trigger_prompt(phrase: &str, context: &[TranscriptSegment]) -> String {
+    use std::fmt::Write;
+
+    let mut prompt = String::new();
+    let _ = writeln!(prompt, "# Meeting request: \"{}\"\n", phrase);
+    let _ = writeln!(
+        prompt,
+        "A participant just said \"{}\" during the live meeting. Act on that request using \
+         the conversation below. Keep changes focused on what was asked.\n",
+        phrase
+    );
+    let _ = writeln!(prompt, "## Recent conversation\n");
+    for seg in context {
+        let _ = writeln!(
+            prompt,
+            "- [{:.0}s] {}: {}",
+            seg.start_time,
+            seg.speaker,
+            seg.text.trim()
+        );
+    }
+    let _ = writeln!(
+        prompt,
+        "\nSee `.meeting-updates.jsonl` and `.transcript.jsonl` for the full meeting context."
+    );
+    prompt
+}
+
+/// Check the newest segment for a trigger phrase and, if found, launch a targeted agent prompt.
+/// `segments` is the meeting transcript with the new segment last.
+pub fn maybe_fire(app: &AppHandle, project_path: &str, meeting_id: &str, segments: &[TranscriptSegment]) {
+    let settings = repo_config::effective_settings(app, project_path);
+    if !settings.keyword_triggers_enabled {
+        return;
+    }
+    let Some(latest) = segments.last() else { return; };
+    let Some(phrase) = find_trigger_phrase(&latest.text, &settings.agent_trigger_phrases) else {
+        return;
+    };
+
+    let key = (meeting_id.to_string(), normalize(phrase));
+    if !claim_phrase(&mut LAST_FIRED.lock().unwrap(), key, Instant::now()) {
+        log::info!("AUTOMATION keyword '{}' skipped: cooldown active", phrase);
+        return;
+    }
+
+    let start = segments.len().saturating_sub(CONTEXT_SEGMENTS + 1);
+    let context = segments[start..].to_vec();
+    let app = app.clone();
+    let project_path = project_path.to_string();
+    let meeting_id = meeting_id.to_string();
+    let phrase = phrase.to_string();
+    // Code retrieval and launching the agent are slow; keep them off the transcription worker
+    tauri::async_runtime::spawn_blocking(move || {
+        fire(&app, &project_path, &meeting_id, &phrase, &context)
+    });
+}
+
+fn fire(
+    app: &AppHandle,
+    project_path: &str,
+    meeting_id: &str,
+    phrase: &str,
+    context: &[TranscriptSegment],
+) {
+    let mut prompt = build_trigger_prompt(phrase, context);
+
+    let discussion: Vec<&str> = context.iter().map(|s| s.text.as_str()).collect();
+    prompt.push_str(&crate::integrations::jira::prompt_section(&discussion.join("\n")));
+    let snippets = crate::codebase::embeddings::retrieve(
+        app,
+        std::path::Path::new(project_path),
+        &discussion.join(" "),
+        CODE_SNIPPETS,
+    );
+    if !snippets.is_empty() {
+        prompt.push_str("\n## Relevant code\n\n");
+        prompt.push_str(&crate::codebase::embeddings::format_snippets(&snippets));
+    }
+
+    match crate::automation::claude_trigger::trigger_agent_prompt(project_path, meeting_id, phrase, &prompt) {
+        Ok(prompt_path) => {
+            events::emit(
+                app,
+                AppEvent::AgentKeywordTriggered {
+                    meeting_id: meeting_id.to_string(),
+                    phrase: phrase.to_string(),
+                    prompt_path,
+                },
+            );
+            log::info!("AUTOMATION keyword '{}' fired for meeting {}", phrase, meeting_id);
+        }
+        Err(e) => log::warn!("AUTOMATION keyword '{}' trigger failed: {}", phrase, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn phrases() -> Vec<String> {
+        vec!["let's build that".to_string(), "make a ticket".to_string()]
+    }
+
+    #[test]
+    fn test_find_trigger_phrase_ignores_case_and_punctuation() {
+        let p = phrases();
+        assert_eq!(find_trigger_phrase("OK, Let’s build that!", &p), Some("let's build that"));
+        assert_eq!(find_trigger_phrase("can you make a ticket for it", &p), Some("make a ticket"));
+    }
+
+    #[test]
+    fn test_find_trigger_phrase_requires_whole_words() {
+        let p = phrases();
+        assert_eq!(find_trigger_phrase("we should remake a tickets page", &p), None);
+        assert_eq!(find_trigger_phrase("", &p), None);
+    }
+
+    #[test]
+    fn test_claim_phrase_enforces_cooldown_and_prunes() {
+        let mut fired = HashMap::new();
+        let now = Instant::now();
+        let key = |m: &str| (m.to_string(), "make a ticket".to_string());
+        assert!(claim_phrase(&mut fired, key("m-1"), now));
+        assert!(!claim_phrase(&mut fired, key("m-1"), now + Duration::from_secs(5)));
+        assert!(claim_phrase(&mut fired, key("m-2"), now + Duration::from_secs(10)));
+
+        // Once the cooldown passes the old meeting's entry is dropped
+        let later = now + PHRASE_COOLDOWN + Duration::from_secs(1);
+        assert!(claim_phrase(&mut fired, key("m-3"), later));
+        assert!(!fired.contains_key(&key("m-1")));
+        assert_eq!(fired.len(), 2);
+    }
+
+    #[test]
+    fn test_build_trigger_prompt_includes_context() {
+        let seg = TranscriptSegment {
+            speaker: "Speaker 1".to_string(),
+            start_time: 12.0,
+            end_time: 20.0,
+            text: "Add CSV export. Let's build that.".to_string(),
+            confidence: 0.9,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        };
+        let prompt = build_trigger_prompt("let's build that", &[seg]);
+        assert!(prompt.contains("Speaker 1: Add CSV export."));
+        assert!(prompt.contains("\"let's build that\""));
+    }
+}
@@ -1,3 +1,8 @@
+pub mod agent_logs;
 pub mod claude_trigger;
+pub mod command_suggestions;
 pub mod github_workflow;
-
+pub mod guardrails;
+pub mod keyword_trigger;
+pub mod prd_agent;
+pub mod repo_config;
@@ -0,0 +1,293 @@
+use crate::codebase::deps::related_files;
+use crate::codebase::routes::{match_routes, ApiRoute};
+use crate::codebase::symbols::symbol_outline;
+use crate::codebase::CodebaseManifest;
+use crate::document_generation::{AcceptanceCriterion, PRDContent};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Common words too generic to match against symbol or file names
+const STOP_WORDS: &[&str] = &[
+    "able", "about", "also", "been", "each", "from", "have", "into", "more", "should", "that",
+    "their", "them", "then", "they", "this", "want", "what", "when", "will", "with",
+];
+
+/// Files whose exported symbols or names match words in `item_text`, plus their direct
+/// importers and imports from the dependency graph
+fn affected_files(manifest: &CodebaseManifest, item_text: &str) -> Vec<PathBuf> {
+    let words: Vec<String> = item_text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() >= 4)
+        .map(|w| w.to_lowercase())
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+    let mentions = |name: &str| {
+        let name = name.to_lowercase();
+        words.iter().any(|w| name.contains(w.as_str()))
+    };
+
+    let mut seeds: Vec<PathBuf> = manifest
+        .symbols
+        .iter()
+        .filter(|f| {
+            f.path.file_stem().map(|s| mentions(&s.to_string_lossy())).unwrap_or(false)
+                || f.symbols.iter().any(|s| mentions(&s.name))
+        })
+        .map(|f| f.path.clone())
+        .collect();
+    seeds.sort();
+
+    let related = related_files(&manifest.dependency_graph, &seeds);
+    seeds.extend(related);
+    seeds
+}
+
+/// Existing endpoints whose paths match each user story and requirement, keyed by item id
+pub fn map_items_to_routes(
+    content: &PRDContent,
+    routes: &[ApiRoute],
+) -> BTreeMap<String, Vec<ApiRoute>> {
+    let items = content
+        .user_stories
+        .iter()
+        .map(|s| (s.id.clone(), format!("{} {}", s.want, s.so_that)))
+        .chain(
+            content
+                .functional_requirements
+                .iter()
+                .chain(content.non_functional_requirements.iter())
+                .map(|r| (r.id.clone(), format!("{} {}", r.title, r.description))),
+        )
+        .chain(
+            content
+                .technical_requirements
+                .iter()
+                .map(|t| (t.id.clone(), format!("{} {}", t.title, t.description))),
+        );
+
+    items
+        .filter_map(|(id, text)| {
+            let matched: Vec<ApiRoute> = match_routes(routes, &text)
+                .into_iter()
+                .take(10)
+                .cloned()
+                .collect();
+            (!matched.is_empty()).then_some((id, matched))
+        })
+        .collect()
+}
+
+/// Compose a focused agent prompt for a single PRD user story or requirement
+pub fn compose_item_prompt(
+    meeting_name: &str,
+    content: &PRDContent,
+    item_id: &str,
+    manifest: Option<&CodebaseManifest>,
+) -> Result<String> {
+    let mut prompt = String::new();
+    let item_text: String;
+
+    if let Some(story) = content.user_stories.iter().find(|s| s.id == item_id) {
+        let _ = writeln!(prompt, "# Implement user story {}\n", story.id);
+        let _ = writeln!(
+            prompt,
+            "As a {}, I want to {}, so that {}.\n",
+            story.persona, story.want, story.so_that
+        );
+        let _ = writeln!(prompt, "**Priority:** {}\n", story.priority);
+        item_text = format!("{} {}", story.want, story.so_that);
+    } else if let Some(req) = content
+        .functional_requirements
+        .iter()
+        .chain(content.non_functional_requirements.iter())
+        .find(|r| r.id == item_id)
+    {
+        let _ = writeln!(prompt, "# Implement requirement {}: {}\n", req.id, req.title);
+        let _ = writeln!(prompt, "{}\n", req.description);
+        let _ = writeln!(prompt, "**Priority:** {}", req.priority);
+        item_text = format!("{} {}", req.title, req.description);
+        if let Some(ref category) = req.category {
+            let _ = writeln!(prompt, "**Category:** {}", category);
+        }
+        let _ = writeln!(prompt);
+    } else if let Some(tech) = content.technical_requirements.iter().find(|t| t.id == item_id) {
+        let _ = writeln!(prompt, "# Implement technical requirement {}: {}\n", tech.id, tech.title);
+        let _ = writeln!(prompt, "{}\n", tech.description);
+        if !tech.rationale.is_empty() {
+            let _ = writeln!(prompt, "**Rationale:** {}\n", tech.rationale);
+        }
+        item_text = format!("{} {}", tech.title, tech.description);
+    } else {
+        return Err(anyhow!("PRD item not found: {}", item_id));
+    }
+
+    let criteria: Vec<&AcceptanceCriterion> = content
+        .acceptance_criteria
+        .iter()
+        .filter(|c| c.requirement_id == item_id)
+        .collect();
+    if !criteria.is_empty() {
+        let _ = writeln!(prompt, "## Acceptance criteria\n");
+        for c in criteria {
+            let _ = writeln!(prompt, "- [ ] {}", c.description);
+        }
+        let _ = writeln!(prompt);
+    }
+
+    if let Some(m) = manifest {
+        let _ = writeln!(prompt, "## Codebase context\n");
+        if let Some(ref fw) = m.framework {
+            let _ = writeln!(prompt, "- Framework: {}", fw);
+        }
+        if !m.languages.is_empty() {
+            let _ = writeln!(prompt, "- Languages: {}", m.languages.join(", "));
+        }
+        for entry in &m.entry_points {
+            let rel = entry.strip_prefix(&m.root_path).unwrap_or(entry);
+            let _ = writeln!(prompt, "- Entry point: {}", rel.display());
+        }
+        let mut dirs: Vec<&String> = m.key_directories.keys().collect();
+        dirs.sort();
+        if !dirs.is_empty() {
+            let dirs: Vec<&str> = dirs.iter().map(|d| d.as_str()).collect();
+            let _ = writeln!(prompt, "- Key directories: {}", dirs.join(", "));
+        }
+        if !m.symbols.is_empty() {
+            let _ = writeln!(prompt, "\n### Exported symbols\n");
+            prompt.push_str(&symbol_outline(&m.symbols, 40));
+        }
+        let endpoints = match_routes(&m.routes, &item_text);
+        if !endpoints.is_empty() {
+            let _ = writeln!(prompt, "\n### Existing endpoints\n");
+            for route in endpoints.iter().take(10) {
+                let _ = writeln!(
+                    prompt,
+                    "- {} {} ({})",
+                    route.method,
+                    route.path,
+                    route.file.display()
+                );
+            }
+        }
+        let affected = affected_files(m, &item_text);
+        if !affected.is_empty() {
+            let _ = writeln!(prompt, "\n### Likely affected files\n");
+            for path in affected.iter().take(20) {
+                if m.test_coverage.is_tested(path) {
+                    let _ = writeln!(prompt, "- {}", path.display());
+                } else {
+                    let _ = writeln!(prompt, "- {} (no tests; add coverage)", path.display());
+                }
+            }
+        }
+        let _ = writeln!(prompt);
+    }
+
+    let _ = writeln!(
+        prompt,
+        "Work only on this item from the \"{}\" PRD. Respect `.claudeignore` and keep new code \
+         under `experiments/` unless the item clearly belongs elsewhere.",
+        meeting_name
+    );
+
+    Ok(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document_generation::{Requirement, UserStory};
+
+    fn sample_content() -> PRDContent {
+        PRDContent {
+            user_stories: vec![UserStory {
+                id: "US-1".to_string(),
+                persona: "user".to_string(),
+                want: "export reports as CSV".to_string(),
+                so_that: "I can share them".to_string(),
+                priority: "high".to_string(),
+                status: "planned".to_string(),
+                mentioned_at: vec![3],
+            }],
+            functional_requirements: vec![Requirement {
+                id: "FR-1".to_string(),
+                title: "CSV export".to_string(),
+                description: "Export any report to CSV".to_string(),
+                priority: "medium".to_string(),
+                status: "discussed".to_string(),
+                category: None,
+                mentioned_at: vec![4],
+            }],
+            acceptance_criteria: vec![AcceptanceCriterion {
+                id: "AC-1".to_string(),
+                requirement_id: "US-1".to_string(),
+                description: "Download button produces a .csv file".to_string(),
+                testable: true,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compose_user_story_prompt_with_criteria() {
+        let prompt = compose_item_prompt("Planning", &sample_content(), "US-1", None).unwrap();
+        assert!(prompt.contains("I want to export reports as CSV"));
+        assert!(prompt.contains("- [ ] Download button produces a .csv file"));
+    }
+
+    #[test]
+    fn test_compose_requirement_prompt() {
+        let prompt = compose_item_prompt("Planning", &sample_content(), "FR-1", None).unwrap();
+        assert!(prompt.contains("requirement FR-1: CSV export"));
+        assert!(!prompt.contains("Acceptance criteria"));
+    }
+
+    #[test]
+    fn test_affected_files_follow_dependency_graph() {
+        use crate::codebase::deps::ModuleImports;
+        use crate::codebase::{CodeSymbol, FileSymbols};
+
+        let manifest = CodebaseManifest {
+            root_path: PathBuf::from("/project"),
+            framework: None,
+            languages: vec![],
+            entry_points: vec![],
+            key_directories: Default::default(),
+            dependencies: Default::default(),
+            total_files: 3,
+            analyzed_at: String::new(),
+            symbols: vec![FileSymbols {
+                path: PathBuf::from("src/lib/reports.ts"),
+                symbols: vec![CodeSymbol { name: "exportReport".to_string(), kind: "function".to_string(), line: 1 }],
+            }],
+            dependency_graph: vec![ModuleImports {
+                path: PathBuf::from("src/ui/ReportsPage.tsx"),
+                imports: vec![PathBuf::from("src/lib/reports.ts")],
+            }],
+            routes: vec![ApiRoute {
+                method: "GET".to_string(),
+                path: "/api/reports/[id]".to_string(),
+                kind: "api".to_string(),
+                file: PathBuf::from("src/app/api/reports/[id]/route.ts"),
+                line: Some(3),
+                handler: None,
+            }],
+            test_coverage: Default::default(),
+        };
+        let prompt = compose_item_prompt("Planning", &sample_content(), "US-1", Some(&manifest)).unwrap();
+        assert!(prompt.contains("- src/lib/reports.ts (no tests; add coverage)"));
+        assert!(prompt.contains("- src/ui/ReportsPage.tsx"));
+        assert!(prompt.contains("- GET /api/reports/[id]"));
+        assert!(map_items_to_routes(&sample_content(), &manifest.routes).contains_key("FR-1"));
+    }
+
+    #[test]
+    fn test_compose_unknown_item_fails() {
+        assert!(compose_item_prompt("Planning", &sample_content(), "US-9", None).is_err());
+    }
+}
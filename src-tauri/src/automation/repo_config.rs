@@ -0,0 +1,280 @@
+//! Per-repo automation overrides from a `.meetingcoder.toml` at the repo root, so a team
+//! can share branch naming, PR behaviour, commit paths, and agent triggers through
+//! version control. The file is read when a meeting binds to the repo; anything it
+//! doesn't set falls back to the app settings.
+//!
+//! ```toml
+//! [github]
+//! branch_pattern = "meeting/{meeting_name}-{meeting_id}"
+//! auto_create_pr = false
+//! commit_paths = [".transcript.jsonl", ".claude/*", "docs/meetings/**"]
+//!
+//! [agent]
+//! trigger_phrases = ["let's build", "ship it"]
+//! allowed_paths = ["src/**", "tests/**"]
+//!
+//! [discord]
+//! prd_versions = false
+//! ```
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::settings::{self, AppSettings};
+
+pub const REPO_CONFIG_FILE: &str = ".meetingcoder.toml";
+/// Meeting artifacts committed when the repo doesn't set `github.commit_paths`
+pub const DEFAULT_COMMIT_PATHS: &[&str] = &[".transcript.jsonl", ".claude/*"];
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GithubOverrides {
+    pub branch_pattern: Option<String>,
+    pub default_branch: Option<String>,
+    pub auto_commit_push: Option<bool>,
+    pub auto_create_pr: Option<bool>,
+    pub auto_update_pr: Option<bool>,
+    /// Pathspecs staged by auto-commit
+    pub commit_paths: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AgentOverrides {
+    pub auto_trigger: Option<bool>,
+    pub auto_accept_changes: Option<bool>,
+    pub min_interval_seconds: Option<u32>,
+    pub max_files_touched: Option<u32>,
+    pub max_loc_changed: Option<u32>,
+    /// Globs the agent may modify before auto-accept is blocked
+    pub allowed_paths: Option<Vec<String>>,
+    pub keyword_triggers: Option<bool>,
+    pub trigger_phrases: Option<Vec<String>>,
+}
+
+/// Which events go to the project's Discord channel
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscordOverrides {
+    pub meeting_ended: Option<bool>,
+    pub prd_versions: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RepoConfig {
+    pub github: GithubOverrides,
+    pub agent: AgentOverrides,
+    pub discord: DiscordOverrides,
+}
+
+impl RepoConfig {
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: RepoConfig = toml::from_str(content)?;
+        if let Some(seconds) = config.agent.min_interval_seconds {
+            if !(30..=600).contains(&seconds) {
+                anyhow::bail!("agent.min_interval_seconds must be between 30 and 600");
+            }
+        }
+        if config
+            .github
+            .commit_paths
+            .as_ref()
+            .is_some_and(|paths| paths.iter().all(|p| p.trim().is_empty()))
+        {
+            anyhow::bail!("github.commit_paths must list at least one path");
+        }
+        Ok(config)
+    }
+
+    /// Apply the overrides to `settings`; returns the names of the settings overridden
+    pub fn apply(&self, settings: &mut AppSettings) -> Vec<&'static str> {
+        let mut applied = Vec::new();
+        macro_rules! set {
+            ($value:expr, $field:ident) => {
+                if let Some(value) = &$value {
+                    settings.$field = value.clone();
+                    applied.push(stringify!($field));
+                }
+            };
+        }
+        let (github, agent) = (&self.github, &self.agent);
+        set!(github.branch_pattern, github_branch_pattern);
+        set!(github.default_branch, github_default_branch);
+        set!(github.auto_commit_push, github_auto_commit_push);
+        set!(github.auto_create_pr, github_auto_create_pr);
+        set!(github.auto_update_pr, github_auto_update_pr);
+        set!(agent.auto_trigger, auto_trigger_meeting_command);
+        set!(agent.auto_accept_changes, auto_accept_changes);
+        set!(
+            agent.min_interval_seconds,
+            auto_trigger_min_interval_seconds
+        );
+        set!(agent.max_files_touched, agent_max_files_touched);
+        set!(agent.max_loc_changed, agent_max_loc_changed);
+        set!(agent.allowed_paths, agent_allowed_path_globs);
+        set!(agent.keyword_triggers, keyword_triggers_enabled);
+        set!(agent.trigger_phrases, agent_trigger_phrases);
+        if let Some(value) = self.discord.meeting_ended {
+            settings.discord.meeting_ended = value;
+            applied.push("discord.meeting_ended");
+        }
+        if let Some(value) = self.discord.prd_versions {
+            settings.discord.prd_versions = value;
+            applied.push("discord.prd_versions");
+        }
+        applied
+    }
+}
+
+/// Configs read for repos meetings are bound to, keyed by repo root
+static CONFIGS: Lazy<Mutex<HashMap<PathBuf, Option<RepoConfig>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn read(repo_root: &Path) -> Result<Option<RepoConfig>> {
+    let path = repo_root.join(REPO_CONFIG_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)?;
+    RepoConfig::parse(&content)
+        .map(Some)
+        .with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// (Re)read the repo's config when a meeting binds to it. An invalid file is logged and
+/// ignored so a typo can't stop a meeting from starting.
+pub fn bind(repo_root: &str) -> Option<RepoConfig> {
+    let root = PathBuf::from(repo_root);
+    let config = match read(&root) {
+        Ok(Some(config)) => {
+            let overridden = config.apply(&mut settings::get_default_settings());
+            log::info!(
+                "REPO_CONFIG {} overrides: {}",
+                root.join(REPO_CONFIG_FILE).display(),
+                overridden.join(", ")
+            );
+            Some(config)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            log::warn!("REPO_CONFIG ignored: {:#}", e);
+            None
+        }
+    };
+    CONFIGS.lock().unwrap().insert(root, config.clone());
+    config
+}
+
+fn config_for(repo_root: &str) -> Option<RepoConfig> {
+    let cached = CONFIGS.lock().unwrap().get(Path::new(repo_root)).cloned();
+    match cached {
+        Some(config) => config,
+        None => bind(repo_root),
+    }
+}
+
+/// `(owner, name)` of a clone made by `github::ensure_local_repo_clone`, which lives at
+/// `<repos>/<owner>/<name>`
+fn cloned_repo(repos_dir: &Path, repo_root: &Path) -> Option<(String, String)> {
+    let relative = repo_root.strip_prefix(repos_dir).ok()?;
+    let parts: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    match parts.as_slice() {
+        [owner, name] => Some((owner.clone(), name.clone())),
+        _ => None,
+    }
+}
+
+/// App settings with the repo's overrides applied. A meeting in one of the app's clones
+/// targets that clone's repo, which differs from the configured one when a
+/// `meetingcoder://start?repo=` link picked it.
+pub fn effective_settings(app: &AppHandle, repo_root: &str) -> AppSettings {
+    let mut settings = settings::get_settings(app);
+    if let Some(config) = config_for(repo_root) {
+        config.apply(&mut settings);
+    }
+    let cloned = crate::storage::root::storage_dir("repos")
+        .ok()
+        .and_then(|repos| cloned_repo(&repos, Path::new(repo_root)));
+    if let Some((owner, name)) = cloned {
+        settings.github_repo_owner = Some(owner);
+        settings.github_repo_name = Some(name);
+    }
+    settings
+}
+
+/// Pathspecs auto-commit stages in this repo
+pub fn commit_paths(repo_root: &str) -> Vec<String> {
+    config_for(repo_root)
+        .and_then(|c| c.github.commit_paths)
+        .map(|paths| {
+            paths
+                .into_iter()
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect()
+        })
+        .unwrap_or_else(|| DEFAULT_COMMIT_PATHS.iter().map(|p| p.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_config_overrides() {
+        let config = RepoConfig::parse(
+            r#"
+            [github]
+            branch_pattern = "team/{meeting_name}"
+            auto_create_pr = false
+
+            [agent]
+            trigger_phrases = ["ship it"]
+            max_files_touched = 3
+
+            [discord]
+            prd_versions = false
+            "#,
+        )
+        .unwrap();
+        let mut settings = settings::get_default_settings();
+        let applied = config.apply(&mut settings);
+        assert_eq!(settings.github_branch_pattern, "team/{meeting_name}");
+        assert!(!settings.github_auto_create_pr);
+        assert_eq!(settings.agent_trigger_phrases, vec!["ship it".to_string()]);
+        assert_eq!(settings.agent_max_files_touched, 3);
+        assert!(!settings.discord.prd_versions);
+        assert!(settings.discord.meeting_ended);
+        assert_eq!(applied.len(), 5);
+        assert_eq!(
+            settings.github_default_branch,
+            settings::get_default_settings().github_default_branch
+        );
+
+        assert!(RepoConfig::parse("[github]\nbranch = \"x\"").is_err());
+        assert!(RepoConfig::parse("[agent]\nmin_interval_seconds = 5").is_err());
+        assert!(RepoConfig::parse("[github]\ncommit_paths = []").is_err());
+        assert_eq!(RepoConfig::parse("").unwrap(), RepoConfig::default());
+    }
+
+    #[test]
+    fn test_cloned_repo() {
+        let repos = Path::new("/data/repos");
+        assert_eq!(
+            cloned_repo(repos, Path::new("/data/repos/org/app")),
+            Some(("org".to_string(), "app".to_string()))
+        );
+        assert_eq!(cloned_repo(repos, Path::new("/data/repos/org")), None);
+        assert_eq!(cloned_repo(repos, Path::new("/data/repos/org/app/sub")), None);
+        assert_eq!(cloned_repo(repos, Path::new("/work/app")), None);
+    }
+}
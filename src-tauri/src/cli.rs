@@ -0,0 +1,264 @@
+//! Command-line flags for running a meeting or import without the main window,
+//! e.g. `meetingcoder --start-meeting "Sprint Planning" --source system --end-after 60m`
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::events::{self, AppEvent};
+use crate::managers::audio::{AudioRecordingManager, AudioSource};
+use crate::managers::meeting::MeetingManager;
+use crate::system_audio::{SystemAudioCapture, SystemAudioCaptureDevice};
+
+pub const USAGE: &str = "Usage: meetingcoder [OPTIONS]
+
+Options:
+  --start-meeting <NAME>   Start recording a meeting without showing the window
+  --source <SOURCE>        mic, system, system:<device>, both, or both:<device>
+                           (default: current source)
+  --end-after <DURATION>   End the meeting after e.g. 90s, 60m, 1h (bare numbers are minutes)
+  --import <FILE>          Import an audio file as a meeting, then quit
+  -h, --help               Print this help";
+
+/// Audio source requested with `--source`
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliSource {
+    Microphone,
+    /// `None` uses the detected virtual device (BlackHole etc.)
+    SystemAudio(Option<String>),
+    /// Microphone plus system audio, labelled "Me" and "Remote"
+    Dual(Option<String>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CliArgs {
+    pub start_meeting: Option<String>,
+    pub source: Option<CliSource>,
+    pub end_after: Option<Duration>,
+    pub import: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl CliArgs {
+    /// Parse arguments after the program name. Unknown flags are rejected so a typo
+    /// doesn't silently open the normal UI.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut parsed = CliArgs::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{} needs a value", name))
+            };
+            match flag.as_str() {
+                "--start-meeting" => parsed.start_meeting = Some(value("--start-meeting")?),
+                "--source" => parsed.source = Some(parse_source(&value("--source")?)?),
+                "--end-after" => parsed.end_after = Some(parse_duration(&value("--end-after")?)?),
+                "--import" => parsed.import = Some(PathBuf::from(value("--import")?)),
+                "-h" | "--help" => parsed.help = true,
+                // Handled by the deep-link plugin
+                s if s.starts_with(&format!("{}://", crate::deep_link::SCHEME)) => {}
+                // Added by macOS when launched from Finder
+                s if s.starts_with("-psn_") => {}
+                other => return Err(format!("Unknown argument '{}'", other)),
+            }
+        }
+        if parsed.start_meeting.is_some() && parsed.import.is_some() {
+            return Err("--start-meeting and --import can't be combined".to_string());
+        }
+        if parsed.start_meeting.is_none() && (parsed.source.is_some() || parsed.end_after.is_some())
+        {
+            return Err("--source and --end-after require --start-meeting".to_string());
+        }
+        Ok(parsed)
+    }
+
+    /// Whether the app should stay out of sight and run this task
+    pub fn is_headless(&self) -> bool {
+        self.start_meeting.is_some() || self.import.is_some()
+    }
+}
+
+fn parse_source(value: &str) -> Result<CliSource, String> {
+    match value {
+        "mic" | "microphone" => Ok(CliSource::Microphone),
+        "system" => Ok(CliSource::SystemAudio(None)),
+        "both" => Ok(CliSource::Dual(None)),
+        _ => match value.split_once(':') {
+            Some(("system", device)) if !device.trim().is_empty() => {
+                Ok(CliSource::SystemAudio(Some(device.trim().to_string())))
+            }
+            Some(("both", device)) if !device.trim().is_empty() => {
+                Ok(CliSource::Dual(Some(device.trim().to_string())))
+            }
+            _ => Err(format!(
+                "Invalid source '{}': expected mic, system, system:<device>, both, or \
+                 both:<device>",
+                value
+            )),
+        },
+    }
+}
+
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let (number, unit_secs) = match value.char_indices().last() {
+        Some((i, 's')) => (&value[..i], 1),
+        Some((i, 'm')) => (&value[..i], 60),
+        Some((i, 'h')) => (&value[..i], 3600),
+        _ => (value, 60),
+    };
+    match number.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(Duration::from_secs(n * unit_secs)),
+        _ => Err(format!(
+            "Invalid duration '{}': expected e.g. 90s, 60m, 1h",
+            value
+        )),
+    }
+}
+
+fn emit_meeting_state(app: &AppHandle, meeting_id: &str, status: &str) {
+    events::emit(app, AppEvent::meeting_state(meeting_id, status, "cli"));
+}
+
+fn detect_system_device() -> Result<String, String> {
+    let device = SystemAudioCapture::new()
+        .and_then(|capture| capture.detect_virtual_device())
+        .map_err(|e| format!("Failed to detect system audio device: {}", e))?
+        .ok_or_else(|| "No system audio device found; pass --source system:<device>".to_string())?;
+    Ok(device.name)
+}
+
+fn apply_source(app: &AppHandle, source: &CliSource) -> Result<(), String> {
+    let audio_source = match source {
+        CliSource::Microphone => AudioSource::Microphone,
+        CliSource::SystemAudio(Some(device)) => AudioSource::SystemAudio(device.clone()),
+        CliSource::SystemAudio(None) => AudioSource::SystemAudio(detect_system_device()?),
+        CliSource::Dual(Some(device)) => AudioSource::Dual(device.clone()),
+        CliSource::Dual(None) => AudioSource::Dual(detect_system_device()?),
+    };
+    app.state::<Arc<AudioRecordingManager>>()
+        .set_audio_source(audio_source)
+        .map_err(|e| format!("Failed to set audio source: {}", e))
+}
+
+async fn run_meeting(app: &AppHandle, args: &CliArgs, name: &str) -> Result<bool, String> {
+    if let Some(source) = &args.source {
+        apply_source(app, source)?;
+    }
+    let mm = app.state::<Arc<MeetingManager>>().inner().clone();
+    let meeting_id = mm
+        .start_meeting(name.to_string())
+        .await
+        .map_err(|e| format!("Failed to start meeting: {}", e))?;
+    emit_meeting_state(app, &meeting_id, "recording");
+    log::info!("CLI started meeting '{}' ({})", name, meeting_id);
+
+    // Without --end-after the meeting runs until stopped from the tray, a shortcut,
+    // or the local API, and the app keeps running afterwards
+    let Some(end_after) = args.end_after else {
+        return Ok(false);
+    };
+    tokio::time::sleep(end_after).await;
+    if mm.get_meeting(&meeting_id).await.is_err() {
+        log::info!("CLI meeting {} was already ended", meeting_id);
+        return Ok(true);
+    }
+    mm.end_meeting(&meeting_id)
+        .await
+        .map_err(|e| format!("Failed to end meeting: {}", e))?;
+    emit_meeting_state(app, &meeting_id, "completed");
+    log::info!("CLI ended meeting {} after {:?}", meeting_id, end_after);
+    Ok(true)
+}
+
+async fn run_import(app: &AppHandle, path: &PathBuf) -> Result<bool, String> {
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(crate::managers::meeting::default_meeting_name);
+    let summary = crate::commands::import::import_audio_from_path_as_meeting(
+        app.clone(),
+        name,
+        path.to_string_lossy().to_string(),
+        "import:cli",
+        app.state(),
+        app.state(),
+        app.state(),
+    )
+    .await?;
+    log::info!("CLI imported {:?} as meeting {}", path, summary.meeting_id);
+    Ok(true)
+}
+
+/// Run the task the flags describe; quits the app when the task has a natural end
+pub async fn run(app: AppHandle, args: CliArgs) {
+    let result = match (&args.start_meeting, &args.import) {
+        (Some(name), _) => run_meeting(&app, &args, name).await,
+        (None, Some(path)) => run_import(&app, path).await,
+        (None, None) => return,
+    };
+    match result {
+        Ok(true) => app.exit(0),
+        Ok(false) => {}
+        Err(e) => {
+            log::error!("CLI task failed: {}", e);
+            eprintln!("meetingcoder: {}", e);
+            app.exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        CliArgs::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_cli_args() {
+        let args = parse(&[
+            "--start-meeting",
+            "Sprint Planning",
+            "--source",
+            "system",
+            "--end-after=60m",
+        ])
+        .unwrap();
+        assert_eq!(args.start_meeting.as_deref(), Some("Sprint Planning"));
+        assert_eq!(args.source, Some(CliSource::SystemAudio(None)));
+        assert_eq!(args.end_after, Some(Duration::from_secs(3600)));
+        assert!(args.is_headless());
+
+        let args = parse(&["--import", "file.mp3"]).unwrap();
+        assert_eq!(args.import, Some(PathBuf::from("file.mp3")));
+        assert!(!parse(&[]).unwrap().is_headless());
+
+        assert_eq!(
+            parse_source("system:BlackHole 2ch"),
+            Ok(CliSource::SystemAudio(Some("BlackHole 2ch".to_string())))
+        );
+        assert_eq!(
+            parse_source("both:BlackHole 2ch"),
+            Ok(CliSource::Dual(Some("BlackHole 2ch".to_string())))
+        );
+        assert!(parse_source("both:").is_err());
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("15"), Ok(Duration::from_secs(900)));
+        assert!(parse_duration("0m").is_err());
+        assert!(parse(&["--end-after", "5m"]).is_err());
+        assert!(parse(&["--start-meeting"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+}
@@ -3,7 +3,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use super::coverage::{self, TestCoverage};
+use super::deps::{self, ModuleImports};
+use super::routes::{self, ApiRoute};
+use super::symbols::{self, FileSymbols};
+use super::walk::project_walker;
 
 /// Represents a project's codebase structure and metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +20,61 @@ pub struct CodebaseManifest {
     pub dependencies: HashMap<String, String>,
     pub total_files: usize,
     pub analyzed_at: String,
+    /// Exported functions, types, and routes per source file
+    #[serde(default)]
+    pub symbols: Vec<FileSymbols>,
+    /// Project-local imports per source file
+    #[serde(default)]
+    pub dependency_graph: Vec<ModuleImports>,
+    /// HTTP endpoints and pages declared by the project
+    #[serde(default)]
+    pub routes: Vec<ApiRoute>,
+    /// Test files, the sources they cover, and ingested coverage reports
+    #[serde(default)]
+    pub test_coverage: TestCoverage,
+}
+
+impl CodebaseManifest {
+    /// Short textual description of the codebase for LLM prompts
+    pub fn context_summary(&self, max_symbol_files: usize) -> String {
+        let mut summary = String::new();
+        if let Some(ref fw) = self.framework {
+            summary.push_str(&format!("Framework: {}\n", fw));
+        }
+        if !self.languages.is_empty() {
+            summary.push_str(&format!("Languages: {}\n", self.languages.join(", ")));
+        }
+        summary.push_str(&format!("Source files: {}\n", self.total_files));
+        if !self.symbols.is_empty() {
+            summary.push_str("Exported symbols:\n");
+            summary.push_str(&symbols::symbol_outline(&self.symbols, max_symbol_files));
+        }
+        if !self.routes.is_empty() {
+            summary.push_str("HTTP routes:\n");
+            for route in self.routes.iter().take(40) {
+                summary.push_str(&format!(
+                    "- {} {} ({})\n",
+                    route.method,
+                    route.path,
+                    route.file.display()
+                ));
+            }
+        }
+        if !self.test_coverage.untested_areas.is_empty() {
+            summary.push_str(&format!(
+                "Areas without tests: {}\n",
+                self.test_coverage.untested_areas.join(", ")
+            ));
+        }
+        let edges = deps::area_edges(&self.dependency_graph);
+        if !edges.is_empty() {
+            summary.push_str("Module dependencies (area -> area, imports):\n");
+            for (from, to, count) in edges.iter().take(20) {
+                summary.push_str(&format!("- {} -> {} ({})\n", from, to, count));
+            }
+        }
+        summary
+    }
 }
 
 /// Framework detection result
@@ -26,8 +85,9 @@ pub struct FrameworkInfo {
     pub indicators: Vec<String>,
 }
 
-/// Analyzes a codebase and generates a comprehensive manifest
-pub async fn analyze_codebase(project_path: &Path) -> Result<CodebaseManifest> {
+/// Analyzes a codebase and generates a comprehensive manifest.
+/// Files excluded by `.gitignore`/`.git/info/exclude` or `ignore_patterns` are skipped.
+pub async fn analyze_codebase(project_path: &Path, ignore_patterns: &[String]) -> Result<CodebaseManifest> {
     log::info!("Starting codebase analysis for: {:?}", project_path);
 
     // Detect framework
@@ -35,7 +95,7 @@ pub async fn analyze_codebase(project_path: &Path) -> Result<CodebaseManifest> {
     log::info!("Detected framework: {:?}", framework);
 
     // Detect languages
-    let languages = detect_languages(project_path).await?;
+    let languages = detect_languages(project_path, ignore_patterns).await?;
     log::info!("Detected languages: {:?}", languages);
 
     // Find entry points
@@ -51,9 +111,29 @@ pub async fn analyze_codebase(project_path: &Path) -> Result<CodebaseManifest> {
     log::info!("Found {} dependencies", dependencies.len());
 
     // Count total files (excluding common ignore patterns)
-    let total_files = count_source_files(project_path).await?;
+    let total_files = count_source_files(project_path, ignore_patterns).await?;
     log::info!("Total source files: {}", total_files);
 
+    // Extract exported symbols, routes, and imports (tree-sitter parsing is CPU-bound)
+    let parse_root = project_path.to_path_buf();
+    let parse_ignores = ignore_patterns.to_vec();
+    let (symbols, dependency_graph, routes, test_coverage) = tokio::task::spawn_blocking(move || {
+        let symbols = symbols::extract_project_symbols(&parse_root, &parse_ignores);
+        let routes = routes::collect_routes(&parse_root, &parse_ignores, &symbols);
+        let graph = deps::build_dependency_graph(&parse_root, &parse_ignores);
+        let test_coverage = coverage::analyze_test_coverage(&parse_root, &parse_ignores, &graph);
+        (symbols, graph, routes, test_coverage)
+    })
+    .await
+    .context("Source parsing task failed")?;
+    log::info!(
+        "Extracted symbols from {} files, imports from {} files, {} routes, {} test files",
+        symbols.len(),
+        dependency_graph.len(),
+        routes.len(),
+        test_coverage.test_files.len()
+    );
+
     let manifest = CodebaseManifest {
         root_path: project_path.to_path_buf(),
         framework,
@@ -63,6 +143,10 @@ pub async fn analyze_codebase(project_path: &Path) -> Result<CodebaseManifest> {
         dependencies,
         total_files,
         analyzed_at: chrono::Utc::now().to_rfc3339(),
+        symbols,
+        dependency_graph,
+        routes,
+        test_coverage,
     };
 
     Ok(manifest)
@@ -182,16 +266,12 @@ async fn detect_framework(project_path: &Path) -> Result<Option<String>> {
 }
 
 /// Detects programming languages used in the project
-async fn detect_languages(project_path: &Path) -> Result<Vec<String>> {
+async fn detect_languages(project_path: &Path, ignore_patterns: &[String]) -> Result<Vec<String>> {
     let mut languages = Vec::new();
     let mut extensions_found = std::collections::HashSet::new();
 
-    for entry in WalkDir::new(project_path)
-        .max_depth(3)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        if entry.file_type().is_file() {
+    for entry in project_walker(project_path, ignore_patterns, Some(3)).filter_map(|e| e.ok()) {
+        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
             if let Some(ext) = entry.path().extension() {
                 extensions_found.insert(ext.to_string_lossy().to_string());
             }
@@ -462,33 +542,11 @@ async fn extract_dependencies(
     Ok(dependencies)
 }
 
-/// Counts source files, excluding common ignore patterns
-async fn count_source_files(project_path: &Path) -> Result<usize> {
-    let ignore_patterns = vec![
-        "node_modules",
-        "target",
-        "dist",
-        "build",
-        ".git",
-        ".next",
-        ".vercel",
-        "__pycache__",
-        ".pytest_cache",
-        "venv",
-        ".venv",
-    ];
-
+/// Counts source files, honoring ignore files and configured patterns
+async fn count_source_files(project_path: &Path, ignore_patterns: &[String]) -> Result<usize> {
     let mut count = 0;
-    for entry in WalkDir::new(project_path)
-        .into_iter()
-        .filter_entry(|e| {
-            !ignore_patterns
-                .iter()
-                .any(|pattern| e.path().to_string_lossy().contains(pattern))
-        })
-        .filter_map(|e| e.ok())
-    {
-        if entry.file_type().is_file() {
+    for entry in project_walker(project_path, ignore_patterns, None).filter_map(|e| e.ok()) {
+        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
             if let Some(ext) = entry.path().extension() {
                 let ext_str = ext.to_string_lossy();
                 if matches!(
@@ -545,9 +603,20 @@ pub async fn save_manifest_to_state(
     Ok(())
 }
 
+/// Loads the manifest previously saved to .claude/.meeting-state.json, if any
+pub fn load_manifest_from_state(project_path: &Path) -> Option<CodebaseManifest> {
+    let state_file = project_path.join(".claude").join(".meeting-state.json");
+    let content = fs::read_to_string(state_file).ok()?;
+    let state: serde_json::Value = serde_json::from_str(&content).ok()?;
+    serde_json::from_value(state.get("codebase_manifest")?.clone()).ok()
+}
+
 /// Convenience function: analyze and save in one call
-pub async fn analyze_and_save_codebase(project_path: &Path) -> Result<CodebaseManifest> {
-    let manifest = analyze_codebase(project_path).await?;
+pub async fn analyze_and_save_codebase(
+    project_path: &Path,
+    ignore_patterns: &[String],
+) -> Result<CodebaseManifest> {
+    let manifest = analyze_codebase(project_path, ignore_patterns).await?;
     save_manifest_to_state(project_path, &manifest).await?;
     Ok(manifest)
 }
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::deps::{area_of, ModuleImports};
+use super::symbols::language_for;
+use super::walk::project_walker;
+
+/// Skip files larger than this when looking for inline test modules
+const MAX_FILE_BYTES: u64 = 256 * 1024;
+
+/// Directory names whose contents are treated as tests
+const TEST_DIRS: &[&str] = &["tests", "test", "__tests__", "spec", "e2e"];
+
+/// Coverage reports looked for at the project root, in order of preference
+const COVERAGE_REPORTS: &[&str] = &[
+    "coverage/lcov.info",
+    "lcov.info",
+    "coverage/coverage-summary.json",
+    "coverage/cobertura-coverage.xml",
+    "coverage.xml",
+];
+
+/// A test file and the source files it exercises
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestFile {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    /// Source files covered, by naming convention, imports, or inline test modules
+    pub covers: Vec<PathBuf>,
+}
+
+/// Which parts of the project have tests and, when a report exists, their line coverage
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestCoverage {
+    pub test_files: Vec<TestFile>,
+    /// Coverage report that `line_coverage` was read from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<PathBuf>,
+    /// Percentage of lines covered per source file (0-100)
+    #[serde(default)]
+    pub line_coverage: BTreeMap<PathBuf, f64>,
+    /// Areas (see `deps::area_of`) containing no tested source file
+    #[serde(default)]
+    pub untested_areas: Vec<String>,
+}
+
+impl TestCoverage {
+    /// Whether any test maps to `path`, or the coverage report shows it executed
+    pub fn is_tested(&self, path: &Path) -> bool {
+        self.test_files.iter().any(|t| t.covers.iter().any(|c| c == path))
+            || self.line_coverage.get(path).map(|pct| *pct > 0.0).unwrap_or(false)
+    }
+}
+
+/// Whether a path looks like a test file (`foo.test.ts`, `test_foo.py`, `tests/…`)
+pub fn is_test_file(rel: &Path) -> bool {
+    let in_test_dir = rel
+        .parent()
+        .map(|p| {
+            p.components()
+                .any(|c| TEST_DIRS.contains(&c.as_os_str().to_string_lossy().as_ref()))
+        })
+        .unwrap_or(false);
+    in_test_dir || subject_stem(rel).is_some()
+}
+
+/// Stem of the module a test file is named after: `foo.test.ts`, `foo.spec.js`,
+/// `test_foo.py`, `foo_test.py`, and `foo_test.rs` all map to `foo`
+fn subject_stem(rel: &Path) -> Option<String> {
+    let name = rel.file_name()?.to_str()?;
+    let stem = name.split('.').next()?;
+    if name.contains(".test.") || name.contains(".spec.") {
+        return Some(stem.to_string());
+    }
+    stem.strip_prefix("test_")
+        .or_else(|| stem.strip_suffix("_test"))
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// Map test files to the source files they cover
+pub fn map_tests(
+    project_path: &Path,
+    ignore_patterns: &[String],
+    graph: &[ModuleImports],
+) -> (Vec<TestFile>, Vec<PathBuf>) {
+    let files: Vec<PathBuf> = project_walker(project_path, ignore_patterns, None)
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+        .filter(|e| language_for(e.path()).is_some())
+        .filter_map(|e| e.path().strip_prefix(project_path).ok().map(|p| p.to_path_buf()))
+        .collect();
+    let (tests, sources): (Vec<PathBuf>, Vec<PathBuf>) = files.into_iter().partition(|p| is_test_file(p));
+
+    let mut by_stem: HashMap<String, Vec<&PathBuf>> = HashMap::new();
+    for source in &sources {
+        if let Some(stem) = source.file_stem().and_then(|s| s.to_str()) {
+            by_stem.entry(stem.to_string()).or_default().push(source);
+        }
+    }
+    let imports: HashMap<&PathBuf, &Vec<PathBuf>> = graph.iter().map(|m| (&m.path, &m.imports)).collect();
+
+    let mut test_files: Vec<TestFile> = tests
+        .iter()
+        .map(|test| {
+            let mut covers: BTreeSet<PathBuf> = BTreeSet::new();
+            if let Some(candidates) = subject_stem(test).and_then(|stem| by_stem.get(&stem)) {
+                // Prefer a namesake next to the test; fall back to any namesake in the project
+                let siblings: Vec<&&PathBuf> = candidates
+                    .iter()
+                    .filter(|c| c.parent() == test.parent())
+                    .collect();
+                if siblings.is_empty() {
+                    covers.extend(candidates.iter().map(|c| c.to_path_buf()));
+                } else {
+                    covers.extend(siblings.into_iter().map(|c| c.to_path_buf()));
+                }
+            }
+            if let Some(imported) = imports.get(test) {
+                covers.extend(imported.iter().filter(|p| !is_test_file(p)).cloned());
+            }
+            TestFile {
+                path: test.clone(),
+                covers: covers.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    // Rust keeps unit tests inline in `#[cfg(test)]` modules
+    for source in sources.iter().filter(|p| p.extension().map(|e| e == "rs").unwrap_or(false)) {
+        let full = project_path.join(source);
+        if fs::metadata(&full).map(|m| m.len() > MAX_FILE_BYTES).unwrap_or(true) {
+            continue;
+        }
+        if fs::read_to_string(&full).map(|s| s.contains("#[cfg(test)]")).unwrap_or(false) {
+            test_files.push(TestFile {
+                path: source.clone(),
+                covers: vec![source.clone()],
+            });
+        }
+    }
+
+    test_files.sort_by(|a, b| a.path.cmp(&b.path));
+    (test_files, sources)
+}
+
+/// Make a report path relative to the project root
+fn relative_report_path(project_path: &Path, file: &str) -> PathBuf {
+    let path = Path::new(file.trim());
+    path.strip_prefix(project_path)
+        .unwrap_or(path)
+        .to_path_buf()
+}
+
+/// Parse an lcov tracefile (`SF:`, `LF:`, `LH:` records)
+pub fn parse_lcov(project_path: &Path, content: &str) -> BTreeMap<PathBuf, f64> {
+    let mut result = BTreeMap::new();
+    let (mut file, mut found, mut hit) = (None, 0u64, 0u64);
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("SF:") {
+            file = Some(relative_report_path(project_path, path));
+            found = 0;
+            hit = 0;
+        } else if let Some(n) = line.strip_prefix("LF:") {
+            found = n.parse().unwrap_or(0);
+        } else if let Some(n) = line.strip_prefix("LH:") {
+            hit = n.parse().unwrap_or(0);
+        } else if line == "end_of_record" {
+            if let Some(path) = file.take() {
+                let pct = if found == 0 { 0.0 } else { hit as f64 * 100.0 / found as f64 };
+                result.insert(path, pct);
+            }
+        }
+    }
+    result
+}
+
+/// Parse an Istanbul `coverage-summary.json`
+fn parse_istanbul_summary(project_path: &Path, content: &str) -> BTreeMap<PathBuf, f64> {
+    let Ok(serde_json::Value::Object(files)) = serde_json::from_str::<serde_json::Value>(content) else {
+        return BTreeMap::new();
+    };
+    files
+        .iter()
+        .filter(|(name, _)| name.as_str() != "total")
+        .filter_map(|(name, stats)| {
+            let pct = stats.get("lines")?.get("pct")?.as_f64()?;
+            Some((relative_report_path(project_path, name), pct))
+        })
+        .collect()
+}
+
+/// Parse a Cobertura XML report (coverage.py, Istanbul's cobertura reporter)
+fn parse_cobertura(project_path: &Path, content: &str) -> BTreeMap<PathBuf, f64> {
+    let attr = |tag: &str, name: &str| -> Option<String> {
+        let start = tag.find(&format!(" {}=\"", name))? + name.len() + 3;
+        let end = tag[start..].find('"')?;
+        Some(tag[start..start + end].to_string())
+    };
+    content
+        .split("<class ")
+        .skip(1)
+        .filter_map(|tag| {
+            let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+            let file = attr(tag, "filename")?;
+            let rate: f64 = attr(tag, "line-rate")?.parse().ok()?;
+            Some((relative_report_path(project_path, &file), rate * 100.0))
+        })
+        .collect()
+}
+
+/// Read the first coverage report found in the project, if any
+fn load_coverage_report(project_path: &Path) -> Option<(PathBuf, BTreeMap<PathBuf, f64>)> {
+    COVERAGE_REPORTS.iter().find_map(|rel| {
+        let content = fs::read_to_string(project_path.join(rel)).ok()?;
+        let parsed = if rel.ends_with(".info") {
+            parse_lcov(project_path, &content)
+        } else if rel.ends_with(".json") {
+            parse_istanbul_summary(project_path, &content)
+        } else {
+            parse_cobertura(project_path, &content)
+        };
+        (!parsed.is_empty()).then(|| (PathBuf::from(rel), parsed))
+    })
+}
+
+/// Map tests to source files, ingest any coverage report, and list untested areas
+pub fn analyze_test_coverage(
+    project_path: &Path,
+    ignore_patterns: &[String],
+    graph: &[ModuleImports],
+) -> TestCoverage {
+    let (test_files, sources) = map_tests(project_path, ignore_patterns, graph);
+    let (report, line_coverage) = match load_coverage_report(project_path) {
+        Some((report, coverage)) => (Some(report), coverage),
+        None => (None, BTreeMap::new()),
+    };
+    let mut coverage = TestCoverage {
+        test_files,
+        report,
+        line_coverage,
+        untested_areas: Vec::new(),
+    };
+
+    let mut areas: BTreeMap<String, bool> = BTreeMap::new();
+    for source in &sources {
+        let tested = coverage.is_tested(source);
+        *areas.entry(area_of(source)).or_insert(false) |= tested;
+    }
+    coverage.untested_areas = areas
+        .into_iter()
+        .filter(|(_, tested)| !tested)
+        .map(|(area, _)| area)
+        .collect();
+    coverage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detects_test_files() {
+        assert!(is_test_file(Path::new("src/lib/reports.test.ts")));
+        assert!(is_test_file(Path::new("tests/test_api.py")));
+        assert!(is_test_file(Path::new("src/__tests__/Button.tsx")));
+        assert!(is_test_file(Path::new("pkg/parser_test.py")));
+        assert!(!is_test_file(Path::new("src/lib/reports.ts")));
+        assert_eq!(subject_stem(Path::new("tests/test_api.py")).as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_maps_tests_by_name_and_imports() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for file in [
+            "src/lib/reports.ts",
+            "src/lib/reports.test.ts",
+            "src/billing/invoice.ts",
+            "src/ui/page.tsx",
+            "e2e/flows.ts",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "export const x = 1;").unwrap();
+        }
+        let graph = vec![ModuleImports {
+            path: PathBuf::from("e2e/flows.ts"),
+            imports: vec![PathBuf::from("src/billing/invoice.ts")],
+        }];
+
+        let coverage = analyze_test_coverage(root, &[], &graph);
+        assert!(coverage.is_tested(Path::new("src/lib/reports.ts")));
+        assert!(coverage.is_tested(Path::new("src/billing/invoice.ts")));
+        assert!(!coverage.is_tested(Path::new("src/ui/page.tsx")));
+        assert_eq!(coverage.untested_areas, vec!["src/ui".to_string()]);
+    }
+
+    #[test]
+    fn test_parses_lcov_and_cobertura() {
+        let root = Path::new("/project");
+        let lcov = "SF:/project/src/a.ts\nLF:10\nLH:4\nend_of_record\nSF:src/b.ts\nLF:0\nLH:0\nend_of_record\n";
+        let parsed = parse_lcov(root, lcov);
+        assert_eq!(parsed.get(Path::new("src/a.ts")), Some(&40.0));
+        assert_eq!(parsed.get(Path::new("src/b.ts")), Some(&0.0));
+
+        let xml = r#"<classes><class name="app" filename="app/views.py" line-rate="0.5" branch-rate="0"></class></classes>"#;
+        let parsed = parse_cobertura(root, xml);
+        assert_eq!(parsed.get(Path::new("app/views.py")), Some(&50.0));
+    }
+}
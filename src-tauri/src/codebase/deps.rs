@@ -0,0 +1,430 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+use super::symbols::{first_string_literal, language_for, text};
+use super::walk::project_walker;
+
+/// Files larger than this are skipped to keep analysis fast
+const MAX_FILE_BYTES: u64 = 256 * 1024;
+
+/// Upper bound on files parsed per analysis run
+const MAX_FILES: usize = 2000;
+
+const JS_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs", "cjs"];
+
+/// Project-local imports of a single source file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleImports {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    /// Project files this file imports, relative to the project root
+    pub imports: Vec<PathBuf>,
+}
+
+/// Lexically normalize `a/./b/../c` to `a/c` without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            c => out.push(c.as_os_str()),
+        }
+    }
+    out
+}
+
+fn first_known(candidates: impl IntoIterator<Item = PathBuf>, known: &HashSet<PathBuf>) -> Option<PathBuf> {
+    candidates
+        .into_iter()
+        .map(|c| normalize(&c))
+        .find(|c| known.contains(c))
+}
+
+// --- TypeScript / JavaScript ----------------------------------------------
+
+fn resolve_js(file: &Path, spec: &str, known: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let dir = file.parent().unwrap_or(Path::new(""));
+    let bases: Vec<PathBuf> = if spec.starts_with('.') {
+        vec![dir.join(spec)]
+    } else if let Some(rest) = spec.strip_prefix("@/").or_else(|| spec.strip_prefix("~/")) {
+        // Common tsconfig path aliases
+        vec![Path::new("src").join(rest), PathBuf::from(rest)]
+    } else {
+        // Bare specifiers are packages, not project files
+        return None;
+    };
+
+    let mut candidates = Vec::new();
+    for base in bases {
+        candidates.push(base.clone());
+        for ext in JS_EXTENSIONS {
+            let mut with_ext = base.clone().into_os_string();
+            with_ext.push(format!(".{}", ext));
+            candidates.push(PathBuf::from(with_ext));
+        }
+        for ext in JS_EXTENSIONS {
+            candidates.push(base.join(format!("index.{}", ext)));
+        }
+    }
+    first_known(candidates, known)
+}
+
+fn js_specifiers(node: Node, source: &[u8], out: &mut Vec<String>) {
+    match node.kind() {
+        "import_statement" | "export_statement" => {
+            if let Some(spec) = node
+                .child_by_field_name("source")
+                .and_then(|s| first_string_literal(s, source))
+            {
+                out.push(spec);
+            }
+        }
+        "call_expression" => {
+            let callee = node.child_by_field_name("function").map(|f| text(f, source));
+            if matches!(callee, Some("require") | Some("import")) {
+                if let Some(spec) = node
+                    .child_by_field_name("arguments")
+                    .and_then(|a| first_string_literal(a, source))
+                {
+                    out.push(spec);
+                }
+            }
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        js_specifiers(child, source, out);
+    }
+}
+
+// --- Python ---------------------------------------------------------------
+
+fn resolve_python(file: &Path, module: &str, known: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let level = module.chars().take_while(|c| *c == '.').count();
+    let dotted = &module[level..];
+    let rel: PathBuf = dotted.split('.').filter(|s| !s.is_empty()).collect();
+
+    let bases: Vec<PathBuf> = if level > 0 {
+        let mut dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+        for _ in 1..level {
+            dir.pop();
+        }
+        vec![dir.join(&rel)]
+    } else {
+        vec![rel.clone(), Path::new("src").join(&rel)]
+    };
+
+    let candidates = bases.into_iter().flat_map(|base| {
+        let mut module_file = base.clone().into_os_string();
+        module_file.push(".py");
+        [PathBuf::from(module_file), base.join("__init__.py")]
+    });
+    first_known(candidates, known)
+}
+
+fn python_modules(root: Node, source: &[u8], out: &mut Vec<String>) {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "import_statement" => {
+                // `import a.b, c as d`
+                let raw = text(child, source).trim_start_matches("import").trim();
+                for part in raw.split(',') {
+                    if let Some(module) = part.split_whitespace().next() {
+                        out.push(module.to_string());
+                    }
+                }
+            }
+            "import_from_statement" => {
+                let Some(module) = child
+                    .child_by_field_name("module_name")
+                    .map(|m| text(m, source).to_string())
+                else {
+                    continue;
+                };
+                // `from pkg import mod` may name submodules rather than attributes
+                let raw = text(child, source);
+                if let Some((_, names)) = raw.split_once(" import ") {
+                    let names = names.trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace());
+                    for name in names.split(',') {
+                        if let Some(name) = name.split_whitespace().next().filter(|n| *n != "*") {
+                            let sep = if module.ends_with('.') { "" } else { "." };
+                            out.push(format!("{}{}{}", module, sep, name));
+                        }
+                    }
+                }
+                out.push(module);
+            }
+            _ => {}
+        }
+    }
+}
+
+// --- Rust -----------------------------------------------------------------
+
+/// Directory holding the child modules of `file` (`foo.rs` -> `foo/`, `mod.rs` -> its dir)
+fn rust_module_dir(file: &Path) -> PathBuf {
+    let dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+    match file.file_stem().and_then(|s| s.to_str()) {
+        Some("mod") | Some("lib") | Some("main") => dir,
+        Some(stem) => dir.join(stem),
+        None => dir,
+    }
+}
+
+/// The `src` directory of the crate containing `file`
+fn rust_crate_root(file: &Path) -> PathBuf {
+    let mut root = PathBuf::new();
+    let mut found = None;
+    for component in file.parent().unwrap_or(Path::new("")).components() {
+        root.push(component.as_os_str());
+        if component.as_os_str() == "src" {
+            found = Some(root.clone());
+        }
+    }
+    found.unwrap_or_default()
+}
+
+fn resolve_rust_path(base: &Path, segments: &[&str], known: &HashSet<PathBuf>) -> Option<PathBuf> {
+    // Prefer the longest prefix that maps to a module file (`a::b::Item` -> `a/b.rs` or `a.rs`)
+    (1..=segments.len()).rev().find_map(|n| {
+        let rel: PathBuf = segments[..n].iter().collect();
+        let mut module_file = base.join(&rel).into_os_string();
+        module_file.push(".rs");
+        first_known([PathBuf::from(module_file), base.join(&rel).join("mod.rs")], known)
+    })
+}
+
+fn resolve_rust_use(file: &Path, path: &str, known: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let path = path.split(['{', ' ', ';']).next()?.trim_end_matches("::");
+    let segments: Vec<&str> = path.split("::").filter(|s| !s.is_empty()).collect();
+    let (base, rest) = match segments.first().copied()? {
+        "crate" => (rust_crate_root(file), &segments[1..]),
+        "self" => (rust_module_dir(file), &segments[1..]),
+        "super" => {
+            let mut base = rust_module_dir(file);
+            let mut rest = &segments[..];
+            while rest.first() == Some(&"super") {
+                base.pop();
+                rest = &rest[1..];
+            }
+            (base, rest)
+        }
+        _ => return None,
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    resolve_rust_path(&base, rest, known)
+}
+
+fn rust_imports(root: Node, source: &[u8], file: &Path, known: &HashSet<PathBuf>, out: &mut Vec<PathBuf>) {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "mod_item" if child.child_by_field_name("body").is_none() => {
+                if let Some(name) = child.child_by_field_name("name").map(|n| text(n, source)) {
+                    let dir = rust_module_dir(file);
+                    if let Some(target) =
+                        first_known([dir.join(format!("{}.rs", name)), dir.join(name).join("mod.rs")], known)
+                    {
+                        out.push(target);
+                    }
+                }
+            }
+            "use_declaration" => {
+                if let Some(arg) = child.child_by_field_name("argument") {
+                    if let Some(target) = resolve_rust_use(file, text(arg, source), known) {
+                        out.push(target);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolve the project-local imports of one file. `file` and `known` are relative to the project root.
+pub fn extract_file_imports(file: &Path, source: &str, known: &HashSet<PathBuf>) -> Vec<PathBuf> {
+    let Some(language) = language_for(file) else { return Vec::new(); };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else { return Vec::new(); };
+    let bytes = source.as_bytes();
+    let root = tree.root_node();
+
+    let mut imports = Vec::new();
+    match file.extension().and_then(|e| e.to_str()) {
+        Some("rs") => rust_imports(root, bytes, file, known, &mut imports),
+        Some("py") => {
+            let mut modules = Vec::new();
+            python_modules(root, bytes, &mut modules);
+            imports.extend(modules.iter().filter_map(|m| resolve_python(file, m, known)));
+        }
+        _ => {
+            let mut specs = Vec::new();
+            js_specifiers(root, bytes, &mut specs);
+            imports.extend(specs.iter().filter_map(|s| resolve_js(file, s, known)));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    imports.retain(|p| p != file && seen.insert(p.clone()));
+    imports.sort();
+    imports
+}
+
+/// Build the file-level import graph for every supported, non-ignored source file
+pub fn build_dependency_graph(project_path: &Path, ignore_patterns: &[String]) -> Vec<ModuleImports> {
+    let files: Vec<PathBuf> = project_walker(project_path, ignore_patterns, None)
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+        .filter(|e| language_for(e.path()).is_some())
+        .filter(|e| e.metadata().map(|m| m.len() <= MAX_FILE_BYTES).unwrap_or(false))
+        .filter_map(|e| e.path().strip_prefix(project_path).ok().map(|p| p.to_path_buf()))
+        .take(MAX_FILES)
+        .collect();
+    let known: HashSet<PathBuf> = files.iter().cloned().collect();
+
+    let mut graph: Vec<ModuleImports> = files
+        .iter()
+        .filter_map(|rel| {
+            let source = fs::read_to_string(project_path.join(rel)).ok()?;
+            let imports = extract_file_imports(rel, &source, &known);
+            (!imports.is_empty()).then(|| ModuleImports {
+                path: rel.clone(),
+                imports,
+            })
+        })
+        .collect();
+    graph.sort_by(|a, b| a.path.cmp(&b.path));
+    graph
+}
+
+/// Files directly imported by, or directly importing, any of `seeds`
+pub fn related_files(graph: &[ModuleImports], seeds: &[PathBuf]) -> Vec<PathBuf> {
+    let seeds: HashSet<&PathBuf> = seeds.iter().collect();
+    let mut related = HashSet::new();
+    for module in graph {
+        if seeds.contains(&module.path) {
+            related.extend(module.imports.iter().cloned());
+        }
+        if module.imports.iter().any(|i| seeds.contains(i)) {
+            related.insert(module.path.clone());
+        }
+    }
+    let mut related: Vec<PathBuf> = related.into_iter().filter(|p| !seeds.contains(p)).collect();
+    related.sort();
+    related
+}
+
+/// Area of the codebase a file belongs to: its first two directory components
+pub fn area_of(path: &Path) -> String {
+    let dirs: Vec<String> = path
+        .parent()
+        .map(|p| p.components().take(2).map(|c| c.as_os_str().to_string_lossy().to_string()).collect())
+        .unwrap_or_default();
+    if dirs.is_empty() {
+        ".".to_string()
+    } else {
+        dirs.join("/")
+    }
+}
+
+/// Collapse the file graph into area-level edges (`from -> to` with import counts)
+pub fn area_edges(graph: &[ModuleImports]) -> Vec<(String, String, usize)> {
+    let mut edges: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for module in graph {
+        let from = area_of(&module.path);
+        for import in &module.imports {
+            let to = area_of(import);
+            if to != from {
+                *edges.entry((from.clone(), to)).or_default() += 1;
+            }
+        }
+    }
+    let mut edges: Vec<(String, String, usize)> = edges.into_iter().map(|((f, t), n)| (f, t, n)).collect();
+    edges.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known(files: &[&str]) -> HashSet<PathBuf> {
+        files.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_resolves_typescript_relative_and_alias_imports() {
+        let k = known(&["src/app.tsx", "src/lib/api.ts", "src/components/index.tsx"]);
+        let src = r#"
+import { get } from "./lib/api";
+import Components from "@/components";
+import React from "react";
+const lazy = import("./lib/api");
+"#;
+        let imports = extract_file_imports(Path::new("src/app.tsx"), src, &k);
+        assert_eq!(
+            imports,
+            vec![PathBuf::from("src/components/index.tsx"), PathBuf::from("src/lib/api.ts")]
+        );
+    }
+
+    #[test]
+    fn test_resolves_python_absolute_relative_and_submodule_imports() {
+        let k = known(&["app/main.py", "app/models.py", "app/services/__init__.py", "app/services/billing.py"]);
+        let src = "from .models import User\nfrom app.services import billing\nimport os\n";
+        let imports = extract_file_imports(Path::new("app/main.py"), src, &k);
+        assert_eq!(
+            imports,
+            vec![
+                PathBuf::from("app/models.py"),
+                PathBuf::from("app/services/__init__.py"),
+                PathBuf::from("app/services/billing.py"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolves_rust_mod_and_use_declarations() {
+        let k = known(&["src/lib.rs", "src/audio.rs", "src/managers/mod.rs", "src/managers/meeting.rs"]);
+        let src = "mod audio;\npub mod managers;\nuse crate::managers::meeting::MeetingManager;\nuse std::fs;\n";
+        let imports = extract_file_imports(Path::new("src/lib.rs"), src, &k);
+        assert_eq!(
+            imports,
+            vec![
+                PathBuf::from("src/audio.rs"),
+                PathBuf::from("src/managers/meeting.rs"),
+                PathBuf::from("src/managers/mod.rs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_related_files_and_area_edges() {
+        let graph = vec![
+            ModuleImports {
+                path: PathBuf::from("src/ui/page.tsx"),
+                imports: vec![PathBuf::from("src/lib/api.ts")],
+            },
+            ModuleImports {
+                path: PathBuf::from("src/lib/api.ts"),
+                imports: vec![PathBuf::from("src/lib/http.ts")],
+            },
+        ];
+        let related = related_files(&graph, &[PathBuf::from("src/lib/api.ts")]);
+        assert_eq!(related, vec![PathBuf::from("src/lib/http.ts"), PathBuf::from("src/ui/page.tsx")]);
+        assert_eq!(area_edges(&graph), vec![("src/ui".to_string(), "src/lib".to_string(), 1)]);
+    }
+}
@@ -0,0 +1,445 @@
+//! Local embedding index over project source files.
+//!
+//! Vectors are hashed TF-IDF over identifier-aware tokens (camelCase and snake_case are
+//! split), so the index needs no model download and works offline. The `embedder` id is
+//! stored with each index so a different embedding scheme invalidates old indexes.
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Manager};
+
+use super::walk::project_walker;
+
+const EMBEDDER_ID: &str = "hashed-tfidf-v1";
+
+/// Number of hash buckets per vector
+const DIMENSIONS: usize = 4096;
+
+/// Lines per chunk and overlap between consecutive chunks
+const CHUNK_LINES: usize = 40;
+const CHUNK_OVERLAP: usize = 10;
+
+const MAX_FILE_BYTES: u64 = 256 * 1024;
+const MAX_CHUNKS: usize = 20_000;
+
+const INDEXED_EXTENSIONS: &[&str] = &[
+    "rs", "ts", "tsx", "js", "jsx", "py", "go", "java", "rb", "php", "swift", "vue", "svelte", "md",
+];
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "let", "const", "var", "fn", "pub", "use", "self", "return", "import",
+    "from", "def", "if", "else", "to", "of", "in", "is", "it", "this", "that", "with", "mut",
+    "new", "function", "export", "default", "async", "await", "true", "false", "none", "null",
+];
+
+/// Sparse vector as (bucket, weight) pairs sorted by bucket
+type SparseVector = Vec<(u32, f32)>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedChunk {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    /// 1-based, inclusive
+    pub start_line: usize,
+    pub end_line: usize,
+    pub text: String,
+    vector: SparseVector,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingIndex {
+    pub project_path: PathBuf,
+    pub embedder: String,
+    pub built_at: String,
+    /// File modification times (seconds) used to reuse chunks on rebuild
+    file_mtimes: HashMap<PathBuf, u64>,
+    /// Raw term-frequency vectors per chunk; IDF is applied at query time
+    chunks: Vec<IndexedChunk>,
+    idf: Vec<f32>,
+}
+
+/// A chunk returned by a retrieval query
+#[derive(Debug, Clone, Serialize)]
+pub struct RetrievedChunk {
+    pub path: PathBuf,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub text: String,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexStats {
+    pub files: usize,
+    pub chunks: usize,
+    pub reused_files: usize,
+    pub built_at: String,
+}
+
+/// Split text into lowercase tokens, breaking identifiers on case and underscores
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        let mut current = String::new();
+        let mut prev_lower = false;
+        for c in word.chars() {
+            if c.is_uppercase() && prev_lower && !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+            current.extend(c.to_lowercase());
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens.retain(|t| t.len() >= 2 && !STOP_WORDS.contains(&t.as_str()));
+    tokens
+}
+
+/// FNV-1a, stable across builds and platforms
+fn bucket(feature: &str) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for b in feature.as_bytes() {
+        hash ^= *b as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash % DIMENSIONS as u32
+}
+
+/// Term-frequency vector: unigrams plus adjacent-token bigrams at half weight
+fn term_vector(text: &str) -> SparseVector {
+    let tokens = tokenize(text);
+    let mut counts: HashMap<u32, f32> = HashMap::new();
+    for t in &tokens {
+        *counts.entry(bucket(t)).or_default() += 1.0;
+    }
+    for pair in tokens.windows(2) {
+        *counts.entry(bucket(&format!("{} {}", pair[0], pair[1]))).or_default() += 0.5;
+    }
+    let mut vector: SparseVector = counts
+        .into_iter()
+        .map(|(k, tf)| (k, 1.0 + tf.ln_1p()))
+        .collect();
+    vector.sort_by_key(|(k, _)| *k);
+    vector
+}
+
+/// Apply IDF weights and L2-normalize
+fn weighted(vector: &SparseVector, idf: &[f32]) -> SparseVector {
+    let mut out: SparseVector = vector
+        .iter()
+        .map(|(k, w)| (*k, w * idf.get(*k as usize).copied().unwrap_or(1.0)))
+        .collect();
+    let norm = out.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for (_, w) in out.iter_mut() {
+            *w /= norm;
+        }
+    }
+    out
+}
+
+fn dot(a: &SparseVector, b: &SparseVector) -> f32 {
+    let (mut i, mut j, mut sum) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                sum += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    sum
+}
+
+/// Split a file into overlapping line windows
+fn chunk_file(path: &Path, content: &str) -> Vec<IndexedChunk> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < lines.len() {
+        let end = (start + CHUNK_LINES).min(lines.len());
+        let text = lines[start..end].join("\n");
+        if !text.trim().is_empty() {
+            chunks.push(IndexedChunk {
+                path: path.to_path_buf(),
+                start_line: start + 1,
+                end_line: end,
+                vector: term_vector(&format!("{} {}", path.display(), text)),
+                text,
+            });
+        }
+        if end == lines.len() {
+            break;
+        }
+        start = end - CHUNK_OVERLAP;
+    }
+    chunks
+}
+
+fn compute_idf(chunks: &[IndexedChunk]) -> Vec<f32> {
+    let mut df = vec![0u32; DIMENSIONS];
+    for chunk in chunks {
+        for (k, _) in &chunk.vector {
+            df[*k as usize] += 1;
+        }
+    }
+    let n = chunks.len() as f32;
+    df.into_iter()
+        .map(|d| ((n + 1.0) / (d as f32 + 1.0)).ln() + 1.0)
+        .collect()
+}
+
+/// Build (or incrementally rebuild) the index; unchanged files reuse chunks from `previous`
+pub fn build_index(
+    project_path: &Path,
+    ignore_patterns: &[String],
+    previous: Option<&EmbeddingIndex>,
+) -> (EmbeddingIndex, usize) {
+    let previous = previous.filter(|p| p.embedder == EMBEDDER_ID);
+    let mut previous_chunks: HashMap<&Path, Vec<&IndexedChunk>> = HashMap::new();
+    if let Some(prev) = previous {
+        for chunk in &prev.chunks {
+            previous_chunks.entry(chunk.path.as_path()).or_default().push(chunk);
+        }
+    }
+
+    let mut chunks = Vec::new();
+    let mut file_mtimes = HashMap::new();
+    let mut reused = 0;
+
+    for entry in project_walker(project_path, ignore_patterns, None).filter_map(|e| e.ok()) {
+        if chunks.len() >= MAX_CHUNKS {
+            log::warn!("Embedding index truncated at {} chunks", MAX_CHUNKS);
+            break;
+        }
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let indexed = entry
+            .path()
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| INDEXED_EXTENSIONS.contains(&e))
+            .unwrap_or(false);
+        let Ok(metadata) = entry.metadata() else { continue; };
+        if !indexed || metadata.len() > MAX_FILE_BYTES {
+            continue;
+        }
+        let Ok(rel) = entry.path().strip_prefix(project_path) else { continue; };
+        let rel = rel.to_path_buf();
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let unchanged = previous
+            .and_then(|p| p.file_mtimes.get(&rel))
+            .map(|m| *m == mtime)
+            .unwrap_or(false);
+        if let (true, Some(old)) = (unchanged, previous_chunks.get(rel.as_path())) {
+            chunks.extend(old.iter().map(|c| (*c).clone()));
+            reused += 1;
+        } else if let Ok(content) = fs::read_to_string(entry.path()) {
+            chunks.extend(chunk_file(&rel, &content));
+        } else {
+            continue;
+        }
+        file_mtimes.insert(rel, mtime);
+    }
+
+    let idf = compute_idf(&chunks);
+    let index = EmbeddingIndex {
+        project_path: project_path.to_path_buf(),
+        embedder: EMBEDDER_ID.to_string(),
+        built_at: chrono::Utc::now().to_rfc3339(),
+        file_mtimes,
+        chunks,
+        idf,
+    };
+    (index, reused)
+}
+
+/// Return the `limit` chunks most similar to `query`
+pub fn search(index: &EmbeddingIndex, query: &str, limit: usize) -> Vec<RetrievedChunk> {
+    let query_vector = weighted(&term_vector(query), &index.idf);
+    if query_vector.is_empty() {
+        return Vec::new();
+    }
+    let mut scored: Vec<(f32, &IndexedChunk)> = index
+        .chunks
+        .iter()
+        .map(|c| (dot(&query_vector, &weighted(&c.vector, &index.idf)), c))
+        .filter(|(score, _)| *score > 0.0)
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(score, c)| RetrievedChunk {
+            path: c.path.clone(),
+            start_line: c.start_line,
+            end_line: c.end_line,
+            text: c.text.clone(),
+            score,
+        })
+        .collect()
+}
+
+/// Render retrieved chunks as fenced snippets for prompts
+pub fn format_snippets(chunks: &[RetrievedChunk]) -> String {
+    let mut out = String::new();
+    for c in chunks {
+        let _ = writeln!(
+            out,
+            "`{}` (lines {}-{}):\n```\n{}\n```\n",
+            c.path.display(),
+            c.start_line,
+            c.end_line,
+            c.text
+        );
+    }
+    out
+}
+
+// --- Storage --------------------------------------------------------------
+
+// Map: project path -> loaded index, to avoid re-reading the JSON on every query
+static LOADED: Lazy<Mutex<HashMap<PathBuf, Arc<EmbeddingIndex>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn index_file(app: &AppHandle, project_path: &Path) -> Result<PathBuf> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .context("Failed to resolve app data directory")?
+        .join("embeddings");
+    let name: String = project_path
+        .to_string_lossy()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = name.trim_matches('_');
+    // Keep the tail, which holds the distinguishing project directory names
+    let tail: String = name.chars().rev().take(120).collect::<Vec<_>>().into_iter().rev().collect();
+    Ok(dir.join(format!("{}.json", tail)))
+}
+
+fn load_index(app: &AppHandle, project_path: &Path) -> Option<Arc<EmbeddingIndex>> {
+    if let Some(index) = LOADED.lock().unwrap().get(project_path) {
+        return Some(index.clone());
+    }
+    let content = fs::read_to_string(index_file(app, project_path).ok()?).ok()?;
+    let index: EmbeddingIndex = serde_json::from_str(&content).ok()?;
+    if index.embedder != EMBEDDER_ID || index.project_path != project_path {
+        return None;
+    }
+    let index = Arc::new(index);
+    LOADED
+        .lock()
+        .unwrap()
+        .insert(project_path.to_path_buf(), index.clone());
+    Some(index)
+}
+
+/// Rebuild the project's index (reusing unchanged files) and persist it to app data
+pub fn refresh_project_index(app: &AppHandle, project_path: &Path, ignore_patterns: &[String]) -> Result<IndexStats> {
+    let previous = load_index(app, project_path);
+    let (index, reused_files) = build_index(project_path, ignore_patterns, previous.as_deref());
+
+    let path = index_file(app, project_path)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_vec(&index)?).context("Failed to write embedding index")?;
+
+    let stats = IndexStats {
+        files: index.file_mtimes.len(),
+        chunks: index.chunks.len(),
+        reused_files,
+        built_at: index.built_at.clone(),
+    };
+    LOADED
+        .lock()
+        .unwrap()
+        .insert(project_path.to_path_buf(), Arc::new(index));
+    log::info!(
+        "Embedding index for {}: {} files ({} reused), {} chunks",
+        project_path.display(),
+        stats.files,
+        stats.reused_files,
+        stats.chunks
+    );
+    Ok(stats)
+}
+
+/// Search the project's persisted index; empty if no index has been built
+pub fn search_project(app: &AppHandle, project_path: &Path, query: &str, limit: usize) -> Vec<RetrievedChunk> {
+    match load_index(app, project_path) {
+        Some(index) => search(&index, query, limit),
+        None => Vec::new(),
+    }
+}
+
+/// Retrieve relevant chunks for prompt context if embeddings are enabled and an index exists.
+/// Returns an empty list otherwise, so callers can use it unconditionally.
+pub fn retrieve(app: &AppHandle, project_path: &Path, query: &str, limit: usize) -> Vec<RetrievedChunk> {
+    if !crate::settings::get_settings(app).codebase_embeddings_enabled {
+        return Vec::new();
+    }
+    search_project(app, project_path, query, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tokenize_splits_identifiers() {
+        assert_eq!(
+            tokenize("fn exportReportCsv(meeting_id: &str)"),
+            vec!["report", "csv", "meeting", "id", "str"]
+        );
+    }
+
+    #[test]
+    fn test_search_ranks_relevant_chunk_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/billing.ts"), "export function createInvoice(customer) {\n  return stripe.invoices.create(customer);\n}\n").unwrap();
+        fs::write(root.join("src/auth.ts"), "export function login(user, password) {\n  return session.start(user);\n}\n").unwrap();
+
+        let (index, reused) = build_index(root, &[], None);
+        assert_eq!(reused, 0);
+        let results = search(&index, "we need to send an invoice to each customer", 2);
+        assert_eq!(results[0].path, PathBuf::from("src/billing.ts"));
+
+        let (rebuilt, reused) = build_index(root, &[], Some(&index));
+        assert_eq!(reused, 2);
+        assert_eq!(rebuilt.chunks.len(), index.chunks.len());
+    }
+
+    #[test]
+    fn test_chunks_overlap() {
+        let content: String = (1..=100).map(|i| format!("line {}\n", i)).collect();
+        let chunks = chunk_file(Path::new("a.rs"), &content);
+        assert_eq!(chunks[0].start_line, 1);
+        assert_eq!(chunks[0].end_line, CHUNK_LINES);
+        assert_eq!(chunks[1].start_line, CHUNK_LINES - CHUNK_OVERLAP + 1);
+        assert_eq!(chunks.last().unwrap().end_line, 100);
+    }
+}
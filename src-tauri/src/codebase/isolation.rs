@@ -1,16 +1,19 @@
+use crate::settings::ClaudeignoreFragment;
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Generates .claudeignore file to protect sensitive areas
-pub fn generate_claudeignore(project_path: &Path, framework: Option<&str>) -> Result<()> {
-    let claudeignore_path = project_path.join(".claudeignore");
+const MANAGED_BEGIN: &str = "# >>> MeetingCoder managed patterns (regenerated; edit below the end marker) >>>";
+const MANAGED_END: &str = "# <<< MeetingCoder managed patterns <<<";
+/// Header written by versions that overwrote the whole file
+const LEGACY_HEADER: &str = "# MeetingCoder File Isolation";
 
+/// Built-in protections for every project plus framework-specific additions
+fn builtin_patterns(framework: Option<&str>) -> Vec<String> {
     // Base patterns that apply to all projects
     let mut patterns = vec![
-        "# MeetingCoder File Isolation".to_string(),
-        "# Generated automatically to protect core project files".to_string(),
-        "".to_string(),
         "# Protect core application code (allow reading, restrict edits)".to_string(),
         "src/**".to_string(),
         "app/**".to_string(),
@@ -132,11 +135,151 @@ pub fn generate_claudeignore(project_path: &Path, framework: Option<&str>) -> Re
         }
     }
 
-    let content = patterns.join("\n");
-    fs::write(&claudeignore_path, content)
-        .context("Failed to write .claudeignore file")?;
+    patterns
+}
+
+/// Effective rules of a `.claudeignore`, ignoring comments and blank lines
+fn rules(lines: &[String]) -> Vec<&str> {
+    lines
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect()
+}
+
+/// Lines of an existing `.claudeignore` written by the user (outside the managed block).
+/// For files generated before the managed block existed, only rules that aren't
+/// built-in are kept.
+fn user_lines(existing: &str, framework: Option<&str>) -> Vec<String> {
+    if existing.trim_start().starts_with(LEGACY_HEADER) {
+        let builtin = builtin_patterns(framework);
+        return existing
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#') && !builtin.iter().any(|b| b == l))
+            .map(|l| l.to_string())
+            .collect();
+    }
+    let mut lines = Vec::new();
+    let mut in_block = false;
+    for line in existing.lines() {
+        if line.trim() == MANAGED_BEGIN {
+            in_block = true;
+        } else if line.trim() == MANAGED_END {
+            in_block = false;
+        } else if !in_block {
+            lines.push(line.to_string());
+        }
+    }
+    while lines.first().map(|l| l.trim().is_empty()).unwrap_or(false) {
+        lines.remove(0);
+    }
+    lines
+}
+
+/// Compose `.claudeignore` content: a managed block (built-ins plus matching template
+/// fragments) followed by the user's own lines, which take precedence because later
+/// rules win. Patterns the user already lists are not repeated in the managed block.
+pub fn render_claudeignore(
+    existing: Option<&str>,
+    framework: Option<&str>,
+    fragments: &[ClaudeignoreFragment],
+) -> String {
+    let user = existing.map(|e| user_lines(e, framework)).unwrap_or_default();
+    let user_rules: HashSet<&str> = rules(&user).into_iter().collect();
+
+    let mut managed = builtin_patterns(framework);
+    for fragment in fragments {
+        let applies = fragment.frameworks.is_empty()
+            || framework
+                .map(|fw| fragment.frameworks.iter().any(|f| f.eq_ignore_ascii_case(fw)))
+                .unwrap_or(false);
+        if applies && !fragment.patterns.is_empty() {
+            managed.push(String::new());
+            managed.push(format!("# Template: {}", fragment.name));
+            managed.extend(fragment.patterns.iter().map(|p| p.trim().to_string()));
+        }
+    }
 
-    log::info!("Generated .claudeignore at {:?}", claudeignore_path);
+    let mut seen = HashSet::new();
+    let mut lines = vec![MANAGED_BEGIN.to_string()];
+    for line in managed {
+        let rule = line.trim();
+        let is_rule = !rule.is_empty() && !rule.starts_with('#');
+        if is_rule && (user_rules.contains(rule) || !seen.insert(rule.to_string())) {
+            continue;
+        }
+        lines.push(line);
+    }
+    lines.push(MANAGED_END.to_string());
+    if !user.is_empty() {
+        lines.push(String::new());
+        lines.extend(user);
+    }
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Resulting protection set for a project's `.claudeignore`, without writing it
+#[derive(Debug, Clone, Serialize)]
+pub struct ClaudeignorePreview {
+    pub content: String,
+    /// Paths agents are kept out of
+    pub protected: Vec<String>,
+    /// `!` exceptions agents may still edit
+    pub allowed: Vec<String>,
+    /// Rules not present in the current file
+    pub added: Vec<String>,
+    /// Rules in the current file that would no longer apply
+    pub removed: Vec<String>,
+}
+
+/// Preview the merged `.claudeignore` for a project
+pub fn preview_claudeignore(
+    project_path: &Path,
+    framework: Option<&str>,
+    fragments: &[ClaudeignoreFragment],
+) -> ClaudeignorePreview {
+    let existing = fs::read_to_string(project_path.join(".claudeignore")).ok();
+    let content = render_claudeignore(existing.as_deref(), framework, fragments);
+
+    let new_lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+    let old_lines: Vec<String> = existing
+        .as_deref()
+        .map(|e| e.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default();
+    let new_rules = rules(&new_lines);
+    let old_rules = rules(&old_lines);
+
+    ClaudeignorePreview {
+        protected: new_rules.iter().filter(|r| !r.starts_with('!')).map(|r| r.to_string()).collect(),
+        allowed: new_rules
+            .iter()
+            .filter_map(|r| r.strip_prefix('!'))
+            .map(|r| r.to_string())
+            .collect(),
+        added: new_rules.iter().filter(|r| !old_rules.contains(r)).map(|r| r.to_string()).collect(),
+        removed: old_rules.iter().filter(|r| !new_rules.contains(r)).map(|r| r.to_string()).collect(),
+        content,
+    }
+}
+
+/// Writes .claudeignore to protect sensitive areas, merging with any existing file
+pub fn generate_claudeignore(
+    project_path: &Path,
+    framework: Option<&str>,
+    fragments: &[ClaudeignoreFragment],
+) -> Result<()> {
+    let claudeignore_path = project_path.join(".claudeignore");
+    let existing = fs::read_to_string(&claudeignore_path).ok();
+    let content = render_claudeignore(existing.as_deref(), framework, fragments);
+
+    if existing.as_deref() != Some(content.as_str()) {
+        fs::write(&claudeignore_path, content)
+            .context("Failed to write .claudeignore file")?;
+        log::info!("Generated .claudeignore at {:?}", claudeignore_path);
+    }
     Ok(())
 }
 
@@ -306,6 +449,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_claudeignore_merges_with_user_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::write(project_path.join(".claudeignore"), "docs/internal/**\nsrc/**\n").unwrap();
+
+        let fragments = vec![
+            ClaudeignoreFragment {
+                name: "infra".to_string(),
+                frameworks: vec![],
+                patterns: vec!["terraform/**".to_string()],
+            },
+            ClaudeignoreFragment {
+                name: "rails-only".to_string(),
+                frameworks: vec!["Rails".to_string()],
+                patterns: vec!["db/seeds.rb".to_string()],
+            },
+        ];
+        generate_claudeignore(project_path, Some("Next.js"), &fragments).unwrap();
+        let content = fs::read_to_string(project_path.join(".claudeignore")).unwrap();
+
+        assert!(content.contains("docs/internal/**"));
+        assert!(content.contains("terraform/**"));
+        assert!(!content.contains("db/seeds.rb"));
+        // The user's own rule is kept once, after the managed block
+        assert_eq!(content.matches("\nsrc/**\n").count(), 1);
+        assert!(content.find(MANAGED_END).unwrap() < content.find("docs/internal/**").unwrap());
+
+        // Regenerating is stable
+        generate_claudeignore(project_path, Some("Next.js"), &fragments).unwrap();
+        assert_eq!(fs::read_to_string(project_path.join(".claudeignore")).unwrap(), content);
+
+        let preview = preview_claudeignore(project_path, Some("Next.js"), &[]);
+        assert_eq!(preview.removed, vec!["terraform/**".to_string()]);
+        assert!(preview.allowed.contains(&"experiments/**".to_string()));
+    }
+
     #[test]
     fn test_create_experiments_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,9 +1,18 @@
 pub mod analyzer;
+pub mod coverage;
+pub mod deps;
+pub mod embeddings;
 pub mod isolation;
+pub mod routes;
+pub mod symbols;
+pub mod walk;
 
 pub use analyzer::{
-    analyze_and_save_codebase, analyze_codebase, save_manifest_to_state, CodebaseManifest,
+    analyze_and_save_codebase, analyze_codebase, load_manifest_from_state, save_manifest_to_state,
+    CodebaseManifest,
 };
+pub use routes::ApiRoute;
+pub use symbols::{CodeSymbol, FileSymbols};
 pub use isolation::{
-    create_experiments_dir, generate_claudeignore,
+    create_experiments_dir, generate_claudeignore, preview_claudeignore, ClaudeignorePreview,
 };
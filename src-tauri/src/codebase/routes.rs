@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::symbols::{app_router_path, FileSymbols};
+use super::walk::project_walker;
+
+const PAGE_EXTENSIONS: &[&str] = &["tsx", "ts", "jsx", "js", "mdx"];
+
+/// An HTTP route or page declared by the project
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiRoute {
+    /// HTTP method, or "ANY" when the handler accepts every method
+    pub method: String,
+    pub path: String,
+    /// "api" for endpoints, "page" for rendered pages
+    pub kind: String,
+    /// Declaring file relative to the project root
+    pub file: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handler: Option<String>,
+}
+
+/// Routes found by symbol extraction (Express, FastAPI/Flask, Rust attributes, Next.js route handlers)
+fn routes_from_symbols(symbols: &[FileSymbols]) -> Vec<ApiRoute> {
+    symbols
+        .iter()
+        .flat_map(|file| {
+            file.symbols
+                .iter()
+                .filter(|s| s.kind == "route")
+                .filter_map(move |s| {
+                    let (method, path) = s.name.split_once(' ')?;
+                    Some(ApiRoute {
+                        method: method.to_string(),
+                        path: path.to_string(),
+                        kind: "api".to_string(),
+                        file: file.path.clone(),
+                        line: Some(s.line),
+                        handler: None,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Next.js pages router: `pages/about.tsx` -> `/about`, `pages/api/users/[id].ts` -> API route
+fn pages_router_route(rel: &Path) -> Option<ApiRoute> {
+    let ext = rel.extension()?.to_str()?;
+    if !PAGE_EXTENSIONS.contains(&ext) {
+        return None;
+    }
+    let components: Vec<String> = rel
+        .with_extension("")
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let pages_idx = components.iter().position(|c| c == "pages")?;
+    // Only top-level `pages/` or `src/pages/`
+    if pages_idx > 1 || (pages_idx == 1 && components[0] != "src") {
+        return None;
+    }
+    let mut segments: Vec<&str> = components[pages_idx + 1..].iter().map(|s| s.as_str()).collect();
+    if segments.first().map(|s| s.starts_with('_')).unwrap_or(true) {
+        // _app, _document, _error
+        return None;
+    }
+    if segments.last() == Some(&"index") {
+        segments.pop();
+    }
+    let is_api = segments.first() == Some(&"api");
+    Some(ApiRoute {
+        method: if is_api { "ANY" } else { "GET" }.to_string(),
+        path: format!("/{}", segments.join("/")),
+        kind: if is_api { "api" } else { "page" }.to_string(),
+        file: rel.to_path_buf(),
+        line: None,
+        handler: None,
+    })
+}
+
+/// Next.js app router pages: `app/settings/page.tsx` -> `/settings`
+fn app_router_page(rel: &Path) -> Option<ApiRoute> {
+    let ext = rel.extension()?.to_str()?;
+    if rel.file_stem()? != "page" || !PAGE_EXTENSIONS.contains(&ext) {
+        return None;
+    }
+    Some(ApiRoute {
+        method: "GET".to_string(),
+        path: app_router_path(rel.parent()?)?,
+        kind: "page".to_string(),
+        file: rel.to_path_buf(),
+        line: None,
+        handler: None,
+    })
+}
+
+/// Django `urls.py`: `path("items/<int:pk>/", views.detail)` entries (include() prefixes are not resolved)
+pub fn django_routes(rel: &Path, source: &str) -> Vec<ApiRoute> {
+    let mut routes = Vec::new();
+    for (idx, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(args) = trimmed
+            .strip_prefix("path(")
+            .or_else(|| trimmed.strip_prefix("re_path("))
+        else {
+            continue;
+        };
+        let Some(quote) = args.chars().next().filter(|c| *c == '"' || *c == '\'') else { continue; };
+        let Some(end) = args[1..].find(quote) else { continue; };
+        let pattern = &args[1..=end];
+        let rest = args[end + 2..].trim_start_matches([',', ' ']);
+        let handler: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+            .collect();
+        if handler == "include" || rest.starts_with("include(") {
+            continue;
+        }
+        let path = format!("/{}", pattern.trim_start_matches('^').trim_end_matches('$').trim_start_matches('/'));
+        routes.push(ApiRoute {
+            method: "ANY".to_string(),
+            path,
+            kind: "api".to_string(),
+            file: rel.to_path_buf(),
+            line: Some(idx + 1),
+            handler: (!handler.is_empty()).then_some(handler),
+        });
+    }
+    routes
+}
+
+/// Collect every route the project declares, sorted by path then method
+pub fn collect_routes(project_path: &Path, ignore_patterns: &[String], symbols: &[FileSymbols]) -> Vec<ApiRoute> {
+    let mut routes = routes_from_symbols(symbols);
+
+    for entry in project_walker(project_path, ignore_patterns, None).filter_map(|e| e.ok()) {
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let Ok(rel) = entry.path().strip_prefix(project_path) else { continue; };
+        if let Some(route) = pages_router_route(rel).or_else(|| app_router_page(rel)) {
+            routes.push(route);
+        } else if rel.file_name().map(|n| n == "urls.py").unwrap_or(false) {
+            if let Ok(source) = fs::read_to_string(entry.path()) {
+                routes.extend(django_routes(rel, &source));
+            }
+        }
+    }
+
+    routes.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.method.cmp(&b.method)));
+    routes.dedup_by(|a, b| a.path == b.path && a.method == b.method && a.file == b.file);
+    routes
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() >= 3)
+        .map(|w| {
+            let w = w.to_lowercase();
+            // Treat simple plurals as the same word ("invoices" ~ "invoice")
+            w.strip_suffix('s').filter(|s| s.len() >= 3).map(|s| s.to_string()).unwrap_or(w)
+        })
+        .collect()
+}
+
+/// Routes whose path segments share words with `text`, best matches first
+pub fn match_routes<'a>(routes: &'a [ApiRoute], text: &str) -> Vec<&'a ApiRoute> {
+    let text_words = words(text);
+    let mut scored: Vec<(usize, &ApiRoute)> = routes
+        .iter()
+        .map(|r| {
+            let path_words = words(&r.path);
+            let score = path_words
+                .iter()
+                .filter(|w| *w != "api" && text_words.contains(w))
+                .count();
+            (score, r)
+        })
+        .filter(|(score, _)| *score > 0)
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_pages_and_app_router() {
+        let api = pages_router_route(Path::new("src/pages/api/users/[id].ts")).unwrap();
+        assert_eq!((api.kind.as_str(), api.path.as_str()), ("api", "/api/users/[id]"));
+        let index = pages_router_route(Path::new("pages/index.tsx")).unwrap();
+        assert_eq!(index.path, "/");
+        assert!(pages_router_route(Path::new("pages/_app.tsx")).is_none());
+        assert!(pages_router_route(Path::new("lib/pages/x.ts")).is_none());
+
+        let page = app_router_page(Path::new("app/(dashboard)/settings/page.tsx")).unwrap();
+        assert_eq!((page.kind.as_str(), page.path.as_str()), ("page", "/settings"));
+    }
+
+    #[test]
+    fn test_django_urls() {
+        let src = r#"
+urlpatterns = [
+    path("invoices/<int:pk>/", views.invoice_detail, name="invoice"),
+    path('admin/', include(admin.site.urls)),
+    re_path(r'^legacy/$', legacy_view),
+]
+"#;
+        let routes = django_routes(Path::new("billing/urls.py"), src);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].path, "/invoices/<int:pk>/");
+        assert_eq!(routes[0].handler.as_deref(), Some("views.invoice_detail"));
+    }
+
+    #[test]
+    fn test_match_routes_by_path_words() {
+        let route = |path: &str| ApiRoute {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            kind: "api".to_string(),
+            file: PathBuf::from("x.ts"),
+            line: None,
+            handler: None,
+        };
+        let routes = vec![route("/api/invoices"), route("/api/users"), route("/health")];
+        let matched = match_routes(&routes, "Users can download each invoice as a PDF");
+        let paths: Vec<&str> = matched.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&"/api/invoices") && paths.contains(&"/api/users"));
+    }
+}
@@ -0,0 +1,475 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Language, Node, Parser};
+
+use super::walk::project_walker;
+
+/// Files larger than this are skipped to keep analysis fast
+const MAX_FILE_BYTES: u64 = 256 * 1024;
+
+/// Upper bound on files parsed per analysis run
+const MAX_FILES: usize = 2000;
+
+const HTTP_METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// An exported symbol (function, type, route, ...) found in a source file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CodeSymbol {
+    pub name: String,
+    /// "function", "class", "struct", "enum", "trait", "interface", "type", "const", "command", or "route"
+    pub kind: String,
+    /// 1-based line number
+    pub line: usize,
+}
+
+/// Exported symbols of a single file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSymbols {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    pub symbols: Vec<CodeSymbol>,
+}
+
+pub(crate) fn language_for(path: &Path) -> Option<Language> {
+    let ext = path.extension()?.to_str()?;
+    Some(match ext {
+        "rs" => tree_sitter_rust::LANGUAGE.into(),
+        "ts" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        "tsx" => tree_sitter_typescript::LANGUAGE_TSX.into(),
+        "js" | "jsx" | "mjs" | "cjs" => tree_sitter_javascript::LANGUAGE.into(),
+        "py" => tree_sitter_python::LANGUAGE.into(),
+        _ => return None,
+    })
+}
+
+pub(crate) fn text<'a>(node: Node, source: &'a [u8]) -> &'a str {
+    node.utf8_text(source).unwrap_or("")
+}
+
+fn name_of(node: Node, source: &[u8]) -> Option<String> {
+    node.child_by_field_name("name")
+        .map(|n| text(n, source).to_string())
+        .filter(|n| !n.is_empty())
+}
+
+fn symbol(name: String, kind: &str, node: Node) -> CodeSymbol {
+    CodeSymbol {
+        name,
+        kind: kind.to_string(),
+        line: node.start_position().row + 1,
+    }
+}
+
+/// Return the first string literal in an attribute/decorator/call, without quotes
+pub(crate) fn first_string_literal(node: Node, source: &[u8]) -> Option<String> {
+    let raw = text(node, source);
+    let start = raw.find(['"', '\'', '`'])?;
+    let quote = raw[start..].chars().next()?;
+    let rest = &raw[start + 1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_string())
+}
+
+// --- Rust -----------------------------------------------------------------
+
+fn has_visibility(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|n| n.kind() == "visibility_modifier");
+    found
+}
+
+fn rust_symbols(root: Node, source: &[u8], out: &mut Vec<CodeSymbol>) {
+    let mut cursor = root.walk();
+    let children: Vec<Node> = root.named_children(&mut cursor).collect();
+    let mut attributes: Vec<Node> = Vec::new();
+
+    for child in children {
+        if child.kind() == "attribute_item" {
+            attributes.push(child);
+            continue;
+        }
+        let is_pub = has_visibility(child);
+
+        match child.kind() {
+            "function_item" => {
+                let route = attributes.iter().find_map(|attr| {
+                    let raw = text(*attr, source);
+                    let method = HTTP_METHODS
+                        .iter()
+                        .find(|m| raw.starts_with(&format!("#[{}(", m)))?;
+                    let path = first_string_literal(*attr, source)?;
+                    Some(format!("{} {}", method.to_uppercase(), path))
+                });
+                let is_command = attributes
+                    .iter()
+                    .any(|a| text(*a, source).contains("tauri::command"));
+                if let Some(route) = route {
+                    out.push(symbol(route, "route", child));
+                } else if let Some(name) = name_of(child, source) {
+                    if is_command {
+                        out.push(symbol(name, "command", child));
+                    } else if is_pub {
+                        out.push(symbol(name, "function", child));
+                    }
+                }
+            }
+            "struct_item" | "enum_item" | "trait_item" | "type_item" | "const_item" if is_pub => {
+                let kind = match child.kind() {
+                    "struct_item" => "struct",
+                    "enum_item" => "enum",
+                    "trait_item" => "trait",
+                    "type_item" => "type",
+                    _ => "const",
+                };
+                if let Some(name) = name_of(child, source) {
+                    out.push(symbol(name, kind, child));
+                }
+            }
+            "mod_item" if is_pub => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    rust_symbols(body, source, out);
+                }
+            }
+            _ => {}
+        }
+        attributes.clear();
+    }
+}
+
+// --- TypeScript / JavaScript ----------------------------------------------
+
+/// URL path for a directory under a Next.js `app/` router, e.g. `app/api/meetings` -> `/api/meetings`
+pub(crate) fn app_router_path(dir: &Path) -> Option<String> {
+    let components: Vec<String> = dir
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let app_idx = components.iter().rposition(|c| c == "app")?;
+    let segments: Vec<&str> = components[app_idx + 1..]
+        .iter()
+        .map(|s| s.as_str())
+        // Route groups like `(marketing)` don't appear in the URL
+        .filter(|s| !(s.starts_with('(') && s.ends_with(')')))
+        .collect();
+    Some(format!("/{}", segments.join("/")))
+}
+
+/// URL path served by a Next.js app-router `route.ts`, e.g. `app/api/meetings/route.ts` -> `/api/meetings`
+fn next_route_path(path: &Path) -> Option<String> {
+    if path.file_stem()? != "route" {
+        return None;
+    }
+    app_router_path(path.parent()?)
+}
+
+/// Exported `GET`/`POST`/... handlers in a route file become routes
+fn route_name(route_path: Option<&str>, export_name: &str) -> Option<String> {
+    let route_path = route_path?;
+    HTTP_METHODS
+        .contains(&export_name.to_lowercase().as_str())
+        .then(|| format!("{} {}", export_name.to_uppercase(), route_path))
+}
+
+fn js_declaration(decl: Node, source: &[u8], route_path: Option<&str>, out: &mut Vec<CodeSymbol>) {
+    match decl.kind() {
+        "function_declaration" | "generator_function_declaration" => {
+            if let Some(name) = name_of(decl, source) {
+                if let Some(route) = route_name(route_path, &name) {
+                    out.push(symbol(route, "route", decl));
+                } else {
+                    out.push(symbol(name, "function", decl));
+                }
+            }
+        }
+        "class_declaration" | "abstract_class_declaration" => {
+            if let Some(name) = name_of(decl, source) {
+                out.push(symbol(name, "class", decl));
+            }
+        }
+        "interface_declaration" => {
+            if let Some(name) = name_of(decl, source) {
+                out.push(symbol(name, "interface", decl));
+            }
+        }
+        "type_alias_declaration" => {
+            if let Some(name) = name_of(decl, source) {
+                out.push(symbol(name, "type", decl));
+            }
+        }
+        "enum_declaration" => {
+            if let Some(name) = name_of(decl, source) {
+                out.push(symbol(name, "enum", decl));
+            }
+        }
+        "lexical_declaration" | "variable_declaration" => {
+            let mut cursor = decl.walk();
+            for declarator in decl.named_children(&mut cursor) {
+                if declarator.kind() != "variable_declarator" {
+                    continue;
+                }
+                let Some(name) = name_of(declarator, source) else { continue; };
+                let is_fn = declarator
+                    .child_by_field_name("value")
+                    .map(|v| matches!(v.kind(), "arrow_function" | "function_expression" | "function"))
+                    .unwrap_or(false);
+                if let Some(route) = route_name(route_path, &name) {
+                    out.push(symbol(route, "route", declarator));
+                    continue;
+                }
+                let kind = if is_fn { "function" } else { "const" };
+                out.push(symbol(name, kind, declarator));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Find Express-style `app.get("/path", ...)` registrations anywhere in the tree
+fn js_routes(node: Node, source: &[u8], out: &mut Vec<CodeSymbol>) {
+    if node.kind() == "call_expression" {
+        let method = node
+            .child_by_field_name("function")
+            .filter(|f| f.kind() == "member_expression")
+            .and_then(|f| f.child_by_field_name("property"))
+            .map(|p| text(p, source).to_lowercase());
+        if let Some(method) = method.filter(|m| HTTP_METHODS.contains(&m.as_str())) {
+            let path = node.child_by_field_name("arguments").and_then(|args| {
+                let mut c = args.walk();
+                let first = args.named_children(&mut c).next()?;
+                if matches!(first.kind(), "string" | "template_string") {
+                    first_string_literal(first, source)
+                } else {
+                    None
+                }
+            });
+            if let Some(path) = path.filter(|p| p.starts_with('/')) {
+                out.push(symbol(format!("{} {}", method.to_uppercase(), path), "route", node));
+            }
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        js_routes(child, source, out);
+    }
+}
+
+fn js_symbols(root: Node, source: &[u8], route_path: Option<&str>, out: &mut Vec<CodeSymbol>) {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        if child.kind() != "export_statement" {
+            continue;
+        }
+        if let Some(decl) = child.child_by_field_name("declaration") {
+            js_declaration(decl, source, route_path, out);
+        } else if text(child, source).starts_with("export default") {
+            let name = child
+                .child_by_field_name("value")
+                .and_then(|v| name_of(v, source))
+                .unwrap_or_else(|| "default".to_string());
+            out.push(symbol(name, "function", child));
+        }
+    }
+    js_routes(root, source, out);
+}
+
+// --- Python ---------------------------------------------------------------
+
+fn python_symbols(root: Node, source: &[u8], out: &mut Vec<CodeSymbol>) {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        let (definition, decorators) = if child.kind() == "decorated_definition" {
+            let mut c = child.walk();
+            let decorators: Vec<Node> = child
+                .named_children(&mut c)
+                .filter(|n| n.kind() == "decorator")
+                .collect();
+            match child.child_by_field_name("definition") {
+                Some(def) => (def, decorators),
+                None => continue,
+            }
+        } else {
+            (child, Vec::new())
+        };
+
+        let kind = match definition.kind() {
+            "function_definition" => "function",
+            "class_definition" => "class",
+            _ => continue,
+        };
+        let Some(name) = name_of(definition, source) else { continue; };
+
+        // FastAPI / Flask style: @app.get("/items"), @router.post(...), @app.route("/x")
+        let route = decorators.iter().find_map(|d| {
+            let raw = text(*d, source);
+            let call = raw.trim_start_matches('@');
+            let method = call.split('(').next()?.rsplit('.').next()?.to_lowercase();
+            if method != "route" && !HTTP_METHODS.contains(&method.as_str()) {
+                return None;
+            }
+            let path = first_string_literal(*d, source).filter(|p| p.starts_with('/'))?;
+            let verb = if method == "route" { "ANY".to_string() } else { method.to_uppercase() };
+            Some(format!("{} {}", verb, path))
+        });
+
+        // Routes point at the decorator line, where the path is declared
+        if let Some(route) = route {
+            out.push(symbol(route, "route", child));
+        } else if !name.starts_with('_') {
+            out.push(symbol(name, kind, definition));
+        }
+    }
+}
+
+/// Extract exported symbols and routes from a single source file
+pub fn extract_file_symbols(path: &Path, source: &str) -> Vec<CodeSymbol> {
+    let Some(language) = language_for(path) else { return Vec::new(); };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else { return Vec::new(); };
+
+    let bytes = source.as_bytes();
+    let root = tree.root_node();
+    let mut symbols = Vec::new();
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("rs") => rust_symbols(root, bytes, &mut symbols),
+        Some("py") => python_symbols(root, bytes, &mut symbols),
+        _ => {
+            let route_path = next_route_path(path);
+            js_symbols(root, bytes, route_path.as_deref(), &mut symbols)
+        }
+    }
+    symbols
+}
+
+/// Walk the project and extract symbols for every supported, non-ignored source file
+pub fn extract_project_symbols(project_path: &Path, ignore_patterns: &[String]) -> Vec<FileSymbols> {
+    let mut files = Vec::new();
+    let walker = project_walker(project_path, ignore_patterns, None).filter_map(|e| e.ok());
+
+    for entry in walker {
+        if files.len() >= MAX_FILES {
+            log::warn!("Symbol extraction stopped after {} files", MAX_FILES);
+            break;
+        }
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) || language_for(entry.path()).is_none() {
+            continue;
+        }
+        if entry.metadata().map(|m| m.len() > MAX_FILE_BYTES).unwrap_or(true) {
+            continue;
+        }
+        let Ok(source) = fs::read_to_string(entry.path()) else { continue; };
+        let symbols = extract_file_symbols(entry.path(), &source);
+        if symbols.is_empty() {
+            continue;
+        }
+        let rel = entry
+            .path()
+            .strip_prefix(project_path)
+            .unwrap_or(entry.path())
+            .to_path_buf();
+        files.push(FileSymbols { path: rel, symbols });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+/// Render a compact per-file outline for LLM prompts, capped at `max_lines` files
+pub fn symbol_outline(files: &[FileSymbols], max_lines: usize) -> String {
+    let mut outline = String::new();
+    for file in files.iter().take(max_lines) {
+        let names: Vec<String> = file
+            .symbols
+            .iter()
+            .map(|s| match s.kind.as_str() {
+                "route" => s.name.clone(),
+                kind => format!("{} {}", kind, s.name),
+            })
+            .collect();
+        let _ = writeln!(outline, "- {}: {}", file.path.display(), names.join(", "));
+    }
+    if files.len() > max_lines {
+        let _ = writeln!(outline, "- ... {} more files", files.len() - max_lines);
+    }
+    outline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(symbols: &[CodeSymbol]) -> Vec<(&str, &str)> {
+        symbols.iter().map(|s| (s.kind.as_str(), s.name.as_str())).collect()
+    }
+
+    #[test]
+    fn test_rust_exports_commands_and_routes() {
+        let src = r#"
+pub struct Config;
+struct Private;
+#[tauri::command]
+fn start_meeting() {}
+#[get("/health")]
+async fn health() {}
+pub mod api { pub fn list() {} }
+"#;
+        let symbols = extract_file_symbols(Path::new("lib.rs"), src);
+        assert_eq!(
+            names(&symbols),
+            vec![
+                ("struct", "Config"),
+                ("command", "start_meeting"),
+                ("route", "GET /health"),
+                ("function", "list"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typescript_exports_and_express_routes() {
+        let src = r#"
+export function useMeeting() {}
+export class Recorder {}
+export const API_URL = "x";
+function internal() {}
+app.post("/meetings", handler);
+"#;
+        let symbols = extract_file_symbols(Path::new("src/app.ts"), src);
+        assert_eq!(
+            names(&symbols),
+            vec![
+                ("function", "useMeeting"),
+                ("class", "Recorder"),
+                ("const", "API_URL"),
+                ("route", "POST /meetings"),
+            ]
+        );
+
+        let route = extract_file_symbols(Path::new("app/api/route.ts"), "export async function GET() {}");
+        assert_eq!(names(&route), vec![("route", "GET /api")]);
+    }
+
+    #[test]
+    fn test_python_public_defs_and_decorated_routes() {
+        let src = r#"
+@app.get("/items")
+def list_items():
+    pass
+
+class Item:
+    pass
+
+def _helper():
+    pass
+"#;
+        let symbols = extract_file_symbols(Path::new("main.py"), src);
+        assert_eq!(names(&symbols), vec![("route", "GET /items"), ("class", "Item")]);
+        assert_eq!(symbols[0].line, 2);
+    }
+}
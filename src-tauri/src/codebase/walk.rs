@@ -0,0 +1,104 @@
+use ignore::overrides::OverrideBuilder;
+use ignore::{Walk, WalkBuilder};
+use std::path::Path;
+
+/// Directories skipped even when a project has no ignore files
+const DEFAULT_IGNORED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "dist",
+    "build",
+    ".git",
+    ".next",
+    ".vercel",
+    "__pycache__",
+    ".pytest_cache",
+    "venv",
+    ".venv",
+];
+
+/// Walk a project honoring `.gitignore` (including nested ones), `.git/info/exclude`,
+/// `.ignore`, the user's global git excludes, and `extra_patterns` (gitignore syntax).
+pub fn project_walker(root: &Path, extra_patterns: &[String], max_depth: Option<usize>) -> Walk {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(false)
+        .git_ignore(true)
+        .git_exclude(true)
+        .git_global(true)
+        .require_git(false)
+        .max_depth(max_depth)
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !DEFAULT_IGNORED_DIRS
+                    .iter()
+                    .any(|d| e.file_name().to_string_lossy() == *d)
+        });
+
+    if !extra_patterns.is_empty() {
+        let mut overrides = OverrideBuilder::new(root);
+        for pattern in extra_patterns {
+            // Overrides are whitelists by default; `!` turns a glob into an exclusion
+            if let Err(e) = overrides.add(&format!("!{}", pattern.trim_start_matches('!'))) {
+                log::warn!("Ignoring invalid codebase ignore pattern '{}': {}", pattern, e);
+            }
+        }
+        match overrides.build() {
+            Ok(o) => {
+                builder.overrides(o);
+            }
+            Err(e) => log::warn!("Failed to build codebase ignore patterns: {}", e),
+        }
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn walked_files(root: &Path, extra: &[String]) -> Vec<String> {
+        let mut files: Vec<String> = project_walker(root, extra, None)
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+            .map(|e| {
+                e.path()
+                    .strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_respects_nested_gitignore_and_extra_patterns() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for file in [
+            "src/main.rs",
+            "third_party/vendored/lib.js",
+            "web/out/bundle.js",
+            "web/app.ts",
+            "node_modules/pkg/index.js",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x").unwrap();
+        }
+        fs::write(root.join(".gitignore"), "third_party/\n").unwrap();
+        fs::write(root.join("web/.gitignore"), "out/\n").unwrap();
+
+        let files = walked_files(root, &[]);
+        assert_eq!(files, vec![".gitignore", "src/main.rs", "web/.gitignore", "web/app.ts"]);
+
+        let files = walked_files(root, &["*.ts".to_string()]);
+        assert!(!files.contains(&"web/app.ts".to_string()));
+        assert!(files.contains(&"src/main.rs".to_string()));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::audio_feedback;
 use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
+use crate::commands::AppError;
 use crate::managers::audio::{AudioRecordingManager, AudioSource, MicrophoneMode};
 use crate::settings::{get_settings, write_settings};
 use serde::{Deserialize, Serialize};
@@ -37,7 +38,7 @@ pub struct AudioDevice {
 }
 
 #[tauri::command]
-pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), String> {
+pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), AppError> {
     // Update settings
     let mut settings = get_settings(&app);
     settings.always_on_microphone = always_on;
@@ -52,17 +53,17 @@ pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), Str
     };
 
     rm.update_mode(new_mode)
-        .map_err(|e| format!("Failed to update microphone mode: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to update microphone mode: {}", e)))
 }
 
 #[tauri::command]
-pub fn get_microphone_mode(app: AppHandle) -> Result<bool, String> {
+pub fn get_microphone_mode(app: AppHandle) -> Result<bool, AppError> {
     let settings = get_settings(&app);
     Ok(settings.always_on_microphone)
 }
 
 #[tauri::command]
-pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
+pub fn get_available_microphones() -> Result<Vec<AudioDevice>, AppError> {
     let devices =
         list_input_devices().map_err(|e| format!("Failed to list audio devices: {}", e))?;
 
@@ -82,7 +83,7 @@ pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
 }
 
 #[tauri::command]
-pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
+pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
     settings.selected_microphone = if device_name == "default" {
         None
@@ -100,7 +101,7 @@ pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<()
 }
 
 #[tauri::command]
-pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
+pub fn get_selected_microphone(app: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app);
     Ok(settings
         .selected_microphone
@@ -108,7 +109,7 @@ pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
 }
 
 #[tauri::command]
-pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
+pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, AppError> {
     let devices =
         list_output_devices().map_err(|e| format!("Failed to list output devices: {}", e))?;
 
@@ -128,7 +129,7 @@ pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
 }
 
 #[tauri::command]
-pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result<(), String> {
+pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
     settings.selected_output_device = if device_name == "default" {
         None
@@ -140,7 +141,7 @@ pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result
 }
 
 #[tauri::command]
-pub fn get_selected_output_device(app: AppHandle) -> Result<String, String> {
+pub fn get_selected_output_device(app: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app);
     Ok(settings
         .selected_output_device
@@ -168,33 +169,44 @@ pub fn play_test_sound(app: AppHandle, sound_type: String) {
 pub fn set_system_audio_source(
     app: AppHandle,
     device_name: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
 
     rm.set_audio_source(AudioSource::SystemAudio(device_name))
-        .map_err(|e| format!("Failed to set system audio source: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to set system audio source: {}", e)))
 }
 
 #[tauri::command]
-pub fn set_microphone_source(app: AppHandle) -> Result<(), String> {
+pub fn set_microphone_source(app: AppHandle) -> Result<(), AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
 
     rm.set_audio_source(AudioSource::Microphone)
-        .map_err(|e| format!("Failed to set microphone source: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to set microphone source: {}", e)))
 }
 
+/// Record the microphone and a system audio device together; meeting segments are
+/// labelled "Me" and "Remote"
 #[tauri::command]
-pub fn get_current_audio_source(app: AppHandle) -> Result<String, String> {
+pub fn set_dual_audio_source(app: AppHandle, device_name: String) -> Result<(), AppError> {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+
+    rm.set_audio_source(AudioSource::Dual(device_name))
+        .map_err(|e| AppError::Internal(format!("Failed to start dual capture: {}", e)))
+}
+
+#[tauri::command]
+pub fn get_current_audio_source(app: AppHandle) -> Result<String, AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
 
     match rm.get_audio_source() {
         AudioSource::Microphone => Ok("microphone".to_string()),
         AudioSource::SystemAudio(device) => Ok(format!("system:{}", device)),
+        AudioSource::Dual(device) => Ok(format!("dual:{}", device)),
     }
 }
 
 #[tauri::command]
-pub fn get_system_audio_buffer_size(app: AppHandle) -> Result<usize, String> {
+pub fn get_system_audio_buffer_size(app: AppHandle) -> Result<usize, AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
     Ok(rm.get_system_audio_buffer_size())
 }
@@ -203,14 +215,14 @@ pub fn get_system_audio_buffer_size(app: AppHandle) -> Result<usize, String> {
 pub fn save_system_audio_buffer_to_wav(
     app: AppHandle,
     filename: String,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
     rm.save_system_audio_buffer_to_wav(&filename)
-        .map_err(|e| format!("Failed to save WAV: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to save WAV: {}", e)))
 }
 
 #[tauri::command]
-pub fn clear_system_audio_buffer(app: AppHandle) -> Result<(), String> {
+pub fn clear_system_audio_buffer(app: AppHandle) -> Result<(), AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
     rm.clear_system_audio_buffer();
     Ok(())
@@ -237,10 +249,13 @@ pub struct AudioMetrics {
     queue_queued: i64,
     queue_processing: i64,
     queue_backlog_seconds: f32,
+    queue_dead_letters: i64,
+    queue_workers: usize,
+    queue_paused: bool,
 }
 
 #[tauri::command]
-pub fn get_audio_metrics(app: AppHandle) -> Result<AudioMetrics, String> {
+pub fn get_audio_metrics(app: AppHandle) -> Result<AudioMetrics, AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
     let size = rm.get_system_audio_buffer_size();
     let cap = rm.get_system_audio_buffer_capacity();
@@ -258,12 +273,13 @@ pub fn get_audio_metrics(app: AppHandle) -> Result<AudioMetrics, String> {
     let is_capturing = rm.is_system_audio_capturing();
     let backlog_secs_estimate = size as f32 / 16_000.0; // mono 16kHz
     // Queue metrics
-    let (q_queued, q_processing, q_backlog_secs) = if let Some(q) = app.try_state::<Arc<crate::queue::Queue>>() {
-        match (q.counts(), q.backlog_seconds()) {
-            (Ok((a,b,_)), Ok(backlog)) => (a,b,backlog),
-            _ => (0,0,0.0)
+    let (q_queued, q_processing, q_dead, q_backlog_secs) = if let Some(q) = app.try_state::<Arc<crate::queue::Queue>>() {
+        match (q.counts(), q.total_backlog_seconds()) {
+            (Ok((a,b,c)), Ok(backlog)) => (a,b,c,backlog),
+            _ => (0,0,0,0.0)
         }
-    } else { (0,0,0.0) };
+    } else { (0,0,0,0.0) };
+    let pool = app.try_state::<Arc<crate::workers::asr_worker::WorkerPools>>();
 
     Ok(AudioMetrics {
         buffer_size_samples: size,
@@ -284,11 +300,14 @@ pub fn get_audio_metrics(app: AppHandle) -> Result<AudioMetrics, String> {
         queue_queued: q_queued,
         queue_processing: q_processing,
         queue_backlog_seconds: q_backlog_secs,
+        queue_dead_letters: q_dead,
+        queue_workers: pool.as_ref().map(|pool| pool.worker_count()).unwrap_or(0),
+        queue_paused: pool.as_ref().map(|pool| pool.is_paused()).unwrap_or(false),
     })
 }
 
 #[tauri::command]
-pub fn get_audio_errors(app: AppHandle) -> Result<Vec<String>, String> {
+pub fn get_audio_errors(app: AppHandle) -> Result<Vec<String>, AppError> {
     let rm = app.state::<Arc<AudioRecordingManager>>();
     Ok(rm.get_recent_audio_errors())
 }
@@ -1,21 +1,30 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use tauri::{AppHandle, State};
-use crate::managers::meeting::MeetingManager;
+use tauri::{AppHandle, Manager, State};
+use crate::automation::agent_logs::{self, AgentLogEntry};
+use crate::automation::command_suggestions::{self, ProjectTooling, SuggestedCommand};
+use crate::automation::guardrails::{self, AgentGuardrails, GuardrailReport};
+use crate::automation::repo_config;
+use crate::codebase::ApiRoute;
+use crate::commands::AppError;
+use crate::managers::meeting::{MeetingManager, TranscriptSegment};
+use crate::project::claude_hooks::{self, AgentActivity};
+use crate::storage::db::MeetingDb;
 
 #[tauri::command]
 pub async fn trigger_meeting_command_now(
     app: AppHandle,
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(false); };
     if path.is_empty() { return Ok(false); }
     crate::automation::claude_trigger::trigger_meeting_update(&app, &path, &meeting_id, 0)
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
@@ -23,74 +32,342 @@ pub async fn open_meeting_terminal(
     app: AppHandle,
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(()); };
     if path.is_empty() { return Ok(()); }
-    crate::automation::claude_trigger::open_project_in_terminal(&app, &path).map_err(|e| e.to_string())
+    crate::automation::claude_trigger::open_project_in_terminal(&app, &path, &meeting_id)
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn open_meeting_vscode(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(()); };
     if path.is_empty() { return Ok(()); }
-    crate::automation::claude_trigger::open_project_in_vscode(&path).map_err(|e| e.to_string())
+    crate::automation::claude_trigger::open_project_in_vscode(&path)
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn open_meeting_cursor(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(()); };
     if path.is_empty() { return Ok(()); }
-    crate::automation::claude_trigger::open_project_in_cursor(&path).map_err(|e| e.to_string())
+    crate::automation::claude_trigger::open_project_in_cursor(&path)
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn open_meeting_vscode_with_meeting(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(()); };
     if path.is_empty() { return Ok(()); }
     // Ensure the correct project window is opened/focused first, then run the meeting command
     // Open project first; then run meeting in the correct workspace
     let _ = crate::automation::claude_trigger::open_project_in_vscode(&path);
-    crate::automation::claude_trigger::open_vscode_with_meeting(&path).map_err(|e| e.to_string())
+    crate::automation::claude_trigger::open_vscode_with_meeting(&path)
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn open_meeting_cursor_with_meeting(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let Some(path) = meeting.project_path.clone() else { return Ok(()); };
     if path.is_empty() { return Ok(()); }
     // Ensure the correct project window is opened/focused first, then run the meeting command
     // Open project first; then run meeting in the correct workspace
     let _ = crate::automation::claude_trigger::open_project_in_cursor(&path);
-    crate::automation::claude_trigger::open_cursor_with_meeting(&path).map_err(|e| e.to_string())
+    crate::automation::claude_trigger::open_cursor_with_meeting(&path)
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Evaluate the project's pending changes against the agent guardrails
+#[tauri::command]
+pub async fn check_agent_guardrails(
+    app: AppHandle,
+    meeting_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<GuardrailReport, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone() else { return Ok(GuardrailReport::default()); };
+    if path.is_empty() { return Ok(GuardrailReport::default()); }
+    let limits = AgentGuardrails::from_settings(&repo_config::effective_settings(&app, &path));
+    guardrails::evaluate_working_tree(&path, &limits)
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// List captured agent terminal sessions for a meeting's project
+#[tauri::command]
+pub async fn get_agent_logs(
+    meeting_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<Vec<AgentLogEntry>, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone() else { return Ok(Vec::new()); };
+    if path.is_empty() { return Ok(Vec::new()); }
+    Ok(agent_logs::list_sessions(&path))
+}
+
+/// Read the captured output of a single agent session
+#[tauri::command]
+pub async fn read_agent_log(
+    meeting_id: String,
+    log_path: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<String, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone() else {
+        return Err(AppError::NotConfigured("Meeting has no project path".to_string()));
+    };
+    agent_logs::read_log(&path, &log_path).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// List agent tool use (file edits and commands) reported by the Claude Code hooks
+#[tauri::command]
+pub async fn get_agent_activity(
+    meeting_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<Vec<AgentActivity>, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone() else { return Ok(Vec::new()); };
+    if path.is_empty() { return Ok(Vec::new()); }
+    Ok(claude_hooks::read_activity(&path, 0).0)
+}
+
+/// Transcript and project path of a saved meeting, for meetings that are no longer active
+fn saved_transcript(
+    db: &MeetingDb,
+    meeting_id: &str,
+) -> Result<(Vec<TranscriptSegment>, Option<String>), AppError> {
+    let meeting = db
+        .get_meeting(meeting_id)
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let segments = db.get_segments(meeting_id).map_err(AppError::from)?;
+    Ok((segments, meeting.project_path))
+}
+
+/// Suggest shell commands for "run X / deploy Y" requests spoken in the meeting.
+/// Ended meetings are read from their saved transcript.
+/// Suggestions are informational only and are never executed.
+#[tauri::command]
+pub async fn get_suggested_commands(
+    meeting_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<SuggestedCommand>, AppError> {
+    let (segments, project_path) = match meeting_manager.get_meeting(&meeting_id).await {
+        Ok(meeting) => (meeting.transcript_segments, meeting.project_path),
+        Err(_) => saved_transcript(&db, &meeting_id)?,
+    };
+    let tooling = match project_path.as_deref() {
+        Some(path) if !path.is_empty() => ProjectTooling::detect(std::path::Path::new(path)),
+        _ => ProjectTooling::default(),
+    };
+    Ok(command_suggestions::suggest_commands(&segments, &tooling))
+}
+
+/// Launch the agent on a single PRD user story or requirement from the meeting's latest PRD.
+/// Returns the prompt file path relative to the project root.
+#[tauri::command]
+pub async fn implement_prd_item(
+    app: AppHandle,
+    meeting_id: String,
+    item_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<String, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone().filter(|p| !p.is_empty()) else {
+        return Err(AppError::NotConfigured("Meeting has no project path".to_string()));
+    };
+
+    let latest = crate::document_generation::get_all_versions(&meeting_id)
+        .map_err(|e| format!("Failed to get PRD versions: {}", e))?
+        .pop()
+        .ok_or_else(|| {
+            AppError::NotConfigured("No PRD has been generated for this meeting".to_string())
+        })?;
+    let (_, content, _) = crate::document_generation::load_prd_version(&meeting_id, latest.version)
+        .map_err(|e| format!("Failed to load PRD version: {}", e))?;
+
+    let manifest = crate::codebase::load_manifest_from_state(std::path::Path::new(&path));
+    let mut prompt = crate::automation::prd_agent::compose_item_prompt(
+        &meeting.name,
+        &content,
+        &item_id,
+        manifest.as_ref(),
+    )
+    .map_err(|e| e.to_string())?;
+    let tickets = crate::integrations::jira::prompt_section(&prompt);
+    prompt.push_str(&tickets);
+
+    let snippets = crate::codebase::embeddings::retrieve(&app, std::path::Path::new(&path), &prompt, 5);
+    if !snippets.is_empty() {
+        prompt.push_str("\n## Relevant code\n\n");
+        prompt.push_str(&crate::codebase::embeddings::format_snippets(&snippets));
+    }
+
+    crate::automation::claude_trigger::trigger_agent_prompt(
+        &path,
+        &meeting_id,
+        &format!("prd-{}", item_id),
+        &prompt,
+    )
+    .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Map each PRD user story and requirement to existing HTTP endpoints in the meeting's project
+#[tauri::command]
+pub async fn map_prd_to_routes(
+    meeting_id: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<BTreeMap<String, Vec<ApiRoute>>, AppError> {
+    let meeting = meeting_manager
+        .get_meeting(&meeting_id)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let Some(path) = meeting.project_path.clone().filter(|p| !p.is_empty()) else {
+        return Err(AppError::NotConfigured("Meeting has no project path".to_string()));
+    };
+    let manifest = crate::codebase::load_manifest_from_state(std::path::Path::new(&path))
+        .ok_or_else(|| AppError::NotConfigured("Codebase has not been analyzed yet".to_string()))?;
+
+    let latest = crate::document_generation::get_all_versions(&meeting_id)
+        .map_err(|e| format!("Failed to get PRD versions: {}", e))?
+        .pop()
+        .ok_or_else(|| {
+            AppError::NotConfigured("No PRD has been generated for this meeting".to_string())
+        })?;
+    let (_, content, _) = crate::document_generation::load_prd_version(&meeting_id, latest.version)
+        .map_err(|e| format!("Failed to load PRD version: {}", e))?;
+
+    Ok(crate::automation::prd_agent::map_items_to_routes(
+        &content,
+        &manifest.routes,
+    ))
+}
+
+/// Compile the AppleScript library into ~/Library/Script Libraries so Script Editor and
+/// Shortcuts can `tell script "MeetingCoder"`. Returns the installed path.
+#[tauri::command]
+pub async fn install_automation_script(app: AppHandle) -> Result<String, AppError> {
+    if !cfg!(target_os = "macos") {
+        return Err(AppError::InvalidInput(
+            "AppleScript automation is only available on macOS".to_string(),
+        ));
+    }
+    let source = app
+        .path()
+        .resolve(
+            "resources/automation/MeetingCoder.applescript",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .map_err(|e| e.to_string())?;
+    let libraries = dirs::home_dir()
+        .ok_or_else(|| "Could not determine home directory".to_string())?
+        .join("Library/Script Libraries");
+    std::fs::create_dir_all(&libraries).map_err(|e| e.to_string())?;
+    let target = libraries.join("MeetingCoder.scpt");
+
+    let output = std::process::Command::new("osacompile")
+        .arg("-o")
+        .arg(&target)
+        .arg(&source)
+        .output()
+        .map_err(|e| format!("Failed to run osacompile: {}", e))?;
+    if !output.status.success() {
+        return Err(AppError::Internal(format!(
+            "osacompile failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    log::info!("Installed AppleScript library to {:?}", target);
+    Ok(target.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::meeting::{MeetingSession, MeetingStatus};
+    use std::time::SystemTime;
+
+    #[test]
+    fn test_suggestions_for_ended_meeting_come_from_saved_transcript() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = MeetingDb::open_at(dir.path().join("meetings.db")).unwrap();
+        let start = SystemTime::now();
+        let meeting = MeetingSession {
+            id: "m-ended".to_string(),
+            name: "Standup".to_string(),
+            start_time: start,
+            end_time: Some(start),
+            status: MeetingStatus::Completed,
+            participants: vec!["Speaker 1".to_string()],
+            project_path: Some("/tmp/project".to_string()),
+            transcript_segments: vec![TranscriptSegment {
+                speaker: "Speaker 1".to_string(),
+                start_time: 0.0,
+                end_time: 2.0,
+                text: "Just run cargo fmt.".to_string(),
+                confidence: 0.9,
+                timestamp: start,
+                words: Vec::new(),
+                translated_text: None,
+            }],
+            translation_language: None,
+            cloud_asr: false,
+        };
+        db.save_meeting(&meeting, None).unwrap();
+
+        let (segments, project_path) = saved_transcript(&db, "m-ended").unwrap();
+        assert_eq!(project_path.as_deref(), Some("/tmp/project"));
+        let tooling = ProjectTooling { cargo: true, ..Default::default() };
+        let suggestions = command_suggestions::suggest_commands(&segments, &tooling);
+        assert_eq!(suggestions[0].command, "cargo fmt");
+
+        assert!(matches!(saved_transcript(&db, "missing"), Err(AppError::NotFound(_))));
+    }
 }
@@ -0,0 +1,208 @@
+use crate::commands::AppError;
+use crate::integrations::{cloud_sync, github, local_api};
+use crate::managers::meeting::MeetingManager;
+use crate::settings;
+use crate::settings_transfer::{self, SettingsExport};
+use crate::settings_update;
+use crate::storage::backup::{self, BackupManifest, BackupSources, RestoreReport, SecretReference};
+use crate::storage::transcript::TranscriptStorage;
+use crate::summarization::llm;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+fn backup_sources(app: &AppHandle) -> Result<BackupSources, String> {
+    let prd_root = dirs::home_dir()
+        .ok_or_else(|| "Could not determine home directory".to_string())?
+        .join(".handy")
+        .join("meetings");
+    Ok(BackupSources {
+        app_data_dir: app.path().app_data_dir().map_err(|e| e.to_string())?,
+        meetings_dir: TranscriptStorage::default_path().map_err(|e| e.to_string())?,
+        prds_dir: prd_root,
+    })
+}
+
+/// Keychain entries the app uses, with whether each is currently set
+fn secret_references() -> Vec<SecretReference> {
+    vec![
+        SecretReference {
+            name: "Claude API key".to_string(),
+            service: llm::KEYCHAIN_SERVICE.to_string(),
+            account: llm::KEYCHAIN_ACCOUNT.to_string(),
+            present: llm::has_api_key(),
+        },
+        SecretReference {
+            name: "OpenAI-compatible API key".to_string(),
+            service: llm::KEYCHAIN_SERVICE.to_string(),
+            account: llm::OPENAI_KEYCHAIN_ACCOUNT.to_string(),
+            present: llm::has_openai_api_key(),
+        },
+        SecretReference {
+            name: "GitHub token".to_string(),
+            service: github::KEYCHAIN_SERVICE.to_string(),
+            account: github::KEYCHAIN_ACCOUNT.to_string(),
+            present: github::get_github_token().is_ok(),
+        },
+        SecretReference {
+            name: "Cloud sync credentials".to_string(),
+            service: cloud_sync::KEYCHAIN_SERVICE.to_string(),
+            account: cloud_sync::KEYCHAIN_ACCOUNT.to_string(),
+            present: cloud_sync::has_credentials(),
+        },
+        SecretReference {
+            name: "Local API token".to_string(),
+            service: local_api::KEYCHAIN_SERVICE.to_string(),
+            account: local_api::KEYCHAIN_ACCOUNT.to_string(),
+            present: local_api::has_token(),
+        },
+    ]
+}
+
+/// Write a versioned backup archive of settings, history, meetings, and PRDs to `target_path`
+#[tauri::command]
+pub async fn create_backup(
+    app: AppHandle,
+    target_path: String,
+) -> Result<BackupManifest, AppError> {
+    let sources = backup_sources(&app)?;
+    let version = app.package_info().version.to_string();
+    let target = PathBuf::from(target_path);
+    tauri::async_runtime::spawn_blocking(move || {
+        backup::create_backup(&target, &sources, &version, secret_references())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| AppError::Internal(format!("Backup failed: {}", e)))
+}
+
+/// Read a backup's manifest without restoring it
+#[tauri::command]
+pub fn inspect_backup(archive_path: String) -> Result<BackupManifest, AppError> {
+    backup::read_backup_manifest(&PathBuf::from(archive_path))
+        .map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+/// Restore a backup over the current data. Refused while a meeting is running. Settings
+/// and databases are staged and only take effect after the app is restarted.
+#[tauri::command]
+pub async fn restore_backup(
+    app: AppHandle,
+    archive_path: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<RestoreReport, AppError> {
+    if !meeting_manager.get_active_meetings().await.is_empty() {
+        return Err(AppError::Busy(
+            "End active meetings before restoring a backup".to_string(),
+        ));
+    }
+    let sources = backup_sources(&app)?;
+    let archive = PathBuf::from(archive_path);
+    let report =
+        tauri::async_runtime::spawn_blocking(move || backup::restore_backup(&archive, &sources))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("Restore failed: {}", e))?;
+    log::info!("Restored {} files from backup", report.files_restored);
+    Ok(report)
+}
+
+/// Write the current settings to a JSON file for use on another machine. Keychain
+/// secrets are not included; the file lists which ones to set up again.
+#[tauri::command]
+pub fn export_settings(app: AppHandle, target_path: String) -> Result<SettingsExport, AppError> {
+    let secrets = secret_references()
+        .into_iter()
+        .filter(|s| s.present)
+        .map(|s| s.name)
+        .collect();
+    let export = settings_transfer::build_export(
+        &settings::get_settings(&app),
+        &app.package_info().version.to_string(),
+        secrets,
+    )?;
+    let json = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+    std::fs::write(&target_path, json)
+        .map_err(|e| AppError::Io(format!("Failed to write settings: {}", e)))?;
+    log::info!(
+        "Exported {} settings to {}",
+        export.settings.len(),
+        target_path
+    );
+    Ok(export)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsImportReport {
+    /// Schema the file was written with, when it had to be migrated
+    pub migrated_from: Option<u32>,
+    pub changed: Vec<String>,
+    /// Fields this version doesn't know, which were ignored
+    pub unknown_fields: Vec<String>,
+    /// Machine-specific settings that were left as they are
+    pub skipped: Vec<String>,
+    pub binding_errors: Vec<String>,
+    /// Keychain entries the exporting machine had, to enter again here
+    pub secrets_to_reenter: Vec<String>,
+}
+
+/// Apply a settings file from `export_settings` (or a copy of `settings_store.json`).
+/// Nothing changes if any value is invalid.
+#[tauri::command]
+pub fn import_settings(
+    app: AppHandle,
+    source_path: String,
+) -> Result<SettingsImportReport, AppError> {
+    let json = std::fs::read_to_string(&source_path)
+        .map_err(|e| AppError::Io(format!("Failed to read {}: {}", source_path, e)))?;
+    let parsed = settings_transfer::parse_export(&json).map_err(AppError::InvalidInput)?;
+    let current = settings::get_settings(&app);
+    let plan = settings_transfer::plan_import(&parsed.settings, &current);
+    let (updated, changes) =
+        settings_update::apply_patch(&current, &plan.patch).map_err(AppError::InvalidInput)?;
+    settings_update::commit(&app, updated, &changes);
+    let mut changed: Vec<String> = changes.into_iter().map(|c| c.setting).collect();
+
+    if let Some(always_on) = plan.always_on_microphone {
+        if always_on != current.always_on_microphone {
+            crate::commands::audio::update_microphone_mode(app.clone(), always_on)?;
+            changed.push("always_on_microphone".to_string());
+        }
+    }
+    let mut binding_errors = Vec::new();
+    for (id, binding) in plan.bindings {
+        match crate::shortcut::change_binding(app.clone(), id.clone(), binding) {
+            Ok(response) if response.success => changed.push(format!("bindings.{}", id)),
+            Ok(response) => {
+                binding_errors.push(format!("{}: {}", id, response.error.unwrap_or_default()))
+            }
+            Err(e) => binding_errors.push(format!("{}: {}", id, e)),
+        }
+    }
+
+    let present: Vec<String> = secret_references()
+        .into_iter()
+        .filter(|s| s.present)
+        .map(|s| s.name)
+        .collect();
+    let secrets_to_reenter = parsed
+        .secrets
+        .into_iter()
+        .filter(|name| !present.contains(name))
+        .collect();
+    log::info!(
+        "Imported settings from {}: {} changed, {} unknown fields ignored",
+        source_path,
+        changed.len(),
+        plan.unknown_fields.len()
+    );
+    Ok(SettingsImportReport {
+        migrated_from: parsed.migrated_from,
+        changed,
+        unknown_fields: plan.unknown_fields,
+        skipped: plan.skipped,
+        binding_errors,
+        secrets_to_reenter,
+    })
+}
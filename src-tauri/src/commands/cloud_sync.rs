@@ -0,0 +1,41 @@
+use crate::commands::AppError;
+use crate::integrations::cloud_sync::{self, CloudCredentials, MeetingSyncStatus};
+use tauri::AppHandle;
+
+/// Store cloud sync credentials (S3 access key/secret or WebDAV username/password)
+#[tauri::command]
+pub async fn set_cloud_sync_credentials(
+    username: String,
+    secret: String,
+) -> Result<bool, AppError> {
+    cloud_sync::store_credentials(&CloudCredentials { username, secret })
+        .map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Remove cloud sync credentials
+#[tauri::command]
+pub async fn remove_cloud_sync_credentials() -> Result<bool, AppError> {
+    cloud_sync::clear_credentials().map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Sync status for one meeting, or every meeting that has been synced
+#[tauri::command]
+pub async fn get_cloud_sync_status(
+    app: AppHandle,
+    meeting_id: Option<String>,
+) -> Result<Vec<MeetingSyncStatus>, AppError> {
+    Ok(cloud_sync::get_status(&app, meeting_id.as_deref()))
+}
+
+/// Re-run a failed or partial sync; already uploaded files are skipped
+#[tauri::command]
+pub async fn retry_cloud_sync(
+    app: AppHandle,
+    meeting_id: String,
+) -> Result<MeetingSyncStatus, AppError> {
+    cloud_sync::retry_meeting(&app, &meeting_id)
+        .await
+        .map_err(AppError::from)
+}
@@ -1,22 +1,30 @@
-use crate::codebase::{analyze_codebase, save_manifest_to_state, CodebaseManifest};
+use crate::codebase::embeddings::{self, IndexStats, RetrievedChunk};
+use crate::codebase::{
+    analyze_codebase, load_manifest_from_state, save_manifest_to_state, ClaudeignorePreview,
+    CodebaseManifest,
+};
+use crate::commands::AppError;
 use std::path::PathBuf;
+use tauri::AppHandle;
 
 /// Analyzes a codebase and returns a manifest
 #[tauri::command]
-pub async fn analyze_project_codebase(project_path: String) -> Result<CodebaseManifest, String> {
+pub async fn analyze_project_codebase(app: AppHandle, project_path: String) -> Result<CodebaseManifest, AppError> {
     let path = PathBuf::from(project_path);
+    let ignore_patterns = crate::settings::get_settings(&app).codebase_ignore_patterns;
 
-    analyze_codebase(&path)
+    analyze_codebase(&path, &ignore_patterns)
         .await
-        .map_err(|e| format!("Failed to analyze codebase: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to analyze codebase: {}", e)))
 }
 
 /// Analyzes a codebase and saves the manifest to .meeting-state.json
 #[tauri::command]
-pub async fn analyze_and_save_codebase(project_path: String) -> Result<CodebaseManifest, String> {
+pub async fn analyze_and_save_codebase(app: AppHandle, project_path: String) -> Result<CodebaseManifest, AppError> {
     let path = PathBuf::from(project_path);
+    let ignore_patterns = crate::settings::get_settings(&app).codebase_ignore_patterns;
 
-    let manifest = analyze_codebase(&path)
+    let manifest = analyze_codebase(&path, &ignore_patterns)
         .await
         .map_err(|e| format!("Failed to analyze codebase: {}", e))?;
 
@@ -26,3 +34,46 @@ pub async fn analyze_and_save_codebase(project_path: String) -> Result<CodebaseM
 
     Ok(manifest)
 }
+
+/// Builds (or incrementally refreshes) the local embedding index for a project
+#[tauri::command]
+pub async fn build_codebase_index(app: AppHandle, project_path: String) -> Result<IndexStats, AppError> {
+    let path = PathBuf::from(project_path);
+    let ignore_patterns = crate::settings::get_settings(&app).codebase_ignore_patterns;
+
+    tokio::task::spawn_blocking(move || embeddings::refresh_project_index(&app, &path, &ignore_patterns))
+        .await
+        .map_err(|e| format!("Index task failed: {}", e))?
+        .map_err(|e| AppError::Internal(format!("Failed to build codebase index: {}", e)))
+}
+
+/// Returns the code chunks most relevant to `query` from the project's embedding index
+#[tauri::command]
+pub async fn search_codebase(
+    app: AppHandle,
+    project_path: String,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<RetrievedChunk>, AppError> {
+    Ok(embeddings::search_project(
+        &app,
+        &PathBuf::from(project_path),
+        &query,
+        limit.unwrap_or(5),
+    ))
+}
+
+/// Shows the `.claudeignore` that would be written for a project (built-in patterns,
+/// matching template fragments, and the user's existing rules) without writing it
+#[tauri::command]
+pub fn preview_claudeignore(app: AppHandle, project_path: String) -> Result<ClaudeignorePreview, AppError> {
+    let path = PathBuf::from(project_path);
+    let fragments = crate::settings::get_settings(&app).claudeignore_fragments;
+    let framework = load_manifest_from_state(&path).and_then(|m| m.framework);
+
+    Ok(crate::codebase::preview_claudeignore(
+        &path,
+        framework.as_deref(),
+        &fragments,
+    ))
+}
@@ -0,0 +1,147 @@
+//! The error type commands return. It reaches the frontend as
+//! `{ "code": "not_found", "message": "...", "hint": "..." }`: `code` is stable and meant
+//! for branching and translation (see `src/lib/errors.ts`), `message` is English detail
+//! for logs and the fallback text, and `hint` says what the user can do about it.
+//!
+//! Plain `String` errors convert to `Internal`, so helpers that still return
+//! `Result<_, String>` work with `?`; use a specific variant wherever the user can act
+//! on the failure.
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::network::OfflineError;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AppError {
+    /// A meeting, project, model, file... that doesn't exist (any more)
+    #[error("{0} not found")]
+    NotFound(String),
+    #[error("{0}")]
+    InvalidInput(String),
+    /// Something in settings has to be set up first
+    #[error("{0}")]
+    NotConfigured(String),
+    /// Rejected or missing credentials for GitHub or the LLM provider
+    #[error("{0}")]
+    Unauthorized(String),
+    /// Conflicts with something already running
+    #[error("{0}")]
+    Busy(String),
+    #[error(transparent)]
+    Offline(#[from] OfflineError),
+    /// A remote service failed or couldn't be reached
+    #[error("{0}")]
+    Network(String),
+    #[error("{0}")]
+    Io(String),
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::InvalidInput(_) => "invalid_input",
+            AppError::NotConfigured(_) => "not_configured",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Busy(_) => "busy",
+            AppError::Offline(_) => "offline",
+            AppError::Network(_) => "network",
+            AppError::Io(_) => "io",
+            AppError::Internal(_) => "internal",
+        }
+    }
+
+    pub fn hint(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "It may have been deleted or moved. Refresh and try again.",
+            AppError::InvalidInput(_) => "Check the value and try again.",
+            AppError::NotConfigured(_) => "Finish setting it up in Settings, then try again.",
+            AppError::Unauthorized(_) => {
+                "Reconnect the account or update the key in Settings > Integrations."
+            }
+            AppError::Busy(_) => "Wait for it to finish or stop it, then try again.",
+            AppError::Offline(_) => "Turn off offline mode in Settings > General to allow this.",
+            AppError::Network(_) => {
+                "Check your connection and the proxy settings in Settings > Advanced."
+            }
+            AppError::Io(_) => "Check that the path exists and MeetingCoder can write to it.",
+            AppError::Internal(_) => {
+                "If this keeps happening, export a diagnostics bundle from Settings > Debug and report it."
+            }
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("AppError", 3)?;
+        s.serialize_field("code", self.code())?;
+        s.serialize_field("message", &self.to_string())?;
+        s.serialize_field("hint", self.hint())?;
+        s.end()
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Internal(message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        AppError::Internal(message.to_string())
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(e: std::io::Error) -> Self {
+        match e.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound(e.to_string()),
+            _ => AppError::Io(e.to_string()),
+        }
+    }
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(e: anyhow::Error) -> Self {
+        match e.downcast::<OfflineError>() {
+            Ok(offline) => AppError::Offline(offline),
+            Err(e) => AppError::Internal(e.to_string()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        match e.status().map(|s| s.as_u16()) {
+            Some(401 | 403) => AppError::Unauthorized(e.to_string()),
+            _ => AppError::Network(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::Service;
+
+    #[test]
+    fn test_serialized_shape() {
+        let value = serde_json::to_value(AppError::NotFound("Meeting abc".to_string())).unwrap();
+        assert_eq!(value["code"], "not_found");
+        assert_eq!(value["message"], "Meeting abc not found");
+        assert!(value["hint"].as_str().is_some_and(|h| !h.is_empty()));
+
+        let offline: AppError = anyhow::Error::new(OfflineError {
+            service: Service::GitHub,
+            target: "https://api.github.com".to_string(),
+        })
+        .into();
+        assert_eq!(offline.code(), "offline");
+        assert_eq!(AppError::from("boom".to_string()).code(), "internal");
+    }
+}
@@ -1,6 +1,10 @@
-use crate::integrations::github::{self, GitHubState, RepoInfo, DeviceCodeResponse};
+use crate::automation::repo_config;
+use crate::commands::AppError;
+use crate::integrations::digest::MeetingDigest;
+use crate::integrations::github::{self, CreatedIssue, GitHubState, RepoInfo, DeviceCodeResponse};
 use crate::managers::meeting::MeetingManager;
 use crate::settings;
+use crate::storage::db::MeetingDb;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
@@ -40,23 +44,45 @@ pub struct PRResult {
     pub error: Option<String>,
 }
 
+#[derive(serde::Serialize)]
+pub struct IssuesResult {
+    /// Issues opened by this call
+    pub created: Vec<CreatedIssue>,
+    /// Items that already had an issue from an earlier call
+    pub already_created: usize,
+}
+
+/// The repo a meeting's project pushes to. Clones under the repos folder name their repo,
+/// so a meeting started in a different clone targets that one.
+fn meeting_repo(settings: &settings::AppSettings) -> Result<(&str, &str), AppError> {
+    let owner = settings
+        .github_repo_owner
+        .as_deref()
+        .ok_or_else(|| AppError::NotConfigured("GitHub repository owner not set".to_string()))?;
+    let repo = settings
+        .github_repo_name
+        .as_deref()
+        .ok_or_else(|| AppError::NotConfigured("GitHub repository name not set".to_string()))?;
+    Ok((owner, repo))
+}
+
 /// Store GitHub token securely
 #[tauri::command]
-pub async fn set_github_token(token: String) -> Result<bool, String> {
+pub async fn set_github_token(token: String) -> Result<bool, AppError> {
     github::store_github_token(&token).map_err(|e| e.to_string())?;
     Ok(true)
 }
 
 /// Remove GitHub token
 #[tauri::command]
-pub async fn remove_github_token() -> Result<bool, String> {
+pub async fn remove_github_token() -> Result<bool, AppError> {
     github::delete_github_token().map_err(|e| e.to_string())?;
     Ok(true)
 }
 
 /// Test GitHub connection with current token
 #[tauri::command]
-pub async fn test_github_connection() -> Result<GitHubConnectionTest, String> {
+pub async fn test_github_connection() -> Result<GitHubConnectionTest, AppError> {
     match github::get_github_token() {
         Ok(token) => match github::test_github_connection(&token).await {
             Ok(username) => Ok(GitHubConnectionTest {
@@ -80,11 +106,12 @@ pub async fn test_github_connection() -> Result<GitHubConnectionTest, String> {
 
 /// List user's GitHub repositories
 #[tauri::command]
-pub async fn list_github_repos() -> Result<Vec<RepoInfo>, String> {
-    let token = github::get_github_token().map_err(|e| format!("No GitHub token: {}", e))?;
+pub async fn list_github_repos() -> Result<Vec<RepoInfo>, AppError> {
+    let token = github::get_github_token()
+        .map_err(|e| AppError::Unauthorized(format!("No GitHub token: {}", e)))?;
     github::list_user_repos(&token)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 /// Update GitHub repository settings
@@ -95,7 +122,7 @@ pub async fn set_github_repo(
     name: String,
     default_branch: Option<String>,
     branch_pattern: Option<String>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let mut settings = settings::get_settings(&app);
 
     settings.github_repo_owner = Some(owner);
@@ -114,7 +141,7 @@ pub async fn set_github_repo(
 
 /// Enable or disable GitHub integration
 #[tauri::command]
-pub async fn set_github_enabled(app: AppHandle, enabled: bool) -> Result<bool, String> {
+pub async fn set_github_enabled(app: AppHandle, enabled: bool) -> Result<bool, AppError> {
     let mut settings = settings::get_settings(&app);
     settings.github_enabled = enabled;
     settings::write_settings(&app, settings);
@@ -128,7 +155,7 @@ pub async fn get_github_repo_status(
     app: AppHandle,
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<GitHubRepoStatus, String> {
+) -> Result<GitHubRepoStatus, AppError> {
     let settings = settings::get_settings(&app);
 
     let has_token = github::get_github_token().is_ok();
@@ -137,7 +164,7 @@ pub async fn get_github_repo_status(
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
 
     let current_branch = if let Some(path) = &meeting.project_path {
         match github::init_git_repo(path) {
@@ -154,6 +181,10 @@ pub async fn get_github_repo_status(
     } else {
         GitHubState::new()
     };
+    let settings = match &meeting.project_path {
+        Some(path) => repo_config::effective_settings(&app, path),
+        None => settings,
+    };
 
     Ok(GitHubRepoStatus {
         repo_owner: settings.github_repo_owner.clone(),
@@ -175,32 +206,24 @@ pub async fn push_meeting_changes(
     meeting_id: String,
     commit_message: Option<String>,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<PushResult, String> {
-    let settings = settings::get_settings(&app);
-
-    // Validate settings
-    let owner = settings
-        .github_repo_owner
-        .as_ref()
-        .ok_or("GitHub repository owner not set")?;
-    let repo = settings
-        .github_repo_name
-        .as_ref()
-        .ok_or("GitHub repository name not set")?;
-
+) -> Result<PushResult, AppError> {
     // Get token
-    let token = github::get_github_token().map_err(|e| format!("No GitHub token: {}", e))?;
+    let token = github::get_github_token()
+        .map_err(|e| AppError::Unauthorized(format!("No GitHub token: {}", e)))?;
 
     // Get meeting
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
 
     let project_path = meeting
         .project_path
         .as_ref()
-        .ok_or("Meeting has no project path")?;
+        .ok_or_else(|| AppError::NotConfigured("Meeting has no project path".to_string()))?;
+    // The repo's .meetingcoder.toml may override the branch settings
+    let settings = repo_config::effective_settings(&app, project_path);
+    let (owner, repo) = meeting_repo(&settings)?;
 
     // Initialize repo
     let repo_obj = github::init_git_repo(project_path).map_err(|e| e.to_string())?;
@@ -227,12 +250,19 @@ pub async fn push_meeting_changes(
         )
     });
 
-    github::commit_meeting_files(&repo_obj, &message, "Handy", "noreply@handy.computer")
+    github::commit_meeting_files(
+        &repo_obj,
+        &repo_config::commit_paths(project_path),
+        &message,
+        "Handy",
+        "noreply@handy.computer",
+    )
         .map_err(|e| e.to_string())?;
 
     // Push to remote
-    github::push_to_remote(project_path, &branch_name, &token, owner, repo)
-        .map_err(|e| e.to_string())?;
+    let auth = github::GitAuth::from_settings(&settings, &token);
+    github::push_to_remote(project_path, &branch_name, &auth, owner, repo)
+        .map_err(AppError::from)?;
 
     // Update GitHub state
     let mut github_state = github::read_github_state(project_path);
@@ -256,32 +286,24 @@ pub async fn create_or_update_pr(
     title: Option<String>,
     body: Option<String>,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<PRResult, String> {
-    let settings = settings::get_settings(&app);
-
-    // Validate settings
-    let owner = settings
-        .github_repo_owner
-        .as_ref()
-        .ok_or("GitHub repository owner not set")?;
-    let repo = settings
-        .github_repo_name
-        .as_ref()
-        .ok_or("GitHub repository name not set")?;
-
+) -> Result<PRResult, AppError> {
     // Get token
-    let token = github::get_github_token().map_err(|e| format!("No GitHub token: {}", e))?;
+    let token = github::get_github_token()
+        .map_err(|e| AppError::Unauthorized(format!("No GitHub token: {}", e)))?;
 
     // Get meeting
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
 
     let project_path = meeting
         .project_path
         .as_ref()
-        .ok_or("Meeting has no project path")?;
+        .ok_or_else(|| AppError::NotConfigured("Meeting has no project path".to_string()))?;
+
+    let settings = repo_config::effective_settings(&app, project_path);
+    let (owner, repo) = meeting_repo(&settings)?;
 
     // Read GitHub state
     let mut github_state = github::read_github_state(project_path);
@@ -306,7 +328,7 @@ pub async fn create_or_update_pr(
     let existing_prs =
         github::get_prs_for_branch(&token, owner, repo, &branch_name)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(AppError::from)?;
 
     let pr = if let Some(existing_pr) = existing_prs.first() {
         // Update existing PR
@@ -319,10 +341,10 @@ pub async fn create_or_update_pr(
             Some(&pr_body),
         )
         .await
-        .map_err(|e| e.to_string())?
+        .map_err(AppError::from)?
     } else {
         // Create new PR
-        github::create_pull_request(
+        let new_pr = github::create_pull_request(
             &token,
             owner,
             repo,
@@ -332,7 +354,25 @@ pub async fn create_or_update_pr(
             &settings.github_default_branch,
         )
         .await
-        .map_err(|e| e.to_string())?
+        .map_err(AppError::from)?;
+        crate::notifications::notify(
+            &app,
+            crate::notifications::Milestone::PrCreated,
+            &format!("Pull request #{} created", new_pr.number),
+            &pr_title,
+        );
+        crate::integrations::webhooks::dispatch(
+            &app,
+            crate::settings::WebhookEvent::PrCreated,
+            serde_json::json!({
+                "meeting_id": meeting_id,
+                "project_path": project_path,
+                "pr_number": new_pr.number,
+                "pr_url": new_pr.html_url,
+                "title": pr_title,
+            }),
+        );
+        new_pr
     };
 
     // Update GitHub state
@@ -355,39 +395,30 @@ pub async fn post_meeting_update_comment(
     meeting_id: String,
     comment: Option<String>,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<bool, String> {
-    let settings = settings::get_settings(&app);
-
-    // Validate settings
-    let owner = settings
-        .github_repo_owner
-        .as_ref()
-        .ok_or("GitHub repository owner not set")?;
-    let repo = settings
-        .github_repo_name
-        .as_ref()
-        .ok_or("GitHub repository name not set")?;
-
+) -> Result<bool, AppError> {
     // Get token
-    let token = github::get_github_token().map_err(|e| format!("No GitHub token: {}", e))?;
+    let token = github::get_github_token()
+        .map_err(|e| AppError::Unauthorized(format!("No GitHub token: {}", e)))?;
 
     // Get meeting
     let meeting = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("{}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
 
     let project_path = meeting
         .project_path
         .as_ref()
-        .ok_or("Meeting has no project path")?;
+        .ok_or_else(|| AppError::NotConfigured("Meeting has no project path".to_string()))?;
+    let settings = repo_config::effective_settings(&app, project_path);
+    let (owner, repo) = meeting_repo(&settings)?;
 
     // Read GitHub state
     let github_state = github::read_github_state(project_path);
 
     let pr_number = github_state
         .last_pr_number
-        .ok_or("No PR found for this meeting")?;
+        .ok_or_else(|| AppError::NotFound("Pull request for this meeting".to_string()))?;
 
     // Generate comment
     let comment_text = if let Some(c) = comment {
@@ -404,23 +435,86 @@ pub async fn post_meeting_update_comment(
     // Post comment
     github::post_pr_comment(&token, owner, repo, pr_number, &comment_text)
         .await
-        .map_err(|e| e.to_string())?;
+        .map_err(AppError::from)?;
 
     Ok(true)
 }
 
+/// Open GitHub issues for a saved meeting's open action items and questions, labelled
+/// `meeting`. Items filed before (per `.claude/.github-state.json`) are skipped.
+#[tauri::command]
+pub async fn create_issues_from_meeting(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<IssuesResult, AppError> {
+    let token = github::get_github_token()
+        .map_err(|e| AppError::Unauthorized(format!("No GitHub token: {}", e)))?;
+
+    let meeting = db
+        .get_meeting(&meeting_id)
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let project_path = meeting
+        .project_path
+        .ok_or_else(|| AppError::NotConfigured("Meeting has no project path".to_string()))?;
+    let settings = repo_config::effective_settings(&app, &project_path);
+    let (owner, repo) = meeting_repo(&settings)?;
+
+    let digest = MeetingDigest::load(&db, &meeting_id).map_err(AppError::from)?;
+    // Meetings saved to disk keep their tasks (and done state) in tasks.json
+    let action_items = match super::meeting::meeting_dir_path(&db, &meeting_id) {
+        Ok(dir) => super::meeting::load_tasks(&db, &meeting_id, &dir)?.items,
+        Err(_) => {
+            let segments = db.get_segments(&meeting_id).map_err(AppError::from)?;
+            crate::summarization::action_items::extract(&segments, 0)
+        }
+    };
+
+    let mut state = github::read_github_state(&project_path);
+    let mut result = IssuesResult {
+        created: Vec::new(),
+        already_created: 0,
+    };
+    for draft in github::issue_drafts(&digest, &action_items) {
+        let filed = state
+            .created_issues
+            .iter()
+            .any(|i| i.meeting_id == meeting_id && i.item_key == draft.item_key);
+        if filed {
+            result.already_created += 1;
+            continue;
+        }
+        let issue = github::create_issue(&token, owner, repo, &draft)
+            .await
+            .map_err(AppError::from)?;
+        let created = CreatedIssue {
+            item_key: draft.item_key,
+            meeting_id: meeting_id.clone(),
+            number: issue.number,
+            html_url: issue.html_url,
+            title: draft.title,
+        };
+        state.created_issues.push(created.clone());
+        // Recorded per issue, so a failure part-way through doesn't duplicate on retry
+        github::write_github_state(&project_path, &state).map_err(AppError::from)?;
+        result.created.push(created);
+    }
+    Ok(result)
+}
+
 /// Begin GitHub OAuth Device Flow
 #[tauri::command]
-pub async fn github_begin_device_auth() -> Result<DeviceCodeResponse, String> {
+pub async fn github_begin_device_auth() -> Result<DeviceCodeResponse, AppError> {
     github::begin_device_auth()
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 /// Poll for GitHub OAuth Device Flow token
 #[tauri::command]
-pub async fn github_poll_device_token(device_code: String) -> Result<Option<String>, String> {
+pub async fn github_poll_device_token(device_code: String) -> Result<Option<String>, AppError> {
     github::poll_device_token(&device_code)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
@@ -1,18 +1,24 @@
+use crate::commands::AppError;
 use crate::managers::history::{HistoryEntry, HistoryManager};
+use crate::managers::meeting::MeetingManager;
+use crate::storage::db::{MeetingDb, TranscriptSearchFilters, TranscriptSearchHit};
+use crate::storage::integrity::{self, IntegrityReport, RepairReport};
+use crate::storage::transcript::TranscriptStorage;
+use crate::storage::usage::{self, CleanupReport, StorageUsage, UsageRoots};
 use std::path::{Component, Path};
 use std::sync::Arc;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 
 /// Security: Sanitize filename to prevent path traversal attacks
-fn sanitize_filename(filename: &str) -> Result<String, String> {
+fn sanitize_filename(filename: &str) -> Result<String, AppError> {
     // Reject empty filenames
     if filename.is_empty() {
-        return Err("Filename cannot be empty".to_string());
+        return Err(AppError::InvalidInput("Filename cannot be empty".to_string()));
     }
 
     // Reject filenames that are too long
     if filename.len() > 255 {
-        return Err("Filename too long".to_string());
+        return Err(AppError::InvalidInput("Filename too long".to_string()));
     }
 
     // Reject path traversal attempts by checking path components
@@ -20,27 +26,35 @@ fn sanitize_filename(filename: &str) -> Result<String, String> {
     for component in path.components() {
         match component {
             Component::Normal(_) => continue,
-            _ => return Err("Invalid filename: path traversal detected".to_string()),
+            _ => {
+                return Err(AppError::InvalidInput(
+                    "Invalid filename: path traversal detected".to_string(),
+                ))
+            }
         }
     }
 
     // Reject filenames with null bytes or path separators
     if filename.contains(&['\0', '/', '\\'][..]) {
-        return Err("Invalid filename: contains illegal characters".to_string());
+        return Err(AppError::InvalidInput(
+            "Invalid filename: contains illegal characters".to_string(),
+        ));
     }
 
     Ok(filename.to_string())
 }
 
+/// History entries, optionally filtered by record type ("dictation" or "meeting")
 #[tauri::command]
 pub async fn get_history_entries(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
-) -> Result<Vec<HistoryEntry>, String> {
+    record_type: Option<String>,
+) -> Result<Vec<HistoryEntry>, AppError> {
     history_manager
-        .get_history_entries()
+        .get_history_entries(record_type.as_deref())
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
@@ -48,11 +62,11 @@ pub async fn toggle_history_entry_saved(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     id: i64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     history_manager
         .toggle_saved_status(id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
@@ -60,13 +74,13 @@ pub async fn get_audio_file_path(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     file_name: String,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     // Security: Sanitize filename to prevent path traversal
     let sanitized = sanitize_filename(&file_name)?;
 
     let path = history_manager.get_audio_file_path(&sanitized).map_err(|e| e.to_string())?;
     path.to_str()
-        .ok_or_else(|| "Invalid file path".to_string())
+        .ok_or_else(|| AppError::Internal("Invalid file path".to_string()))
         .map(|s| s.to_string())
 }
 
@@ -75,11 +89,11 @@ pub async fn delete_history_entry(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     id: i64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     history_manager
         .delete_entry(id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
@@ -87,7 +101,7 @@ pub async fn update_history_limit(
     app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     limit: usize,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = crate::settings::get_settings(&app);
     settings.history_limit = limit;
     crate::settings::write_settings(&app, settings);
@@ -98,3 +112,97 @@ pub async fn update_history_limit(
 
     Ok(())
 }
+
+/// Full-text search across saved meeting transcripts
+#[tauri::command]
+pub async fn search_transcripts(
+    query: String,
+    filters: Option<TranscriptSearchFilters>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<TranscriptSearchHit>, AppError> {
+    db.search_transcripts(&query, &filters.unwrap_or_default())
+        .map_err(|e| AppError::Internal(format!("Search failed: {}", e)))
+}
+
+fn usage_roots(app: &AppHandle) -> Result<UsageRoots, String> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    Ok(UsageRoots {
+        transcripts: TranscriptStorage::default_path().map_err(|e| e.to_string())?,
+        models: app_data_dir.join("models"),
+        exports: crate::storage::root::storage_dir("exports").map_err(|e| e.to_string())?,
+        recordings: app_data_dir.join("recordings"),
+    })
+}
+
+/// Bytes used by transcripts, audio, models, exports, and recordings, with a per-meeting breakdown
+#[tauri::command]
+pub async fn get_storage_usage(
+    app: AppHandle,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<StorageUsage, AppError> {
+    let meetings = db.list_meetings().map_err(|e| e.to_string())?;
+    let roots = usage_roots(&app)?;
+    tauri::async_runtime::spawn_blocking(move || usage::compute_usage(&meetings, &roots))
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Targeted cleanup: "segment_wavs", "full_audio", "exports", or "partial_models",
+/// optionally limited to one meeting
+#[tauri::command]
+pub async fn cleanup_storage(
+    app: AppHandle,
+    action: String,
+    meeting_id: Option<String>,
+    db: State<'_, Arc<MeetingDb>>,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<CleanupReport, AppError> {
+    // Segment WAVs of a running meeting are still being written and composed
+    if action == "segment_wavs" && !meeting_manager.get_active_meetings().await.is_empty() {
+        return Err(AppError::Busy(
+            "End active meetings before removing audio segments".to_string(),
+        ));
+    }
+    let meetings = db.list_meetings().map_err(|e| e.to_string())?;
+    let roots = usage_roots(&app)?;
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        usage::cleanup(&action, &meetings, meeting_id.as_deref(), &roots)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+    log::info!(
+        "Storage cleanup removed {} files ({} bytes)",
+        report.files_removed,
+        report.bytes_freed
+    );
+    Ok(report)
+}
+
+/// Verify the databases, saved transcript files, and project audio segments
+#[tauri::command]
+pub async fn check_storage_integrity(app: AppHandle) -> Result<IntegrityReport, AppError> {
+    tauri::async_runtime::spawn_blocking(move || integrity::run_check(&app))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Apply a repair suggested by `check_storage_integrity`: "rebuild_index", "relink_segments", or "vacuum"
+#[tauri::command]
+pub async fn repair_storage(
+    app: AppHandle,
+    action: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<RepairReport, AppError> {
+    if !integrity::REPAIR_ACTIONS.contains(&action.as_str()) {
+        return Err(AppError::InvalidInput(format!("Unknown repair action: {}", action)));
+    }
+    if !meeting_manager.get_active_meetings().await.is_empty() {
+        return Err(AppError::Busy("End active meetings before repairing storage".to_string()));
+    }
+    tauri::async_runtime::spawn_blocking(move || integrity::repair(&app, &action))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
@@ -1,38 +1,48 @@
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use tauri::{AppHandle, Manager, State, Emitter};
+use tauri::{AppHandle, Manager, State};
 
 use crate::audio_toolkit::audio::load_audio_file_to_mono_16k;
 use crate::automation::claude_trigger::trigger_meeting_update;
-use crate::managers::meeting::{MeetingManager, TranscriptSegment};
-use crate::managers::transcription::TranscriptionManager;
+use crate::commands::AppError;
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::{MeetingManager, TranscriptSegment, WordTiming};
+use crate::managers::transcription::{
+    should_drop, ImportTranscriptionManager, TranscriptionManager,
+};
 use crate::meeting::context_writer::append_update;
+use crate::meeting::import_checkpoint::{self, ImportCheckpoint, InterruptedImport};
+use crate::queue::JobPriority;
 use crate::settings;
 use crate::summarization::agent::summarize_segments_with_context;
 use crate::audio_toolkit::vad::{SileroVad, SmoothedVad, VoiceActivityDetector, VadFrame};
 use crate::managers::model::{ModelManager, EngineType};
+use crate::workers::asr_worker::transcribe_in_background;
 use tauri::path::BaseDirectory;
 
-#[derive(serde::Serialize, Clone)]
-struct ImportProgress<'a> {
-    stage: &'a str,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    percent: Option<u8>,
+/// Bumped by `cancel_operation`; an import stops once it changes under it
+static CANCEL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Stop running imports after their current chunk. Their checkpoints stay, so importing
+/// the same file again continues where they stopped.
+pub fn cancel_imports() {
+    CANCEL_GENERATION.fetch_add(1, Ordering::SeqCst);
 }
 
 fn emit_progress(app: &AppHandle, stage: &str, percent: Option<u8>) {
-    let _ = app.emit(
-        "import-progress",
-        ImportProgress { stage, percent },
+    events::emit(
+        app,
+        AppEvent::ImportProgress { stage: stage.to_string(), percent },
     );
 }
 
 /// Native file picker for audio files via Rust dialog plugin.
 /// Returns an optional absolute path as String.
 #[tauri::command]
-pub async fn pick_audio_file(app: AppHandle) -> Result<Option<String>, String> {
+pub async fn pick_audio_file(app: AppHandle) -> Result<Option<String>, AppError> {
     use tauri_plugin_dialog::DialogExt;
     let picked = app
         .dialog()
@@ -47,7 +57,7 @@ pub async fn pick_audio_file(app: AppHandle) -> Result<Option<String>, String> {
     Ok(picked.map(|p| p.to_string()))
 }
 
-fn is_supported_audio_extension(path: &PathBuf) -> bool {
+pub(crate) fn is_supported_audio_extension(path: &PathBuf) -> bool {
     if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
         let ext = ext.to_ascii_lowercase();
         matches!(ext.as_str(),
@@ -60,7 +70,7 @@ fn is_supported_audio_extension(path: &PathBuf) -> bool {
 
 const MAX_IMPORT_FILE_BYTES: u64 = 1_500_000_000; // ~1.5GB safety cap
 
-async fn import_audio_from_path_as_meeting(
+pub(crate) async fn import_audio_from_path_as_meeting(
     app: AppHandle,
     meeting_name: String,
     file_path: String,
@@ -93,11 +103,37 @@ async fn import_audio_from_path_as_meeting(
         }
     }
 
-    // Start an offline meeting
-    let meeting_id = meeting_manager
-        .start_offline_meeting(meeting_name.clone())
-        .await
-        .map_err(|e| e.to_string())?;
+    let cancel_generation = CANCEL_GENERATION.load(Ordering::SeqCst);
+    let (file_len, file_modified) = import_checkpoint::file_stamp(&path).unwrap_or_default();
+    // Continue an unfinished import of the same file, else start an offline meeting
+    let checkpoint = import_checkpoint::find(&app, &path);
+    let meeting_id = match &checkpoint {
+        Some(checkpoint) => {
+            let id = checkpoint.session.id.clone();
+            if meeting_manager.get_meeting(&id).await.is_ok() {
+                return Err(format!("{} is already being imported", file_path));
+            }
+            log::info!(
+                "Resuming import of {} at {:.0}s",
+                file_path,
+                checkpoint.sample_offset as f64 / 16_000f64
+            );
+            meeting_manager.resume_offline_meeting(checkpoint.session.clone()).await;
+            id
+        }
+        None => meeting_manager
+            .start_offline_meeting(meeting_name.clone())
+            .await
+            .map_err(|e| e.to_string())?,
+    };
+    let meeting_name = checkpoint
+        .as_ref()
+        .map(|c| c.session.name.clone())
+        .unwrap_or(meeting_name);
+
+    // A dedicated import model keeps imports from swapping out or blocking the live model
+    let transcription_manager = ImportTranscriptionManager::for_imports(&app)
+        .unwrap_or_else(|| transcription_manager.inner().clone());
 
     // Load model for import; prefer Whisper if enabled and available
     emit_progress(&app, "loading-model", Some(0));
@@ -186,17 +222,7 @@ async fn import_audio_from_path_as_meeting(
     // Segment and transcribe
     let settings = settings::get_settings(&app);
     let total = samples.len();
-    // Select segmentation strategy
-    let segments_to_process = if settings.use_fixed_windows_for_imports {
-        log::info!("Segmentation: using fixed 45s windows (imports override)");
-        build_fixed_segments_with_overlap(total, 45, 0.9)
-    } else {
-        // Try VAD segmentation for more natural boundaries; fallback to fixed windows
-        match build_vad_segments(&app, &samples, settings.min_segment_duration_for_imports) {
-            Ok(v) if !v.is_empty() => v,
-            _ => build_fixed_segments_with_overlap(total, 45, 0.9),
-        }
-    };
+    let segments_to_process = plan_segments(&app, &samples);
     // Instrumentation: segment coverage + stats
     {
         let total_secs = (total as f64) / 16_000f64;
@@ -217,7 +243,11 @@ async fn import_audio_from_path_as_meeting(
             n, mean, median, (last_end as f64)/16_000f64, total_secs);
     }
     let mut processed_until = 0usize;
-    let mut segments_accum: Vec<TranscriptSegment> = Vec::new();
+    let resume_offset = checkpoint.as_ref().map_or(0, |c| c.sample_offset);
+    let mut segments_accum: Vec<TranscriptSegment> = checkpoint
+        .as_ref()
+        .map(|c| c.session.transcript_segments.clone())
+        .unwrap_or_default();
     let import_start = std::time::Instant::now();
     let mut total_audio_sec_processed = 0.0f64;
     let mut total_wall_sec_transcribing = 0.0f64;
@@ -229,28 +259,22 @@ async fn import_audio_from_path_as_meeting(
         .ok()
         .and_then(|m| m.project_path);
 
-    let mut sent_last_update_idx: usize = 0;
-
-    // Helper to trim overlapping text at segment joins (UTF-8 safe)
-    fn trim_overlap(prev: &str, cur: &str) -> String {
-        if prev.is_empty() || cur.is_empty() { return cur.to_string(); }
-        let prev_chars: Vec<char> = prev.chars().collect();
-        let tail_start = prev_chars.len().saturating_sub(200);
-        let prev_tail: String = prev_chars[tail_start..].iter().collect();
-
-        let cur_chars: Vec<char> = cur.chars().collect();
-        let max_check = cur_chars.len().min(120);
-        let mut best = 0usize;
-        for k in (10..=max_check).rev() {
-            let prefix: String = cur_chars[..k].iter().collect();
-            if prev_tail.ends_with(&prefix) { best = k; break; }
-        }
-        cur_chars[best..].iter().collect()
-    }
+    let mut sent_last_update_idx = checkpoint.as_ref().map_or(0, |c| c.sent_segments);
 
     for (start_idx_global, end_idx) in segments_to_process.into_iter() {
-        let chunk = samples[start_idx_global..end_idx].to_vec();
-
+        if end_idx <= resume_offset {
+            continue;
+        }
+        if CANCEL_GENERATION.load(Ordering::SeqCst) != cancel_generation {
+            meeting_manager.close_offline_meeting(&meeting_id).await;
+            emit_progress(&app, "cancelled", None);
+            log::info!(
+                "Import of {} cancelled at {:.0}s",
+                file_path,
+                start_idx_global as f64 / 16_000f64
+            );
+            return Err("Import cancelled".to_string());
+        }
         let start_time = (start_idx_global as f64) / 16_000f64;
         let end_time = (end_idx as f64) / 16_000f64;
 
@@ -259,16 +283,25 @@ async fn import_audio_from_path_as_meeting(
         let pct = ((processed_until as f64 / total as f64) * 100.0).round() as u8;
         emit_progress(&app, "transcribing", Some(pct.min(100)));
 
-        // Transcribe chunk (blocking)
+        // Transcribe chunk on the background pool; live meeting chunks are claimed first
         let chunk_audio_sec = (end_idx - start_idx_global) as f64 / 16_000f64;
         let chunk_wall_start = std::time::Instant::now();
-        let text = {
-            let tm = transcription_manager.inner().clone();
-            tauri::async_runtime::spawn_blocking(move || tm.transcribe(chunk))
-                .await
-                .map_err(|e| e.to_string())
-                .and_then(|r| r.map_err(|e| e.to_string()))?
+        let transcription = {
+            let previous_text = segments_accum.last().map(|s| s.text.clone()).unwrap_or_default();
+            transcribe_in_background(
+                &app,
+                transcription_manager.clone(),
+                &meeting_id,
+                start_idx_global as u64 * 1000 / 16_000,
+                &samples[start_idx_global..end_idx],
+                &previous_text,
+                JobPriority::Import,
+            )
+            .await?
         };
+        let confidence = transcription.segment_confidence();
+        let words = transcription.words_at(start_time);
+        let text = transcription.text;
         let wall = chunk_wall_start.elapsed().as_secs_f64();
         total_audio_sec_processed += chunk_audio_sec;
         total_wall_sec_transcribing += wall;
@@ -281,7 +314,7 @@ async fn import_audio_from_path_as_meeting(
             );
         }
 
-        if !text.trim().is_empty() {
+        if !text.trim().is_empty() && !should_drop(confidence) {
             let final_text = if let Some(prev) = segments_accum.last() {
                 let trimmed = trim_overlap(&prev.text, &text);
                 if trimmed.len() < text.len() {
@@ -293,9 +326,11 @@ async fn import_audio_from_path_as_meeting(
                 speaker: "Speaker 1".to_string(),
                 start_time,
                 end_time,
+                words: words_for_text(words, &final_text),
                 text: final_text.clone(),
-                confidence: 0.95,
+                confidence,
                 timestamp: std::time::SystemTime::now(),
+                translated_text: None,
             };
             meeting_manager
                 .add_segment(&meeting_id, seg.clone())
@@ -314,10 +349,13 @@ async fn import_audio_from_path_as_meeting(
                 if end >= start {
                     let summary = summarize_segments_with_context(
                         Some(pp),
-                        &full_transcript,
+                        &full_transcript[start..=end],
                         start,
                         end,
                     );
+                    let tickets = crate::integrations::jira::tickets_in_segments(
+                        &full_transcript[start..=end],
+                    );
                     if let Ok(update_id) = append_update(
                         pp,
                         &meeting_id,
@@ -325,11 +363,12 @@ async fn import_audio_from_path_as_meeting(
                         &settings.selected_model,
                         source_label,
                         &summary,
+                        &tickets,
                     ) {
                         // Notify frontend and attempt automation
-                        let _ = app.emit(
-                            "meeting-update-appended",
-                            serde_json::json!({"update_id": update_id, "meeting_id": meeting_id }),
+                        events::emit(
+                            &app,
+                            AppEvent::MeetingUpdateAppended { update_id, meeting_id: meeting_id.clone() },
                         );
                         let _ = trigger_meeting_update(&app, pp, &meeting_id, update_id);
                         sent_last_update_idx = end + 1;
@@ -338,6 +377,21 @@ async fn import_audio_from_path_as_meeting(
             }
         }
 
+        // A quit or cancel from here on resumes after this chunk
+        if let Ok(session) = meeting_manager.get_meeting(&meeting_id).await {
+            let checkpoint = ImportCheckpoint {
+                file_path: file_path.clone(),
+                file_len,
+                file_modified,
+                source_label: source_label.to_string(),
+                sample_offset: end_idx,
+                sent_segments: sent_last_update_idx,
+                session,
+            };
+            if let Err(e) = import_checkpoint::save(&app, &checkpoint) {
+                log::warn!("Failed to checkpoint import of {}: {}", file_path, e);
+            }
+        }
     }
 
     emit_progress(&app, "finalizing", Some(100));
@@ -353,10 +407,105 @@ async fn import_audio_from_path_as_meeting(
     }
 
     // End meeting and persist transcript
-    meeting_manager
+    let summary = meeting_manager
         .end_meeting(&meeting_id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    import_checkpoint::clear(&app, &meeting_id);
+    crate::notifications::notify(
+        &app,
+        crate::notifications::Milestone::ImportFinished,
+        "Import finished",
+        &format!("{} ({} segments)", summary.name, summary.total_segments),
+    );
+    Ok(summary)
+}
+
+// Helper to trim overlapping text at segment joins (UTF-8 safe)
+fn trim_overlap(prev: &str, cur: &str) -> String {
+    if prev.is_empty() || cur.is_empty() { return cur.to_string(); }
+    let prev_chars: Vec<char> = prev.chars().collect();
+    let tail_start = prev_chars.len().saturating_sub(200);
+    let prev_tail: String = prev_chars[tail_start..].iter().collect();
+
+    let cur_chars: Vec<char> = cur.chars().collect();
+    let max_check = cur_chars.len().min(120);
+    let mut best = 0usize;
+    for k in (10..=max_check).rev() {
+        let prefix: String = cur_chars[..k].iter().collect();
+        if prev_tail.ends_with(&prefix) { best = k; break; }
+    }
+    cur_chars[best..].iter().collect()
+}
+
+/// The timings of the words left in `text` after `trim_overlap` cut the head off
+fn words_for_text(mut words: Vec<WordTiming>, text: &str) -> Vec<WordTiming> {
+    let kept = text.split_whitespace().count();
+    words.drain(..words.len().saturating_sub(kept));
+    words
+}
+
+/// Split 16 kHz samples into transcription chunks the way imports do: at pauses found by
+/// VAD, or in fixed overlapping windows when that's configured or VAD finds nothing
+pub(crate) fn plan_segments(app: &AppHandle, samples: &[f32]) -> Vec<(usize, usize)> {
+    let settings = settings::get_settings(app);
+    if settings.use_fixed_windows_for_imports {
+        return build_fixed_segments_with_overlap(samples.len(), 45, 0.9);
+    }
+    match build_vad_segments(app, samples, settings.min_segment_duration_for_imports) {
+        Ok(v) if !v.is_empty() => v,
+        _ => build_fixed_segments_with_overlap(samples.len(), 45, 0.9),
+    }
+}
+
+/// Transcribe 16 kHz samples of `meeting_id` chunk by chunk with `transcription_manager`,
+/// queued at `priority` on the background pool. Segments are single-speaker;
+/// `on_progress` gets the percent done.
+pub(crate) async fn transcribe_samples(
+    app: &AppHandle,
+    transcription_manager: Arc<TranscriptionManager>,
+    meeting_id: &str,
+    priority: JobPriority,
+    samples: &[f32],
+    mut on_progress: impl FnMut(u8),
+) -> Result<Vec<TranscriptSegment>, String> {
+    let total = samples.len().max(1);
+    let mut segments: Vec<TranscriptSegment> = Vec::new();
+    for (start_idx, end_idx) in plan_segments(app, samples) {
+        let previous_text = segments.last().map(|s| s.text.clone()).unwrap_or_default();
+        let transcription = transcribe_in_background(
+            app,
+            transcription_manager.clone(),
+            meeting_id,
+            start_idx as u64 * 1000 / 16_000,
+            &samples[start_idx..end_idx],
+            &previous_text,
+            priority,
+        )
+        .await?;
+        on_progress(((end_idx as f64 / total as f64) * 100.0).round().min(100.0) as u8);
+        let confidence = transcription.segment_confidence();
+        if transcription.text.trim().is_empty() || should_drop(confidence) {
+            continue;
+        }
+        let start_time = start_idx as f64 / 16_000f64;
+        let words = transcription.words_at(start_time);
+        let text = match segments.last() {
+            Some(prev) => trim_overlap(&prev.text, &transcription.text),
+            None => transcription.text,
+        };
+        segments.push(TranscriptSegment {
+            speaker: "Speaker 1".to_string(),
+            start_time,
+            end_time: end_idx as f64 / 16_000f64,
+            words: words_for_text(words, &text),
+            text,
+            confidence,
+            timestamp: std::time::SystemTime::now(),
+            translated_text: None,
+        });
+    }
+    Ok(segments)
 }
 
 fn build_fixed_segments_with_overlap(total: usize, chunk_seconds: u32, overlap_seconds: f64) -> Vec<(usize, usize)> {
@@ -548,7 +697,7 @@ pub async fn import_audio_as_meeting(
     meeting_manager: State<'_, Arc<MeetingManager>>,
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<crate::managers::meeting::MeetingSummary, String> {
+) -> Result<crate::managers::meeting::MeetingSummary, AppError> {
     import_audio_from_path_as_meeting(
         app,
         meeting_name,
@@ -559,8 +708,24 @@ pub async fn import_audio_as_meeting(
         model_manager,
     )
     .await
+    .map_err(AppError::from)
+}
+
+/// Imports that stopped before finishing; importing the file again resumes them
+#[tauri::command]
+pub fn list_interrupted_imports(app: AppHandle) -> Vec<InterruptedImport> {
+    import_checkpoint::list(&app)
 }
 
+/// Forget an unfinished import, so importing its file starts over
+#[tauri::command]
+pub fn discard_interrupted_import(app: AppHandle, meeting_id: String) {
+    import_checkpoint::clear(&app, &meeting_id);
+}
+
+const YT_DLP_MISSING: &str =
+    "yt-dlp not found. Please install yt-dlp and ensure it is on your PATH.";
+
 /// Import a YouTube URL as a new offline meeting. Requires `yt-dlp` in PATH.
 #[tauri::command]
 pub async fn import_youtube_as_meeting(
@@ -570,7 +735,8 @@ pub async fn import_youtube_as_meeting(
     meeting_manager: State<'_, Arc<MeetingManager>>,
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<crate::managers::meeting::MeetingSummary, String> {
+) -> Result<crate::managers::meeting::MeetingSummary, AppError> {
+    crate::network::check(crate::network::Service::MediaDownload, &url)?;
     // Indicate start of YouTube flow
     emit_progress(&app, "downloading", Some(0));
 
@@ -578,29 +744,29 @@ pub async fn import_youtube_as_meeting(
     match std::process::Command::new("yt-dlp").arg("--version").output() {
         Ok(out) => {
             if !out.status.success() {
-                return Err("yt-dlp not found. Please install yt-dlp and ensure it is on your PATH.".to_string());
+                return Err(AppError::NotConfigured(YT_DLP_MISSING.to_string()));
             }
         }
         Err(e) => {
             if e.kind() == std::io::ErrorKind::NotFound {
-                return Err("yt-dlp not found. Please install yt-dlp and ensure it is on your PATH.".to_string());
+                return Err(AppError::NotConfigured(YT_DLP_MISSING.to_string()));
             }
-            return Err(format!("Failed to check yt-dlp availability: {}", e));
+            return Err(AppError::Internal(format!(
+                "Failed to check yt-dlp availability: {}",
+                e
+            )));
         }
     }
 
     // Download best audio to a temp file using yt-dlp
-    let app_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
-    let target = app_dir.join("downloads");
+    let target = crate::storage::root::storage_dir("downloads")
+        .map_err(|e| format!("Failed to resolve downloads dir: {}", e))?;
     std::fs::create_dir_all(&target).map_err(|e| e.to_string())?;
 
     let output = target.join("yt_audio.%(ext)s");
     let output_str = output.to_string_lossy().to_string();
 
-    let output_exec = std::process::Command::new("yt-dlp")
+    let output_exec = crate::network::configure_command(&mut std::process::Command::new("yt-dlp"))
         .arg("-f")
         .arg("bestaudio/best")
         .arg("-o")
@@ -620,7 +786,7 @@ pub async fn import_youtube_as_meeting(
                 stderr.trim()
             )
         };
-        return Err(msg);
+        return Err(AppError::Network(msg));
     }
 
     // Find the downloaded file (first matching yt_audio.*)
@@ -649,4 +815,5 @@ pub async fn import_youtube_as_meeting(
         model_manager,
     )
     .await
+    .map_err(AppError::from)
 }
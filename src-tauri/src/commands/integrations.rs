@@ -0,0 +1,526 @@
+//! Posting meeting summaries to chat and other destinations
+
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+use crate::commands::AppError;
+use crate::integrations::asana::{self, AsanaSyncResult};
+use crate::integrations::calendar::{self, CalendarEvent};
+use crate::integrations::calendar_writeback::{
+    self, CalendarProvider, CalendarWriteBack, CalendarWriteCredentials,
+};
+use crate::integrations::digest::MeetingDigest;
+use crate::integrations::discord;
+use crate::integrations::email::{self, EmailDelivery, SmtpCredentials};
+use crate::integrations::jira::{self, JiraTicket};
+use crate::integrations::obsidian;
+use crate::integrations::slack::{self, SlackCredentials, SlackPostResult};
+use crate::integrations::teams;
+use crate::integrations::trello::{self, TrelloCredentials, TrelloList, TrelloPushResult};
+use crate::integrations::webhooks;
+use crate::storage::db::{ExternalLink, MeetingDb, WebhookDelivery};
+
+fn load_digest(db: &MeetingDb, meeting_id: &str) -> Result<MeetingDigest, AppError> {
+    if !db.meeting_exists(meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    MeetingDigest::load(db, meeting_id).map_err(AppError::from)
+}
+
+/// Connect Slack with a bot token (`xoxb-...`) or an incoming webhook URL
+#[tauri::command]
+pub async fn set_slack_credentials(
+    bot_token: Option<String>,
+    webhook_url: Option<String>,
+) -> Result<(), AppError> {
+    let non_empty = |v: Option<String>| v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    slack::store_credentials(&SlackCredentials {
+        bot_token: non_empty(bot_token),
+        webhook_url: non_empty(webhook_url),
+    })
+    .map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_slack_credentials() -> Result<(), AppError> {
+    slack::clear_credentials().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_slack_credentials() -> Result<bool, AppError> {
+    Ok(slack::has_credentials())
+}
+
+/// Post a meeting's summary, action items, and PR link to Slack. `channel` defaults to
+/// the one in settings and is ignored when posting through a webhook.
+#[tauri::command]
+pub async fn post_summary_to_slack(
+    app: AppHandle,
+    meeting_id: String,
+    channel: Option<String>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<SlackPostResult, AppError> {
+    if !slack::has_credentials() {
+        return Err(AppError::NotConfigured(
+            "Slack is not connected".to_string(),
+        ));
+    }
+    let digest = load_digest(&db, &meeting_id)?;
+    let channel = channel.unwrap_or_else(|| crate::settings::get_settings(&app).slack.channel);
+    slack::post_digest(&digest, Some(&channel))
+        .await
+        .map_err(AppError::from)
+}
+
+/// Connect a Teams channel through its incoming webhook or Workflows URL
+#[tauri::command]
+pub async fn set_teams_webhook(url: String) -> Result<(), AppError> {
+    teams::store_webhook(url.trim()).map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_teams_webhook() -> Result<(), AppError> {
+    teams::clear_webhook().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_teams_webhook() -> Result<bool, AppError> {
+    Ok(teams::has_webhook())
+}
+
+/// Post a meeting's summary and PR link to the Teams channel as an Adaptive Card
+#[tauri::command]
+pub async fn post_summary_to_teams(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<(), AppError> {
+    if !teams::has_webhook() {
+        return Err(AppError::NotConfigured(
+            "No Teams webhook is set".to_string(),
+        ));
+    }
+    let digest = load_digest(&db, &meeting_id)?;
+    teams::post_digest(&digest).await.map_err(AppError::from)
+}
+
+/// Set the Discord webhook for a project, or the default used by projects without one
+#[tauri::command]
+pub async fn set_discord_webhook(
+    url: String,
+    project_path: Option<String>,
+) -> Result<(), AppError> {
+    discord::store_webhook(project_path.as_deref(), url.trim())
+        .map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_discord_webhook(project_path: Option<String>) -> Result<(), AppError> {
+    discord::clear_webhook(project_path.as_deref()).map_err(AppError::from)
+}
+
+/// Whether this project (or, without one, the default) has its own webhook
+#[tauri::command]
+pub async fn has_discord_webhook(project_path: Option<String>) -> Result<bool, AppError> {
+    Ok(discord::has_webhook(project_path.as_deref()))
+}
+
+/// Post a meeting's summary to its project's Discord channel
+#[tauri::command]
+pub async fn post_summary_to_discord(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<(), AppError> {
+    let digest = load_digest(&db, &meeting_id)?;
+    let project_path = db
+        .get_meeting(&meeting_id)
+        .map_err(AppError::from)?
+        .and_then(|m| m.project_path);
+    if !discord::has_webhook(project_path.as_deref()) && !discord::has_webhook(None) {
+        return Err(AppError::NotConfigured(
+            "No Discord webhook is set".to_string(),
+        ));
+    }
+    discord::post_digest(&digest, project_path.as_deref())
+        .await
+        .map_err(AppError::from)
+}
+
+/// Store the SMTP login used to send summaries
+#[tauri::command]
+pub async fn set_smtp_credentials(username: String, password: String) -> Result<(), AppError> {
+    email::store_credentials(&SmtpCredentials { username, password }).map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn remove_smtp_credentials() -> Result<(), AppError> {
+    email::clear_credentials().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_smtp_credentials() -> Result<bool, AppError> {
+    Ok(email::has_credentials())
+}
+
+/// Email a meeting's summary to its participants, using the speaker-to-address mapping
+/// in settings
+#[tauri::command]
+pub async fn email_meeting_summary(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<EmailDelivery, AppError> {
+    let config = crate::settings::get_settings(&app).email;
+    let digest = load_digest(&db, &meeting_id)?;
+    if email::resolve_recipients(&config, &digest.participants)
+        .0
+        .is_empty()
+    {
+        return Err(AppError::NotConfigured(
+            "None of the participants have an email address in settings".to_string(),
+        ));
+    }
+    email::send_digest(&app, &config, &digest)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Write a meeting's note into the Obsidian vault; returns the note's path
+#[tauri::command]
+pub async fn export_meeting_to_obsidian(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<String, AppError> {
+    let config = crate::settings::get_settings(&app).obsidian;
+    if config.vault_path.is_none() {
+        return Err(AppError::NotConfigured("No Obsidian vault is set".to_string()));
+    }
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    obsidian::export_meeting(&db, &config, &meeting_id)
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(AppError::from)
+}
+
+/// Connect Asana with a personal access token
+#[tauri::command]
+pub async fn set_asana_token(token: String) -> Result<(), AppError> {
+    asana::store_token(&token).map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_asana_token() -> Result<(), AppError> {
+    asana::clear_token().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_asana_token() -> Result<bool, AppError> {
+    Ok(asana::has_token())
+}
+
+/// Create Asana tasks for a meeting's action items; items synced before are skipped
+#[tauri::command]
+pub async fn sync_action_items_to_asana(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<AsanaSyncResult, AppError> {
+    let config = crate::settings::get_settings(&app).asana;
+    if !asana::has_token() {
+        return Err(AppError::NotConfigured("Asana is not connected".to_string()));
+    }
+    if config.project_gid.trim().is_empty() {
+        return Err(AppError::NotConfigured(
+            "Choose an Asana project to sync to".to_string(),
+        ));
+    }
+    let digest = load_digest(&db, &meeting_id)?;
+    asana::sync_digest(&db, &config, &digest)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Connect Trello with an API key and a token generated for it
+#[tauri::command]
+pub async fn set_trello_credentials(api_key: String, token: String) -> Result<(), AppError> {
+    trello::store_credentials(&TrelloCredentials { api_key, token })
+        .map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_trello_credentials() -> Result<(), AppError> {
+    trello::clear_credentials().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_trello_credentials() -> Result<bool, AppError> {
+    Ok(trello::has_credentials())
+}
+
+fn require_trello() -> Result<(), AppError> {
+    if trello::has_credentials() {
+        Ok(())
+    } else {
+        Err(AppError::NotConfigured("Trello is not connected".to_string()))
+    }
+}
+
+/// Open lists on a board, for mapping action items and user stories to lists
+#[tauri::command]
+pub async fn list_trello_lists(board_id: String) -> Result<Vec<TrelloList>, AppError> {
+    require_trello()?;
+    if board_id.trim().is_empty() {
+        return Err(AppError::InvalidInput("Trello board id is empty".to_string()));
+    }
+    trello::board_lists(&board_id).await.map_err(AppError::from)
+}
+
+/// Create cards for a meeting's action items in the mapped list; items pushed before are
+/// skipped
+#[tauri::command]
+pub async fn push_action_items_to_trello(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<TrelloPushResult, AppError> {
+    require_trello()?;
+    let config = crate::settings::get_settings(&app).trello;
+    if config.action_items_list_id.trim().is_empty() {
+        return Err(AppError::NotConfigured(
+            "Choose a Trello list for action items".to_string(),
+        ));
+    }
+    let digest = load_digest(&db, &meeting_id)?;
+    let cards = trello::action_item_cards(&digest);
+    trello::push_cards(&db, &meeting_id, &config.action_items_list_id, &cards)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Create cards for the user stories of a PRD version (the latest by default)
+#[tauri::command]
+pub async fn push_user_stories_to_trello(
+    app: AppHandle,
+    meeting_id: String,
+    version: Option<u32>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<TrelloPushResult, AppError> {
+    require_trello()?;
+    let config = crate::settings::get_settings(&app).trello;
+    if config.user_stories_list_id.trim().is_empty() {
+        return Err(AppError::NotConfigured(
+            "Choose a Trello list for user stories".to_string(),
+        ));
+    }
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    let version = version
+        .or_else(|| crate::document_generation::prd_storage::latest_version(&meeting_id))
+        .ok_or_else(|| AppError::NotFound(format!("PRD for meeting {}", meeting_id)))?;
+    let (_, content, _) = crate::document_generation::load_prd_version(&meeting_id, version)
+        .map_err(|e| AppError::NotFound(format!("PRD v{}: {}", version, e)))?;
+    let cards = trello::user_story_cards(&meeting_id, version, &content);
+    trello::push_cards(&db, &meeting_id, &config.user_stories_list_id, &cards)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Cards created from a meeting's items, for linking back to them
+#[tauri::command]
+pub async fn get_trello_cards(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<ExternalLink>, AppError> {
+    trello::meeting_cards(&db, &meeting_id).map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn set_jira_token(token: String) -> Result<(), AppError> {
+    jira::store_token(&token).map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_jira_token() -> Result<(), AppError> {
+    jira::clear_token().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_jira_token() -> Result<bool, AppError> {
+    Ok(jira::has_token())
+}
+
+/// Jira tickets mentioned in a saved meeting's transcript, with their current status
+#[tauri::command]
+pub async fn get_meeting_jira_tickets(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<JiraTicket>, AppError> {
+    if !jira::has_token() {
+        return Err(AppError::NotConfigured("Jira is not connected".to_string()));
+    }
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    let segments = db.get_segments(&meeting_id).map_err(AppError::from)?;
+    let text: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+    jira::tickets_for_text(&app, &text.join("\n"))
+        .await
+        .map_err(AppError::from)
+}
+
+/// Link a calendar by its ICS feed URL (Google Calendar's "secret address in iCal
+/// format" or a `webcal://` link) and return its upcoming meetings
+#[tauri::command]
+pub async fn link_calendar(
+    app: AppHandle,
+    feed_url: String,
+) -> Result<Vec<CalendarEvent>, AppError> {
+    let feed_url = calendar::normalize_feed_url(&feed_url).map_err(AppError::InvalidInput)?;
+    calendar::link(&feed_url).await.map_err(AppError::from)?;
+    calendar::upcoming_events(&crate::settings::get_settings(&app).calendar)
+        .await
+        .map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn unlink_calendar() -> Result<(), AppError> {
+    calendar::unlink().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn is_calendar_linked() -> Result<bool, AppError> {
+    Ok(calendar::is_linked())
+}
+
+/// Events in progress or starting within the configured lookahead, soonest first
+#[tauri::command]
+pub async fn list_upcoming_meetings(app: AppHandle) -> Result<Vec<CalendarEvent>, AppError> {
+    if !calendar::is_linked() {
+        return Err(AppError::NotConfigured("No calendar is linked".to_string()));
+    }
+    calendar::upcoming_events(&crate::settings::get_settings(&app).calendar)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Give MeetingCoder write access to the calendar with an OAuth client and refresh token,
+/// checked by exchanging it for an access token
+#[tauri::command]
+pub async fn set_calendar_write_access(
+    provider: CalendarProvider,
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+) -> Result<(), AppError> {
+    calendar_writeback::store_credentials(&CalendarWriteCredentials {
+        provider,
+        client_id,
+        client_secret,
+        refresh_token,
+    })
+    .await
+    .map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_calendar_write_access() -> Result<(), AppError> {
+    calendar_writeback::clear_credentials().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_calendar_write_access() -> Result<bool, AppError> {
+    Ok(calendar_writeback::has_credentials())
+}
+
+/// Tie a meeting to a calendar event occurrence (iCalendar UID and start in Unix
+/// milliseconds) so its summary is written there when the meeting ends
+#[tauri::command]
+pub async fn link_meeting_to_calendar_event(
+    meeting_id: String,
+    event_uid: String,
+    starts_at: i64,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<(), AppError> {
+    if event_uid.trim().is_empty() {
+        return Err(AppError::InvalidInput("An event UID is required".to_string()));
+    }
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    calendar_writeback::record_event(&db, &meeting_id, event_uid.trim(), starts_at, None)
+        .map_err(AppError::from)
+}
+
+/// Write a saved meeting's summary into the calendar event it was started for
+#[tauri::command]
+pub async fn write_meeting_summary_to_calendar(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<CalendarWriteBack, AppError> {
+    if !calendar_writeback::has_credentials() {
+        return Err(AppError::NotConfigured(
+            "Calendar write access is not set up".to_string(),
+        ));
+    }
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    calendar_writeback::write_back(&db, &meeting_id)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Secret that outgoing webhook requests are signed with, created on first use
+#[tauri::command]
+pub async fn get_webhook_signing_secret() -> Result<String, AppError> {
+    webhooks::signing_secret().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn regenerate_webhook_signing_secret() -> Result<String, AppError> {
+    webhooks::regenerate_secret().map_err(AppError::from)
+}
+
+/// Recent webhook deliveries, newest first (100 by default)
+#[tauri::command]
+pub async fn list_webhook_deliveries(
+    limit: Option<usize>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<WebhookDelivery>, AppError> {
+    db.list_webhook_deliveries(limit.unwrap_or(100))
+        .map_err(AppError::from)
+}
+
+/// Send a logged delivery again; it is logged as a new delivery
+#[tauri::command]
+pub async fn redeliver_webhook(
+    app: AppHandle,
+    delivery_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<(), AppError> {
+    if db
+        .get_webhook_delivery(&delivery_id)
+        .map_err(AppError::from)?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!("Webhook delivery {}", delivery_id)));
+    }
+    webhooks::redeliver(&app, &delivery_id).map_err(AppError::from)
+}
+
+/// Send a `ping` event to an endpoint to check it's reachable and verifies signatures
+#[tauri::command]
+pub async fn send_test_webhook(app: AppHandle, endpoint_id: String) -> Result<(), AppError> {
+    let known = crate::settings::get_settings(&app)
+        .webhooks
+        .endpoints
+        .iter()
+        .any(|e| e.id == endpoint_id);
+    if !known {
+        return Err(AppError::NotFound(format!("Webhook endpoint {}", endpoint_id)));
+    }
+    webhooks::send_test(&app, &endpoint_id).map_err(AppError::from)
+}
@@ -1,17 +1,34 @@
+use crate::commands::AppError;
 use crate::summarization::llm;
 use tauri::command;
 
 #[command]
-pub async fn store_claude_api_key(api_key: String) -> Result<(), String> {
-    llm::store_api_key(&api_key).map_err(|e| e.to_string())
+pub async fn store_claude_api_key(api_key: String) -> Result<(), AppError> {
+    llm::store_api_key(&api_key).map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[command]
-pub async fn has_claude_api_key() -> Result<bool, String> {
+pub async fn has_claude_api_key() -> Result<bool, AppError> {
     Ok(llm::has_api_key())
 }
 
 #[command]
-pub async fn delete_claude_api_key() -> Result<(), String> {
-    llm::delete_api_key().map_err(|e| e.to_string())
+pub async fn delete_claude_api_key() -> Result<(), AppError> {
+    llm::delete_api_key().map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Key for OpenAI or another OpenAI-compatible endpoint (`llm_provider = "openai"`)
+#[command]
+pub async fn store_openai_api_key(api_key: String) -> Result<(), AppError> {
+    llm::store_openai_api_key(&api_key).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+#[command]
+pub async fn has_openai_api_key() -> Result<bool, AppError> {
+    Ok(llm::has_openai_api_key())
+}
+
+#[command]
+pub async fn delete_openai_api_key() -> Result<(), AppError> {
+    llm::delete_openai_api_key().map_err(|e| AppError::Internal(e.to_string()))
 }
@@ -0,0 +1,17 @@
+use crate::commands::AppError;
+use crate::integrations::local_api;
+use tauri::AppHandle;
+
+/// Token external tools send as `Authorization: Bearer <token>`
+#[tauri::command]
+pub async fn get_local_api_token() -> Result<String, AppError> {
+    local_api::api_token().map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Issue a new token and restart the server so the old one stops working
+#[tauri::command]
+pub async fn regenerate_local_api_token(app: AppHandle) -> Result<String, AppError> {
+    let token = local_api::regenerate_token().map_err(|e| e.to_string())?;
+    local_api::restart(&app);
+    Ok(token)
+}
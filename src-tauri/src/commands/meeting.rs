@@ -1,10 +1,30 @@
-use crate::managers::meeting::{MeetingManager, MeetingStatus, MeetingSummary, TranscriptSegment};
+use crate::audio_toolkit::audio::load_audio_file_to_mono_16k;
+use crate::commands::AppError;
+use crate::managers::history::HistoryManager;
+use crate::managers::meeting::{
+    MeetingManager, MeetingSession, MeetingStatus, MeetingSummary, TranscriptSegment,
+};
+use crate::managers::model::ModelManager;
+use crate::managers::transcription::ImportTranscriptionManager;
+use crate::meeting::recovery::{InterruptedMeeting, RecoveryAction};
+use crate::storage::analytics::{self, MeetingAnalytics};
+use crate::storage::bundle::{self, BundleManifest};
+use crate::storage::clips::{self, ClipExport, ClipSource};
+use crate::storage::db::{ImportReport, MeetingDb, MeetingMarker, StoredMeeting, StoredSummary};
+use crate::storage::export;
+use crate::storage::playback::{self, AudioInfo, AudioRange, WaveformPeaks};
+use crate::storage::project_index::{self, ProjectIndex};
+use crate::storage::retention::{self, RetentionReport};
+use crate::storage::tasks;
 use crate::storage::transcript::{TranscriptStorage, TranscriptMetadata};
+use crate::storage::trash::{self, TrashedMeeting};
+use crate::summarization::action_items::ActionItem;
+use crate::summarization::chapters::{self, Chapter};
 use chrono::{DateTime, Local, TimeZone};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, Manager, State};
 
 /// Meeting history entry for the History UI
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,55 +38,114 @@ pub struct MeetingHistoryEntry {
 pub async fn start_meeting(
     meeting_name: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     meeting_manager
         .start_meeting(meeting_name)
         .await
-        .map_err(|e| format!("Failed to start meeting: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to start meeting: {}", e)))
 }
 
 #[tauri::command]
 pub async fn end_meeting(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<MeetingSummary, String> {
+) -> Result<MeetingSummary, AppError> {
     meeting_manager
         .end_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("Failed to end meeting: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to end meeting: {}", e)))
 }
 
 #[tauri::command]
 pub async fn pause_meeting(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     meeting_manager
         .pause_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("Failed to pause meeting: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to pause meeting: {}", e)))
 }
 
 #[tauri::command]
 pub async fn resume_meeting(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     meeting_manager
         .resume_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("Failed to resume meeting: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to resume meeting: {}", e)))
+}
+
+/// Meetings the app stopped recording without ending, e.g. after a crash
+#[tauri::command]
+pub async fn list_interrupted_meetings(
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<Vec<InterruptedMeeting>, AppError> {
+    Ok(meeting_manager.interrupted_meetings().await)
+}
+
+/// Resume recording an interrupted meeting, finalize it like a normally ended one, or
+/// discard it (its files are kept). Returns the summary when finalized.
+#[tauri::command]
+pub async fn recover_meeting(
+    app: AppHandle,
+    meeting_id: String,
+    action: RecoveryAction,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<Option<MeetingSummary>, AppError> {
+    if !meeting_manager
+        .interrupted_meetings()
+        .await
+        .iter()
+        .any(|m| m.meeting_id == meeting_id)
+    {
+        return Err(AppError::NotFound(format!("Interrupted meeting {}", meeting_id)));
+    }
+    // Ending a meeting stops audio capture, so neither can run beside a live meeting
+    if action != RecoveryAction::Discard {
+        if let Some(current) = meeting_manager.current_meeting().await {
+            return Err(AppError::Busy(format!(
+                "Meeting '{}' is in progress",
+                current.name
+            )));
+        }
+    }
+    let summary = meeting_manager
+        .recover_meeting(&meeting_id, action)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to recover meeting: {}", e)))?;
+    if action == RecoveryAction::Resume {
+        crate::events::emit(
+            &app,
+            crate::events::AppEvent::meeting_state(&meeting_id, "recording", "recovery"),
+        );
+    }
+    Ok(summary)
 }
 
 #[tauri::command]
 pub async fn get_live_transcript(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<Vec<TranscriptSegment>, String> {
+) -> Result<Vec<TranscriptSegment>, AppError> {
     meeting_manager
         .get_live_transcript(&meeting_id)
         .await
-        .map_err(|e| format!("Failed to get transcript: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to get transcript: {}", e)))
+}
+
+/// Last `n` (default 5, at most 50) transcript segments of the meeting in progress, oldest
+/// first; empty when no meeting is running
+#[tauri::command]
+pub async fn get_recent_transcript_lines(
+    n: Option<usize>,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<Vec<TranscriptSegment>, AppError> {
+    Ok(meeting_manager
+        .recent_transcript_lines(n.unwrap_or(5).min(50))
+        .await)
 }
 
 #[tauri::command]
@@ -74,17 +153,17 @@ pub async fn update_speaker_labels(
     meeting_id: String,
     mapping: HashMap<String, String>,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     meeting_manager
         .update_speaker_labels(&meeting_id, mapping)
         .await
-        .map_err(|e| format!("Failed to update speaker labels: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to update speaker labels: {}", e)))
 }
 
 #[tauri::command]
 pub async fn get_active_meetings(
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, AppError> {
     Ok(meeting_manager.get_active_meetings().await)
 }
 
@@ -99,11 +178,11 @@ pub struct MeetingInfo {
 pub async fn get_meeting_info(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<MeetingInfo, String> {
+) -> Result<MeetingInfo, AppError> {
     let m = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("Failed to get meeting: {}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
     let status = match m.status {
         MeetingStatus::Recording => "recording",
         MeetingStatus::Paused => "paused",
@@ -112,21 +191,52 @@ pub async fn get_meeting_info(
     Ok(MeetingInfo { id: m.id, name: m.name, status: status.to_string() })
 }
 
+/// Translate the active meeting's new segments into `language` (e.g. "en"), or stop
+/// translating with None
+#[tauri::command]
+pub async fn set_meeting_translation(
+    meeting_id: String,
+    language: Option<String>,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<(), AppError> {
+    let language = crate::meeting::translation::normalize_language(language.as_deref());
+    meeting_manager
+        .set_translation_language(&meeting_id, language)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))
+}
+
+/// Send the active meeting's remaining chunks to the cloud provider, or back to the local model
+#[tauri::command]
+pub async fn set_meeting_cloud_asr(
+    meeting_id: String,
+    enabled: bool,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<(), AppError> {
+    meeting_manager
+        .set_cloud_asr(&meeting_id, enabled)
+        .await
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))
+}
+
 #[tauri::command]
 pub async fn get_meeting_project_path(
     meeting_id: String,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, AppError> {
     match meeting_manager.get_meeting(&meeting_id).await {
         Ok(meeting) => Ok(meeting.project_path.clone()),
-        Err(e) => Err(format!("Failed to get meeting: {}", e)),
+        Err(_) => Err(AppError::NotFound(format!("Meeting {}", meeting_id))),
     }
 }
 
 /// Compute the transcript directory path for a given meeting name and start time.
 /// start_time expects a Unix timestamp in seconds or milliseconds.
 #[tauri::command]
-pub fn get_transcript_dir_for(meeting_name: String, start_time: i64) -> Result<String, String> {
+pub fn get_transcript_dir_for(
+    meeting_name: String,
+    start_time: i64,
+) -> Result<String, AppError> {
     // Determine if the timestamp is in ms or s
     let secs = if start_time > 1_000_000_000_000 { // > ~2001-09-09 in ms
         start_time / 1000
@@ -135,7 +245,10 @@ pub fn get_transcript_dir_for(meeting_name: String, start_time: i64) -> Result<S
     };
 
     // Format date like TranscriptStorage (local time)
-    let dt: DateTime<Local> = Local.timestamp_opt(secs, 0).single().ok_or_else(|| "Invalid timestamp".to_string())?;
+    let dt: DateTime<Local> = Local
+        .timestamp_opt(secs, 0)
+        .single()
+        .ok_or_else(|| AppError::InvalidInput("Invalid timestamp".to_string()))?;
     let date_str = dt.format("%Y-%m-%d").to_string();
 
     // Sanitize like TranscriptStorage::generate_meeting_dir_name
@@ -155,7 +268,7 @@ pub fn get_transcript_dir_for(meeting_name: String, start_time: i64) -> Result<S
 
 /// List all saved meeting transcripts
 #[tauri::command]
-pub fn list_saved_meetings() -> Result<Vec<MeetingHistoryEntry>, String> {
+pub fn list_saved_meetings() -> Result<Vec<MeetingHistoryEntry>, AppError> {
     let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
     let meeting_dirs = storage.list_meetings().map_err(|e| e.to_string())?;
 
@@ -185,7 +298,7 @@ pub fn list_saved_meetings() -> Result<Vec<MeetingHistoryEntry>, String> {
 
 /// Open a meeting folder in the file manager
 #[tauri::command]
-pub fn open_meeting_folder(dir_path: String) -> Result<(), String> {
+pub fn open_meeting_folder(dir_path: String) -> Result<(), AppError> {
     use std::process::Command;
 
     #[cfg(target_os = "macos")]
@@ -193,7 +306,7 @@ pub fn open_meeting_folder(dir_path: String) -> Result<(), String> {
         Command::new("open")
             .arg(&dir_path)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::Io(format!("Failed to open folder: {}", e)))?;
     }
 
     #[cfg(target_os = "windows")]
@@ -201,7 +314,7 @@ pub fn open_meeting_folder(dir_path: String) -> Result<(), String> {
         Command::new("explorer")
             .arg(&dir_path)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::Io(format!("Failed to open folder: {}", e)))?;
     }
 
     #[cfg(target_os = "linux")]
@@ -209,15 +322,1004 @@ pub fn open_meeting_folder(dir_path: String) -> Result<(), String> {
         Command::new("xdg-open")
             .arg(&dir_path)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::Io(format!("Failed to open folder: {}", e)))?;
     }
 
     Ok(())
 }
 
-/// Delete a saved meeting transcript
+/// Delete a saved meeting. The transcript directory moves to the trash and can be
+/// restored until the retention window (`retention.trash_days`) passes.
+#[tauri::command]
+pub fn delete_saved_meeting(
+    app: AppHandle,
+    dir_name: String,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+    queue: State<'_, Arc<crate::queue::Queue>>,
+) -> Result<TrashedMeeting, AppError> {
+    let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
+    let metadata = storage.load_transcript(&dir_name).ok().map(|(m, _)| m);
+    let trashed = trash::move_to_trash(
+        storage.base_path(),
+        &trash::trash_dir().map_err(|e| e.to_string())?,
+        &dir_name,
+        metadata.as_ref().map(|m| m.meeting_id.clone()),
+        metadata.map(|m| m.name),
+        chrono::Utc::now().timestamp_millis(),
+    )
+    .map_err(|e| e.to_string())?;
+    if let Some(meeting_id) = &trashed.meeting_id {
+        if let Err(e) = crate::commands::transcription::cancel_jobs(&app, &queue, meeting_id) {
+            log::warn!("Failed to cancel queued jobs of meeting {}: {}", meeting_id, e);
+        }
+    }
+    if let Err(e) = db.delete_by_dir_name(&dir_name) {
+        log::warn!("Failed to delete meeting {} from database: {}", dir_name, e);
+    }
+    if let Err(e) = history.delete_meeting_entries_by_dir(&dir_name) {
+        log::warn!("Failed to delete meeting {} from history: {}", dir_name, e);
+    }
+    Ok(trashed)
+}
+
+/// Meetings in the trash, most recently deleted first
+#[tauri::command]
+pub fn list_trashed_meetings(app: AppHandle) -> Result<Vec<TrashedMeeting>, AppError> {
+    let trash_days = crate::settings::get_settings(&app).retention.trash_days;
+    let trash_base = trash::trash_dir().map_err(|e| e.to_string())?;
+    trash::list_trash(&trash_base, trash_days).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Move a trashed meeting back into storage and re-add it to the database and history
+#[tauri::command]
+pub fn restore_meeting(
+    trash_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<Option<StoredMeeting>, AppError> {
+    let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
+    let trash_base = trash::trash_dir().map_err(|e| e.to_string())?;
+    let restored = trash::restore_from_trash(&trash_base, storage.base_path(), &trash_id)
+        .map_err(|e| e.to_string())?;
+
+    let meeting_id = db
+        .import_saved_dir(&storage, storage.base_path(), &restored.dir_name)
+        .map_err(|e| format!("Restored files, but failed to re-index the meeting: {}", e))?
+        .or(restored.meeting_id);
+    let Some(meeting) = meeting_id.and_then(|id| db.get_meeting(&id).ok().flatten()) else {
+        return Ok(None);
+    };
+    add_history_entry(&db, &history, &meeting);
+    Ok(Some(meeting))
+}
+
+fn add_history_entry(db: &MeetingDb, history: &HistoryManager, meeting: &StoredMeeting) {
+    let text = db
+        .get_segments(&meeting.id)
+        .unwrap_or_default()
+        .iter()
+        .map(|s| s.text.trim().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Err(e) = history.upsert_meeting_entry(
+        &meeting.id,
+        &meeting.name,
+        meeting.start_time / 1000,
+        meeting.dir_name.as_deref(),
+        &text,
+    ) {
+        log::warn!("Failed to add meeting {} to history: {}", meeting.id, e);
+    }
+}
+
+/// Load a saved meeting, apply `edit`, and write the result back to the transcript files,
+/// the database, and history. With `regenerate_summary`, `summary.md` is rebuilt too.
+fn edit_saved_meeting(
+    dir_name: &str,
+    regenerate_summary: bool,
+    db: &MeetingDb,
+    history: &HistoryManager,
+    edit: impl FnOnce(&mut MeetingSession) -> Result<(), AppError>,
+) -> Result<StoredMeeting, AppError> {
+    let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
+    let mut session = storage.load_session(dir_name).map_err(|e| e.to_string())?;
+    // Summaries use the project for context; transcript files don't record it
+    session.project_path = db
+        .get_meeting(&session.id)
+        .ok()
+        .flatten()
+        .and_then(|m| m.project_path);
+    edit(&mut session)?;
+
+    let meeting_dir = storage
+        .rewrite_transcript(dir_name, &session)
+        .map_err(|e| format!("Failed to rewrite transcript: {}", e))?;
+    db.save_meeting(&session, Some(dir_name))
+        .map_err(|e| e.to_string())?;
+    // Keep owners and wording of extracted tasks in step with the transcript
+    if meeting_dir.join(tasks::TASKS_FILE).is_file() {
+        if let Err(e) = tasks::sync(&meeting_dir, &session.id, &session.transcript_segments) {
+            log::warn!("Failed to update tasks of {}: {}", dir_name, e);
+        }
+    }
+    // Speakers or text may have changed, so the analytics are stale
+    if let Err(e) = analytics::sync(&meeting_dir, &session.id, &session.transcript_segments) {
+        log::warn!("Failed to update analytics of {}: {}", dir_name, e);
+    }
+    if regenerate_summary && !session.transcript_segments.is_empty() {
+        let md = crate::managers::meeting::summary_markdown(&session);
+        std::fs::write(meeting_dir.join("summary.md"), &md)
+            .map_err(|e| format!("Failed to write summary.md: {}", e))?;
+        db.save_summary(&session.id, "markdown", &md)
+            .map_err(|e| e.to_string())?;
+    }
+    let meeting = db
+        .get_meeting(&session.id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", session.id)))?;
+    add_history_entry(db, history, &meeting);
+    log::info!("Edited saved meeting {}", dir_name);
+    Ok(meeting)
+}
+
+/// Add segments transcribed after a meeting was saved (e.g. chunks finished after a
+/// restart) to its saved transcript. Returns false when the meeting isn't saved yet.
+pub(crate) fn attach_segments_to_saved(
+    app: &AppHandle,
+    meeting_id: &str,
+    segments: Vec<TranscriptSegment>,
+) -> Result<bool, AppError> {
+    let (Some(db), Some(history)) = (
+        app.try_state::<Arc<MeetingDb>>(),
+        app.try_state::<Arc<HistoryManager>>(),
+    ) else {
+        return Ok(false);
+    };
+    let Some(dir_name) = db
+        .get_meeting(meeting_id)
+        .map_err(|e| e.to_string())?
+        .and_then(|m| m.dir_name)
+    else {
+        return Ok(false);
+    };
+    edit_saved_meeting(&dir_name, false, &db, &history, |session| {
+        crate::meeting::recovery::merge_recovered(&mut session.transcript_segments, segments);
+        Ok(())
+    })?;
+    Ok(true)
+}
+
+/// Rename speakers in a saved meeting (e.g. "Speaker 1" -> "John")
 #[tauri::command]
-pub fn delete_saved_meeting(dir_name: String) -> Result<(), String> {
+pub fn update_saved_speaker_labels(
+    dir_name: String,
+    mapping: HashMap<String, String>,
+    regenerate_summary: Option<bool>,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<StoredMeeting, AppError> {
+    if mapping.values().any(|label| label.trim().is_empty()) {
+        return Err(AppError::InvalidInput("Speaker labels cannot be empty".to_string()));
+    }
+    edit_saved_meeting(&dir_name, regenerate_summary.unwrap_or(false), &db, &history, |session| {
+        let mapping = mapping
+            .iter()
+            .map(|(from, to)| (from.clone(), to.trim().to_string()))
+            .collect();
+        session.relabel_speakers(&mapping);
+        Ok(())
+    })
+}
+
+/// Relabel a saved meeting's speakers by voice from its recording. Custom speaker names
+/// are replaced with "Speaker N"; the summary is regenerated with the new labels.
+#[tauri::command]
+pub async fn rediarize_meeting(
+    app: AppHandle,
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<StoredMeeting, AppError> {
+    if !crate::diarization::status(&app).model_downloaded {
+        return Err(AppError::NotConfigured(
+            "Download the speaker model to re-diarize meetings".to_string(),
+        ));
+    }
+    let audio_path = meeting_audio_path(&db, &meeting_id)?;
+    let dir_name = db
+        .get_meeting(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .and_then(|m| m.dir_name)
+        .ok_or_else(|| AppError::NotFound(format!("Saved files of meeting {}", meeting_id)))?;
+    let db = db.inner().clone();
+    let history = history.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        edit_saved_meeting(&dir_name, true, &db, &history, |session| {
+            crate::diarization::rediarize(&app, &audio_path, &mut session.transcript_segments)
+                .map_err(|e| AppError::Internal(format!("Re-diarization failed: {}", e)))?;
+            let mut speakers: Vec<String> = Vec::new();
+            for segment in &session.transcript_segments {
+                if !speakers.contains(&segment.speaker) {
+                    speakers.push(segment.speaker.clone());
+                }
+            }
+            session.participants = speakers;
+            Ok(())
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Re-run a saved meeting's recording through another model. The previous transcript,
+/// summary, and tasks are kept under `versions/`; speakers carry over by time overlap, and
+/// the summary (and the PRD, if the meeting has one) is regenerated from the new text.
+#[tauri::command]
+pub async fn retranscribe_meeting(
+    app: AppHandle,
+    meeting_id: String,
+    model_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+    model_manager: State<'_, Arc<ModelManager>>,
+) -> Result<StoredMeeting, AppError> {
+    let model = model_manager
+        .get_model_info(&model_id)
+        .ok_or_else(|| AppError::NotFound(format!("Model {}", model_id)))?;
+    if !model.is_downloaded {
+        return Err(AppError::NotConfigured(format!(
+            "Download {} to re-transcribe with it",
+            model.name
+        )));
+    }
+    let stored = db
+        .get_meeting(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let dir_name = stored
+        .dir_name
+        .clone()
+        .ok_or_else(|| AppError::NotFound(format!("Saved files of meeting {}", meeting_id)))?;
+    let samples = load_meeting_samples(&db, &stored)?;
+
+    // The import engine, so the live model stays loaded
+    let transcription_manager = app.state::<ImportTranscriptionManager>().0.clone();
+    emit_retranscribe_progress(&app, "loading-model", Some(0));
+    {
+        let tm = transcription_manager.clone();
+        let model_id = model_id.clone();
+        tauri::async_runtime::spawn_blocking(move || tm.load_model(&model_id))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("Failed to load {}: {}", model.name, e))?;
+    }
+    let segments = crate::commands::import::transcribe_samples(
+        &app,
+        transcription_manager,
+        &meeting_id,
+        crate::queue::JobPriority::Retranscribe,
+        &samples,
+        |percent| emit_retranscribe_progress(&app, "transcribing", Some(percent)),
+    )
+    .await?;
+    if segments.is_empty() {
+        return Err(AppError::Internal(format!("{} found no speech", model.name)));
+    }
+
+    emit_retranscribe_progress(&app, "finalizing", Some(100));
+    let label = format!("{}-before-{}", Local::now().format("%Y%m%d-%H%M%S"), model_id);
+    TranscriptStorage::with_default_path()
+        .and_then(|storage| storage.snapshot_transcript(&dir_name, &label))
+        .map_err(|e| format!("Failed to keep the previous transcript: {}", e))?;
+    let meeting = {
+        let db = db.inner().clone();
+        let history = history.inner().clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            edit_saved_meeting(&dir_name, true, &db, &history, |session| {
+                session.transcript_segments =
+                    carry_over_speakers(&session.transcript_segments, segments);
+                let mut speakers: Vec<String> = Vec::new();
+                for segment in &session.transcript_segments {
+                    if !speakers.contains(&segment.speaker) {
+                        speakers.push(segment.speaker.clone());
+                    }
+                }
+                session.participants = speakers;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| e.to_string())??
+    };
+    log::info!("Re-transcribed meeting {} with {}", meeting_id, model_id);
+
+    // Only meetings that already had a PRD get a new version
+    let mut prd = crate::document_generation::PRDGenerator::load(
+        meeting_id.clone(),
+        meeting.name.clone(),
+    )
+    .ok()
+    .filter(|g| !g.get_all_versions().is_empty());
+    if let Some(prd) = prd.as_mut() {
+        let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+        if let Err(e) = prd.generate_final_prd(&segments, &[]).await {
+            log::warn!("Failed to regenerate PRD for {}: {}", meeting_id, e);
+        }
+    }
+    Ok(meeting)
+}
+
+fn emit_retranscribe_progress(app: &AppHandle, stage: &str, percent: Option<u8>) {
+    crate::events::emit(
+        app,
+        crate::events::AppEvent::ImportProgress {
+            stage: stage.to_string(),
+            percent,
+        },
+    );
+}
+
+/// A saved meeting's audio as 16 kHz mono: its recording, or the project's segment files
+/// stitched together when no recording was composed
+fn load_meeting_samples(db: &MeetingDb, meeting: &StoredMeeting) -> Result<Vec<f32>, AppError> {
+    if let Ok(path) = meeting_audio_path(db, &meeting.id) {
+        return load_audio_file_to_mono_16k(&path)
+            .map_err(|e| AppError::Internal(format!("Failed to read {}: {}", path.display(), e)));
+    }
+    let segments_dir = meeting
+        .project_path
+        .as_deref()
+        .map(|p| std::path::Path::new(p).join("audio_segments"))
+        .filter(|dir| dir.is_dir())
+        .ok_or_else(|| AppError::NotFound(format!("Recorded audio of meeting {}", meeting.id)))?;
+    let mut files: Vec<std::path::PathBuf> = std::fs::read_dir(&segments_dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("segment_") && n.ends_with(".wav"))
+        })
+        .collect();
+    files.sort();
+    let mut samples = Vec::new();
+    for file in &files {
+        match load_audio_file_to_mono_16k(file) {
+            Ok(chunk) => samples.extend(chunk),
+            Err(e) => log::warn!("Skipping unreadable segment {}: {}", file.display(), e),
+        }
+    }
+    if samples.is_empty() {
+        return Err(AppError::NotFound(format!("Recorded audio of meeting {}", meeting.id)));
+    }
+    Ok(samples)
+}
+
+/// Give each new segment the speaker of the old segment it overlaps most in time
+fn carry_over_speakers(
+    old: &[TranscriptSegment],
+    mut new: Vec<TranscriptSegment>,
+) -> Vec<TranscriptSegment> {
+    for segment in &mut new {
+        let best = old
+            .iter()
+            .map(|o| {
+                let overlap =
+                    o.end_time.min(segment.end_time) - o.start_time.max(segment.start_time);
+                (o, overlap)
+            })
+            .filter(|(_, overlap)| *overlap > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((o, _)) = best {
+            segment.speaker = o.speaker.clone();
+        }
+    }
+    new
+}
+
+/// Replace the participant list of a saved meeting
+#[tauri::command]
+pub fn update_saved_participants(
+    dir_name: String,
+    participants: Vec<String>,
+    regenerate_summary: Option<bool>,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<StoredMeeting, AppError> {
+    edit_saved_meeting(&dir_name, regenerate_summary.unwrap_or(false), &db, &history, |session| {
+        let mut cleaned: Vec<String> = Vec::new();
+        for name in participants.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+            if !cleaned.iter().any(|c| c == name) {
+                cleaned.push(name.to_string());
+            }
+        }
+        session.participants = cleaned;
+        Ok(())
+    })
+}
+
+/// Correct the text of one segment (by position) in a saved meeting
+#[tauri::command]
+pub fn update_saved_segment_text(
+    dir_name: String,
+    segment_index: usize,
+    text: String,
+    regenerate_summary: Option<bool>,
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<StoredMeeting, AppError> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err(AppError::InvalidInput("Segment text cannot be empty".to_string()));
+    }
+    edit_saved_meeting(&dir_name, regenerate_summary.unwrap_or(false), &db, &history, |session| {
+        let count = session.transcript_segments.len();
+        let segment = session
+            .transcript_segments
+            .get_mut(segment_index)
+            .ok_or_else(|| {
+                AppError::InvalidInput(format!(
+                    "Segment {} out of range (meeting has {})",
+                    segment_index, count
+                ))
+            })?;
+        segment.text = text;
+        Ok(())
+    })
+}
+
+/// Import meetings saved on disk (before SQLite storage existed) into the database,
+/// and list them in history
+#[tauri::command]
+pub fn import_saved_meetings_to_db(
+    db: State<'_, Arc<MeetingDb>>,
+    history: State<'_, Arc<HistoryManager>>,
+) -> Result<ImportReport, AppError> {
     let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
-    storage.delete_transcript(&dir_name).map_err(|e| e.to_string())
+    let base_path = TranscriptStorage::default_path().map_err(|e| e.to_string())?;
+    let report = db.import_from_disk(&storage, &base_path).map_err(|e| e.to_string())?;
+
+    for meeting in db.list_meetings().map_err(|e| e.to_string())? {
+        add_history_entry(&db, &history, &meeting);
+    }
+    Ok(report)
+}
+
+/// Meetings that targeted a project, with summaries, PRD versions, and branch/PR links.
+/// Also refreshes the project's index file.
+#[tauri::command]
+pub fn list_meetings_for_project(
+    project_path: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<ProjectIndex, AppError> {
+    project_index::refresh_index(&db, std::path::Path::new(&project_path))
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// List meetings stored in the database, most recent first
+#[tauri::command]
+pub fn list_stored_meetings(
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<StoredMeeting>, AppError> {
+    db.list_meetings().map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Transcript segments of a stored meeting
+#[tauri::command]
+pub fn get_stored_transcript(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<TranscriptSegment>, AppError> {
+    db.get_segments(&meeting_id).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Bookmark a moment in a meeting
+#[tauri::command]
+pub fn add_meeting_marker(
+    meeting_id: String,
+    at_seconds: f64,
+    label: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<i64, AppError> {
+    db.add_marker(&meeting_id, at_seconds, label.trim())
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+#[tauri::command]
+pub fn get_meeting_markers(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<MeetingMarker>, AppError> {
+    db.list_markers(&meeting_id).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+#[tauri::command]
+pub fn get_meeting_summaries(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<StoredSummary>, AppError> {
+    db.list_summaries(&meeting_id).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Star a meeting to exempt it from retention cleanup
+#[tauri::command]
+pub fn set_meeting_starred(
+    meeting_id: String,
+    starred: bool,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<(), AppError> {
+    match db.set_starred(&meeting_id, starred) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(AppError::NotFound(format!("Meeting {}", meeting_id))),
+        Err(e) => Err(AppError::Internal(e.to_string())),
+    }
+}
+
+/// Report what the retention policy would delete, without deleting anything
+#[tauri::command]
+pub async fn preview_retention(app: AppHandle) -> Result<RetentionReport, AppError> {
+    tauri::async_runtime::spawn_blocking(move || retention::run_retention(&app, true))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Apply the retention policy now
+#[tauri::command]
+pub async fn apply_retention_now(app: AppHandle) -> Result<RetentionReport, AppError> {
+    tauri::async_runtime::spawn_blocking(move || retention::run_retention(&app, false))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Export a saved meeting (transcript, summary, PRD versions, markers, and optionally
+/// audio) as a single zip bundle. Returns the bundle path.
+#[tauri::command]
+pub fn export_meeting_bundle(
+    meeting_id: String,
+    destination: Option<String>,
+    include_audio: Option<bool>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<String, AppError> {
+    let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
+    let dir_name = storage
+        .find_meeting_dir(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Saved transcript for meeting {}", meeting_id)))?;
+    let (metadata, _) = storage.load_transcript(&dir_name).map_err(|e| e.to_string())?;
+
+    let destination = match destination {
+        Some(d) => std::path::PathBuf::from(d),
+        None => crate::storage::root::storage_dir("exports")
+            .map_err(|e| e.to_string())?
+            .join(format!("{}.zip", dir_name)),
+    };
+    let manifest = BundleManifest {
+        format_version: bundle::BUNDLE_FORMAT_VERSION,
+        meeting_id: meeting_id.clone(),
+        name: metadata.name,
+        dir_name: dir_name.clone(),
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        includes_audio: include_audio.unwrap_or(false),
+    };
+    let prd_dir = crate::document_generation::get_prd_directory(&meeting_id).ok();
+    let markers = db.list_markers(&meeting_id).unwrap_or_default();
+
+    bundle::write_bundle(
+        &destination,
+        &manifest,
+        &storage.base_path().join(&dir_name),
+        prd_dir.as_deref(),
+        &markers,
+    )
+    .map_err(|e| format!("Failed to export meeting: {}", e))?;
+    log::info!("Exported meeting {} to {:?}", meeting_id, destination);
+    Ok(destination.to_string_lossy().to_string())
+}
+
+/// Export a stored meeting's transcript as "srt", "vtt", "docx", "pdf", or "html".
+/// Returns the written file path.
+#[tauri::command]
+pub fn export_transcript(
+    meeting_id: String,
+    format: String,
+    destination: Option<String>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<String, AppError> {
+    let format = format.trim().to_lowercase();
+    let meeting = db
+        .get_meeting(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+
+    let started = Local
+        .timestamp_millis_opt(meeting.start_time)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let mut subtitle = started;
+    if let Some(end) = meeting.end_time {
+        subtitle.push_str(&format!(" · {} min", ((end - meeting.start_time) / 60_000).max(0)));
+    }
+    if !meeting.participants.is_empty() {
+        subtitle.push_str(&format!(" · {}", meeting.participants.join(", ")));
+    }
+    let bytes = export::render_transcript(&format, &meeting.name, &subtitle, &segments)
+        .map_err(|e| AppError::InvalidInput(e.to_string()))?;
+
+    let destination = match destination {
+        Some(d) => std::path::PathBuf::from(d),
+        None => crate::storage::root::storage_dir("exports")
+            .map_err(|e| e.to_string())?
+            .join(format!(
+                "{}.{}",
+                meeting.dir_name.clone().unwrap_or_else(|| meeting_id.clone()),
+                format
+            )),
+    };
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(&destination, bytes)
+        .map_err(|e| AppError::Io(format!("Failed to write export: {}", e)))?;
+    log::info!("Exported transcript of {} as {} to {:?}", meeting_id, format, destination);
+    Ok(destination.to_string_lossy().to_string())
+}
+
+/// Composed audio file of a saved meeting
+pub(crate) fn meeting_dir_path(db: &MeetingDb, meeting_id: &str) -> Result<std::path::PathBuf, AppError> {
+    let meeting = db
+        .get_meeting(meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let dir_name = meeting
+        .dir_name
+        .ok_or_else(|| AppError::NotFound(format!("Saved files of meeting {}", meeting_id)))?;
+    Ok(TranscriptStorage::default_path()
+        .map_err(|e| e.to_string())?
+        .join(dir_name))
+}
+
+fn meeting_audio_path(db: &MeetingDb, meeting_id: &str) -> Result<std::path::PathBuf, AppError> {
+    let path = meeting_dir_path(db, meeting_id)?.join(playback::FULL_AUDIO_FILE);
+    if !path.is_file() {
+        return Err(AppError::NotFound(format!("Recorded audio of meeting {}", meeting_id)));
+    }
+    Ok(path)
+}
+
+/// Format, data offset, and duration of a meeting's recorded audio
+#[tauri::command]
+pub fn get_meeting_audio_info(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<AudioInfo, AppError> {
+    let path = meeting_audio_path(&db, &meeting_id)?;
+    playback::read_wav_info(&path).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Raw bytes of the meeting's WAV file starting at `offset` (at most 4 MiB per call).
+/// Returned as a binary IPC response rather than a JSON array.
+#[tauri::command]
+pub fn get_audio_chunk(
+    meeting_id: String,
+    offset: u64,
+    len: u64,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<tauri::ipc::Response, AppError> {
+    let path = meeting_audio_path(&db, &meeting_id)?;
+    let bytes = playback::read_chunk(&path, offset, len).map_err(|e| e.to_string())?;
+    Ok(tauri::ipc::Response::new(bytes))
+}
+
+/// Time and byte range of a transcript segment within the meeting audio, for seeking
+#[tauri::command]
+pub fn get_segment_audio_range(
+    meeting_id: String,
+    segment_index: usize,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<AudioRange, AppError> {
+    let path = meeting_audio_path(&db, &meeting_id)?;
+    let info = playback::read_wav_info(&path).map_err(|e| e.to_string())?;
+    let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+    let segment = segments
+        .get(segment_index)
+        .ok_or_else(|| AppError::InvalidInput(format!("Segment {} out of range", segment_index)))?;
+    Ok(playback::segment_range(
+        &info,
+        segment_index,
+        segment.start_time,
+        segment.end_time,
+    ))
+}
+
+/// Cut `start..end` (seconds, e.g. between two bookmarks) of a saved meeting into a
+/// shareable MP3 plus a Markdown snippet of the transcript. Written to `destination`
+/// (a directory) or the exports folder.
+#[tauri::command]
+pub async fn export_clip(
+    meeting_id: String,
+    start: f64,
+    end: f64,
+    destination: Option<String>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<ClipExport, AppError> {
+    let path = meeting_audio_path(&db, &meeting_id)?;
+    let info = playback::read_wav_info(&path).map_err(|e| e.to_string())?;
+    let end = end.min(info.duration_seconds);
+    if !(start >= 0.0 && start < end) {
+        return Err(AppError::InvalidInput(format!(
+            "Clip range {:.1}s–{:.1}s is not within the {:.0}s recording",
+            start, end, info.duration_seconds
+        )));
+    }
+    let meeting = db
+        .get_meeting(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+    let markers = db.list_markers(&meeting_id).unwrap_or_default();
+    let dir = match destination {
+        Some(d) => std::path::PathBuf::from(d),
+        None => crate::storage::root::storage_dir("exports")
+            .map_err(|e| e.to_string())?
+            .join("clips"),
+    };
+    let mmss = |s: f64| format!("{:02}-{:02}", s as u64 / 60, s as u64 % 60);
+    let base_name = format!(
+        "{}_clip_{}_{}",
+        meeting.dir_name.clone().unwrap_or_else(|| meeting_id.clone()),
+        mmss(start),
+        mmss(end)
+    );
+
+    tauri::async_runtime::spawn_blocking(move || -> Result<ClipExport, AppError> {
+        let date = Local
+            .timestamp_millis_opt(meeting.start_time)
+            .single()
+            .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        let source = ClipSource {
+            meeting_id: &meeting_id,
+            title: &meeting.name,
+            date: &date,
+            segments: &segments,
+            markers: &markers,
+        };
+        let clip = clips::export_clip(&source, &path, &dir, &base_name, start, end)
+            .map_err(|e| AppError::Io(format!("Failed to export clip: {}", e)))?;
+        log::info!("Exported clip of {} to {:?}", meeting_id, clip.audio_path);
+        Ok(clip)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+pub(crate) fn load_tasks(
+    db: &MeetingDb,
+    meeting_id: &str,
+    dir: &std::path::Path,
+) -> Result<tasks::TaskList, AppError> {
+    match tasks::load(dir).map_err(|e| e.to_string())? {
+        Some(list) => Ok(list),
+        None => {
+            let segments = db.get_segments(meeting_id).map_err(|e| e.to_string())?;
+            Ok(tasks::sync(dir, meeting_id, &segments).map_err(|e| e.to_string())?)
+        }
+    }
+}
+
+/// A saved meeting's segments with PII replaced, as LLM prompts and GitHub posts see them
+/// when `redact_pii` is on; shown regardless of the setting so it can be checked first
+#[tauri::command]
+pub fn get_redacted_transcript(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<TranscriptSegment>, AppError> {
+    if !db.meeting_exists(&meeting_id).map_err(AppError::from)? {
+        return Err(AppError::NotFound(format!("Meeting {}", meeting_id)));
+    }
+    let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+    Ok(crate::redaction::redact_segments(&segments))
+}
+
+/// Topic chapters of a saved meeting from its metadata; meetings saved before chapters
+/// existed get them computed from their segments. Empty for short meetings.
+#[tauri::command]
+pub fn get_meeting_chapters(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<Chapter>, AppError> {
+    let dir = meeting_dir_path(&db, &meeting_id)?;
+    let metadata = std::fs::read(dir.join("metadata.json"))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<TranscriptMetadata>(&bytes).ok());
+    match metadata {
+        Some(metadata) if !metadata.chapters.is_empty() => Ok(metadata.chapters),
+        _ => {
+            let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+            Ok(chapters::detect(&segments))
+        }
+    }
+}
+
+/// Talk time, longest monologue, interruptions, and speaking pace per speaker of a saved
+/// meeting, computed into its `analytics.json` on first use
+#[tauri::command]
+pub fn get_meeting_analytics(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<MeetingAnalytics, AppError> {
+    let dir = meeting_dir_path(&db, &meeting_id)?;
+    if let Some(existing) = analytics::load(&dir).map_err(|e| e.to_string())? {
+        return Ok(existing);
+    }
+    let segments = db.get_segments(&meeting_id).map_err(|e| e.to_string())?;
+    Ok(analytics::sync(&dir, &meeting_id, &segments).map_err(|e| e.to_string())?)
+}
+
+/// Action items of a saved meeting, extracted into its `tasks.json` on first use
+#[tauri::command]
+pub fn list_meeting_tasks(
+    meeting_id: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<Vec<ActionItem>, AppError> {
+    let dir = meeting_dir_path(&db, &meeting_id)?;
+    Ok(load_tasks(&db, &meeting_id, &dir)?.items)
+}
+
+/// Mark one of a meeting's tasks done (or not done again)
+#[tauri::command]
+pub fn set_meeting_task_done(
+    meeting_id: String,
+    task_id: String,
+    done: bool,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<ActionItem, AppError> {
+    let dir = meeting_dir_path(&db, &meeting_id)?;
+    let mut list = load_tasks(&db, &meeting_id, &dir)?;
+    let item = tasks::set_done(&mut list, &task_id, done)
+        .map_err(|e| AppError::NotFound(e.to_string()))?;
+    tasks::save(&dir, &mut list).map_err(|e| e.to_string())?;
+    Ok(item)
+}
+
+/// Write a meeting's tasks as a Markdown checklist ("md") or CSV ("csv"). Defaults to the
+/// exports folder; returns the file path.
+#[tauri::command]
+pub fn export_meeting_tasks(
+    meeting_id: String,
+    format: String,
+    destination: Option<String>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<String, AppError> {
+    let format = format.trim().trim_start_matches('.').to_lowercase();
+    if !tasks::TASK_EXPORT_FORMATS.contains(&format.as_str()) {
+        return Err(AppError::InvalidInput(format!(
+            "Unsupported task export format '{}'",
+            format
+        )));
+    }
+    let items = load_tasks(&db, &meeting_id, &meeting_dir_path(&db, &meeting_id)?)?.items;
+    let meeting = db
+        .get_meeting(&meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
+    let content = tasks::render_export(&meeting.name, &meeting_id, &items, &format)
+        .map_err(|e| AppError::InvalidInput(e.to_string()))?;
+    let dir = match destination {
+        Some(d) => std::path::PathBuf::from(d),
+        None => crate::storage::root::storage_dir("exports")
+            .map_err(|e| e.to_string())?
+            .join("tasks"),
+    };
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!(
+        "{}_tasks.{}",
+        meeting.dir_name.unwrap_or_else(|| meeting_id.clone()),
+        format
+    ));
+    std::fs::write(&path, content).map_err(|e| e.to_string())?;
+    log::info!("Exported {} tasks of {} to {:?}", items.len(), meeting_id, path);
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Downsampled waveform peaks for a meeting's recording (default 1000 buckets), cached on disk
+#[tauri::command]
+pub async fn get_meeting_waveform(
+    meeting_id: String,
+    buckets: Option<usize>,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<WaveformPeaks, AppError> {
+    let path = meeting_audio_path(&db, &meeting_id)?;
+    let buckets = buckets
+        .unwrap_or(playback::DEFAULT_WAVEFORM_BUCKETS)
+        .clamp(10, 10_000);
+    tauri::async_runtime::spawn_blocking(move || playback::cached_peaks(&path, buckets))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Import a meeting bundle produced by `export_meeting_bundle`. Returns the meeting id.
+#[tauri::command]
+pub fn import_meeting_bundle(
+    bundle_path: String,
+    db: State<'_, Arc<MeetingDb>>,
+) -> Result<String, AppError> {
+    let bundle_path = std::path::PathBuf::from(bundle_path);
+    let manifest = bundle::read_bundle_manifest(&bundle_path).map_err(|e| e.to_string())?;
+    if db.meeting_exists(&manifest.meeting_id).map_err(|e| e.to_string())? {
+        return Err(AppError::InvalidInput(format!(
+            "Meeting \"{}\" is already in your history",
+            manifest.name
+        )));
+    }
+
+    // Security: the directory name comes from the bundle; only accept a single plain component
+    let safe_name = std::path::Path::new(&manifest.dir_name)
+        .components()
+        .all(|c| matches!(c, std::path::Component::Normal(_)))
+        && !manifest.dir_name.is_empty()
+        && !manifest.dir_name.contains(['/', '\\']);
+    if !safe_name {
+        return Err(AppError::InvalidInput("Invalid meeting directory name in bundle".to_string()));
+    }
+
+    let storage = TranscriptStorage::with_default_path().map_err(|e| e.to_string())?;
+    let mut dir_name = manifest.dir_name.clone();
+    let mut n = 1;
+    while storage.base_path().join(&dir_name).exists() {
+        n += 1;
+        dir_name = format!("{}-{}", manifest.dir_name, n);
+    }
+
+    let prd_dir = crate::document_generation::get_prd_directory(&manifest.meeting_id).map_err(|e| e.to_string())?;
+    bundle::extract_bundle(&bundle_path, &storage.base_path().join(&dir_name), &prd_dir)
+        .map_err(|e| format!("Failed to import meeting: {}", e))?;
+
+    db.import_saved_dir(&storage, storage.base_path(), &dir_name)
+        .map_err(|e| format!("Failed to index imported meeting: {}", e))?;
+    for marker in bundle::read_bundle_markers(&bundle_path).unwrap_or_default() {
+        let _ = db.add_marker(&manifest.meeting_id, marker.at_seconds, &marker.label);
+    }
+    log::info!("Imported meeting {} into {}", manifest.meeting_id, dir_name);
+    Ok(manifest.meeting_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, start_time: f64, end_time: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time,
+            end_time,
+            text: String::new(),
+            confidence: 0.9,
+            timestamp: std::time::SystemTime::UNIX_EPOCH,
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_carry_over_speakers() {
+        let old = vec![segment("Ana", 0.0, 10.0), segment("Ben", 10.0, 30.0)];
+        let new = vec![
+            segment("Speaker 1", 0.0, 8.0),
+            segment("Speaker 1", 8.0, 25.0),
+            segment("Speaker 1", 40.0, 45.0),
+        ];
+        let speakers: Vec<String> = carry_over_speakers(&old, new)
+            .into_iter()
+            .map(|s| s.speaker)
+            .collect();
+        assert_eq!(speakers, vec!["Ana", "Ben", "Speaker 1"]);
+    }
 }
@@ -5,14 +5,32 @@ pub mod models;
 pub mod system_audio;
 pub mod transcription;
 pub mod automation;
+pub mod backup;
 pub mod github;
 pub mod import;
 pub mod llm;
+pub mod cloud_sync;
+pub mod local_api;
 pub mod codebase;
 pub mod prd;
+pub mod self_test;
+pub mod speakers;
+pub mod diagnostics;
+pub mod permissions;
+pub mod integrations;
+pub mod error;
 
+pub use error::AppError;
+
+use crate::managers::meeting::MeetingManager;
+use crate::queue::Queue;
+use crate::settings;
+use crate::storage::db::MeetingDb;
+use crate::storage::project_index;
+use crate::storage::root::{self, StorageMigrationReport};
 use crate::utils::cancel_current_operation;
-use tauri::{AppHandle, Manager};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_plugin_opener::OpenerExt;
 
 #[tauri::command]
@@ -21,7 +39,7 @@ pub fn cancel_operation(app: AppHandle) {
 }
 
 #[tauri::command]
-pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
+pub fn get_app_dir_path(app: AppHandle) -> Result<String, AppError> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -30,10 +48,98 @@ pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
     Ok(app_data_dir.to_string_lossy().to_string())
 }
 
+/// Connected monitors and the overlay placement used for them
+#[tauri::command]
+pub fn get_overlay_placement(app: AppHandle) -> crate::overlay::OverlayPlacementInfo {
+    crate::overlay::overlay_placement_info(&app)
+}
+
+#[tauri::command]
+pub fn get_storage_root() -> Result<String, AppError> {
+    root::storage_root()
+        .map(|p| p.to_string_lossy().to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Move meetings, projects, repo clones, exports, and downloads to `new_root` and make it
+/// the storage root, updating the paths recorded for them. Refused while a meeting is in
+/// progress.
+#[tauri::command]
+pub async fn migrate_storage_root(
+    app: AppHandle,
+    new_root: String,
+    meeting_manager: State<'_, Arc<MeetingManager>>,
+) -> Result<StorageMigrationReport, AppError> {
+    if !meeting_manager.get_active_meetings().await.is_empty() {
+        return Err(AppError::Busy("End active meetings before moving storage".to_string()));
+    }
+    let to = std::path::PathBuf::from(new_root.trim());
+    if !to.is_absolute() {
+        return Err(AppError::InvalidInput("Storage root must be an absolute path".to_string()));
+    }
+    let from = root::storage_root().map_err(|e| e.to_string())?;
+    let target = to.clone();
+    let report = tauri::async_runtime::spawn_blocking(move || root::migrate_storage(&from, &target))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    relocate_recorded_paths(&app, &report);
+
+    let mut s = settings::get_settings(&app);
+    s.storage_root = Some(to.to_string_lossy().to_string());
+    settings::write_settings(&app, s);
+    root::set_storage_root(Some(to.clone()));
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "storage_root", "value": to }),
+    );
+    log::info!(
+        "Storage migrated to {:?}: {} moved, {} skipped",
+        to,
+        report.moved.len(),
+        report.skipped.len()
+    );
+    Ok(report)
+}
+
+/// Rewrite the paths the app recorded under the old storage root: meeting project paths
+/// (and each project's meeting index), queued chunk files, and crash-recovery journals
+fn relocate_recorded_paths(app: &AppHandle, report: &StorageMigrationReport) {
+    let relocate = |path: &str| report.relocate(path);
+    if let Some(db) = app.try_state::<Arc<MeetingDb>>() {
+        let mut projects = std::collections::BTreeSet::new();
+        for meeting in db.list_meetings().unwrap_or_default() {
+            let Some(new_path) = meeting.project_path.as_deref().and_then(relocate) else {
+                continue;
+            };
+            match db.set_project_path(&meeting.id, &new_path) {
+                Ok(_) => {
+                    projects.insert(new_path);
+                }
+                Err(e) => log::warn!("Failed to update project path of {}: {}", meeting.id, e),
+            }
+        }
+        for project in projects {
+            if let Err(e) = project_index::refresh_index(&db, std::path::Path::new(&project)) {
+                log::warn!("Failed to refresh meeting index of {}: {}", project, e);
+            }
+        }
+    }
+    if let Some(queue) = app.try_state::<Arc<Queue>>() {
+        if let Err(e) = queue.relocate_files(relocate) {
+            log::warn!("Failed to update queued chunk paths: {}", e);
+        }
+    }
+    if let Err(e) = crate::meeting::recovery::relocate_projects(app, relocate) {
+        log::warn!("Failed to update recovery journals: {}", e);
+    }
+}
+
 #[tauri::command]
-pub fn open_path_in_file_manager(app: AppHandle, path: String) -> Result<(), String> {
+pub fn open_path_in_file_manager(app: AppHandle, path: String) -> Result<(), AppError> {
     app
         .opener()
         .open_path(path, None::<&str>)
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Io(e.to_string()))
 }
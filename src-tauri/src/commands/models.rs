@@ -1,3 +1,5 @@
+use crate::commands::AppError;
+use crate::diarization::DiarizationStatus;
 use crate::managers::model::{ModelInfo, ModelManager};
 use crate::managers::transcription::TranscriptionManager;
 use crate::settings::{get_settings, write_settings};
@@ -7,7 +9,7 @@ use tauri::{AppHandle, State};
 #[tauri::command]
 pub async fn get_available_models(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<Vec<ModelInfo>, String> {
+) -> Result<Vec<ModelInfo>, AppError> {
     Ok(model_manager.get_available_models())
 }
 
@@ -15,7 +17,7 @@ pub async fn get_available_models(
 pub async fn get_model_info(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<Option<ModelInfo>, String> {
+) -> Result<Option<ModelInfo>, AppError> {
     Ok(model_manager.get_model_info(&model_id))
 }
 
@@ -23,21 +25,21 @@ pub async fn get_model_info(
 pub async fn download_model(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .download_model(&model_id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
 pub async fn delete_model(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .delete_model(&model_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
@@ -46,14 +48,17 @@ pub async fn set_active_model(
     model_manager: State<'_, Arc<ModelManager>>,
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     // Check if model exists and is available
     let model_info = model_manager
         .get_model_info(&model_id)
-        .ok_or_else(|| format!("Model not found: {}", model_id))?;
+        .ok_or_else(|| AppError::NotFound(format!("Model {}", model_id)))?;
 
     if !model_info.is_downloaded {
-        return Err(format!("Model not downloaded: {}", model_id));
+        return Err(AppError::NotConfigured(format!(
+            "Model not downloaded: {}",
+            model_id
+        )));
     }
 
     // Load the model in the transcription manager
@@ -70,7 +75,7 @@ pub async fn set_active_model(
 }
 
 #[tauri::command]
-pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String> {
+pub async fn get_current_model(app_handle: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app_handle);
     Ok(settings.selected_model)
 }
@@ -78,14 +83,14 @@ pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String>
 #[tauri::command]
 pub async fn get_transcription_model_status(
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, AppError> {
     Ok(transcription_manager.get_current_model())
 }
 
 #[tauri::command]
 pub async fn is_model_loading(
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     // Check if transcription manager has a loaded model
     let current_model = transcription_manager.get_current_model();
     Ok(current_model.is_none())
@@ -94,7 +99,7 @@ pub async fn is_model_loading(
 #[tauri::command]
 pub async fn has_any_models_available(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let models = model_manager.get_available_models();
     Ok(models.iter().any(|m| m.is_downloaded))
 }
@@ -102,7 +107,7 @@ pub async fn has_any_models_available(
 #[tauri::command]
 pub async fn has_any_models_or_downloads(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let models = model_manager.get_available_models();
     // Return true if any models are downloaded OR if any downloads are in progress
     Ok(models.iter().any(|m| m.is_downloaded))
@@ -112,14 +117,30 @@ pub async fn has_any_models_or_downloads(
 pub async fn cancel_download(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .cancel_download(&model_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| AppError::Internal(e.to_string()))
 }
 
 #[tauri::command]
-pub async fn get_recommended_first_model() -> Result<String, String> {
+pub async fn get_recommended_first_model() -> Result<String, AppError> {
     // Recommend Parakeet V3 model for first-time users - fastest and most accurate
     Ok("parakeet-tdt-0.6b-v3".to_string())
 }
+
+/// Whether speaker diarization is on and its voice model is downloaded
+#[tauri::command]
+pub async fn get_diarization_status(app: AppHandle) -> Result<DiarizationStatus, AppError> {
+    Ok(crate::diarization::status(&app))
+}
+
+/// Download the speaker embedding model; progress arrives as `model-download-progress`
+/// events with model id `speaker-embedding`
+#[tauri::command]
+pub async fn download_diarization_model(app: AppHandle) -> Result<String, AppError> {
+    let path = crate::diarization::download_model(&app)
+        .await
+        .map_err(AppError::from)?;
+    Ok(path.to_string_lossy().to_string())
+}
@@ -0,0 +1,253 @@
+//! One place to see every OS permission MeetingCoder relies on, with a link to the
+//! settings pane that grants it. macOS checks go through the macos-permissions plugin,
+//! Windows reads the privacy consent store, and Linux has no per-app prompts.
+
+use cpal::traits::HostTrait;
+use serde::{Deserialize, Serialize};
+use tauri::plugin::PermissionState;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_opener::OpenerExt;
+
+use crate::commands::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionKind {
+    Microphone,
+    /// Screen recording on macOS, which covers capturing other apps' audio
+    ScreenCapture,
+    /// Needed to paste transcriptions and drive other apps
+    Accessibility,
+    Notifications,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionStatus {
+    Granted,
+    Denied,
+    /// The OS hasn't asked yet
+    NotDetermined,
+    /// This platform doesn't gate the capability
+    NotApplicable,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionCheck {
+    pub kind: PermissionKind,
+    pub label: &'static str,
+    pub status: PermissionStatus,
+    pub detail: String,
+    /// Whether `open_permission_settings` has a pane to open for this permission
+    pub can_open_settings: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionsReport {
+    pub platform: &'static str,
+    pub permissions: Vec<PermissionCheck>,
+    /// No permission is denied or still waiting on a prompt
+    pub all_granted: bool,
+}
+
+impl PermissionKind {
+    fn label(self) -> &'static str {
+        match self {
+            PermissionKind::Microphone => "Microphone",
+            PermissionKind::ScreenCapture => "Screen & system audio capture",
+            PermissionKind::Accessibility => "Accessibility",
+            PermissionKind::Notifications => "Notifications",
+        }
+    }
+
+    /// Deep link to the OS settings pane that controls this permission
+    fn settings_url(self) -> Option<&'static str> {
+        if cfg!(target_os = "macos") {
+            Some(match self {
+                PermissionKind::Microphone => {
+                    "x-apple.systempreferences:com.apple.preference.security?Privacy_Microphone"
+                }
+                PermissionKind::ScreenCapture => {
+                    "x-apple.systempreferences:com.apple.preference.security?Privacy_ScreenCapture"
+                }
+                PermissionKind::Accessibility => {
+                    "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility"
+                }
+                PermissionKind::Notifications => {
+                    "x-apple.systempreferences:com.apple.preference.notifications"
+                }
+            })
+        } else if cfg!(target_os = "windows") {
+            match self {
+                PermissionKind::Microphone => Some("ms-settings:privacy-microphone"),
+                PermissionKind::Notifications => Some("ms-settings:notifications"),
+                PermissionKind::ScreenCapture | PermissionKind::Accessibility => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn from_bool(granted: bool) -> PermissionStatus {
+    if granted {
+        PermissionStatus::Granted
+    } else {
+        PermissionStatus::Denied
+    }
+}
+
+/// The Windows privacy consent store value ("Allow"/"Deny") for a capability
+#[cfg(target_os = "windows")]
+fn windows_consent(capability: &str) -> PermissionStatus {
+    let key = format!(
+        r"HKCU\Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\{}",
+        capability
+    );
+    let output = std::process::Command::new("reg")
+        .args(["query", &key, "/v", "Value"])
+        .output();
+    match output {
+        Ok(out) if out.status.success() => {
+            let text = String::from_utf8_lossy(&out.stdout);
+            if text.contains("Deny") {
+                PermissionStatus::Denied
+            } else if text.contains("Allow") {
+                PermissionStatus::Granted
+            } else {
+                PermissionStatus::Unknown
+            }
+        }
+        _ => PermissionStatus::Unknown,
+    }
+}
+
+async fn check_microphone() -> (PermissionStatus, String) {
+    let has_input = cpal::default_host().default_input_device().is_some();
+    #[cfg(target_os = "macos")]
+    let status = from_bool(tauri_plugin_macos_permissions::check_microphone_permission().await);
+    #[cfg(target_os = "windows")]
+    let status = windows_consent("microphone");
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let status = if has_input {
+        PermissionStatus::NotApplicable
+    } else {
+        PermissionStatus::Unknown
+    };
+
+    let detail = match (status, has_input) {
+        (_, false) => "No input device found".to_string(),
+        (PermissionStatus::Denied, _) => "Recording is blocked by the OS".to_string(),
+        (PermissionStatus::NotApplicable, _) => {
+            "No per-app permission on this platform".to_string()
+        }
+        _ => "Input device available".to_string(),
+    };
+    (status, detail)
+}
+
+async fn check_screen_capture() -> (PermissionStatus, String) {
+    #[cfg(target_os = "macos")]
+    {
+        let granted = tauri_plugin_macos_permissions::check_screen_recording_permission().await;
+        let detail = if granted {
+            "Other apps' audio can be captured"
+        } else {
+            "Allow screen recording to capture the other side of calls"
+        };
+        (from_bool(granted), detail.to_string())
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        (
+            PermissionStatus::NotApplicable,
+            "System audio is captured through a loopback device".to_string(),
+        )
+    }
+}
+
+async fn check_accessibility() -> (PermissionStatus, String) {
+    #[cfg(target_os = "macos")]
+    {
+        let granted = tauri_plugin_macos_permissions::check_accessibility_permission().await;
+        let detail = if granted {
+            "Transcriptions can be pasted into other apps"
+        } else {
+            "Needed to paste transcriptions and run automation"
+        };
+        (from_bool(granted), detail.to_string())
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        (
+            PermissionStatus::NotApplicable,
+            "No per-app permission on this platform".to_string(),
+        )
+    }
+}
+
+fn check_notifications(app: &AppHandle) -> (PermissionStatus, String) {
+    match app.notification().permission_state() {
+        Ok(PermissionState::Granted) => (PermissionStatus::Granted, "Allowed".to_string()),
+        Ok(PermissionState::Denied) => (
+            PermissionStatus::Denied,
+            "Meeting milestones won't show desktop notifications".to_string(),
+        ),
+        Ok(_) => (
+            PermissionStatus::NotDetermined,
+            "You'll be asked the first time a notification is shown".to_string(),
+        ),
+        Err(e) => (PermissionStatus::Unknown, e.to_string()),
+    }
+}
+
+/// Microphone, screen/audio capture, accessibility, and notification permissions for
+/// this platform
+#[tauri::command]
+pub async fn check_all_permissions(app: AppHandle) -> Result<PermissionsReport, AppError> {
+    let checks = [
+        (PermissionKind::Microphone, check_microphone().await),
+        (PermissionKind::ScreenCapture, check_screen_capture().await),
+        (PermissionKind::Accessibility, check_accessibility().await),
+        (PermissionKind::Notifications, check_notifications(&app)),
+    ];
+    let permissions: Vec<PermissionCheck> = checks
+        .into_iter()
+        .map(|(kind, (status, detail))| PermissionCheck {
+            kind,
+            label: kind.label(),
+            status,
+            detail,
+            can_open_settings: kind.settings_url().is_some(),
+        })
+        .collect();
+    let all_granted = permissions.iter().all(|p| {
+        !matches!(
+            p.status,
+            PermissionStatus::Denied | PermissionStatus::NotDetermined
+        )
+    });
+    Ok(PermissionsReport {
+        platform: std::env::consts::OS,
+        permissions,
+        all_granted,
+    })
+}
+
+/// Open the OS settings pane where `kind` is granted
+#[tauri::command]
+pub fn open_permission_settings(app: AppHandle, kind: PermissionKind) -> Result<(), AppError> {
+    let url = kind.settings_url().ok_or_else(|| {
+        AppError::InvalidInput(format!(
+            "{} has no settings pane on {}",
+            kind.label(),
+            std::env::consts::OS
+        ))
+    })?;
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|e| AppError::Io(e.to_string()))
+}
@@ -1,3 +1,4 @@
+use crate::commands::AppError;
 use crate::document_generation::*;
 use crate::managers::meeting::MeetingManager;
 use std::sync::Arc;
@@ -7,20 +8,27 @@ use tauri::{AppHandle, State};
 #[tauri::command]
 pub async fn generate_prd_now(
     meeting_id: String,
-    _app: AppHandle,
+    app: AppHandle,
     meeting_manager: State<'_, Arc<MeetingManager>>,
-) -> Result<PRDVersion, String> {
+) -> Result<PRDVersion, AppError> {
     log::info!("Manual PRD generation requested for meeting: {}", meeting_id);
 
     // Get meeting data
     let meeting_session = meeting_manager
         .get_meeting(&meeting_id)
         .await
-        .map_err(|e| format!("Meeting not found: {}", e))?;
+        .map_err(|_| AppError::NotFound(format!("Meeting {}", meeting_id)))?;
 
     // Get transcript
     let transcript = meeting_session.transcript_segments.clone();
 
+    // Codebase context (framework, exported symbols, routes) from the last analysis
+    let project_context = meeting_session
+        .project_path
+        .as_deref()
+        .and_then(|p| crate::codebase::load_manifest_from_state(std::path::Path::new(p)))
+        .map(|m| m.context_summary(60));
+
     // Create or load PRD generator
     let mut prd_generator = PRDGenerator::load(meeting_id.clone(), meeting_session.name.clone())
         .unwrap_or_else(|_| PRDGenerator::new(meeting_id.clone(), meeting_session.name.clone()));
@@ -32,7 +40,7 @@ pub async fn generate_prd_now(
             .generate_initial_prd(
                 &transcript,
                 &[], // TODO: Get feature extractions
-                None,
+                project_context,
             )
             .await
             .map_err(|e| format!("Failed to generate initial PRD: {}", e))?
@@ -47,18 +55,40 @@ pub async fn generate_prd_now(
             .map_err(|e| format!("Failed to generate PRD update: {}", e))?
     };
 
+    crate::notifications::notify(
+        &app,
+        crate::notifications::Milestone::PrdGenerated,
+        &format!("PRD v{} generated", version.version),
+        &meeting_session.name,
+    );
+    crate::integrations::discord::spawn_post_prd_version(
+        &app,
+        &meeting_session.name,
+        meeting_session.project_path.as_deref(),
+        &version,
+    );
+    crate::integrations::webhooks::dispatch(
+        &app,
+        crate::settings::WebhookEvent::PrdVersion,
+        serde_json::json!({
+            "meeting_id": meeting_id,
+            "meeting_name": meeting_session.name,
+            "version": version,
+        }),
+    );
     Ok(version)
 }
 
 /// Get all PRD versions for a meeting
 #[tauri::command]
-pub async fn get_prd_versions(meeting_id: String) -> Result<Vec<PRDVersion>, String> {
-    get_all_versions(&meeting_id).map_err(|e| format!("Failed to get PRD versions: {}", e))
+pub async fn get_prd_versions(meeting_id: String) -> Result<Vec<PRDVersion>, AppError> {
+    get_all_versions(&meeting_id)
+        .map_err(|e| AppError::Internal(format!("Failed to get PRD versions: {}", e)))
 }
 
 /// Get PRD content for a specific version
 #[tauri::command]
-pub async fn get_prd_content(meeting_id: String, version: u32) -> Result<String, String> {
+pub async fn get_prd_content(meeting_id: String, version: u32) -> Result<String, AppError> {
     let (_, _, markdown) = load_prd_version(&meeting_id, version)
         .map_err(|e| format!("Failed to load PRD version: {}", e))?;
 
@@ -67,7 +97,7 @@ pub async fn get_prd_content(meeting_id: String, version: u32) -> Result<String,
 
 /// Get PRD content as JSON for a specific version
 #[tauri::command]
-pub async fn get_prd_content_json(meeting_id: String, version: u32) -> Result<PRDContent, String> {
+pub async fn get_prd_content_json(meeting_id: String, version: u32) -> Result<PRDContent, AppError> {
     let (_, content, _) = load_prd_version(&meeting_id, version)
         .map_err(|e| format!("Failed to load PRD version: {}", e))?;
 
@@ -76,8 +106,9 @@ pub async fn get_prd_content_json(meeting_id: String, version: u32) -> Result<PR
 
 /// Get the changelog for a meeting's PRD
 #[tauri::command]
-pub async fn get_prd_changelog(meeting_id: String) -> Result<PRDChangelog, String> {
-    load_changelog(&meeting_id).map_err(|e| format!("Failed to load changelog: {}", e))
+pub async fn get_prd_changelog(meeting_id: String) -> Result<PRDChangelog, AppError> {
+    load_changelog(&meeting_id)
+        .map_err(|e| AppError::Internal(format!("Failed to load changelog: {}", e)))
 }
 
 /// Get a specific change between two versions
@@ -86,7 +117,7 @@ pub async fn get_prd_change(
     meeting_id: String,
     from_version: u32,
     to_version: u32,
-) -> Result<PRDChange, String> {
+) -> Result<PRDChange, AppError> {
     let changelog = load_changelog(&meeting_id)
         .map_err(|e| format!("Failed to load changelog: {}", e))?;
 
@@ -95,7 +126,9 @@ pub async fn get_prd_change(
         .iter()
         .find(|c| c.from_version == from_version && c.to_version == to_version)
         .cloned()
-        .ok_or_else(|| format!("Change not found: {} -> {}", from_version, to_version))
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Change {} -> {}", from_version, to_version))
+        })
 }
 
 /// Export PRD to a file (markdown, PDF, HTML)
@@ -104,7 +137,7 @@ pub async fn export_prd(
     meeting_id: String,
     version: u32,
     format: String,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let (version_data, _content, _markdown) = load_prd_version(&meeting_id, version)
         .map_err(|e| format!("Failed to load PRD version: {}", e))?;
 
@@ -115,25 +148,26 @@ pub async fn export_prd(
         }
         "pdf" => {
             // TODO: Implement PDF export
-            Err("PDF export not yet implemented".to_string())
+            Err(AppError::InvalidInput("PDF export not yet implemented".to_string()))
         }
         "html" => {
             // TODO: Implement HTML export
-            Err("HTML export not yet implemented".to_string())
+            Err(AppError::InvalidInput("HTML export not yet implemented".to_string()))
         }
-        _ => Err(format!("Unsupported export format: {}", format)),
+        _ => Err(AppError::InvalidInput(format!("Unsupported export format: {}", format))),
     }
 }
 
 /// Get PRD metadata for a meeting
 #[tauri::command]
-pub async fn get_prd_metadata(meeting_id: String) -> Result<Option<PRDMetadata>, String> {
-    load_metadata(&meeting_id).map_err(|e| format!("Failed to load metadata: {}", e))
+pub async fn get_prd_metadata(meeting_id: String) -> Result<Option<PRDMetadata>, AppError> {
+    load_metadata(&meeting_id)
+        .map_err(|e| AppError::Internal(format!("Failed to load metadata: {}", e)))
 }
 
 /// Delete a PRD version
 #[tauri::command]
-pub async fn delete_prd_version(_meeting_id: String, _version: u32) -> Result<(), String> {
+pub async fn delete_prd_version(_meeting_id: String, _version: u32) -> Result<(), AppError> {
     // TODO: Implement deletion
-    Err("PRD deletion not yet implemented".to_string())
+    Err(AppError::Internal("PRD deletion not yet implemented".to_string()))
 }
@@ -0,0 +1,342 @@
+//! End-to-end self-test for support: speaks a known phrase with the OS text-to-speech
+//! voice, feeds the clip straight into the transcription model, and runs the result
+//! through summary generation and transcript storage in a scratch folder.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
+use tauri::State;
+
+use crate::audio_toolkit::audio::load_audio_file_to_mono_16k;
+use crate::commands::AppError;
+use crate::managers::meeting::{
+    summary_markdown, MeetingSession, MeetingStatus, TranscriptSegment,
+};
+use crate::managers::transcription::TranscriptionManager;
+use crate::storage::transcript::TranscriptStorage;
+
+const PHRASE: &str = "The quick brown fox jumps over the lazy dog. Please schedule the design review for Thursday afternoon.";
+/// Share of the phrase's words the transcript must contain
+const MIN_WORD_MATCH: f32 = 0.6;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    /// An earlier step it depends on failed
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestStep {
+    pub name: String,
+    pub status: StepStatus,
+    pub detail: String,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestReport {
+    pub passed: bool,
+    pub steps: Vec<SelfTestStep>,
+    pub model: Option<String>,
+    pub expected_text: String,
+    pub transcript: Option<String>,
+    pub app_version: String,
+    pub os: String,
+}
+
+#[derive(Default)]
+struct Steps(Vec<SelfTestStep>);
+
+impl Steps {
+    /// Run `f` as step `name` unless an earlier step failed
+    fn run<T>(&mut self, name: &str, f: impl FnOnce() -> Result<(T, String), String>) -> Option<T> {
+        if self.failed() {
+            self.0.push(SelfTestStep {
+                name: name.to_string(),
+                status: StepStatus::Skipped,
+                detail: "Skipped after an earlier failure".to_string(),
+                duration_ms: 0,
+            });
+            return None;
+        }
+        let started = Instant::now();
+        let result = f();
+        let duration_ms = started.elapsed().as_millis() as u64;
+        let (status, detail, value) = match result {
+            Ok((value, detail)) => (StepStatus::Passed, detail, Some(value)),
+            Err(e) => (StepStatus::Failed, e, None),
+        };
+        log::info!("SELF_TEST {} {:?}: {}", name, status, detail);
+        self.0.push(SelfTestStep {
+            name: name.to_string(),
+            status,
+            detail,
+            duration_ms,
+        });
+        value
+    }
+
+    fn failed(&self) -> bool {
+        self.0.iter().any(|s| s.status == StepStatus::Failed)
+    }
+}
+
+fn run_tts(program: &str, args: &[&str]) -> Result<(), String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Speak `PHRASE` into a WAV file with the platform's built-in voice
+fn synthesize_clip(path: &Path) -> Result<(), String> {
+    let out = path.to_string_lossy().to_string();
+    if cfg!(target_os = "macos") {
+        run_tts(
+            "say",
+            &[
+                "-o",
+                &out,
+                "--file-format=WAVE",
+                "--data-format=LEI16@16000",
+                PHRASE,
+            ],
+        )
+    } else if cfg!(target_os = "windows") {
+        let script = format!(
+            "Add-Type -AssemblyName System.Speech; \
+             $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+             $s.SetOutputToWaveFile('{}'); $s.Speak('{}'); $s.Dispose()",
+            out.replace('\'', "''"),
+            PHRASE
+        );
+        run_tts("powershell", &["-NoProfile", "-Command", &script])
+    } else {
+        run_tts("espeak-ng", &["-w", &out, PHRASE])
+            .or_else(|_| run_tts("espeak", &["-w", &out, PHRASE]))
+            .map_err(|e| format!("{} (install espeak-ng for the self-test)", e))
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Share of `expected` words that appear in `actual`, ignoring case and punctuation
+fn word_match(expected: &str, actual: &str) -> f32 {
+    let expected = words(expected);
+    if expected.is_empty() {
+        return 1.0;
+    }
+    let actual = words(actual);
+    let found = expected.iter().filter(|w| actual.contains(w)).count();
+    found as f32 / expected.len() as f32
+}
+
+fn check_files(dir: &Path, names: &[&str]) -> Result<(), String> {
+    for name in names {
+        let size = std::fs::metadata(dir.join(name))
+            .map(|m| m.len())
+            .map_err(|_| format!("{} was not written", name))?;
+        if size == 0 {
+            return Err(format!("{} is empty", name));
+        }
+    }
+    Ok(())
+}
+
+/// The meetings folder under the storage root accepts writes
+fn probe_storage_root() -> Result<PathBuf, String> {
+    let dir = TranscriptStorage::default_path().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Cannot create {:?}: {}", dir, e))?;
+    let probe = dir.join(".self_test_probe");
+    std::fs::write(&probe, b"ok").map_err(|e| format!("Cannot write to {:?}: {}", dir, e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(dir)
+}
+
+fn run_steps(
+    transcription_manager: &TranscriptionManager,
+    scratch: &Path,
+    report: &mut SelfTestReport,
+) -> Vec<SelfTestStep> {
+    let mut steps = Steps::default();
+    let clip_path = scratch.join("self_test.wav");
+
+    let samples = steps.run("audio_clip", || {
+        synthesize_clip(&clip_path)?;
+        let samples = load_audio_file_to_mono_16k(&clip_path).map_err(|e| e.to_string())?;
+        if samples.is_empty() {
+            return Err("Synthesized clip has no audio".to_string());
+        }
+        let detail = format!(
+            "{:.1}s of speech at 16 kHz",
+            samples.len() as f32 / 16_000.0
+        );
+        Ok((samples, detail))
+    });
+
+    let transcript = steps.run("transcription", || {
+        if !transcription_manager.is_model_loaded() {
+            transcription_manager.initiate_model_load();
+        }
+        let samples = samples.clone().unwrap_or_default();
+        let duration = samples.len() as f64 / 16_000.0;
+        let text = transcription_manager
+            .transcribe(samples)
+            .map_err(|e| format!("Transcription failed: {}", e))?;
+        report.model = transcription_manager.get_current_model();
+        report.transcript = Some(text.clone());
+        let score = word_match(PHRASE, &text);
+        if score < MIN_WORD_MATCH {
+            return Err(format!(
+                "Only {:.0}% of the expected words were recognized",
+                score * 100.0
+            ));
+        }
+        Ok((
+            (text, duration),
+            format!("{:.0}% of expected words recognized", score * 100.0),
+        ))
+    });
+
+    let session = transcript.map(|(text, duration)| {
+        let start_time = SystemTime::now();
+        MeetingSession {
+            id: format!("self-test-{}", uuid::Uuid::new_v4()),
+            name: "Self-test".to_string(),
+            start_time,
+            end_time: Some(start_time + std::time::Duration::from_secs_f64(duration)),
+            transcript_segments: vec![TranscriptSegment {
+                speaker: "Speaker 1".to_string(),
+                start_time: 0.0,
+                end_time: duration,
+                text,
+                confidence: 1.0,
+                timestamp: start_time,
+                words: Vec::new(),
+                translated_text: None,
+            }],
+            status: MeetingStatus::Completed,
+            participants: vec!["Speaker 1".to_string()],
+            project_path: None,
+            translation_language: None,
+            cloud_asr: false,
+        }
+    });
+
+    let summary = steps.run("summary", || {
+        let md = summary_markdown(session.as_ref().ok_or("No transcript")?);
+        if md.trim().is_empty() {
+            return Err("Summary is empty".to_string());
+        }
+        let detail = format!("{} characters", md.len());
+        Ok((md, detail))
+    });
+
+    steps.run("file_writes", || {
+        let session = session.as_ref().ok_or("No transcript")?;
+        let storage =
+            TranscriptStorage::new(scratch.join("meetings")).map_err(|e| e.to_string())?;
+        let dir = storage
+            .save_transcript(session)
+            .map_err(|e| format!("Failed to save transcript: {}", e))?;
+        std::fs::write(
+            dir.join("summary.md"),
+            summary.as_deref().unwrap_or_default(),
+        )
+        .map_err(|e| format!("Failed to write summary.md: {}", e))?;
+        check_files(
+            &dir,
+            &[
+                "metadata.json",
+                "transcript.json",
+                "transcript.md",
+                "summary.md",
+            ],
+        )?;
+        let dir_name = dir.file_name().map(|n| n.to_string_lossy().to_string());
+        let (_, data) = storage
+            .load_transcript(dir_name.as_deref().unwrap_or_default())
+            .map_err(|e| format!("Saved transcript can't be read back: {}", e))?;
+        if data.segments.len() != session.transcript_segments.len() {
+            return Err("Saved transcript lost segments".to_string());
+        }
+        Ok((
+            (),
+            "Transcript and summary written and read back".to_string(),
+        ))
+    });
+
+    steps.run("storage_root", || {
+        let dir = probe_storage_root()?;
+        Ok(((), format!("{} is writable", dir.display())))
+    });
+
+    steps.0
+}
+
+/// Exercise audio decoding, transcription, summary generation, and file writes with a
+/// known clip; nothing is added to the meeting list
+#[tauri::command]
+pub async fn run_self_test(
+    app: tauri::AppHandle,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+) -> Result<SelfTestReport, AppError> {
+    let transcription_manager = transcription_manager.inner().clone();
+    let mut report = SelfTestReport {
+        passed: false,
+        steps: Vec::new(),
+        model: None,
+        expected_text: PHRASE.to_string(),
+        transcript: None,
+        app_version: app.package_info().version.to_string(),
+        os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+    };
+    let scratch =
+        std::env::temp_dir().join(format!("meetingcoder-self-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&scratch).map_err(|e| e.to_string())?;
+
+    let (report, scratch) = tauri::async_runtime::spawn_blocking(move || {
+        report.steps = run_steps(&transcription_manager, &scratch, &mut report);
+        report.passed = report.steps.iter().all(|s| s.status == StepStatus::Passed);
+        (report, scratch)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let _ = std::fs::remove_dir_all(&scratch);
+    log::info!("SELF_TEST finished: passed={}", report.passed);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_match() {
+        assert_eq!(word_match(PHRASE, PHRASE), 1.0);
+        let heard = "the quick brown fox jumped over the lazy dog please schedule the design review for thursday";
+        assert!(word_match(PHRASE, heard) >= MIN_WORD_MATCH);
+        assert!(word_match(PHRASE, "thank you") < MIN_WORD_MATCH);
+        assert_eq!(word_match("", "anything"), 1.0);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::commands::AppError;
+use crate::storage::speakers::{SpeakerDb, SpeakerProfile};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Longest name a voiceprint can be enrolled under
+const MAX_NAME_CHARS: usize = 64;
+
+/// Enroll a named voiceprint from short recordings of that person speaking alone (a few
+/// 5–30 s clips work best). Meetings then label their voice with `name` automatically.
+#[tauri::command]
+pub async fn enroll_speaker(
+    app: AppHandle,
+    name: String,
+    sample_paths: Vec<String>,
+    db: State<'_, Arc<SpeakerDb>>,
+) -> Result<SpeakerProfile, AppError> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_CHARS {
+        return Err(AppError::InvalidInput(format!(
+            "Speaker name must be 1-{} characters",
+            MAX_NAME_CHARS
+        )));
+    }
+    if sample_paths.is_empty() {
+        return Err(AppError::InvalidInput(
+            "Add at least one voice sample".to_string(),
+        ));
+    }
+    let paths: Vec<PathBuf> = sample_paths.iter().map(PathBuf::from).collect();
+    if let Some(missing) = paths.iter().find(|p| !p.is_file()) {
+        return Err(AppError::NotFound(format!("Voice sample {:?}", missing)));
+    }
+    if !crate::diarization::status(&app).model_downloaded {
+        return Err(AppError::NotConfigured(
+            "Download the speaker model to enroll voices".to_string(),
+        ));
+    }
+    let db = db.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::diarization::enroll(&app, &db, &name, &paths)
+            .map_err(|e| AppError::InvalidInput(format!("Enrollment failed: {}", e)))
+    })
+    .await
+    .map_err(|e| AppError::Internal(e.to_string()))?
+}
+
+#[tauri::command]
+pub async fn list_speakers(db: State<'_, Arc<SpeakerDb>>) -> Result<Vec<SpeakerProfile>, AppError> {
+    db.list().map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn delete_speaker(id: i64, db: State<'_, Arc<SpeakerDb>>) -> Result<(), AppError> {
+    match db.delete(id).map_err(AppError::from)? {
+        Some(_) => Ok(()),
+        None => Err(AppError::NotFound(format!("Speaker {}", id))),
+    }
+}
@@ -1,5 +1,6 @@
 //! Tauri commands for system audio capture functionality
 
+use crate::commands::AppError;
 use crate::system_audio::{get_setup_instructions, is_system_audio_available, SystemAudioCapture, SystemAudioCaptureDevice, VirtualDeviceInfo};
 use serde::{Deserialize, Serialize};
 
@@ -24,7 +25,7 @@ pub fn get_system_audio_setup_instructions() -> String {
 
 /// Detect if a virtual audio device is installed
 #[tauri::command]
-pub fn detect_virtual_audio_device() -> Result<DetectDeviceResponse, String> {
+pub fn detect_virtual_audio_device() -> Result<DetectDeviceResponse, AppError> {
     let capture = SystemAudioCapture::new().map_err(|e| e.to_string())?;
 
     match capture.detect_virtual_device() {
@@ -36,16 +37,16 @@ pub fn detect_virtual_audio_device() -> Result<DetectDeviceResponse, String> {
             available: false,
             device: None,
         }),
-        Err(e) => Err(format!("Failed to detect virtual device: {}", e)),
+        Err(e) => Err(AppError::Internal(format!("Failed to detect virtual device: {}", e))),
     }
 }
 
 /// List all available system audio output devices
 #[tauri::command]
-pub fn list_system_audio_devices() -> Result<Vec<VirtualDeviceInfo>, String> {
+pub fn list_system_audio_devices() -> Result<Vec<VirtualDeviceInfo>, AppError> {
     let capture = SystemAudioCapture::new().map_err(|e| e.to_string())?;
 
     capture
         .list_output_devices()
-        .map_err(|e| format!("Failed to list devices: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to list devices: {}", e)))
 }
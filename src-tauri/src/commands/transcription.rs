@@ -1,5 +1,11 @@
+use crate::commands::AppError;
+use crate::events::{self, AppEvent};
+use crate::managers::cloud_asr;
 use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
+use crate::queue::{DeadLetter, Queue};
+use crate::settings::{get_settings, write_settings, CloudAsrProvider, ModelUnloadTimeout};
+use crate::workers::asr_worker::{QueueMetrics, WorkerPools};
+use std::sync::Arc;
 use tauri::{AppHandle, State};
 
 #[tauri::command]
@@ -12,7 +18,7 @@ pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
 #[tauri::command]
 pub fn get_model_load_status(
     transcription_manager: State<TranscriptionManager>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     let is_loaded = transcription_manager.is_model_loaded();
     let current_model = transcription_manager.get_current_model();
 
@@ -25,8 +31,85 @@ pub fn get_model_load_status(
 #[tauri::command]
 pub fn unload_model_manually(
     transcription_manager: State<TranscriptionManager>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     transcription_manager
         .unload_model()
-        .map_err(|e| format!("Failed to unload model: {}", e))
+        .map_err(|e| AppError::Internal(format!("Failed to unload model: {}", e)))
+}
+
+/// Worker throughput per lane (same payloads as the periodic `queue-metrics` events)
+#[tauri::command]
+pub fn get_queue_metrics(pool: State<'_, Arc<WorkerPools>>) -> Vec<QueueMetrics> {
+    pool.metrics()
+}
+
+/// Stop transcribing queued audio (e.g. on battery or while screen sharing). Audio keeps
+/// being enqueued and is transcribed after `resume_queue_processing`.
+#[tauri::command]
+pub fn pause_queue_processing(pool: State<'_, Arc<WorkerPools>>) {
+    pool.set_paused(true);
+}
+
+#[tauri::command]
+pub fn resume_queue_processing(pool: State<'_, Arc<WorkerPools>>) {
+    pool.set_paused(false);
+}
+
+/// Stop all queued and in-flight transcription of a meeting
+#[tauri::command]
+pub fn cancel_meeting_jobs(
+    app: AppHandle,
+    meeting_id: String,
+    queue: State<'_, Arc<Queue>>,
+) -> Result<usize, AppError> {
+    cancel_jobs(&app, &queue, &meeting_id).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+pub(crate) fn cancel_jobs(app: &AppHandle, queue: &Queue, meeting_id: &str) -> anyhow::Result<usize> {
+    let cancelled = queue.cancel_meeting(meeting_id)?;
+    if cancelled > 0 {
+        log::info!("Cancelled {} transcription jobs of meeting {}", cancelled, meeting_id);
+        events::emit(
+            app,
+            AppEvent::QueueMeetingCancelled {
+                meeting_id: meeting_id.to_string(),
+                cancelled,
+            },
+        );
+    }
+    Ok(cancelled)
+}
+
+/// Queue jobs that failed on every retry
+#[tauri::command]
+pub fn list_dead_letters(queue: State<'_, Arc<Queue>>) -> Result<Vec<DeadLetter>, AppError> {
+    queue.list_dead_letters().map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Put dead-lettered jobs back in the queue (all, or only those of `meeting_id`)
+#[tauri::command]
+pub fn requeue_dead_letters(
+    meeting_id: Option<String>,
+    queue: State<'_, Arc<Queue>>,
+) -> Result<usize, AppError> {
+    let requeued = queue
+        .requeue_dead_letters(meeting_id.as_deref())
+        .map_err(|e| e.to_string())?;
+    log::info!("Requeued {} dead-lettered transcription jobs", requeued);
+    Ok(requeued)
+}
+
+#[tauri::command]
+pub async fn set_cloud_asr_key(provider: CloudAsrProvider, key: String) -> Result<(), AppError> {
+    cloud_asr::store_api_key(provider, &key).map_err(|e| AppError::InvalidInput(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_cloud_asr_key(provider: CloudAsrProvider) -> Result<(), AppError> {
+    cloud_asr::clear_api_key(provider).map_err(AppError::from)
+}
+
+#[tauri::command]
+pub async fn has_cloud_asr_key(provider: CloudAsrProvider) -> Result<bool, AppError> {
+    Ok(cloud_asr::has_api_key(provider))
 }
@@ -0,0 +1,321 @@
+//! `meetingcoder://` links from calendars and docs:
+//! - `meetingcoder://start?name=Standup&repo=org/app` starts a meeting, in that repo for
+//!   this meeting only
+//! - `meetingcoder://open-meeting/{id}` shows a live meeting or opens a saved one's folder
+//! - `meetingcoder://end` ends the meeting in progress
+//! - `meetingcoder://import?path=/abs/file.mp3&name=Interview` imports an audio file
+//! - `meetingcoder://export-summary/{id|latest}` writes the summary to the exports folder
+//!   and copies it to the clipboard
+//!
+//! These are also the bridge for macOS Shortcuts ("Open URL") and AppleScript
+//! (`resources/automation/MeetingCoder.applescript`).
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_deep_link::DeepLinkExt;
+use tauri_plugin_opener::OpenerExt;
+
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::{default_meeting_name, MeetingManager};
+use crate::storage::db::MeetingDb;
+use crate::storage::transcript::TranscriptStorage;
+
+pub const SCHEME: &str = "meetingcoder";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLink {
+    Start {
+        name: Option<String>,
+        /// (owner, name)
+        repo: Option<(String, String)>,
+    },
+    OpenMeeting(String),
+    End,
+    Import {
+        path: PathBuf,
+        name: Option<String>,
+    },
+    /// Meeting id, or "latest" for the most recent saved meeting
+    ExportSummary(String),
+}
+
+/// First path segment, e.g. the id in `open-meeting/{id}`
+fn path_arg(url: &Url) -> Option<String> {
+    url.path_segments()
+        .and_then(|mut segments| segments.next().map(str::to_string))
+        .filter(|arg| !arg.is_empty())
+}
+
+pub fn parse(url: &Url) -> Result<DeepLink, String> {
+    if url.scheme() != SCHEME {
+        return Err(format!("Not a {}:// link", SCHEME));
+    }
+    let param = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    match url.host_str().unwrap_or_default() {
+        "start" => {
+            let repo = match param("repo") {
+                Some(repo) => match repo.split_once('/') {
+                    Some((owner, name))
+                        if !owner.is_empty() && !name.is_empty() && !name.contains('/') =>
+                    {
+                        Some((owner.to_string(), name.to_string()))
+                    }
+                    _ => return Err(format!("Invalid repo '{}': expected owner/name", repo)),
+                },
+                None => None,
+            };
+            Ok(DeepLink::Start {
+                name: param("name"),
+                repo,
+            })
+        }
+        "open-meeting" => path_arg(url)
+            .map(DeepLink::OpenMeeting)
+            .ok_or_else(|| "open-meeting needs a meeting id".to_string()),
+        "end" => Ok(DeepLink::End),
+        "import" => {
+            let path = param("path")
+                .map(PathBuf::from)
+                .filter(|p| p.is_absolute())
+                .ok_or_else(|| "import needs an absolute path".to_string())?;
+            Ok(DeepLink::Import {
+                path,
+                name: param("name"),
+            })
+        }
+        "export-summary" => Ok(DeepLink::ExportSummary(
+            path_arg(url).unwrap_or_else(|| "latest".to_string()),
+        )),
+        other => Err(format!("Unknown action '{}'", other)),
+    }
+}
+
+/// Ask the main window to switch to a sidebar section
+fn navigate(app: &AppHandle, section: &str) {
+    crate::show_main_window(app);
+    let _ = app.emit("navigate-to-section", section);
+}
+
+async fn start_meeting(
+    app: &AppHandle,
+    name: Option<String>,
+    repo: Option<(String, String)>,
+) -> Result<(), String> {
+    let mm = app.state::<Arc<MeetingManager>>().inner().clone();
+    if let Some(current) = mm.current_meeting().await {
+        return Err(format!("Meeting '{}' is already in progress", current.name));
+    }
+    // Links can come from anywhere, so the repo only applies to this meeting and never
+    // replaces the configured one
+    let meeting_id = mm
+        .start_meeting_in_repo(name.unwrap_or_else(default_meeting_name), repo)
+        .await
+        .map_err(|e| format!("Failed to start meeting: {}", e))?;
+    events::emit(
+        app,
+        AppEvent::meeting_state(&meeting_id, "recording", "deep_link"),
+    );
+    // Always surface the app so a link can't start recording unnoticed
+    navigate(app, "meetings");
+    Ok(())
+}
+
+async fn open_meeting(app: &AppHandle, meeting_id: &str) -> Result<(), String> {
+    let mm = app.state::<Arc<MeetingManager>>().inner().clone();
+    if mm.get_meeting(meeting_id).await.is_ok() {
+        navigate(app, "meetings");
+        return Ok(());
+    }
+    let stored = app
+        .state::<Arc<MeetingDb>>()
+        .get_meeting(meeting_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let dir_name = stored
+        .dir_name
+        .ok_or_else(|| format!("Meeting {} has no saved folder", meeting_id))?;
+    let dir = TranscriptStorage::default_path()
+        .map_err(|e| e.to_string())?
+        .join(dir_name);
+    app.opener()
+        .open_path(dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+async fn end_meeting(app: &AppHandle) -> Result<(), String> {
+    let mm = app.state::<Arc<MeetingManager>>().inner().clone();
+    let current = mm
+        .current_meeting()
+        .await
+        .ok_or_else(|| "No meeting in progress".to_string())?;
+    mm.end_meeting(&current.id)
+        .await
+        .map_err(|e| format!("Failed to end meeting: {}", e))?;
+    events::emit(
+        app,
+        AppEvent::meeting_state(&current.id, "completed", "deep_link"),
+    );
+    Ok(())
+}
+
+async fn import_file(app: &AppHandle, path: PathBuf, name: Option<String>) -> Result<(), String> {
+    let name = name
+        .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(default_meeting_name);
+    crate::commands::import::import_audio_from_path_as_meeting(
+        app.clone(),
+        name,
+        path.to_string_lossy().to_string(),
+        "import:deep_link",
+        app.state(),
+        app.state(),
+        app.state(),
+    )
+    .await
+    .map(|_| ())
+}
+
+fn export_summary(app: &AppHandle, meeting_id: &str) -> Result<(), String> {
+    let db = app.state::<Arc<MeetingDb>>();
+    let meeting = if meeting_id == "latest" {
+        db.list_meetings()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .next()
+            .ok_or_else(|| "No saved meetings".to_string())?
+    } else {
+        db.get_meeting(meeting_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Meeting {} not found", meeting_id))?
+    };
+    let summaries = db.list_summaries(&meeting.id).map_err(|e| e.to_string())?;
+    let summary = summaries
+        .iter()
+        .rev()
+        .find(|s| s.kind == "markdown")
+        .or_else(|| summaries.last())
+        .ok_or_else(|| format!("Meeting '{}' has no summary yet", meeting.name))?;
+
+    let exports = crate::storage::root::storage_dir("exports").map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&exports).map_err(|e| e.to_string())?;
+    let destination = exports.join(format!(
+        "{}-summary.md",
+        meeting
+            .dir_name
+            .clone()
+            .unwrap_or_else(|| meeting.id.clone())
+    ));
+    std::fs::write(&destination, &summary.content)
+        .map_err(|e| format!("Failed to write summary: {}", e))?;
+    // Shortcuts can pick the text up with "Get Clipboard"
+    app.clipboard()
+        .write_text(summary.content.clone())
+        .map_err(|e| e.to_string())?;
+    log::info!(
+        "DEEP_LINK exported summary of {} to {:?}",
+        meeting.id,
+        destination
+    );
+    Ok(())
+}
+
+pub fn handle_url(app: &AppHandle, url: &Url) {
+    let link = match parse(url) {
+        Ok(link) => link,
+        Err(e) => {
+            log::warn!("DEEP_LINK ignored {}: {}", url, e);
+            return;
+        }
+    };
+    log::info!("DEEP_LINK {:?}", link);
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let result = match link {
+            DeepLink::Start { name, repo } => start_meeting(&app, name, repo).await,
+            DeepLink::OpenMeeting(id) => open_meeting(&app, &id).await,
+            DeepLink::End => end_meeting(&app).await,
+            DeepLink::Import { path, name } => import_file(&app, path, name).await,
+            DeepLink::ExportSummary(id) => export_summary(&app, &id),
+        };
+        if let Err(e) = result {
+            log::warn!("DEEP_LINK failed: {}", e);
+            let _ = app.emit("deep-link-error", e);
+        }
+    });
+}
+
+/// Handle links that launched the app and any opened while it runs. Links opened
+/// while running reach this instance through the single-instance plugin.
+pub fn init(app: &AppHandle) {
+    // Installed bundles register the scheme; dev builds on Linux/Windows must do it at runtime
+    #[cfg(all(debug_assertions, any(target_os = "linux", target_os = "windows")))]
+    if let Err(e) = app.deep_link().register_all() {
+        log::warn!("DEEP_LINK failed to register scheme: {}", e);
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            handle_url(&handle, &url);
+        }
+    });
+    if let Ok(Some(urls)) = app.deep_link().get_current() {
+        for url in urls {
+            handle_url(app, &url);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(s: &str) -> Result<DeepLink, String> {
+        parse(&Url::parse(s).unwrap())
+    }
+
+    #[test]
+    fn test_parse_deep_links() {
+        assert_eq!(
+            parse_str("meetingcoder://start?name=Standup&repo=org/app"),
+            Ok(DeepLink::Start {
+                name: Some("Standup".to_string()),
+                repo: Some(("org".to_string(), "app".to_string())),
+            })
+        );
+        assert_eq!(
+            parse_str("meetingcoder://start"),
+            Ok(DeepLink::Start {
+                name: None,
+                repo: None
+            })
+        );
+        assert_eq!(
+            parse_str("meetingcoder://open-meeting/abc-123"),
+            Ok(DeepLink::OpenMeeting("abc-123".to_string()))
+        );
+        assert_eq!(
+            parse_str("meetingcoder://import?path=/tmp/Sprint%20Review.mp3"),
+            Ok(DeepLink::Import {
+                path: PathBuf::from("/tmp/Sprint Review.mp3"),
+                name: None
+            })
+        );
+        assert_eq!(
+            parse_str("meetingcoder://export-summary"),
+            Ok(DeepLink::ExportSummary("latest".to_string()))
+        );
+        assert!(parse_str("meetingcoder://import?path=relative.mp3").is_err());
+        assert!(parse_str("meetingcoder://start?repo=noslash").is_err());
+        assert!(parse_str("meetingcoder://open-meeting").is_err());
+        assert!(parse_str("meetingcoder://delete-everything").is_err());
+        assert!(parse_str("https://start?name=x").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! Online clustering of voice embeddings: each utterance joins the most similar known
+//! speaker, or starts a new one when none is close enough.
+
+use crate::storage::speakers::Voiceprint;
+
+/// Cosine similarity of two L2-normalized vectors
+fn similarity(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[derive(Debug, Clone)]
+pub struct SpeakerClusters {
+    /// Normalized mean embedding of each speaker, in order of first appearance
+    centroids: Vec<Vec<f32>>,
+    counts: Vec<usize>,
+    threshold: f32,
+    max_speakers: usize,
+}
+
+impl SpeakerClusters {
+    pub fn new(threshold: f32, max_speakers: usize) -> Self {
+        Self {
+            centroids: Vec::new(),
+            counts: Vec::new(),
+            threshold,
+            max_speakers: max_speakers.max(1),
+        }
+    }
+
+    pub fn speaker_count(&self) -> usize {
+        self.centroids.len()
+    }
+
+    /// Most similar speaker and its similarity
+    pub fn nearest(&self, embedding: &[f32]) -> Option<(usize, f32)> {
+        self.centroids
+            .iter()
+            .map(|c| similarity(c, embedding))
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Speaker index (0-based) for an utterance, updating that speaker's centroid
+    pub fn assign(&mut self, embedding: &[f32]) -> usize {
+        match self.nearest(embedding) {
+            Some((index, score))
+                if score >= self.threshold || self.centroids.len() >= self.max_speakers =>
+            {
+                let n = self.counts[index] as f32;
+                let centroid = &mut self.centroids[index];
+                for (c, e) in centroid.iter_mut().zip(embedding) {
+                    *c = (*c * n + e) / (n + 1.0);
+                }
+                let norm = centroid.iter().map(|v| v * v).sum::<f32>().sqrt();
+                if norm > f32::EPSILON {
+                    centroid.iter_mut().for_each(|v| *v /= norm);
+                }
+                self.counts[index] += 1;
+                index
+            }
+            _ => {
+                self.centroids.push(embedding.to_vec());
+                self.counts.push(1);
+                self.centroids.len() - 1
+            }
+        }
+    }
+}
+
+/// Speaker index per embedding for a whole recording: an online pass finds the speakers,
+/// then every utterance is matched against the final centroids so early ones benefit
+/// from what was learned later. Indices are renumbered by first appearance.
+pub fn cluster_all(
+    embeddings: &[Option<Vec<f32>>],
+    threshold: f32,
+    max_speakers: usize,
+) -> Vec<Option<usize>> {
+    let mut clusters = SpeakerClusters::new(threshold, max_speakers);
+    for embedding in embeddings.iter().flatten() {
+        clusters.assign(embedding);
+    }
+    let mut order: Vec<usize> = Vec::new();
+    embeddings
+        .iter()
+        .map(|embedding| {
+            let (index, _) = clusters.nearest(embedding.as_ref()?)?;
+            let position = match order.iter().position(|&i| i == index) {
+                Some(p) => p,
+                None => {
+                    order.push(index);
+                    order.len() - 1
+                }
+            };
+            Some(position)
+        })
+        .collect()
+}
+
+/// Enrolled person whose voiceprint is most similar to `embedding`, if any reaches
+/// `threshold`. Names in `taken` already belong to another speaker and are skipped.
+pub fn identify<'a>(
+    voiceprints: &'a [Voiceprint],
+    embedding: &[f32],
+    threshold: f32,
+    taken: &[String],
+) -> Option<&'a str> {
+    voiceprints
+        .iter()
+        .filter(|v| !taken.contains(&v.name))
+        .map(|v| (v.name.as_str(), similarity(&v.embedding, embedding)))
+        .filter(|(_, score)| *score >= threshold)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(name, _)| name)
+}
+
+/// Normalized mean of several embeddings, e.g. one voice's utterances
+pub fn mean(embeddings: &[&[f32]]) -> Option<Vec<f32>> {
+    let first = embeddings.first()?;
+    let mut sum = vec![0.0; first.len()];
+    for embedding in embeddings {
+        for (s, e) in sum.iter_mut().zip(embedding.iter()) {
+            *s += e;
+        }
+    }
+    let norm = sum.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm <= f32::EPSILON {
+        return None;
+    }
+    sum.iter_mut().for_each(|v| *v /= norm);
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(v: &[f32]) -> Vec<f32> {
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.iter().map(|x| x / norm).collect()
+    }
+
+    #[test]
+    fn test_clusters_distinct_voices() {
+        let a = unit(&[1.0, 0.1, 0.0]);
+        let a2 = unit(&[0.9, 0.2, 0.05]);
+        let b = unit(&[0.0, 1.0, 0.1]);
+        let c = unit(&[0.0, 0.3, 1.0]);
+
+        let mut clusters = SpeakerClusters::new(0.7, 8);
+        assert_eq!(clusters.assign(&a), 0);
+        assert_eq!(clusters.assign(&b), 1);
+        assert_eq!(clusters.assign(&a2), 0);
+        assert_eq!(clusters.assign(&c), 2);
+        assert_eq!(clusters.speaker_count(), 3);
+
+        // With a cap, a new voice joins the closest speaker instead
+        let mut capped = SpeakerClusters::new(0.7, 2);
+        capped.assign(&a);
+        capped.assign(&b);
+        assert_eq!(capped.assign(&c), 1);
+
+        let labels = cluster_all(
+            &[Some(b.clone()), None, Some(a.clone()), Some(b), Some(a2)],
+            0.7,
+            8,
+        );
+        assert_eq!(labels, vec![Some(0), None, Some(1), Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn test_identify_enrolled_voice() {
+        let prints = vec![
+            Voiceprint {
+                name: "Alice".to_string(),
+                embedding: unit(&[1.0, 0.1, 0.0]),
+            },
+            Voiceprint {
+                name: "Damon".to_string(),
+                embedding: unit(&[0.0, 1.0, 0.1]),
+            },
+        ];
+        let voice = unit(&[0.9, 0.2, 0.05]);
+        assert_eq!(identify(&prints, &voice, 0.7, &[]), Some("Alice"));
+        assert_eq!(identify(&prints, &voice, 0.7, &["Alice".to_string()]), None);
+        assert_eq!(identify(&prints, &unit(&[0.0, 0.3, 1.0]), 0.7, &[]), None);
+
+        let averaged = mean(&[&[1.0, 0.0], &[0.0, 1.0]]).unwrap();
+        assert!((averaged[0] - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,63 @@
+//! Speaker embeddings from an ONNX model that takes fbank features (`[1, frames, 80]`) and
+//! returns one voice vector per utterance, e.g. the WeSpeaker ResNet34 export.
+
+use anyhow::{anyhow, Result};
+use ort::session::Session;
+use ort::value::Tensor;
+use std::path::Path;
+
+use super::fbank;
+
+/// Shortest voiced audio that yields a usable embedding
+const MIN_FRAMES: usize = 100;
+/// Frames of 30 ms below this RMS are dropped as silence before embedding
+const SILENCE_RMS: f32 = 0.005;
+const SILENCE_FRAME: usize = 480;
+
+pub struct SpeakerEmbedder {
+    session: Session,
+}
+
+/// The chunk with silent stretches removed, so pauses don't dilute the voice
+fn voiced(samples: &[f32]) -> Vec<f32> {
+    samples
+        .chunks(SILENCE_FRAME)
+        .filter(|frame| {
+            let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+            energy.sqrt() >= SILENCE_RMS
+        })
+        .flatten()
+        .copied()
+        .collect()
+}
+
+impl SpeakerEmbedder {
+    pub fn new(model_path: &Path) -> Result<Self> {
+        let session = Session::builder()?
+            .with_intra_threads(2)?
+            .commit_from_file(model_path)
+            .map_err(|e| anyhow!("Failed to load speaker model {:?}: {}", model_path, e))?;
+        Ok(Self { session })
+    }
+
+    /// L2-normalized voice vector for 16 kHz mono audio, or None when there is too
+    /// little speech in it
+    pub fn embed(&mut self, samples: &[f32]) -> Result<Option<Vec<f32>>> {
+        let features = fbank::compute(&voiced(samples));
+        if features.len() < MIN_FRAMES {
+            return Ok(None);
+        }
+        let frames = features.len();
+        let flat: Vec<f32> = features.into_iter().flatten().collect();
+        let input = Tensor::from_array(([1usize, frames, fbank::NUM_BINS], flat))?;
+        let outputs = self.session.run(ort::inputs![input])?;
+        let (_, data) = outputs[0].try_extract_tensor::<f32>()?;
+        let mut embedding = data.to_vec();
+        let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm <= f32::EPSILON {
+            return Ok(None);
+        }
+        embedding.iter_mut().for_each(|v| *v /= norm);
+        Ok(Some(embedding))
+    }
+}
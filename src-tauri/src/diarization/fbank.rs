@@ -0,0 +1,104 @@
+//! Kaldi-style log mel filterbank features, the input speaker embedding models are
+//! trained on (25 ms Hamming frames every 10 ms, 80 bins, mean-normalized).
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+pub const SAMPLE_RATE: usize = 16_000;
+pub const NUM_BINS: usize = 80;
+const FRAME_LENGTH: usize = 400;
+const FRAME_SHIFT: usize = 160;
+const FFT_SIZE: usize = 512;
+const PREEMPHASIS: f32 = 0.97;
+const LOW_FREQ: f32 = 20.0;
+
+fn mel(freq: f32) -> f32 {
+    1127.0 * (1.0 + freq / 700.0).ln()
+}
+
+/// Triangular filters over the FFT bins, evenly spaced on the mel scale
+fn mel_banks() -> Vec<Vec<f32>> {
+    let nyquist = SAMPLE_RATE as f32 / 2.0;
+    let (low, high) = (mel(LOW_FREQ), mel(nyquist));
+    let delta = (high - low) / (NUM_BINS + 1) as f32;
+    let bin_width = SAMPLE_RATE as f32 / FFT_SIZE as f32;
+    (0..NUM_BINS)
+        .map(|b| {
+            let left = low + b as f32 * delta;
+            let center = left + delta;
+            let right = center + delta;
+            (0..FFT_SIZE / 2 + 1)
+                .map(|k| {
+                    let m = mel(k as f32 * bin_width);
+                    if m > left && m < right {
+                        if m <= center {
+                            (m - left) / delta
+                        } else {
+                            (right - m) / delta
+                        }
+                    } else {
+                        0.0
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Features for 16 kHz mono samples in [-1, 1], one row of `NUM_BINS` per 10 ms frame
+pub fn compute(samples: &[f32]) -> Vec<[f32; NUM_BINS]> {
+    if samples.len() < FRAME_LENGTH {
+        return Vec::new();
+    }
+    let frames = 1 + (samples.len() - FRAME_LENGTH) / FRAME_SHIFT;
+    let window: Vec<f32> = (0..FRAME_LENGTH)
+        .map(|i| {
+            0.54 - 0.46 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32).cos()
+        })
+        .collect();
+    let banks = mel_banks();
+    let fft = FftPlanner::<f32>::new().plan_fft_forward(FFT_SIZE);
+    let mut buffer = vec![Complex::new(0.0, 0.0); FFT_SIZE];
+    let mut out = Vec::with_capacity(frames);
+
+    for f in 0..frames {
+        // Models are trained on int16-scaled audio
+        let mut frame: Vec<f32> = samples[f * FRAME_SHIFT..f * FRAME_SHIFT + FRAME_LENGTH]
+            .iter()
+            .map(|s| s * 32768.0)
+            .collect();
+        let mean = frame.iter().sum::<f32>() / FRAME_LENGTH as f32;
+        frame.iter_mut().for_each(|s| *s -= mean);
+        for i in (1..FRAME_LENGTH).rev() {
+            frame[i] -= PREEMPHASIS * frame[i - 1];
+        }
+        frame[0] -= PREEMPHASIS * frame[0];
+
+        for (i, slot) in buffer.iter_mut().enumerate() {
+            let s = if i < FRAME_LENGTH {
+                frame[i] * window[i]
+            } else {
+                0.0
+            };
+            *slot = Complex::new(s, 0.0);
+        }
+        fft.process(&mut buffer);
+        let power: Vec<f32> = buffer[..FFT_SIZE / 2 + 1]
+            .iter()
+            .map(|c| c.norm_sqr())
+            .collect();
+
+        let mut row = [0.0f32; NUM_BINS];
+        for (b, bank) in banks.iter().enumerate() {
+            let energy: f32 = bank.iter().zip(&power).map(|(w, p)| w * p).sum();
+            row[b] = energy.max(f32::EPSILON).ln();
+        }
+        out.push(row);
+    }
+
+    // Cepstral mean normalization over the utterance
+    for b in 0..NUM_BINS {
+        let mean = out.iter().map(|r| r[b]).sum::<f32>() / out.len() as f32;
+        out.iter_mut().for_each(|r| r[b] -= mean);
+    }
+    out
+}
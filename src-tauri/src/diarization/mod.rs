@@ -0,0 +1,329 @@
+//! Speaker diarization from voice embeddings. Each transcribed chunk is embedded with a
+//! speaker model and clustered against the voices heard so far in the meeting, so
+//! `TranscriptSegment.speaker` follows actual distinct voices instead of alternating on
+//! pauses. Voices matching an enrolled voiceprint (`storage::speakers`) are labelled with
+//! the person's name. Saved meetings can be re-diarized from their recording.
+
+pub mod cluster;
+pub mod embedder;
+pub mod fbank;
+
+use anyhow::{anyhow, Context, Result};
+use futures_util::StreamExt;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::managers::meeting::TranscriptSegment;
+use crate::managers::model::DownloadProgress;
+use crate::network::{self, Service};
+use crate::storage::speakers::{SpeakerDb, SpeakerProfile, Voiceprint};
+use cluster::SpeakerClusters;
+use embedder::SpeakerEmbedder;
+
+/// Stored next to the transcription models
+pub const MODEL_FILE: &str = "speaker_embedding.onnx";
+/// Progress events use this as the model id
+pub const MODEL_ID: &str = "speaker-embedding";
+/// WeSpeaker ResNet34 trained on VoxCeleb (~26 MB)
+const MODEL_URL: &str = "https://github.com/k2-fsa/sherpa-onnx/releases/download/\
+                         speaker-recongition-models/wespeaker_en_voxceleb_resnet34.onnx";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiarizationStatus {
+    pub enabled: bool,
+    pub model_downloaded: bool,
+    pub model_path: Option<PathBuf>,
+}
+
+/// Voices heard so far in a live meeting
+struct LiveState {
+    clusters: SpeakerClusters,
+    /// Speaker of the previous chunk, reused when a chunk has too little speech to embed
+    last: Option<usize>,
+    /// Speakers recognized as enrolled people
+    names: HashMap<usize, String>,
+}
+
+/// Loaded on first use and kept for the life of the app
+static EMBEDDER: Lazy<Mutex<Option<SpeakerEmbedder>>> = Lazy::new(|| Mutex::new(None));
+static LIVE: Lazy<Mutex<HashMap<String, LiveState>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn label(index: usize) -> String {
+    format!("Speaker {}", index + 1)
+}
+
+/// Enrolled voiceprints, empty when none are stored or the database is unavailable
+fn voiceprints(app: &AppHandle) -> Vec<Voiceprint> {
+    app.try_state::<Arc<SpeakerDb>>()
+        .and_then(|db| {
+            db.voiceprints()
+                .map_err(|e| log::warn!("DIARIZATION {}", e))
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+pub fn model_path(app: &AppHandle) -> Result<PathBuf> {
+    Ok(app.path().app_data_dir()?.join("models").join(MODEL_FILE))
+}
+
+pub fn status(app: &AppHandle) -> DiarizationStatus {
+    let path = model_path(app).ok().filter(|p| p.is_file());
+    DiarizationStatus {
+        enabled: crate::settings::get_settings(app).diarization.enabled,
+        model_downloaded: path.is_some(),
+        model_path: path,
+    }
+}
+
+/// Download the speaker model, reporting progress like the transcription models do
+pub async fn download_model(app: &AppHandle) -> Result<PathBuf> {
+    let path = model_path(app)?;
+    if path.is_file() {
+        return Ok(path);
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let response = network::client(Service::ModelDownload, MODEL_URL)?
+        .get(MODEL_URL)
+        .send()
+        .await
+        .context("Failed to reach the model server")?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Model download failed: {}", response.status()));
+    }
+    let total = response.content_length().unwrap_or(0);
+    let partial = path.with_extension("onnx.partial");
+    let mut file = std::fs::File::create(&partial)?;
+    let mut downloaded: u64 = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk)?;
+        downloaded += chunk.len() as u64;
+        let _ = app.emit(
+            "model-download-progress",
+            &DownloadProgress {
+                model_id: MODEL_ID.to_string(),
+                downloaded,
+                total,
+                percentage: if total > 0 {
+                    downloaded as f64 / total as f64 * 100.0
+                } else {
+                    0.0
+                },
+            },
+        );
+    }
+    file.flush()?;
+    drop(file);
+    std::fs::rename(&partial, &path)?;
+    log::info!("DIARIZATION downloaded speaker model to {:?}", path);
+    Ok(path)
+}
+
+fn with_embedder<T>(
+    app: &AppHandle,
+    f: impl FnOnce(&mut SpeakerEmbedder) -> Result<T>,
+) -> Result<T> {
+    let mut guard = EMBEDDER.lock().unwrap();
+    if guard.is_none() {
+        let path = model_path(app)?;
+        if !path.is_file() {
+            return Err(anyhow!("The speaker model has not been downloaded"));
+        }
+        *guard = Some(SpeakerEmbedder::new(&path)?);
+    }
+    f(guard.as_mut().expect("embedder was just loaded"))
+}
+
+/// Clamp the thresholds to usable ranges, for the settings commands
+pub fn normalize_config(config: &mut crate::settings::DiarizationConfig) -> Result<(), String> {
+    if !config.similarity_threshold.is_finite() {
+        return Err("Speaker similarity threshold must be a number".to_string());
+    }
+    config.similarity_threshold = config.similarity_threshold.clamp(0.0, 1.0);
+    config.max_speakers = config.max_speakers.clamp(2, 20);
+    Ok(())
+}
+
+/// Speaker label for a live chunk of 16 kHz mono audio. None when diarization is off or
+/// the model is unavailable, in which case callers keep their pause-based labels.
+pub fn live_label(app: &AppHandle, meeting_id: &str, samples: &[f32]) -> Option<String> {
+    let config = crate::settings::get_settings(app).diarization;
+    if !config.enabled {
+        return None;
+    }
+    let embedding = match with_embedder(app, |e| e.embed(samples)) {
+        Ok(embedding) => embedding,
+        Err(e) => {
+            log::warn!("DIARIZATION unavailable, using pause-based speakers: {}", e);
+            return None;
+        }
+    };
+    let mut live = LIVE.lock().unwrap();
+    let state = live
+        .entry(meeting_id.to_string())
+        .or_insert_with(|| LiveState {
+            clusters: SpeakerClusters::new(config.similarity_threshold, config.max_speakers),
+            last: None,
+            names: HashMap::new(),
+        });
+    let index = match &embedding {
+        Some(embedding) => state.clusters.assign(embedding),
+        None => state.last.unwrap_or(0),
+    };
+    state.last = Some(index);
+    // Until a speaker is recognized, check each of their utterances against the enrolled voices
+    if let (false, Some(embedding)) = (state.names.contains_key(&index), &embedding) {
+        let taken: Vec<String> = state.names.values().cloned().collect();
+        let prints = voiceprints(app);
+        if let Some(name) =
+            cluster::identify(&prints, embedding, config.similarity_threshold, &taken)
+        {
+            log::info!("DIARIZATION recognized {} as {}", label(index), name);
+            state.names.insert(index, name.to_string());
+        }
+    }
+    Some(
+        state
+            .names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| label(index)),
+    )
+}
+
+/// Drop the voices of a meeting that ended
+pub fn forget_meeting(meeting_id: &str) {
+    LIVE.lock().unwrap().remove(meeting_id);
+}
+
+/// Relabel every segment of a recorded meeting by voice. Enrolled people get their
+/// names; other speakers, including custom names, become "Speaker N". Returns the number
+/// of distinct speakers found.
+pub fn rediarize(
+    app: &AppHandle,
+    audio_path: &Path,
+    segments: &mut [TranscriptSegment],
+) -> Result<usize> {
+    let config = crate::settings::get_settings(app).diarization;
+    let samples = crate::audio_toolkit::audio::load_audio_file_to_mono_16k(audio_path)?;
+    let to_sample =
+        |seconds: f64| ((seconds.max(0.0) * fbank::SAMPLE_RATE as f64) as usize).min(samples.len());
+    let embeddings = with_embedder(app, |embedder| {
+        segments
+            .iter()
+            .map(|s| {
+                let (start, end) = (to_sample(s.start_time), to_sample(s.end_time));
+                if end <= start {
+                    return Ok(None);
+                }
+                embedder.embed(&samples[start..end])
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    let speakers = cluster::cluster_all(
+        &embeddings,
+        config.similarity_threshold,
+        config.max_speakers,
+    );
+    let names = identify_speakers(
+        &voiceprints(app),
+        &embeddings,
+        &speakers,
+        config.similarity_threshold,
+    );
+    let mut previous = 0;
+    for (segment, speaker) in segments.iter_mut().zip(speakers) {
+        // Segments too short to embed keep the speaker before them
+        let index = speaker.unwrap_or(previous);
+        segment.speaker = names.get(&index).cloned().unwrap_or_else(|| label(index));
+        previous = index;
+    }
+    let count = segments
+        .iter()
+        .map(|s| s.speaker.as_str())
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    log::info!(
+        "DIARIZATION relabelled {} segments from {:?} into {} speakers",
+        segments.len(),
+        audio_path,
+        count
+    );
+    Ok(count)
+}
+
+/// Enrolled names for the speakers of a recording, matching each speaker's averaged voice.
+/// Speakers with the most utterances pick first, so a name goes to its likeliest voice.
+fn identify_speakers(
+    voiceprints: &[Voiceprint],
+    embeddings: &[Option<Vec<f32>>],
+    speakers: &[Option<usize>],
+    threshold: f32,
+) -> HashMap<usize, String> {
+    let mut voices: HashMap<usize, Vec<&[f32]>> = HashMap::new();
+    for (embedding, speaker) in embeddings.iter().zip(speakers) {
+        if let (Some(embedding), Some(speaker)) = (embedding, speaker) {
+            voices.entry(*speaker).or_default().push(embedding);
+        }
+    }
+    let mut voices: Vec<(usize, Vec<&[f32]>)> = voices.into_iter().collect();
+    voices.sort_by_key(|(index, utterances)| (std::cmp::Reverse(utterances.len()), *index));
+    let mut names: HashMap<usize, String> = HashMap::new();
+    for (index, utterances) in voices {
+        let Some(voice) = cluster::mean(&utterances) else {
+            continue;
+        };
+        let taken: Vec<String> = names.values().cloned().collect();
+        if let Some(name) = cluster::identify(voiceprints, &voice, threshold, &taken) {
+            names.insert(index, name.to_string());
+        }
+    }
+    names
+}
+
+/// Enroll `name` from one or more short recordings of them speaking alone. The voiceprint
+/// is the average of the samples' embeddings; re-enrolling a name replaces it.
+pub fn enroll(
+    app: &AppHandle,
+    db: &SpeakerDb,
+    name: &str,
+    sample_paths: &[PathBuf],
+) -> Result<SpeakerProfile> {
+    let mut embeddings = Vec::new();
+    let mut seconds = 0.0;
+    with_embedder(app, |embedder| {
+        for path in sample_paths {
+            let samples = crate::audio_toolkit::audio::load_audio_file_to_mono_16k(path)
+                .with_context(|| format!("Cannot read voice sample {:?}", path))?;
+            match embedder.embed(&samples)? {
+                Some(embedding) => {
+                    embeddings.push(embedding);
+                    seconds += samples.len() as f64 / fbank::SAMPLE_RATE as f64;
+                }
+                None => log::warn!("DIARIZATION too little speech in {:?}, skipped", path),
+            }
+        }
+        Ok(())
+    })?;
+    let refs: Vec<&[f32]> = embeddings.iter().map(|e| e.as_slice()).collect();
+    let voiceprint = cluster::mean(&refs)
+        .ok_or_else(|| anyhow!("The samples have too little speech to recognize a voice"))?;
+    let profile = db.enroll(name, &voiceprint, embeddings.len(), seconds)?;
+    log::info!(
+        "DIARIZATION enrolled {} from {} samples ({:.1}s)",
+        profile.name,
+        profile.sample_count,
+        profile.sample_seconds
+    );
+    Ok(profile)
+}
@@ -6,5 +6,7 @@ pub mod types;
 
 // Re-export main types and functions for convenience
 pub use prd_generator::PRDGenerator;
-pub use prd_storage::{get_all_versions, load_changelog, load_metadata, load_prd_version};
+pub use prd_storage::{
+    get_all_versions, get_prd_directory, load_changelog, load_metadata, load_prd_version,
+};
 pub use types::*;
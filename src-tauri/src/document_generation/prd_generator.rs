@@ -335,11 +335,11 @@ impl PRDGenerator {
         project_context: Option<String>,
         previous_content: Option<&PRDContent>,
     ) -> Result<PRDContent> {
-        use crate::summarization::llm::{call_claude_api, has_api_key};
+        use crate::summarization::llm::{complete, is_available, json_body};
 
         // Check if LLM is available
-        if !has_api_key() {
-            log::warn!("No Claude API key found, falling back to heuristic PRD extraction");
+        if !is_available() {
+            log::warn!("LLM provider not configured, falling back to heuristic PRD extraction");
             return self.extract_prd_content_heuristic(extractions, previous_content);
         }
 
@@ -351,11 +351,11 @@ impl PRDGenerator {
             get_prd_initial_prompt(transcript, extractions, project_context)
         };
 
-        // Call Claude API
-        match call_claude_api("claude-sonnet-4-5-20250929", &system_prompt, &user_prompt).await {
+        // Call the configured LLM provider
+        match complete(&system_prompt, &user_prompt).await {
             Ok(response) => {
                 // Parse JSON response
-                let content: PRDContent = serde_json::from_str(&response)
+                let content: PRDContent = serde_json::from_str(json_body(&response))
                     .context("Failed to parse LLM response as PRDContent")?;
 
                 Ok(content)
@@ -500,7 +500,7 @@ Please create a comprehensive PRD with:
 5. Technical requirements (frameworks, libraries, architecture)
 6. Acceptance criteria for key requirements
 7. Dependencies (if mentioned)
-8. Risks (if mentioned)
+8. Risks (if mentioned, plus any requirement that touches an area listed under "Areas without tests")
 9. Timeline milestones (if dates/phases mentioned)
 10. Open questions (uncertainties or ambiguities)
 
@@ -177,6 +177,20 @@ pub fn load_metadata(meeting_id: &str) -> Result<Option<PRDMetadata>> {
     Ok(Some(metadata))
 }
 
+/// Latest PRD version of a meeting, without creating its PRD directory
+pub fn latest_version(meeting_id: &str) -> Option<u32> {
+    let metadata_path = dirs::home_dir()?
+        .join(".handy")
+        .join("meetings")
+        .join(meeting_id)
+        .join("prds")
+        .join("metadata.json");
+    let content = fs::read_to_string(metadata_path).ok()?;
+    serde_json::from_str::<PRDMetadata>(&content)
+        .ok()
+        .map(|m| m.latest_version)
+}
+
 /// Update metadata after saving a new version
 pub fn update_metadata(meeting_id: &str, meeting_name: &str, new_version: u32) -> Result<()> {
     let existing = load_metadata(meeting_id)?;
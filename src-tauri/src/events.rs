@@ -0,0 +1,242 @@
+//! Catalog of meeting, import, queue, and agent events sent to the frontend.
+//!
+//! Events go out under their usual names with unchanged payloads, and are also kept
+//! in a bounded replay buffer so a reloaded window can catch up with
+//! `get_recent_events(since)`. High-rate or purely visual events (mic levels, overlay
+//! frames, queue metrics) and `settings-changed` stay as plain `emit` calls.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+use crate::automation::command_suggestions::SuggestedCommand;
+use crate::integrations::cloud_sync::MeetingSyncStatus;
+use crate::managers::meeting::TranscriptSegment;
+use crate::meeting::recovery::InterruptedMeeting;
+use crate::network::BlockedRequest;
+use crate::queue::RecoveryReport;
+use crate::storage::db::MeetingMarker;
+use crate::storage::retention::RetentionReport;
+use crate::watch_folder::WatchFileStatus;
+
+/// Events kept for replay; older ones are dropped first
+const REPLAY_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "payload", rename_all = "kebab-case")]
+pub enum AppEvent {
+    TranscriptSegmentAdded {
+        meeting_id: String,
+        segment: TranscriptSegment,
+    },
+    MeetingStateChanged {
+        meeting_id: String,
+        /// "recording", "paused", or "completed"
+        status: String,
+        /// What changed it, e.g. "shortcut", "tray", "api"
+        source: String,
+    },
+    /// A segment's `translated_text` arrived
+    SegmentTranslated {
+        meeting_id: String,
+        segment_index: usize,
+        translated_text: String,
+    },
+    MeetingMarkerAdded(MeetingMarker),
+    MeetingMarkerUpdated {
+        id: i64,
+        meeting_id: String,
+        label: String,
+    },
+    MeetingUpdateAppended {
+        update_id: u32,
+        meeting_id: String,
+    },
+    AudioStreamRestarting {
+        meeting_id: String,
+        attempt: u32,
+        max_attempts: u32,
+    },
+    AudioStreamRestartSuccess {
+        meeting_id: String,
+    },
+    AudioStreamRestartFailed {
+        meeting_id: String,
+        error: String,
+        attempts_remaining: u32,
+    },
+    AudioStreamWarning {
+        meeting_id: String,
+        message: String,
+        consecutive_empty_chunks: u32,
+    },
+    AudioSilentChunk {
+        meeting_id: String,
+        dbfs: f32,
+        threshold: f32,
+    },
+    ImportProgress {
+        stage: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        percent: Option<u8>,
+    },
+    /// A file in the watched folder moved through auto-import
+    WatchFolderFile {
+        path: String,
+        status: WatchFileStatus,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        meeting_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    QueueRecovered(RecoveryReport),
+    /// Meetings the previous run stopped recording without ending
+    MeetingsInterrupted(Vec<InterruptedMeeting>),
+    QueueJobDeadLettered {
+        id: i64,
+        meeting_id: String,
+        error: String,
+    },
+    QueueMeetingCancelled {
+        meeting_id: String,
+        cancelled: usize,
+    },
+    RetentionApplied(RetentionReport),
+    CloudSyncStatus(MeetingSyncStatus),
+    SuggestedCommands {
+        meeting_id: String,
+        suggestions: Vec<SuggestedCommand>,
+    },
+    AgentKeywordTriggered {
+        meeting_id: String,
+        phrase: String,
+        prompt_path: String,
+    },
+    AgentChangeBlocked {
+        meeting_id: String,
+        update_id: u32,
+        reasons: Vec<String>,
+        files_touched: Vec<String>,
+        lines_changed: u64,
+        untested_files: Vec<String>,
+    },
+    NetworkRequestBlocked(BlockedRequest),
+}
+
+impl AppEvent {
+    pub fn meeting_state(meeting_id: &str, status: &str, source: &str) -> Self {
+        AppEvent::MeetingStateChanged {
+            meeting_id: meeting_id.to_string(),
+            status: status.to_string(),
+            source: source.to_string(),
+        }
+    }
+}
+
+/// An emitted event as returned by `get_recent_events`
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedEvent {
+    /// Increases by one per event for the life of the process
+    pub seq: u64,
+    /// Unix milliseconds
+    pub emitted_at: i64,
+    #[serde(flatten)]
+    pub event: AppEvent,
+}
+
+struct ReplayBuffer {
+    next_seq: u64,
+    events: VecDeque<RecordedEvent>,
+}
+
+static REPLAY: Lazy<Mutex<ReplayBuffer>> = Lazy::new(|| {
+    Mutex::new(ReplayBuffer {
+        next_seq: 1,
+        events: VecDeque::with_capacity(REPLAY_CAPACITY),
+    })
+});
+
+fn record(event: AppEvent) -> RecordedEvent {
+    let mut buffer = REPLAY.lock().unwrap();
+    let recorded = RecordedEvent {
+        seq: buffer.next_seq,
+        emitted_at: chrono::Utc::now().timestamp_millis(),
+        event,
+    };
+    buffer.next_seq += 1;
+    if buffer.events.len() == REPLAY_CAPACITY {
+        buffer.events.pop_front();
+    }
+    buffer.events.push_back(recorded.clone());
+    recorded
+}
+
+/// Record `event` for replay and send it to the frontend
+pub fn emit(app: &AppHandle, event: AppEvent) {
+    let recorded = record(event);
+    match serde_json::to_value(&recorded.event) {
+        Ok(serde_json::Value::Object(mut value)) => {
+            let name = value
+                .remove("event")
+                .and_then(|n| n.as_str().map(str::to_string))
+                .unwrap_or_default();
+            let payload = value.remove("payload").unwrap_or(serde_json::Value::Null);
+            let _ = app.emit(&name, payload);
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Failed to serialize event: {}", e),
+    }
+}
+
+/// Buffered events newer than `since` (all buffered events when `None`), oldest first
+pub fn recent_events(since: Option<u64>) -> Vec<RecordedEvent> {
+    let since = since.unwrap_or(0);
+    REPLAY
+        .lock()
+        .unwrap()
+        .events
+        .iter()
+        .filter(|e| e.seq > since)
+        .cloned()
+        .collect()
+}
+
+/// Events a reloaded window missed: pass the `seq` of the last event it handled
+#[tauri::command]
+pub fn get_recent_events(since: Option<u64>) -> Vec<RecordedEvent> {
+    recent_events(since)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_wire_format_and_replay() {
+        let value = serde_json::to_value(AppEvent::MeetingUpdateAppended {
+            update_id: 3,
+            meeting_id: "m1".to_string(),
+        })
+        .unwrap();
+        assert_eq!(value["event"], "meeting-update-appended");
+        assert_eq!(value["payload"]["update_id"], 3);
+
+        let first = record(AppEvent::AudioStreamRestartSuccess {
+            meeting_id: "m1".to_string(),
+        });
+        for _ in 0..REPLAY_CAPACITY {
+            record(AppEvent::QueueMeetingCancelled {
+                meeting_id: "m1".to_string(),
+                cancelled: 1,
+            });
+        }
+        let all = recent_events(None);
+        assert_eq!(all.len(), REPLAY_CAPACITY);
+        assert!(all.iter().all(|e| e.seq > first.seq));
+        let last = all.last().unwrap().seq;
+        assert_eq!(recent_events(Some(last - 2)).len(), 2);
+        assert!(recent_events(Some(last)).is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+//! Turns on the OS Do Not Disturb mode while a meeting records so notification
+//! sounds don't end up in captured system audio, then puts it back afterwards.
+//!
+//! - macOS has no public Focus API, so this runs two user-created Shortcuts
+//!   (`MACOS_FOCUS_ON_SHORTCUT` / `MACOS_FOCUS_OFF_SHORTCUT`) with a "Set Focus" action
+//! - Windows turns off notification banners and sounds, the switch behind Focus Assist
+//! - Linux (GNOME) turns off notification banners
+//!
+//! Only a mode this module turned on is turned off again; if Do Not Disturb was
+//! already on when the meeting started it is left alone.
+
+use once_cell::sync::Lazy;
+use std::process::Command;
+use std::sync::Mutex;
+
+pub const MACOS_FOCUS_ON_SHORTCUT: &str = "MeetingCoder Focus On";
+pub const MACOS_FOCUS_OFF_SHORTCUT: &str = "MeetingCoder Focus Off";
+
+/// What to put back on restore; `None` while nothing was changed
+static SAVED: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+fn run(program: &str, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new(program).args(args).output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{} {} failed: {}",
+            program,
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Value of a REG_DWORD line in `reg query` output
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_reg_dword(output: &str, name: &str) -> Option<u32> {
+    output.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        if parts.next()? != name || parts.next()? != "REG_DWORD" {
+            return None;
+        }
+        u32::from_str_radix(parts.next()?.trim_start_matches("0x"), 16).ok()
+    })
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::*;
+
+    /// Focus modes are recorded here; unreadable without Full Disk Access, in which
+    /// case Focus is assumed to be off
+    fn focus_active() -> bool {
+        let Some(home) = dirs::home_dir() else {
+            return false;
+        };
+        std::fs::read_to_string(home.join("Library/DoNotDisturb/DB/Assertions.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .and_then(|v| {
+                v["data"].as_array().map(|data| {
+                    data.iter().any(|d| {
+                        d["storeAssertionRecords"]
+                            .as_array()
+                            .is_some_and(|r| !r.is_empty())
+                    })
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    fn ensure_shortcut(name: &str) -> anyhow::Result<()> {
+        if run("shortcuts", &["list"])?
+            .lines()
+            .any(|l| l.trim() == name)
+        {
+            Ok(())
+        } else {
+            anyhow::bail!(
+                "Shortcut '{}' not found; create it in the Shortcuts app with a \"Set Focus\" action",
+                name
+            )
+        }
+    }
+
+    pub fn enable() -> anyhow::Result<Option<String>> {
+        if focus_active() {
+            return Ok(None);
+        }
+        ensure_shortcut(MACOS_FOCUS_ON_SHORTCUT)?;
+        ensure_shortcut(MACOS_FOCUS_OFF_SHORTCUT)?;
+        run("shortcuts", &["run", MACOS_FOCUS_ON_SHORTCUT])?;
+        Ok(Some(String::new()))
+    }
+
+    pub fn restore(_saved: &str) -> anyhow::Result<()> {
+        run("shortcuts", &["run", MACOS_FOCUS_OFF_SHORTCUT]).map(|_| ())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+
+    const KEY: &str = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Notifications\Settings";
+    const VALUE: &str = "NOC_GLOBAL_SETTING_TOASTS_ENABLED";
+    /// Saved when the value didn't exist, which Windows treats as enabled
+    const ABSENT: &str = "absent";
+
+    fn set(value: &str) -> anyhow::Result<()> {
+        run(
+            "reg",
+            &[
+                "add",
+                KEY,
+                "/v",
+                VALUE,
+                "/t",
+                "REG_DWORD",
+                "/d",
+                value,
+                "/f",
+            ],
+        )
+        .map(|_| ())
+    }
+
+    pub fn enable() -> anyhow::Result<Option<String>> {
+        let previous = run("reg", &["query", KEY, "/v", VALUE])
+            .ok()
+            .and_then(|out| parse_reg_dword(&out, VALUE));
+        if previous == Some(0) {
+            return Ok(None);
+        }
+        set("0")?;
+        Ok(Some(
+            previous.map_or_else(|| ABSENT.to_string(), |v| v.to_string()),
+        ))
+    }
+
+    pub fn restore(saved: &str) -> anyhow::Result<()> {
+        if saved == ABSENT {
+            run("reg", &["delete", KEY, "/v", VALUE, "/f"]).map(|_| ())
+        } else {
+            set(saved)
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::*;
+
+    const SCHEMA: &str = "org.gnome.desktop.notifications";
+    const KEY: &str = "show-banners";
+
+    pub fn enable() -> anyhow::Result<Option<String>> {
+        let previous = run("gsettings", &["get", SCHEMA, KEY])?.trim().to_string();
+        if previous == "false" {
+            return Ok(None);
+        }
+        run("gsettings", &["set", SCHEMA, KEY, "false"])?;
+        Ok(Some(previous))
+    }
+
+    pub fn restore(saved: &str) -> anyhow::Result<()> {
+        run("gsettings", &["set", SCHEMA, KEY, saved]).map(|_| ())
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+mod platform {
+    pub fn enable() -> anyhow::Result<Option<String>> {
+        anyhow::bail!("Do Not Disturb isn't supported on this platform")
+    }
+
+    pub fn restore(_saved: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Turn Do Not Disturb on unless this module already did. Blocks on child processes.
+pub fn enable() {
+    let mut saved = SAVED.lock().unwrap();
+    if saved.is_some() {
+        return;
+    }
+    match platform::enable() {
+        Ok(Some(previous)) => {
+            log::info!("FOCUS_MODE enabled Do Not Disturb for recording");
+            *saved = Some(previous);
+        }
+        Ok(None) => log::info!("FOCUS_MODE Do Not Disturb already on; leaving it"),
+        Err(e) => log::warn!("FOCUS_MODE failed to enable Do Not Disturb: {}", e),
+    }
+}
+
+/// Undo `enable`; a no-op when it changed nothing
+pub fn restore() {
+    let Some(previous) = SAVED.lock().unwrap().take() else {
+        return;
+    };
+    match platform::restore(&previous) {
+        Ok(()) => log::info!("FOCUS_MODE restored Do Not Disturb"),
+        Err(e) => log::warn!("FOCUS_MODE failed to restore Do Not Disturb: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reg_dword() {
+        let output = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Notifications\\Settings\r\n    NOC_GLOBAL_SETTING_TOASTS_ENABLED    REG_DWORD    0x1\r\n\r\n";
+        assert_eq!(
+            parse_reg_dword(output, "NOC_GLOBAL_SETTING_TOASTS_ENABLED"),
+            Some(1)
+        );
+        assert_eq!(parse_reg_dword(output, "OTHER"), None);
+        assert_eq!(
+            parse_reg_dword("", "NOC_GLOBAL_SETTING_TOASTS_ENABLED"),
+            None
+        );
+    }
+}
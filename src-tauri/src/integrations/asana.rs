@@ -0,0 +1,244 @@
+//! Asana tasks from a meeting's action items. Each item becomes a task in the configured
+//! project, assigned through the speaker-to-owner mapping in settings. Created tasks are
+//! recorded in the meeting database, so syncing a meeting again only adds new items.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::AsanaConfig;
+use crate::storage::db::{ExternalLink, MeetingDb};
+use crate::summarization::agent::Feature;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.asana";
+const KEYCHAIN_ACCOUNT: &str = "token";
+const FALLBACK_FILE: &str = ".asana-token";
+const TASKS_URL: &str = "https://app.asana.com/api/1.0/tasks";
+/// `service` of the tasks' rows in the external links table
+const LINK_SERVICE: &str = "asana";
+/// Asana rejects task names longer than this
+const MAX_NAME_CHARS: usize = 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AsanaSyncResult {
+    pub created: usize,
+    /// Items that already had a task from an earlier sync
+    pub already_synced: usize,
+    /// Speakers of created tasks with no Asana owner in settings
+    pub unassigned: Vec<String>,
+    /// Every task of the meeting, including earlier ones
+    pub tasks: Vec<ExternalLink>,
+}
+
+/// Store a personal access token in the keyring, falling back to a file
+pub fn store_token(token: &str) -> Result<()> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(anyhow!("Asana personal access token is empty"));
+    }
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, token)
+}
+
+fn load_token() -> Result<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map(|t| t.trim().to_string())
+        .map_err(|_| anyhow!("Asana is not connected"))
+}
+
+pub fn has_token() -> bool {
+    load_token().is_ok()
+}
+
+/// Remove the stored token
+pub fn clear_token() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Asana user (email or gid) for a speaker label, matched case-insensitively
+fn owner_for<'a>(config: &'a AsanaConfig, speaker: &str) -> Option<&'a str> {
+    config
+        .owners
+        .iter()
+        .find(|(label, _)| label.trim().eq_ignore_ascii_case(speaker.trim()))
+        .map(|(_, owner)| owner.trim())
+        .filter(|o| !o.is_empty())
+}
+
+/// `due_in_days` after the meeting's date
+fn due_on(config: &AsanaConfig, digest: &MeetingDigest) -> Option<String> {
+    let days = config.due_in_days?;
+    let date = NaiveDate::parse_from_str(&digest.date(), "%Y-%m-%d").ok()?;
+    Some(
+        (date + Duration::days(days as i64))
+            .format("%Y-%m-%d")
+            .to_string(),
+    )
+}
+
+/// Request body creating the task for one action item
+pub fn task_body(config: &AsanaConfig, digest: &MeetingDigest, item: &Feature) -> Value {
+    let notes = format!("{}\n\n{}", item.description, digest.item_context(item));
+    let mut data = json!({
+        "name": item.title.chars().take(MAX_NAME_CHARS).collect::<String>(),
+        "notes": notes,
+        "projects": [config.project_gid.trim()],
+    });
+    if let Some(owner) = owner_for(config, &item.mentioned_by) {
+        data["assignee"] = json!(owner);
+    }
+    if let Some(due) = due_on(config, digest) {
+        data["due_on"] = json!(due);
+    }
+    json!({ "data": data })
+}
+
+async fn create_task(token: &str, body: &Value) -> Result<(String, Option<String>)> {
+    let response = network::client(Service::Asana, TASKS_URL)?
+        .post(TASKS_URL)
+        .bearer_auth(token)
+        .json(body)
+        .send()
+        .await
+        .context("Failed to reach Asana")?;
+    let status = response.status();
+    let value: Value = response.json().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(anyhow!(
+            "Asana returned {}: {}",
+            status,
+            value["errors"][0]["message"]
+                .as_str()
+                .unwrap_or("unknown error")
+        ));
+    }
+    let gid = value["data"]["gid"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Asana response had no task id"))?;
+    Ok((
+        gid.to_string(),
+        value["data"]["permalink_url"].as_str().map(String::from),
+    ))
+}
+
+/// Create tasks for the meeting's action items that don't have one yet
+pub async fn sync_digest(
+    db: &MeetingDb,
+    config: &AsanaConfig,
+    digest: &MeetingDigest,
+) -> Result<AsanaSyncResult> {
+    if config.project_gid.trim().is_empty() {
+        return Err(anyhow!("Choose an Asana project to sync to"));
+    }
+    let token = load_token()?;
+    let existing = db.list_external_links(&digest.meeting_id, LINK_SERVICE)?;
+    let mut result = AsanaSyncResult {
+        created: 0,
+        already_synced: 0,
+        unassigned: Vec::new(),
+        tasks: Vec::new(),
+    };
+    for item in &digest.action_item_details {
+        if existing.iter().any(|l| l.item_id == item.id) {
+            result.already_synced += 1;
+            continue;
+        }
+        let (gid, url) = create_task(&token, &task_body(config, digest, item)).await?;
+        // Recorded per task, so a failure part-way through doesn't duplicate on retry
+        db.save_external_link(
+            &digest.meeting_id,
+            LINK_SERVICE,
+            &item.id,
+            &gid,
+            url.as_deref(),
+        )?;
+        result.created += 1;
+        if owner_for(config, &item.mentioned_by).is_none()
+            && !result.unassigned.contains(&item.mentioned_by)
+        {
+            result.unassigned.push(item.mentioned_by.clone());
+        }
+    }
+    result.tasks = db.list_external_links(&digest.meeting_id, LINK_SERVICE)?;
+    Ok(result)
+}
+
+/// Sync the action items of a meeting that just ended, if auto-sync is on
+pub fn spawn_sync_after_meeting(app: &AppHandle, meeting_id: &str) {
+    let config = crate::settings::get_settings(app).asana;
+    if !config.auto_sync || config.project_gid.trim().is_empty() || !has_token() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => sync_digest(&db, &config, &digest).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(r) => log::info!(
+                "ASANA created {} tasks for meeting {} ({} already synced)",
+                r.created,
+                meeting_id,
+                r.already_synced
+            ),
+            Err(e) => log::warn!("ASANA sync of meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summarization::agent::Priority;
+
+    #[test]
+    fn test_task_body() {
+        let config = AsanaConfig {
+            project_gid: "1200".to_string(),
+            owners: [("alice".to_string(), "alice@example.com".to_string())]
+                .into_iter()
+                .collect(),
+            due_in_days: Some(7),
+            ..Default::default()
+        };
+        let digest = MeetingDigest {
+            meeting_id: "m-1".to_string(),
+            title: "Planning".to_string(),
+            // 2026-03-04T10:00:00Z
+            start_time: 1_772_618_400_000,
+            ..Default::default()
+        };
+        let mut item = Feature {
+            id: "f1".to_string(),
+            title: "We need CSV export.".to_string(),
+            description: "We need CSV export.".to_string(),
+            priority: Priority::High,
+            technical_notes: None,
+            mentioned_by: "Alice".to_string(),
+            timestamp: 75.0,
+        };
+        let body = task_body(&config, &digest, &item);
+        assert_eq!(body["data"]["name"], "We need CSV export.");
+        assert_eq!(body["data"]["projects"][0], "1200");
+        assert_eq!(body["data"]["assignee"], "alice@example.com");
+        assert_eq!(body["data"]["due_on"], "2026-03-11");
+        let notes = body["data"]["notes"].as_str().unwrap();
+        assert!(notes.contains("Raised by Alice at 01:15 in \"Planning\""));
+        assert!(notes.contains("meetingcoder://open-meeting/m-1"));
+
+        item.mentioned_by = "Speaker 2".to_string();
+        assert!(task_body(&config, &digest, &item)["data"]
+            .get("assignee")
+            .is_none());
+    }
+}
@@ -0,0 +1,764 @@
+//! Upcoming meetings from a calendar feed: Google Calendar's secret iCal address or any
+//! other ICS URL. With auto-start on, a meeting named after the event, with its attendees
+//! as participants, starts when an event with a video-call link begins, and the event is
+//! remembered for `calendar_writeback`.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc,
+    Weekday,
+};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::events::{self, AppEvent};
+use crate::integrations::calendar_writeback;
+use crate::managers::meeting::{default_meeting_name, MeetingManager};
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::{self, CalendarConfig};
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.calendar";
+const KEYCHAIN_ACCOUNT: &str = "feed-url";
+const FALLBACK_FILE: &str = ".calendar-feed";
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+/// A downloaded feed is reused for this long
+const FEED_TTL: Duration = Duration::from_secs(5 * 60);
+/// Events that began up to this long ago still auto-start, covering poll gaps and late
+/// launches
+const START_GRACE_MS: i64 = 5 * 60 * 1000;
+/// Hosts whose links mark an event as a call
+const VIDEO_HOSTS: &[&str] = &[
+    "zoom.us",
+    "meet.google.com",
+    "teams.microsoft.com",
+    "teams.live.com",
+    "webex.com",
+    "whereby.com",
+    "chime.aws",
+    "meet.jit.si",
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub title: String,
+    /// Unix milliseconds
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub all_day: bool,
+    pub location: Option<String>,
+    /// Zoom, Meet, Teams… link found in the event
+    pub video_link: Option<String>,
+    /// Attendee names, or email addresses for attendees without one
+    pub attendees: Vec<String>,
+}
+
+/// The linked feed as last downloaded
+static FEED: Lazy<Mutex<Option<(Instant, String)>>> = Lazy::new(|| Mutex::new(None));
+/// Occurrences already auto-started, as `uid@starts_at`
+static STARTED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Accept `webcal://` links as calendar apps hand them out; only HTTP(S) is fetched
+pub fn normalize_feed_url(url: &str) -> Result<String, String> {
+    let url = url.trim();
+    let url = match url.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{}", rest),
+        None => url.to_string(),
+    };
+    match reqwest::Url::parse(&url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(url),
+        _ => Err("Calendar feed must be an https:// or webcal:// URL".to_string()),
+    }
+}
+
+/// The feed URL grants read access to the calendar, so it's kept with the other secrets
+fn store_feed_url(url: &str) -> Result<()> {
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, url)
+}
+
+fn load_feed_url() -> Result<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map(|u| u.trim().to_string())
+        .map_err(|_| anyhow!("No calendar is linked"))
+}
+
+pub fn is_linked() -> bool {
+    load_feed_url().is_ok()
+}
+
+/// Check that `url` serves a calendar, then remember it
+pub async fn link(url: &str) -> Result<()> {
+    let body = fetch_feed(url).await?;
+    store_feed_url(url)?;
+    *FEED.lock().unwrap() = Some((Instant::now(), body));
+    Ok(())
+}
+
+/// Forget the feed URL and the downloaded feed
+pub fn unlink() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    *FEED.lock().unwrap() = None;
+    Ok(())
+}
+
+/// Clamp the lookahead, for the settings commands
+pub fn normalize_config(config: &mut CalendarConfig) -> Result<(), String> {
+    config.lookahead_hours = config.lookahead_hours.clamp(1, 24 * 7);
+    Ok(())
+}
+
+async fn fetch_feed(url: &str) -> Result<String> {
+    let response = network::client(Service::Calendar, url)?
+        .get(url)
+        .send()
+        .await
+        .context("Calendar feed request failed")?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Calendar feed returned {}", response.status()));
+    }
+    let body = response.text().await?;
+    if !body.contains("BEGIN:VCALENDAR") {
+        return Err(anyhow!("The URL did not return an iCalendar feed"));
+    }
+    Ok(body)
+}
+
+async fn cached_feed() -> Result<String> {
+    let cached = FEED
+        .lock()
+        .unwrap()
+        .as_ref()
+        .filter(|(fetched, _)| fetched.elapsed() < FEED_TTL)
+        .map(|(_, body)| body.clone());
+    if let Some(body) = cached {
+        return Ok(body);
+    }
+    let body = fetch_feed(&load_feed_url()?).await?;
+    *FEED.lock().unwrap() = Some((Instant::now(), body.clone()));
+    Ok(body)
+}
+
+/// The linked calendar's events in progress or starting within the lookahead
+pub async fn upcoming_events(config: &CalendarConfig) -> Result<Vec<CalendarEvent>> {
+    let ics = cached_feed().await?;
+    let now = Utc::now();
+    let until = now + ChronoDuration::hours(config.lookahead_hours as i64);
+    Ok(parse_events(&ics, now, until))
+}
+
+/// Poll the linked calendar and start a meeting when an event with a video-call link begins
+pub fn spawn_auto_start(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let config = settings::get_settings(&app).calendar;
+            if !config.auto_start || !is_linked() {
+                continue;
+            }
+            if let Err(e) = auto_start_due(&app, &config).await {
+                log::debug!("CALENDAR poll failed: {}", e);
+            }
+        }
+    });
+}
+
+fn occurrence_key(event: &CalendarEvent) -> String {
+    format!("{}@{}", event.uid, event.starts_at)
+}
+
+/// The event to start at `now`: a call that began within the grace period and hasn't
+/// been started already
+fn due_event<'a>(
+    events: &'a [CalendarEvent],
+    now: i64,
+    started: &HashSet<String>,
+) -> Option<&'a CalendarEvent> {
+    events.iter().find(|e| {
+        !e.all_day
+            && e.video_link.is_some()
+            && e.starts_at <= now
+            && now - e.starts_at <= START_GRACE_MS
+            && e.ends_at > now
+            && !started.contains(&occurrence_key(e))
+    })
+}
+
+async fn auto_start_due(app: &AppHandle, config: &CalendarConfig) -> Result<()> {
+    let upcoming = upcoming_events(config).await?;
+    let now = Utc::now().timestamp_millis();
+    let due = {
+        let started = STARTED.lock().unwrap();
+        due_event(&upcoming, now, &started).cloned()
+    };
+    let Some(event) = due else {
+        return Ok(());
+    };
+    let mm = app.state::<Arc<MeetingManager>>().inner().clone();
+    // Retried each poll until the grace period ends, in case the other meeting ends first
+    if let Some(current) = mm.current_meeting().await {
+        log::debug!(
+            "CALENDAR '{}' began during meeting '{}'",
+            event.title,
+            current.name
+        );
+        return Ok(());
+    }
+    STARTED.lock().unwrap().insert(occurrence_key(&event));
+    let name = if event.title.is_empty() {
+        default_meeting_name()
+    } else {
+        event.title.clone()
+    };
+    let meeting_id = mm.start_meeting(name).await?;
+    mm.add_participants(&meeting_id, &event.attendees).await?;
+    // Stored now so the summary can be written back to this occurrence when it ends
+    if let Some(db) = app.try_state::<Arc<MeetingDb>>() {
+        let recorded = mm.get_meeting(&meeting_id).await.and_then(|meeting| {
+            db.ensure_meeting(&meeting)?;
+            calendar_writeback::record_event(
+                &db,
+                &meeting_id,
+                &event.uid,
+                event.starts_at,
+                event.video_link.as_deref(),
+            )
+        });
+        if let Err(e) = recorded {
+            log::warn!(
+                "CALENDAR could not record the event of meeting {}: {}",
+                meeting_id,
+                e
+            );
+        }
+    }
+    log::info!(
+        "CALENDAR started meeting {} for '{}'",
+        meeting_id,
+        event.title
+    );
+    events::emit(
+        app,
+        AppEvent::meeting_state(&meeting_id, "recording", "calendar"),
+    );
+    // Always surface the app so a calendar event can't start recording unnoticed
+    crate::show_main_window(app);
+    let _ = app.emit("navigate-to-section", "meetings");
+    Ok(())
+}
+
+/// The first video-call link in an event's text
+pub fn video_link(text: &str) -> Option<String> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '(' | ')'))
+        .filter_map(|word| word.find("https://").map(|i| &word[i..]))
+        .map(|url| url.trim_end_matches(&['.', ',', ';'][..]))
+        .find(|url| {
+            reqwest::Url::parse(url)
+                .ok()
+                .and_then(|u| u.host_str().map(is_video_host))
+                .unwrap_or(false)
+        })
+        .map(str::to_string)
+}
+
+fn is_video_host(host: &str) -> bool {
+    VIDEO_HOSTS
+        .iter()
+        .any(|v| host == *v || host.ends_with(&format!(".{}", v)))
+}
+
+/// A date or date-time value as written in the feed
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stamp {
+    at: NaiveDateTime,
+    utc: bool,
+    date_only: bool,
+}
+
+impl Stamp {
+    /// Times with a TZID other than UTC are read in the computer's zone, which is the
+    /// calendar's zone for nearly everyone; not worth bundling a time zone database for
+    fn to_utc(self) -> Option<DateTime<Utc>> {
+        if self.utc {
+            Some(Utc.from_utc_datetime(&self.at))
+        } else {
+            Local
+                .from_local_datetime(&self.at)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc))
+        }
+    }
+}
+
+struct Property<'a> {
+    name: String,
+    params: Vec<(String, String)>,
+    value: &'a str,
+}
+
+fn param<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+fn param_is(params: &[(String, String)], key: &str, value: &str) -> bool {
+    param(params, key).is_some_and(|v| v.eq_ignore_ascii_case(value))
+}
+
+/// Undo line folding: continuation lines start with a space or tab
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        if let Some(rest) = line.strip_prefix(&[' ', '\t'][..]) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        lines.push(line.to_string());
+    }
+    lines
+}
+
+fn split_unquoted(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == sep && !in_quotes {
+            parts.push(&s[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// `NAME;PARAM=value;...:value`, where quoted parameter values may contain `:` and `;`
+fn parse_property(line: &str) -> Option<Property<'_>> {
+    let mut in_quotes = false;
+    let (colon, _) = line.char_indices().find(|&(_, c)| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        c == ':' && !in_quotes
+    })?;
+    let head = split_unquoted(&line[..colon], ';');
+    Some(Property {
+        name: head[0].trim().to_ascii_uppercase(),
+        params: head[1..]
+            .iter()
+            .filter_map(|p| p.split_once('='))
+            .map(|(k, v)| {
+                (
+                    k.trim().to_ascii_uppercase(),
+                    v.trim_matches('"').to_string(),
+                )
+            })
+            .collect(),
+        value: &line[colon + 1..],
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+fn parse_stamp(value: &str, params: &[(String, String)]) -> Option<Stamp> {
+    let value = value.trim();
+    if param_is(params, "VALUE", "DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(Stamp {
+            at: date.and_hms_opt(0, 0, 0)?,
+            utc: false,
+            date_only: true,
+        });
+    }
+    let (digits, zulu) = match value.strip_suffix(&['Z', 'z'][..]) {
+        Some(v) => (v, true),
+        None => (value, false),
+    };
+    let at = NaiveDateTime::parse_from_str(digits, "%Y%m%dT%H%M%S").ok()?;
+    let utc = zulu || matches!(param(params, "TZID"), Some("UTC" | "Etc/UTC" | "GMT"));
+    Some(Stamp {
+        at,
+        utc,
+        date_only: false,
+    })
+}
+
+/// `DURATION` values like `PT1H30M` or `P1D`
+fn parse_duration(value: &str) -> Option<ChronoDuration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.trim_start_matches('+')),
+    };
+    let mut total = ChronoDuration::zero();
+    let mut number = String::new();
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total = total
+                    + match c {
+                        'W' => ChronoDuration::weeks(n),
+                        'D' => ChronoDuration::days(n),
+                        'H' => ChronoDuration::hours(n),
+                        'M' => ChronoDuration::minutes(n),
+                        _ => ChronoDuration::seconds(n),
+                    };
+            }
+            _ => return None,
+        }
+    }
+    Some(if negative { -total } else { total })
+}
+
+fn attendee_name(prop: &Property) -> Option<String> {
+    let params = &prop.params;
+    if param_is(params, "PARTSTAT", "DECLINED")
+        || param_is(params, "ROLE", "NON-PARTICIPANT")
+        || param_is(params, "CUTYPE", "ROOM")
+        || param_is(params, "CUTYPE", "RESOURCE")
+    {
+        return None;
+    }
+    if let Some(name) = param(params, "CN").map(str::trim).filter(|n| !n.is_empty()) {
+        return Some(name.to_string());
+    }
+    let value = prop.value.trim();
+    let email = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map_or(value, |_| &value[7..]);
+    (!email.is_empty()).then(|| email.to_string())
+}
+
+#[derive(Default)]
+struct RawEvent {
+    uid: String,
+    summary: String,
+    location: Option<String>,
+    /// Link-bearing text: conference data, URL, description
+    link_text: Vec<String>,
+    attendees: Vec<String>,
+    start: Option<Stamp>,
+    end: Option<Stamp>,
+    duration: Option<ChronoDuration>,
+    rrule: Option<String>,
+    exdates: Vec<DateTime<Utc>>,
+    /// Set on an edited occurrence of a recurring event: the start it replaces
+    recurrence_id: Option<DateTime<Utc>>,
+    cancelled: bool,
+}
+
+fn parse_raw(ics: &str) -> Vec<RawEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<RawEvent> = None;
+    // Components inside the event (alarms), whose properties aren't the event's
+    let mut nested = 0usize;
+    for line in unfold(ics) {
+        let Some(prop) = parse_property(&line) else {
+            continue;
+        };
+        let value = prop.value.trim();
+        match prop.name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(RawEvent::default());
+                nested = 0;
+                continue;
+            }
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                events.extend(current.take());
+                continue;
+            }
+            "BEGIN" => nested += 1,
+            "END" => nested = nested.saturating_sub(1),
+            _ => {}
+        }
+        let Some(event) = current.as_mut().filter(|_| nested == 0) else {
+            continue;
+        };
+        match prop.name.as_str() {
+            "UID" => event.uid = value.to_string(),
+            "SUMMARY" => event.summary = unescape(prop.value),
+            "LOCATION" => event.location = Some(unescape(prop.value)).filter(|l| !l.is_empty()),
+            "X-GOOGLE-CONFERENCE" | "CONFERENCE" | "URL" => {
+                event.link_text.insert(0, value.to_string())
+            }
+            "DESCRIPTION" => event.link_text.push(unescape(prop.value)),
+            "ATTENDEE" => event.attendees.extend(attendee_name(&prop)),
+            "DTSTART" => event.start = parse_stamp(value, &prop.params),
+            "DTEND" => event.end = parse_stamp(value, &prop.params),
+            "DURATION" => event.duration = parse_duration(value),
+            "RRULE" => event.rrule = Some(value.to_string()),
+            "EXDATE" => event.exdates.extend(
+                value
+                    .split(',')
+                    .filter_map(|v| parse_stamp(v, &prop.params))
+                    .filter_map(Stamp::to_utc),
+            ),
+            "RECURRENCE-ID" => {
+                event.recurrence_id = parse_stamp(value, &prop.params).and_then(Stamp::to_utc)
+            }
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+    events
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    // Weekly rules use bare codes; a numeric prefix (`1MO`) only appears in monthly ones
+    match code.get(code.len().saturating_sub(2)..)? {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Starts of a recurring event's occurrences from `from` up to `to`. Daily and weekly
+/// rules (INTERVAL, COUNT, UNTIL, and BYDAY for weekly) are expanded; others keep only
+/// the first occurrence.
+fn occurrences(start: Stamp, rrule: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Stamp> {
+    let rule: HashMap<String, &str> = rrule
+        .split(';')
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.trim().to_ascii_uppercase(), v.trim()))
+        .collect();
+    let interval = rule
+        .get("INTERVAL")
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(1);
+    let count = rule.get("COUNT").and_then(|v| v.parse::<usize>().ok());
+    let until = rule
+        .get("UNTIL")
+        .and_then(|v| parse_stamp(v, &[]))
+        .and_then(Stamp::to_utc)
+        .map_or(to, |u| u.min(to));
+
+    // Candidate starts in order; COUNT counts from the first occurrence, not from `from`
+    let candidates: Box<dyn Iterator<Item = NaiveDateTime>> =
+        match rule.get("FREQ").map(|f| f.to_ascii_uppercase()).as_deref() {
+            Some("DAILY") => {
+                Box::new((0..).map(move |n| start.at + ChronoDuration::days(n * interval)))
+            }
+            Some("WEEKLY") => {
+                let mut days: Vec<Weekday> = rule
+                    .get("BYDAY")
+                    .map(|v| v.split(',').filter_map(weekday).collect())
+                    .unwrap_or_default();
+                if days.is_empty() {
+                    days.push(start.at.weekday());
+                }
+                days.sort_by_key(|d| d.num_days_from_monday());
+                days.dedup();
+                // Weeks start on Monday; WKST is ignored
+                let week_start = start.at
+                    - ChronoDuration::days(start.at.weekday().num_days_from_monday() as i64);
+                Box::new(
+                    (0..)
+                        .flat_map(move |week| {
+                            let base = week_start + ChronoDuration::weeks(week * interval);
+                            days.clone().into_iter().map(move |d| {
+                                base + ChronoDuration::days(d.num_days_from_monday() as i64)
+                            })
+                        })
+                        .filter(move |at| *at >= start.at),
+                )
+            }
+            _ => Box::new(std::iter::once(start.at)),
+        };
+
+    let mut result = Vec::new();
+    for (n, at) in candidates.enumerate() {
+        if count.is_some_and(|c| n >= c) {
+            break;
+        }
+        let stamp = Stamp { at, ..start };
+        let Some(utc) = stamp.to_utc() else {
+            continue;
+        };
+        if utc > until {
+            break;
+        }
+        if utc >= from {
+            result.push(stamp);
+        }
+    }
+    result
+}
+
+/// Events in an ICS document overlapping `from..to`, soonest first; recurring events are
+/// expanded into their occurrences
+pub fn parse_events(ics: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<CalendarEvent> {
+    let raw = parse_raw(ics);
+    // Occurrences of recurring events that were edited or cancelled individually
+    let overridden: HashSet<(&str, DateTime<Utc>)> = raw
+        .iter()
+        .filter_map(|e| Some((e.uid.as_str(), e.recurrence_id?)))
+        .collect();
+    let mut events = Vec::new();
+    for event in raw.iter().filter(|e| !e.cancelled) {
+        let Some(start) = event.start else {
+            continue;
+        };
+        let length = match (event.end.and_then(Stamp::to_utc), start.to_utc()) {
+            (Some(end), Some(begin)) => end - begin,
+            _ => event.duration.unwrap_or(if start.date_only {
+                ChronoDuration::days(1)
+            } else {
+                ChronoDuration::zero()
+            }),
+        };
+        let starts = match (&event.rrule, event.recurrence_id) {
+            (Some(rule), None) => occurrences(start, rule, from - length, to),
+            _ => vec![start],
+        };
+        for occurrence in starts {
+            let Some(starts_at) = occurrence.to_utc() else {
+                continue;
+            };
+            let ends_at = starts_at + length;
+            if ends_at <= from || starts_at >= to {
+                continue;
+            }
+            if event.recurrence_id.is_none()
+                && (event.exdates.contains(&starts_at)
+                    || overridden.contains(&(event.uid.as_str(), starts_at)))
+            {
+                continue;
+            }
+            events.push(CalendarEvent {
+                uid: event.uid.clone(),
+                title: event.summary.clone(),
+                starts_at: starts_at.timestamp_millis(),
+                ends_at: ends_at.timestamp_millis(),
+                all_day: start.date_only,
+                location: event.location.clone(),
+                video_link: event
+                    .link_text
+                    .iter()
+                    .chain(event.location.iter())
+                    .find_map(|t| video_link(t)),
+                attendees: event.attendees.clone(),
+            });
+        }
+    }
+    events.sort_by_key(|e| e.starts_at);
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+SUMMARY:Daily standup\r\n\
+DTSTART:20261012T090000Z\r\n\
+DTEND:20261012T091500Z\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r\n\
+EXDATE:20261014T090000Z\r\n\
+DESCRIPTION:Join: https://acme.zoom.us/j/123\r\n \
+456?pwd=x.\r\n\
+ATTENDEE;CN=\"Doe, Jane\";PARTSTAT=ACCEPTED:mailto:jane@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:mailto:bob@example.com\r\n\
+ATTENDEE;CN=Room 4;CUTYPE=ROOM:mailto:room4@example.com\r\n\
+BEGIN:VALARM\r\n\
+DESCRIPTION:Reminder\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+RECURRENCE-ID:20261016T090000Z\r\n\
+STATUS:CANCELLED\r\n\
+DTSTART:20261016T090000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:offsite\r\n\
+SUMMARY:Offsite\r\n\
+DTSTART;VALUE=DATE:20261015\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn at(s: &str) -> DateTime<Utc> {
+        parse_stamp(s, &[]).and_then(Stamp::to_utc).unwrap()
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let events = parse_events(FEED, at("20261012T000000Z"), at("20261020T000000Z"));
+        let standups: Vec<&CalendarEvent> = events.iter().filter(|e| e.uid == "standup").collect();
+        // Wednesday is excluded and Friday cancelled, leaving Monday and next Monday
+        let starts: Vec<i64> = standups.iter().map(|e| e.starts_at).collect();
+        assert_eq!(
+            starts,
+            vec![
+                at("20261012T090000Z").timestamp_millis(),
+                at("20261019T090000Z").timestamp_millis()
+            ]
+        );
+        let first = standups[0];
+        assert_eq!(first.ends_at - first.starts_at, 15 * 60 * 1000);
+        assert_eq!(
+            first.video_link.as_deref(),
+            Some("https://acme.zoom.us/j/123456?pwd=x")
+        );
+        assert_eq!(first.attendees, vec!["Doe, Jane", "bob@example.com"]);
+        assert!(events.iter().any(|e| e.uid == "offsite" && e.all_day));
+
+        let now = first.starts_at + 60_000;
+        assert_eq!(due_event(&events, now, &HashSet::new()), Some(first));
+        let started = HashSet::from([occurrence_key(first)]);
+        assert_eq!(due_event(&events, now, &started), None);
+        assert_eq!(
+            due_event(
+                &events,
+                first.starts_at + START_GRACE_MS + 1,
+                &HashSet::new()
+            ),
+            None
+        );
+
+        assert_eq!(video_link("Room 4 (https://example.com/room)"), None);
+        assert_eq!(parse_duration("PT1H30M"), Some(ChronoDuration::minutes(90)));
+        assert_eq!(
+            normalize_feed_url("webcal://calendar.google.com/basic.ics").as_deref(),
+            Ok("https://calendar.google.com/basic.ics")
+        );
+    }
+}
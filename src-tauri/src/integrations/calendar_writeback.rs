@@ -0,0 +1,413 @@
+//! Meeting summaries written back to the calendar event a meeting was started for. The
+//! event is remembered by its iCalendar UID and occurrence start (`record_event`), and
+//! found again through the Google Calendar or Microsoft Graph API with an OAuth client
+//! and refresh token the user sets up. The summary is appended below a marker line in
+//! the event description; writing again replaces it instead of stacking copies.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.calendar";
+const KEYCHAIN_ACCOUNT: &str = "write-access";
+const FALLBACK_FILE: &str = ".calendar-write-access";
+/// `service` of the row tying a meeting to its event: item id is the occurrence start in
+/// Unix milliseconds, external id the event's iCalendar UID
+const LINK_SERVICE: &str = "calendar_event";
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const GOOGLE_EVENTS_URL: &str = "https://www.googleapis.com/calendar/v3/calendars/primary/events";
+const MICROSOFT_TOKEN_URL: &str = "https://login.microsoftonline.com/common/oauth2/v2.0/token";
+const GRAPH_BASE: &str = "https://graph.microsoft.com/v1.0/me";
+const MICROSOFT_SCOPE: &str = "https://graph.microsoft.com/Calendars.ReadWrite offline_access";
+/// Everything from this line on is MeetingCoder's and is replaced on the next write
+pub const MARKER: &str = "── MeetingCoder summary ──";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarProvider {
+    Google,
+    /// Outlook / Microsoft 365, through Microsoft Graph
+    Outlook,
+}
+
+/// An OAuth client the user registered and a refresh token issued to it with calendar
+/// write scope (`calendar.events` for Google, `Calendars.ReadWrite` for Microsoft)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CalendarWriteCredentials {
+    pub provider: CalendarProvider,
+    pub client_id: String,
+    /// Google requires one; public Microsoft clients have none
+    #[serde(default)]
+    pub client_secret: String,
+    pub refresh_token: String,
+}
+
+/// The event a summary was written to
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarWriteBack {
+    pub provider: CalendarProvider,
+    pub event_id: String,
+}
+
+fn save_credentials(credentials: &CalendarWriteCredentials) -> Result<()> {
+    let raw = serde_json::to_string(credentials)?;
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, &raw)
+}
+
+fn load_credentials() -> Result<CalendarWriteCredentials> {
+    let raw = secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map_err(|_| anyhow!("Calendar write access is not set up"))?;
+    serde_json::from_str(&raw).context("Stored calendar credentials are unreadable")
+}
+
+pub fn has_credentials() -> bool {
+    load_credentials().is_ok()
+}
+
+/// Check that the refresh token works, then store the credentials
+pub async fn store_credentials(credentials: &CalendarWriteCredentials) -> Result<()> {
+    if credentials.client_id.trim().is_empty() || credentials.refresh_token.trim().is_empty() {
+        return Err(anyhow!("A client id and refresh token are required"));
+    }
+    if credentials.provider == CalendarProvider::Google
+        && credentials.client_secret.trim().is_empty()
+    {
+        return Err(anyhow!("Google requires the OAuth client secret"));
+    }
+    let mut credentials = credentials.clone();
+    access_token(&mut credentials).await?;
+    save_credentials(&credentials)
+}
+
+/// Remove the stored OAuth client and refresh token
+pub fn clear_credentials() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Remember which event occurrence (UID and start in Unix milliseconds) a meeting is for
+pub fn record_event(
+    db: &MeetingDb,
+    meeting_id: &str,
+    uid: &str,
+    starts_at: i64,
+    video_link: Option<&str>,
+) -> Result<()> {
+    db.save_external_link(
+        meeting_id,
+        LINK_SERVICE,
+        &starts_at.to_string(),
+        uid,
+        video_link,
+    )
+}
+
+/// The event UID and occurrence start a meeting was started for, if any
+fn meeting_event(db: &MeetingDb, meeting_id: &str) -> Result<Option<(String, i64)>> {
+    Ok(db
+        .list_external_links(meeting_id, LINK_SERVICE)?
+        .into_iter()
+        .next()
+        .and_then(|link| Some((link.external_id, link.item_id.parse().ok()?))))
+}
+
+async fn check_response(response: reqwest::Response, what: &str) -> Result<Value> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("{} returned {}: {}", what, status, body.trim()));
+    }
+    Ok(response.json().await?)
+}
+
+/// Exchange the refresh token for an access token. Microsoft rotates refresh tokens, so a
+/// new one in the response replaces the stored one.
+async fn access_token(credentials: &mut CalendarWriteCredentials) -> Result<String> {
+    let (url, what) = match credentials.provider {
+        CalendarProvider::Google => (GOOGLE_TOKEN_URL, "Google"),
+        CalendarProvider::Outlook => (MICROSOFT_TOKEN_URL, "Microsoft"),
+    };
+    let mut form = vec![
+        ("grant_type", "refresh_token"),
+        ("client_id", credentials.client_id.trim()),
+        ("refresh_token", credentials.refresh_token.trim()),
+    ];
+    if !credentials.client_secret.trim().is_empty() {
+        form.push(("client_secret", credentials.client_secret.trim()));
+    }
+    if credentials.provider == CalendarProvider::Outlook {
+        form.push(("scope", MICROSOFT_SCOPE));
+    }
+    let response = network::client(Service::Calendar, url)?
+        .post(url)
+        .form(&form)
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {}", what))?;
+    let value = check_response(response, what).await?;
+    if let Some(rotated) = value["refresh_token"].as_str() {
+        credentials.refresh_token = rotated.to_string();
+    }
+    value["access_token"]
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| anyhow!("{} returned no access token", what))
+}
+
+fn rfc3339(ms: i64) -> String {
+    DateTime::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Graph reports times like `2026-10-12T09:00:00.0000000` in the zone asked for (UTC)
+fn graph_time_ms(value: &Value) -> Option<i64> {
+    let text = value["dateTime"].as_str()?;
+    let at = NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+    Some(at.and_utc().timestamp_millis())
+}
+
+/// The event description with `summary` in place of any earlier one
+pub fn with_summary(description: &str, summary: &str) -> String {
+    let kept = description
+        .find(MARKER)
+        .map_or(description, |i| &description[..i])
+        .trim_end();
+    if kept.is_empty() {
+        format!("{}\n{}", MARKER, summary.trim_end())
+    } else {
+        format!("{}\n\n{}\n{}", kept, MARKER, summary.trim_end())
+    }
+}
+
+/// The summary as it appears in the event: the lists, the pull request, and the PRD
+pub fn summary_text(digest: &MeetingDigest, prd_version: Option<u32>) -> String {
+    let mut text = String::new();
+    let _ = writeln!(text, "{} min recorded\n", digest.duration_minutes);
+    for (title, items) in digest.sections() {
+        let _ = writeln!(text, "{}", title);
+        for item in items {
+            let _ = writeln!(text, "- {}", item);
+        }
+        let _ = writeln!(text);
+    }
+    if digest.sections().is_empty() {
+        let _ = writeln!(
+            text,
+            "No action items, decisions, or questions were captured.\n"
+        );
+    }
+    if let Some(url) = &digest.pr_url {
+        let _ = writeln!(text, "Pull request: {}", url);
+    }
+    if let Some(version) = prd_version {
+        let _ = writeln!(text, "PRD: v{}", version);
+    }
+    let _ = write!(
+        text,
+        "Open the meeting: meetingcoder://open-meeting/{}",
+        digest.meeting_id
+    );
+    text
+}
+
+async fn write_google(token: &str, uid: &str, starts_at: i64, summary: &str) -> Result<String> {
+    // Instances are listed so a recurring event gets the summary on this occurrence only
+    let response = network::client(Service::Calendar, GOOGLE_EVENTS_URL)?
+        .get(GOOGLE_EVENTS_URL)
+        .bearer_auth(token)
+        .query(&[
+            ("iCalUID", uid),
+            ("singleEvents", "true"),
+            ("timeMin", &rfc3339(starts_at)),
+            ("timeMax", &rfc3339(starts_at + 60_000)),
+        ])
+        .send()
+        .await
+        .context("Failed to reach Google Calendar")?;
+    let listed = check_response(response, "Google Calendar").await?;
+    let event = listed["items"]
+        .as_array()
+        .and_then(|items| items.first())
+        .ok_or_else(|| anyhow!("The event is not in the primary Google calendar"))?;
+    let id = event["id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Google Calendar returned an event without an id"))?;
+    let description = with_summary(event["description"].as_str().unwrap_or(""), summary);
+    let url = format!("{}/{}", GOOGLE_EVENTS_URL, id);
+    let response = network::client(Service::Calendar, &url)?
+        .patch(&url)
+        .bearer_auth(token)
+        .json(&json!({ "description": description }))
+        .send()
+        .await
+        .context("Failed to reach Google Calendar")?;
+    check_response(response, "Google Calendar").await?;
+    Ok(id.to_string())
+}
+
+async fn write_outlook(
+    token: &str,
+    uid: &str,
+    title: &str,
+    starts_at: i64,
+    summary: &str,
+) -> Result<String> {
+    let url = format!("{}/calendarView", GRAPH_BASE);
+    let response = network::client(Service::Calendar, &url)?
+        .get(&url)
+        .bearer_auth(token)
+        // Plain text, so the marker can be found and the body rewritten without HTML
+        .header(
+            "Prefer",
+            "outlook.body-content-type=\"text\", outlook.timezone=\"UTC\"",
+        )
+        .query(&[
+            ("startDateTime", rfc3339(starts_at)),
+            ("endDateTime", rfc3339(starts_at + 60_000)),
+            ("$select", "id,subject,iCalUId,start,body".to_string()),
+        ])
+        .send()
+        .await
+        .context("Failed to reach Microsoft Graph")?;
+    let listed = check_response(response, "Microsoft Graph").await?;
+    let starting: Vec<&Value> = listed["value"]
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .filter(|e| {
+                    graph_time_ms(&e["start"]).is_some_and(|s| (s - starts_at).abs() < 60_000)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // Published feeds don't always carry Graph's iCalUId, so fall back to a unique title
+    let event = starting
+        .iter()
+        .find(|e| e["iCalUId"].as_str() == Some(uid))
+        .or_else(|| {
+            let titled: Vec<&&Value> = starting
+                .iter()
+                .filter(|e| e["subject"].as_str() == Some(title))
+                .collect();
+            (titled.len() == 1).then(|| titled[0])
+        })
+        .ok_or_else(|| anyhow!("The event is not in the default Outlook calendar"))?;
+    let id = event["id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Microsoft Graph returned an event without an id"))?;
+    let body = with_summary(event["body"]["content"].as_str().unwrap_or(""), summary);
+    let url = format!("{}/events/{}", GRAPH_BASE, id);
+    let response = network::client(Service::Calendar, &url)?
+        .patch(&url)
+        .bearer_auth(token)
+        .json(&json!({ "body": { "contentType": "text", "content": body } }))
+        .send()
+        .await
+        .context("Failed to reach Microsoft Graph")?;
+    check_response(response, "Microsoft Graph").await?;
+    Ok(id.to_string())
+}
+
+/// Write a saved meeting's summary into the event it was started for
+pub async fn write_back(db: &MeetingDb, meeting_id: &str) -> Result<CalendarWriteBack> {
+    let (uid, starts_at) = meeting_event(db, meeting_id)?.ok_or_else(|| {
+        anyhow!(
+            "Meeting {} was not started from a calendar event",
+            meeting_id
+        )
+    })?;
+    let mut credentials = load_credentials()?;
+    let token = access_token(&mut credentials).await?;
+    save_credentials(&credentials)?;
+    let digest = MeetingDigest::load(db, meeting_id)?;
+    let prd_version = crate::document_generation::prd_storage::latest_version(meeting_id);
+    let summary = summary_text(&digest, prd_version);
+    let event_id = match credentials.provider {
+        CalendarProvider::Google => write_google(&token, &uid, starts_at, &summary).await?,
+        CalendarProvider::Outlook => {
+            write_outlook(&token, &uid, &digest.title, starts_at, &summary).await?
+        }
+    };
+    Ok(CalendarWriteBack {
+        provider: credentials.provider,
+        event_id,
+    })
+}
+
+/// Write the summary of a meeting that just ended to its event, if write access is set up
+pub fn spawn_write_back_after_meeting(app: &AppHandle, meeting_id: &str) {
+    if !has_credentials() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    match meeting_event(&db, meeting_id) {
+        Ok(Some(_)) => {}
+        _ => return,
+    }
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        match write_back(&db, &meeting_id).await {
+            Ok(r) => log::info!(
+                "CALENDAR wrote summary of meeting {} to event {}",
+                meeting_id,
+                r.event_id
+            ),
+            Err(e) => log::warn!(
+                "CALENDAR write-back for meeting {} failed: {}",
+                meeting_id,
+                e
+            ),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_summary() {
+        let first = with_summary("Agenda\nhttps://meet.google.com/abc\n", "Decisions\n- Ship");
+        assert_eq!(
+            first,
+            format!(
+                "Agenda\nhttps://meet.google.com/abc\n\n{}\nDecisions\n- Ship",
+                MARKER
+            )
+        );
+        // Writing again replaces the earlier summary
+        assert_eq!(
+            with_summary(&first, "Decisions\n- Wait"),
+            format!(
+                "Agenda\nhttps://meet.google.com/abc\n\n{}\nDecisions\n- Wait",
+                MARKER
+            )
+        );
+        assert_eq!(with_summary("", "x"), format!("{}\nx", MARKER));
+
+        let digest = MeetingDigest {
+            meeting_id: "m-1".to_string(),
+            duration_minutes: 30,
+            decisions: vec!["Ship Friday".to_string()],
+            pr_url: Some("https://github.com/o/r/pull/7".to_string()),
+            ..Default::default()
+        };
+        let text = summary_text(&digest, Some(2));
+        assert!(text.contains("Decisions\n- Ship Friday\n"));
+        assert!(text.contains("Pull request: https://github.com/o/r/pull/7\nPRD: v2\n"));
+        assert!(text.ends_with("meetingcoder://open-meeting/m-1"));
+    }
+}
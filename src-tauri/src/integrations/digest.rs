@@ -0,0 +1,109 @@
+//! What a finished meeting looks like when it's posted somewhere else: the same points as
+//! `summary.md`, plus the project's pull request. Each destination formats it its own way.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::storage::db::MeetingDb;
+use crate::summarization::agent::Feature;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MeetingDigest {
+    pub meeting_id: String,
+    pub title: String,
+    /// Unix milliseconds
+    pub start_time: i64,
+    pub duration_minutes: i64,
+    /// Speaker labels, after any renaming
+    pub participants: Vec<String>,
+    /// Features and follow-ups raised in the meeting
+    pub action_items: Vec<String>,
+    /// The action items with their stable ids, priority, and who raised them
+    pub action_item_details: Vec<Feature>,
+    pub decisions: Vec<String>,
+    pub open_questions: Vec<String>,
+    pub pr_url: Option<String>,
+}
+
+impl MeetingDigest {
+    /// Summarize a saved meeting from the database
+    pub fn load(db: &MeetingDb, meeting_id: &str) -> Result<Self> {
+        let meeting = db
+            .get_meeting(meeting_id)?
+            .ok_or_else(|| anyhow!("Meeting {} not found", meeting_id))?;
+        let segments = db.get_segments(meeting_id)?;
+
+        let mut digest = MeetingDigest {
+            meeting_id: meeting.id,
+            title: meeting.name,
+            start_time: meeting.start_time,
+            duration_minutes: meeting
+                .end_time
+                .map(|end| (end - meeting.start_time).max(0) / 60_000)
+                .unwrap_or(0),
+            participants: meeting.participants,
+            pr_url: meeting
+                .project_path
+                .as_deref()
+                .and_then(|p| crate::integrations::github::read_github_state(p).last_pr_url),
+            ..Default::default()
+        };
+        if !segments.is_empty() {
+            // Not `summarize_segments_with_context`: that skips features the live meeting
+            // already wrote to the project, which is all of them once the meeting has ended
+            let summary =
+                crate::summarization::agent::summarize_segments(&segments, 0, segments.len() - 1);
+            digest.action_items = if summary.new_features_structured.is_empty() {
+                summary.new_features
+            } else {
+                summary
+                    .new_features_structured
+                    .iter()
+                    .map(|f| f.title.clone())
+                    .collect()
+            };
+            digest.action_item_details = summary.new_features_structured;
+            digest.decisions = summary.technical_decisions;
+            digest.open_questions = summary.questions;
+        }
+        Ok(digest)
+    }
+
+    /// Date the meeting started, e.g. "2026-03-04"
+    pub fn date(&self) -> String {
+        chrono::DateTime::from_timestamp_millis(self.start_time)
+            .map(|t| t.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    }
+
+    /// Where an action item came from, for the description of a task or card made from it
+    pub fn item_context(&self, item: &Feature) -> String {
+        let total = item.timestamp.max(0.0) as u64;
+        let mut text = format!(
+            "Raised by {} at {:02}:{:02} in \"{}\" ({}).\n\
+             Open the meeting: meetingcoder://open-meeting/{}",
+            item.mentioned_by,
+            total / 60,
+            total % 60,
+            self.title,
+            self.date(),
+            self.meeting_id
+        );
+        if let Some(url) = &self.pr_url {
+            text.push_str(&format!("\nPull request: {}", url));
+        }
+        text
+    }
+
+    /// Titled lists with at least one item, in display order
+    pub fn sections(&self) -> Vec<(&'static str, &[String])> {
+        [
+            ("Action items", self.action_items.as_slice()),
+            ("Decisions", self.decisions.as_slice()),
+            ("Open questions", self.open_questions.as_slice()),
+        ]
+        .into_iter()
+        .filter(|(_, items)| !items.is_empty())
+        .collect()
+    }
+}
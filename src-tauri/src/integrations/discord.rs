@@ -0,0 +1,235 @@
+//! Discord channel webhooks. Each project can post to its own channel; projects without
+//! one use the default webhook. Which events are posted comes from settings and can be
+//! overridden per repo in `.meetingcoder.toml`:
+//!
+//! ```toml
+//! [discord]
+//! meeting_ended = true
+//! prd_versions = false
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::document_generation::PRDVersion;
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.discord";
+/// Keychain account of the webhook used by projects without their own
+const DEFAULT_ACCOUNT: &str = "default";
+const WEBHOOK_PREFIXES: &[&str] = &[
+    "https://discord.com/api/webhooks/",
+    "https://discordapp.com/api/webhooks/",
+];
+/// Discord's brand blurple, the embed's accent colour
+const EMBED_COLOR: u32 = 0x5865F2;
+const MAX_FIELD_CHARS: usize = 1024;
+
+fn account(project_path: Option<&str>) -> &str {
+    project_path.unwrap_or(DEFAULT_ACCOUNT)
+}
+
+/// Fallback file for an account; project paths are hashed into a safe file name
+fn fallback_file(account: &str) -> String {
+    if account == DEFAULT_ACCOUNT {
+        return ".discord-webhook".to_string();
+    }
+    let digest = Sha256::digest(account.as_bytes());
+    let hex: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!(".discord-webhook-{}", hex)
+}
+
+/// Store the webhook for a project, or the default one when `project_path` is None
+pub fn store_webhook(project_path: Option<&str>, url: &str) -> Result<()> {
+    if !WEBHOOK_PREFIXES.iter().any(|p| url.starts_with(p)) {
+        return Err(anyhow!(
+            "Discord webhook URLs start with {}",
+            WEBHOOK_PREFIXES[0]
+        ));
+    }
+    let account = account(project_path);
+    secrets::store(KEYCHAIN_SERVICE, account, &fallback_file(account), url)
+}
+
+fn load_account(account: &str) -> Option<String> {
+    secrets::load(KEYCHAIN_SERVICE, account, &fallback_file(account)).ok()
+}
+
+/// The project's own webhook, else the default one
+fn webhook_for(project_path: Option<&str>) -> Option<String> {
+    project_path
+        .and_then(load_account)
+        .or_else(|| load_account(DEFAULT_ACCOUNT))
+}
+
+/// Whether a webhook is set for exactly this project (or the default, for None)
+pub fn has_webhook(project_path: Option<&str>) -> bool {
+    load_account(account(project_path)).is_some()
+}
+
+/// Remove a project's webhook, or the default one when `project_path` is None
+pub fn clear_webhook(project_path: Option<&str>) -> Result<()> {
+    let account = account(project_path);
+    secrets::delete(KEYCHAIN_SERVICE, account, &fallback_file(account));
+    Ok(())
+}
+
+fn truncate(text: String, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text;
+    }
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Embed with one field per list; the title links the pull request when there is one
+pub fn summary_embed(digest: &MeetingDigest) -> Value {
+    let fields: Vec<Value> = digest
+        .sections()
+        .into_iter()
+        .map(|(title, items)| {
+            let list: Vec<String> = items.iter().map(|i| format!("• {}", i)).collect();
+            json!({
+                "name": title,
+                "value": truncate(list.join("\n"), MAX_FIELD_CHARS),
+            })
+        })
+        .collect();
+    let mut description = format!("{} · {} min", digest.date(), digest.duration_minutes);
+    if let Some(url) = &digest.pr_url {
+        description.push_str(&format!("\n[View pull request]({})", url));
+    }
+    let mut embed = json!({
+        "title": truncate(format!("Meeting ended: {}", digest.title), 256),
+        "description": description,
+        "color": EMBED_COLOR,
+        "fields": fields,
+    });
+    if let Some(url) = &digest.pr_url {
+        embed["url"] = json!(url);
+    }
+    embed
+}
+
+pub fn prd_embed(meeting_name: &str, version: &PRDVersion) -> Value {
+    json!({
+        "title": truncate(format!("PRD v{} for {}", version.version, meeting_name), 256),
+        "description": format!(
+            "{} version, {} words, covering segments {}–{}",
+            version.version_type, version.word_count, version.segment_range.0, version.segment_range.1
+        ),
+        "color": EMBED_COLOR,
+        "timestamp": version.generated_at,
+    })
+}
+
+async fn post(url: &str, embed: Value) -> Result<()> {
+    let response = network::client(Service::Discord, url)?
+        .post(url)
+        .json(&json!({ "username": "MeetingCoder", "embeds": [embed] }))
+        .send()
+        .await
+        .context("Failed to reach Discord")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!(
+            "Discord webhook returned {}: {}",
+            status,
+            body.trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Post a meeting's summary to its project's channel
+pub async fn post_digest(digest: &MeetingDigest, project_path: Option<&str>) -> Result<()> {
+    let url = webhook_for(project_path).ok_or_else(|| anyhow!("No Discord webhook is set"))?;
+    post(&url, summary_embed(digest)).await
+}
+
+/// Event toggles for a project: app settings with the repo's overrides applied
+fn events_for(app: &AppHandle, project_path: Option<&str>) -> crate::settings::DiscordConfig {
+    match project_path {
+        Some(path) => crate::automation::repo_config::effective_settings(app, path).discord,
+        None => crate::settings::get_settings(app).discord,
+    }
+}
+
+/// Post the summary of a meeting that just ended, if the project has that turned on
+pub fn spawn_post_after_meeting(app: &AppHandle, meeting_id: &str, project_path: Option<&str>) {
+    if !events_for(app, project_path).meeting_ended || webhook_for(project_path).is_none() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    let project_path = project_path.map(String::from);
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => post_digest(&digest, project_path.as_deref()).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => log::info!("DISCORD posted summary of meeting {}", meeting_id),
+            Err(e) => log::warn!("DISCORD post for meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+/// Announce a new PRD version, if the project has that turned on
+pub fn spawn_post_prd_version(
+    app: &AppHandle,
+    meeting_name: &str,
+    project_path: Option<&str>,
+    version: &PRDVersion,
+) {
+    if !events_for(app, project_path).prd_versions {
+        return;
+    }
+    let Some(url) = webhook_for(project_path) else {
+        return;
+    };
+    let embed = prd_embed(meeting_name, version);
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = post(&url, embed).await {
+            log::warn!("DISCORD PRD notification failed: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_embed() {
+        let digest = MeetingDigest {
+            title: "Planning".to_string(),
+            duration_minutes: 12,
+            decisions: vec!["Use SQLite".to_string()],
+            pr_url: Some("https://github.com/o/r/pull/7".to_string()),
+            ..Default::default()
+        };
+        let embed = summary_embed(&digest);
+        assert_eq!(embed["title"], "Meeting ended: Planning");
+        assert_eq!(embed["url"], "https://github.com/o/r/pull/7");
+        assert_eq!(embed["fields"][0]["name"], "Decisions");
+        assert_eq!(embed["fields"][0]["value"], "• Use SQLite");
+        assert!(embed["description"]
+            .as_str()
+            .unwrap()
+            .ends_with("[View pull request](https://github.com/o/r/pull/7)"));
+
+        let long = truncate("x".repeat(2000), MAX_FIELD_CHARS);
+        assert_eq!(long.chars().count(), MAX_FIELD_CHARS);
+    }
+}
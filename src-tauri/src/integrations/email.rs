@@ -0,0 +1,330 @@
+//! Emailing meeting summaries to participants. Speaker labels are mapped to addresses in
+//! settings; "smtp" sends directly, "system" opens a draft in the default mail app.
+
+use anyhow::{anyhow, Context, Result};
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::EmailConfig;
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.smtp";
+const KEYCHAIN_ACCOUNT: &str = "credentials";
+const FALLBACK_FILE: &str = ".smtp-credentials";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SmtpCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailDelivery {
+    pub recipients: Vec<String>,
+    /// Participants with no address in settings
+    pub unmapped: Vec<String>,
+    /// False when a draft was opened in the mail app instead of sent
+    pub sent: bool,
+}
+
+/// Store the SMTP login in the keyring, falling back to a file
+pub fn store_credentials(credentials: &SmtpCredentials) -> Result<()> {
+    let raw = serde_json::to_string(credentials)?;
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, &raw)
+}
+
+/// None when the server takes mail without logging in
+fn load_credentials() -> Option<SmtpCredentials> {
+    let raw = secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+pub fn has_credentials() -> bool {
+    load_credentials().is_some()
+}
+
+/// Remove the stored SMTP login
+pub fn clear_credentials() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Trim the config and reject values that can't work, for the settings commands
+pub fn normalize_config(config: &mut EmailConfig) -> Result<(), String> {
+    config.smtp_host = config.smtp_host.trim().to_string();
+    config.from_address = config.from_address.trim().to_string();
+    config.always_cc = config
+        .always_cc
+        .iter()
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect();
+    if !matches!(config.transport.as_str(), "smtp" | "system") {
+        return Err(format!("Unsupported email transport: {}", config.transport));
+    }
+    if !matches!(config.security.as_str(), "starttls" | "tls" | "none") {
+        return Err(format!("Unsupported SMTP security: {}", config.security));
+    }
+    if config.transport == "smtp" && config.auto_send {
+        if config.smtp_host.is_empty() {
+            return Err("SMTP server is required".to_string());
+        }
+        if config.from_address.parse::<Mailbox>().is_err() {
+            return Err("A valid sender address is required".to_string());
+        }
+    }
+    let invalid = config
+        .recipients
+        .values()
+        .chain(config.always_cc.iter())
+        .map(|a| a.trim())
+        .find(|a| !a.is_empty() && a.parse::<Mailbox>().is_err());
+    if let Some(address) = invalid {
+        return Err(format!("Invalid email address: {}", address));
+    }
+    Ok(())
+}
+
+/// Addresses for the meeting's participants (speaker labels match case-insensitively),
+/// followed by the always-copied addresses, without duplicates. Also returns the
+/// participants that have no address.
+pub fn resolve_recipients(
+    config: &EmailConfig,
+    participants: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let mut recipients: Vec<String> = Vec::new();
+    let mut unmapped = Vec::new();
+    for participant in participants {
+        let address = config
+            .recipients
+            .iter()
+            .find(|(label, _)| label.trim().eq_ignore_ascii_case(participant.trim()))
+            .map(|(_, address)| address.trim().to_string())
+            .filter(|a| !a.is_empty());
+        match address {
+            Some(address) => recipients.push(address),
+            None => unmapped.push(participant.clone()),
+        }
+    }
+    recipients.extend(config.always_cc.iter().map(|a| a.trim().to_string()));
+    let mut seen = std::collections::HashSet::new();
+    recipients.retain(|a| !a.is_empty() && seen.insert(a.to_lowercase()));
+    (recipients, unmapped)
+}
+
+pub fn subject(digest: &MeetingDigest) -> String {
+    format!("Meeting summary: {} ({})", digest.title, digest.date())
+}
+
+/// Plain-text follow-up: the lists from the summary and the pull request
+pub fn body(digest: &MeetingDigest) -> String {
+    let mut text = String::new();
+    let _ = writeln!(text, "{}", digest.title);
+    let _ = writeln!(
+        text,
+        "{} · {} min\n",
+        digest.date(),
+        digest.duration_minutes
+    );
+    if !digest.participants.is_empty() {
+        let _ = writeln!(text, "Participants: {}\n", digest.participants.join(", "));
+    }
+    for (title, items) in digest.sections() {
+        let _ = writeln!(text, "{}", title);
+        for item in items {
+            let _ = writeln!(text, "- {}", item);
+        }
+        let _ = writeln!(text);
+    }
+    if digest.sections().is_empty() {
+        let _ = writeln!(
+            text,
+            "No action items, decisions, or questions were captured.\n"
+        );
+    }
+    if let Some(url) = &digest.pr_url {
+        let _ = writeln!(text, "Pull request: {}\n", url);
+    }
+    let _ = write!(text, "Sent by MeetingCoder");
+    text
+}
+
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'@' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn mailto_url(recipients: &[String], subject: &str, body: &str) -> String {
+    format!(
+        "mailto:{}?subject={}&body={}",
+        recipients
+            .iter()
+            .map(|r| percent_encode(r))
+            .collect::<Vec<_>>()
+            .join(","),
+        percent_encode(subject),
+        percent_encode(body)
+    )
+}
+
+async fn send_smtp(
+    config: &EmailConfig,
+    recipients: &[String],
+    subject: &str,
+    body: String,
+) -> Result<()> {
+    if config.smtp_host.trim().is_empty() {
+        return Err(anyhow!("No SMTP server is set"));
+    }
+    network::check(
+        Service::Email,
+        &format!("smtp://{}:{}", config.smtp_host, config.smtp_port),
+    )?;
+    let from: Mailbox = config
+        .from_address
+        .parse()
+        .with_context(|| format!("Invalid sender address: {}", config.from_address))?;
+    let mut message = Message::builder().from(from).subject(subject);
+    for recipient in recipients {
+        let to: Mailbox = recipient
+            .parse()
+            .with_context(|| format!("Invalid recipient address: {}", recipient))?;
+        message = message.to(to);
+    }
+    let message = message.header(ContentType::TEXT_PLAIN).body(body)?;
+
+    let host = config.smtp_host.trim();
+    let mut transport = match config.security.as_str() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+        "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+        _ => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?,
+    }
+    .port(config.smtp_port);
+    if let Some(credentials) = load_credentials() {
+        transport =
+            transport.credentials(Credentials::new(credentials.username, credentials.password));
+    }
+    transport
+        .build()
+        .send(message)
+        .await
+        .context("SMTP server rejected the message")?;
+    Ok(())
+}
+
+/// Email a meeting's summary to its mapped participants
+pub async fn send_digest(
+    app: &AppHandle,
+    config: &EmailConfig,
+    digest: &MeetingDigest,
+) -> Result<EmailDelivery> {
+    let (recipients, unmapped) = resolve_recipients(config, &digest.participants);
+    if recipients.is_empty() {
+        return Err(anyhow!(
+            "None of the participants ({}) have an email address in settings",
+            digest.participants.join(", ")
+        ));
+    }
+    let subject = subject(digest);
+    let body = body(digest);
+    let sent = if config.transport == "system" {
+        app.opener()
+            .open_url(mailto_url(&recipients, &subject, &body), None::<&str>)
+            .map_err(|e| anyhow!("Failed to open the mail app: {}", e))?;
+        false
+    } else {
+        send_smtp(config, &recipients, &subject, body).await?;
+        true
+    };
+    Ok(EmailDelivery {
+        recipients,
+        unmapped,
+        sent,
+    })
+}
+
+/// Email the summary of a meeting that just ended, if auto-send is on
+pub fn spawn_send_after_meeting(app: &AppHandle, meeting_id: &str) {
+    let config = crate::settings::get_settings(app).email;
+    if !config.auto_send {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let app = app.clone();
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => send_digest(&app, &config, &digest).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(delivery) => log::info!(
+                "EMAIL summary of meeting {} to {} recipients (unmapped: {:?})",
+                meeting_id,
+                delivery.recipients.len(),
+                delivery.unmapped
+            ),
+            Err(e) => log::warn!("EMAIL summary of meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_recipients() {
+        let config = EmailConfig {
+            recipients: [
+                ("Alice".to_string(), "alice@example.com".to_string()),
+                ("Bob".to_string(), " ".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+            always_cc: vec![
+                "ALICE@example.com".to_string(),
+                "pm@example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+        let participants = vec![
+            "alice".to_string(),
+            "Bob".to_string(),
+            "Speaker 3".to_string(),
+        ];
+        let (recipients, unmapped) = resolve_recipients(&config, &participants);
+        assert_eq!(recipients, vec!["alice@example.com", "pm@example.com"]);
+        assert_eq!(unmapped, vec!["Bob", "Speaker 3"]);
+    }
+
+    #[test]
+    fn test_mailto_url() {
+        let url = mailto_url(
+            &["a@example.com".to_string(), "b@example.com".to_string()],
+            "Hi there",
+            "Line 1\nLine 2 & more",
+        );
+        assert_eq!(
+            url,
+            "mailto:a@example.com,b@example.com?subject=Hi%20there&body=Line%201%0ALine%202%20%26%20more"
+        );
+    }
+}
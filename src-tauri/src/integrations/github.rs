@@ -1,12 +1,16 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use std::process::Command;
 use std::fs;
 use std::env;
 
-const KEYCHAIN_SERVICE: &str = "com.handy.github";
-const KEYCHAIN_ACCOUNT: &str = "github_token";
+use crate::network::{self, Service};
+use crate::redaction;
+use crate::settings::{AppSettings, GitAuthMethod};
+
+pub(crate) const KEYCHAIN_SERVICE: &str = "com.handy.github";
+pub(crate) const KEYCHAIN_ACCOUNT: &str = "github_token";
+const GITHUB_API: &str = "https://api.github.com";
 
 // Fallback token storage path for when keyring fails (development mode)
 fn get_token_fallback_path() -> Result<std::path::PathBuf> {
@@ -29,6 +33,20 @@ pub struct GitHubState {
     pub last_pr_url: Option<String>,
     pub last_pr_number: Option<u32>,
     pub last_push_time: Option<String>,
+    /// Issues filed from meeting action items and questions, so none is filed twice
+    #[serde(default)]
+    pub created_issues: Vec<CreatedIssue>,
+}
+
+/// A GitHub issue created from a meeting item
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreatedIssue {
+    /// Action item id, or `q` + a hash of the question text
+    pub item_key: String,
+    pub meeting_id: String,
+    pub number: u32,
+    pub html_url: String,
+    pub title: String,
 }
 
 impl GitHubState {
@@ -42,6 +60,7 @@ impl GitHubState {
             last_pr_url: None,
             last_pr_number: None,
             last_push_time: None,
+            created_issues: Vec::new(),
         }
     }
 }
@@ -101,33 +120,123 @@ pub fn write_github_state(project_path: &str, state: &GitHubState) -> Result<()>
     Ok(())
 }
 
+/// How a clone or push authenticates: the `git_auth_method` setting plus what it needs
+pub struct GitAuth<'a> {
+    pub method: GitAuthMethod,
+    pub token: &'a str,
+    pub ssh_key_path: Option<&'a Path>,
+}
+
+impl<'a> GitAuth<'a> {
+    pub fn from_settings(settings: &'a AppSettings, token: &'a str) -> Self {
+        Self {
+            method: settings.git_auth_method,
+            token,
+            ssh_key_path: settings.git_ssh_key_path.as_deref().map(Path::new),
+        }
+    }
+}
+
+/// Remote URL for the auth method; credentials come from callbacks, never the URL
+pub fn remote_url(method: GitAuthMethod, owner: &str, repo: &str) -> String {
+    match method {
+        GitAuthMethod::Ssh => format!("git@github.com:{}/{}.git", owner, repo),
+        GitAuthMethod::Token | GitAuthMethod::CredentialHelper => {
+            format!("https://github.com/{}/{}.git", owner, repo)
+        }
+    }
+}
+
+/// Keys tried after the SSH agent when no key is configured
+fn default_ssh_keys() -> Vec<std::path::PathBuf> {
+    let Ok(home) = env::var("HOME").or_else(|_| env::var("USERPROFILE")) else {
+        return Vec::new();
+    };
+    ["id_ed25519", "id_ecdsa", "id_rsa"]
+        .iter()
+        .map(|name| Path::new(&home).join(".ssh").join(name))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// libgit2 callbacks that answer credential requests for `auth`. libgit2 asks again
+/// after a rejected credential, so each source is offered once before giving up.
+fn remote_callbacks<'a>(auth: &'a GitAuth<'a>) -> git2::RemoteCallbacks<'a> {
+    let ssh_keys: Vec<std::path::PathBuf> = match auth.ssh_key_path {
+        Some(path) => vec![path.to_path_buf()],
+        None => default_ssh_keys(),
+    };
+    let mut attempts = 0usize;
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.credentials(move |url, username, allowed| {
+        let user = username.unwrap_or("git");
+        // SSH first asks who to connect as; that isn't an attempt
+        if allowed.contains(git2::CredentialType::USERNAME) {
+            return git2::Cred::username(user);
+        }
+        attempts += 1;
+        match auth.method {
+            GitAuthMethod::Token if attempts == 1 => {
+                git2::Cred::userpass_plaintext("x-access-token", auth.token)
+            }
+            GitAuthMethod::Ssh if attempts == 1 => git2::Cred::ssh_key_from_agent(user),
+            GitAuthMethod::Ssh if attempts - 2 < ssh_keys.len() => {
+                git2::Cred::ssh_key(user, None, &ssh_keys[attempts - 2], None)
+            }
+            GitAuthMethod::CredentialHelper if attempts == 1 => {
+                let config = git2::Config::open_default()?;
+                git2::Cred::credential_helper(&config, url, username)
+            }
+            method => Err(git2::Error::from_str(&format!(
+                "GitHub rejected the {:?} credentials for {}",
+                method, url
+            ))),
+        }
+    });
+    // A rejected ref (e.g. non-fast-forward) fails the push instead of passing silently
+    callbacks.push_update_reference(|refname, status| match status {
+        Some(message) => Err(git2::Error::from_str(&format!(
+            "{} was rejected: {}",
+            refname, message
+        ))),
+        None => Ok(()),
+    });
+    callbacks
+}
+
 /// Ensure a local clone of the selected GitHub repository exists and return its path
-/// Layout: ~/MeetingCoder/repos/{owner}/{repo}
-pub fn ensure_local_repo_clone(owner: &str, repo: &str, token: &str) -> Result<String> {
-    let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not determine home directory"))?;
-    let base = home.join("MeetingCoder").join("repos").join(owner);
+/// Layout: {storage_root}/repos/{owner}/{repo}
+pub fn ensure_local_repo_clone(owner: &str, repo: &str, auth: &GitAuth) -> Result<String> {
+    let base = crate::storage::root::storage_dir("repos")?.join(owner);
     fs::create_dir_all(&base)?;
     let dest = base.join(repo);
+    let url = remote_url(auth.method, owner, repo);
 
     // If already cloned, return path
     if dest.join(".git").exists() {
         log::info!("GITHUB using existing local clone: {}", dest.display());
+        // Older clones kept the token in the origin URL
+        let repository = git2::Repository::open(&dest)?;
+        let origin_url = repository
+            .find_remote("origin")
+            .ok()
+            .and_then(|r| r.url().map(str::to_string));
+        if origin_url.is_some_and(|u| u.starts_with("https://") && u.contains('@')) {
+            repository.remote_set_url("origin", &url)?;
+            log::info!("GITHUB removed credentials from the origin URL of {}", dest.display());
+        }
         return Ok(dest.to_string_lossy().to_string());
     }
 
-    // Clone using token in URL for simplicity (dev mode); production should rely on keychain/credential helper
-    let remote_url = format!("https://{}@github.com/{}/{}.git", token, owner, repo);
-    let output = Command::new("git")
-        .arg("clone")
-        .arg(&remote_url)
-        .arg(&dest)
-        .output()?;
-    if !output.status.success() {
-        return Err(anyhow!(
-            "Git clone failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
+    network::check(Service::GitHub, "https://github.com")?;
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options
+        .remote_callbacks(remote_callbacks(auth))
+        .proxy_options(network::git_proxy_options());
+    git2::build::RepoBuilder::new()
+        .fetch_options(fetch_options)
+        .clone(&url, &dest)
+        .map_err(|e| anyhow!("Git clone failed: {}", e.message()))?;
 
     log::info!("GITHUB cloned repo to {}", dest.display());
     Ok(dest.to_string_lossy().to_string())
@@ -230,7 +339,7 @@ pub fn delete_github_token() -> Result<()> {
 
 /// Test GitHub token by making an authenticated API call
 pub async fn test_github_connection(token: &str) -> Result<String> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let response = client
         .get("https://api.github.com/user")
         .header("Authorization", format!("Bearer {}", token))
@@ -275,7 +384,7 @@ pub struct RepoOwner {
 
 /// Get list of user's repositories
 pub async fn list_user_repos(token: &str) -> Result<Vec<RepoInfo>> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
 
     // Fetch both user repos and org repos
     let mut all_repos = Vec::new();
@@ -300,7 +409,7 @@ pub async fn list_user_repos(token: &str) -> Result<Vec<RepoInfo>> {
 
 /// Get repository information
 pub async fn get_repo_info(token: &str, owner: &str, repo: &str) -> Result<serde_json::Value> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let url = format!("https://api.github.com/repos/{}/{}", owner, repo);
 
     let response = client
@@ -368,16 +477,16 @@ pub fn get_current_branch(repo: &git2::Repository) -> Result<String> {
 /// Commit meeting-related files only (does not stage the whole tree)
 pub fn commit_meeting_files(
     repo: &git2::Repository,
+    specs: &[String],
     message: &str,
     author_name: &str,
     author_email: &str,
 ) -> Result<git2::Oid> {
     let mut index = repo.index()?;
 
-    // Add meeting artifacts only by default for safety
-    // - transcript file and .claude metadata
+    // Add meeting artifacts only for safety (transcript file and .claude metadata
+    // unless the repo's .meetingcoder.toml lists other paths).
     // This avoids committing unrelated source changes automatically.
-    let specs = [".transcript.jsonl", ".claude/*"];
     index.add_all(specs.iter(), git2::IndexAddOption::DEFAULT, None)?;
     index.write()?;
 
@@ -410,33 +519,26 @@ pub fn commit_meeting_files(
     Ok(oid)
 }
 
-/// Push branch to remote using git command (libgit2 auth can be complex)
+/// Push a branch to GitHub with libgit2, authenticating per `auth`
 pub fn push_to_remote(
     project_path: &str,
     branch_name: &str,
-    token: &str,
+    auth: &GitAuth,
     owner: &str,
     repo: &str,
 ) -> Result<()> {
-    // Set up remote URL with token authentication
-    let remote_url = format!(
-        "https://{}@github.com/{}/{}.git",
-        token, owner, repo
-    );
-
-    // Use git command for push (simpler authentication)
-    let output = Command::new("git")
-        .current_dir(project_path)
-        .arg("push")
-        .arg(&remote_url)
-        .arg(format!("{}:{}", branch_name, branch_name))
-        .arg("--set-upstream")
-        .output()?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!("Git push failed: {}", stderr));
-    }
+    network::check(Service::GitHub, "https://github.com")?;
+    let repository = git2::Repository::open(project_path)?;
+    let mut remote = repository.remote_anonymous(&remote_url(auth.method, owner, repo))?;
+
+    let mut push_options = git2::PushOptions::new();
+    push_options
+        .remote_callbacks(remote_callbacks(auth))
+        .proxy_options(network::git_proxy_options());
+    let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch_name);
+    remote
+        .push(&[refspec.as_str()], Some(&mut push_options))
+        .map_err(|e| anyhow!("Git push failed: {}", e.message()))?;
 
     log::info!("GITHUB pushed branch {} to remote", branch_name);
     Ok(())
@@ -452,12 +554,12 @@ pub async fn create_pull_request(
     head: &str,
     base: &str,
 ) -> Result<GitHubPR> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let url = format!("https://api.github.com/repos/{}/{}/pulls", owner, repo);
 
     let request = CreatePRRequest {
         title: title.to_string(),
-        body: body.to_string(),
+        body: redaction::outgoing(body).into_owned(),
         head: head.to_string(),
         base: base.to_string(),
     };
@@ -494,7 +596,7 @@ pub async fn update_pull_request(
     title: Option<&str>,
     body: Option<&str>,
 ) -> Result<GitHubPR> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let url = format!(
         "https://api.github.com/repos/{}/{}/pulls/{}",
         owner, repo, pr_number
@@ -502,7 +604,7 @@ pub async fn update_pull_request(
 
     let request = UpdatePRRequest {
         title: title.map(|s| s.to_string()),
-        body: body.map(|s| s.to_string()),
+        body: body.map(|s| redaction::outgoing(s).into_owned()),
         state: None,
     };
 
@@ -537,14 +639,14 @@ pub async fn post_pr_comment(
     pr_number: u32,
     comment: &str,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let url = format!(
         "https://api.github.com/repos/{}/{}/issues/{}/comments",
         owner, repo, pr_number
     );
 
     let request = CreateCommentRequest {
-        body: comment.to_string(),
+        body: redaction::outgoing(comment).into_owned(),
     };
 
     let response = client
@@ -576,7 +678,7 @@ pub async fn get_prs_for_branch(
     repo: &str,
     branch: &str,
 ) -> Result<Vec<GitHubPR>> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, GITHUB_API)?;
     let url = format!(
         "https://api.github.com/repos/{}/{}/pulls?head={}:{}&state=open",
         owner, repo, owner, branch
@@ -600,6 +702,141 @@ pub async fn get_prs_for_branch(
     Ok(prs)
 }
 
+// ===== Issues from meetings =====
+
+/// Label on every issue filed from a meeting
+pub const MEETING_ISSUE_LABEL: &str = "meeting";
+/// GitHub rejects longer issue titles
+const MAX_ISSUE_TITLE_CHARS: usize = 256;
+
+/// An issue to file for a meeting item
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueDraft {
+    pub item_key: String,
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GitHubIssue {
+    pub number: u32,
+    pub html_url: String,
+}
+
+fn issue_title(prefix: &str, text: &str) -> String {
+    let title = format!("{}{}", prefix, text.trim().trim_end_matches('.'));
+    if title.chars().count() <= MAX_ISSUE_TITLE_CHARS {
+        return title;
+    }
+    let cut: String = title.chars().take(MAX_ISSUE_TITLE_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Issues for a meeting's open action items and its open questions. Done action items
+/// are left out.
+pub fn issue_drafts(
+    digest: &crate::integrations::digest::MeetingDigest,
+    action_items: &[crate::summarization::action_items::ActionItem],
+) -> Vec<IssueDraft> {
+    let footer = {
+        let mut text = format!(
+            "From \"{}\" ({}).\nOpen the meeting: meetingcoder://open-meeting/{}",
+            digest.title,
+            digest.date(),
+            digest.meeting_id
+        );
+        if let Some(url) = &digest.pr_url {
+            text.push_str(&format!("\nPull request: {}", url));
+        }
+        text
+    };
+    let labels = |kind: &str| vec![MEETING_ISSUE_LABEL.to_string(), kind.to_string()];
+
+    let mut drafts: Vec<IssueDraft> = action_items
+        .iter()
+        .filter(|item| !item.done)
+        .map(|item| {
+            let total = item.timestamp.max(0.0) as u64;
+            let mut body = format!("> {}\n\n", item.text);
+            if let Some(owner) = &item.owner {
+                body.push_str(&format!("- Owner: {}\n", owner));
+            }
+            if let Some(due) = &item.due {
+                body.push_str(&format!("- Due: {}\n", due));
+            }
+            body.push_str(&format!(
+                "- Said by {} at {:02}:{:02}\n\n{}",
+                item.speaker,
+                total / 60,
+                total % 60,
+                footer
+            ));
+            IssueDraft {
+                item_key: item.id.clone(),
+                title: issue_title("", &item.text),
+                body,
+                labels: labels("action-item"),
+            }
+        })
+        .collect();
+    for question in &digest.open_questions {
+        let id = crate::summarization::action_items::text_id(question);
+        let item_key = format!("q{}", &id[1..]);
+        if drafts.iter().any(|d| d.item_key == item_key) {
+            continue;
+        }
+        drafts.push(IssueDraft {
+            item_key,
+            title: issue_title("Open question: ", question),
+            body: format!(
+                "> {}\n\nRaised in the meeting and not yet answered.\n\n{}",
+                question.trim(),
+                footer
+            ),
+            labels: labels("question"),
+        });
+    }
+    drafts
+}
+
+/// Open an issue; labels that don't exist yet are created by GitHub
+pub async fn create_issue(
+    token: &str,
+    owner: &str,
+    repo: &str,
+    draft: &IssueDraft,
+) -> Result<GitHubIssue> {
+    let client = network::client(Service::GitHub, GITHUB_API)?;
+    let url = format!("https://api.github.com/repos/{}/{}/issues", owner, repo);
+
+    let response = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "Handy-App")
+        .json(&serde_json::json!({
+            "title": redaction::outgoing(&draft.title),
+            "body": redaction::outgoing(&draft.body),
+            "labels": draft.labels,
+        }))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await?;
+        return Err(anyhow!(
+            "Failed to create issue ({}): {}",
+            status,
+            error_text
+        ));
+    }
+
+    let issue: GitHubIssue = response.json().await?;
+    log::info!("GITHUB created issue #{}: {}", issue.number, issue.html_url);
+    Ok(issue)
+}
+
 /// Generate branch name from meeting ID using the pattern
 pub fn generate_branch_name(pattern: &str, meeting_id: &str, meeting_name: &str) -> String {
     let sanitized_name = meeting_name
@@ -643,7 +880,7 @@ pub enum DeviceTokenPollResponse {
 
 /// Initiate OAuth Device Flow
 pub async fn begin_device_auth() -> Result<DeviceCodeResponse> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, "https://github.com/login/device/code")?;
 
     let mut params = std::collections::HashMap::new();
     params.insert("client_id", GITHUB_CLIENT_ID);
@@ -669,7 +906,7 @@ pub async fn begin_device_auth() -> Result<DeviceCodeResponse> {
 
 /// Poll for OAuth Device Flow token
 pub async fn poll_device_token(device_code: &str) -> Result<Option<String>> {
-    let client = reqwest::Client::new();
+    let client = network::client(Service::GitHub, "https://github.com/login/oauth/access_token")?;
 
     let mut params = std::collections::HashMap::new();
     params.insert("client_id", GITHUB_CLIENT_ID);
@@ -700,3 +937,63 @@ pub async fn poll_device_token(device_code: &str) -> Result<Option<String>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::digest::MeetingDigest;
+    use crate::summarization::action_items::ActionItem;
+
+    #[test]
+    fn test_issue_drafts_from_meeting() {
+        let digest = MeetingDigest {
+            meeting_id: "m-1".to_string(),
+            title: "Planning".to_string(),
+            open_questions: vec!["Do we support SSO?".to_string()],
+            pr_url: Some("https://github.com/acme/app/pull/7".to_string()),
+            ..Default::default()
+        };
+        let item = |id: &str, done: bool| ActionItem {
+            id: id.to_string(),
+            text: "Priya will update the roadmap before next week.".to_string(),
+            owner: Some("Priya".to_string()),
+            due: Some("before next week".to_string()),
+            speaker: "Dana".to_string(),
+            segment_index: 4,
+            timestamp: 75.0,
+            done,
+            completed_at: None,
+        };
+
+        let drafts = issue_drafts(&digest, &[item("t1", false), item("t2", true)]);
+        assert_eq!(drafts.len(), 2);
+        assert_eq!(drafts[0].item_key, "t1");
+        assert_eq!(drafts[0].title, "Priya will update the roadmap before next week");
+        assert!(drafts[0].body.contains("- Owner: Priya\n- Due: before next week\n"));
+        assert!(drafts[0].body.contains("at 01:15"));
+        assert!(drafts[0].body.contains("meetingcoder://open-meeting/m-1"));
+        assert_eq!(drafts[0].labels, vec!["meeting", "action-item"]);
+
+        assert!(drafts[1].item_key.starts_with('q'));
+        assert_eq!(drafts[1].title, "Open question: Do we support SSO?");
+        assert!(drafts[1].body.contains("pull/7"));
+
+        assert!(issue_title("", &"x".repeat(300)).chars().count() <= MAX_ISSUE_TITLE_CHARS);
+    }
+
+    #[test]
+    fn test_remote_url_has_no_credentials() {
+        assert_eq!(
+            remote_url(GitAuthMethod::Ssh, "acme", "app"),
+            "git@github.com:acme/app.git"
+        );
+        assert_eq!(
+            remote_url(GitAuthMethod::Token, "acme", "app"),
+            "https://github.com/acme/app.git"
+        );
+        assert_eq!(
+            remote_url(GitAuthMethod::CredentialHelper, "acme", "app"),
+            "https://github.com/acme/app.git"
+        );
+    }
+}
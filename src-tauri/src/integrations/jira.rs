@@ -0,0 +1,290 @@
+//! Jira context for ticket keys mentioned in a meeting. Keys like `PROJ-123` are picked
+//! out of transcript segments as they arrive and looked up in the background; summaries,
+//! meeting updates, and agent prompts then read the titles and statuses from the cache.
+
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::managers::meeting::TranscriptSegment;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::JiraConfig;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.jira";
+const KEYCHAIN_ACCOUNT: &str = "token";
+const FALLBACK_FILE: &str = ".jira-token";
+/// Longest project key prefix Jira allows
+const MAX_PREFIX_CHARS: usize = 10;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct JiraTicket {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub issue_type: Option<String>,
+    pub assignee: Option<String>,
+    pub url: String,
+}
+
+/// Looked-up tickets by key; `None` while a lookup is running or when the key doesn't exist
+static CACHE: Lazy<Mutex<HashMap<String, Option<JiraTicket>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Store an API token (Jira Cloud) or personal access token (Server/Data Center)
+pub fn store_token(token: &str) -> Result<()> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(anyhow!("Jira API token is empty"));
+    }
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, token)
+}
+
+fn load_token() -> Result<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map(|t| t.trim().to_string())
+        .map_err(|_| anyhow!("Jira is not connected"))
+}
+
+pub fn has_token() -> bool {
+    load_token().is_ok()
+}
+
+/// Remove the stored token and forget looked-up tickets
+pub fn clear_token() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    CACHE.lock().unwrap().clear();
+    Ok(())
+}
+
+/// Trim the config and check the site URL, for the settings commands
+pub fn normalize_config(config: &mut JiraConfig) -> Result<(), String> {
+    config.base_url = config.base_url.trim().trim_end_matches('/').to_string();
+    config.email = config.email.trim().to_string();
+    config.project_keys = config
+        .project_keys
+        .iter()
+        .map(|k| k.trim().to_ascii_uppercase())
+        .filter(|k| !k.is_empty())
+        .collect();
+    config.project_keys.sort();
+    config.project_keys.dedup();
+    if !config.base_url.is_empty() && !config.base_url.starts_with("https://") {
+        return Err("Jira site URL must start with https://".to_string());
+    }
+    if config.enabled && config.base_url.is_empty() {
+        return Err("Enter your Jira site URL".to_string());
+    }
+    Ok(())
+}
+
+/// A possible ticket key in text: project prefix, issue number, and whether it was
+/// written the way Jira writes keys (`PROJ-123`) rather than transcribed loosely
+/// (`proj 123`, `Proj-123`)
+fn candidates(text: &str) -> Vec<(String, String, bool)> {
+    let words: Vec<&str> = text
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .map(|w| w.trim_matches('-'))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let is_prefix = |p: &str| {
+        p.len() >= 2
+            && p.len() <= MAX_PREFIX_CHARS
+            && p.starts_with(|c: char| c.is_ascii_alphabetic())
+            && p.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+    let is_number = |n: &str| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit());
+    let mut found = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        if let Some((prefix, number)) = word.rsplit_once('-') {
+            if is_prefix(prefix) && is_number(number) {
+                let strict = !prefix.chars().any(|c| c.is_ascii_lowercase());
+                found.push((prefix.to_ascii_uppercase(), number.to_string(), strict));
+            }
+        } else if is_prefix(word) && !word.chars().any(|c| c.is_ascii_digit()) {
+            if let Some(number) = words.get(i + 1).filter(|n| is_number(n)) {
+                found.push((word.to_ascii_uppercase(), number.to_string(), false));
+            }
+        }
+    }
+    found
+}
+
+/// Ticket keys mentioned in text. With project keys configured, only those projects are
+/// matched, also when transcribed loosely; otherwise any `ABC-123` is.
+pub fn find_keys(text: &str, project_keys: &[String]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for (prefix, number, strict) in candidates(text) {
+        let allowed = if project_keys.is_empty() {
+            strict
+        } else {
+            project_keys.iter().any(|k| *k == prefix)
+        };
+        let key = format!("{}-{}", prefix, number);
+        if allowed && !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+async fn fetch_ticket(config: &JiraConfig, token: &str, key: &str) -> Result<Option<JiraTicket>> {
+    let url = format!("{}/rest/api/2/issue/{}", config.base_url, key);
+    let request = network::client(Service::Jira, &url)?
+        .get(&url)
+        .query(&[("fields", "summary,status,assignee,issuetype")]);
+    // Cloud takes the account email with an API token; Server/Data Center a bearer PAT
+    let request = if config.email.is_empty() {
+        request.bearer_auth(token)
+    } else {
+        request.basic_auth(&config.email, Some(token))
+    };
+    let response = request.send().await.context("Failed to reach Jira")?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        return Err(anyhow!("Jira returned {} for {}", status, key));
+    }
+    let value: Value = response.json().await?;
+    let fields = &value["fields"];
+    let text = |v: &Value| v.as_str().map(String::from);
+    Ok(Some(JiraTicket {
+        key: text(&value["key"]).unwrap_or_else(|| key.to_string()),
+        summary: text(&fields["summary"]).unwrap_or_default(),
+        status: text(&fields["status"]["name"]).unwrap_or_default(),
+        issue_type: text(&fields["issuetype"]["name"]),
+        assignee: text(&fields["assignee"]["displayName"]),
+        url: format!("{}/browse/{}", config.base_url, key),
+    }))
+}
+
+/// Look up keys that aren't cached yet, recording the results
+async fn lookup_keys(config: &JiraConfig, keys: Vec<String>) {
+    let token = match load_token() {
+        Ok(token) => token,
+        Err(_) => {
+            let mut cache = CACHE.lock().unwrap();
+            for key in &keys {
+                cache.remove(key);
+            }
+            return;
+        }
+    };
+    for key in keys {
+        match fetch_ticket(config, &token, &key).await {
+            Ok(ticket) => {
+                CACHE.lock().unwrap().insert(key, ticket);
+            }
+            Err(e) => {
+                // Forget the key so the next mention tries again
+                CACHE.lock().unwrap().remove(&key);
+                log::warn!("JIRA lookup of {} failed: {}", key, e);
+            }
+        }
+    }
+}
+
+/// Claim the keys in text nobody has looked up yet
+fn uncached_keys(config: &JiraConfig, text: &str) -> Vec<String> {
+    let mut cache = CACHE.lock().unwrap();
+    find_keys(text, &config.project_keys)
+        .into_iter()
+        .filter(|key| {
+            if cache.contains_key(key) {
+                return false;
+            }
+            cache.insert(key.clone(), None);
+            true
+        })
+        .collect()
+}
+
+/// Start looking up tickets mentioned in a new transcript segment, if Jira is connected
+pub fn observe_text(app: &AppHandle, text: &str) {
+    let config = crate::settings::get_settings(app).jira;
+    if !config.enabled || config.base_url.is_empty() {
+        return;
+    }
+    // The token is only read once there is something to look up
+    let keys = uncached_keys(&config, text);
+    if keys.is_empty() {
+        return;
+    }
+    tauri::async_runtime::spawn(async move { lookup_keys(&config, keys).await });
+}
+
+/// Tickets mentioned in text, looking up any that aren't cached yet
+pub async fn tickets_for_text(app: &AppHandle, text: &str) -> Result<Vec<JiraTicket>> {
+    let config = crate::settings::get_settings(app).jira;
+    if config.base_url.is_empty() {
+        return Err(anyhow!("No Jira site is set"));
+    }
+    load_token()?;
+    let keys = uncached_keys(&config, text);
+    lookup_keys(&config, keys).await;
+    Ok(tickets_in(text))
+}
+
+/// Already looked-up tickets mentioned in text, in order of first mention
+pub fn tickets_in(text: &str) -> Vec<JiraTicket> {
+    let cache = CACHE.lock().unwrap();
+    let mut tickets: Vec<JiraTicket> = Vec::new();
+    for (prefix, number, _) in candidates(text) {
+        let key = format!("{}-{}", prefix, number);
+        if let Some(Some(ticket)) = cache.get(&key) {
+            if !tickets.iter().any(|t| t.key == ticket.key) {
+                tickets.push(ticket.clone());
+            }
+        }
+    }
+    tickets
+}
+
+/// Already looked-up tickets mentioned in transcript segments
+pub fn tickets_in_segments(segments: &[TranscriptSegment]) -> Vec<JiraTicket> {
+    let text: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+    tickets_in(&text.join("\n"))
+}
+
+/// Markdown list of tickets, one line each
+pub fn ticket_lines(tickets: &[JiraTicket]) -> String {
+    let mut md = String::new();
+    for t in tickets {
+        let _ = write!(md, "- [{}]({}): {} ({}", t.key, t.url, t.summary, t.status);
+        if let Some(assignee) = &t.assignee {
+            let _ = write!(md, ", assigned to {}", assignee);
+        }
+        let _ = writeln!(md, ")");
+    }
+    md
+}
+
+/// Prompt section describing the tickets mentioned in text; empty when there are none
+pub fn prompt_section(text: &str) -> String {
+    let tickets = tickets_in(text);
+    if tickets.is_empty() {
+        return String::new();
+    }
+    format!("\n## Referenced Jira tickets\n\n{}", ticket_lines(&tickets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_keys() {
+        let text = "Let's pick up PROJ-123 and proj 45, then UI-7. Ask about web-2 too (PROJ-123).";
+        assert_eq!(find_keys(text, &[]), vec!["PROJ-123", "UI-7"]);
+        let keys = vec!["PROJ".to_string(), "WEB".to_string()];
+        assert_eq!(find_keys(text, &keys), vec!["PROJ-123", "PROJ-45", "WEB-2"]);
+        assert!(find_keys("version 2 of the API-first plan", &[]).is_empty());
+    }
+}
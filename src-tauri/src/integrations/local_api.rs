@@ -0,0 +1,357 @@
+use anyhow::Result;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path as UrlPath, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Listener, Manager};
+use tokio::sync::{broadcast, oneshot};
+
+mod deck;
+
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::{default_meeting_name, MeetingManager};
+use crate::secrets;
+use crate::storage::db::MeetingDb;
+
+pub(crate) const KEYCHAIN_SERVICE: &str = "com.handy.local-api";
+pub(crate) const KEYCHAIN_ACCOUNT: &str = "token";
+const FALLBACK_FILE: &str = ".local-api-token";
+/// App events forwarded to WebSocket clients
+const STREAMED_EVENTS: &[&str] = &[
+    "transcript-segment-added",
+    "meeting-state-changed",
+    "meeting-marker-added",
+];
+
+/// Shutdown handle of the running server
+static SERVER: Lazy<Mutex<Option<oneshot::Sender<()>>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Clone)]
+struct ApiState {
+    app: AppHandle,
+    token: Arc<String>,
+    events: broadcast::Sender<String>,
+}
+
+/// One message on the `/v1/stream` WebSocket
+#[derive(Serialize)]
+struct StreamMessage<'a> {
+    event: &'a str,
+    payload: serde_json::Value,
+}
+
+#[derive(Deserialize, Default)]
+struct StartMeetingRequest {
+    name: Option<String>,
+}
+
+fn store_token(token: &str) -> Result<()> {
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, token)
+}
+
+fn load_token() -> Option<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .ok()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+pub fn has_token() -> bool {
+    load_token().is_some()
+}
+
+/// The bearer token clients must send, created on first use
+pub fn api_token() -> Result<String> {
+    match load_token() {
+        Some(token) => Ok(token),
+        None => regenerate_token(),
+    }
+}
+
+/// Replace the token; clients using the old one are rejected from then on
+pub fn regenerate_token() -> Result<String> {
+    let token = format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    store_token(&token)?;
+    Ok(token)
+}
+
+/// Compare without short-circuiting so response timing doesn't leak the token
+fn token_matches(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Token from `Authorization: Bearer ...`, or `?token=` for WebSocket clients that
+/// can't set headers
+fn request_token<'a>(request: &'a Request) -> Option<&'a str> {
+    request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .or_else(|| {
+            request
+                .uri()
+                .query()
+                .and_then(|q| q.split('&').find_map(|pair| pair.strip_prefix("token=")))
+        })
+}
+
+async fn require_token(State(state): State<ApiState>, request: Request, next: Next) -> Response {
+    match request_token(&request) {
+        Some(given) if token_matches(&state.token, given) => next.run(request).await,
+        _ => (StatusCode::UNAUTHORIZED, "Missing or invalid API token").into_response(),
+    }
+}
+
+fn error_response(status: StatusCode, message: impl ToString) -> Response {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+        .into_response()
+}
+
+fn emit_meeting_state(app: &AppHandle, meeting_id: &str, status: &str) {
+    events::emit(app, AppEvent::meeting_state(meeting_id, status, "api"));
+}
+
+fn meeting_manager(state: &ApiState) -> Arc<MeetingManager> {
+    state.app.state::<Arc<MeetingManager>>().inner().clone()
+}
+
+async fn status(State(state): State<ApiState>) -> Response {
+    let current = meeting_manager(&state).current_meeting().await;
+    Json(serde_json::json!({
+        "version": state.app.package_info().version.to_string(),
+        "meeting": current,
+    }))
+    .into_response()
+}
+
+async fn start_meeting(
+    State(state): State<ApiState>,
+    body: Option<Json<StartMeetingRequest>>,
+) -> Response {
+    let mm = meeting_manager(&state);
+    if let Some(current) = mm.current_meeting().await {
+        return error_response(
+            StatusCode::CONFLICT,
+            format!("Meeting {} is already in progress", current.id),
+        );
+    }
+    let name = body
+        .and_then(|Json(b)| b.name)
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(default_meeting_name);
+    match mm.start_meeting(name).await {
+        Ok(meeting_id) => {
+            emit_meeting_state(&state.app, &meeting_id, "recording");
+            Json(serde_json::json!({ "meeting_id": meeting_id })).into_response()
+        }
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// `id` may be "current" for the meeting in progress
+async fn stop_meeting(State(state): State<ApiState>, UrlPath(id): UrlPath<String>) -> Response {
+    let mm = meeting_manager(&state);
+    let meeting_id = if id == "current" {
+        match mm.current_meeting().await {
+            Some(current) => current.id,
+            None => return error_response(StatusCode::NOT_FOUND, "No meeting in progress"),
+        }
+    } else {
+        id
+    };
+    match mm.end_meeting(&meeting_id).await {
+        Ok(summary) => {
+            emit_meeting_state(&state.app, &meeting_id, "completed");
+            Json(summary).into_response()
+        }
+        Err(e) => error_response(StatusCode::NOT_FOUND, e),
+    }
+}
+
+async fn meeting_summary(State(state): State<ApiState>, UrlPath(id): UrlPath<String>) -> Response {
+    let db = state.app.state::<Arc<MeetingDb>>().inner().clone();
+    match db.list_summaries(&id) {
+        Ok(summaries) => match summaries
+            .iter()
+            .rev()
+            .find(|s| s.kind == "markdown")
+            .or_else(|| summaries.last())
+        {
+            Some(summary) => Json(summary.clone()).into_response(),
+            None => error_response(StatusCode::NOT_FOUND, "No summary for this meeting yet"),
+        },
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+async fn meeting_transcript(
+    State(state): State<ApiState>,
+    UrlPath(id): UrlPath<String>,
+) -> Response {
+    // Meetings in progress are only in memory; finished ones come from the database
+    if let Ok(meeting) = meeting_manager(&state).get_meeting(&id).await {
+        return Json(meeting.transcript_segments).into_response();
+    }
+    let db = state.app.state::<Arc<MeetingDb>>().inner().clone();
+    match db.get_segments(&id) {
+        Ok(segments) => Json(segments).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+async fn stream(State(state): State<ApiState>, ws: WebSocketUpgrade) -> Response {
+    let events = state.events.subscribe();
+    ws.on_upgrade(move |socket| forward_events(socket, events))
+}
+
+async fn forward_events(mut socket: WebSocket, mut events: broadcast::Receiver<String>) {
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(text) => {
+                    if socket.send(Message::Text(text)).await.is_err() {
+                        break;
+                    }
+                }
+                // A slow client missed some events; keep streaming the rest
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    log::warn!("LOCAL_API stream client lagged, dropped {} events", n);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/v1/status", get(status))
+        .route("/v1/meetings", post(start_meeting))
+        .route("/v1/meetings/:id/stop", post(stop_meeting))
+        .route("/v1/meetings/:id/summary", get(meeting_summary))
+        .route("/v1/meetings/:id/transcript", get(meeting_transcript))
+        .route("/v1/stream", get(stream))
+        .merge(deck::routes())
+        .layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+/// Forward app events to WebSocket subscribers for as long as the app runs
+fn event_channel(app: &AppHandle) -> broadcast::Sender<String> {
+    static CHANNEL: Lazy<broadcast::Sender<String>> = Lazy::new(|| broadcast::channel(256).0);
+    static LISTENING: std::sync::Once = std::sync::Once::new();
+    LISTENING.call_once(|| {
+        for &event in STREAMED_EVENTS {
+            app.listen_any(event, move |e| {
+                if CHANNEL.receiver_count() == 0 {
+                    return;
+                }
+                let payload = serde_json::from_str(e.payload()).unwrap_or(serde_json::Value::Null);
+                if let Ok(text) = serde_json::to_string(&StreamMessage { event, payload }) {
+                    let _ = CHANNEL.send(text);
+                }
+            });
+        }
+    });
+    CHANNEL.clone()
+}
+
+/// Stop the server if it is running
+pub fn stop() {
+    if let Some(shutdown) = SERVER.lock().unwrap().take() {
+        let _ = shutdown.send(());
+        log::info!("LOCAL_API server stopped");
+    }
+}
+
+/// (Re)start the server according to settings; stops it when disabled.
+/// Only binds to the loopback interface.
+pub fn restart(app: &AppHandle) {
+    stop();
+    let config = crate::settings::get_settings(app).local_api;
+    if !config.enabled {
+        return;
+    }
+    let token = match api_token() {
+        Ok(token) => token,
+        Err(e) => {
+            log::error!("LOCAL_API cannot start without a token: {}", e);
+            return;
+        }
+    };
+    let state = ApiState {
+        app: app.clone(),
+        token: Arc::new(token),
+        events: event_channel(app),
+    };
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    *SERVER.lock().unwrap() = Some(shutdown_tx);
+
+    tauri::async_runtime::spawn(async move {
+        let addr = std::net::SocketAddr::from(([127, 0, 0, 1], config.port));
+        let listener = match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                log::error!("LOCAL_API failed to bind {}: {}", addr, e);
+                return;
+            }
+        };
+        log::info!("LOCAL_API listening on http://{}", addr);
+        let served = axum::serve(listener, router(state))
+            .with_graceful_shutdown(async {
+                let _ = shutdown_rx.await;
+            })
+            .await;
+        if let Err(e) = served {
+            log::error!("LOCAL_API server error: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_checks() {
+        assert!(token_matches("abc123", "abc123"));
+        assert!(!token_matches("abc123", "abc124"));
+        assert!(!token_matches("abc123", "abc12"));
+
+        let request = Request::builder()
+            .uri("/v1/stream?foo=1&token=secret")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        assert_eq!(request_token(&request), Some("secret"));
+        let request = Request::builder()
+            .uri("/v1/status")
+            .header(header::AUTHORIZATION, "Bearer other")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        assert_eq!(request_token(&request), Some("other"));
+    }
+}
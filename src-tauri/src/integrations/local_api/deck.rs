@@ -0,0 +1,224 @@
+//! Stable endpoints for the Stream Deck plugin (documented in docs/STREAM_DECK_API.md).
+//! Every reply carries the full [`DeckState`] so a button can redraw from any response.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::Manager;
+use tokio::sync::broadcast;
+
+use super::{emit_meeting_state, meeting_manager, ApiState};
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::{default_meeting_name, MeetingStatus};
+use crate::storage::db::MeetingDb;
+
+/// How often the state stream checks for changes made outside the app's events
+/// (e.g. meetings started from the main window)
+const STATE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(super) struct DeckState {
+    /// "idle", "recording", or "paused"
+    status: &'static str,
+    meeting_id: Option<String>,
+    meeting_name: Option<String>,
+    /// Unix milliseconds, for drawing an elapsed timer on the key
+    started_at: Option<i64>,
+    marker_count: usize,
+}
+
+#[derive(Serialize)]
+struct DeckResponse {
+    ok: bool,
+    /// Why the action was refused, for `showAlert`-style feedback
+    message: Option<String>,
+    state: DeckState,
+}
+
+#[derive(Deserialize, Default)]
+struct MarkerRequest {
+    label: Option<String>,
+}
+
+async fn current_state(state: &ApiState) -> DeckState {
+    let Some(meeting) = meeting_manager(state).current_meeting().await else {
+        return DeckState {
+            status: "idle",
+            meeting_id: None,
+            meeting_name: None,
+            started_at: None,
+            marker_count: 0,
+        };
+    };
+    let marker_count = state
+        .app
+        .state::<Arc<MeetingDb>>()
+        .list_markers(&meeting.id)
+        .map(|m| m.len())
+        .unwrap_or(0);
+    DeckState {
+        status: if meeting.status == MeetingStatus::Paused {
+            "paused"
+        } else {
+            "recording"
+        },
+        started_at: meeting
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_millis() as i64),
+        meeting_id: Some(meeting.id),
+        meeting_name: Some(meeting.name),
+        marker_count,
+    }
+}
+
+async fn reply(state: &ApiState, result: Result<(), (StatusCode, String)>) -> Response {
+    let deck_state = current_state(state).await;
+    match result {
+        Ok(()) => Json(DeckResponse {
+            ok: true,
+            message: None,
+            state: deck_state,
+        })
+        .into_response(),
+        Err((status, message)) => (
+            status,
+            Json(DeckResponse {
+                ok: false,
+                message: Some(message),
+                state: deck_state,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+async fn get_state(State(state): State<ApiState>) -> Response {
+    Json(current_state(&state).await).into_response()
+}
+
+/// Start a meeting, or end the one in progress
+async fn toggle(State(state): State<ApiState>) -> Response {
+    let mm = meeting_manager(&state);
+    let result = match mm.current_meeting().await {
+        Some(current) => mm
+            .end_meeting(&current.id)
+            .await
+            .map(|_| emit_meeting_state(&state.app, &current.id, "completed")),
+        None => mm
+            .start_meeting(default_meeting_name())
+            .await
+            .map(|id| emit_meeting_state(&state.app, &id, "recording")),
+    }
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()));
+    reply(&state, result).await
+}
+
+async fn marker(State(state): State<ApiState>, body: Option<Json<MarkerRequest>>) -> Response {
+    let mm = meeting_manager(&state);
+    let result = match mm.current_meeting().await {
+        Some(current) => {
+            let label = body
+                .and_then(|Json(b)| b.label)
+                .map(|l| l.trim().to_string())
+                .unwrap_or_default();
+            mm.add_marker(&current.id, &label)
+                .await
+                .map(|marker| events::emit(&state.app, AppEvent::MeetingMarkerAdded(marker)))
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        None => Err((StatusCode::CONFLICT, "No meeting in progress".to_string())),
+    };
+    reply(&state, result).await
+}
+
+/// Run the coding agent on the live meeting's project now instead of waiting for the
+/// next automatic update
+async fn trigger_agent(State(state): State<ApiState>) -> Response {
+    let mm = meeting_manager(&state);
+    let result = match mm.current_meeting().await {
+        Some(current) => match mm.get_meeting(&current.id).await {
+            Ok(meeting) => match meeting.project_path.filter(|p| !p.is_empty()) {
+                Some(path) => crate::automation::claude_trigger::trigger_meeting_update(
+                    &state.app,
+                    &path,
+                    &current.id,
+                    0,
+                )
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+                .and_then(|triggered| {
+                    if triggered {
+                        Ok(())
+                    } else {
+                        Err((
+                            StatusCode::CONFLICT,
+                            "Agent auto-trigger is turned off".to_string(),
+                        ))
+                    }
+                }),
+                None => Err((
+                    StatusCode::CONFLICT,
+                    "Meeting has no project to run the agent in".to_string(),
+                )),
+            },
+            Err(e) => Err((StatusCode::NOT_FOUND, e.to_string())),
+        },
+        None => Err((StatusCode::CONFLICT, "No meeting in progress".to_string())),
+    };
+    reply(&state, result).await
+}
+
+async fn stream(State(state): State<ApiState>, ws: WebSocketUpgrade) -> Response {
+    let events = state.events.subscribe();
+    ws.on_upgrade(move |socket| push_state(socket, state, events))
+}
+
+/// Send the state on connect and whenever it changes
+async fn push_state(
+    mut socket: WebSocket,
+    state: ApiState,
+    mut events: broadcast::Receiver<String>,
+) {
+    let mut last: Option<DeckState> = None;
+    let mut poll = tokio::time::interval(STATE_POLL_INTERVAL);
+    loop {
+        let current = current_state(&state).await;
+        if last.as_ref() != Some(&current) {
+            let Ok(text) = serde_json::to_string(&current) else {
+                break;
+            };
+            if socket.send(Message::Text(text)).await.is_err() {
+                break;
+            }
+            last = Some(current);
+        }
+        tokio::select! {
+            _ = poll.tick() => {}
+            event = events.recv() => {
+                if let Err(broadcast::error::RecvError::Closed) = event {
+                    break;
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+pub(super) fn routes() -> Router<ApiState> {
+    Router::new()
+        .route("/v1/deck/state", get(get_state))
+        .route("/v1/deck/toggle", post(toggle))
+        .route("/v1/deck/marker", post(marker))
+        .route("/v1/deck/agent", post(trigger_agent))
+        .route("/v1/deck/stream", get(stream))
+}
@@ -1 +1,15 @@
+pub mod asana;
+pub mod calendar;
+pub mod calendar_writeback;
+pub mod cloud_sync;
+pub mod digest;
+pub mod discord;
+pub mod email;
 pub mod github;
+pub mod jira;
+pub mod local_api;
+pub mod obsidian;
+pub mod slack;
+pub mod teams;
+pub mod trello;
+pub mod webhooks;
@@ -0,0 +1,243 @@
+//! Meetings as notes in an Obsidian vault: YAML frontmatter for Dataview, a wikilink to
+//! the project's note, action items as checkboxes, and the start of the transcript.
+
+use anyhow::{anyhow, Result};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::integrations::digest::MeetingDigest;
+use crate::managers::meeting::TranscriptSegment;
+use crate::settings::ObsidianConfig;
+use crate::storage::db::MeetingDb;
+
+/// Transcript lines included in the note; the full transcript stays in MeetingCoder
+const EXCERPT_SEGMENTS: usize = 20;
+
+/// Quote a YAML scalar
+fn yaml(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Characters Obsidian doesn't allow in note names
+fn note_name(text: &str) -> String {
+    let name: String = text
+        .chars()
+        .map(|c| match c {
+            '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    name.trim().trim_matches('.').to_string()
+}
+
+fn timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+/// Project note name, from the last component of the project path
+fn project_note(project_path: Option<&str>) -> Option<String> {
+    project_path
+        .and_then(|p| Path::new(p).file_name())
+        .map(|n| note_name(&n.to_string_lossy()))
+        .filter(|n| !n.is_empty())
+}
+
+pub fn render_note(
+    digest: &MeetingDigest,
+    project_path: Option<&str>,
+    segments: &[TranscriptSegment],
+) -> String {
+    let project = project_note(project_path);
+    let mut md = String::new();
+    let _ = writeln!(md, "---");
+    let _ = writeln!(md, "title: {}", yaml(&digest.title));
+    let _ = writeln!(md, "date: {}", digest.date());
+    let _ = writeln!(md, "duration_minutes: {}", digest.duration_minutes);
+    let _ = writeln!(md, "meeting_id: {}", yaml(&digest.meeting_id));
+    if let Some(project) = &project {
+        let _ = writeln!(md, "project: {}", yaml(&format!("[[{}]]", project)));
+    }
+    if !digest.participants.is_empty() {
+        let _ = writeln!(md, "participants:");
+        for p in &digest.participants {
+            let _ = writeln!(md, "  - {}", yaml(p));
+        }
+    }
+    if let Some(url) = &digest.pr_url {
+        let _ = writeln!(md, "pull_request: {}", yaml(url));
+    }
+    let _ = writeln!(md, "tags:\n  - meeting");
+    let _ = writeln!(md, "---\n");
+
+    let _ = writeln!(md, "# {}\n", digest.title);
+    if let Some(project) = &project {
+        let _ = writeln!(md, "Project: [[{}]]\n", project);
+    }
+    if !digest.action_items.is_empty() {
+        let _ = writeln!(md, "## Action Items");
+        for item in &digest.action_items {
+            let _ = writeln!(md, "- [ ] {}", item);
+        }
+        let _ = writeln!(md);
+    }
+    for (title, items) in [
+        ("Decisions", &digest.decisions),
+        ("Open Questions", &digest.open_questions),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        let _ = writeln!(md, "## {}", title);
+        for item in items {
+            let _ = writeln!(md, "- {}", item);
+        }
+        let _ = writeln!(md);
+    }
+    if let Some(url) = &digest.pr_url {
+        let _ = writeln!(md, "Pull request: {}\n", url);
+    }
+    if !segments.is_empty() {
+        let _ = writeln!(md, "## Transcript Excerpt");
+        for s in segments.iter().take(EXCERPT_SEGMENTS) {
+            let _ = writeln!(
+                md,
+                "> **{}** ({}): {}",
+                s.speaker,
+                timestamp(s.start_time),
+                s.text.trim()
+            );
+            let _ = writeln!(md, ">");
+        }
+        if segments.len() > EXCERPT_SEGMENTS {
+            let _ = writeln!(
+                md,
+                "> _…{} more segments_",
+                segments.len() - EXCERPT_SEGMENTS
+            );
+        } else {
+            // Drop the trailing empty quote line
+            md.truncate(md.len() - ">\n".len());
+        }
+    }
+    md
+}
+
+/// Trim the config and reject a vault that doesn't exist, for the settings commands
+pub fn normalize_config(config: &mut ObsidianConfig) -> Result<(), String> {
+    config.vault_path = config
+        .vault_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(String::from);
+    config.folder = config.folder.trim().trim_matches('/').to_string();
+    if config.folder.split(['/', '\\']).any(|part| part == "..") {
+        return Err("The notes folder must be inside the vault".to_string());
+    }
+    match &config.vault_path {
+        Some(vault) if !Path::new(vault).is_dir() => {
+            Err(format!("Obsidian vault {} does not exist", vault))
+        }
+        None if config.auto_export => Err("Choose an Obsidian vault to export to".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// Folder notes are written to, created if needed
+fn notes_dir(config: &ObsidianConfig) -> Result<PathBuf> {
+    let vault = config
+        .vault_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+        .ok_or_else(|| anyhow!("No Obsidian vault is set"))?;
+    let vault = PathBuf::from(vault);
+    if !vault.is_dir() {
+        return Err(anyhow!("Obsidian vault {} does not exist", vault.display()));
+    }
+    let dir = vault.join(config.folder.trim().trim_matches('/'));
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Write (or overwrite) a meeting's note; returns its path
+pub fn export_meeting(
+    db: &MeetingDb,
+    config: &ObsidianConfig,
+    meeting_id: &str,
+) -> Result<PathBuf> {
+    let dir = notes_dir(config)?;
+    let digest = MeetingDigest::load(db, meeting_id)?;
+    let project_path = db.get_meeting(meeting_id)?.and_then(|m| m.project_path);
+    let segments = db.get_segments(meeting_id)?;
+    let note = render_note(&digest, project_path.as_deref(), &segments);
+    let path = dir.join(format!("{} {}.md", digest.date(), note_name(&digest.title)));
+    std::fs::write(&path, note)?;
+    log::info!("OBSIDIAN wrote meeting {} to {:?}", meeting_id, path);
+    Ok(path)
+}
+
+/// Export a meeting that just ended, if auto-export is on
+pub fn spawn_export_after_meeting(app: &AppHandle, meeting_id: &str) {
+    let config = crate::settings::get_settings(app).obsidian;
+    if !config.auto_export {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    // Summarizing the transcript can take a moment; keep it off the meeting-end path
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = export_meeting(&db, &config, &meeting_id) {
+            log::warn!("OBSIDIAN export of meeting {} failed: {}", meeting_id, e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn segment(speaker: &str, start: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time: start,
+            end_time: start + 5.0,
+            text: text.to_string(),
+            confidence: 1.0,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_render_note() {
+        let digest = MeetingDigest {
+            meeting_id: "m-1".to_string(),
+            title: "Sprint \"planning\"".to_string(),
+            participants: vec!["Alice".to_string()],
+            action_items: vec!["Add CSV export".to_string()],
+            decisions: vec!["Use SQLite".to_string()],
+            ..Default::default()
+        };
+        let note = render_note(
+            &digest,
+            Some("/home/a/projects/billing-api"),
+            &[segment("Alice", 65.0, " Let's ship it ")],
+        );
+        assert!(note.starts_with("---\ntitle: \"Sprint \\\"planning\\\"\"\n"));
+        assert!(note.contains("project: \"[[billing-api]]\"\n"));
+        assert!(note.contains("participants:\n  - \"Alice\"\n"));
+        assert!(note.contains("Project: [[billing-api]]\n"));
+        assert!(note.contains("## Action Items\n- [ ] Add CSV export\n"));
+        assert!(note.contains("## Decisions\n- Use SQLite\n"));
+        assert!(note.ends_with("> **Alice** (01:05): Let's ship it\n"));
+        assert_eq!(note_name("a/b: c?"), "a-b- c-");
+    }
+}
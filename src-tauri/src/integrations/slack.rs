@@ -0,0 +1,311 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.slack";
+const KEYCHAIN_ACCOUNT: &str = "credentials";
+const FALLBACK_FILE: &str = ".slack-credentials";
+const POST_MESSAGE_URL: &str = "https://slack.com/api/chat.postMessage";
+const WEBHOOK_PREFIX: &str = "https://hooks.slack.com/";
+/// Slack rejects section text longer than this
+const MAX_SECTION_CHARS: usize = 3000;
+/// Waits before each retry of a failed post
+const RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_secs(2),
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+];
+/// Longest `Retry-After` from a rate-limited post that is waited out
+const MAX_RETRY_AFTER_SECS: u64 = 60;
+
+/// A bot token (`xoxb-...`, posts to any channel the bot is in) or an incoming webhook
+/// URL (always posts to the channel it was created for)
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct SlackCredentials {
+    pub bot_token: Option<String>,
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SlackPostResult {
+    /// The channel posted to, when known (webhooks don't report it)
+    pub channel: Option<String>,
+    /// Message timestamp, Slack's message id; bot tokens only
+    pub ts: Option<String>,
+}
+
+/// Check and store Slack credentials in the keyring, falling back to a file
+pub fn store_credentials(credentials: &SlackCredentials) -> Result<()> {
+    match (&credentials.bot_token, &credentials.webhook_url) {
+        (None, None) => return Err(anyhow!("Provide a bot token or an incoming webhook URL")),
+        (Some(token), _) if !token.starts_with("xox") => {
+            return Err(anyhow!("Slack bot tokens start with xoxb-"))
+        }
+        (_, Some(url)) if !url.starts_with(WEBHOOK_PREFIX) => {
+            return Err(anyhow!("Webhook URLs start with {}", WEBHOOK_PREFIX))
+        }
+        _ => {}
+    }
+    let raw = serde_json::to_string(credentials)?;
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, &raw)
+}
+
+fn load_credentials() -> Result<SlackCredentials> {
+    let raw = secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map_err(|_| anyhow!("Slack is not connected"))?;
+    Ok(serde_json::from_str(&raw)?)
+}
+
+pub fn has_credentials() -> bool {
+    load_credentials().is_ok()
+}
+
+/// Remove stored Slack credentials
+pub fn clear_credentials() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Escape the characters Slack's mrkdwn treats as control sequences
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn truncate(text: String, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text;
+    }
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Block Kit message: header, date and duration, one section per list, and a button
+/// linking the pull request
+pub fn blocks(digest: &MeetingDigest) -> Value {
+    let mut blocks = vec![
+        json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": truncate(digest.title.clone(), 150) },
+        }),
+        json!({
+            "type": "context",
+            "elements": [{
+                "type": "mrkdwn",
+                "text": format!("{} · {} min", digest.date(), digest.duration_minutes),
+            }],
+        }),
+    ];
+    for (title, items) in digest.sections() {
+        let list: Vec<String> = items.iter().map(|i| format!("• {}", escape(i))).collect();
+        blocks.push(json!({
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": truncate(format!("*{}*\n{}", title, list.join("\n")), MAX_SECTION_CHARS),
+            },
+        }));
+    }
+    if digest.sections().is_empty() {
+        blocks.push(json!({
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "_No action items, decisions, or questions were captured._" },
+        }));
+    }
+    if let Some(url) = &digest.pr_url {
+        blocks.push(json!({
+            "type": "actions",
+            "elements": [{
+                "type": "button",
+                "text": { "type": "plain_text", "text": "View pull request" },
+                "url": url,
+            }],
+        }));
+    }
+    Value::Array(blocks)
+}
+
+/// Post a meeting's digest, retrying with backoff while Slack is unreachable, rate
+/// limiting, or failing on its side. `channel` is required with a bot token and ignored
+/// by webhooks.
+pub async fn post_digest(digest: &MeetingDigest, channel: Option<&str>) -> Result<SlackPostResult> {
+    let credentials = load_credentials()?;
+    let channel = channel.filter(|c| !c.trim().is_empty());
+    if credentials.bot_token.is_some() && channel.is_none() {
+        return Err(anyhow!("Choose a Slack channel to post to"));
+    }
+    // Shown in notifications and by clients that can't render blocks
+    let fallback_text = format!("Meeting summary: {}", digest.title);
+    let message = match channel.filter(|_| credentials.bot_token.is_some()) {
+        Some(channel) => {
+            json!({ "channel": channel, "text": fallback_text, "blocks": blocks(digest) })
+        }
+        None => json!({ "text": fallback_text, "blocks": blocks(digest) }),
+    };
+    let mut attempt = 0;
+    loop {
+        match post_once(&credentials, &message, attempt).await {
+            Ok(result) => return Ok(result),
+            Err((e, Some(wait))) => {
+                log::warn!(
+                    "SLACK post failed (attempt {}), retrying in {}s: {}",
+                    attempt + 1,
+                    wait.as_secs(),
+                    e
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+            }
+            Err((e, None)) => return Err(e),
+        }
+    }
+}
+
+/// How long to wait before retry number `attempt + 1`, or `None` when the failure won't
+/// go away by itself. `status` is `None` when Slack couldn't be reached.
+fn retry_delay(attempt: usize, status: Option<u16>, retry_after: Option<&str>) -> Option<Duration> {
+    let backoff = *RETRY_DELAYS.get(attempt)?;
+    match status {
+        None => Some(backoff),
+        Some(429) => Some(
+            retry_after
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(|secs| Duration::from_secs(secs.min(MAX_RETRY_AFTER_SECS)))
+                .unwrap_or(backoff),
+        ),
+        Some(status) if status >= 500 => Some(backoff),
+        Some(_) => None,
+    }
+}
+
+/// Send one message; on failure, returns the wait before retrying if a retry could help
+async fn post_once(
+    credentials: &SlackCredentials,
+    message: &Value,
+    attempt: usize,
+) -> Result<SlackPostResult, (anyhow::Error, Option<Duration>)> {
+    let url = match (&credentials.bot_token, &credentials.webhook_url) {
+        (Some(_), _) => POST_MESSAGE_URL,
+        (None, Some(url)) => url.as_str(),
+        (None, None) => return Err((anyhow!("Slack is not connected"), None)),
+    };
+    let mut request = network::client(Service::Slack, url)
+        .map_err(|e| (e, None))?
+        .post(url)
+        .json(message);
+    if let Some(token) = &credentials.bot_token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.map_err(|e| {
+        (
+            anyhow::Error::new(e).context("Failed to reach Slack"),
+            retry_delay(attempt, None, None),
+        )
+    })?;
+    let status = response.status();
+    if !status.is_success() {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().await.unwrap_or_default();
+        return Err((
+            anyhow!("Slack returned {}: {}", status, body.trim()),
+            retry_delay(attempt, Some(status.as_u16()), retry_after.as_deref()),
+        ));
+    }
+    if credentials.bot_token.is_none() {
+        // Webhooks answer a bare "ok" and don't say where the message went
+        return Ok(SlackPostResult {
+            channel: None,
+            ts: None,
+        });
+    }
+    let response: Value = response.json().await.map_err(|e| (e.into(), None))?;
+    if response["ok"].as_bool() != Some(true) {
+        return Err((
+            anyhow!(
+                "Slack rejected the message: {}",
+                response["error"].as_str().unwrap_or("unknown error")
+            ),
+            None,
+        ));
+    }
+    Ok(SlackPostResult {
+        channel: response["channel"].as_str().map(String::from),
+        ts: response["ts"].as_str().map(String::from),
+    })
+}
+
+/// Post the summary of a meeting that just ended, if auto-post is on
+pub fn spawn_post_after_meeting(app: &AppHandle, meeting_id: &str) {
+    let config = crate::settings::get_settings(app).slack;
+    if !config.auto_post || !has_credentials() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => post_digest(&digest, Some(&config.channel)).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(_) => log::info!("SLACK posted summary of meeting {}", meeting_id),
+            Err(e) => log::warn!("SLACK auto-post for meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_include_sections_and_pr_button() {
+        let digest = MeetingDigest {
+            title: "Planning".to_string(),
+            duration_minutes: 30,
+            action_items: vec!["Add <export> & import".to_string()],
+            decisions: vec!["Use SQLite".to_string()],
+            pr_url: Some("https://github.com/o/r/pull/7".to_string()),
+            ..Default::default()
+        };
+        let blocks = blocks(&digest);
+        let blocks = blocks.as_array().unwrap();
+        assert_eq!(blocks[0]["text"]["text"], "Planning");
+        // header, context, two sections, actions
+        assert_eq!(blocks.len(), 5);
+        let action_items = blocks[2]["text"]["text"].as_str().unwrap();
+        assert!(action_items.starts_with("*Action items*"));
+        assert!(action_items.contains("Add &lt;export&gt; &amp; import"));
+        assert_eq!(
+            blocks[4]["elements"][0]["url"],
+            "https://github.com/o/r/pull/7"
+        );
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0, None, None), Some(RETRY_DELAYS[0]));
+        assert_eq!(retry_delay(1, Some(503), None), Some(RETRY_DELAYS[1]));
+        assert_eq!(retry_delay(0, Some(429), Some("7")), Some(Duration::from_secs(7)));
+        assert_eq!(retry_delay(0, Some(429), Some("3600")), Some(Duration::from_secs(60)));
+        // Bad webhook URLs and revoked tokens aren't retried
+        assert_eq!(retry_delay(0, Some(404), None), None);
+        assert_eq!(retry_delay(RETRY_DELAYS.len(), None, None), None);
+    }
+}
@@ -0,0 +1,164 @@
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::storage::db::MeetingDb;
+
+const KEYCHAIN_SERVICE: &str = "com.handy.teams";
+const KEYCHAIN_ACCOUNT: &str = "webhook";
+const FALLBACK_FILE: &str = ".teams-webhook";
+
+/// Store the channel's incoming webhook (or Workflows) URL. The URL is the credential,
+/// so it goes in the keyring rather than settings.
+pub fn store_webhook(url: &str) -> Result<()> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| anyhow!("Invalid webhook URL: {}", e))?;
+    if parsed.scheme() != "https" {
+        return Err(anyhow!("Teams webhook URLs start with https://"));
+    }
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, url)
+}
+
+fn load_webhook() -> Result<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map_err(|_| anyhow!("No Teams webhook is set"))
+}
+
+pub fn has_webhook() -> bool {
+    load_webhook().is_ok()
+}
+
+/// Remove the stored webhook URL
+pub fn clear_webhook() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Adaptive Card message: title, date and duration, one bulleted block per list, and an
+/// action opening the pull request
+pub fn card(digest: &MeetingDigest) -> Value {
+    let mut body = vec![
+        json!({
+            "type": "TextBlock",
+            "text": digest.title,
+            "size": "Large",
+            "weight": "Bolder",
+            "wrap": true,
+        }),
+        json!({
+            "type": "TextBlock",
+            "text": format!("{} · {} min", digest.date(), digest.duration_minutes),
+            "isSubtle": true,
+            "spacing": "None",
+        }),
+    ];
+    for (title, items) in digest.sections() {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": title,
+            "weight": "Bolder",
+            "separator": true,
+        }));
+        let list: Vec<String> = items.iter().map(|i| format!("- {}", i)).collect();
+        body.push(json!({ "type": "TextBlock", "text": list.join("\n"), "wrap": true }));
+    }
+    if digest.sections().is_empty() {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": "No action items, decisions, or questions were captured.",
+            "isSubtle": true,
+            "wrap": true,
+        }));
+    }
+    let actions: Vec<Value> = digest
+        .pr_url
+        .iter()
+        .map(|url| json!({ "type": "Action.OpenUrl", "title": "View pull request", "url": url }))
+        .collect();
+
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "body": body,
+                "actions": actions,
+            },
+        }],
+    })
+}
+
+/// Post a meeting's digest to the configured channel
+pub async fn post_digest(digest: &MeetingDigest) -> Result<()> {
+    let url = load_webhook()?;
+    let response = network::client(Service::Teams, &url)?
+        .post(&url)
+        .json(&card(digest))
+        .send()
+        .await
+        .context("Failed to reach Teams")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!(
+            "Teams webhook returned {}: {}",
+            status,
+            body.trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Post the summary of a meeting that just ended, if auto-post is on
+pub fn spawn_post_after_meeting(app: &AppHandle, meeting_id: &str) {
+    if !crate::settings::get_settings(app).teams.auto_post || !has_webhook() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => post_digest(&digest).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => log::info!("TEAMS posted summary of meeting {}", meeting_id),
+            Err(e) => log::warn!("TEAMS auto-post for meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_layout() {
+        let digest = MeetingDigest {
+            title: "Planning".to_string(),
+            open_questions: vec!["Who owns billing?".to_string()],
+            pr_url: Some("https://github.com/o/r/pull/7".to_string()),
+            ..Default::default()
+        };
+        let card = card(&digest);
+        let content = &card["attachments"][0]["content"];
+        assert_eq!(content["type"], "AdaptiveCard");
+        // title, date, section title, section items
+        assert_eq!(content["body"].as_array().unwrap().len(), 4);
+        assert_eq!(content["body"][2]["text"], "Open questions");
+        assert_eq!(content["body"][3]["text"], "- Who owns billing?");
+        assert_eq!(
+            content["actions"][0]["url"],
+            "https://github.com/o/r/pull/7"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Trello cards from meeting action items and PRD user stories. Each kind goes to the list
+//! chosen for it in settings. Card ids are recorded in the meeting database, which both
+//! links items to their cards and keeps a second push from duplicating them.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::document_generation::PRDContent;
+use crate::integrations::digest::MeetingDigest;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::TrelloConfig;
+use crate::storage::db::{ExternalLink, MeetingDb};
+
+const KEYCHAIN_SERVICE: &str = "com.handy.trello";
+const KEYCHAIN_ACCOUNT: &str = "credentials";
+const FALLBACK_FILE: &str = ".trello-credentials";
+const API_BASE: &str = "https://api.trello.com/1";
+/// `service` of the cards' rows in the external links table
+const LINK_SERVICE: &str = "trello";
+/// Trello rejects descriptions longer than this
+const MAX_DESC_CHARS: usize = 16384;
+
+/// An API key and a token the user generated for it
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TrelloCredentials {
+    pub api_key: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrelloList {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrelloPushResult {
+    pub created: usize,
+    /// Items that already had a card from an earlier push
+    pub already_pushed: usize,
+    /// Every card of the meeting, including earlier ones
+    pub cards: Vec<ExternalLink>,
+}
+
+/// A card to create for a meeting item
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardDraft {
+    /// Action item feature id or user story id
+    pub item_id: String,
+    pub name: String,
+    pub desc: String,
+}
+
+/// Store the API key and token in the keyring, falling back to a file
+pub fn store_credentials(credentials: &TrelloCredentials) -> Result<()> {
+    if credentials.api_key.trim().is_empty() || credentials.token.trim().is_empty() {
+        return Err(anyhow!("Both a Trello API key and token are required"));
+    }
+    let raw = serde_json::to_string(credentials)?;
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, &raw)
+}
+
+fn load_credentials() -> Result<TrelloCredentials> {
+    let raw = secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .map_err(|_| anyhow!("Trello is not connected"))?;
+    serde_json::from_str(&raw).context("Stored Trello credentials are unreadable")
+}
+
+pub fn has_credentials() -> bool {
+    load_credentials().is_ok()
+}
+
+/// Remove the stored API key and token
+pub fn clear_credentials() -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE);
+    Ok(())
+}
+
+/// Trello accepts the key and token as an OAuth header, which keeps them out of URLs
+fn auth_header(credentials: &TrelloCredentials) -> String {
+    format!(
+        "OAuth oauth_consumer_key=\"{}\", oauth_token=\"{}\"",
+        credentials.api_key.trim(),
+        credentials.token.trim()
+    )
+}
+
+async fn check_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Trello returned {}: {}", status, body.trim()));
+    }
+    Ok(response.json().await?)
+}
+
+/// Open lists on a board, for choosing where each kind of card goes
+pub async fn board_lists(board_id: &str) -> Result<Vec<TrelloList>> {
+    let credentials = load_credentials()?;
+    let url = format!("{}/boards/{}/lists", API_BASE, board_id.trim());
+    let response = network::client(Service::Trello, &url)?
+        .get(&url)
+        .header("Authorization", auth_header(&credentials))
+        .query(&[("filter", "open"), ("fields", "name")])
+        .send()
+        .await
+        .context("Failed to reach Trello")?;
+    Ok(serde_json::from_value(check_response(response).await?)?)
+}
+
+fn truncate(text: String) -> String {
+    text.chars().take(MAX_DESC_CHARS).collect()
+}
+
+/// One card per action item, describing who raised it and where
+pub fn action_item_cards(digest: &MeetingDigest) -> Vec<CardDraft> {
+    digest
+        .action_item_details
+        .iter()
+        .map(|item| CardDraft {
+            item_id: item.id.clone(),
+            name: item.title.clone(),
+            desc: truncate(format!(
+                "{}\n\n{}",
+                item.description,
+                digest.item_context(item)
+            )),
+        })
+        .collect()
+}
+
+/// One card per user story in the PRD, named after what the persona wants
+pub fn user_story_cards(meeting_id: &str, version: u32, content: &PRDContent) -> Vec<CardDraft> {
+    content
+        .user_stories
+        .iter()
+        .map(|story| CardDraft {
+            item_id: story.id.clone(),
+            name: format!("{}: {}", story.id, story.want),
+            desc: truncate(format!(
+                "As a {}, I want to {}, so that {}.\n\nPriority: {}\nStatus: {}\n\n\
+                 From PRD v{}, meetingcoder://open-meeting/{}",
+                story.persona,
+                story.want,
+                story.so_that,
+                story.priority,
+                story.status,
+                version,
+                meeting_id
+            )),
+        })
+        .collect()
+}
+
+async fn create_card(
+    credentials: &TrelloCredentials,
+    list_id: &str,
+    card: &CardDraft,
+) -> Result<(String, Option<String>)> {
+    let url = format!("{}/cards", API_BASE);
+    let response = network::client(Service::Trello, &url)?
+        .post(&url)
+        .header("Authorization", auth_header(credentials))
+        .form(&[
+            ("idList", list_id),
+            ("name", card.name.as_str()),
+            ("desc", card.desc.as_str()),
+            ("pos", "bottom"),
+        ])
+        .send()
+        .await
+        .context("Failed to reach Trello")?;
+    let value = check_response(response).await?;
+    let id = value["id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Trello response had no card id"))?;
+    Ok((id.to_string(), value["shortUrl"].as_str().map(String::from)))
+}
+
+/// Create cards in `list_id` for the drafts that don't have one yet
+pub async fn push_cards(
+    db: &MeetingDb,
+    meeting_id: &str,
+    list_id: &str,
+    cards: &[CardDraft],
+) -> Result<TrelloPushResult> {
+    if list_id.trim().is_empty() {
+        return Err(anyhow!("Choose a Trello list for these cards"));
+    }
+    let credentials = load_credentials()?;
+    let existing = db.list_external_links(meeting_id, LINK_SERVICE)?;
+    let mut result = TrelloPushResult {
+        created: 0,
+        already_pushed: 0,
+        cards: Vec::new(),
+    };
+    for card in cards {
+        if existing.iter().any(|l| l.item_id == card.item_id) {
+            result.already_pushed += 1;
+            continue;
+        }
+        let (id, url) = create_card(&credentials, list_id.trim(), card).await?;
+        // Recorded per card, so a failure part-way through doesn't duplicate on retry
+        db.save_external_link(meeting_id, LINK_SERVICE, &card.item_id, &id, url.as_deref())?;
+        result.created += 1;
+    }
+    result.cards = meeting_cards(db, meeting_id)?;
+    Ok(result)
+}
+
+/// Cards created from a meeting's items, oldest first
+pub fn meeting_cards(db: &MeetingDb, meeting_id: &str) -> Result<Vec<ExternalLink>> {
+    db.list_external_links(meeting_id, LINK_SERVICE)
+}
+
+/// Push the action items of a meeting that just ended, if auto-push is on
+pub fn spawn_push_after_meeting(app: &AppHandle, meeting_id: &str) {
+    let config = crate::settings::get_settings(app).trello;
+    if !config.auto_push || config.action_items_list_id.trim().is_empty() || !has_credentials() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    let meeting_id = meeting_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match MeetingDigest::load(&db, &meeting_id) {
+            Ok(digest) => {
+                let cards = action_item_cards(&digest);
+                push_cards(&db, &meeting_id, &config.action_items_list_id, &cards).await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(r) => log::info!(
+                "TRELLO created {} cards for meeting {} ({} already pushed)",
+                r.created,
+                meeting_id,
+                r.already_pushed
+            ),
+            Err(e) => log::warn!("TRELLO push for meeting {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document_generation::UserStory;
+
+    #[test]
+    fn test_user_story_cards() {
+        let content = PRDContent {
+            user_stories: vec![UserStory {
+                id: "US-001".to_string(),
+                persona: "project manager".to_string(),
+                want: "export meetings as CSV".to_string(),
+                so_that: "I can share them".to_string(),
+                priority: "high".to_string(),
+                status: "planned".to_string(),
+                mentioned_at: vec![3],
+            }],
+            ..Default::default()
+        };
+        let cards = user_story_cards("m-1", 2, &content);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].item_id, "US-001");
+        assert_eq!(cards[0].name, "US-001: export meetings as CSV");
+        assert!(cards[0]
+            .desc
+            .starts_with("As a project manager, I want to export meetings as CSV"));
+        assert!(cards[0]
+            .desc
+            .ends_with("From PRD v2, meetingcoder://open-meeting/m-1"));
+    }
+}
@@ -0,0 +1,350 @@
+//! Outgoing webhooks for Zapier, n8n, and similar tools. Each event is POSTed as JSON to
+//! the endpoints subscribed to it:
+//!
+//! ```json
+//! { "id": "<delivery id>", "event": "meeting.ended", "created_at": "...", "data": { ... } }
+//! ```
+//!
+//! Requests carry `X-MeetingCoder-Timestamp` (Unix seconds) and
+//! `X-MeetingCoder-Signature: sha256=<hex>`, an HMAC-SHA256 of `"{timestamp}.{body}"`
+//! with the signing secret, so receivers can check a request came from this app.
+//! Failed deliveries are retried with backoff, and every delivery is logged in the
+//! meeting database.
+
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+
+use crate::managers::meeting::MeetingSession;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::{WebhookEndpoint, WebhookEvent, WebhooksConfig};
+use crate::storage::db::{MeetingDb, WebhookDelivery};
+
+const KEYCHAIN_SERVICE: &str = "com.handy.webhooks";
+const KEYCHAIN_ACCOUNT: &str = "signing-secret";
+const FALLBACK_FILE: &str = ".webhook-secret";
+/// Waits before each retry; a delivery gets one attempt more than there are waits
+const RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(300),
+];
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Deliveries kept in the log; older ones are dropped first
+const LOG_CAPACITY: usize = 500;
+
+impl WebhookEvent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebhookEvent::MeetingStarted => "meeting.started",
+            WebhookEvent::MeetingEnded => "meeting.ended",
+            WebhookEvent::SummaryReady => "summary.ready",
+            WebhookEvent::PrdVersion => "prd.version",
+            WebhookEvent::PrCreated => "pr.created",
+        }
+    }
+}
+
+fn store_secret(secret: &str) -> Result<()> {
+    secrets::store(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE, secret)
+}
+
+fn load_secret() -> Option<String> {
+    secrets::load(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT, FALLBACK_FILE)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// The secret requests are signed with, created on first use
+pub fn signing_secret() -> Result<String> {
+    match load_secret() {
+        Some(secret) => Ok(secret),
+        None => regenerate_secret(),
+    }
+}
+
+/// Replace the signing secret; receivers must be updated to keep verifying requests
+pub fn regenerate_secret() -> Result<String> {
+    let secret = format!(
+        "whsec_{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    store_secret(&secret)?;
+    Ok(secret)
+}
+
+/// `sha256=<hex>` of `"{timestamp}.{body}"`
+pub fn signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("sha256={}", hex)
+}
+
+/// Check endpoint URLs and give new endpoints an id, for the settings commands
+pub fn normalize_config(config: &mut WebhooksConfig) -> Result<(), String> {
+    for endpoint in &mut config.endpoints {
+        endpoint.url = endpoint.url.trim().to_string();
+        endpoint.name = endpoint.name.trim().to_string();
+        let parsed = reqwest::Url::parse(&endpoint.url)
+            .map_err(|e| format!("Invalid webhook URL {}: {}", endpoint.url, e))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(format!("Webhook URLs must be http(s): {}", endpoint.url));
+        }
+        if endpoint.id.trim().is_empty() {
+            endpoint.id = uuid::Uuid::new_v4().to_string();
+        }
+        let mut seen = std::collections::HashSet::new();
+        endpoint.events.retain(|e| seen.insert(*e));
+    }
+    Ok(())
+}
+
+/// Whether a failed attempt is worth repeating: no response, server errors, timeouts,
+/// and rate limiting
+fn is_retryable(status: Option<u16>) -> bool {
+    match status {
+        None => true,
+        Some(code) => code >= 500 || code == 408 || code == 429,
+    }
+}
+
+/// One POST; the response status when there was one
+async fn attempt(delivery: &WebhookDelivery) -> (Option<u16>, Result<()>) {
+    let client = match network::client(Service::Webhooks, &delivery.url) {
+        Ok(client) => client,
+        Err(e) => return (None, Err(e)),
+    };
+    let secret = match signing_secret() {
+        Ok(secret) => secret,
+        Err(e) => return (None, Err(e)),
+    };
+    let timestamp = chrono::Utc::now().timestamp();
+    let result = client
+        .post(&delivery.url)
+        .timeout(REQUEST_TIMEOUT)
+        .header("Content-Type", "application/json")
+        .header("User-Agent", "MeetingCoder-Webhooks")
+        .header("X-MeetingCoder-Event", &delivery.event)
+        .header("X-MeetingCoder-Delivery", &delivery.id)
+        .header("X-MeetingCoder-Timestamp", timestamp.to_string())
+        .header(
+            "X-MeetingCoder-Signature",
+            signature(&secret, timestamp, &delivery.payload),
+        )
+        .body(delivery.payload.clone())
+        .send()
+        .await;
+    match result {
+        Ok(response) if response.status().is_success() => {
+            (Some(response.status().as_u16()), Ok(()))
+        }
+        Ok(response) => {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            let body: String = body.trim().chars().take(300).collect();
+            (
+                Some(status.as_u16()),
+                Err(anyhow!("Endpoint returned {}: {}", status, body)),
+            )
+        }
+        Err(e) => (None, Err(anyhow!("Request failed: {}", e))),
+    }
+}
+
+/// Attempt a logged delivery until it succeeds, fails permanently, or runs out of retries
+async fn deliver(db: Arc<MeetingDb>, mut delivery: WebhookDelivery) {
+    loop {
+        let (status, result) = attempt(&delivery).await;
+        delivery.attempts += 1;
+        let offline = result
+            .as_ref()
+            .err()
+            .is_some_and(|e| e.downcast_ref::<network::OfflineError>().is_some());
+        let retry = result.is_err()
+            && !offline
+            && is_retryable(status)
+            && (delivery.attempts as usize) <= RETRY_DELAYS.len();
+        let state = match (&result, retry) {
+            (Ok(()), _) => "delivered",
+            (Err(_), true) => "pending",
+            (Err(_), false) => "failed",
+        };
+        let error = result.as_ref().err().map(|e| e.to_string());
+        if let Err(e) = db.update_webhook_delivery(
+            &delivery.id,
+            state,
+            delivery.attempts,
+            status,
+            error.as_deref(),
+        ) {
+            log::warn!("WEBHOOKS failed to log delivery {}: {}", delivery.id, e);
+        }
+        if !retry {
+            match error {
+                None => log::info!("WEBHOOKS delivered {} to {}", delivery.event, delivery.url),
+                Some(e) => log::warn!(
+                    "WEBHOOKS {} to {} failed after {} attempts: {}",
+                    delivery.event,
+                    delivery.url,
+                    delivery.attempts,
+                    e
+                ),
+            }
+            return;
+        }
+        tokio::time::sleep(RETRY_DELAYS[delivery.attempts as usize - 1]).await;
+    }
+}
+
+/// `data` of the meeting events
+pub fn meeting_data(meeting: &MeetingSession) -> Value {
+    let time = |t: SystemTime| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339();
+    json!({
+        "meeting_id": meeting.id,
+        "name": meeting.name,
+        "started_at": time(meeting.start_time),
+        "ended_at": meeting.end_time.map(time),
+        "participants": meeting.participants,
+        "segment_count": meeting.transcript_segments.len(),
+        "project_path": meeting.project_path,
+    })
+}
+
+/// The JSON body sent for an event
+pub fn payload(delivery_id: &str, event: WebhookEvent, data: &Value) -> Value {
+    json!({
+        "id": delivery_id,
+        "event": event.as_str(),
+        "created_at": chrono::Utc::now().to_rfc3339(),
+        "data": data,
+    })
+}
+
+fn start_delivery(db: &Arc<MeetingDb>, endpoint: &WebhookEndpoint, event: &str, body: String) {
+    let now = chrono::Utc::now().timestamp_millis();
+    let delivery = WebhookDelivery {
+        id: uuid::Uuid::new_v4().to_string(),
+        endpoint_id: endpoint.id.clone(),
+        url: endpoint.url.clone(),
+        event: event.to_string(),
+        payload: body,
+        status: "pending".to_string(),
+        attempts: 0,
+        response_status: None,
+        error: None,
+        created_at: now,
+        updated_at: now,
+    };
+    if let Err(e) = db.insert_webhook_delivery(&delivery) {
+        log::warn!("WEBHOOKS failed to log delivery: {}", e);
+    }
+    let _ = db.prune_webhook_deliveries(LOG_CAPACITY);
+    tauri::async_runtime::spawn(deliver(db.clone(), delivery));
+}
+
+/// Send an event to every enabled endpoint subscribed to it
+pub fn dispatch(app: &AppHandle, event: WebhookEvent, data: Value) {
+    let endpoints: Vec<WebhookEndpoint> = crate::settings::get_settings(app)
+        .webhooks
+        .endpoints
+        .into_iter()
+        .filter(|e| e.enabled && e.events.contains(&event))
+        .collect();
+    if endpoints.is_empty() {
+        return;
+    }
+    let Some(db) = app.try_state::<Arc<MeetingDb>>().map(|s| s.inner().clone()) else {
+        return;
+    };
+    for endpoint in &endpoints {
+        // A delivery id per endpoint, so receivers can de-duplicate retries
+        let id = uuid::Uuid::new_v4().to_string();
+        let body = payload(&id, event, &data).to_string();
+        start_delivery(&db, endpoint, event.as_str(), body);
+    }
+}
+
+/// Send a logged delivery again as a new delivery, with the same body
+pub fn redeliver(app: &AppHandle, delivery_id: &str) -> Result<()> {
+    let db = app
+        .try_state::<Arc<MeetingDb>>()
+        .map(|s| s.inner().clone())
+        .ok_or_else(|| anyhow!("Meeting database is not available"))?;
+    let original = db
+        .get_webhook_delivery(delivery_id)?
+        .ok_or_else(|| anyhow!("Webhook delivery {} not found", delivery_id))?;
+    let endpoint = WebhookEndpoint {
+        id: original.endpoint_id,
+        url: original.url,
+        ..Default::default()
+    };
+    start_delivery(&db, &endpoint, &original.event, original.payload);
+    Ok(())
+}
+
+/// Send a `ping` event to one endpoint, whatever it is subscribed to
+pub fn send_test(app: &AppHandle, endpoint_id: &str) -> Result<()> {
+    let endpoint = crate::settings::get_settings(app)
+        .webhooks
+        .endpoints
+        .into_iter()
+        .find(|e| e.id == endpoint_id)
+        .ok_or_else(|| anyhow!("Webhook endpoint {} not found", endpoint_id))?;
+    let db = app
+        .try_state::<Arc<MeetingDb>>()
+        .map(|s| s.inner().clone())
+        .ok_or_else(|| anyhow!("Meeting database is not available"))?;
+    let id = uuid::Uuid::new_v4().to_string();
+    let body = json!({
+        "id": id,
+        "event": "ping",
+        "created_at": chrono::Utc::now().to_rfc3339(),
+        "data": { "endpoint": endpoint.name },
+    });
+    start_delivery(&db, &endpoint, "ping", body.to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_and_retry_policy() {
+        // HMAC-SHA256("secret", "1700000000.{}")
+        assert_eq!(
+            signature("secret", 1_700_000_000, "{}"),
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+        assert!(is_retryable(None));
+        assert!(is_retryable(Some(503)));
+        assert!(is_retryable(Some(429)));
+        assert!(!is_retryable(Some(404)));
+
+        let value = payload(
+            "d-1",
+            WebhookEvent::MeetingEnded,
+            &json!({ "meeting_id": "m-1" }),
+        );
+        assert_eq!(value["event"], "meeting.ended");
+        assert_eq!(value["data"]["meeting_id"], "m-1");
+        assert_eq!(
+            serde_json::to_value(WebhookEvent::PrCreated).unwrap(),
+            WebhookEvent::PrCreated.as_str()
+        );
+    }
+}
@@ -1,22 +1,36 @@
 mod actions;
 mod audio_feedback;
+mod cli;
 pub mod audio_toolkit;
 mod clipboard;
 mod commands;
+mod deep_link;
+mod events;
+mod focus_mode;
+mod logging;
 mod managers;
+mod metrics;
+mod network;
+mod notifications;
 mod overlay;
 mod settings;
+mod settings_transfer;
+mod settings_update;
 mod shortcut;
 mod storage;
 mod project;
+mod redaction;
+mod secrets;
 mod meeting;
 mod summarization;
 mod system_audio;
 mod tray;
 mod utils;
+mod watch_folder;
 mod automation;
 mod integrations;
 mod codebase;
+mod diarization;
 mod queue;
 mod workers;
 mod document_generation;
@@ -66,6 +80,23 @@ fn show_main_window(app: &AppHandle) {
 }
 
 fn initialize_core_logic(app_handle: &AppHandle) {
+    // Resolve the storage root before any manager touches meeting or project paths
+    storage::root::set_storage_root(
+        settings::get_settings(app_handle).storage_root.map(std::path::PathBuf::from),
+    );
+    match logging::log_dir(app_handle) {
+        Ok(dir) => {
+            logging::attach_file(&dir, settings::get_settings(app_handle).log_level.into())
+        }
+        Err(e) => log::warn!("No log directory, logging to the console only: {}", e),
+    }
+    // Offline mode has to hold before anything below can reach the network
+    network::init(app_handle);
+    summarization::llm::apply_settings(&settings::get_settings(app_handle));
+    redaction::apply_settings(&settings::get_settings(app_handle));
+    managers::transcription::apply_settings(&settings::get_settings(app_handle));
+    managers::audio::apply_settings(app_handle, &settings::get_settings(app_handle));
+
     // First, initialize the managers
     let recording_manager = Arc::new(
         AudioRecordingManager::new(app_handle).expect("Failed to initialize recording manager"),
@@ -85,11 +116,43 @@ fn initialize_core_logic(app_handle: &AppHandle) {
 
     // Initialize durable audio queue and ASR worker(s)
     let queue = queue::Queue::new(app_handle).expect("Failed to initialize audio queue");
-    app_handle.manage(queue.clone());
-    let worker_count = settings::get_settings(app_handle).queue_worker_count.clamp(1, 8);
-    for _ in 0..worker_count {
-        workers::asr_worker::spawn(queue.clone(), meeting_manager.clone(), transcription_manager.clone(), app_handle.clone());
+    // Pick up work left over from the previous run before any worker claims jobs
+    match queue.recover() {
+        Ok(report) if !report.meetings.is_empty() || report.missing_files > 0 => {
+            log::info!(
+                "Recovered audio queue: {} interrupted job(s) requeued, {} missing segment file(s), {} meeting(s) pending",
+                report.requeued,
+                report.missing_files,
+                report.meetings.len()
+            );
+            events::emit(app_handle, events::AppEvent::QueueRecovered(report));
+        }
+        Ok(_) => {}
+        Err(e) => log::error!("Failed to recover audio queue: {}", e),
     }
+    app_handle.manage(queue.clone());
+    let meeting_db = storage::db::MeetingDb::new(app_handle).expect("Failed to initialize meeting database");
+    app_handle.manage(meeting_db);
+    let speaker_db = storage::speakers::SpeakerDb::new(app_handle)
+        .expect("Failed to initialize speaker database");
+    app_handle.manage(speaker_db);
+    storage::retention::spawn_retention_task(app_handle.clone());
+    // Idle until an import needs it; the model loads on first use
+    let import_transcription_manager = Arc::new(
+        TranscriptionManager::new(app_handle, model_manager.clone())
+            .expect("Failed to initialize import transcription manager"),
+    );
+    app_handle.manage(managers::transcription::ImportTranscriptionManager(
+        import_transcription_manager,
+    ));
+    // Pools start at their configured size and then scale with the backlog
+    let worker_pools = workers::asr_worker::WorkerPools::start(
+        queue.clone(),
+        meeting_manager.clone(),
+        transcription_manager.clone(),
+        app_handle.clone(),
+    );
+    app_handle.manage(worker_pools);
 
     // Add managers to Tauri's managed state
     app_handle.manage(recording_manager.clone());
@@ -97,6 +160,10 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(transcription_manager.clone());
     app_handle.manage(history_manager.clone());
     app_handle.manage(meeting_manager.clone());
+    meeting_manager.detect_interrupted_meetings();
+
+    // Start the localhost API if the user enabled it
+    integrations::local_api::restart(app_handle);
 
     // Initialize the shortcuts
     shortcut::init_shortcuts(app_handle);
@@ -135,6 +202,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 show_main_window(app);
                 let _ = app.emit("check-for-updates", ());
             }
+            id @ ("meeting_start" | "meeting_pause" | "meeting_resume" | "meeting_end") => {
+                tray::handle_meeting_menu_event(app, id);
+            }
             "cancel" => {
                 use crate::utils::cancel_current_operation;
 
@@ -152,6 +222,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
 
     // Initialize tray menu with idle state
     utils::update_tray_menu(app_handle, &utils::TrayIconState::Idle);
+    tray::spawn_meeting_menu_refresher(app_handle.clone());
+    integrations::calendar::spawn_auto_start(app_handle.clone());
+    watch_folder::spawn(app_handle.clone());
 
     // Get the autostart manager and configure based on user setting
     let autostart_manager = app_handle.autolaunch();
@@ -178,12 +251,31 @@ fn trigger_update_check(app: AppHandle) -> Result<(), String> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    env_logger::init();
+    logging::init();
+
+    let cli_args = match cli::CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("meetingcoder: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if cli_args.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
 
     tauri::Builder::default()
-        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
-            show_main_window(app);
+        .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+            // A second launch with CLI flags hands its task to the running instance
+            match cli::CliArgs::parse(args.into_iter().skip(1)) {
+                Ok(cli_args) if cli_args.is_headless() => {
+                    tauri::async_runtime::spawn(cli::run(app.clone(), cli_args));
+                }
+                _ => show_main_window(app),
+            }
         }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_process::init())
@@ -192,6 +284,7 @@ pub fn run() {
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_macos_permissions::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(
             tauri_plugin_sql::Builder::default()
@@ -199,6 +292,7 @@ pub fn run() {
                     "sqlite:history.db",
                     managers::history::HistoryManager::get_migrations(),
                 )
+                .add_migrations("sqlite:meetings.db", storage::db::MeetingDb::get_migrations())
                 .build(),
         )
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
@@ -208,13 +302,28 @@ pub fn run() {
         ))
         .manage(Mutex::new(ShortcutToggleStates::default()))
         .setup(move |app| {
+            // A restored backup is swapped in before the settings store or databases open
+            if let Ok(app_data_dir) = app.path().app_data_dir() {
+                match storage::backup::apply_pending_restore(&app_data_dir) {
+                    Ok(0) => {}
+                    Ok(n) => log::info!("Applied {} files from a restored backup", n),
+                    Err(e) => log::error!("Failed to apply restored backup: {}", e),
+                }
+            }
             let settings = settings::get_settings(&app.handle());
             let app_handle = app.handle().clone();
 
             initialize_core_logic(&app_handle);
+            deep_link::init(&app_handle);
 
-            // Show main window only if not starting hidden
-            if !settings.start_hidden {
+            if cli_args.is_headless() {
+                #[cfg(target_os = "macos")]
+                let _ = app_handle.set_activation_policy(tauri::ActivationPolicy::Accessory);
+                tauri::async_runtime::spawn(cli::run(app_handle.clone(), cli_args.clone()));
+            }
+
+            // Show main window only if not starting hidden or running headless
+            if !settings.start_hidden && !cli_args.is_headless() {
                 if let Some(main_window) = app_handle.get_webview_window("main") {
                     main_window.show().unwrap();
                     main_window.set_focus().unwrap();
@@ -245,6 +354,12 @@ pub fn run() {
             _ => {}
         })
         .invoke_handler(tauri::generate_handler![
+            events::get_recent_events,
+            network::get_network_status,
+            network::ensure_online,
+            metrics::get_metrics_dashboard,
+            metrics::preview_shared_metrics,
+            metrics::share_metrics,
             shortcut::change_binding,
             shortcut::reset_binding,
             shortcut::change_ptt_setting,
@@ -254,8 +369,16 @@ pub fn run() {
             shortcut::change_start_hidden_setting,
             shortcut::change_autostart_setting,
             shortcut::change_translate_to_english_setting,
+            shortcut::change_whisper_prompt_context_setting,
+            shortcut::change_whisper_beam_size_setting,
+            shortcut::change_whisper_temperature_setting,
+            shortcut::change_whisper_temperature_increment_setting,
+            shortcut::change_whisper_no_speech_threshold_setting,
+            shortcut::change_whisper_condition_on_previous_text_setting,
             shortcut::change_selected_language_setting,
             shortcut::change_overlay_position_setting,
+            shortcut::change_overlay_content_setting,
+            shortcut::change_overlay_placement_setting,
             shortcut::change_debug_mode_setting,
             shortcut::change_word_correction_threshold_setting,
             shortcut::change_paste_method_setting,
@@ -263,9 +386,12 @@ pub fn run() {
             shortcut::update_custom_words,
             shortcut::suspend_binding,
             shortcut::resume_binding,
+            settings_update::update_settings,
             shortcut::change_mute_while_recording_setting,
+            shortcut::change_focus_mode_while_recording_setting,
             shortcut::change_meeting_update_interval_seconds_setting,
             shortcut::change_system_audio_silence_threshold_setting,
+            shortcut::change_noise_suppression_enabled_setting,
             shortcut::change_system_audio_buffer_seconds_setting,
             shortcut::change_auto_trigger_meeting_command_setting,
             shortcut::change_auto_accept_changes_setting,
@@ -280,12 +406,51 @@ pub fn run() {
             shortcut::change_use_fixed_windows_for_imports_setting,
             shortcut::change_min_segment_duration_for_imports_setting,
             shortcut::change_ffmpeg_fallback_for_imports_setting,
+            shortcut::change_agent_max_files_touched_setting,
+            shortcut::change_agent_max_loc_changed_setting,
+            shortcut::change_agent_allowed_path_globs_setting,
+            shortcut::change_keyword_triggers_enabled_setting,
+            shortcut::change_agent_trigger_phrases_setting,
+            shortcut::change_codebase_ignore_patterns_setting,
+            shortcut::change_codebase_embeddings_enabled_setting,
+            shortcut::change_claudeignore_fragments_setting,
+            shortcut::change_cloud_sync_settings,
+            shortcut::change_storage_root_setting,
+            shortcut::change_retention_policy_setting,
+            shortcut::change_notification_settings,
+            shortcut::change_local_api_settings,
+            shortcut::change_slack_settings,
+            shortcut::change_teams_settings,
+            shortcut::change_discord_settings,
+            shortcut::change_email_settings,
+            shortcut::change_obsidian_settings,
+            shortcut::change_asana_settings,
+            shortcut::change_trello_settings,
+            shortcut::change_webhooks_settings,
+            shortcut::change_jira_settings,
+            shortcut::change_calendar_settings,
+            shortcut::change_diarization_settings,
+            shortcut::change_watch_folder_settings,
+            shortcut::change_low_confidence_settings,
+            shortcut::change_meeting_translation_settings,
+            shortcut::change_cloud_asr_settings,
+            shortcut::change_queue_worker_counts_setting,
+            shortcut::change_separate_import_model_setting,
+            shortcut::change_gpu_scheduling_setting,
+            shortcut::change_tray_meeting_tooltip_setting,
+            shortcut::change_marker_label_presets_setting,
             trigger_update_check,
             commands::cancel_operation,
             commands::get_app_dir_path,
+            commands::get_storage_root,
+            commands::get_overlay_placement,
+            commands::self_test::run_self_test,
+            commands::migrate_storage_root,
             commands::models::get_available_models,
             commands::models::get_model_info,
             commands::models::download_model,
+            commands::models::get_diarization_status,
+            commands::models::download_diarization_model,
             commands::models::delete_model,
             commands::models::cancel_download,
             commands::models::set_active_model,
@@ -307,6 +472,7 @@ pub fn run() {
             commands::audio::check_custom_sounds,
             commands::audio::set_system_audio_source,
             commands::audio::set_microphone_source,
+            commands::audio::set_dual_audio_source,
             commands::audio::get_current_audio_source,
             commands::audio::get_system_audio_buffer_size,
             commands::audio::save_system_audio_buffer_to_wav,
@@ -316,25 +482,80 @@ pub fn run() {
             commands::transcription::set_model_unload_timeout,
             commands::transcription::get_model_load_status,
             commands::transcription::unload_model_manually,
+            commands::transcription::list_dead_letters,
+            commands::transcription::requeue_dead_letters,
+            commands::transcription::cancel_meeting_jobs,
+            commands::transcription::get_queue_metrics,
+            commands::transcription::pause_queue_processing,
+            commands::transcription::resume_queue_processing,
+            commands::transcription::set_cloud_asr_key,
+            commands::transcription::remove_cloud_asr_key,
+            commands::transcription::has_cloud_asr_key,
             commands::history::get_history_entries,
             commands::history::toggle_history_entry_saved,
             commands::history::get_audio_file_path,
             commands::history::delete_history_entry,
             commands::history::update_history_limit,
+            commands::history::search_transcripts,
+            commands::history::get_storage_usage,
+            commands::history::cleanup_storage,
+            commands::history::check_storage_integrity,
+            commands::history::repair_storage,
             commands::meeting::start_meeting,
             commands::meeting::end_meeting,
             commands::meeting::pause_meeting,
             commands::meeting::resume_meeting,
+            commands::meeting::list_interrupted_meetings,
+            commands::meeting::recover_meeting,
             commands::meeting::get_live_transcript,
+            commands::meeting::get_recent_transcript_lines,
             commands::meeting::update_speaker_labels,
             commands::meeting::get_active_meetings,
             commands::meeting::get_meeting_info,
+            commands::meeting::set_meeting_translation,
+            commands::meeting::set_meeting_cloud_asr,
             commands::meeting::get_meeting_project_path,
             commands::meeting::get_transcript_dir_for,
             commands::meeting::list_saved_meetings,
             commands::meeting::open_meeting_folder,
             commands::meeting::delete_saved_meeting,
+            commands::meeting::list_trashed_meetings,
+            commands::meeting::restore_meeting,
+            commands::meeting::update_saved_speaker_labels,
+            commands::meeting::rediarize_meeting,
+            commands::speakers::enroll_speaker,
+            commands::speakers::list_speakers,
+            commands::speakers::delete_speaker,
+            commands::meeting::retranscribe_meeting,
+            commands::meeting::update_saved_participants,
+            commands::meeting::update_saved_segment_text,
+            commands::meeting::list_meetings_for_project,
+            commands::meeting::import_saved_meetings_to_db,
+            commands::meeting::list_stored_meetings,
+            commands::meeting::get_stored_transcript,
+            commands::meeting::add_meeting_marker,
+            commands::meeting::get_meeting_markers,
+            commands::meeting::get_meeting_summaries,
+            commands::meeting::set_meeting_starred,
+            commands::meeting::preview_retention,
+            commands::meeting::apply_retention_now,
+            commands::meeting::export_transcript,
+            commands::meeting::get_meeting_audio_info,
+            commands::meeting::get_audio_chunk,
+            commands::meeting::get_segment_audio_range,
+            commands::meeting::get_meeting_waveform,
+            commands::meeting::export_clip,
+            commands::meeting::list_meeting_tasks,
+            commands::meeting::get_meeting_analytics,
+            commands::meeting::get_meeting_chapters,
+            commands::meeting::get_redacted_transcript,
+            commands::meeting::set_meeting_task_done,
+            commands::meeting::export_meeting_tasks,
+            commands::meeting::export_meeting_bundle,
+            commands::meeting::import_meeting_bundle,
             commands::import::import_audio_as_meeting,
+            commands::import::list_interrupted_imports,
+            commands::import::discard_interrupted_import,
             commands::import::import_youtube_as_meeting,
             commands::import::pick_audio_file,
             commands::get_app_dir_path,
@@ -345,6 +566,14 @@ pub fn run() {
             commands::automation::open_meeting_cursor,
             commands::automation::open_meeting_vscode_with_meeting,
             commands::automation::open_meeting_cursor_with_meeting,
+            commands::automation::check_agent_guardrails,
+            commands::automation::get_agent_logs,
+            commands::automation::read_agent_log,
+            commands::automation::get_agent_activity,
+            commands::automation::get_suggested_commands,
+            commands::automation::implement_prd_item,
+            commands::automation::map_prd_to_routes,
+            commands::automation::install_automation_script,
             commands::github::set_github_token,
             commands::github::remove_github_token,
             commands::github::test_github_connection,
@@ -355,13 +584,80 @@ pub fn run() {
             commands::github::push_meeting_changes,
             commands::github::create_or_update_pr,
             commands::github::post_meeting_update_comment,
+            commands::github::create_issues_from_meeting,
             commands::github::github_begin_device_auth,
             commands::github::github_poll_device_token,
+            commands::backup::create_backup,
+            commands::backup::inspect_backup,
+            commands::backup::restore_backup,
+            commands::backup::export_settings,
+            commands::backup::import_settings,
+            commands::diagnostics::export_diagnostics_bundle,
+            commands::permissions::check_all_permissions,
+            commands::permissions::open_permission_settings,
+            commands::cloud_sync::set_cloud_sync_credentials,
+            commands::cloud_sync::remove_cloud_sync_credentials,
+            commands::cloud_sync::get_cloud_sync_status,
+            commands::cloud_sync::retry_cloud_sync,
+            commands::integrations::set_slack_credentials,
+            commands::integrations::remove_slack_credentials,
+            commands::integrations::has_slack_credentials,
+            commands::integrations::post_summary_to_slack,
+            commands::integrations::set_teams_webhook,
+            commands::integrations::remove_teams_webhook,
+            commands::integrations::has_teams_webhook,
+            commands::integrations::post_summary_to_teams,
+            commands::integrations::set_discord_webhook,
+            commands::integrations::remove_discord_webhook,
+            commands::integrations::has_discord_webhook,
+            commands::integrations::post_summary_to_discord,
+            commands::integrations::set_smtp_credentials,
+            commands::integrations::remove_smtp_credentials,
+            commands::integrations::has_smtp_credentials,
+            commands::integrations::email_meeting_summary,
+            commands::integrations::export_meeting_to_obsidian,
+            commands::integrations::set_asana_token,
+            commands::integrations::remove_asana_token,
+            commands::integrations::has_asana_token,
+            commands::integrations::sync_action_items_to_asana,
+            commands::integrations::set_trello_credentials,
+            commands::integrations::remove_trello_credentials,
+            commands::integrations::has_trello_credentials,
+            commands::integrations::list_trello_lists,
+            commands::integrations::push_action_items_to_trello,
+            commands::integrations::push_user_stories_to_trello,
+            commands::integrations::get_trello_cards,
+            commands::integrations::set_calendar_write_access,
+            commands::integrations::remove_calendar_write_access,
+            commands::integrations::has_calendar_write_access,
+            commands::integrations::link_meeting_to_calendar_event,
+            commands::integrations::write_meeting_summary_to_calendar,
+            commands::integrations::get_webhook_signing_secret,
+            commands::integrations::regenerate_webhook_signing_secret,
+            commands::integrations::list_webhook_deliveries,
+            commands::integrations::redeliver_webhook,
+            commands::integrations::send_test_webhook,
+            commands::integrations::set_jira_token,
+            commands::integrations::remove_jira_token,
+            commands::integrations::has_jira_token,
+            commands::integrations::get_meeting_jira_tickets,
+            commands::integrations::link_calendar,
+            commands::integrations::unlink_calendar,
+            commands::integrations::is_calendar_linked,
+            commands::integrations::list_upcoming_meetings,
+            commands::local_api::get_local_api_token,
+            commands::local_api::regenerate_local_api_token,
             commands::llm::store_claude_api_key,
             commands::llm::has_claude_api_key,
             commands::llm::delete_claude_api_key,
+            commands::llm::store_openai_api_key,
+            commands::llm::has_openai_api_key,
+            commands::llm::delete_openai_api_key,
             commands::codebase::analyze_project_codebase,
             commands::codebase::analyze_and_save_codebase,
+            commands::codebase::build_codebase_index,
+            commands::codebase::search_codebase,
+            commands::codebase::preview_claudeignore,
             commands::system_audio::is_system_audio_supported,
             commands::system_audio::get_system_audio_setup_instructions,
             commands::system_audio::detect_virtual_audio_device,
@@ -376,6 +672,12 @@ pub fn run() {
             commands::prd::get_prd_metadata,
             commands::prd::delete_prd_version
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // Don't leave Do Not Disturb on if the app quits mid-meeting
+            if let tauri::RunEvent::Exit = event {
+                focus_mode::restore();
+            }
+        });
 }
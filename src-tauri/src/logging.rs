@@ -0,0 +1,219 @@
+//! Logging to the console (filtered by `RUST_LOG`, as before) and to rotating JSON-lines
+//! files under `{app_data}/logs`, filtered by the `log_level` setting. The file sink is
+//! attached once the app data directory is known; earlier records only reach the console.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+pub const LOG_DIR: &str = "logs";
+const LOG_FILE: &str = "meetingcoder.log";
+/// Rotate once the current file reaches this size
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// Rotated files kept besides the current one
+const KEEP_ROTATED: usize = 4;
+
+struct FileSink {
+    dir: PathBuf,
+    file: File,
+    written: u64,
+}
+
+impl FileSink {
+    fn open(dir: &Path) -> std::io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(LOG_FILE))?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            file,
+            written,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        if self.written + line.len() as u64 > MAX_FILE_BYTES {
+            rotate(&self.dir)?;
+            *self = Self::open(&self.dir)?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+}
+
+/// `meetingcoder.log` -> `meetingcoder.1.log` -> ... dropping the oldest
+fn rotate(dir: &Path) -> std::io::Result<()> {
+    let rotated = |n: usize| dir.join(format!("meetingcoder.{}.log", n));
+    let _ = fs::remove_file(rotated(KEEP_ROTATED));
+    for n in (1..KEEP_ROTATED).rev() {
+        if rotated(n).exists() {
+            fs::rename(rotated(n), rotated(n + 1))?;
+        }
+    }
+    fs::rename(dir.join(LOG_FILE), rotated(1))
+}
+
+/// Log files in `dir`, newest first
+pub fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![dir.join(LOG_FILE)];
+    files.extend((1..=KEEP_ROTATED).map(|n| dir.join(format!("meetingcoder.{}.log", n))));
+    files.retain(|f| f.exists());
+    files
+}
+
+#[derive(Serialize)]
+struct LogLine<'a> {
+    ts: String,
+    level: &'a str,
+    target: &'a str,
+    msg: String,
+}
+
+fn format_line(record: &Record) -> String {
+    let line = LogLine {
+        ts: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        level: record.level().as_str(),
+        target: record.target(),
+        msg: record.args().to_string(),
+    };
+    serde_json::to_string(&line).unwrap_or_default() + "\n"
+}
+
+struct AppLogger {
+    console: env_logger::Logger,
+    file: Mutex<Option<FileSink>>,
+    /// `LevelFilter as usize`
+    file_level: AtomicUsize,
+}
+
+impl AppLogger {
+    fn file_level(&self) -> LevelFilter {
+        LEVELS[self.file_level.load(Ordering::Relaxed)]
+    }
+}
+
+const LEVELS: [LevelFilter; 6] = [
+    LevelFilter::Off,
+    LevelFilter::Error,
+    LevelFilter::Warn,
+    LevelFilter::Info,
+    LevelFilter::Debug,
+    LevelFilter::Trace,
+];
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.console.enabled(metadata) || metadata.level() <= self.file_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.console.matches(record) {
+            self.console.log(record);
+        }
+        if record.level() <= self.file_level() {
+            if let Some(sink) = self.file.lock().unwrap().as_mut() {
+                if let Err(e) = sink.write_line(&format_line(record)) {
+                    eprintln!("Failed to write log file: {}", e);
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {
+        self.console.flush();
+        if let Some(sink) = self.file.lock().unwrap().as_mut() {
+            let _ = sink.file.flush();
+        }
+    }
+}
+
+static LOGGER: Lazy<AppLogger> = Lazy::new(|| AppLogger {
+    console: env_logger::Builder::from_default_env().build(),
+    file: Mutex::new(None),
+    file_level: AtomicUsize::new(LevelFilter::Off as usize),
+});
+
+fn update_max_level() {
+    log::set_max_level(LOGGER.console.filter().max(LOGGER.file_level()));
+}
+
+/// Install the logger; replaces `env_logger::init`
+pub fn init() {
+    if log::set_logger(&*LOGGER).is_ok() {
+        update_max_level();
+    }
+}
+
+/// Start writing log files to `dir` at `level`
+pub fn attach_file(dir: &Path, level: LevelFilter) {
+    match FileSink::open(dir) {
+        Ok(sink) => {
+            *LOGGER.file.lock().unwrap() = Some(sink);
+            set_file_level(level);
+            log::info!("Logging to {}", dir.join(LOG_FILE).display());
+        }
+        Err(e) => eprintln!("Failed to open log file in {}: {}", dir.display(), e),
+    }
+}
+
+pub fn set_file_level(level: LevelFilter) {
+    LOGGER.file_level.store(level as usize, Ordering::Relaxed);
+    update_max_level();
+}
+
+/// `{app_data}/logs`
+pub fn log_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    use tauri::Manager;
+    app.path()
+        .app_data_dir()
+        .map(|d| d.join(LOG_DIR))
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_rotation_keeps_newest_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        for round in 0..=KEEP_ROTATED + 1 {
+            fs::write(dir.join(LOG_FILE), format!("round {}", round)).unwrap();
+            rotate(dir).unwrap();
+        }
+        fs::write(dir.join(LOG_FILE), "current").unwrap();
+
+        let files = log_files(dir);
+        assert_eq!(files.len(), KEEP_ROTATED + 1);
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), "current");
+        assert_eq!(
+            fs::read_to_string(&files[1]).unwrap(),
+            format!("round {}", KEEP_ROTATED + 1)
+        );
+    }
+
+    #[test]
+    fn test_log_lines_are_json() {
+        let line = format_line(
+            &Record::builder()
+                .args(format_args!("hello \"world\""))
+                .level(log::Level::Warn)
+                .target("meetingcoder::test")
+                .build(),
+        );
+        let value: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(value["level"], "WARN");
+        assert_eq!(value["msg"], "hello \"world\"");
+    }
+}
@@ -1,15 +1,86 @@
+use crate::audio_toolkit::audio::NoiseSuppressor;
 use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
-use crate::settings::get_settings;
+use crate::network::{self, Service};
+use crate::settings::{get_settings, AppSettings};
 use crate::system_audio::{ring_buffer::SpscRingBuffer, SendableSystemAudio};
 use crate::utils;
 use log::{debug, info};
+use once_cell::sync::OnceCell;
 use std::collections::VecDeque;
-use std::sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, atomic::{AtomicBool, AtomicU64, Ordering}};
 use std::time::{Duration, Instant};
 use tauri::Manager;
 
 const WHISPER_SAMPLE_RATE: usize = 16000;
 
+/// DTLN's two stages (about 4 MB together), downloaded the first time suppression is on
+const NOISE_MODEL_URLS: [&str; 2] = [
+    "https://github.com/breizhn/DTLN/raw/master/pretrained_model/model_1.onnx",
+    "https://github.com/breizhn/DTLN/raw/master/pretrained_model/model_2.onnx",
+];
+
+/// Whether microphone and system audio are denoised before VAD and buffering
+pub static NOISE_SUPPRESSION: AtomicBool = AtomicBool::new(false);
+static NOISE_MODEL_DIR: OnceCell<PathBuf> = OnceCell::new();
+static NOISE_MODEL_DOWNLOADING: AtomicBool = AtomicBool::new(false);
+
+pub fn apply_settings(app: &tauri::AppHandle, settings: &AppSettings) {
+    NOISE_SUPPRESSION.store(settings.noise_suppression_enabled, Ordering::Relaxed);
+    let Ok(models) = app.path().app_data_dir().map(|dir| dir.join("models")) else {
+        return;
+    };
+    let dir = NOISE_MODEL_DIR.get_or_init(|| models).clone();
+    if !settings.noise_suppression_enabled
+        || NoiseSuppressor::models_present(&dir)
+        || NOISE_MODEL_DOWNLOADING.swap(true, Ordering::SeqCst)
+    {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        match download_noise_models(&dir).await {
+            Ok(()) => info!("Noise suppression models downloaded"),
+            Err(e) => log::warn!("Noise suppression model download failed: {}", e),
+        }
+        NOISE_MODEL_DOWNLOADING.store(false, Ordering::SeqCst);
+    });
+}
+
+async fn download_noise_models(dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for (file, url) in NoiseSuppressor::MODEL_FILES.iter().zip(NOISE_MODEL_URLS) {
+        let path = dir.join(file);
+        if path.is_file() {
+            continue;
+        }
+        let response = network::client(Service::ModelDownload, url)?
+            .get(url)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("Model download failed: {}", response.status()));
+        }
+        let bytes = response.bytes().await?;
+        // Renamed into place so a suppressor never loads a partial file
+        let partial = path.with_extension("onnx.partial");
+        std::fs::write(&partial, &bytes)?;
+        std::fs::rename(&partial, &path)?;
+    }
+    Ok(())
+}
+
+/// A suppressor for a new stream, or None (audio passes through) until the models are
+/// downloaded
+pub fn noise_suppressor() -> Option<NoiseSuppressor> {
+    let dir = NOISE_MODEL_DIR.get()?;
+    if !NoiseSuppressor::models_present(dir) {
+        return None;
+    }
+    NoiseSuppressor::new(dir)
+        .map_err(|e| log::warn!("Noise suppression unavailable: {}", e))
+        .ok()
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
 #[derive(Clone, Debug)]
@@ -28,6 +99,18 @@ pub enum MicrophoneMode {
 pub enum AudioSource {
     Microphone,
     SystemAudio(String), // device_name
+    /// Microphone and system audio captured in parallel on separate buffers
+    Dual(String), // system audio device_name
+}
+
+/// Sum two 16 kHz channels into one, padding the shorter with silence
+pub fn mix_channels(a: &[f32], b: &[f32]) -> Vec<f32> {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let s = a.get(i).copied().unwrap_or(0.0) + b.get(i).copied().unwrap_or(0.0);
+            s.clamp(-1.0, 1.0)
+        })
+        .collect()
 }
 
 /* ──────────────────────────────────────────────────────────────── */
@@ -35,21 +118,35 @@ pub enum AudioSource {
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
+    mic_tap: Arc<Mutex<Option<Arc<SpscRingBuffer>>>>,
 ) -> Result<AudioRecorder, anyhow::Error> {
     let silero = SileroVad::new(vad_path, 0.3)
         .map_err(|e| anyhow::anyhow!("Failed to create SileroVad: {}", e))?;
     let smoothed_vad = SmoothedVad::new(Box::new(silero), 15, 15, 2);
 
-    // Recorder with VAD plus a spectrum-level callback that forwards updates to
-    // the frontend.
+    // Recorder with VAD plus spectrum-level and waveform callbacks that forward
+    // updates to the frontend.
     let recorder = AudioRecorder::new()
         .map_err(|e| anyhow::anyhow!("Failed to create AudioRecorder: {}", e))?
         .with_vad(Box::new(smoothed_vad))
+        .with_noise_suppression(&NOISE_SUPPRESSION, noise_suppressor)
         .with_level_callback({
             let app_handle = app_handle.clone();
             move |levels| {
                 utils::emit_levels(&app_handle, &levels);
             }
+        })
+        .with_waveform_callback({
+            let app_handle = app_handle.clone();
+            move |peaks| {
+                utils::emit_waveform(&app_handle, &peaks);
+            }
+        })
+        // Feeds the microphone buffer while dual capture is active
+        .with_frame_callback(move |frame| {
+            if let Some(buffer) = mic_tap.lock().unwrap().as_ref() {
+                buffer.push(frame);
+            }
         });
 
     Ok(recorder)
@@ -71,6 +168,8 @@ pub struct AudioRecordingManager {
     system_audio: Arc<Mutex<Option<SendableSystemAudio>>>,
     current_source: Arc<Mutex<AudioSource>>,
     system_audio_buffer: Arc<Mutex<Arc<SpscRingBuffer>>>,
+    // Microphone samples for dual capture; None when only one source is recorded
+    mic_tap: Arc<Mutex<Option<Arc<SpscRingBuffer>>>>,
     silent_chunks: AtomicU64,
     restart_attempts_total: AtomicU64,
     restart_successes: AtomicU64,
@@ -110,6 +209,7 @@ impl AudioRecordingManager {
             system_audio_buffer: Arc::new(Mutex::new(SpscRingBuffer::new(
                 WHISPER_SAMPLE_RATE * (settings.system_audio_buffer_seconds.max(1) as usize),
             ))),
+            mic_tap: Arc::new(Mutex::new(None)),
             silent_chunks: AtomicU64::new(0),
             restart_attempts_total: AtomicU64::new(0),
             restart_successes: AtomicU64::new(0),
@@ -162,6 +262,7 @@ impl AudioRecordingManager {
             *recorder_opt = Some(create_audio_recorder(
                 vad_path.to_str().unwrap(),
                 &self.app_handle,
+                self.mic_tap.clone(),
             )?);
         }
 
@@ -355,6 +456,15 @@ impl AudioRecordingManager {
 
     /// Start system audio capture
     pub fn start_system_audio(&self, device_name: String) -> Result<(), anyhow::Error> {
+        self.start_system_capture(&device_name)?;
+
+        // Update current source
+        *self.current_source.lock().unwrap() = AudioSource::SystemAudio(device_name);
+        Ok(())
+    }
+
+    /// Open the system audio device into the buffer, leaving the current source as is
+    fn start_system_capture(&self, device_name: &str) -> Result<(), anyhow::Error> {
         let mut sys_audio = self.system_audio.lock().unwrap();
 
         // Create system audio capturer if not exists
@@ -365,12 +475,10 @@ impl AudioRecordingManager {
         // Start capturing with buffer
         if let Some(ref capturer) = *sys_audio {
             let buffer = self.system_audio_buffer.lock().unwrap().clone();
-            capturer.start_capture(Some(device_name.clone()), buffer)?;
+            capturer.start_capture(Some(device_name.to_string()), buffer)?;
             info!("System audio capture started from device: {}", device_name);
         }
 
-        // Update current source
-        *self.current_source.lock().unwrap() = AudioSource::SystemAudio(device_name);
         *self.is_open.lock().unwrap() = true;
 
         Ok(())
@@ -395,7 +503,7 @@ impl AudioRecordingManager {
 
         // Get the current device name before stopping
         let device_name = match self.current_source.lock().unwrap().clone() {
-            AudioSource::SystemAudio(name) => name,
+            AudioSource::SystemAudio(name) | AudioSource::Dual(name) => name,
             _ => {
                 return Err(anyhow::anyhow!("Not currently using system audio"));
             }
@@ -432,7 +540,7 @@ impl AudioRecordingManager {
         }
 
         // Restart capture
-        self.start_system_audio(device_name.clone()).map_err(|e| {
+        self.start_system_capture(&device_name).map_err(|e| {
             anyhow::anyhow!("Failed to start audio device '{}': {}. Check if BlackHole is still installed and set as output.", device_name, e)
         })?;
 
@@ -454,6 +562,11 @@ impl AudioRecordingManager {
                     self.stop_system_audio()?;
                 }
             }
+            AudioSource::Dual(_) => {
+                if *self.is_open.lock().unwrap() {
+                    self.stop_dual_capture()?;
+                }
+            }
         }
 
         // Start new source
@@ -465,11 +578,66 @@ impl AudioRecordingManager {
             AudioSource::SystemAudio(device_name) => {
                 self.start_system_audio(device_name)?;
             }
+            AudioSource::Dual(device_name) => {
+                self.start_dual_capture(device_name)?;
+            }
         }
 
         Ok(())
     }
 
+    /// Record the microphone and a system audio device at the same time, each into
+    /// its own buffer
+    pub fn start_dual_capture(&self, device_name: String) -> Result<(), anyhow::Error> {
+        let capacity = self.get_system_audio_buffer_capacity();
+        *self.mic_tap.lock().unwrap() = Some(SpscRingBuffer::new(capacity));
+
+        // The microphone first: it won't open once system audio has marked the stream open
+        if let Err(e) = self.start_microphone_stream() {
+            *self.mic_tap.lock().unwrap() = None;
+            return Err(e);
+        }
+        if let Err(e) = self.start_system_capture(&device_name) {
+            *self.mic_tap.lock().unwrap() = None;
+            self.stop_microphone_stream();
+            return Err(e);
+        }
+
+        info!("Dual capture started (microphone + {})", device_name);
+        *self.current_source.lock().unwrap() = AudioSource::Dual(device_name);
+        Ok(())
+    }
+
+    /// Stop both halves of dual capture
+    pub fn stop_dual_capture(&self) -> Result<(), anyhow::Error> {
+        *self.mic_tap.lock().unwrap() = None;
+        self.stop_microphone_stream();
+        self.stop_system_audio()
+    }
+
+    /// Get buffered microphone audio during dual capture; empty otherwise
+    pub fn get_microphone_audio_buffer(&self, duration_secs: f32) -> Vec<f32> {
+        let samples_needed = (WHISPER_SAMPLE_RATE as f32 * duration_secs) as usize;
+        match self.mic_tap.lock().unwrap().as_ref() {
+            Some(buffer) => buffer.drain_n(samples_needed),
+            None => Vec::new(),
+        }
+    }
+
+    /// The audio captured since the last chunk was taken, without taking it: system audio,
+    /// mixed with the microphone under dual capture
+    pub fn peek_pending_audio(&self, max_secs: f32) -> Vec<f32> {
+        let samples = (WHISPER_SAMPLE_RATE as f32 * max_secs) as usize;
+        let system = self.system_audio_buffer.lock().unwrap().peek_n(samples);
+        if !matches!(self.get_audio_source(), AudioSource::Dual(_)) {
+            return system;
+        }
+        match self.mic_tap.lock().unwrap().as_ref() {
+            Some(mic) => mix_channels(&system, &mic.peek_n(samples)),
+            None => system,
+        }
+    }
+
     /// Get buffered audio from system audio (for continuous recording)
     pub fn get_system_audio_buffer(&self, duration_secs: f32) -> Vec<f32> {
         let samples_needed = (WHISPER_SAMPLE_RATE as f32 * duration_secs) as usize;
@@ -501,6 +669,7 @@ impl AudioRecordingManager {
         match self.current_source.lock().unwrap().clone() {
             AudioSource::Microphone => "microphone".to_string(),
             AudioSource::SystemAudio(name) => name,
+            AudioSource::Dual(name) => format!("microphone + {}", name),
         }
     }
 
@@ -674,7 +843,11 @@ impl AudioRecordingManager {
         let new_buf = SpscRingBuffer::new(new_cap);
 
         let active_device = match self.current_source.lock().unwrap().clone() {
-            AudioSource::SystemAudio(name) if *self.is_open.lock().unwrap() => Some(name),
+            AudioSource::SystemAudio(name) | AudioSource::Dual(name)
+                if *self.is_open.lock().unwrap() =>
+            {
+                Some(name)
+            }
             _ => None,
         };
 
@@ -685,7 +858,7 @@ impl AudioRecordingManager {
                 let mut guard = self.system_audio_buffer.lock().unwrap();
                 *guard = new_buf.clone();
             }
-            self.start_system_audio(dev)?;
+            self.start_system_capture(&dev)?;
         } else {
             // Just swap buffer for future starts
             let mut guard = self.system_audio_buffer.lock().unwrap();
@@ -694,3 +867,16 @@ impl AudioRecordingManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix_channels() {
+        let mixed = mix_channels(&[0.5, -0.25, 0.75], &[0.25, -0.25]);
+        assert_eq!(mixed, vec![0.75, -0.5, 0.75]);
+        assert_eq!(mix_channels(&[0.8], &[0.6]), vec![1.0]);
+        assert!(mix_channels(&[], &[]).is_empty());
+    }
+}
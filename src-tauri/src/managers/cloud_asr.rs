@@ -0,0 +1,319 @@
+//! Hosted transcription (Deepgram, AssemblyAI) for meetings that opt in. Takes the same
+//! 16kHz mono samples as the local engines and returns the same `Transcription`, so
+//! callers can fall back to the local model on any error.
+
+use anyhow::{anyhow, Result};
+use hound::{WavSpec, WavWriter};
+use serde::Deserialize;
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+use crate::managers::meeting::WordTiming;
+use crate::managers::transcription::Transcription;
+use crate::network::{self, Service};
+use crate::secrets;
+use crate::settings::{CloudAsrConfig, CloudAsrProvider};
+
+const KEYCHAIN_SERVICE: &str = "com.handy.cloud-asr";
+const DEEPGRAM_URL: &str = "https://api.deepgram.com/v1/listen";
+const ASSEMBLYAI_URL: &str = "https://api.assemblyai.com/v2";
+/// How often to ask AssemblyAI whether a transcript is done
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Give up on an AssemblyAI transcript after this long and let the local model take it
+const POLL_TIMEOUT: Duration = Duration::from_secs(120);
+
+fn keychain_account(provider: CloudAsrProvider) -> &'static str {
+    match provider {
+        CloudAsrProvider::Deepgram => "deepgram",
+        CloudAsrProvider::AssemblyAi => "assemblyai",
+    }
+}
+
+fn provider_name(provider: CloudAsrProvider) -> &'static str {
+    match provider {
+        CloudAsrProvider::Deepgram => "Deepgram",
+        CloudAsrProvider::AssemblyAi => "AssemblyAI",
+    }
+}
+
+fn fallback_file(provider: CloudAsrProvider) -> String {
+    format!(".{}-key", keychain_account(provider))
+}
+
+pub fn store_api_key(provider: CloudAsrProvider, key: &str) -> Result<()> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(anyhow!("{} API key is empty", provider_name(provider)));
+    }
+    secrets::store(
+        KEYCHAIN_SERVICE,
+        keychain_account(provider),
+        &fallback_file(provider),
+        key,
+    )
+}
+
+fn load_api_key(provider: CloudAsrProvider) -> Result<String> {
+    secrets::load(
+        KEYCHAIN_SERVICE,
+        keychain_account(provider),
+        &fallback_file(provider),
+    )
+    .map(|k| k.trim().to_string())
+    .map_err(|_| anyhow!("No {} API key is stored", provider_name(provider)))
+}
+
+pub fn has_api_key(provider: CloudAsrProvider) -> bool {
+    load_api_key(provider).is_ok()
+}
+
+pub fn clear_api_key(provider: CloudAsrProvider) -> Result<()> {
+    secrets::delete(
+        KEYCHAIN_SERVICE,
+        keychain_account(provider),
+        &fallback_file(provider),
+    );
+    Ok(())
+}
+
+/// Trim the model name, for the settings commands
+pub fn normalize_config(config: &mut CloudAsrConfig) -> Result<(), String> {
+    config.model = config.model.trim().to_string();
+    if config
+        .model
+        .contains(|c: char| c.is_whitespace() || c == '&' || c == '?')
+    {
+        return Err(format!("Invalid model name: {}", config.model));
+    }
+    Ok(())
+}
+
+/// Whether a meeting with cloud transcription on can use it right now
+pub fn is_available(config: &CloudAsrConfig) -> bool {
+    !network::is_offline() && has_api_key(config.provider)
+}
+
+/// Transcribe `audio` (16kHz mono) with the configured provider
+pub async fn transcribe(config: &CloudAsrConfig, audio: &[f32]) -> Result<Transcription> {
+    let key = load_api_key(config.provider)?;
+    let wav = encode_wav(audio)?;
+    match config.provider {
+        CloudAsrProvider::Deepgram => transcribe_deepgram(&key, &config.model, wav).await,
+        CloudAsrProvider::AssemblyAi => transcribe_assemblyai(&key, &config.model, wav).await,
+    }
+}
+
+fn encode_wav(samples: &[f32]) -> Result<Vec<u8>> {
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: 16000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = WavWriter::new(&mut cursor, spec)?;
+    for sample in samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+    }
+    writer.finalize()?;
+    Ok(cursor.into_inner())
+}
+
+#[derive(Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<DeepgramWord>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramWord {
+    word: String,
+    punctuated_word: Option<String>,
+    start: f64,
+    end: f64,
+}
+
+async fn transcribe_deepgram(key: &str, model: &str, wav: Vec<u8>) -> Result<Transcription> {
+    let model = if model.is_empty() { "nova-2" } else { model };
+    let url = format!("{}?model={}&smart_format=true", DEEPGRAM_URL, model);
+    let response = network::client(Service::CloudAsr, &url)?
+        .post(&url)
+        .header("Authorization", format!("Token {}", key))
+        .header("Content-Type", "audio/wav")
+        .body(wav)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Deepgram returned {}: {}", status, body));
+    }
+    Ok(deepgram_transcription(response.json().await?))
+}
+
+fn deepgram_transcription(response: DeepgramResponse) -> Transcription {
+    let Some(best) = response
+        .results
+        .channels
+        .into_iter()
+        .next()
+        .and_then(|c| c.alternatives.into_iter().next())
+    else {
+        return Transcription {
+            text: String::new(),
+            confidence: None,
+            words: Vec::new(),
+        };
+    };
+    Transcription {
+        text: best.transcript.trim().to_string(),
+        confidence: best.confidence,
+        words: best
+            .words
+            .into_iter()
+            .map(|w| WordTiming {
+                word: w.punctuated_word.unwrap_or(w.word),
+                start: w.start,
+                end: w.end,
+            })
+            .collect(),
+    }
+}
+
+#[derive(Deserialize)]
+struct AssemblyUpload {
+    upload_url: String,
+}
+
+#[derive(Deserialize)]
+struct AssemblyTranscript {
+    id: String,
+    status: String,
+    text: Option<String>,
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Option<Vec<AssemblyWord>>,
+    error: Option<String>,
+}
+
+/// Word times are in milliseconds
+#[derive(Deserialize)]
+struct AssemblyWord {
+    text: String,
+    start: u64,
+    end: u64,
+}
+
+async fn transcribe_assemblyai(key: &str, model: &str, wav: Vec<u8>) -> Result<Transcription> {
+    let client = network::client(Service::CloudAsr, ASSEMBLYAI_URL)?;
+    let upload: AssemblyUpload = client
+        .post(format!("{}/upload", ASSEMBLYAI_URL))
+        .header("Authorization", key)
+        .body(wav)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let mut request = serde_json::json!({ "audio_url": upload.upload_url });
+    if !model.is_empty() {
+        request["speech_model"] = serde_json::Value::String(model.to_string());
+    }
+    let mut transcript: AssemblyTranscript = client
+        .post(format!("{}/transcript", ASSEMBLYAI_URL))
+        .header("Authorization", key)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let started = Instant::now();
+    loop {
+        match transcript.status.as_str() {
+            "completed" => break,
+            "error" => {
+                return Err(anyhow!(
+                    "AssemblyAI failed: {}",
+                    transcript.error.unwrap_or_default()
+                ))
+            }
+            _ if started.elapsed() > POLL_TIMEOUT => {
+                return Err(anyhow!(
+                    "AssemblyAI did not finish within {:?}",
+                    POLL_TIMEOUT
+                ))
+            }
+            _ => {}
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+        transcript = client
+            .get(format!("{}/transcript/{}", ASSEMBLYAI_URL, transcript.id))
+            .header("Authorization", key)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+    }
+
+    Ok(Transcription {
+        text: transcript.text.unwrap_or_default().trim().to_string(),
+        confidence: transcript.confidence,
+        words: transcript
+            .words
+            .unwrap_or_default()
+            .into_iter()
+            .map(|w| WordTiming {
+                word: w.text,
+                start: w.start as f64 / 1000.0,
+                end: w.end as f64 / 1000.0,
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deepgram_response_maps_to_transcription() {
+        let body = r#"{"results":{"channels":[{"alternatives":[{
+            "transcript":"hello there","confidence":0.91,
+            "words":[{"word":"hello","punctuated_word":"Hello","start":0.1,"end":0.4},
+                     {"word":"there","start":0.5,"end":0.9}]}]}]}}"#;
+        let t = deepgram_transcription(serde_json::from_str(body).unwrap());
+        assert_eq!(t.text, "hello there");
+        assert_eq!(t.confidence, Some(0.91));
+        assert_eq!(t.words.len(), 2);
+        assert_eq!(t.words[0].word, "Hello");
+        assert_eq!(t.words[1].end, 0.9);
+    }
+
+    #[test]
+    fn wav_encoding_has_header_and_samples() {
+        let wav = encode_wav(&[0.0; 160]).unwrap();
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(wav.len(), 44 + 160 * 2);
+    }
+}
@@ -10,14 +10,118 @@ use tauri_plugin_sql::{Migration, MigrationKind};
 
 use crate::audio_toolkit::save_wav_file;
 
+const HISTORY_SCHEMA_V1: &str = "CREATE TABLE IF NOT EXISTS transcription_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    file_name TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    saved BOOLEAN NOT NULL DEFAULT 0,
+    title TEXT NOT NULL,
+    transcription_text TEXT NOT NULL
+);";
+
+/// Meetings share the history table so the History view lists both kinds of records
+const HISTORY_SCHEMA_V2: &str = "ALTER TABLE transcription_history ADD COLUMN record_type TEXT NOT NULL DEFAULT 'dictation';
+ALTER TABLE transcription_history ADD COLUMN meeting_id TEXT;
+ALTER TABLE transcription_history ADD COLUMN meeting_dir TEXT;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_history_meeting ON transcription_history(meeting_id);";
+
+const HISTORY_MIGRATIONS: &[(i64, &str, &str)] = &[
+    (1, "create_transcription_history_table", HISTORY_SCHEMA_V1),
+    (2, "add_meeting_records", HISTORY_SCHEMA_V2),
+];
+
+/// `record_type` of push-to-talk transcriptions
+pub const RECORD_TYPE_DICTATION: &str = "dictation";
+/// `record_type` of finished meetings
+pub const RECORD_TYPE_MEETING: &str = "meeting";
+/// Transcript preview stored with meeting records
+const MEETING_PREVIEW_CHARS: usize = 2000;
+
+const ENTRY_COLUMNS: &str =
+    "id, file_name, timestamp, saved, title, transcription_text, record_type, meeting_id, meeting_dir";
+
+fn default_record_type() -> String {
+    RECORD_TYPE_DICTATION.to_string()
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryEntry {
     pub id: i64,
+    /// Recording in the recordings directory (empty for meetings)
     pub file_name: String,
     pub timestamp: i64,
     pub saved: bool,
     pub title: String,
     pub transcription_text: String,
+    /// "dictation" or "meeting"
+    #[serde(default = "default_record_type")]
+    pub record_type: String,
+    #[serde(default)]
+    pub meeting_id: Option<String>,
+    /// Meeting directory name under the transcript storage root
+    #[serde(default)]
+    pub meeting_dir: Option<String>,
+}
+
+/// Bring a history database up to the latest schema, tracked in `user_version`
+fn migrate(conn: &Connection) -> Result<()> {
+    let current: i64 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
+    for &(version, description, sql) in HISTORY_MIGRATIONS.iter().filter(|(v, _, _)| *v > current) {
+        conn.execute_batch(sql)?;
+        conn.execute_batch(&format!("PRAGMA user_version = {};", version))?;
+        debug!("Applied history migration {} ({})", version, description);
+    }
+    Ok(())
+}
+
+fn query_entries(conn: &Connection, record_type: Option<&str>) -> Result<Vec<HistoryEntry>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM transcription_history WHERE ?1 IS NULL OR record_type = ?1 ORDER BY timestamp DESC",
+        ENTRY_COLUMNS
+    ))?;
+    let rows = stmt.query_map(params![record_type], HistoryEntry::from_row)?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Update the meeting's row if it has one, else insert it
+fn upsert_meeting_row(
+    conn: &Connection,
+    meeting_id: &str,
+    title: &str,
+    timestamp: i64,
+    meeting_dir: Option<&str>,
+    transcript_text: &str,
+) -> Result<()> {
+    let preview: String = transcript_text.chars().take(MEETING_PREVIEW_CHARS).collect();
+    let updated = conn.execute(
+        "UPDATE transcription_history SET title = ?2, timestamp = ?3, meeting_dir = COALESCE(?4, meeting_dir),
+           transcription_text = ?5 WHERE meeting_id = ?1",
+        params![meeting_id, title, timestamp, meeting_dir, preview],
+    )?;
+    if updated == 0 {
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, record_type, meeting_id, meeting_dir)
+             VALUES ('', ?1, 0, ?2, ?3, ?4, ?5, ?6)",
+            params![timestamp, title, preview, RECORD_TYPE_MEETING, meeting_id, meeting_dir],
+        )?;
+    }
+    Ok(())
+}
+
+impl HistoryEntry {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(HistoryEntry {
+            id: row.get("id")?,
+            file_name: row.get("file_name")?,
+            timestamp: row.get("timestamp")?,
+            saved: row.get("saved")?,
+            title: row.get("title")?,
+            transcription_text: row.get("transcription_text")?,
+            record_type: row.get("record_type")?,
+            meeting_id: row.get("meeting_id")?,
+            meeting_dir: row.get("meeting_dir")?,
+        })
+    }
 }
 
 pub struct HistoryManager {
@@ -63,34 +167,20 @@ impl HistoryManager {
     }
 
     pub fn get_migrations() -> Vec<Migration> {
-        vec![Migration {
-            version: 1,
-            description: "create_transcription_history_table",
-            sql: "CREATE TABLE IF NOT EXISTS transcription_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                file_name TEXT NOT NULL,
-                timestamp INTEGER NOT NULL,
-                saved BOOLEAN NOT NULL DEFAULT 0,
-                title TEXT NOT NULL,
-                transcription_text TEXT NOT NULL
-            );",
-            kind: MigrationKind::Up,
-        }]
+        HISTORY_MIGRATIONS
+            .iter()
+            .map(|&(version, description, sql)| Migration {
+                version,
+                description,
+                sql,
+                kind: MigrationKind::Up,
+            })
+            .collect()
     }
 
     fn init_database(&self) -> Result<()> {
         let conn = Connection::open(&self.db_path)?;
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS transcription_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                file_name TEXT NOT NULL,
-                timestamp INTEGER NOT NULL,
-                saved BOOLEAN NOT NULL DEFAULT 0,
-                title TEXT NOT NULL,
-                transcription_text TEXT NOT NULL
-            )",
-            [],
-        )?;
+        migrate(&conn)?;
         debug!("Database initialized at: {:?}", self.db_path);
         Ok(())
     }
@@ -160,7 +250,7 @@ impl HistoryManager {
 
         // Get all entries that are not saved, ordered by timestamp desc
         let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 ORDER BY timestamp DESC"
+            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND record_type = 'dictation' ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -200,29 +290,9 @@ impl HistoryManager {
         Ok(())
     }
 
-    pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
-        let conn = self.get_connection()?;
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text FROM transcription_history ORDER BY timestamp DESC"
-        )?;
-
-        let rows = stmt.query_map([], |row| {
-            Ok(HistoryEntry {
-                id: row.get("id")?,
-                file_name: row.get("file_name")?,
-                timestamp: row.get("timestamp")?,
-                saved: row.get("saved")?,
-                title: row.get("title")?,
-                transcription_text: row.get("transcription_text")?,
-            })
-        })?;
-
-        let mut entries = Vec::new();
-        for row in rows {
-            entries.push(row?);
-        }
-
-        Ok(entries)
+    /// History entries, newest first, optionally limited to one record type
+    pub async fn get_history_entries(&self, record_type: Option<&str>) -> Result<Vec<HistoryEntry>> {
+        query_entries(&self.get_connection()?, record_type)
     }
 
     pub async fn toggle_saved_status(&self, id: i64) -> Result<()> {
@@ -281,23 +351,12 @@ impl HistoryManager {
 
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text
-             FROM transcription_history WHERE id = ?1",
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM transcription_history WHERE id = ?1",
+            ENTRY_COLUMNS
+        ))?;
 
-        let entry = stmt
-            .query_row([id], |row| {
-                Ok(HistoryEntry {
-                    id: row.get("id")?,
-                    file_name: row.get("file_name")?,
-                    timestamp: row.get("timestamp")?,
-                    saved: row.get("saved")?,
-                    title: row.get("title")?,
-                    transcription_text: row.get("transcription_text")?,
-                })
-            })
-            .optional()?;
+        let entry = stmt.query_row([id], HistoryEntry::from_row).optional()?;
 
         Ok(entry)
     }
@@ -305,8 +364,13 @@ impl HistoryManager {
     pub async fn delete_entry(&self, id: i64) -> Result<()> {
         let conn = self.get_connection()?;
 
-        // Get the entry to find the file name
-        if let Some(entry) = self.get_entry_by_id(id).await? {
+        // Get the entry to find the file name (meeting records have no recording here;
+        // their files are managed by meeting storage)
+        if let Some(entry) = self
+            .get_entry_by_id(id)
+            .await?
+            .filter(|e| e.record_type == RECORD_TYPE_DICTATION)
+        {
             // Delete the audio file first
             let file_path = self.get_audio_file_path(&entry.file_name)?;
             if file_path.exists() {
@@ -333,6 +397,44 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Add or refresh the history record of a finished meeting
+    pub fn upsert_meeting_entry(
+        &self,
+        meeting_id: &str,
+        title: &str,
+        timestamp: i64,
+        meeting_dir: Option<&str>,
+        transcript_text: &str,
+    ) -> Result<()> {
+        upsert_meeting_row(
+            &self.get_connection()?,
+            meeting_id,
+            title,
+            timestamp,
+            meeting_dir,
+            transcript_text,
+        )?;
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+        Ok(())
+    }
+
+    /// Remove meeting records pointing at a deleted meeting directory
+    pub fn delete_meeting_entries_by_dir(&self, meeting_dir: &str) -> Result<usize> {
+        let conn = self.get_connection()?;
+        let removed = conn.execute(
+            "DELETE FROM transcription_history WHERE record_type = ?1 AND meeting_dir = ?2",
+            params![RECORD_TYPE_MEETING, meeting_dir],
+        )?;
+        if removed > 0 {
+            if let Err(e) = self.app_handle.emit("history-updated", ()) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+        Ok(removed)
+    }
+
     fn format_timestamp_title(&self, timestamp: i64) -> String {
         if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
             // Convert UTC to local timezone
@@ -347,4 +449,108 @@ impl HistoryManager {
         self.cleanup_old_entries()?;
         Ok(())
     }
+
+    /// Problems reported by SQLite's integrity check (empty when the database is healthy)
+    pub fn integrity_check(&self) -> Result<Vec<String>> {
+        crate::storage::integrity::sqlite_integrity(&self.get_connection()?)
+    }
+
+    /// Dictation entries whose recording is gone, as (id, file_name)
+    pub fn missing_recordings(&self) -> Result<Vec<(i64, String)>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name FROM transcription_history WHERE record_type = ?1",
+        )?;
+        let rows = stmt.query_map(params![RECORD_TYPE_DICTATION], |r| {
+            Ok((r.get::<_, i64>(0)?, r.get::<_, String>(1)?))
+        })?;
+        let mut missing = Vec::new();
+        for row in rows {
+            let (id, file_name) = row?;
+            if !self.recordings_dir.join(&file_name).exists() {
+                missing.push((id, file_name));
+            }
+        }
+        Ok(missing)
+    }
+
+    pub fn vacuum(&self) -> Result<()> {
+        self.get_connection()?.execute_batch("VACUUM;")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v1_database(dir: &tempfile::TempDir) -> Connection {
+        let conn = Connection::open(dir.path().join("history.db")).unwrap();
+        conn.execute_batch(HISTORY_SCHEMA_V1).unwrap();
+        conn.execute_batch("PRAGMA user_version = 1;").unwrap();
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text)
+             VALUES ('handy-1.wav', 100, 1, 'Dictation', 'hello world')",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_v1_database_migrates_with_dictation_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = v1_database(&dir);
+        migrate(&conn).unwrap();
+
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |r| r.get(0)).unwrap();
+        assert_eq!(version, 2);
+        let entries = query_entries(&conn, None).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].record_type, RECORD_TYPE_DICTATION);
+        assert_eq!(entries[0].meeting_id, None);
+        assert!(entries[0].saved);
+
+        // Running again at the latest version changes nothing
+        migrate(&conn).unwrap();
+        assert_eq!(query_entries(&conn, None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_entries_filter_by_record_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = v1_database(&dir);
+        migrate(&conn).unwrap();
+        upsert_meeting_row(&conn, "m-1", "Planning", 200, Some("2024-planning"), "notes").unwrap();
+
+        let all = query_entries(&conn, None).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].meeting_id.as_deref(), Some("m-1"));
+
+        let meetings = query_entries(&conn, Some(RECORD_TYPE_MEETING)).unwrap();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].title, "Planning");
+        let dictations = query_entries(&conn, Some(RECORD_TYPE_DICTATION)).unwrap();
+        assert_eq!(dictations.len(), 1);
+        assert_eq!(dictations[0].file_name, "handy-1.wav");
+    }
+
+    #[test]
+    fn test_upsert_meeting_updates_existing_row() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = v1_database(&dir);
+        migrate(&conn).unwrap();
+        upsert_meeting_row(&conn, "m-1", "Planning", 200, Some("2024-planning"), "draft").unwrap();
+        let id = query_entries(&conn, Some(RECORD_TYPE_MEETING)).unwrap()[0].id;
+
+        // A later save without a directory keeps the one already recorded
+        upsert_meeting_row(&conn, "m-1", "Planning (final)", 300, None, "final notes").unwrap();
+        let meetings = query_entries(&conn, Some(RECORD_TYPE_MEETING)).unwrap();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].id, id);
+        assert_eq!(meetings[0].title, "Planning (final)");
+        assert_eq!(meetings[0].timestamp, 300);
+        assert_eq!(meetings[0].transcription_text, "final notes");
+        assert_eq!(meetings[0].meeting_dir.as_deref(), Some("2024-planning"));
+    }
 }
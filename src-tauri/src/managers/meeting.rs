@@ -1,15 +1,18 @@
 use crate::managers::audio::{AudioRecordingManager, AudioSource};
 use crate::shortcut; // for dynamic settings adjustments
-use crate::managers::transcription::TranscriptionManager;
+use crate::managers::transcription::{PartialStream, TranscriptionManager};
+use crate::meeting::recovery::{self, InterruptedMeeting, RecoveryAction, ResumePoint};
 use crate::storage::transcript::TranscriptStorage;
+use crate::storage::db::MeetingMarker;
 use crate::integrations::github;
+use crate::events::{self, AppEvent};
 use crate::settings;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use uuid::Uuid;
@@ -23,6 +26,10 @@ pub enum MeetingStatus {
     Completed,
 }
 
+/// Speaker labels for the microphone and system audio channels of dual capture
+pub const LOCAL_SPEAKER: &str = "Me";
+pub const REMOTE_SPEAKER: &str = "Remote";
+
 // Coarse live diarization state and helpers (pause-based speaker toggling)
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 struct LiveDiarState {
@@ -49,6 +56,22 @@ fn live_silence_fraction(samples: &[f32], thresh: f32) -> f32 {
     silent as f32 / samples.len() as f32
 }
 
+fn live_dbfs(samples: &[f32]) -> f32 {
+    if samples.is_empty() { return f32::NEG_INFINITY; }
+    let rms = (samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32).sqrt();
+    20.0 * rms.max(1e-12).log10()
+}
+
+/// When one word was spoken, from the engine's alignment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    /// Start time in seconds from meeting start
+    pub start: f64,
+    /// End time in seconds from meeting start
+    pub end: f64,
+}
+
 /// A single transcript segment with speaker identification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptSegment {
@@ -64,6 +87,12 @@ pub struct TranscriptSegment {
     pub confidence: f32,
     /// Absolute timestamp when this segment was created
     pub timestamp: SystemTime,
+    /// Per-word timings, when the engine reported them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
+    /// `text` in the meeting's translation language, once translated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translated_text: Option<String>,
 }
 
 /// A complete meeting session with all metadata and transcript segments
@@ -85,6 +114,12 @@ pub struct MeetingSession {
     pub participants: Vec<String>,
     /// Optional project path for context updates
     pub project_path: Option<String>,
+    /// Language code segments are translated into, if any
+    #[serde(default)]
+    pub translation_language: Option<String>,
+    /// Transcribe with the cloud provider instead of the local model while it is reachable
+    #[serde(default)]
+    pub cloud_asr: bool,
 }
 
 /// Summary information returned when a meeting ends
@@ -99,6 +134,48 @@ pub struct MeetingSummary {
     pub end_time: SystemTime,
 }
 
+/// The meeting currently being recorded (or paused), without its transcript
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveMeetingInfo {
+    pub id: String,
+    pub name: String,
+    pub status: MeetingStatus,
+    pub start_time: SystemTime,
+}
+
+/// Name for meetings started without one (tray, global shortcut)
+pub fn default_meeting_name() -> String {
+    format!("Meeting {}", chrono::Local::now().format("%Y-%m-%d %H:%M"))
+}
+
+/// Most recently started meeting that hasn't been ended
+fn current_of(meetings: &HashMap<String, MeetingSession>) -> Option<ActiveMeetingInfo> {
+    meetings
+        .values()
+        .filter(|m| m.status != MeetingStatus::Completed)
+        .max_by_key(|m| m.start_time)
+        .map(|m| ActiveMeetingInfo {
+            id: m.id.clone(),
+            name: m.name.clone(),
+            status: m.status.clone(),
+            start_time: m.start_time,
+        })
+}
+
+/// Last `n` segments of the current meeting, oldest first
+fn recent_lines_of(
+    meetings: &HashMap<String, MeetingSession>,
+    n: usize,
+) -> Vec<TranscriptSegment> {
+    current_of(meetings)
+        .and_then(|current| meetings.get(&current.id))
+        .map(|m| {
+            let segments = &m.transcript_segments;
+            segments[segments.len().saturating_sub(n)..].to_vec()
+        })
+        .unwrap_or_default()
+}
+
 /// Manages the lifecycle of meeting sessions, coordinating between
 /// audio recording and transcription managers
 pub struct MeetingManager {
@@ -106,8 +183,6 @@ pub struct MeetingManager {
     active_meetings: Arc<Mutex<HashMap<String, MeetingSession>>>,
     /// Background task handles for chunking loops
     task_handles: Arc<Mutex<HashMap<String, JoinHandle<()>>>>,
-    /// Storage for saving transcripts
-    transcript_storage: Arc<TranscriptStorage>,
     /// Audio recording manager for capturing system audio
     audio_manager: Arc<AudioRecordingManager>,
     /// Transcription manager for converting audio to text
@@ -116,6 +191,87 @@ pub struct MeetingManager {
     app_handle: AppHandle,
 }
 
+impl MeetingSession {
+    /// Rename speakers (e.g. "Speaker 1" -> "John") in segments and the participant list
+    pub fn relabel_speakers(&mut self, mapping: &HashMap<String, String>) {
+        for segment in &mut self.transcript_segments {
+            if let Some(new_label) = mapping.get(&segment.speaker) {
+                segment.speaker = new_label.clone();
+            }
+        }
+        self.participants = self
+            .participants
+            .iter()
+            .map(|p| mapping.get(p).cloned().unwrap_or_else(|| p.clone()))
+            .collect();
+    }
+}
+
+/// Render the `summary.md` written next to a saved transcript
+pub(crate) fn summary_markdown(meeting: &MeetingSession) -> String {
+    use std::fmt::Write as _;
+    let summary = crate::summarization::agent::summarize_segments_with_context(
+        meeting.project_path.as_deref(),
+        &meeting.transcript_segments,
+        0,
+        meeting.transcript_segments.len().saturating_sub(1),
+    );
+    let duration = meeting
+        .end_time
+        .and_then(|end| end.duration_since(meeting.start_time).ok())
+        .unwrap_or(Duration::from_secs(0));
+    let mut md = String::new();
+    let _ = writeln!(md, "# Meeting Summary\n");
+    let _ = writeln!(md, "**Title**: {}", meeting.name);
+    let _ = writeln!(md, "**Duration**: {} minutes\n", duration.as_secs() / 60);
+    let chapters = crate::summarization::chapters::detect(&meeting.transcript_segments);
+    if !chapters.is_empty() {
+        let _ = writeln!(md, "## Chapters");
+        for chapter in &chapters {
+            let start = chapter.start_time.max(0.0) as u64;
+            let _ = writeln!(md, "- [{:02}:{:02}] {}", start / 60, start % 60, chapter.title);
+        }
+        let _ = writeln!(md);
+    }
+    if !summary.new_features.is_empty() || !summary.new_features_structured.is_empty() {
+        let _ = writeln!(md, "## Key Points / Features");
+        if !summary.new_features_structured.is_empty() {
+            for f in &summary.new_features_structured {
+                let _ = writeln!(md, "- {}", f.title);
+            }
+        } else {
+            for s in &summary.new_features {
+                let _ = writeln!(md, "- {}", s);
+            }
+        }
+        let _ = writeln!(md);
+    }
+    if !summary.technical_decisions.is_empty() {
+        let _ = writeln!(md, "## Decisions");
+        for s in &summary.technical_decisions {
+            let _ = writeln!(md, "- {}", s);
+        }
+        let _ = writeln!(md);
+    }
+    if !summary.questions.is_empty() {
+        let _ = writeln!(md, "## Open Questions");
+        for s in &summary.questions {
+            let _ = writeln!(md, "- {}", s);
+        }
+        let _ = writeln!(md);
+    }
+    if !summary.action_items.is_empty() {
+        let _ = writeln!(md, "## Action Items");
+        let _ = writeln!(md, "{}", crate::storage::tasks::checklist(&summary.action_items));
+    }
+    let tickets = crate::integrations::jira::tickets_in_segments(&meeting.transcript_segments);
+    if !tickets.is_empty() {
+        let _ = writeln!(md, "## Referenced Tickets");
+        let _ = writeln!(md, "{}", crate::integrations::jira::ticket_lines(&tickets));
+    }
+    md
+}
+
 impl MeetingManager {
     /// Create a new MeetingManager instance
     pub fn new(
@@ -123,12 +279,9 @@ impl MeetingManager {
         audio_manager: Arc<AudioRecordingManager>,
         transcription_manager: Arc<TranscriptionManager>,
     ) -> Result<Self> {
-        let transcript_storage = TranscriptStorage::with_default_path()?;
-
         Ok(Self {
             active_meetings: Arc::new(Mutex::new(HashMap::new())),
             task_handles: Arc::new(Mutex::new(HashMap::new())),
-            transcript_storage: Arc::new(transcript_storage),
             audio_manager,
             transcription_manager,
             app_handle: app_handle.clone(),
@@ -143,17 +296,33 @@ impl MeetingManager {
     /// # Returns
     /// The unique meeting_id for this session
     pub async fn start_meeting(&self, name: String) -> Result<String> {
+        self.start_meeting_in_repo(name, None).await
+    }
+
+    /// Start a meeting in `repo` (owner, name) instead of the configured GitHub repo. The
+    /// choice applies to this meeting only; the settings are left alone.
+    pub async fn start_meeting_in_repo(
+        &self,
+        name: String,
+        repo: Option<(String, String)>,
+    ) -> Result<String> {
         let meeting_id = Uuid::new_v4().to_string();
         // Initialize meeting in selected GitHub repo when enabled, else fallback to MeetingCoder workspace
         let settings = settings::get_settings(&self.app_handle);
-        let project_path = if settings.github_enabled
-            && settings.github_repo_owner.is_some()
-            && settings.github_repo_name.is_some()
+        let target_repo = repo.or_else(|| {
+            settings
+                .github_repo_owner
+                .clone()
+                .zip(settings.github_repo_name.clone())
+        });
+        let project_path = if let Some((owner, repo)) =
+            target_repo.filter(|_| settings.github_enabled)
         {
-            let owner = settings.github_repo_owner.clone().unwrap();
-            let repo = settings.github_repo_name.clone().unwrap();
             match github::get_github_token()
-                .and_then(|token| github::ensure_local_repo_clone(&owner, &repo, &token))
+                .and_then(|token| {
+                    let auth = github::GitAuth::from_settings(&settings, &token);
+                    github::ensure_local_repo_clone(&owner, &repo, &auth)
+                })
             {
                 Ok(repo_root) => {
                     // Seed meeting scaffolding inside the repo root
@@ -179,6 +348,9 @@ impl MeetingManager {
                 Err(e) => { log::warn!("Project initialization failed: {}", e); None }
             }
         };
+        if let Some(ref pp) = project_path {
+            crate::automation::repo_config::bind(pp);
+        }
         let meeting = MeetingSession {
             id: meeting_id.clone(),
             name: name.clone(),
@@ -188,14 +360,116 @@ impl MeetingManager {
             status: MeetingStatus::Recording,
             participants: Vec::new(),
             project_path,
+            translation_language: settings.meeting_translation.language.clone(),
+            cloud_asr: settings.cloud_asr.use_for_new_meetings,
         };
 
+        crate::integrations::webhooks::dispatch(
+            &self.app_handle,
+            crate::settings::WebhookEvent::MeetingStarted,
+            crate::integrations::webhooks::meeting_data(&meeting),
+        );
+
+        if let Err(e) = recovery::record(&self.app_handle, &meeting) {
+            log::warn!("Failed to journal meeting {} for crash recovery: {}", meeting_id, e);
+        }
+
         // Insert meeting into active meetings
         {
             let mut meetings = self.active_meetings.lock().await;
             meetings.insert(meeting_id.clone(), meeting);
         }
 
+        self.begin_capture(meeting_id.clone(), None).await;
+        log::info!("Started meeting: {} (ID: {})", name, meeting_id);
+
+        // Analyze codebase and set up file isolation if project path exists (Developer Mode)
+        if let Some(ref path) = {
+            let meetings = self.active_meetings.lock().await;
+            meetings.get(&meeting_id).and_then(|m| m.project_path.clone())
+        } {
+            let project_path_clone = path.clone();
+            let meeting_id_clone = meeting_id.clone();
+            let meeting_name_clone = name.clone();
+            let app_handle_clone = self.app_handle.clone();
+            log::info!("Starting codebase analysis and file isolation for project: {}", path);
+
+            tokio::spawn(async move {
+                let project_path = std::path::PathBuf::from(&project_path_clone);
+
+                // Analyze codebase
+                let ignore_patterns = crate::settings::get_settings(&app_handle_clone).codebase_ignore_patterns;
+                let framework = match crate::codebase::analyze_and_save_codebase(&project_path, &ignore_patterns).await {
+                    Ok(manifest) => {
+                        log::info!(
+                            "Codebase analysis complete: {} files, framework: {:?}",
+                            manifest.total_files,
+                            manifest.framework
+                        );
+                        manifest.framework
+                    }
+                    Err(e) => {
+                        log::warn!("Codebase analysis failed (non-critical): {}", e);
+                        None
+                    }
+                };
+
+                // Refresh the local embedding index used for prompt context
+                if crate::settings::get_settings(&app_handle_clone).codebase_embeddings_enabled {
+                    let app_for_index = app_handle_clone.clone();
+                    let index_root = project_path.clone();
+                    let index_ignores = ignore_patterns.clone();
+                    tokio::task::spawn_blocking(move || {
+                        if let Err(e) = crate::codebase::embeddings::refresh_project_index(
+                            &app_for_index,
+                            &index_root,
+                            &index_ignores,
+                        ) {
+                            log::warn!("Embedding index refresh failed (non-critical): {}", e);
+                        }
+                    });
+                }
+
+                // Generate .claudeignore for file protection
+                if let Err(e) = crate::codebase::generate_claudeignore(
+                    &project_path,
+                    framework.as_deref(),
+                    &crate::settings::get_settings(&app_handle_clone).claudeignore_fragments,
+                ) {
+                    log::warn!("Failed to generate .claudeignore: {}", e);
+                }
+
+                // Create experiments directory for this meeting
+                if let Err(e) = crate::codebase::create_experiments_dir(
+                    &project_path,
+                    &meeting_id_clone
+                ) {
+                    log::warn!("Failed to create experiments directory: {}", e);
+                }
+
+                // Auto-create GitHub branch if enabled
+                if let Err(e) = crate::automation::github_workflow::auto_create_branch(
+                    &app_handle_clone,
+                    &project_path_clone,
+                    &meeting_id_clone,
+                    &meeting_name_clone,
+                ).await {
+                    log::info!("GitHub auto-branch creation skipped or failed: {}", e);
+                }
+            });
+        }
+
+        Ok(meeting_id)
+    }
+
+    /// Start audio capture and the transcription loop for a meeting already in
+    /// `active_meetings`; `resume` continues the timeline of a recovered meeting
+    async fn begin_capture(&self, meeting_id: String, resume: Option<ResumePoint>) {
+        // Before capture starts so notification sounds don't reach the recording
+        if settings::get_settings(&self.app_handle).focus_mode_while_recording {
+            let _ = tokio::task::spawn_blocking(crate::focus_mode::enable).await;
+        }
+
         // Ensure capture is running for the selected source.
         // If user selected System Audio (e.g., BlackHole), start capture here so
         // the transcription loop has a producer and Diagnostics won't keep running idle later.
@@ -213,13 +487,19 @@ impl MeetingManager {
                     );
                 }
             }
+            AudioSource::Dual(device_name) => {
+                if let Err(e) = self.audio_manager.start_dual_capture(device_name.clone()) {
+                    log::warn!(
+                        "Failed to auto-start dual capture for device '{}': {}",
+                        device_name, e
+                    );
+                }
+            }
             AudioSource::Microphone => {
                 // Microphone path is managed separately (always-on or on-demand).
             }
         }
 
-        log::info!("Started meeting: {} (ID: {})", name, meeting_id);
-
         // Load the transcription model before starting transcription
         log::info!("Loading transcription model...");
         self.transcription_manager.initiate_model_load();
@@ -249,6 +529,7 @@ impl MeetingManager {
             self.audio_manager.clone(),
             self.transcription_manager.clone(),
             self.app_handle.clone(),
+            resume,
         ));
 
         // Store task handle for cleanup
@@ -258,66 +539,76 @@ impl MeetingManager {
         }
 
         log::info!("Transcription task spawned for meeting: {}", meeting_id);
+    }
 
-        // Analyze codebase and set up file isolation if project path exists (Developer Mode)
-        if let Some(ref path) = {
-            let meetings = self.active_meetings.lock().await;
-            meetings.get(&meeting_id).and_then(|m| m.project_path.clone())
-        } {
-            let project_path_clone = path.clone();
-            let meeting_id_clone = meeting_id.clone();
-            let meeting_name_clone = name.clone();
-            let app_handle_clone = self.app_handle.clone();
-            log::info!("Starting codebase analysis and file isolation for project: {}", path);
-
-            tokio::spawn(async move {
-                let project_path = std::path::PathBuf::from(&project_path_clone);
-
-                // Analyze codebase
-                let framework = match crate::codebase::analyze_and_save_codebase(&project_path).await {
-                    Ok(manifest) => {
-                        log::info!(
-                            "Codebase analysis complete: {} files, framework: {:?}",
-                            manifest.total_files,
-                            manifest.framework
-                        );
-                        manifest.framework
-                    }
-                    Err(e) => {
-                        log::warn!("Codebase analysis failed (non-critical): {}", e);
-                        None
-                    }
-                };
-
-                // Generate .claudeignore for file protection
-                if let Err(e) = crate::codebase::generate_claudeignore(
-                    &project_path,
-                    framework.as_deref()
-                ) {
-                    log::warn!("Failed to generate .claudeignore: {}", e);
-                }
+    /// Report meetings the previous run stopped recording without ending, so the UI can
+    /// offer to resume or finalize them. Call at startup, before any meeting starts.
+    pub fn detect_interrupted_meetings(&self) {
+        let interrupted = recovery::find_interrupted(&self.app_handle);
+        if interrupted.is_empty() {
+            return;
+        }
+        log::warn!(
+            "Found {} meeting(s) interrupted by the previous run: {}",
+            interrupted.len(),
+            interrupted.iter().map(|m| m.name.as_str()).collect::<Vec<_>>().join(", ")
+        );
+        events::emit(&self.app_handle, AppEvent::MeetingsInterrupted(interrupted));
+    }
 
-                // Create experiments directory for this meeting
-                if let Err(e) = crate::codebase::create_experiments_dir(
-                    &project_path,
-                    &meeting_id_clone
-                ) {
-                    log::warn!("Failed to create experiments directory: {}", e);
-                }
+    /// Meetings the previous run stopped recording without ending
+    pub async fn interrupted_meetings(&self) -> Vec<InterruptedMeeting> {
+        let meetings = self.active_meetings.lock().await;
+        recovery::find_interrupted(&self.app_handle)
+            .into_iter()
+            .filter(|m| !meetings.contains_key(&m.meeting_id))
+            .collect()
+    }
 
-                // Auto-create GitHub branch if enabled
-                if let Err(e) = crate::automation::github_workflow::auto_create_branch(
-                    &app_handle_clone,
-                    &project_path_clone,
-                    &meeting_id_clone,
-                    &meeting_name_clone,
-                ).await {
-                    log::info!("GitHub auto-branch creation skipped or failed: {}", e);
+    /// Rebuild an interrupted meeting from its files and resume recording it, end it
+    /// like any other meeting, or forget it. Returns the summary when finalized.
+    pub async fn recover_meeting(
+        &self,
+        meeting_id: &str,
+        action: RecoveryAction,
+    ) -> Result<Option<MeetingSummary>> {
+        if self.active_meetings.lock().await.contains_key(meeting_id) {
+            return Err(anyhow::anyhow!("Meeting {} is already active", meeting_id));
+        }
+        let (mut session, resume) = recovery::load(&self.app_handle, meeting_id)?;
+        match action {
+            RecoveryAction::Discard => {
+                recovery::clear(&self.app_handle, meeting_id);
+                log::info!("Discarded interrupted meeting: {}", session.name);
+                Ok(None)
+            }
+            RecoveryAction::Resume => {
+                if let Some(ref pp) = session.project_path {
+                    crate::automation::repo_config::bind(pp);
                 }
-            });
+                log::info!(
+                    "Resuming meeting {} at {:.0}s ({} segments recovered)",
+                    session.name,
+                    resume.elapsed_secs,
+                    session.transcript_segments.len()
+                );
+                self.active_meetings
+                    .lock()
+                    .await
+                    .insert(meeting_id.to_string(), session);
+                self.begin_capture(meeting_id.to_string(), Some(resume)).await;
+                Ok(None)
+            }
+            RecoveryAction::Finalize => {
+                session.end_time =
+                    Some(session.start_time + Duration::from_secs_f64(resume.elapsed_secs));
+                self.active_meetings
+                    .lock()
+                    .await
+                    .insert(meeting_id.to_string(), session);
+                self.end_meeting(meeting_id).await.map(Some)
+            }
         }
-
-        Ok(meeting_id)
     }
 
     /// Start a new meeting session without spawning the live transcription loop.
@@ -333,7 +624,10 @@ impl MeetingManager {
             let owner = settings.github_repo_owner.clone().unwrap();
             let repo = settings.github_repo_name.clone().unwrap();
             match crate::integrations::github::get_github_token()
-                .and_then(|token| crate::integrations::github::ensure_local_repo_clone(&owner, &repo, &token))
+                .and_then(|token| {
+                    let auth = crate::integrations::github::GitAuth::from_settings(&settings, &token);
+                    crate::integrations::github::ensure_local_repo_clone(&owner, &repo, &auth)
+                })
             {
                 Ok(repo_root) => {
                     // Seed meeting scaffolding inside the repo root
@@ -359,6 +653,9 @@ impl MeetingManager {
                 Err(e) => { log::warn!("Project initialization failed: {}", e); None }
             }
         };
+        if let Some(ref pp) = project_path {
+            crate::automation::repo_config::bind(pp);
+        }
 
         let meeting = MeetingSession {
             id: meeting_id.clone(),
@@ -369,6 +666,8 @@ impl MeetingManager {
             status: MeetingStatus::Recording,
             participants: Vec::new(),
             project_path,
+            translation_language: settings.meeting_translation.language.clone(),
+            cloud_asr: settings.cloud_asr.use_for_new_meetings,
         };
 
         // Insert into active meetings
@@ -395,7 +694,8 @@ impl MeetingManager {
                 let project_path = std::path::PathBuf::from(&project_path_clone);
 
                 // Analyze codebase
-                let framework = match crate::codebase::analyze_and_save_codebase(&project_path).await {
+                let ignore_patterns = crate::settings::get_settings(&app_handle_clone).codebase_ignore_patterns;
+                let framework = match crate::codebase::analyze_and_save_codebase(&project_path, &ignore_patterns).await {
                     Ok(manifest) => {
                         log::info!(
                             "Codebase analysis complete: {} files, framework: {:?}",
@@ -410,10 +710,27 @@ impl MeetingManager {
                     }
                 };
 
+                // Refresh the local embedding index used for prompt context
+                if crate::settings::get_settings(&app_handle_clone).codebase_embeddings_enabled {
+                    let app_for_index = app_handle_clone.clone();
+                    let index_root = project_path.clone();
+                    let index_ignores = ignore_patterns.clone();
+                    tokio::task::spawn_blocking(move || {
+                        if let Err(e) = crate::codebase::embeddings::refresh_project_index(
+                            &app_for_index,
+                            &index_root,
+                            &index_ignores,
+                        ) {
+                            log::warn!("Embedding index refresh failed (non-critical): {}", e);
+                        }
+                    });
+                }
+
                 // Generate .claudeignore for file protection
                 if let Err(e) = crate::codebase::generate_claudeignore(
                     &project_path,
-                    framework.as_deref()
+                    framework.as_deref(),
+                    &crate::settings::get_settings(&app_handle_clone).claudeignore_fragments,
                 ) {
                     log::warn!("Failed to generate .claudeignore: {}", e);
                 }
@@ -441,6 +758,22 @@ impl MeetingManager {
         Ok(meeting_id)
     }
 
+    /// Put back the session of an import that stopped partway, so it can continue
+    pub async fn resume_offline_meeting(&self, session: MeetingSession) {
+        if let Some(ref pp) = session.project_path {
+            crate::automation::repo_config::bind(pp);
+        }
+        self.active_meetings
+            .lock()
+            .await
+            .insert(session.id.clone(), session);
+    }
+
+    /// Drop an offline meeting without ending it, e.g. when its import is cancelled
+    pub async fn close_offline_meeting(&self, meeting_id: &str) {
+        self.active_meetings.lock().await.remove(meeting_id);
+    }
+
     /// Pause an active meeting
     pub async fn pause_meeting(&self, meeting_id: &str) -> Result<()> {
         let mut meetings = self.active_meetings.lock().await;
@@ -475,21 +808,55 @@ impl MeetingManager {
         }
     }
 
+    /// Bookmark the current moment of an active meeting. The meeting row is created in
+    /// the database if needed, since it is otherwise only stored when the meeting ends.
+    pub async fn add_marker(&self, meeting_id: &str, label: &str) -> Result<MeetingMarker> {
+        let meeting = self.get_meeting(meeting_id).await?;
+        let db = self
+            .app_handle
+            .try_state::<Arc<crate::storage::db::MeetingDb>>()
+            .ok_or_else(|| anyhow::anyhow!("Meeting database is not initialized"))?
+            .inner()
+            .clone();
+        let at_seconds = SystemTime::now()
+            .duration_since(meeting.start_time)
+            .unwrap_or_default()
+            .as_secs_f64();
+        db.ensure_meeting(&meeting)?;
+        let id = db.add_marker(meeting_id, at_seconds, label)?;
+        log::info!("Added marker at {:.1}s to meeting {}", at_seconds, meeting_id);
+        Ok(MeetingMarker {
+            id,
+            meeting_id: meeting_id.to_string(),
+            at_seconds,
+            label: label.to_string(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+
     /// End a meeting and return summary information
     pub async fn end_meeting(&self, meeting_id: &str) -> Result<MeetingSummary> {
         // Mark meeting as completed but keep it in memory so workers can still append
         let mut meetings = self.active_meetings.lock().await;
         if let Some(meeting) = meetings.get_mut(meeting_id) {
-            meeting.end_time = Some(SystemTime::now());
+            // Recovered meetings come with the end of their recorded audio
+            meeting.end_time.get_or_insert_with(SystemTime::now);
             meeting.status = MeetingStatus::Completed;
 
             // Clone the data we need before dropping the lock
             let meeting_clone = meeting.clone();
             drop(meetings);
 
+            tokio::task::spawn_blocking(crate::focus_mode::restore);
+
             let duration = meeting_clone.end_time.unwrap()
                 .duration_since(meeting_clone.start_time)
                 .unwrap_or(Duration::from_secs(0));
+            crate::metrics::record(
+                &self.app_handle,
+                crate::metrics::Metric::MeetingRecorded,
+                duration.as_secs_f64() / 60.0,
+            );
 
             let summary = MeetingSummary {
                 meeting_id: meeting_clone.id.clone(),
@@ -527,57 +894,130 @@ impl MeetingManager {
 
             // Now save transcript using latest in-memory segments
             let meeting_snapshot = {
-                let meetings = self.active_meetings.lock().await;
-                meetings.get(meeting_id).cloned().unwrap()
+                let mut meetings = self.active_meetings.lock().await;
+                let meeting = meetings.get_mut(meeting_id).unwrap();
+                // Chunks finished while the meeting wasn't in memory (after a restart) were
+                // kept in the project's rolling transcript
+                if let Some(pp) = meeting.project_path.clone() {
+                    let recovered =
+                        recovery::read_transcript(std::path::Path::new(&pp), meeting_id);
+                    let added =
+                        recovery::merge_recovered(&mut meeting.transcript_segments, recovered);
+                    if added > 0 {
+                        log::info!(
+                            "Re-attached {} recovered segment(s) to meeting {}",
+                            added,
+                            meeting_id
+                        );
+                    }
+                }
+                meeting.clone()
             };
-            match self.transcript_storage.save_transcript(&meeting_snapshot) {
+            // Resolved per save so a changed storage root takes effect without a restart
+            match TranscriptStorage::with_default_path().and_then(|s| s.save_transcript(&meeting_snapshot)) {
                 Err(e) => {
                     log::error!("Failed to save transcript for meeting {}: {}", meeting_snapshot.name, e);
                 }
                 Ok(meeting_dir) => {
                     log::info!("Transcript saved for meeting: {}", meeting_snapshot.name);
+                    let meeting_db = self
+                        .app_handle
+                        .try_state::<Arc<crate::storage::db::MeetingDb>>()
+                        .map(|db| db.inner().clone());
+                    if let Some(ref db) = meeting_db {
+                        let dir_name = meeting_dir.file_name().map(|n| n.to_string_lossy().to_string());
+                        if let Err(e) = db.save_meeting(&meeting_snapshot, dir_name.as_deref()) {
+                            log::warn!("Failed to save meeting {} to database: {}", meeting_snapshot.id, e);
+                        }
+                    }
+                    if let Some(history) = self
+                        .app_handle
+                        .try_state::<Arc<crate::managers::history::HistoryManager>>()
+                    {
+                        let dir_name = meeting_dir.file_name().map(|n| n.to_string_lossy().to_string());
+                        let text = meeting_snapshot
+                            .transcript_segments
+                            .iter()
+                            .map(|s| s.text.trim())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        let timestamp = meeting_snapshot
+                            .start_time
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map(|d| d.as_secs() as i64)
+                            .unwrap_or(0);
+                        if let Err(e) = history.upsert_meeting_entry(
+                            &meeting_snapshot.id,
+                            &meeting_snapshot.name,
+                            timestamp,
+                            dir_name.as_deref(),
+                            &text,
+                        ) {
+                            log::warn!("Failed to add meeting {} to history: {}", meeting_snapshot.id, e);
+                        }
+                    }
                     // Generate a lightweight summary.md similar to Zoom meeting summary
                     if !meeting_snapshot.transcript_segments.is_empty() {
-                        let start_idx = 0usize;
-                        let end_idx = meeting_snapshot.transcript_segments.len().saturating_sub(1);
-                        let summary = crate::summarization::agent::summarize_segments_with_context(
-                            meeting_snapshot.project_path.as_deref(),
+                        if let Err(e) = crate::storage::tasks::sync(
+                            &meeting_dir,
+                            &meeting_snapshot.id,
                             &meeting_snapshot.transcript_segments,
-                            start_idx,
-                            end_idx,
-                        );
-                        let mut md = String::new();
-                        use std::fmt::Write as _;
-                        let _ = writeln!(md, "# Meeting Summary\n");
-                        let minutes = duration.as_secs() / 60;
-                        let _ = writeln!(md, "**Title**: {}", meeting_snapshot.name);
-                        let _ = writeln!(md, "**Duration**: {} minutes\n", minutes);
-                        if !summary.new_features.is_empty() || !summary.new_features_structured.is_empty() {
-                            let _ = writeln!(md, "## Key Points / Features");
-                            if !summary.new_features_structured.is_empty() {
-                                for f in &summary.new_features_structured {
-                                    let _ = writeln!(md, "- {}", f.title);
-                                }
-                            } else {
-                                for s in &summary.new_features { let _ = writeln!(md, "- {}", s); }
-                            }
-                            let _ = writeln!(md);
-                        }
-                        if !summary.technical_decisions.is_empty() {
-                            let _ = writeln!(md, "## Decisions");
-                            for s in &summary.technical_decisions { let _ = writeln!(md, "- {}", s); }
-                            let _ = writeln!(md);
+                        ) {
+                            log::warn!("Failed to write tasks.json: {}", e);
                         }
-                        if !summary.questions.is_empty() {
-                            let _ = writeln!(md, "## Open Questions");
-                            for s in &summary.questions { let _ = writeln!(md, "- {}", s); }
-                            let _ = writeln!(md);
+                        if let Err(e) = crate::storage::analytics::sync(
+                            &meeting_dir,
+                            &meeting_snapshot.id,
+                            &meeting_snapshot.transcript_segments,
+                        ) {
+                            log::warn!("Failed to write analytics.json: {}", e);
                         }
+                        let md = summary_markdown(&meeting_snapshot);
                         // Save summary.md alongside transcript
                         let summary_path = meeting_dir.join("summary.md");
-                        if let Err(e) = std::fs::write(&summary_path, md) {
+                        if let Err(e) = std::fs::write(&summary_path, &md) {
                             log::warn!("Failed to write summary.md: {}", e);
                         }
+                        if let Some(ref db) = meeting_db {
+                            if let Err(e) = db.save_summary(&meeting_snapshot.id, "markdown", &md) {
+                                log::warn!("Failed to save summary to database: {}", e);
+                            }
+                        }
+                        if let Some(language) = meeting_snapshot.translation_language.clone() {
+                            let meeting_dir = meeting_dir.clone();
+                            let md = md.clone();
+                            tauri::async_runtime::spawn(async move {
+                                let written = crate::meeting::translation::write_translated_summary(
+                                    &meeting_dir,
+                                    &language,
+                                    &md,
+                                )
+                                .await;
+                                if let Err(e) = written {
+                                    log::warn!("Failed to write translated summary: {}", e);
+                                }
+                            });
+                        }
+                        crate::notifications::notify(
+                            &self.app_handle,
+                            crate::notifications::Milestone::SummaryReady,
+                            "Meeting summary ready",
+                            &meeting_snapshot.name,
+                        );
+                        crate::integrations::webhooks::dispatch(
+                            &self.app_handle,
+                            crate::settings::WebhookEvent::SummaryReady,
+                            serde_json::json!({
+                                "meeting_id": meeting_snapshot.id,
+                                "name": meeting_snapshot.name,
+                                "summary_markdown": md,
+                            }),
+                        );
+                    }
+                    if let (Some(ref db), Some(ref pp)) = (&meeting_db, &meeting_snapshot.project_path) {
+                        if let Err(e) = crate::storage::project_index::refresh_index(db, std::path::Path::new(pp)) {
+                            log::warn!("Failed to update project meeting index: {}", e);
+                        }
                     }
 
                     // Compose a final WAV by stitching persisted audio segments
@@ -650,18 +1090,74 @@ impl MeetingManager {
                             log::info!("No segment directory {:?} ({}); skipping final WAV composition", seg_dir, e);
                         }
                     }
+
+                    // Upload finalized artifacts in the background (no-op unless enabled)
+                    crate::integrations::cloud_sync::spawn_sync_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                        &meeting_dir,
+                    );
+                    crate::integrations::slack::spawn_post_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::teams::spawn_post_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::discord::spawn_post_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                        meeting_snapshot.project_path.as_deref(),
+                    );
+                    crate::integrations::email::spawn_send_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::obsidian::spawn_export_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::asana::spawn_sync_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::trello::spawn_push_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::calendar_writeback::spawn_write_back_after_meeting(
+                        &self.app_handle,
+                        &meeting_snapshot.id,
+                    );
+                    crate::integrations::webhooks::dispatch(
+                        &self.app_handle,
+                        crate::settings::WebhookEvent::MeetingEnded,
+                        crate::integrations::webhooks::meeting_data(&meeting_snapshot),
+                    );
                 }
             }
 
             // Auto-stop system audio capture and clear buffer when meeting ends,
             // so Diagnostics doesn't keep filling after stop.
-            if let AudioSource::SystemAudio(_) = self.audio_manager.get_audio_source() {
-                if let Err(e) = self.audio_manager.stop_system_audio() {
-                    log::warn!("Failed to stop system audio at meeting end: {}", e);
-                } else {
-                    self.audio_manager.clear_system_audio_buffer();
-                    log::info!("Stopped system audio capture and cleared buffer after meeting end");
+            match self.audio_manager.get_audio_source() {
+                AudioSource::SystemAudio(_) => {
+                    if let Err(e) = self.audio_manager.stop_system_audio() {
+                        log::warn!("Failed to stop system audio at meeting end: {}", e);
+                    } else {
+                        self.audio_manager.clear_system_audio_buffer();
+                        log::info!("Stopped system audio capture and cleared buffer after meeting end");
+                    }
+                }
+                AudioSource::Dual(_) => {
+                    if let Err(e) = self.audio_manager.stop_dual_capture() {
+                        log::warn!("Failed to stop dual capture at meeting end: {}", e);
+                    } else {
+                        self.audio_manager.clear_system_audio_buffer();
+                        log::info!("Stopped dual capture and cleared buffer after meeting end");
+                    }
                 }
+                AudioSource::Microphone => {}
             }
 
             log::info!(
@@ -671,6 +1167,9 @@ impl MeetingManager {
                 meeting_snapshot.transcript_segments.len()
             );
             // Finally, remove meeting from memory
+            crate::diarization::forget_meeting(meeting_id);
+            crate::automation::command_suggestions::forget_meeting(meeting_id);
+            recovery::clear(&self.app_handle, meeting_id);
             let mut meetings = self.active_meetings.lock().await;
             meetings.remove(meeting_id);
             Ok(MeetingSummary { total_segments: meeting_snapshot.transcript_segments.len(), ..summary })
@@ -689,6 +1188,17 @@ impl MeetingManager {
                 meeting.participants.push(segment.speaker.clone());
             }
 
+            crate::integrations::jira::observe_text(&self.app_handle, &segment.text);
+            if let Some(language) = meeting.translation_language.clone() {
+                crate::meeting::translation::spawn_segment_translation(
+                    &self.app_handle,
+                    meeting_id.to_string(),
+                    meeting.transcript_segments.len(),
+                    segment.text.clone(),
+                    language,
+                    None,
+                );
+            }
             meeting.transcript_segments.push(segment);
             Ok(())
         } else {
@@ -708,6 +1218,46 @@ impl MeetingManager {
     }
 
     /// Get the complete meeting session data
+    /// Record the translation of segment `index` of an active meeting
+    pub async fn set_segment_translation(
+        &self,
+        meeting_id: &str,
+        index: usize,
+        translated_text: String,
+    ) -> Result<()> {
+        let mut meetings = self.active_meetings.lock().await;
+        let segment = meetings
+            .get_mut(meeting_id)
+            .and_then(|m| m.transcript_segments.get_mut(index))
+            .ok_or_else(|| anyhow::anyhow!("Segment {} of {} not found", index, meeting_id))?;
+        segment.translated_text = Some(translated_text);
+        Ok(())
+    }
+
+    /// Translate the meeting's segments from now on into `language`, or stop with None
+    pub async fn set_translation_language(
+        &self,
+        meeting_id: &str,
+        language: Option<String>,
+    ) -> Result<()> {
+        let mut meetings = self.active_meetings.lock().await;
+        let meeting = meetings
+            .get_mut(meeting_id)
+            .ok_or_else(|| anyhow::anyhow!("Meeting not found: {}", meeting_id))?;
+        meeting.translation_language = language;
+        Ok(())
+    }
+
+    /// Switch the meeting's remaining chunks between the cloud provider and the local model
+    pub async fn set_cloud_asr(&self, meeting_id: &str, enabled: bool) -> Result<()> {
+        let mut meetings = self.active_meetings.lock().await;
+        let meeting = meetings
+            .get_mut(meeting_id)
+            .ok_or_else(|| anyhow::anyhow!("Meeting not found: {}", meeting_id))?;
+        meeting.cloud_asr = enabled;
+        Ok(())
+    }
+
     pub async fn get_meeting(&self, meeting_id: &str) -> Result<MeetingSession> {
         let meetings = self.active_meetings.lock().await;
 
@@ -727,18 +1277,7 @@ impl MeetingManager {
         let mut meetings = self.active_meetings.lock().await;
 
         if let Some(meeting) = meetings.get_mut(meeting_id) {
-            // Update all segments
-            for segment in &mut meeting.transcript_segments {
-                if let Some(new_label) = mapping.get(&segment.speaker) {
-                    segment.speaker = new_label.clone();
-                }
-            }
-
-            // Update participants list
-            meeting.participants = meeting.participants.iter()
-                .map(|p| mapping.get(p).cloned().unwrap_or_else(|| p.clone()))
-                .collect();
-
+            meeting.relabel_speakers(&mapping);
             log::info!("Updated speaker labels for meeting: {}", meeting_id);
             Ok(())
         } else {
@@ -746,7 +1285,44 @@ impl MeetingManager {
         }
     }
 
+    /// Add names to a meeting's participants, e.g. a calendar event's attendees
+    pub async fn add_participants(&self, meeting_id: &str, names: &[String]) -> Result<()> {
+        let mut meetings = self.active_meetings.lock().await;
+        let meeting = meetings
+            .get_mut(meeting_id)
+            .ok_or_else(|| anyhow::anyhow!("Meeting not found: {}", meeting_id))?;
+        for name in names {
+            if !meeting.participants.contains(name) {
+                meeting.participants.push(name.clone());
+            }
+        }
+        Ok(())
+    }
+
     /// Get list of all active meeting IDs
+    /// The meeting in progress, if any
+    pub async fn current_meeting(&self) -> Option<ActiveMeetingInfo> {
+        current_of(&*self.active_meetings.lock().await)
+    }
+
+    /// Like `current_meeting` for sync callers (tray); returns `None` instead of blocking
+    /// when the meeting table is locked
+    pub fn try_current_meeting(&self) -> Option<Option<ActiveMeetingInfo>> {
+        let meetings = self.active_meetings.try_lock().ok()?;
+        Some(current_of(&meetings))
+    }
+
+    /// Last `n` transcript segments of the current meeting; empty when none is running
+    pub async fn recent_transcript_lines(&self, n: usize) -> Vec<TranscriptSegment> {
+        recent_lines_of(&*self.active_meetings.lock().await, n)
+    }
+
+    /// Like `recent_transcript_lines` for sync callers; `None` when the table is locked
+    pub fn try_recent_transcript_lines(&self, n: usize) -> Option<Vec<TranscriptSegment>> {
+        let meetings = self.active_meetings.try_lock().ok()?;
+        Some(recent_lines_of(&meetings, n))
+    }
+
     pub async fn get_active_meetings(&self) -> Vec<String> {
         let meetings = self.active_meetings.lock().await;
         meetings.keys().cloned().collect()
@@ -767,14 +1343,16 @@ impl MeetingManager {
         audio_manager: Arc<AudioRecordingManager>,
         transcription_manager: Arc<TranscriptionManager>,
         app_handle: AppHandle,
+        resume: Option<ResumePoint>,
     ) {
         log::info!("Starting transcription loop for meeting: {}", meeting_id);
 
-        let mut segment_index = 0;
+        let resume = resume.unwrap_or_default();
+        let mut segment_index = resume.segment_index;
         let mut diar_state = LiveDiarState::default();
-        let mut last_sent_index: usize = 0;
+        let mut last_sent_index: usize = resume.sent_segments;
         let mut last_update_instant = std::time::Instant::now();
-        let mut accumulated_time: f64 = 0.0;
+        let mut accumulated_time: f64 = resume.elapsed_secs;
         // Append stats for SOAK instrumentation
         #[derive(Default, Clone)]
         struct AppendStats { updates_written: u64, max_append_ms: u128 }
@@ -800,7 +1378,10 @@ impl MeetingManager {
         let mut backlog_high_count: u32 = 0;
         let mut last_overwritten = audio_manager.get_system_audio_overwritten_count();
 
-        loop {
+        // Interim hypotheses for the chunk being recorded (streaming mode)
+        let mut partials = PartialStream::default();
+
+        'chunks: loop {
             // Re-read chunk duration each iteration for live setting updates
             let settings = settings::get_settings(&app_handle);
             let mut chunk_secs = settings.transcription_chunk_seconds as f32;
@@ -808,8 +1389,44 @@ impl MeetingManager {
             if chunk_secs > 60.0 { chunk_secs = 60.0; }
             let chunk_duration = chunk_secs; // seconds
 
-            // Sleep for the chunk duration
-            tokio::time::sleep(Duration::from_secs_f32(chunk_duration)).await;
+            // A chunk that never became a segment (silence, queued) clears its partial
+            if partials.is_active() {
+                transcription_manager.emit_partial(&partials.finalize(&meeting_id, accumulated_time, ""));
+            }
+
+            // Sleep for the chunk duration; in streaming mode, transcribe what has been
+            // captured so far every `streaming_interval_ms` and emit it as a partial
+            let chunk_start_time = accumulated_time;
+            let deadline = tokio::time::Instant::now() + Duration::from_secs_f32(chunk_duration);
+            loop {
+                let settings = settings::get_settings(&app_handle);
+                if !settings.streaming_transcription {
+                    tokio::time::sleep_until(deadline).await;
+                    break;
+                }
+                let interval = Duration::from_millis(settings.streaming_interval_ms as u64);
+                tokio::time::sleep_until(deadline.min(tokio::time::Instant::now() + interval)).await;
+                if tokio::time::Instant::now() >= deadline {
+                    break;
+                }
+                let pending = audio_manager.peek_pending_audio(chunk_duration);
+                // Under half a second, or silence: nothing worth showing yet
+                if pending.len() < 8_000 || live_dbfs(&pending) < settings.system_audio_silence_threshold {
+                    continue;
+                }
+                let partial = tokio::task::spawn_blocking({
+                    let transcription_manager = transcription_manager.clone();
+                    move || transcription_manager.transcribe_partial(pending)
+                }).await;
+                match partial {
+                    Ok(Ok(text)) if !text.trim().is_empty() => {
+                        let partial = partials.update(&meeting_id, chunk_start_time, &text);
+                        transcription_manager.emit_partial(&partial);
+                    }
+                    Ok(Err(e)) => log::debug!("Partial transcription failed: {}", e),
+                    _ => {}
+                }
+            }
 
             // Check if meeting still exists and is recording
             let should_continue = {
@@ -842,6 +1459,18 @@ impl MeetingManager {
             }
 
             let audio_chunk = audio_manager.get_system_audio_buffer(chunk_duration);
+            // Dual capture transcribes the microphone ("Me") and system audio ("Remote")
+            // separately; their mix is what gets persisted and silence-gated
+            let mut channels: Vec<(Option<&str>, Vec<f32>)> = Vec::new();
+            let audio_chunk = if matches!(audio_manager.get_audio_source(), AudioSource::Dual(_)) {
+                let mic_chunk = audio_manager.get_microphone_audio_buffer(chunk_duration);
+                let mixed = crate::managers::audio::mix_channels(&audio_chunk, &mic_chunk);
+                channels.push((Some(LOCAL_SPEAKER), mic_chunk));
+                channels.push((Some(REMOTE_SPEAKER), audio_chunk));
+                mixed
+            } else {
+                audio_chunk
+            };
 
             if audio_chunk.is_empty() {
                 consecutive_empty_chunks += 1;
@@ -871,14 +1500,7 @@ impl MeetingManager {
 
                         // Count attempt and emit restart notification to frontend
                         audio_manager.record_restart_attempt();
-                        #[derive(Clone, Serialize)]
-                        struct AudioRestartPayload {
-                            meeting_id: String,
-                            attempt: u32,
-                            max_attempts: u32,
-                        }
-
-                        let _ = app_handle.emit("audio-stream-restarting", AudioRestartPayload {
+                        events::emit(&app_handle, AppEvent::AudioStreamRestarting {
                             meeting_id: meeting_id.clone(),
                             attempt: restart_attempts + 1,
                             max_attempts: MAX_RESTART_ATTEMPTS,
@@ -896,12 +1518,7 @@ impl MeetingManager {
 
                                 // Count success and emit success notification
                                 audio_manager.record_restart_success();
-                                #[derive(Clone, Serialize)]
-                                struct AudioRestartSuccessPayload {
-                                    meeting_id: String,
-                                }
-
-                                let _ = app_handle.emit("audio-stream-restart-success", AudioRestartSuccessPayload {
+                                events::emit(&app_handle, AppEvent::AudioStreamRestartSuccess {
                                     meeting_id: meeting_id.clone(),
                                 });
 
@@ -919,16 +1536,16 @@ impl MeetingManager {
                                 restart_in_progress = false;
 
                                 // Record failure and emit failure notification with the error
-                                #[derive(Clone, Serialize)]
-                                struct AudioRestartFailedPayload {
-                                    meeting_id: String,
-                                    error: String,
-                                    attempts_remaining: u32,
-                                }
-
                                 let err_str = e.to_string();
                                 audio_manager.record_restart_failure(err_str.clone());
-                                let _ = app_handle.emit("audio-stream-restart-failed", AudioRestartFailedPayload {
+                                crate::tray::flag_tray_error();
+                                crate::notifications::notify(
+                                    &app_handle,
+                                    crate::notifications::Milestone::AudioStreamFailed,
+                                    "Audio capture failed",
+                                    &format!("Couldn't restart the audio stream: {}", err_str),
+                                );
+                                events::emit(&app_handle, AppEvent::AudioStreamRestartFailed {
                                     meeting_id: meeting_id.clone(),
                                     error: err_str,
                                     attempts_remaining: MAX_RESTART_ATTEMPTS.saturating_sub(restart_attempts),
@@ -950,14 +1567,8 @@ impl MeetingManager {
                             time_since_last_restart
                         );
 
-                        #[derive(Clone, Serialize)]
-                        struct AudioWarningPayload {
-                            meeting_id: String,
-                            message: String,
-                            consecutive_empty_chunks: u32,
-                        }
-
-                        let _ = app_handle.emit("audio-stream-warning", AudioWarningPayload {
+                        crate::tray::flag_tray_error();
+                        events::emit(&app_handle, AppEvent::AudioStreamWarning {
                             meeting_id: meeting_id.clone(),
                             message: format!(
                                 "Audio stream failed after {} restart attempts. Please manually restart the meeting.",
@@ -1007,9 +1618,7 @@ impl MeetingManager {
             if dbfs < th {
                 log::info!("Skipping transcription for silent chunk (dBFS {:.1} < threshold {:.1})", dbfs, th);
                 // Emit a lightweight event for diagnostics if desired
-                #[derive(Clone, Serialize)]
-                struct SilentChunkPayload { meeting_id: String, dbfs: f32, threshold: f32 }
-                let _ = app_handle.emit("audio-silent-chunk", SilentChunkPayload { meeting_id: meeting_id.clone(), dbfs, threshold: th });
+                events::emit(&app_handle, AppEvent::AudioSilentChunk { meeting_id: meeting_id.clone(), dbfs, threshold: th });
                 continue;
             }
 
@@ -1039,8 +1648,9 @@ impl MeetingManager {
                     log::warn!("Failed to persist audio segment {:?}: {}", fpath, e);
                 } else {
                     log::info!("Persisted audio segment to {:?}", fpath);
-                    // Enqueue for ASR worker if queue mode is enabled
-                    if settings::get_settings(&app_handle).use_queue_transcription {
+                    // Enqueue for ASR worker if queue mode is enabled; dual capture stays
+                    // in-loop so each channel keeps its speaker
+                    if settings::get_settings(&app_handle).use_queue_transcription && channels.is_empty() {
                         if let Some(q) = app_handle.try_state::<std::sync::Arc<crate::queue::Queue>>() {
                             let start_ms = (start_time * 1000.0) as u64;
                             let end_ms = (end_time * 1000.0) as u64;
@@ -1069,101 +1679,180 @@ impl MeetingManager {
                 }
             }
 
-            // Transcribe the audio chunk (blocking operation)
-            let transcription_result = tokio::task::spawn_blocking({
-                let transcription_manager = transcription_manager.clone();
-                let audio_chunk = audio_chunk.clone();
-                move || transcription_manager.transcribe(audio_chunk)
-            }).await;
-
-            let text = match transcription_result {
-                Ok(Ok(transcribed_text)) => transcribed_text,
-                Ok(Err(e)) => {
-                    log::error!("Transcription error: {}", e);
-                    continue;
-                }
-                Err(e) => {
-                    log::error!("Task join error: {}", e);
-                    continue;
-                }
-            };
-
-            log::info!("Transcription result: '{}' (length: {} chars)", text, text.len());
-
-            // Skip empty transcriptions
-            if text.trim().is_empty() {
-                log::warn!("Empty transcription returned from model, skipping segment {}", segment_index);
-                continue;
+            // Transcribe each channel (blocking operation); a single one unless dual capture
+            if channels.is_empty() {
+                channels.push((None, audio_chunk.clone()));
+            } else {
+                channels.retain(|(_, samples)| live_dbfs(samples) >= th);
             }
-
-            // Calculate segment timing
             let start_time = accumulated_time;
             let end_time = start_time + chunk_duration as f64;
-
-            // Create transcript segment with coarse pause-based speaker toggling
-            let turn_boundary = live_silence_fraction(&audio_chunk, 1e-3) > 0.20; // coarse threshold
-            let speaker_label = live_next_speaker_label(&mut diar_state, turn_boundary);
-            let segment = TranscriptSegment {
-                speaker: speaker_label.clone(),
-                start_time,
-                end_time,
-                text: text.clone(),
-                confidence: 0.95, // Placeholder confidence
-                timestamp: SystemTime::now(),
-            };
-
-            // Add segment to meeting and capture project path for transcript write
-            let mut project_path_for_segment: Option<String> = None;
-            {
-                let mut meetings = active_meetings.lock().await;
-                if let Some(meeting) = meetings.get_mut(&meeting_id) {
-                    // Track new speakers
-                    if !meeting.participants.contains(&segment.speaker) {
-                        meeting.participants.push(segment.speaker.clone());
+            let mut transcribed_any = false;
+            // What was said just before, as context for the model, and where to send the audio
+            let (previous_text, cloud_asr) = active_meetings
+                .lock()
+                .await
+                .get(&meeting_id)
+                .map(|m| {
+                    let previous = m.transcript_segments.last().map(|s| s.text.clone());
+                    (previous.unwrap_or_default(), m.cloud_asr)
+                })
+                .unwrap_or_default();
+            for (channel_speaker, channel_audio) in channels {
+                let transcription_result = tokio::task::spawn_blocking({
+                    let transcription_manager = transcription_manager.clone();
+                    let channel_audio = channel_audio.clone();
+                    let previous_text = previous_text.clone();
+                    move || {
+                        transcription_manager.transcribe_for_meeting(
+                            channel_audio,
+                            &previous_text,
+                            cloud_asr,
+                        )
                     }
-                    meeting.transcript_segments.push(segment.clone());
-                    project_path_for_segment = meeting.project_path.clone();
+                }).await;
 
-                    log::info!("Added segment {} to meeting: {}", segment_index, meeting_id);
-                } else {
-                    log::warn!("Meeting not found while adding segment: {}", meeting_id);
-                    break;
-                }
-            }
+                let (text, confidence, words) = match transcription_result {
+                    Ok(Ok(transcription)) => {
+                        let confidence = transcription.segment_confidence();
+                        let words = transcription.words_at(start_time);
+                        (transcription.text, confidence, words)
+                    }
+                    Ok(Err(e)) => {
+                        log::error!("Transcription error: {}", e);
+                        continue;
+                    }
+                    Err(e) => {
+                        log::error!("Task join error: {}", e);
+                        continue;
+                    }
+                };
 
-            // Emit event to frontend
-            #[derive(Clone, Serialize)]
-            struct SegmentAddedPayload {
-                meeting_id: String,
-                segment: TranscriptSegment,
-            }
+                log::info!("Transcription result: '{}' (length: {} chars)", text, text.len());
 
-            let _ = app_handle.emit("transcript-segment-added", SegmentAddedPayload {
-                meeting_id: meeting_id.clone(),
-                segment,
-            });
+                // Skip empty transcriptions
+                if text.trim().is_empty() {
+                    log::warn!("Empty transcription returned from model, skipping segment {}", segment_index);
+                    continue;
+                }
+                if crate::managers::transcription::should_drop(confidence) {
+                    log::info!(
+                        "Dropping low-confidence segment {} ({:.2})",
+                        segment_index,
+                        confidence
+                    );
+                    continue;
+                }
 
-            // Append rolling transcript line in project folder (non-blocking)
-            if let Some(pp) = project_path_for_segment.clone() {
-                // Build a fresh segment (avoid borrowing moved values)
-                let seg_clone = TranscriptSegment {
-                    speaker: speaker_label,
+                // Label the speaker by voice when diarization is on, else by pause-based toggling
+                // (dual capture already knows the speaker from the channel)
+                let speaker_label = match channel_speaker {
+                    Some(label) => label.to_string(),
+                    None => {
+                        let voice_label = tokio::task::spawn_blocking({
+                            let app_handle = app_handle.clone();
+                            let meeting_id = meeting_id.clone();
+                            let channel_audio = channel_audio.clone();
+                            move || crate::diarization::live_label(&app_handle, &meeting_id, &channel_audio)
+                        }).await.ok().flatten();
+                        voice_label.unwrap_or_else(|| {
+                            let turn_boundary = live_silence_fraction(&channel_audio, 1e-3) > 0.20; // coarse threshold
+                            live_next_speaker_label(&mut diar_state, turn_boundary)
+                        })
+                    }
+                };
+                let segment = TranscriptSegment {
+                    speaker: speaker_label.clone(),
                     start_time,
                     end_time,
                     text: text.clone(),
-                    confidence: 0.95,
+                    confidence,
                     timestamp: SystemTime::now(),
+                    words: words.clone(),
+                    translated_text: None,
                 };
-                let meeting_id_clone = meeting_id.clone();
-                let idx = segment_index;
-                tokio::task::spawn_blocking(move || {
-                    if let Err(e) = crate::meeting::transcript_writer::append_segment(&pp, &meeting_id_clone, idx, &seg_clone) {
-                        log::warn!("Failed to append transcript segment: {}", e);
+
+                // Add segment to meeting and capture project path for transcript write
+                let mut project_path_for_segment: Option<String> = None;
+                let mut recent_segments: Vec<TranscriptSegment> = Vec::new();
+                {
+                    let mut meetings = active_meetings.lock().await;
+                    if let Some(meeting) = meetings.get_mut(&meeting_id) {
+                        // Track new speakers
+                        if !meeting.participants.contains(&segment.speaker) {
+                            meeting.participants.push(segment.speaker.clone());
+                        }
+                        if let Some(language) = meeting.translation_language.clone() {
+                            crate::meeting::translation::spawn_segment_translation(
+                                &app_handle,
+                                meeting_id.clone(),
+                                meeting.transcript_segments.len(),
+                                text.clone(),
+                                language,
+                                Some(channel_audio.clone()),
+                            );
+                        }
+                        meeting.transcript_segments.push(segment.clone());
+                        project_path_for_segment = meeting.project_path.clone();
+                        let tail_start = meeting.transcript_segments.len().saturating_sub(5);
+                        recent_segments = meeting.transcript_segments[tail_start..].to_vec();
+
+                        log::info!("Added segment {} to meeting: {}", segment_index, meeting_id);
+                    } else {
+                        log::warn!("Meeting not found while adding segment: {}", meeting_id);
+                        break 'chunks;
                     }
+                }
+
+                // Emit event to frontend
+                crate::overlay::emit_caption(&app_handle, &segment.text);
+                crate::integrations::jira::observe_text(&app_handle, &segment.text);
+                events::emit(&app_handle, AppEvent::TranscriptSegmentAdded {
+                    meeting_id: meeting_id.clone(),
+                    segment,
                 });
-            }
+                if partials.is_active() {
+                    transcription_manager.emit_partial(&partials.finalize(&meeting_id, start_time, &text));
+                }
 
-            segment_index += 1;
+                // Append rolling transcript line in project folder (non-blocking)
+                if let Some(pp) = project_path_for_segment.clone() {
+                    // Build a fresh segment (avoid borrowing moved values)
+                    let seg_clone = TranscriptSegment {
+                        speaker: speaker_label,
+                        start_time,
+                        end_time,
+                        text: text.clone(),
+                        confidence,
+                        timestamp: SystemTime::now(),
+                        words,
+                        translated_text: None,
+                    };
+                    let meeting_id_clone = meeting_id.clone();
+                    let idx = segment_index;
+                    tokio::task::spawn_blocking(move || {
+                        if let Err(e) = crate::meeting::transcript_writer::append_segment(&pp, &meeting_id_clone, idx, &seg_clone) {
+                            log::warn!("Failed to append transcript segment: {}", e);
+                        }
+                    });
+
+                    // Fire a targeted agent prompt if a trigger phrase was spoken
+                    let app_clone = app_handle.clone();
+                    let pp = project_path_for_segment.clone().unwrap_or_default();
+                    let meeting_id_clone = meeting_id.clone();
+                    tokio::task::spawn_blocking(move || {
+                        crate::automation::keyword_trigger::maybe_fire(&app_clone, &pp, &meeting_id_clone, &recent_segments);
+                        // Surface (never run) shell commands for spoken "run X / deploy Y" requests
+                        crate::automation::command_suggestions::observe_segment(&app_clone, &pp, &meeting_id_clone, &recent_segments);
+                    });
+                }
+
+                segment_index += 1;
+                transcribed_any = true;
+            }
+            if !transcribed_any {
+                continue;
+            }
             accumulated_time = end_time;
 
             // Append meeting update on configured interval
@@ -1191,22 +1880,32 @@ impl MeetingManager {
                         // Determine if this is the first update (for project type detection)
                         let is_first_update = last_sent_index == 0;
 
-                        // Use LLM summarization if enabled and API key is configured
-                        let summary = if settings_now.use_llm_summarization && crate::summarization::llm::has_api_key() {
+                        // Use LLM summarization if enabled and the provider is configured
+                        let summary = if settings_now.use_llm_summarization && crate::summarization::llm::is_available() {
                             log::info!("Using LLM-based summarization (model: {})", settings_now.llm_model);
+                            let discussion: Vec<&str> = new_segments.iter().map(|s| s.text.as_str()).collect();
+                            let snippets = crate::codebase::embeddings::retrieve(
+                                &app_handle,
+                                std::path::Path::new(&project_path_clone),
+                                &discussion.join(" "),
+                                3,
+                            );
+                            let code_context = crate::codebase::embeddings::format_snippets(&snippets);
                             match crate::summarization::llm::summarize_with_llm(
-                                &settings_now.llm_model,
                                 new_segments,
                                 start_idx,
                                 end_idx,
                                 is_first_update,
+                                Some(code_context.as_str()),
                             ).await {
                                 Ok(summary) => {
                                     log::info!("LLM summarization successful");
+                                    crate::metrics::record(&app_handle, crate::metrics::Metric::LlmSummary, 1.0);
                                     summary
                                 },
                                 Err(e) => {
                                     log::warn!("LLM summarization failed: {}, falling back to heuristic agent", e);
+                                    crate::metrics::record(&app_handle, crate::metrics::Metric::LlmSummary, 0.0);
                                     // Fallback to heuristic agent
                                     crate::summarization::agent::summarize_segments_with_context(
                                         Some(project_path_clone.as_str()),
@@ -1230,6 +1929,7 @@ impl MeetingManager {
                         let source_label = match audio_manager.get_audio_source() {
                             crate::managers::audio::AudioSource::Microphone => "microphone".to_string(),
                             crate::managers::audio::AudioSource::SystemAudio(_) => "system_audio".to_string(),
+                            crate::managers::audio::AudioSource::Dual(_) => "dual".to_string(),
                         };
                         let meeting_name = {
                             let meetings = active_meetings.lock().await;
@@ -1246,6 +1946,8 @@ impl MeetingManager {
                         let current_model_owned = current_model.clone();
                         let source_label_owned = source_label.clone();
                         let summary_owned = summary.clone();
+                        let tickets =
+                            crate::integrations::jira::tickets_in_segments(new_segments);
                         tokio::spawn(async move {
                             let started = std::time::Instant::now();
                             let mut attempt: u32 = 0;
@@ -1260,6 +1962,7 @@ impl MeetingManager {
                                     &current_model_owned,
                                     &source_label_owned,
                                     &summary_owned,
+                                    &tickets,
                                 ) {
                                     Ok(update_id) => {
                                         update_id_opt = Some(update_id);
@@ -1288,11 +1991,9 @@ impl MeetingManager {
                             }
 
                             if let Some(update_id) = update_id_opt {
-                                #[derive(Clone, Serialize)]
-                                struct UpdatePayload { update_id: u32, meeting_id: String }
-                                let _ = app_handle_clone.emit(
-                                    "meeting-update-appended",
-                                    UpdatePayload { update_id, meeting_id: meeting_id_clone.clone() },
+                                events::emit(
+                                    &app_handle_clone,
+                                    AppEvent::MeetingUpdateAppended { update_id, meeting_id: meeting_id_clone.clone() },
                                 );
                                 log::info!(
                                     "Appended meeting update {} for segments [{}..={}] in {}ms",
@@ -1427,6 +2128,8 @@ mod tests {
             text: "Hello world".to_string(),
             confidence: 0.95,
             timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
         }).await.unwrap();
 
         // Get transcript
@@ -1477,6 +2180,8 @@ mod tests {
             text: "First".to_string(),
             confidence: 0.9,
             timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
         }).await.unwrap();
 
         manager.add_segment(&meeting_id, TranscriptSegment {
@@ -1486,6 +2191,8 @@ mod tests {
             text: "Second".to_string(),
             confidence: 0.9,
             timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
         }).await.unwrap();
 
         // Update labels
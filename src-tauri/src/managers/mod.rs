@@ -1,4 +1,5 @@
 pub mod audio;
+pub mod cloud_asr;
 pub mod history;
 pub mod meeting;
 pub mod model;
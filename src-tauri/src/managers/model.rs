@@ -1,3 +1,4 @@
+use crate::network::{self, Service};
 use crate::settings::{get_settings, write_settings};
 use anyhow::Result;
 use flate2::read::GzDecoder;
@@ -17,6 +18,7 @@ use tauri::{AppHandle, Emitter, Manager};
 pub enum EngineType {
     Whisper,
     Parakeet,
+    Moonshine,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -182,6 +184,26 @@ impl ModelManager {
             },
         );
 
+        // Useful Sensors Moonshine (ONNX, directory-based): small and fast on CPUs
+        available_models.insert(
+            "moonshine-base".to_string(),
+            ModelInfo {
+                id: "moonshine-base".to_string(),
+                name: "Moonshine Base".to_string(),
+                description: "English only. Very fast on CPUs without a GPU.".to_string(),
+                filename: "moonshine-base".to_string(), // Directory name
+                url: Some("https://blob.handy.computer/moonshine-base.tar.gz".to_string()),
+                size_mb: 58, // Approximate size
+                is_downloaded: false,
+                is_downloading: false,
+                partial_size: 0,
+                is_directory: true,
+                engine_type: EngineType::Moonshine,
+                accuracy_score: 0.65,
+                speed_score: 0.95,
+            },
+        );
+
         let manager = Self {
             app_handle: app_handle.clone(),
             models_dir,
@@ -354,6 +376,9 @@ impl ModelManager {
             0
         };
 
+        // Refused in offline mode before the model is marked as downloading
+        network::check(Service::ModelDownload, &url)?;
+
         // Mark as downloading
         {
             let mut models = self.available_models.lock().unwrap();
@@ -363,7 +388,7 @@ impl ModelManager {
         }
 
         // Security: Create HTTP client with explicit TLS configuration
-        let client = reqwest::Client::builder()
+        let client = network::client_builder(Service::ModelDownload, &url)?
             .danger_accept_invalid_certs(false) // Explicitly reject invalid certs
             .min_tls_version(reqwest::tls::Version::TLS_1_2) // Enforce TLS 1.2+
             .build()?;
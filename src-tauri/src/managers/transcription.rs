@@ -1,22 +1,29 @@
-use crate::audio_toolkit::apply_custom_words;
+use crate::audio_toolkit::{apply_custom_words, build_initial_prompt};
+use crate::managers::cloud_asr;
+use crate::managers::meeting::WordTiming;
 use crate::managers::model::{EngineType, ModelManager};
-use crate::settings::{get_settings, ModelUnloadTimeout};
+use crate::settings::{
+    get_settings, AppSettings, GpuScheduling, LowConfidenceAction, LowConfidenceConfig,
+    ModelUnloadTimeout,
+};
 use anyhow::Result;
 use log::debug;
 use serde::Serialize;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
 use std::thread;
 use std::time::{Duration, SystemTime};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 use transcribe_rs::{
     engines::{
+        moonshine::{ModelVariant, MoonshineEngine, MoonshineModelParams},
         parakeet::{
             ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
         },
         whisper::{WhisperEngine, WhisperInferenceParams},
     },
-    TranscriptionEngine,
+    TranscriptionEngine, TranscriptionResult,
 };
 
 #[derive(Clone, Debug, Serialize)]
@@ -27,14 +34,302 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
-enum LoadedEngine {
-    Whisper(WhisperEngine),
-    Parakeet(ParakeetEngine),
+/// Confidence recorded for segments from engines that don't report one
+pub const UNREPORTED_CONFIDENCE: f32 = 0.95;
+
+/// What the engine heard in a chunk
+#[derive(Clone, Debug)]
+pub struct Transcription {
+    pub text: String,
+    /// Probability of the text (0..1), when the engine reports one
+    pub confidence: Option<f32>,
+    /// Word timings relative to the start of the transcribed audio
+    pub words: Vec<WordTiming>,
+}
+
+impl Transcription {
+    /// Confidence to store on a segment made from this transcription
+    pub fn segment_confidence(&self) -> f32 {
+        self.confidence.unwrap_or(UNREPORTED_CONFIDENCE)
+    }
+
+    /// `words` shifted to seconds from meeting start, for audio that began at `offset`
+    pub fn words_at(&self, offset: f64) -> Vec<WordTiming> {
+        self.words
+            .iter()
+            .map(|w| WordTiming {
+                word: w.word.clone(),
+                start: w.start + offset,
+                end: w.end + offset,
+            })
+            .collect()
+    }
+}
+
+/// The low-confidence settings, for code that has no `AppHandle`
+static LOW_CONFIDENCE: RwLock<LowConfidenceConfig> = RwLock::new(LowConfidenceConfig {
+    threshold: 0.0,
+    action: LowConfidenceAction::Flag,
+});
+
+pub fn apply_settings(settings: &AppSettings) {
+    *LOW_CONFIDENCE.write().unwrap() = settings.low_confidence.clone();
+}
+
+/// Whether a segment with this confidence is below the configured threshold
+pub fn is_low_confidence(confidence: f32) -> bool {
+    let config = LOW_CONFIDENCE.read().unwrap();
+    config.threshold > 0.0 && confidence < config.threshold
+}
+
+/// Whether a new segment with this confidence should be left out of the transcript
+pub fn should_drop(confidence: f32) -> bool {
+    LOW_CONFIDENCE.read().unwrap().action == LowConfidenceAction::Drop
+        && is_low_confidence(confidence)
+}
+
+/// Duration-weighted mean of per-segment probabilities
+fn weighted_confidence(parts: impl Iterator<Item = (f32, f32)>) -> Option<f32> {
+    let (weighted, total) = parts.fold((0.0f32, 0.0f32), |(weighted, total), (secs, p)| {
+        let secs = secs.max(0.01);
+        (weighted + p * secs, total + secs)
+    });
+    (total > 0.0).then(|| (weighted / total).clamp(0.0, 1.0))
+}
+
+/// Word timings from the engine's segments. Parakeet reports one segment per word;
+/// Whisper segments hold several words, whose times are spread by character length.
+fn word_timings<'a>(segments: impl Iterator<Item = (f32, f32, &'a str)>) -> Vec<WordTiming> {
+    let mut words = Vec::new();
+    for (start, end, text) in segments {
+        let parts: Vec<&str> = text.split_whitespace().collect();
+        let chars: usize = parts.iter().map(|w| w.chars().count()).sum();
+        if chars == 0 {
+            continue;
+        }
+        let per_char = (end - start).max(0.0) as f64 / chars as f64;
+        let mut at = start as f64;
+        for part in parts {
+            let len = part.chars().count() as f64 * per_char;
+            words.push(WordTiming {
+                word: part.to_string(),
+                start: at,
+                end: at + len,
+            });
+            at += len;
+        }
+    }
+    words
+}
+
+/// Payload of `transcript-partial`: the words heard so far in the chunk being recorded
+#[derive(Clone, Debug, Serialize)]
+pub struct PartialTranscript {
+    pub meeting_id: String,
+    /// Seconds from meeting start where the chunk begins
+    pub start_time: f64,
+    pub words: Vec<String>,
+    /// How many leading `words` are settled and won't change in later partials
+    pub stable_words: usize,
+    pub text: String,
+    /// Set once when the chunk is finalized into a transcript segment
+    pub is_final: bool,
+}
+
+/// Turns successive hypotheses for one growing chunk into partials. A word becomes
+/// stable once two consecutive hypotheses agree on it and everything before it, so
+/// captions don't flicker as the model revises the tail.
+#[derive(Debug, Default)]
+pub struct PartialStream {
+    previous: Vec<String>,
+    stable: Vec<String>,
+}
+
+fn same_word(a: &str, b: &str) -> bool {
+    let norm = |w: &str| -> String {
+        w.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    norm(a) == norm(b)
+}
+
+impl PartialStream {
+    pub fn update(&mut self, meeting_id: &str, start_time: f64, text: &str) -> PartialTranscript {
+        let current: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+        let agreed = self
+            .previous
+            .iter()
+            .zip(&current)
+            .take_while(|(a, b)| same_word(a, b))
+            .count();
+        if agreed > self.stable.len() {
+            self.stable
+                .extend_from_slice(&current[self.stable.len()..agreed]);
+        }
+        let mut words = self.stable.clone();
+        words.extend(current.iter().skip(self.stable.len()).cloned());
+        self.previous = current;
+        PartialTranscript {
+            meeting_id: meeting_id.to_string(),
+            start_time,
+            stable_words: self.stable.len(),
+            text: words.join(" "),
+            words,
+            is_final: false,
+        }
+    }
+
+    /// The chunk's final text; the stream starts over for the next chunk
+    pub fn finalize(&mut self, meeting_id: &str, start_time: f64, text: &str) -> PartialTranscript {
+        *self = Self::default();
+        let words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+        PartialTranscript {
+            meeting_id: meeting_id.to_string(),
+            start_time,
+            stable_words: words.len(),
+            text: words.join(" "),
+            words,
+            is_final: true,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.previous.is_empty()
+    }
+}
+
+/// What one transcription asks of the engine
+struct AsrRequest<'a> {
+    settings: &'a AppSettings,
+    /// Text heard just before this audio
+    previous_text: Option<&'a str>,
+    /// Translate into English instead of transcribing, where the engine can
+    translate: bool,
+}
+
+/// A loaded model of one speech recognition family. The manager loads, runs, and unloads
+/// the selected model through this; a new family implements it and gets an arm in
+/// `load_engine`.
+trait AsrEngine: Send {
+    fn transcribe(&mut self, audio: Vec<f32>, request: &AsrRequest) -> Result<TranscriptionResult>;
+
+    fn unload(&mut self);
+
+    /// Whether `AsrRequest::translate` is honored
+    fn can_translate(&self) -> bool {
+        false
+    }
+}
+
+impl AsrEngine for WhisperEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, request: &AsrRequest) -> Result<TranscriptionResult> {
+        let settings = request.settings;
+        let mut params = WhisperInferenceParams {
+            language: if settings.selected_language == "auto" {
+                None
+            } else {
+                Some(settings.selected_language.clone())
+            },
+            translate: request.translate,
+            beam_size: settings.whisper_beam_size.clamp(1, 10) as i32,
+            temperature: settings.whisper_temperature,
+            temperature_inc: settings.whisper_temperature_increment,
+            no_speech_thold: settings.whisper_no_speech_threshold,
+            no_context: !settings.whisper_condition_on_previous_text,
+            ..Default::default()
+        };
+        if settings.whisper_prompt_context {
+            params.initial_prompt =
+                build_initial_prompt(&settings.custom_words, request.previous_text);
+        }
+        self.transcribe_samples(audio, Some(params))
+            .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))
+    }
+
+    fn unload(&mut self) {
+        self.unload_model();
+    }
+
+    fn can_translate(&self) -> bool {
+        true
+    }
+}
+
+impl AsrEngine for ParakeetEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, _: &AsrRequest) -> Result<TranscriptionResult> {
+        let params = ParakeetInferenceParams {
+            timestamp_granularity: TimestampGranularity::Word,
+            ..Default::default()
+        };
+        self.transcribe_samples(audio, Some(params))
+            .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))
+    }
+
+    fn unload(&mut self) {
+        self.unload_model();
+    }
+}
+
+impl AsrEngine for MoonshineEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, _: &AsrRequest) -> Result<TranscriptionResult> {
+        self.transcribe_samples(audio, None)
+            .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))
+    }
+
+    fn unload(&mut self) {
+        self.unload_model();
+    }
+}
+
+/// Load the model at `path` with the engine for its family
+fn load_engine(engine_type: &EngineType, path: &Path) -> Result<Box<dyn AsrEngine>, String> {
+    match engine_type {
+        EngineType::Whisper => {
+            let mut engine = WhisperEngine::new();
+            engine.load_model(path).map_err(|e| e.to_string())?;
+            Ok(Box::new(engine))
+        }
+        EngineType::Parakeet => {
+            let mut engine = ParakeetEngine::new();
+            engine
+                .load_model_with_params(path, ParakeetModelParams::int8())
+                .map_err(|e| e.to_string())?;
+            Ok(Box::new(engine))
+        }
+        EngineType::Moonshine => {
+            let mut engine = MoonshineEngine::new();
+            engine
+                .load_model_with_params(path, MoonshineModelParams::variant(ModelVariant::Base))
+                .map_err(|e| e.to_string())?;
+            Ok(Box::new(engine))
+        }
+    }
+}
+
+/// Held for the duration of GPU inference when scheduling is `Serialized`. Each engine is
+/// already behind its own lock; this also keeps the live and import instances from
+/// running on the GPU at the same time and thrashing VRAM.
+static GPU_GATE: Mutex<()> = Mutex::new(());
+
+/// Second model instance used by imports when `separate_import_model` is enabled
+pub struct ImportTranscriptionManager(pub Arc<TranscriptionManager>);
+
+impl ImportTranscriptionManager {
+    /// The manager imports should use under the current settings
+    pub fn for_imports(app: &AppHandle) -> Option<Arc<TranscriptionManager>> {
+        if !get_settings(app).separate_import_model {
+            return None;
+        }
+        app.try_state::<ImportTranscriptionManager>()
+            .map(|m| m.0.clone())
+    }
 }
 
 #[derive(Clone)]
 pub struct TranscriptionManager {
-    engine: Arc<Mutex<Option<LoadedEngine>>>,
+    engine: Arc<Mutex<Option<Box<dyn AsrEngine>>>>,
     model_manager: Arc<ModelManager>,
     app_handle: AppHandle,
     current_model_id: Arc<Mutex<Option<String>>>,
@@ -138,11 +433,8 @@ impl TranscriptionManager {
 
         {
             let mut engine = self.engine.lock().unwrap();
-            if let Some(ref mut loaded_engine) = *engine {
-                match loaded_engine {
-                    LoadedEngine::Whisper(ref mut whisper) => whisper.unload_model(),
-                    LoadedEngine::Parakeet(ref mut parakeet) => parakeet.unload_model(),
-                }
+            if let Some(loaded_engine) = engine.as_mut() {
+                loaded_engine.unload();
             }
             *engine = None; // Drop the engine to free memory
         }
@@ -206,46 +498,22 @@ impl TranscriptionManager {
 
         let model_path = self.model_manager.get_model_path(model_id)?;
 
-        // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
-            EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
-                LoadedEngine::Whisper(engine)
-            }
-            EngineType::Parakeet => {
-                let mut engine = ParakeetEngine::new();
-                engine
-                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Parakeet(engine)
-            }
-        };
+        let loaded_engine = load_engine(&model_info.engine_type, &model_path).map_err(|e| {
+            let error_msg = format!(
+                "Failed to load {:?} model {}: {}",
+                model_info.engine_type, model_id, e
+            );
+            let _ = self.app_handle.emit(
+                "model-state-changed",
+                ModelStateEvent {
+                    event_type: "loading_failed".to_string(),
+                    model_id: Some(model_id.to_string()),
+                    model_name: Some(model_info.name.clone()),
+                    error: Some(error_msg.clone()),
+                },
+            );
+            anyhow::anyhow!(error_msg)
+        })?;
 
         // Update the current engine and model ID
         {
@@ -302,7 +570,87 @@ impl TranscriptionManager {
         current_model.clone()
     }
 
+    /// Whether the current model runs on the GPU (Whisper, via Metal/CUDA)
+    pub fn uses_gpu(&self) -> bool {
+        self.get_current_model()
+            .and_then(|id| self.model_manager.get_model_info(&id))
+            .map(|info| matches!(info.engine_type, EngineType::Whisper))
+            .unwrap_or(false)
+    }
+
     pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        self.run_transcription(audio, true, None, false)
+            .map(|t| t.text)
+    }
+
+    /// `transcribe` for audio that follows `previous_text`. With `whisper_prompt_context`
+    /// on, Whisper is prompted with that text; other engines ignore it.
+    /// The engine's confidence comes along with the text.
+    pub fn transcribe_with_context(
+        &self,
+        audio: Vec<f32>,
+        previous_text: &str,
+    ) -> Result<Transcription> {
+        self.run_transcription(audio, true, Some(previous_text), false)
+    }
+
+    /// `transcribe_with_context` for a meeting chunk, sent to the cloud provider when `cloud`
+    /// is set. Falls back to the local model when offline mode is on, no API key is stored
+    /// or the request fails.
+    pub fn transcribe_for_meeting(
+        &self,
+        audio: Vec<f32>,
+        previous_text: &str,
+        cloud: bool,
+    ) -> Result<Transcription> {
+        if cloud {
+            let config = get_settings(&self.app_handle).cloud_asr;
+            if cloud_asr::is_available(&config) {
+                match tauri::async_runtime::block_on(cloud_asr::transcribe(&config, &audio)) {
+                    Ok(transcription) => return Ok(transcription),
+                    Err(e) => log::warn!("Cloud transcription failed, using local model: {}", e),
+                }
+            }
+        }
+        self.transcribe_with_context(audio, previous_text)
+    }
+
+    /// Interim transcription of a chunk still being recorded. Unlike `transcribe`, never
+    /// unloads the model afterwards, since the finished chunk follows shortly.
+    pub fn transcribe_partial(&self, audio: Vec<f32>) -> Result<String> {
+        self.run_transcription(audio, false, None, false)
+            .map(|t| t.text)
+    }
+
+    /// English translation of `audio` by the speech model (Whisper's translate task); None
+    /// when the loaded model can't translate
+    pub fn translate(&self, audio: Vec<f32>) -> Result<Option<String>> {
+        let can_translate = self
+            .engine
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|e| e.can_translate());
+        if !can_translate {
+            return Ok(None);
+        }
+        self.run_transcription(audio, false, None, true)
+            .map(|t| Some(t.text).filter(|text| !text.is_empty()))
+    }
+
+    /// Send an interim (or finalized) hypothesis to the frontend and the captions overlay
+    pub fn emit_partial(&self, partial: &PartialTranscript) {
+        crate::overlay::emit_caption(&self.app_handle, &partial.text);
+        let _ = self.app_handle.emit("transcript-partial", partial);
+    }
+
+    fn run_transcription(
+        &self,
+        audio: Vec<f32>,
+        allow_unload: bool,
+        previous_text: Option<&str>,
+        force_translate: bool,
+    ) -> Result<Transcription> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -318,7 +666,11 @@ impl TranscriptionManager {
 
         if audio.len() == 0 {
             println!("Empty audio vector");
-            return Ok(String::new());
+            return Ok(Transcription {
+                text: String::new(),
+                confidence: None,
+                words: Vec::new(),
+            });
         }
 
         // Check if model is loaded, if not try to load it
@@ -338,6 +690,9 @@ impl TranscriptionManager {
         // Get current settings for configuration
         let settings = get_settings(&self.app_handle);
 
+        let _gpu_turn = (settings.gpu_scheduling == GpuScheduling::Serialized && self.uses_gpu())
+            .then(|| GPU_GATE.lock().unwrap_or_else(|e| e.into_inner()));
+
         // Perform transcription with the appropriate engine
         let result = {
             let mut engine_guard = self.engine.lock().unwrap();
@@ -347,45 +702,31 @@ impl TranscriptionManager {
                 )
             })?;
 
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    let mut params = WhisperInferenceParams {
-                        language: if settings.selected_language == "auto" {
-                            None
-                        } else {
-                            Some(settings.selected_language.clone())
-                        },
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
-                    // Fast import mode: prefer greedy decode (beam size = 1) for speed
-                    if settings.fast_import_mode_for_imports {
-                        #[allow(unused_mut)]
-                        {
-                            // If transcribe-rs exposes beam_size, set to 1 to force greedy
-                            // This field may not exist on older versions; the allow(unused_mut) + struct update avoids warnings.
-                        }
-                        // Best-effort: access via struct literal if available in this version
-                        // Note: if `beam_size` is not a field, this will be ignored at compile time by not specifying it.
-                    }
-
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => {
-                    let params = ParakeetInferenceParams {
-                        timestamp_granularity: TimestampGranularity::Segment,
-                        ..Default::default()
-                    };
-
-                    parakeet_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
-                }
-            }
+            engine.transcribe(
+                audio,
+                &AsrRequest {
+                    settings: &settings,
+                    previous_text,
+                    translate: force_translate || settings.translate_to_english,
+                },
+            )?
         };
 
+        let confidence = weighted_confidence(
+            result
+                .segments
+                .iter()
+                .flatten()
+                .filter_map(|s| Some((s.end - s.start, s.confidence?))),
+        );
+        let mut words = word_timings(
+            result
+                .segments
+                .iter()
+                .flatten()
+                .map(|s| (s.start, s.end, s.text.as_str())),
+        );
+
         // Apply word correction if custom words are configured
         let corrected_result = if !settings.custom_words.is_empty() {
             apply_custom_words(
@@ -396,6 +737,13 @@ impl TranscriptionManager {
         } else {
             result.text
         };
+        // Keep the timings in step with corrections that didn't change the word count
+        let corrected_words: Vec<&str> = corrected_result.split_whitespace().collect();
+        if corrected_words.len() == words.len() {
+            for (timing, word) in words.iter_mut().zip(corrected_words) {
+                timing.word = word.to_string();
+            }
+        }
 
         let et = std::time::Instant::now();
         let translation_note = if settings.translate_to_english {
@@ -406,14 +754,18 @@ impl TranscriptionManager {
         println!("\ntook {}ms{}", (et - st).as_millis(), translation_note);
 
         // Check if we should immediately unload the model after transcription
-        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
+        if allow_unload && settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
             println!("⚡ Immediately unloading model after transcription");
             if let Err(e) = self.unload_model() {
                 eprintln!("Failed to immediately unload model: {}", e);
             }
         }
 
-        Ok(corrected_result.trim().to_string())
+        Ok(Transcription {
+            text: corrected_result.trim().to_string(),
+            confidence,
+            words,
+        })
     }
 }
 
@@ -434,3 +786,50 @@ impl Drop for TranscriptionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_confidence() {
+        assert_eq!(weighted_confidence(std::iter::empty()), None);
+        let mean = weighted_confidence([(3.0, 0.9), (1.0, 0.5)].into_iter()).unwrap();
+        assert!((mean - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_word_timings_split_by_length() {
+        let words = word_timings([(1.0, 2.0, " ok go"), (2.0, 2.5, "now")].into_iter());
+        let spans: Vec<(&str, f64, f64)> = words
+            .iter()
+            .map(|w| (w.word.as_str(), w.start, w.end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![("ok", 1.0, 1.5), ("go", 1.5, 2.0), ("now", 2.0, 2.5)]
+        );
+    }
+
+    #[test]
+    fn test_partial_stream_stabilizes_agreed_prefix() {
+        let mut stream = PartialStream::default();
+        let first = stream.update("m", 10.0, "so the plan");
+        assert_eq!(first.stable_words, 0);
+        assert_eq!(first.words, vec!["so", "the", "plan"]);
+
+        // "So the" agrees with the last hypothesis; the revised tail stays interim
+        let second = stream.update("m", 10.0, "So the planning is");
+        assert_eq!(second.stable_words, 2);
+        assert_eq!(second.text, "So the planning is");
+
+        // Stable words are kept even if the model later revises them
+        let third = stream.update("m", 10.0, "Sue, the planning is done");
+        assert_eq!(third.stable_words, 2);
+        assert_eq!(third.text, "So the planning is done");
+
+        let fin = stream.finalize("m", 10.0, "So the planning is done.");
+        assert!(fin.is_final && fin.stable_words == 5);
+        assert!(!stream.is_active());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::integrations::jira::JiraTicket;
+use crate::project::claude_hooks::{self, AgentActivity};
 use crate::summarization::agent::SummarizationOutput;
 use anyhow::Result;
 use once_cell::sync::Lazy;
@@ -23,6 +25,15 @@ struct JsonlRecord<'a> {
     project_type: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tech_stack: Option<&'a str>,
+    /// Agent sessions captured since the previous update
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    agent_logs: Vec<String>,
+    /// File edits and commands reported by the Claude Code hooks since the previous update
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    agent_activity: Vec<AgentActivity>,
+    /// Jira tickets mentioned in the summarized segments
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    jira_tickets: &'a [JiraTicket],
     #[serde(flatten)]
     update: &'a SummarizationOutput,
 }
@@ -31,6 +42,12 @@ struct JsonlRecord<'a> {
 struct MeetingState {
     last_update_id: u32,
     last_summary_time: Option<String>,
+    /// Number of agent log index entries already referenced by an update
+    #[serde(default)]
+    agent_logs_referenced: usize,
+    /// Number of hook activity log lines already referenced by an update
+    #[serde(default)]
+    agent_activity_referenced: usize,
 }
 
 fn read_state(path: &Path) -> MeetingState {
@@ -39,7 +56,12 @@ fn read_state(path: &Path) -> MeetingState {
             return state;
         }
     }
-    MeetingState { last_update_id: 0, last_summary_time: None }
+    MeetingState {
+        last_update_id: 0,
+        last_summary_time: None,
+        agent_logs_referenced: 0,
+        agent_activity_referenced: 0,
+    }
 }
 
 fn write_state(path: &Path, state: &MeetingState) -> Result<()> {
@@ -57,18 +79,35 @@ pub fn append_update(
     model: &str,
     source: &str,
     update: &SummarizationOutput,
+    jira_tickets: &[JiraTicket],
 ) -> Result<u32> {
     // Use .claude/.meeting-state.json for persistent update_id
     let claude_dir = Path::new(project_path).join(".claude");
     let state_path = claude_dir.join(".meeting-state.json");
 
-    let (update_id, is_first) = {
+    let (update_id, is_first, agent_logs, agent_activity) = {
         let _guard = STATE_LOCK.lock().unwrap();
         let mut state = read_state(&state_path);
         state.last_update_id = state.last_update_id.saturating_add(1);
         state.last_summary_time = Some(chrono::Utc::now().to_rfc3339());
+        // Reference agent sessions recorded since the previous update
+        let sessions = crate::automation::agent_logs::list_sessions(project_path);
+        let agent_logs: Vec<String> = sessions
+            .iter()
+            .skip(state.agent_logs_referenced)
+            .map(|s| s.log_path.clone())
+            .collect();
+        state.agent_logs_referenced = sessions.len();
+        // Only completed tool calls are reported; PreToolUse entries stay in the raw log
+        let (activity, activity_lines) =
+            claude_hooks::read_activity(project_path, state.agent_activity_referenced);
+        let agent_activity: Vec<AgentActivity> = activity
+            .into_iter()
+            .filter(|a| a.event == "PostToolUse")
+            .collect();
+        state.agent_activity_referenced = activity_lines;
         write_state(&state_path, &state)?;
-        (state.last_update_id, state.last_update_id == 1)
+        (state.last_update_id, state.last_update_id == 1, agent_logs, agent_activity)
     };
 
     let record = JsonlRecord {
@@ -80,6 +119,9 @@ pub fn append_update(
         project_name: if is_first { Some(meeting_name) } else { None },
         project_type: None,
         tech_stack: None,
+        agent_logs,
+        agent_activity,
+        jira_tickets,
         update,
     };
     let line = serde_json::to_string(&record)? + "\n";
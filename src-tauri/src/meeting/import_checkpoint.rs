@@ -0,0 +1,111 @@
+//! Checkpoints for audio imports. After each transcribed chunk the meeting so far and
+//! the decoded-sample offset reached are written to the app data folder, so importing the
+//! same file again after a quit or a cancel continues from there instead of from zero.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::meeting::MeetingSession;
+
+const CHECKPOINT_DIR: &str = "import_checkpoints";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportCheckpoint {
+    pub file_path: String,
+    /// Size and modification time (Unix ms) of the file, to notice it was replaced
+    pub file_len: u64,
+    pub file_modified: i64,
+    pub source_label: String,
+    /// 16 kHz mono samples of the decoded file already transcribed
+    pub sample_offset: usize,
+    /// Segments already covered by meeting updates
+    pub sent_segments: usize,
+    /// The meeting as of `sample_offset`
+    pub session: MeetingSession,
+}
+
+/// An import that stopped before finishing, as listed to the user
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedImport {
+    pub meeting_id: String,
+    pub name: String,
+    pub file_path: String,
+    /// Seconds of audio already transcribed
+    pub transcribed_secs: f64,
+    pub segment_count: usize,
+}
+
+fn checkpoint_dir(app: &AppHandle) -> Result<PathBuf> {
+    Ok(app
+        .path()
+        .resolve(CHECKPOINT_DIR, tauri::path::BaseDirectory::AppData)?)
+}
+
+/// Size and modification time of a file, as recorded in checkpoints
+pub fn file_stamp(path: &Path) -> Option<(u64, i64)> {
+    let meta = fs::metadata(path).ok()?;
+    let modified = meta
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_millis() as i64;
+    Some((meta.len(), modified))
+}
+
+pub fn save(app: &AppHandle, checkpoint: &ImportCheckpoint) -> Result<()> {
+    let dir = checkpoint_dir(app)?;
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.json", checkpoint.session.id));
+    // Written aside and renamed, so a quit mid-write leaves the previous checkpoint
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_vec(checkpoint)?)?;
+    fs::rename(tmp, path)?;
+    Ok(())
+}
+
+pub fn clear(app: &AppHandle, meeting_id: &str) {
+    if let Ok(dir) = checkpoint_dir(app) {
+        let _ = fs::remove_file(dir.join(format!("{}.json", meeting_id)));
+    }
+}
+
+fn read_all(app: &AppHandle) -> Vec<ImportCheckpoint> {
+    let paths: Vec<PathBuf> = checkpoint_dir(app)
+        .and_then(|dir| Ok(fs::read_dir(dir)?))
+        .map(|files| files.filter_map(|f| f.ok()).map(|f| f.path()).collect())
+        .unwrap_or_default();
+    paths
+        .into_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .filter_map(|p| serde_json::from_slice(&fs::read(p).ok()?).ok())
+        .collect()
+}
+
+/// The checkpoint of an unfinished import of `path`, if the file hasn't changed since
+pub fn find(app: &AppHandle, path: &Path) -> Option<ImportCheckpoint> {
+    let stamp = file_stamp(path)?;
+    let file_path = path.to_string_lossy();
+    read_all(app)
+        .into_iter()
+        .find(|c| c.file_path == file_path && (c.file_len, c.file_modified) == stamp)
+}
+
+pub fn list(app: &AppHandle) -> Vec<InterruptedImport> {
+    let mut imports: Vec<InterruptedImport> = read_all(app)
+        .into_iter()
+        .map(|c| InterruptedImport {
+            meeting_id: c.session.id,
+            name: c.session.name,
+            file_path: c.file_path,
+            transcribed_secs: c.sample_offset as f64 / 16_000f64,
+            segment_count: c.session.transcript_segments.len(),
+        })
+        .collect();
+    imports.sort_by(|a, b| a.name.cmp(&b.name));
+    imports
+}
@@ -1,2 +1,5 @@
 pub mod context_writer;
+pub mod import_checkpoint;
+pub mod recovery;
 pub mod transcript_writer;
+pub mod translation;
@@ -0,0 +1,365 @@
+//! Crash recovery for meetings being recorded. A live meeting has a small journal file in
+//! the app data folder from start to end, so one left behind at startup means the app
+//! stopped mid-meeting. Its audio segments and queued chunks are already on disk and its
+//! transcribed text is in the project's `.transcript.jsonl`, which is enough to rebuild
+//! the session and then resume or finalize it.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::managers::meeting::{MeetingSession, MeetingStatus, TranscriptSegment, WordTiming};
+use crate::queue::Queue;
+
+const JOURNAL_DIR: &str = "active_meetings";
+
+/// What the transcript doesn't say about a meeting in progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalEntry {
+    id: String,
+    name: String,
+    /// Unix milliseconds
+    started_at: i64,
+    project_path: Option<String>,
+}
+
+/// A meeting the app stopped recording without ending it
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedMeeting {
+    pub meeting_id: String,
+    pub name: String,
+    /// Unix milliseconds
+    pub started_at: i64,
+    pub project_path: Option<String>,
+    /// Seconds of the meeting that were recorded
+    pub recorded_secs: f64,
+    /// Segments already transcribed
+    pub segment_count: usize,
+    /// Recorded chunks still waiting for transcription
+    pub pending_chunks: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecoveryAction {
+    /// Continue recording where the meeting left off
+    Resume,
+    /// End it like a normal meeting: transcript, summary, and integrations
+    Finalize,
+    /// Forget it; its files stay where they are
+    Discard,
+}
+
+/// Where a resumed meeting's transcription loop picks up
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResumePoint {
+    /// Next audio segment file number
+    pub segment_index: usize,
+    /// Seconds from meeting start to the end of the recorded audio
+    pub elapsed_secs: f64,
+    /// Segments already covered by live meeting updates
+    pub sent_segments: usize,
+}
+
+fn journal_dir(app: &AppHandle) -> Result<PathBuf> {
+    Ok(app
+        .path()
+        .resolve(JOURNAL_DIR, tauri::path::BaseDirectory::AppData)?)
+}
+
+fn to_ms(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+fn from_ms(ms: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(ms.max(0) as u64)
+}
+
+/// Note that a meeting started recording
+pub fn record(app: &AppHandle, session: &MeetingSession) -> Result<()> {
+    let dir = journal_dir(app)?;
+    fs::create_dir_all(&dir)?;
+    let entry = JournalEntry {
+        id: session.id.clone(),
+        name: session.name.clone(),
+        started_at: to_ms(session.start_time),
+        project_path: session.project_path.clone(),
+    };
+    fs::write(
+        dir.join(format!("{}.json", session.id)),
+        serde_json::to_vec_pretty(&entry)?,
+    )?;
+    Ok(())
+}
+
+/// Note that a meeting ended (or was discarded)
+pub fn clear(app: &AppHandle, meeting_id: &str) {
+    if let Ok(dir) = journal_dir(app) {
+        let _ = fs::remove_file(dir.join(format!("{}.json", meeting_id)));
+    }
+}
+
+/// Point journal entries at their project's new location after the storage root moved
+pub fn relocate_projects(app: &AppHandle, relocate: impl Fn(&str) -> Option<String>) -> Result<()> {
+    let dir = journal_dir(app)?;
+    for mut entry in read_entries(&dir) {
+        let Some(new_path) = entry.project_path.as_deref().and_then(&relocate) else {
+            continue;
+        };
+        entry.project_path = Some(new_path);
+        fs::write(
+            dir.join(format!("{}.json", entry.id)),
+            serde_json::to_vec_pretty(&entry)?,
+        )?;
+    }
+    Ok(())
+}
+
+fn read_entries(dir: &Path) -> Vec<JournalEntry> {
+    let paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|files| files.filter_map(|f| f.ok()).map(|f| f.path()).collect())
+        .unwrap_or_default();
+    let mut entries: Vec<JournalEntry> = paths
+        .into_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .filter_map(|p| serde_json::from_slice(&fs::read(p).ok()?).ok())
+        .collect();
+    entries.sort_by_key(|e| e.started_at);
+    entries
+}
+
+#[derive(Deserialize)]
+struct TranscriptRecord {
+    meeting_id: String,
+    segment_index: usize,
+    speaker: String,
+    start_time: f64,
+    end_time: f64,
+    confidence: f32,
+    text: String,
+    timestamp: String,
+    #[serde(default)]
+    words: Vec<WordTiming>,
+}
+
+/// A meeting's segments from a project's rolling transcript, in order. A segment index
+/// written twice keeps its last version.
+pub fn read_transcript(project_path: &Path, meeting_id: &str) -> Vec<TranscriptSegment> {
+    let content = fs::read_to_string(project_path.join(".transcript.jsonl")).unwrap_or_default();
+    let mut segments = BTreeMap::new();
+    for record in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<TranscriptRecord>(line).ok())
+        .filter(|r| r.meeting_id == meeting_id)
+    {
+        let timestamp = DateTime::parse_from_rfc3339(&record.timestamp)
+            .map(|t| SystemTime::from(t.with_timezone(&Utc)))
+            .unwrap_or(UNIX_EPOCH);
+        segments.insert(
+            record.segment_index,
+            TranscriptSegment {
+                speaker: record.speaker,
+                start_time: record.start_time,
+                end_time: record.end_time,
+                text: record.text,
+                confidence: record.confidence,
+                timestamp,
+                words: record.words,
+                translated_text: None,
+            },
+        );
+    }
+    segments.into_values().collect()
+}
+
+/// Add recovered segments the transcript doesn't have yet (matched by start time), in
+/// time order. Returns how many were added.
+pub fn merge_recovered(
+    segments: &mut Vec<TranscriptSegment>,
+    recovered: Vec<TranscriptSegment>,
+) -> usize {
+    let before = segments.len();
+    for segment in recovered {
+        if !segments
+            .iter()
+            .any(|s| (s.start_time - segment.start_time).abs() < 1e-3)
+        {
+            segments.push(segment);
+        }
+    }
+    let added = segments.len() - before;
+    if added > 0 {
+        segments.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+    }
+    added
+}
+
+/// Number and end (ms from meeting start) of a segment file named
+/// `segment_<index>_<start>-<end>.wav`
+fn parse_segment_file(name: &str) -> Option<(usize, u64)> {
+    let rest = name.strip_prefix("segment_")?.strip_suffix(".wav")?;
+    let (index, range) = rest.split_once('_')?;
+    let (_, end) = range.split_once('-')?;
+    Some((index.parse().ok()?, end.parse().ok()?))
+}
+
+/// Continue after the last segment file and whichever ends later, the audio or the
+/// transcript. Projects can hold earlier meetings' segments, so only files written
+/// since `started` count.
+fn resume_point(
+    project_path: Option<&Path>,
+    segments: &[TranscriptSegment],
+    started: SystemTime,
+) -> ResumePoint {
+    let files: Vec<(usize, u64)> = project_path
+        .and_then(|p| fs::read_dir(p.join("audio_segments")).ok())
+        .map(|dir| {
+            dir.filter_map(|e| e.ok())
+                .filter(|e| {
+                    e.metadata()
+                        .and_then(|m| m.modified())
+                        .is_ok_and(|modified| modified >= started)
+                })
+                .filter_map(|e| parse_segment_file(&e.file_name().to_string_lossy()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let audio_end = files.iter().map(|(_, end)| *end).max().unwrap_or(0) as f64 / 1000.0;
+    let transcript_end = segments.iter().map(|s| s.end_time).fold(0.0, f64::max);
+    ResumePoint {
+        segment_index: files.iter().map(|(i, _)| i + 1).max().unwrap_or(0),
+        elapsed_secs: audio_end.max(transcript_end),
+        sent_segments: segments.len(),
+    }
+}
+
+fn pending_chunks(app: &AppHandle, meeting_id: &str) -> i64 {
+    app.try_state::<Arc<Queue>>()
+        .and_then(|q| q.counts_for_meeting(meeting_id).ok())
+        .map(|(queued, processing)| queued + processing)
+        .unwrap_or(0)
+}
+
+/// Meetings with a journal entry, oldest first. Call before any meeting starts; every
+/// entry then belongs to a meeting the previous run didn't end.
+pub fn find_interrupted(app: &AppHandle) -> Vec<InterruptedMeeting> {
+    let Ok(dir) = journal_dir(app) else {
+        return Vec::new();
+    };
+    read_entries(&dir)
+        .into_iter()
+        .map(|entry| {
+            let project = entry.project_path.as_deref().map(Path::new);
+            let segments = project
+                .map(|p| read_transcript(p, &entry.id))
+                .unwrap_or_default();
+            let resume = resume_point(project, &segments, from_ms(entry.started_at));
+            InterruptedMeeting {
+                pending_chunks: pending_chunks(app, &entry.id),
+                meeting_id: entry.id,
+                name: entry.name,
+                started_at: entry.started_at,
+                project_path: entry.project_path,
+                recorded_secs: resume.elapsed_secs,
+                segment_count: segments.len(),
+            }
+        })
+        .collect()
+}
+
+/// Rebuild an interrupted meeting's session from its journal entry and transcript
+pub fn load(app: &AppHandle, meeting_id: &str) -> Result<(MeetingSession, ResumePoint)> {
+    let entry = read_entries(&journal_dir(app)?)
+        .into_iter()
+        .find(|e| e.id == meeting_id)
+        .ok_or_else(|| anyhow!("No interrupted meeting {}", meeting_id))?;
+    let project = entry.project_path.as_deref().map(Path::new);
+    let transcript_segments = project
+        .map(|p| read_transcript(p, meeting_id))
+        .unwrap_or_default();
+    let start_time = from_ms(entry.started_at);
+    let resume = resume_point(project, &transcript_segments, start_time);
+    let mut participants: Vec<String> = Vec::new();
+    for segment in &transcript_segments {
+        if !participants.contains(&segment.speaker) {
+            participants.push(segment.speaker.clone());
+        }
+    }
+    let session = MeetingSession {
+        id: entry.id,
+        name: entry.name,
+        start_time,
+        end_time: None,
+        transcript_segments,
+        status: MeetingStatus::Recording,
+        participants,
+        project_path: entry.project_path,
+        translation_language: None,
+        cloud_asr: false,
+    };
+    Ok((session, resume))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebuild_from_project_files() {
+        let project = std::env::temp_dir().join(format!("recovery-test-{}", std::process::id()));
+        let segments_dir = project.join("audio_segments");
+        fs::create_dir_all(&segments_dir).unwrap();
+        for name in [
+            "segment_000000_0-15000.wav",
+            "segment_000001_15000-30000.wav",
+        ] {
+            fs::write(segments_dir.join(name), b"").unwrap();
+        }
+        let line = |id: &str, index: usize, text: &str| {
+            format!(
+                r#"{{"meeting_id":"{}","segment_index":{},"speaker":"Speaker 1","start_time":{},"end_time":{},"confidence":0.9,"text":"{}","timestamp":"2026-10-16T09:00:00+00:00"}}"#,
+                id,
+                index,
+                index * 15,
+                index * 15 + 15,
+                text
+            )
+        };
+        let jsonl = [
+            line("m-1", 1, "second"),
+            line("other", 0, "elsewhere"),
+            line("m-1", 0, "first"),
+        ]
+        .join("\n");
+        fs::write(project.join(".transcript.jsonl"), jsonl).unwrap();
+
+        let segments = read_transcript(&project, "m-1");
+        let texts: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second"]);
+        assert_eq!(
+            resume_point(Some(&project), &segments, UNIX_EPOCH),
+            ResumePoint {
+                segment_index: 2,
+                elapsed_secs: 30.0,
+                sent_segments: 2,
+            }
+        );
+        assert_eq!(parse_segment_file("full_audio.wav"), None);
+
+        // A saved transcript missing the first segment gets it back, in order
+        let mut saved = segments[1..].to_vec();
+        assert_eq!(merge_recovered(&mut saved, segments.clone()), 1);
+        let texts: Vec<&str> = saved.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second"]);
+        assert_eq!(merge_recovered(&mut saved, segments), 0);
+        let _ = fs::remove_dir_all(&project);
+    }
+}
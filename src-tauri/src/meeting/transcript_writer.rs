@@ -1,4 +1,4 @@
-use crate::managers::meeting::TranscriptSegment;
+use crate::managers::meeting::{TranscriptSegment, WordTiming};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -16,6 +16,8 @@ struct TranscriptJsonlRecord<'a> {
     confidence: f32,
     text: &'a str,
     timestamp: String, // RFC3339
+    #[serde(skip_serializing_if = "<[WordTiming]>::is_empty")]
+    words: &'a [WordTiming],
 }
 
 pub fn append_segment(
@@ -35,6 +37,7 @@ pub fn append_segment(
         confidence: segment.confidence,
         text: &segment.text,
         timestamp: timestamp.to_rfc3339(),
+        words: &segment.words,
     };
     let line = serde_json::to_string(&record)? + "\n";
     let mut file = OpenOptions::new().create(true).append(true).open(path)?;
@@ -43,3 +46,17 @@ pub fn append_segment(
     Ok(())
 }
 
+
+/// Number of segments of `meeting_id` already in the project's rolling transcript
+pub fn segment_count(project_path: &str, meeting_id: &str) -> usize {
+    let path = Path::new(project_path).join(".transcript.jsonl");
+    std::fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+                .filter(|v| v.get("meeting_id").and_then(|m| m.as_str()) == Some(meeting_id))
+                .count()
+        })
+        .unwrap_or(0)
+}
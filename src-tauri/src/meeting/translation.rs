@@ -0,0 +1,131 @@
+//! Translation of meeting transcripts. Each segment keeps its original text and gains a
+//! `translated_text` in the meeting's language, filled in in the background so
+//! transcription never waits on it; the summary is translated when the meeting ends.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use tauri::{AppHandle, Manager};
+
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::MeetingManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{MeetingTranslationConfig, TranslationEngine};
+use crate::summarization::llm;
+
+const SYSTEM_PROMPT: &str = "You translate meeting transcripts. Reply with the translation \
+only: no quotes, notes, or explanations. Keep names, code, and product terms as they are.";
+
+pub fn normalize_config(config: &mut MeetingTranslationConfig) -> Result<(), String> {
+    config.language = normalize_language(config.language.as_deref());
+    Ok(())
+}
+
+/// A language code as stored on meetings: trimmed and lowercase, None when blank
+pub fn normalize_language(language: Option<&str>) -> Option<String> {
+    language
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty())
+}
+
+/// Whisper's translate task only targets English
+fn is_english(language: &str) -> bool {
+    language == "en" || language.starts_with("en-") || language == "english"
+}
+
+/// Translate `text` into `language` with the configured LLM
+pub async fn translate_text(text: &str, language: &str) -> Result<String> {
+    if !llm::is_available() {
+        return Err(anyhow!("No LLM provider is configured for translation"));
+    }
+    let prompt = format!("Translate into the language with code \"{language}\":\n\n{text}");
+    let reply = llm::complete(SYSTEM_PROMPT, &prompt).await?;
+    Ok(reply.trim().to_string())
+}
+
+/// Translate segment `index` of an active meeting in the background. `audio` is the
+/// chunk the text came from; with the Whisper engine and an English target it is
+/// translated by the speech model, otherwise (or if that fails) by the LLM.
+pub fn spawn_segment_translation(
+    app: &AppHandle,
+    meeting_id: String,
+    index: usize,
+    text: String,
+    language: String,
+    audio: Option<Vec<f32>>,
+) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let engine = crate::settings::get_settings(&app)
+            .meeting_translation
+            .engine;
+        let mut translated = None;
+        if let (TranslationEngine::Whisper, Some(audio)) = (engine, audio) {
+            if is_english(&language) {
+                let tm = app.state::<Arc<TranscriptionManager>>().inner().clone();
+                translated = tauri::async_runtime::spawn_blocking(move || tm.translate(audio))
+                    .await
+                    .ok()
+                    .and_then(|r| r.ok().flatten());
+            }
+        }
+        let translated = match translated {
+            Some(t) => t,
+            None => match translate_text(&text, &language).await {
+                Ok(t) => t,
+                Err(e) => {
+                    log::warn!(
+                        "Failed to translate segment {} of {}: {}",
+                        index,
+                        meeting_id,
+                        e
+                    );
+                    return;
+                }
+            },
+        };
+        if translated.is_empty() {
+            return;
+        }
+        let manager = app.state::<Arc<MeetingManager>>();
+        match manager
+            .set_segment_translation(&meeting_id, index, translated.clone())
+            .await
+        {
+            Ok(()) => events::emit(
+                &app,
+                AppEvent::SegmentTranslated {
+                    meeting_id,
+                    segment_index: index,
+                    translated_text: translated,
+                },
+            ),
+            Err(e) => log::debug!("Dropped translation of segment {}: {}", index, e),
+        }
+    });
+}
+
+/// Write `summary.<language>.md` next to `summary.md`, translated by the LLM
+pub async fn write_translated_summary(
+    meeting_dir: &Path,
+    language: &str,
+    summary: &str,
+) -> Result<()> {
+    let translated = translate_text(summary, language).await?;
+    let name = format!("summary.{}.md", language.replace(['/', '\\'], "_"));
+    std::fs::write(meeting_dir.join(name), translated + "\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(normalize_language(Some(" DE ")), Some("de".to_string()));
+        assert_eq!(normalize_language(Some("  ")), None);
+        assert!(is_english("en-us") && !is_english("es"));
+    }
+}
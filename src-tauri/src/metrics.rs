@@ -0,0 +1,318 @@
+//! Anonymous local metrics: meetings recorded, ASR speed, failures, and queue latency,
+//! kept in the meeting database for a year so defaults can be tuned from real numbers.
+//! Samples carry no meeting ids, names, paths, or text. Nothing leaves the machine
+//! unless the user opts in to sharing, and then only the aggregates from
+//! `shared_metrics`, which `preview_shared_metrics` shows first.
+
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::AppError;
+use crate::network::{self, Service};
+use crate::settings;
+use crate::storage::db::MeetingDb;
+
+/// Samples older than this are pruned
+const RETENTION_DAYS: u32 = 365;
+const DEFAULT_WINDOW_DAYS: u32 = 30;
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// One per ended meeting; value is its length in minutes
+    MeetingRecorded,
+    /// One per transcribed chunk; value is the real-time factor (ASR time / audio time)
+    AsrRtf,
+    /// One per failed transcription attempt
+    AsrFailure,
+    /// One per job moved to dead letters
+    QueueDeadLetter,
+    /// One per claimed job; value is milliseconds from enqueue to pickup
+    QueueWaitMs,
+    /// One per LLM summary attempt; value is 1 on success, 0 on failure
+    LlmSummary,
+}
+
+impl Metric {
+    fn as_str(self) -> &'static str {
+        match self {
+            Metric::MeetingRecorded => "meeting_recorded",
+            Metric::AsrRtf => "asr_rtf",
+            Metric::AsrFailure => "asr_failure",
+            Metric::QueueDeadLetter => "queue_dead_letter",
+            Metric::QueueWaitMs => "queue_wait_ms",
+            Metric::LlmSummary => "llm_summary",
+        }
+    }
+}
+
+/// Store one sample; failures are logged and otherwise ignored
+pub fn record(app: &AppHandle, metric: Metric, value: f64) {
+    let Some(db) = app.try_state::<Arc<MeetingDb>>() else {
+        return;
+    };
+    if let Err(e) = db.record_metric(metric.as_str(), value) {
+        log::debug!("METRICS failed to record {}: {}", metric.as_str(), e);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Distribution {
+    pub count: usize,
+    pub mean: f64,
+    pub p50: f64,
+    pub p95: f64,
+}
+
+impl Distribution {
+    fn of(mut values: Vec<f64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        let at = |q: f64| values[((values.len() - 1) as f64 * q).round() as usize];
+        Some(Self {
+            count: values.len(),
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            p50: at(0.5),
+            p95: at(0.95),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsDashboard {
+    pub window_days: u32,
+    pub meetings_recorded: usize,
+    pub meeting_minutes: Option<Distribution>,
+    pub asr_rtf: Option<Distribution>,
+    pub asr_failures: usize,
+    /// Failed attempts / all attempts
+    pub asr_failure_rate: f64,
+    pub queue_wait_ms: Option<Distribution>,
+    pub dead_lettered: usize,
+    pub llm_summaries: usize,
+    pub llm_summary_failures: usize,
+    /// Meetings recorded per day, oldest first, `window_days` entries
+    pub meetings_per_day: Vec<usize>,
+    pub sharing_enabled: bool,
+}
+
+/// Aggregate `(kind, value, recorded_at)` samples over the `window_days` ending at `now_ms`
+pub fn build_dashboard(
+    samples: &[(String, f64, i64)],
+    window_days: u32,
+    now_ms: i64,
+) -> MetricsDashboard {
+    let values = |metric: Metric| -> Vec<f64> {
+        samples
+            .iter()
+            .filter(|(kind, _, _)| kind == metric.as_str())
+            .map(|(_, value, _)| *value)
+            .collect()
+    };
+    let meetings = values(Metric::MeetingRecorded);
+    let rtf = values(Metric::AsrRtf);
+    let asr_failures = values(Metric::AsrFailure).len();
+    let summaries = values(Metric::LlmSummary);
+
+    let start_ms = now_ms - window_days as i64 * DAY_MS;
+    let mut meetings_per_day = vec![0; window_days as usize];
+    for (_, _, at) in samples
+        .iter()
+        .filter(|(kind, _, _)| kind == Metric::MeetingRecorded.as_str())
+    {
+        let day = ((at - start_ms) / DAY_MS) as usize;
+        if let Some(count) = meetings_per_day.get_mut(day) {
+            *count += 1;
+        }
+    }
+
+    let attempts = rtf.len() + asr_failures;
+    MetricsDashboard {
+        window_days,
+        meetings_recorded: meetings.len(),
+        meeting_minutes: Distribution::of(meetings),
+        asr_failure_rate: if attempts == 0 {
+            0.0
+        } else {
+            asr_failures as f64 / attempts as f64
+        },
+        asr_rtf: Distribution::of(rtf),
+        asr_failures,
+        queue_wait_ms: Distribution::of(values(Metric::QueueWaitMs)),
+        dead_lettered: values(Metric::QueueDeadLetter).len(),
+        llm_summary_failures: summaries.iter().filter(|v| **v < 0.5).count(),
+        llm_summaries: summaries.len(),
+        meetings_per_day,
+        sharing_enabled: false,
+    }
+}
+
+fn load_dashboard(app: &AppHandle, days: Option<u32>) -> Result<MetricsDashboard, String> {
+    let db = app
+        .try_state::<Arc<MeetingDb>>()
+        .ok_or("Meeting database is not ready")?;
+    let window_days = days.unwrap_or(DEFAULT_WINDOW_DAYS).clamp(1, RETENTION_DAYS);
+    let now_ms = chrono::Utc::now().timestamp_millis();
+    let _ = db.prune_metrics(now_ms - RETENTION_DAYS as i64 * DAY_MS);
+    let samples = db
+        .metric_samples(now_ms - window_days as i64 * DAY_MS)
+        .map_err(|e| e.to_string())?;
+    Ok(MetricsDashboard {
+        sharing_enabled: settings::get_settings(app).metrics_sharing_enabled,
+        ..build_dashboard(&samples, window_days, now_ms)
+    })
+}
+
+/// What sharing sends: the dashboard's aggregates, rounded, plus app version and OS
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedMetrics {
+    pub app_version: String,
+    pub os: String,
+    pub window_days: u32,
+    pub meetings_recorded: usize,
+    pub median_meeting_minutes: Option<f64>,
+    pub median_asr_rtf: Option<f64>,
+    pub p95_asr_rtf: Option<f64>,
+    pub asr_failure_rate: f64,
+    pub median_queue_wait_ms: Option<f64>,
+    pub p95_queue_wait_ms: Option<f64>,
+    pub dead_lettered: usize,
+    pub llm_summary_failures: usize,
+    pub model: String,
+}
+
+/// Round to `places` decimals; negative places round to tens, hundreds, ...
+fn round(value: f64, places: i32) -> f64 {
+    if places >= 0 {
+        let factor = 10f64.powi(places);
+        (value * factor).round() / factor
+    } else {
+        let step = 10f64.powi(-places);
+        (value / step).round() * step
+    }
+}
+
+pub fn shared_metrics(
+    dashboard: &MetricsDashboard,
+    app_version: &str,
+    model: &str,
+) -> SharedMetrics {
+    SharedMetrics {
+        app_version: app_version.to_string(),
+        os: std::env::consts::OS.to_string(),
+        window_days: dashboard.window_days,
+        meetings_recorded: dashboard.meetings_recorded,
+        median_meeting_minutes: dashboard.meeting_minutes.as_ref().map(|d| round(d.p50, 0)),
+        median_asr_rtf: dashboard.asr_rtf.as_ref().map(|d| round(d.p50, 2)),
+        p95_asr_rtf: dashboard.asr_rtf.as_ref().map(|d| round(d.p95, 2)),
+        asr_failure_rate: round(dashboard.asr_failure_rate, 3),
+        median_queue_wait_ms: dashboard.queue_wait_ms.as_ref().map(|d| round(d.p50, -2)),
+        p95_queue_wait_ms: dashboard.queue_wait_ms.as_ref().map(|d| round(d.p95, -2)),
+        dead_lettered: dashboard.dead_lettered,
+        llm_summary_failures: dashboard.llm_summary_failures,
+        model: model.to_string(),
+    }
+}
+
+fn build_shared(app: &AppHandle) -> Result<SharedMetrics, String> {
+    let dashboard = load_dashboard(app, None)?;
+    let settings = settings::get_settings(app);
+    Ok(shared_metrics(
+        &dashboard,
+        &app.package_info().version.to_string(),
+        &settings.selected_model,
+    ))
+}
+
+/// Local metrics aggregated over the last `days` (default 30)
+#[tauri::command]
+pub fn get_metrics_dashboard(
+    app: AppHandle,
+    days: Option<u32>,
+) -> Result<MetricsDashboard, AppError> {
+    Ok(load_dashboard(&app, days)?)
+}
+
+/// Exactly what `share_metrics` would send
+#[tauri::command]
+pub fn preview_shared_metrics(app: AppHandle) -> Result<SharedMetrics, AppError> {
+    Ok(build_shared(&app)?)
+}
+
+/// Send the aggregate stats to the configured endpoint; requires the opt-in
+#[tauri::command]
+pub async fn share_metrics(app: AppHandle) -> Result<SharedMetrics, AppError> {
+    let settings = settings::get_settings(&app);
+    if !settings.metrics_sharing_enabled {
+        return Err(AppError::NotConfigured(
+            "Metrics sharing is off".to_string(),
+        ));
+    }
+    let url = settings
+        .metrics_share_url
+        .ok_or_else(|| AppError::NotConfigured("No metrics sharing URL is set".to_string()))?;
+    let payload = build_shared(&app)?;
+    let response = network::client(Service::Metrics, &url)?
+        .post(&url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| AppError::Network(format!("Failed to share metrics: {}", e)))?;
+    if !response.status().is_success() {
+        return Err(AppError::Network(format!(
+            "Metrics endpoint returned {}",
+            response.status()
+        )));
+    }
+    log::info!("METRICS shared aggregate stats with {}", url);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashboard_aggregates() {
+        let now = 100 * DAY_MS;
+        let sample = |metric: Metric, value: f64, days_ago: i64| {
+            (
+                metric.as_str().to_string(),
+                value,
+                now - days_ago * DAY_MS - 1,
+            )
+        };
+        let samples = vec![
+            sample(Metric::MeetingRecorded, 30.0, 0),
+            sample(Metric::MeetingRecorded, 45.0, 2),
+            sample(Metric::AsrRtf, 0.2, 0),
+            sample(Metric::AsrRtf, 0.4, 0),
+            sample(Metric::AsrRtf, 0.3, 0),
+            sample(Metric::AsrFailure, 1.0, 0),
+            sample(Metric::QueueWaitMs, 1200.0, 0),
+            sample(Metric::LlmSummary, 1.0, 1),
+            sample(Metric::LlmSummary, 0.0, 1),
+        ];
+        let dashboard = build_dashboard(&samples, 7, now);
+        assert_eq!(dashboard.meetings_recorded, 2);
+        assert_eq!(dashboard.meetings_per_day, vec![0, 0, 0, 0, 1, 0, 1]);
+        let rtf = dashboard.asr_rtf.clone().unwrap();
+        assert_eq!((rtf.count, rtf.p50), (3, 0.3));
+        assert_eq!(dashboard.asr_failure_rate, 0.25);
+        assert_eq!(
+            (dashboard.llm_summaries, dashboard.llm_summary_failures),
+            (2, 1)
+        );
+
+        let shared = shared_metrics(&dashboard, "1.0.0", "parakeet");
+        assert_eq!(shared.median_queue_wait_ms, Some(1200.0));
+        assert_eq!(shared.median_meeting_minutes, Some(45.0));
+
+        let empty = build_dashboard(&[], 30, now);
+        assert!(empty.asr_rtf.is_none());
+        assert_eq!(empty.asr_failure_rate, 0.0);
+    }
+}
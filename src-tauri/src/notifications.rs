@@ -0,0 +1,57 @@
+use serde::Serialize;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+use crate::settings::{self, NotificationSettings};
+
+/// Meeting milestones that raise a desktop notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Milestone {
+    SummaryReady,
+    PrCreated,
+    AudioStreamFailed,
+    PrdGenerated,
+    ImportFinished,
+}
+
+impl Milestone {
+    fn enabled_in(self, prefs: &NotificationSettings) -> bool {
+        prefs.enabled
+            && match self {
+                Milestone::SummaryReady => prefs.summary_ready,
+                Milestone::PrCreated => prefs.pr_created,
+                Milestone::AudioStreamFailed => prefs.audio_stream_failed,
+                Milestone::PrdGenerated => prefs.prd_generated,
+                Milestone::ImportFinished => prefs.import_finished,
+            }
+    }
+}
+
+/// Show a native notification unless the user opted out of this milestone
+pub fn notify(app: &AppHandle, milestone: Milestone, title: &str, body: &str) {
+    if !milestone.enabled_in(&settings::get_settings(app).notifications) {
+        return;
+    }
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        log::warn!("Failed to show {:?} notification: {}", milestone, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestone_opt_outs() {
+        let mut prefs = NotificationSettings {
+            pr_created: false,
+            ..Default::default()
+        };
+        assert!(Milestone::SummaryReady.enabled_in(&prefs));
+        assert!(!Milestone::PrCreated.enabled_in(&prefs));
+
+        prefs.enabled = false;
+        assert!(!Milestone::SummaryReady.enabled_in(&prefs));
+    }
+}
@@ -1,11 +1,35 @@
 use crate::settings;
-use crate::settings::OverlayPosition;
+use crate::settings::{OverlayContent, OverlayPlacement, OverlayPosition};
 use log::debug;
 use enigo::{Enigo, Mouse};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::RwLock;
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize, WebviewWindowBuilder};
 
 const OVERLAY_WIDTH: f64 = 172.0;
 const OVERLAY_HEIGHT: f64 = 36.0;
+/// Captions need room for a line of text; the status pill only shows a label and timer
+const CAPTIONS_OVERLAY_WIDTH: f64 = 360.0;
+const STATUS_PILL_OVERLAY_WIDTH: f64 = 120.0;
+/// Longest caption sent to the overlay; older text is cut from the front
+const MAX_CAPTION_CHARS: usize = 60;
+
+/// Cached content mode so the per-frame level stream doesn't read settings
+static CONTENT_MODE: Lazy<RwLock<OverlayContent>> =
+    Lazy::new(|| RwLock::new(OverlayContent::default()));
+
+fn content_mode() -> OverlayContent {
+    *CONTENT_MODE.read().unwrap()
+}
+
+fn overlay_width(mode: OverlayContent) -> f64 {
+    match mode {
+        OverlayContent::Captions => CAPTIONS_OVERLAY_WIDTH,
+        OverlayContent::StatusPill => STATUS_PILL_OVERLAY_WIDTH,
+        OverlayContent::Levels | OverlayContent::Waveform => OVERLAY_WIDTH,
+    }
+}
 
 #[cfg(target_os = "macos")]
 const OVERLAY_TOP_OFFSET: f64 = 46.0;
@@ -36,6 +60,82 @@ fn get_monitor_with_cursor(app_handle: &AppHandle) -> Option<tauri::Monitor> {
     app_handle.primary_monitor().ok().flatten()
 }
 
+fn monitor_name(monitor: &tauri::Monitor, index: usize) -> String {
+    monitor
+        .name()
+        .cloned()
+        .unwrap_or_else(|| format!("Display {}", index + 1))
+}
+
+/// Identifies the set of connected monitors, e.g.
+/// "Built-in Retina Display 2880x1800+0+0|DELL U2720Q 3840x2160+2880+0"
+pub fn display_configuration(app_handle: &AppHandle) -> String {
+    let mut monitors: Vec<String> = app_handle
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            format!(
+                "{} {}x{}+{}+{}",
+                monitor_name(m, i),
+                m.size().width,
+                m.size().height,
+                m.position().x,
+                m.position().y
+            )
+        })
+        .collect();
+    monitors.sort();
+    monitors.join("|")
+}
+
+fn current_placement(app_handle: &AppHandle) -> OverlayPlacement {
+    settings::get_settings(app_handle)
+        .overlay_placements
+        .remove(&display_configuration(app_handle))
+        .unwrap_or_default()
+}
+
+/// Monitor chosen in the placement, falling back to the one under the cursor
+fn placement_monitor(app_handle: &AppHandle, placement: &OverlayPlacement) -> Option<tauri::Monitor> {
+    if let Some(wanted) = &placement.monitor {
+        let found = app_handle
+            .available_monitors()
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .find(|(i, m)| &monitor_name(m, *i) == wanted)
+            .map(|(_, m)| m);
+        if found.is_some() {
+            return found;
+        }
+    }
+    get_monitor_with_cursor(app_handle)
+}
+
+/// Monitors and the placement in effect, for the settings UI
+#[derive(Serialize)]
+pub struct OverlayPlacementInfo {
+    pub display_configuration: String,
+    pub monitors: Vec<String>,
+    pub placement: OverlayPlacement,
+}
+
+pub fn overlay_placement_info(app_handle: &AppHandle) -> OverlayPlacementInfo {
+    OverlayPlacementInfo {
+        display_configuration: display_configuration(app_handle),
+        monitors: app_handle
+            .available_monitors()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(i, m)| monitor_name(m, i))
+            .collect(),
+        placement: current_placement(app_handle),
+    }
+}
+
 fn is_mouse_within_monitor(
     mouse_pos: (i32, i32),
     monitor_pos: &PhysicalPosition<i32>,
@@ -52,7 +152,8 @@ fn is_mouse_within_monitor(
 }
 
 fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
-    if let Some(monitor) = get_monitor_with_cursor(app_handle) {
+    let placement = current_placement(app_handle);
+    if let Some(monitor) = placement_monitor(app_handle, &placement) {
             let work_area = monitor.work_area();
             let scale = monitor.scale_factor();
             let work_area_width = work_area.size.width as f64 / scale;
@@ -62,7 +163,8 @@ fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
 
             let settings = settings::get_settings(app_handle);
 
-            let x = work_area_x + (work_area_width - OVERLAY_WIDTH) / 2.0;
+            let width = overlay_width(settings.overlay_content);
+            let x = work_area_x + (work_area_width - width) / 2.0;
             let y = match settings.overlay_position {
                 OverlayPosition::Top => work_area_y + OVERLAY_TOP_OFFSET,
                 OverlayPosition::Bottom | OverlayPosition::None => {
@@ -71,6 +173,14 @@ fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
                 }
             };
 
+            // Offsets can't push the overlay off its monitor
+            let x = (x + placement.offset_x as f64)
+                .min(work_area_x + work_area_width - width)
+                .max(work_area_x);
+            let y = (y + placement.offset_y as f64)
+                .min(work_area_y + work_area_height - OVERLAY_BOTTOM_OFFSET)
+                .max(work_area_y);
+
             return Some((x, y));
         }
     None
@@ -78,6 +188,8 @@ fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
 
 /// Creates the recording overlay window and keeps it hidden by default
 pub fn create_recording_overlay(app_handle: &AppHandle) {
+    let mode = settings::get_settings(app_handle).overlay_content;
+    *CONTENT_MODE.write().unwrap() = mode;
     if let Some((x, y)) = calculate_overlay_position(app_handle) {
         match WebviewWindowBuilder::new(
             app_handle,
@@ -87,7 +199,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
         .title("Recording")
         .position(x, y)
         .resizable(false)
-        .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
+        .inner_size(overlay_width(mode), OVERLAY_HEIGHT)
         .shadow(false)
         .maximizable(false)
         .minimizable(false)
@@ -123,6 +235,7 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         let _ = overlay_window.show();
+        let _ = overlay_window.emit("overlay-content", content_mode());
         // Emit event to trigger fade-in animation with recording state
         let _ = overlay_window.emit("show-overlay", "recording");
     }
@@ -140,6 +253,7 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         let _ = overlay_window.show();
+        let _ = overlay_window.emit("overlay-content", content_mode());
         // Emit event to switch to transcribing state
         let _ = overlay_window.emit("show-overlay", "transcribing");
     }
@@ -175,8 +289,48 @@ pub fn emit_levels(app_handle: &AppHandle, levels: &Vec<f32>) {
     // emit levels to main app
     let _ = app_handle.emit("mic-level", levels);
 
-    // also emit to the recording overlay if it's open
+    // also emit to the recording overlay if it's open and showing level bars
+    if content_mode() != OverlayContent::Levels {
+        return;
+    }
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         let _ = overlay_window.emit("mic-level", levels);
     }
 }
+
+/// Amplitude peaks for the waveform mode; only the overlay consumes them
+pub fn emit_waveform(app_handle: &AppHandle, peaks: &[f32]) {
+    if content_mode() != OverlayContent::Waveform {
+        return;
+    }
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("overlay-waveform", peaks);
+    }
+}
+
+/// Latest transcribed text for the captions mode
+pub fn emit_caption(app_handle: &AppHandle, text: &str) {
+    if content_mode() != OverlayContent::Captions || text.trim().is_empty() {
+        return;
+    }
+    let chars: Vec<char> = text.trim().chars().collect();
+    let caption: String = chars[chars.len().saturating_sub(MAX_CAPTION_CHARS)..]
+        .iter()
+        .collect();
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("overlay-caption", caption);
+    }
+}
+
+/// Switch the overlay to a new content mode: resize it and tell the webview
+pub fn apply_overlay_content(app_handle: &AppHandle, mode: OverlayContent) {
+    *CONTENT_MODE.write().unwrap() = mode;
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize {
+            width: overlay_width(mode),
+            height: OVERLAY_HEIGHT,
+        }));
+        let _ = overlay_window.emit("overlay-content", mode);
+    }
+    update_overlay_position(app_handle);
+}
@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Activity log written by the hook script, relative to the project root
+pub const ACTIVITY_LOG: &str = ".claude/agent-activity.jsonl";
+
+/// Hook script relative to the project root
+const HOOK_SCRIPT: &str = ".claude/hooks/meetingcoder-log.sh";
+
+/// Tools whose use is reported back to MeetingCoder
+const HOOK_MATCHER: &str = "Edit|MultiEdit|Write|NotebookEdit|Bash";
+
+const HOOK_SCRIPT_CONTENT: &str = r#"#!/usr/bin/env bash
+# Generated by MeetingCoder: records Claude Code tool use so meeting updates
+# can report what the agent changed. Never blocks the tool call.
+dir="${CLAUDE_PROJECT_DIR:-$(pwd)}"
+mkdir -p "$dir/.claude"
+payload="$(cat | tr -d '\n')"
+[ -z "$payload" ] && exit 0
+printf '{"ts":"%s","payload":%s}\n' "$(date -u +%Y-%m-%dT%H:%M:%SZ)" "$payload" >> "$dir/.claude/agent-activity.jsonl"
+exit 0
+"#;
+
+/// A single tool-use event reported by the Claude Code hooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentActivity {
+    pub timestamp: String,
+    /// "PreToolUse" or "PostToolUse"
+    pub event: String,
+    pub tool_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+fn hook_entry() -> serde_json::Value {
+    serde_json::json!({
+        "matcher": HOOK_MATCHER,
+        "hooks": [{
+            "type": "command",
+            "command": format!("\"$CLAUDE_PROJECT_DIR\"/{}", HOOK_SCRIPT),
+        }]
+    })
+}
+
+fn is_our_entry(entry: &serde_json::Value) -> bool {
+    entry
+        .get("hooks")
+        .and_then(|h| h.as_array())
+        .map(|hooks| {
+            hooks.iter().any(|h| {
+                h.get("command")
+                    .and_then(|c| c.as_str())
+                    .map(|c| c.contains("meetingcoder-log.sh"))
+                    .unwrap_or(false)
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Merge the MeetingCoder pre/post tool-use hooks into an existing settings value,
+/// leaving any user-defined hooks untouched
+pub fn merge_hook_settings(mut settings: serde_json::Value) -> serde_json::Value {
+    if !settings.is_object() {
+        settings = serde_json::json!({});
+    }
+    if !settings.get("hooks").map(|h| h.is_object()).unwrap_or(false) {
+        settings["hooks"] = serde_json::json!({});
+    }
+    for event in ["PreToolUse", "PostToolUse"] {
+        let mut entries: Vec<serde_json::Value> = settings["hooks"]
+            .get(event)
+            .and_then(|e| e.as_array())
+            .cloned()
+            .unwrap_or_default();
+        entries.retain(|e| !is_our_entry(e));
+        entries.push(hook_entry());
+        settings["hooks"][event] = serde_json::Value::Array(entries);
+    }
+    settings
+}
+
+/// Install the hook script and register it in `.claude/settings.json`
+pub fn install(project_dir: &Path) -> Result<()> {
+    let script_path = project_dir.join(HOOK_SCRIPT);
+    if let Some(parent) = script_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&script_path, HOOK_SCRIPT_CONTENT)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms)?;
+    }
+
+    let settings_path = project_dir.join(".claude").join("settings.json");
+    // Never replace settings we can't read back; the user has to fix them first
+    let existing = match fs::read_to_string(&settings_path) {
+        Ok(content) => serde_json::from_str::<serde_json::Value>(&content)
+            .ok()
+            .filter(|v| v.is_object())
+            .ok_or_else(|| {
+                anyhow!(
+                    "{} is not a valid settings object; fix it to install the hooks",
+                    settings_path.display()
+                )
+            })?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => serde_json::json!({}),
+        Err(e) => return Err(e.into()),
+    };
+    let merged = merge_hook_settings(existing);
+    fs::write(&settings_path, serde_json::to_string_pretty(&merged)?)?;
+
+    log::info!("Installed Claude Code hooks in {}", project_dir.display());
+    Ok(())
+}
+
+fn parse_activity_line(line: &str) -> Option<AgentActivity> {
+    let v: serde_json::Value = serde_json::from_str(line).ok()?;
+    let payload = v.get("payload")?;
+    let input = payload.get("tool_input");
+    let str_field = |key: &str| {
+        input
+            .and_then(|i| i.get(key))
+            .and_then(|x| x.as_str())
+            .map(|s| s.to_string())
+    };
+    Some(AgentActivity {
+        timestamp: v.get("ts").and_then(|x| x.as_str()).unwrap_or_default().to_string(),
+        event: payload
+            .get("hook_event_name")
+            .and_then(|x| x.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        tool_name: payload.get("tool_name").and_then(|x| x.as_str())?.to_string(),
+        file_path: str_field("file_path").or_else(|| str_field("notebook_path")),
+        command: str_field("command"),
+    })
+}
+
+/// Read hook-reported activity, skipping the first `skip` log lines.
+/// Returns the parsed events and the total number of lines in the log.
+pub fn read_activity(project_path: &str, skip: usize) -> (Vec<AgentActivity>, usize) {
+    let path = Path::new(project_path).join(ACTIVITY_LOG);
+    let Ok(content) = fs::read_to_string(path) else { return (Vec::new(), 0); };
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    let events = lines
+        .iter()
+        .skip(skip)
+        .filter_map(|l| parse_activity_line(l))
+        .collect();
+    (events, lines.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_merge_preserves_user_hooks_and_is_idempotent() {
+        let user = serde_json::json!({
+            "permissions": { "allow": ["Bash(ls:*)"] },
+            "hooks": { "PreToolUse": [{ "matcher": "Bash", "hooks": [{ "type": "command", "command": "echo hi" }] }] }
+        });
+        let merged = merge_hook_settings(merge_hook_settings(user));
+        let pre = merged["hooks"]["PreToolUse"].as_array().unwrap();
+        assert_eq!(pre.len(), 2);
+        assert_eq!(merged["hooks"]["PostToolUse"].as_array().unwrap().len(), 1);
+        assert!(merged["permissions"].is_object());
+    }
+
+    #[test]
+    fn test_install_and_read_activity() {
+        let temp_dir = TempDir::new().unwrap();
+        install(temp_dir.path()).unwrap();
+        assert!(temp_dir.path().join(HOOK_SCRIPT).exists());
+        assert!(temp_dir.path().join(".claude/settings.json").exists());
+
+        let log = concat!(
+            r#"{"ts":"2025-01-01T00:00:00Z","payload":{"hook_event_name":"PostToolUse","tool_name":"Write","tool_input":{"file_path":"experiments/a.ts"}}}"#,
+            "\n",
+            r#"{"ts":"2025-01-01T00:00:05Z","payload":{"hook_event_name":"PostToolUse","tool_name":"Bash","tool_input":{"command":"npm test"}}}"#,
+            "\n"
+        );
+        fs::write(temp_dir.path().join(ACTIVITY_LOG), log).unwrap();
+
+        let root = temp_dir.path().to_str().unwrap();
+        let (events, total) = read_activity(root, 0);
+        assert_eq!(total, 2);
+        assert_eq!(events[0].file_path.as_deref(), Some("experiments/a.ts"));
+        assert_eq!(events[1].command.as_deref(), Some("npm test"));
+
+        let (rest, _) = read_activity(root, 1);
+        assert_eq!(rest.len(), 1);
+    }
+
+    #[test]
+    fn test_install_keeps_unparseable_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let settings_path = temp_dir.path().join(".claude/settings.json");
+        fs::create_dir_all(settings_path.parent().unwrap()).unwrap();
+        fs::write(&settings_path, "{ \"permissions\": ").unwrap();
+
+        assert!(install(temp_dir.path()).is_err());
+        assert_eq!(fs::read_to_string(&settings_path).unwrap(), "{ \"permissions\": ");
+    }
+}
@@ -17,10 +17,7 @@ impl ProjectInitializer {
     }
 
     pub fn with_default_path() -> Result<Self> {
-        let home = dirs::home_dir()
-            .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        let base = home.join("MeetingCoder").join("projects");
-        Self::new(base)
+        Self::new(crate::storage::root::storage_dir("projects")?)
     }
 
     fn sanitize_name(name: &str) -> String {
@@ -67,6 +64,11 @@ impl ProjectInitializer {
             log::warn!("Dev template not found at {}", dev_template_path.display());
         }
 
+        // Claude Code hooks that report agent tool use back into the update stream
+        if let Err(e) = super::claude_hooks::install(&project_dir) {
+            log::warn!("Failed to install Claude Code hooks: {}", e);
+        }
+
         // Optional README
         let readme_path = project_dir.join("README.md");
         if !readme_path.exists() {
@@ -209,6 +211,11 @@ exit 0
             }
         }
 
+        // Claude Code hooks that report agent tool use back into the update stream
+        if let Err(e) = super::claude_hooks::install(dir) {
+            log::warn!("Failed to install Claude Code hooks: {}", e);
+        }
+
         Ok(())
     }
 }
@@ -1,2 +1,2 @@
+pub mod claude_hooks;
 pub mod initializer;
-
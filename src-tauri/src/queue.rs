@@ -1,9 +1,97 @@
 use anyhow::Result;
-use rusqlite::{params, Connection, OptionalExtension};
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
+/// Queue table, as first shipped
+const QUEUE_SCHEMA_V1: &str = r#"
+CREATE TABLE IF NOT EXISTS queue (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  meeting_id TEXT NOT NULL,
+  start_ms INTEGER NOT NULL,
+  end_ms INTEGER NOT NULL,
+  file_path TEXT NOT NULL,
+  status TEXT NOT NULL DEFAULT 'queued',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  error TEXT,
+  created_at INTEGER NOT NULL,
+  updated_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_queue_status ON queue(status);
+CREATE INDEX IF NOT EXISTS idx_queue_meeting ON queue(meeting_id);
+"#;
+
+/// Job priorities; existing rows were all live chunks
+const QUEUE_SCHEMA_V2: &str = r#"
+ALTER TABLE queue ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+CREATE INDEX IF NOT EXISTS idx_queue_pick ON queue(status, priority, id);
+"#;
+
+/// Retry scheduling for failed jobs
+const QUEUE_SCHEMA_V3: &str = r#"
+ALTER TABLE queue ADD COLUMN next_attempt_at INTEGER NOT NULL DEFAULT 0;
+"#;
+
+const QUEUE_MIGRATIONS: &[(i64, &str)] = &[
+    (1, QUEUE_SCHEMA_V1),
+    (2, QUEUE_SCHEMA_V2),
+    (3, QUEUE_SCHEMA_V3),
+];
+
+/// A job that has failed this many times is moved to the dead-letter state
+pub const MAX_ATTEMPTS: i64 = 5;
+const BACKOFF_BASE_MS: i64 = 2_000;
+const BACKOFF_MAX_MS: i64 = 5 * 60 * 1000;
+
+/// Delay before retry number `attempts` (1-based): 2s, 4s, 8s, ... capped at 5 minutes
+pub fn backoff_ms(attempts: i64) -> i64 {
+    let exponent = (attempts - 1).clamp(0, 20) as u32;
+    (BACKOFF_BASE_MS << exponent).min(BACKOFF_MAX_MS)
+}
+
+/// Workers always take the most urgent job first; lower values win
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JobPriority {
+    /// Chunks of a meeting being recorded (live captions)
+    Live = 0,
+    /// Chunks of an imported recording
+    Import = 1,
+    /// Re-running ASR over audio that already has a transcript
+    Retranscribe = 2,
+}
+
+impl JobPriority {
+    fn from_i64(value: i64) -> Self {
+        match value {
+            0 => JobPriority::Live,
+            1 => JobPriority::Import,
+            _ => JobPriority::Retranscribe,
+        }
+    }
+}
+
+/// Which jobs a worker pool claims, so imports never hold up live captions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkLane {
+    /// Live-meeting chunks
+    Live,
+    /// Imports and re-transcription
+    Background,
+}
+
+impl WorkLane {
+    /// Inclusive priority range served by the lane
+    fn priorities(self) -> (JobPriority, JobPriority) {
+        match self {
+            WorkLane::Live => (JobPriority::Live, JobPriority::Live),
+            WorkLane::Background => (JobPriority::Import, JobPriority::Retranscribe),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct QueueItem {
     pub id: i64,
@@ -11,6 +99,47 @@ pub struct QueueItem {
     pub start_ms: u64,
     pub end_ms: u64,
     pub file_path: String,
+    pub priority: JobPriority,
+    /// Unix milliseconds when the job was enqueued
+    pub created_at: i64,
+}
+
+/// A job that exhausted its retries
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub meeting_id: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub file_path: String,
+    pub attempts: i64,
+    pub error: Option<String>,
+    pub updated_at: i64,
+}
+
+/// Outcome of reconciling the queue at startup
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct RecoveryReport {
+    /// Jobs interrupted mid-processing and put back in the queue
+    pub requeued: usize,
+    /// Queued jobs whose segment file no longer exists (moved to dead letters)
+    pub missing_files: usize,
+    /// Meetings that still have queued work
+    pub meetings: Vec<String>,
+}
+
+/// Project a segment file was recorded for. Live segments are written to
+/// `<project>/audio_segments/`, so the project outlives the in-memory meeting.
+pub fn recovery_project_path(file_path: &str) -> Option<String> {
+    let dir = Path::new(file_path).parent()?;
+    if dir.file_name()? != "audio_segments" {
+        return None;
+    }
+    let project = dir.parent()?;
+    if project.as_os_str().is_empty() {
+        return None;
+    }
+    Some(project.to_string_lossy().to_string())
 }
 
 pub struct Queue {
@@ -22,32 +151,23 @@ impl Queue {
         let db_path: PathBuf = app
             .path()
             .resolve("audio_queue.sqlite", tauri::path::BaseDirectory::AppData)?;
+        Ok(Arc::new(Self::open_at(db_path)?))
+    }
+
+    /// Open (creating if needed) a queue database at an explicit path
+    pub fn open_at(db_path: PathBuf) -> Result<Self> {
         std::fs::create_dir_all(db_path.parent().unwrap_or_else(|| Path::new(".")))?;
         let conn = Connection::open(&db_path)?;
-        conn.execute_batch(
-            r#"
-            PRAGMA journal_mode=WAL;
-            PRAGMA synchronous=NORMAL;
-            CREATE TABLE IF NOT EXISTS queue (
-              id INTEGER PRIMARY KEY AUTOINCREMENT,
-              meeting_id TEXT NOT NULL,
-              start_ms INTEGER NOT NULL,
-              end_ms INTEGER NOT NULL,
-              file_path TEXT NOT NULL,
-              status TEXT NOT NULL DEFAULT 'queued',
-              attempts INTEGER NOT NULL DEFAULT 0,
-              error TEXT,
-              created_at INTEGER NOT NULL,
-              updated_at INTEGER NOT NULL
-            );
-            CREATE INDEX IF NOT EXISTS idx_queue_status ON queue(status);
-            CREATE INDEX IF NOT EXISTS idx_queue_meeting ON queue(meeting_id);
-            "#,
-        )?;
-        Ok(Arc::new(Self { db_path }))
+        conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
+        let current: i64 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
+        for &(version, sql) in QUEUE_MIGRATIONS.iter().filter(|(v, _)| *v > current) {
+            conn.execute_batch(sql)?;
+            conn.execute_batch(&format!("PRAGMA user_version = {};", version))?;
+        }
+        Ok(Self { db_path })
     }
 
-    fn now_ms() -> i64 {
+    pub fn now_ms() -> i64 {
         use std::time::{SystemTime, UNIX_EPOCH};
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -55,29 +175,95 @@ impl Queue {
             .as_millis() as i64
     }
 
-    fn open(&self) -> Result<Connection> { Ok(Connection::open(&self.db_path)?) }
+    fn open(&self) -> Result<Connection> {
+        let conn = Connection::open(&self.db_path)?;
+        // Several workers claim jobs concurrently
+        conn.busy_timeout(Duration::from_secs(5))?;
+        Ok(conn)
+    }
+
+    /// Reconcile persisted jobs after a restart: jobs left 'processing' by the previous run
+    /// are queued again, and queued jobs whose segment file is gone are dead-lettered.
+    /// Must run before any worker starts claiming jobs.
+    pub fn recover(&self) -> Result<RecoveryReport> {
+        let mut conn = self.open()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let now = Self::now_ms();
+        let requeued = tx.execute(
+            "UPDATE queue SET status='queued', next_attempt_at=0, updated_at=? WHERE status='processing'",
+            params![now],
+        )?;
+        let queued: Vec<(i64, String)> = {
+            let mut stmt = tx.prepare("SELECT id, file_path FROM queue WHERE status='queued'")?;
+            let rows = stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+        let mut missing_files = 0;
+        for (id, file_path) in queued {
+            if !Path::new(&file_path).is_file() {
+                tx.execute(
+                    "UPDATE queue SET status='dead', error='segment file missing', updated_at=? WHERE id=?",
+                    params![now, id],
+                )?;
+                missing_files += 1;
+            }
+        }
+        let meetings = {
+            let mut stmt = tx.prepare(
+                "SELECT meeting_id FROM queue WHERE status='queued' GROUP BY meeting_id ORDER BY MIN(id)",
+            )?;
+            let rows = stmt.query_map([], |r| r.get(0))?;
+            rows.collect::<rusqlite::Result<Vec<String>>>()?
+        };
+        tx.commit()?;
+        Ok(RecoveryReport {
+            requeued,
+            missing_files,
+            meetings,
+        })
+    }
 
+    /// Enqueue a live-meeting chunk
     pub fn enqueue(&self, meeting_id: &str, start_ms: u64, end_ms: u64, file_path: &str) -> Result<i64> {
+        self.enqueue_with_priority(meeting_id, start_ms, end_ms, file_path, JobPriority::Live)
+    }
+
+    pub fn enqueue_with_priority(
+        &self,
+        meeting_id: &str,
+        start_ms: u64,
+        end_ms: u64,
+        file_path: &str,
+        priority: JobPriority,
+    ) -> Result<i64> {
         let conn = self.open()?;
         let now = Self::now_ms();
         conn.execute(
-            "INSERT INTO queue (meeting_id, start_ms, end_ms, file_path, status, attempts, created_at, updated_at) VALUES (?, ?, ?, ?, 'queued', 0, ?, ?)",
-            params![meeting_id, start_ms as i64, end_ms as i64, file_path, now, now],
+            "INSERT INTO queue (meeting_id, start_ms, end_ms, file_path, status, attempts, priority, created_at, updated_at) VALUES (?, ?, ?, ?, 'queued', 0, ?, ?, ?)",
+            params![meeting_id, start_ms as i64, end_ms as i64, file_path, priority as i64, now, now],
         )?;
         Ok(conn.last_insert_rowid())
     }
 
-    pub fn fetch_next(&self) -> Result<Option<QueueItem>> {
-        let conn = self.open()?;
-        let tx = conn.unchecked_transaction()?;
-        let row: Option<(i64, String, i64, i64, String)> = tx
+    /// Claim the next job of `lane`: highest priority first, and within a meeting strictly
+    /// in enqueue order with at most one job in flight, so segments are appended in order.
+    pub fn fetch_next(&self, lane: WorkLane) -> Result<Option<QueueItem>> {
+        let (min_priority, max_priority) = lane.priorities();
+        let mut conn = self.open()?;
+        // IMMEDIATE takes the write lock up front so two workers can't claim the same row
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let row: Option<(i64, String, i64, i64, String, i64, i64)> = tx
             .query_row(
-                "SELECT id, meeting_id, start_ms, end_ms, file_path FROM queue WHERE status='queued' ORDER BY id LIMIT 1",
-                [],
-                |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?)),
+                "SELECT id, meeting_id, start_ms, end_ms, file_path, priority, created_at FROM queue q
+                 WHERE status='queued' AND next_attempt_at <= ?1 AND priority BETWEEN ?2 AND ?3
+                   AND NOT EXISTS (SELECT 1 FROM queue p WHERE p.meeting_id=q.meeting_id AND p.status='processing')
+                   AND NOT EXISTS (SELECT 1 FROM queue e WHERE e.meeting_id=q.meeting_id AND e.status='queued' AND e.id<q.id)
+                 ORDER BY priority, id LIMIT 1",
+                params![Self::now_ms(), min_priority as i64, max_priority as i64],
+                |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?, r.get(5)?, r.get(6)?)),
             )
             .optional()?;
-        if let Some((id, meeting_id, start_ms, end_ms, file_path)) = row {
+        if let Some((id, meeting_id, start_ms, end_ms, file_path, priority, created_at)) = row {
             tx.execute(
                 "UPDATE queue SET status='processing', updated_at=? WHERE id=?",
                 params![Self::now_ms(), id],
@@ -89,6 +275,8 @@ impl Queue {
                 start_ms: start_ms as u64,
                 end_ms: end_ms as u64,
                 file_path,
+                priority: JobPriority::from_i64(priority),
+                created_at,
             }))
         } else {
             tx.commit()?;
@@ -98,31 +286,115 @@ impl Queue {
 
     pub fn mark_done(&self, id: i64) -> Result<()> {
         let conn = self.open()?;
+        // A job cancelled while in flight stays cancelled
         conn.execute(
-            "UPDATE queue SET status='done', updated_at=? WHERE id=?",
+            "UPDATE queue SET status='done', updated_at=? WHERE id=? AND status='processing'",
             params![Self::now_ms(), id],
         )?;
         Ok(())
     }
 
-    pub fn mark_failed(&self, id: i64, error: &str) -> Result<()> {
+    /// Record a failed attempt and schedule a retry with exponential backoff. Returns true
+    /// when the job ran out of attempts and was moved to the dead-letter state.
+    pub fn mark_failed(&self, id: i64, error: &str) -> Result<bool> {
         let conn = self.open()?;
+        let Some(attempts) = conn
+            .query_row(
+                "SELECT attempts + 1 FROM queue WHERE id=? AND status='processing'",
+                params![id],
+                |r| r.get::<_, i64>(0),
+            )
+            .optional()?
+        else {
+            // Cancelled while in flight
+            return Ok(false);
+        };
+        let now = Self::now_ms();
+        let dead = attempts >= MAX_ATTEMPTS;
         conn.execute(
-            "UPDATE queue SET status='queued', attempts=attempts+1, error=?, updated_at=? WHERE id=?",
-            params![error, Self::now_ms(), id],
+            "UPDATE queue SET status=?, attempts=?, error=?, next_attempt_at=?, updated_at=? WHERE id=?",
+            params![
+                if dead { "dead" } else { "queued" },
+                attempts,
+                error,
+                now + backoff_ms(attempts),
+                now,
+                id
+            ],
         )?;
-        Ok(())
+        Ok(dead)
+    }
+
+    /// Drop a meeting's queued jobs and mark its in-flight job cancelled, so the worker
+    /// discards the result instead of appending it. Returns how many jobs were cancelled.
+    pub fn cancel_meeting(&self, meeting_id: &str) -> Result<usize> {
+        let mut conn = self.open()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let removed = tx.execute(
+            "DELETE FROM queue WHERE meeting_id=? AND status='queued'",
+            params![meeting_id],
+        )?;
+        let in_flight = tx.execute(
+            "UPDATE queue SET status='cancelled', updated_at=? WHERE meeting_id=? AND status='processing'",
+            params![Self::now_ms(), meeting_id],
+        )?;
+        tx.commit()?;
+        Ok(removed + in_flight)
+    }
+
+    /// Whether a claimed job was cancelled since it was picked up
+    pub fn is_cancelled(&self, id: i64) -> Result<bool> {
+        let conn = self.open()?;
+        Ok(conn
+            .query_row("SELECT status FROM queue WHERE id=?", params![id], |r| r.get::<_, String>(0))
+            .optional()?
+            .map(|status| status == "cancelled")
+            // Row gone entirely: nothing left to deliver the result to
+            .unwrap_or(true))
+    }
+
+    /// Jobs in the dead-letter state, oldest first
+    pub fn list_dead_letters(&self) -> Result<Vec<DeadLetter>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, meeting_id, start_ms, end_ms, file_path, attempts, error, updated_at
+             FROM queue WHERE status='dead' ORDER BY id",
+        )?;
+        let rows = stmt.query_map([], |r| {
+            Ok(DeadLetter {
+                id: r.get(0)?,
+                meeting_id: r.get(1)?,
+                start_ms: r.get::<_, i64>(2)? as u64,
+                end_ms: r.get::<_, i64>(3)? as u64,
+                file_path: r.get(4)?,
+                attempts: r.get(5)?,
+                error: r.get(6)?,
+                updated_at: r.get(7)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Give dead-lettered jobs (optionally of one meeting) a fresh set of attempts
+    pub fn requeue_dead_letters(&self, meeting_id: Option<&str>) -> Result<usize> {
+        let conn = self.open()?;
+        Ok(conn.execute(
+            "UPDATE queue SET status='queued', attempts=0, next_attempt_at=0, updated_at=?1
+             WHERE status='dead' AND (?2 IS NULL OR meeting_id=?2)",
+            params![Self::now_ms(), meeting_id],
+        )?)
     }
 
     pub fn counts(&self) -> Result<(i64, i64, i64)> {
         let conn = self.open()?;
         let queued: i64 = conn.query_row("SELECT COUNT(*) FROM queue WHERE status='queued'", [], |r| r.get(0))?;
         let processing: i64 = conn.query_row("SELECT COUNT(*) FROM queue WHERE status='processing'", [], |r| r.get(0))?;
-        let failed: i64 = conn.query_row("SELECT COUNT(*) FROM queue WHERE status!='queued' AND status!='processing' AND status!='done'", [], |r| r.get(0)).unwrap_or(0);
+        let failed: i64 = conn.query_row("SELECT COUNT(*) FROM queue WHERE status!='queued' AND status!='processing' AND status!='done' AND status!='cancelled'", [], |r| r.get(0)).unwrap_or(0);
         Ok((queued, processing, failed))
     }
 
-    pub fn backlog_seconds(&self) -> Result<f32> {
+    /// Seconds of audio queued or in progress, in total
+    pub fn total_backlog_seconds(&self) -> Result<f32> {
         let conn = self.open()?;
         let total_ms: i64 = conn.query_row(
             "SELECT COALESCE(SUM(end_ms - start_ms), 0) FROM queue WHERE status IN ('queued','processing')",
@@ -132,6 +404,19 @@ impl Queue {
         Ok(total_ms as f32 / 1000.0)
     }
 
+    /// Seconds of audio queued or in progress for one lane
+    pub fn backlog_seconds(&self, lane: WorkLane) -> Result<f32> {
+        let (min_priority, max_priority) = lane.priorities();
+        let conn = self.open()?;
+        let total_ms: i64 = conn.query_row(
+            "SELECT COALESCE(SUM(end_ms - start_ms), 0) FROM queue
+             WHERE status IN ('queued','processing') AND priority BETWEEN ?1 AND ?2",
+            params![min_priority as i64, max_priority as i64],
+            |r| r.get(0),
+        )?;
+        Ok(total_ms as f32 / 1000.0)
+    }
+
     pub fn counts_for_meeting(&self, meeting_id: &str) -> Result<(i64, i64)> {
         let conn = self.open()?;
         let queued: i64 = conn.query_row(
@@ -146,4 +431,204 @@ impl Queue {
         )?;
         Ok((queued, processing))
     }
+
+    /// Point unfinished jobs at their chunk files' new location after the storage root
+    /// moved. Returns how many jobs were updated.
+    pub fn relocate_files(&self, relocate: impl Fn(&str) -> Option<String>) -> Result<usize> {
+        let mut conn = self.open()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let jobs: Vec<(i64, String)> = {
+            let mut stmt = tx.prepare(
+                "SELECT id, file_path FROM queue WHERE status IN ('queued','processing','dead')",
+            )?;
+            let rows = stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+        let mut updated = 0;
+        for (id, file_path) in jobs {
+            if let Some(new_path) = relocate(&file_path) {
+                tx.execute(
+                    "UPDATE queue SET file_path=?, updated_at=? WHERE id=?",
+                    params![new_path, Self::now_ms(), id],
+                )?;
+                updated += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_fetch_next_orders_by_priority_and_meeting_fifo() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = Queue::open_at(temp_dir.path().join("queue.sqlite")).unwrap();
+        let retranscribe = queue
+            .enqueue_with_priority("old", 0, 1000, "r1.wav", JobPriority::Retranscribe)
+            .unwrap();
+        let import_1 = queue
+            .enqueue_with_priority("import", 0, 1000, "i1.wav", JobPriority::Import)
+            .unwrap();
+        let import_2 = queue
+            .enqueue_with_priority("import", 1000, 2000, "i2.wav", JobPriority::Import)
+            .unwrap();
+        let live_1 = queue.enqueue("live", 0, 1000, "l1.wav").unwrap();
+        let live_2 = queue.enqueue("live", 1000, 2000, "l2.wav").unwrap();
+
+        // Each lane only sees its own jobs; a meeting's second chunk waits for the first
+        let first = queue.fetch_next(WorkLane::Live).unwrap().unwrap();
+        assert_eq!((first.id, first.priority), (live_1, JobPriority::Live));
+        assert!(queue.fetch_next(WorkLane::Live).unwrap().is_none());
+        let second = queue.fetch_next(WorkLane::Background).unwrap().unwrap();
+        assert_eq!(second.id, import_1);
+        let third = queue.fetch_next(WorkLane::Background).unwrap().unwrap();
+        assert_eq!(third.id, retranscribe);
+        assert!(queue.fetch_next(WorkLane::Background).unwrap().is_none());
+
+        queue.mark_done(live_1).unwrap();
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, live_2);
+        assert_eq!(queue.backlog_seconds(WorkLane::Live).unwrap(), 1.0);
+        queue.mark_done(import_1).unwrap();
+        assert_eq!(
+            queue.fetch_next(WorkLane::Background).unwrap().unwrap().id,
+            import_2
+        );
+    }
+
+    #[test]
+    fn test_failed_jobs_back_off_then_dead_letter() {
+        assert_eq!(backoff_ms(1), 2_000);
+        assert_eq!(backoff_ms(3), 8_000);
+        assert_eq!(backoff_ms(30), BACKOFF_MAX_MS);
+
+        let temp_dir = TempDir::new().unwrap();
+        let queue = Queue::open_at(temp_dir.path().join("queue.sqlite")).unwrap();
+        let id = queue.enqueue("m", 0, 1000, "a.wav").unwrap();
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, id);
+        assert!(!queue.mark_failed(id, "model not loaded").unwrap());
+        // Waiting out the backoff
+        assert!(queue.fetch_next(WorkLane::Live).unwrap().is_none());
+
+        // Each retry is claimed again once its backoff has passed
+        let retry = || {
+            queue
+                .open()
+                .unwrap()
+                .execute("UPDATE queue SET next_attempt_at=0", [])
+                .unwrap();
+            assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, id);
+        };
+        for _ in 1..MAX_ATTEMPTS - 1 {
+            retry();
+            assert!(!queue.mark_failed(id, "model not loaded").unwrap());
+        }
+        retry();
+        assert!(queue.mark_failed(id, "model not loaded").unwrap());
+        let dead = queue.list_dead_letters().unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, MAX_ATTEMPTS);
+
+        assert_eq!(queue.requeue_dead_letters(Some("other")).unwrap(), 0);
+        assert_eq!(queue.requeue_dead_letters(None).unwrap(), 1);
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, id);
+    }
+
+    #[test]
+    fn test_mark_failed_ignores_jobs_not_in_flight() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = Queue::open_at(temp_dir.path().join("queue.sqlite")).unwrap();
+        let id = queue.enqueue("m", 0, 1000, "a.wav").unwrap();
+        // Still queued: nothing to fail, and no attempt is counted
+        assert!(!queue.mark_failed(id, "model not loaded").unwrap());
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, id);
+
+        // Cancelled while in flight
+        assert_eq!(queue.cancel_meeting("m").unwrap(), 1);
+        assert!(!queue.mark_failed(id, "model not loaded").unwrap());
+        let attempts: i64 = queue
+            .open()
+            .unwrap()
+            .query_row("SELECT attempts FROM queue WHERE id=?", params![id], |r| r.get(0))
+            .unwrap();
+        assert_eq!(attempts, 0);
+        assert!(queue.list_dead_letters().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_recover_requeues_interrupted_jobs() {
+        let temp_dir = TempDir::new().unwrap();
+        let segments = temp_dir.path().join("project/audio_segments");
+        std::fs::create_dir_all(&segments).unwrap();
+        let kept = segments.join("segment_000000_0-1000.wav");
+        std::fs::write(&kept, "RIFF").unwrap();
+        let kept = kept.to_string_lossy().to_string();
+        let gone = segments.join("segment_000001_1000-2000.wav");
+
+        let path = temp_dir.path().join("queue.sqlite");
+        let queue = Queue::open_at(path.clone()).unwrap();
+        let interrupted = queue.enqueue("m", 0, 1000, &kept).unwrap();
+        queue.enqueue("m", 1000, 2000, &gone.to_string_lossy()).unwrap();
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, interrupted);
+
+        // Simulated restart
+        let queue = Queue::open_at(path).unwrap();
+        let report = queue.recover().unwrap();
+        assert_eq!((report.requeued, report.missing_files), (1, 1));
+        assert_eq!(report.meetings, vec!["m"]);
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, interrupted);
+        assert_eq!(queue.list_dead_letters().unwrap().len(), 1);
+
+        assert_eq!(
+            recovery_project_path(&kept),
+            Some(temp_dir.path().join("project").to_string_lossy().to_string())
+        );
+        assert_eq!(recovery_project_path("audio_segments/segment.wav"), None);
+    }
+
+    #[test]
+    fn test_cancel_meeting_drops_queued_and_in_flight_jobs() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = Queue::open_at(temp_dir.path().join("queue.sqlite")).unwrap();
+        let in_flight = queue.enqueue("m", 0, 1000, "a.wav").unwrap();
+        queue.enqueue("m", 1000, 2000, "b.wav").unwrap();
+        let other = queue.enqueue("other", 0, 1000, "c.wav").unwrap();
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, in_flight);
+
+        assert_eq!(queue.cancel_meeting("m").unwrap(), 2);
+        assert!(queue.is_cancelled(in_flight).unwrap());
+        assert!(!queue.is_cancelled(other).unwrap());
+        // The worker's result for the cancelled job is ignored
+        assert!(!queue.mark_failed(in_flight, "aborted").unwrap());
+        queue.mark_done(in_flight).unwrap();
+        assert!(queue.is_cancelled(in_flight).unwrap());
+        assert_eq!(queue.counts_for_meeting("m").unwrap(), (0, 0));
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, other);
+    }
+
+    #[test]
+    fn test_relocate_files_rewrites_unfinished_jobs() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = Queue::open_at(temp_dir.path().join("queue.sqlite")).unwrap();
+        let done = queue.enqueue("m", 0, 1000, "/old/a.wav").unwrap();
+        queue.enqueue("m", 1000, 2000, "/old/b.wav").unwrap();
+        queue.enqueue("other", 0, 1000, "/elsewhere/c.wav").unwrap();
+        assert_eq!(queue.fetch_next(WorkLane::Live).unwrap().unwrap().id, done);
+        queue.mark_done(done).unwrap();
+
+        let relocate = |p: &str| p.strip_prefix("/old/").map(|rest| format!("/new/{}", rest));
+        assert_eq!(queue.relocate_files(relocate).unwrap(), 1);
+        assert_eq!(
+            queue.fetch_next(WorkLane::Live).unwrap().unwrap().file_path,
+            "/new/b.wav"
+        );
+        assert_eq!(
+            queue.fetch_next(WorkLane::Live).unwrap().unwrap().file_path,
+            "/elsewhere/c.wav"
+        );
+    }
 }
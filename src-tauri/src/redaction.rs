@@ -0,0 +1,202 @@
+//! PII redaction for text that leaves the machine. With `redact_pii` on, emails, phone
+//! numbers, payment card numbers, and the user's own patterns are replaced with
+//! placeholders in LLM prompts, GitHub comments, and PR bodies. Saved transcripts keep
+//! the original text.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::managers::meeting::TranscriptSegment;
+use crate::settings::AppSettings;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CUSTOM: Lazy<RwLock<Vec<Regex>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+static EMAIL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}\b").expect("valid email pattern")
+});
+/// 13-19 digits, optionally grouped by spaces or dashes; confirmed with the Luhn check
+static CARD: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d(?:[ -]?\d){12,18}\b").expect("valid card pattern"));
+/// Optional country code, then groups of digits with common separators. The digits
+/// must stand alone, so runs inside hashes and identifiers are left alone.
+static PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{2,4}\)[ .-]?)?\b\d{2,4}(?:[ .-]?\d{2,4}){1,3}\b")
+        .expect("valid phone pattern")
+});
+/// Dates (2024-05-12, 12.05.2024, 2024/05/12) look like phone numbers to `PHONE`
+static DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:\d{4}[-./]\d{1,2}[-./]\d{1,2}|\d{1,2}[-./]\d{1,2}[-./]\d{4})$")
+        .expect("valid date pattern")
+});
+/// Links keep their digits: ids in paths and queries aren't phone or card numbers
+static URL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:https?://|www\.)\S+").expect("valid URL pattern"));
+/// Fewest digits a phone number has, so years and small counts are left alone
+const MIN_PHONE_DIGITS: usize = 7;
+
+pub const EMAIL_PLACEHOLDER: &str = "[EMAIL]";
+pub const PHONE_PLACEHOLDER: &str = "[PHONE]";
+pub const CARD_PLACEHOLDER: &str = "[CARD]";
+pub const CUSTOM_PLACEHOLDER: &str = "[REDACTED]";
+
+/// Pick up `redact_pii` and the custom patterns; invalid patterns are skipped
+pub fn apply_settings(settings: &AppSettings) {
+    ENABLED.store(settings.redact_pii, Ordering::Relaxed);
+    *CUSTOM.write().unwrap() = settings
+        .redaction_patterns
+        .iter()
+        .filter_map(|p| match Regex::new(p) {
+            Ok(regex) => Some(regex),
+            Err(e) => {
+                log::warn!("Ignoring invalid redaction pattern {:?}: {}", p, e);
+                None
+            }
+        })
+        .collect();
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Trim the custom patterns and drop empty ones, rejecting any that don't compile
+pub fn normalize_patterns(patterns: &mut Vec<String>) -> Result<(), String> {
+    patterns.iter_mut().for_each(|p| *p = p.trim().to_string());
+    patterns.retain(|p| !p.is_empty());
+    for pattern in patterns.iter() {
+        Regex::new(pattern).map_err(|e| format!("Invalid pattern {:?}: {}", pattern, e))?;
+    }
+    Ok(())
+}
+
+fn luhn_valid(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Card and phone numbers in `text` replaced by placeholders
+fn redact_numbers(text: &str) -> String {
+    let text = CARD.replace_all(text, |caps: &regex::Captures| {
+        let digits: Vec<u32> = caps[0].chars().filter_map(|c| c.to_digit(10)).collect();
+        if luhn_valid(&digits) {
+            CARD_PLACEHOLDER.to_string()
+        } else {
+            caps[0].to_string()
+        }
+    });
+    let text = PHONE.replace_all(&text, |caps: &regex::Captures| {
+        let digits = caps[0].chars().filter(char::is_ascii_digit).count();
+        if digits >= MIN_PHONE_DIGITS && !DATE.is_match(&caps[0]) {
+            PHONE_PLACEHOLDER.to_string()
+        } else {
+            caps[0].to_string()
+        }
+    });
+    text.into_owned()
+}
+
+/// `text` with PII replaced by placeholders, whether or not redaction is turned on
+pub fn redact(text: &str) -> String {
+    let mut numbers_redacted = String::with_capacity(text.len());
+    let mut last = 0;
+    for url in URL.find_iter(text) {
+        numbers_redacted.push_str(&redact_numbers(&text[last..url.start()]));
+        numbers_redacted.push_str(url.as_str());
+        last = url.end();
+    }
+    numbers_redacted.push_str(&redact_numbers(&text[last..]));
+    let mut text = EMAIL
+        .replace_all(&numbers_redacted, EMAIL_PLACEHOLDER)
+        .into_owned();
+    for pattern in CUSTOM.read().unwrap().iter() {
+        if let Cow::Owned(replaced) = pattern.replace_all(&text, CUSTOM_PLACEHOLDER) {
+            text = replaced;
+        }
+    }
+    text
+}
+
+/// `text` as it may be sent off the machine: redacted when `redact_pii` is on
+pub fn outgoing(text: &str) -> Cow<'_, str> {
+    if is_enabled() {
+        Cow::Owned(redact(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Redacted copy of a transcript, for previews and exports that leave the machine
+pub fn redact_segments(segments: &[TranscriptSegment]) -> Vec<TranscriptSegment> {
+    segments
+        .iter()
+        .map(|s| TranscriptSegment {
+            text: redact(&s.text),
+            translated_text: s.translated_text.as_deref().map(redact),
+            // Word timings would still carry the original words
+            words: Vec::new(),
+            ..s.clone()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_builtin_pii() {
+        let text = "Mail jane.doe@example.com or call +1 (415) 555-0132, card 4111 1111 1111 1111.";
+        assert_eq!(redact(text), "Mail [EMAIL] or call [PHONE], card [CARD].");
+        // Short numbers and dates stay
+        assert_eq!(
+            redact("We shipped 42 fixes on 2024-05-12"),
+            "We shipped 42 fixes on 2024-05-12"
+        );
+        assert_eq!(redact("Or try 555.123.4567"), "Or try [PHONE]");
+        assert_eq!(redact("London: +44 20 7946 0958"), "London: [PHONE]");
+    }
+
+    #[test]
+    fn test_leaves_urls_hashes_and_dates() {
+        for text in [
+            "See https://github.com/acme/app/pull/12345678 and www.example.com/j/5551234567",
+            "Fixed in 3f2a9c81234567d, reverted by 1234567abcdef",
+            "Due 12.05.2024, moved to 2024.05.19, then 2024/05/26 and 31-05-2024",
+        ] {
+            assert_eq!(redact(text), text);
+        }
+        // Text around a link is still redacted
+        assert_eq!(
+            redact("Call 415 555 0132 about https://example.com/t/4155550132"),
+            "Call [PHONE] about https://example.com/t/4155550132"
+        );
+    }
+
+    #[test]
+    fn test_normalize_patterns() {
+        let mut patterns = vec![" ACME-\\d+ ".to_string(), String::new()];
+        normalize_patterns(&mut patterns).unwrap();
+        assert_eq!(patterns, vec!["ACME-\\d+"]);
+        assert!(normalize_patterns(&mut vec!["(".to_string()]).is_err());
+    }
+}
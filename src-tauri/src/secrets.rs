@@ -0,0 +1,90 @@
+//! Credentials for integrations and providers. Secrets go in the system keyring; when
+//! it can't be used (unsigned development builds, Linux without a secret service) they
+//! fall back to a plaintext file in `~/.handy`, readable only by the user, and a warning
+//! is logged so the downgrade never happens silently.
+
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn fallback_path(file_name: &str) -> Result<PathBuf> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map_err(|_| anyhow!("Could not determine home directory"))?;
+    let config_dir = Path::new(&home).join(".handy");
+    fs::create_dir_all(&config_dir)?;
+    Ok(config_dir.join(file_name))
+}
+
+/// Write a fallback file, restricting it to the current user where the platform allows
+fn write_fallback(path: &Path, value: &str) -> Result<()> {
+    fs::write(path, value)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+/// Store `value` in the keyring under `service`/`account`, or in `~/.handy/<fallback_file>`
+/// when the keyring is unavailable
+pub fn store(service: &str, account: &str, fallback_file: &str, value: &str) -> Result<()> {
+    let keyring_result =
+        keyring::Entry::new(service, account).and_then(|entry| entry.set_password(value));
+    let Err(e) = keyring_result else {
+        return Ok(());
+    };
+    let path = fallback_path(fallback_file)?;
+    log::warn!(
+        "Keyring unavailable for {} ({}): storing the secret in plaintext at {}",
+        service,
+        e,
+        path.display()
+    );
+    write_fallback(&path, value)
+}
+
+/// Read a secret from the keyring, then from its fallback file
+pub fn load(service: &str, account: &str, fallback_file: &str) -> Result<String> {
+    if let Ok(value) = keyring::Entry::new(service, account).and_then(|entry| entry.get_password())
+    {
+        return Ok(value);
+    }
+    let path = fallback_path(fallback_file)?;
+    let value = fs::read_to_string(&path)
+        .map_err(|_| anyhow!("No secret stored for {}/{}", service, account))?;
+    log::debug!(
+        "Read {} secret from plaintext fallback {}",
+        service,
+        path.display()
+    );
+    Ok(value)
+}
+
+/// Remove a secret from the keyring and its fallback file
+pub fn delete(service: &str, account: &str, fallback_file: &str) {
+    if let Ok(entry) = keyring::Entry::new(service, account) {
+        let _ = entry.delete_credential();
+    }
+    if let Ok(path) = fallback_path(fallback_file) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_fallback_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".token");
+        write_fallback(&path, "secret").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "secret");
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
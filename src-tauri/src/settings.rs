@@ -12,6 +12,404 @@ pub struct ShortcutBinding {
     pub current_binding: String,
 }
 
+/// User-defined `.claudeignore` patterns merged into the generated file
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClaudeignoreFragment {
+    pub name: String,
+    /// Frameworks this fragment applies to (as detected, e.g. "Next.js"); empty means all
+    #[serde(default)]
+    pub frameworks: Vec<String>,
+    pub patterns: Vec<String>,
+}
+
+/// Remote destination for finalized meeting artifacts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CloudSyncConfig {
+    pub enabled: bool,
+    /// "s3" (any S3-compatible store) or "webdav"
+    pub provider: String,
+    /// e.g. "https://s3.us-east-1.amazonaws.com" or a WebDAV collection URL
+    pub endpoint: String,
+    /// S3 only
+    pub bucket: String,
+    /// S3 only
+    pub region: String,
+    /// Remote path prefix under which meetings are stored
+    pub prefix: String,
+    pub include_audio: bool,
+}
+
+impl Default for CloudSyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: "s3".to_string(),
+            endpoint: String::new(),
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            prefix: "meetingcoder".to_string(),
+            include_audio: false,
+        }
+    }
+}
+
+/// Localhost HTTP/WebSocket API for scripts and launchers; requests need the API token
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct LocalApiConfig {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for LocalApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 47615,
+        }
+    }
+}
+
+/// Age limits after which saved meeting data is cleaned up
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    pub enabled: bool,
+    /// Delete recorded audio after this many days (None keeps it)
+    pub audio_days: Option<u32>,
+    /// Delete the whole meeting (transcript, summary, database rows) after this many days
+    pub transcript_days: Option<u32>,
+    /// Starred meetings are never cleaned up
+    pub keep_starred: bool,
+    /// Deleted meetings stay restorable in the trash for this many days
+    pub trash_days: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            audio_days: Some(30),
+            transcript_days: Some(365),
+            keep_starred: true,
+            trash_days: 30,
+        }
+    }
+}
+
+/// Desktop notifications for meeting milestones, each of which can be turned off
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct NotificationSettings {
+    pub enabled: bool,
+    pub summary_ready: bool,
+    pub pr_created: bool,
+    pub audio_stream_failed: bool,
+    pub prd_generated: bool,
+    pub import_finished: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            summary_ready: true,
+            pr_created: true,
+            audio_stream_failed: true,
+            prd_generated: true,
+            import_finished: true,
+        }
+    }
+}
+
+/// Posting meeting summaries to Slack; the bot token or webhook lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SlackConfig {
+    /// Post the summary when a meeting ends
+    pub auto_post: bool,
+    /// Channel name or ID for bot tokens; webhooks always post to their own channel
+    pub channel: String,
+}
+
+/// Posting meeting summaries to a Teams channel; the webhook URL lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TeamsConfig {
+    /// Post the summary when a meeting ends
+    pub auto_post: bool,
+}
+
+/// Which events are posted to Discord; webhooks live in the keychain, one per project
+/// plus a default. Repos can override these in `.meetingcoder.toml`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DiscordConfig {
+    pub meeting_ended: bool,
+    pub prd_versions: bool,
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        Self {
+            meeting_ended: true,
+            prd_versions: true,
+        }
+    }
+}
+
+/// Emailing meeting summaries to participants; the SMTP login lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EmailConfig {
+    /// Email the summary when a meeting ends
+    pub auto_send: bool,
+    /// "smtp" sends directly; "system" opens a draft in the default mail app
+    pub transport: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    /// "starttls", "tls", or "none"
+    pub security: String,
+    pub from_address: String,
+    /// Speaker label to email address
+    pub recipients: HashMap<String, String>,
+    /// Addresses copied on every summary
+    pub always_cc: Vec<String>,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            auto_send: false,
+            transport: "smtp".to_string(),
+            smtp_host: String::new(),
+            smtp_port: 587,
+            security: "starttls".to_string(),
+            from_address: String::new(),
+            recipients: HashMap::new(),
+            always_cc: Vec::new(),
+        }
+    }
+}
+
+/// Writing meetings as notes into an Obsidian vault
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ObsidianConfig {
+    /// Root folder of the vault
+    pub vault_path: Option<String>,
+    /// Folder inside the vault that notes are written to
+    pub folder: String,
+    /// Export the note when a meeting ends
+    pub auto_export: bool,
+}
+
+impl Default for ObsidianConfig {
+    fn default() -> Self {
+        Self {
+            vault_path: None,
+            folder: "Meetings".to_string(),
+            auto_export: false,
+        }
+    }
+}
+
+/// Creating Asana tasks from action items; the access token lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct AsanaConfig {
+    /// Sync action items when a meeting ends
+    pub auto_sync: bool,
+    /// Project the tasks are added to
+    pub project_gid: String,
+    /// Speaker label to assignee (Asana email or user gid)
+    pub owners: HashMap<String, String>,
+    /// Days after the meeting tasks are due; None leaves the due date empty
+    pub due_in_days: Option<u32>,
+}
+
+impl Default for AsanaConfig {
+    fn default() -> Self {
+        Self {
+            auto_sync: false,
+            project_gid: String::new(),
+            owners: HashMap::new(),
+            due_in_days: Some(7),
+        }
+    }
+}
+
+/// Trello cards from action items and PRD user stories; the API key and token live in
+/// the keychain
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TrelloConfig {
+    /// Push action items when a meeting ends
+    pub auto_push: bool,
+    pub board_id: String,
+    /// List on the board that action item cards go to
+    pub action_items_list_id: String,
+    /// List on the board that user story cards go to
+    pub user_stories_list_id: String,
+}
+
+/// Speaker diarization from voice embeddings; off uses pause-based speaker alternation
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DiarizationConfig {
+    pub enabled: bool,
+    /// Cosine similarity (0–1) a voice needs to count as an already-heard speaker
+    pub similarity_threshold: f32,
+    /// Voices beyond this join the most similar speaker
+    pub max_speakers: usize,
+}
+
+impl Default for DiarizationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            similarity_threshold: 0.5,
+            max_speakers: 8,
+        }
+    }
+}
+
+/// Jira lookups for ticket keys mentioned in meetings; the API token lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct JiraConfig {
+    pub enabled: bool,
+    /// Site URL, e.g. https://acme.atlassian.net
+    pub base_url: String,
+    /// Account email for Jira Cloud; empty uses the token as a Server/Data Center PAT
+    pub email: String,
+    /// Project keys to match, also when transcribed loosely ("proj 123"); empty matches
+    /// any `ABC-123`
+    pub project_keys: Vec<String>,
+}
+
+/// Upcoming meetings from a calendar feed; the feed URL lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CalendarConfig {
+    /// Start a meeting when an event with a video-call link begins
+    pub auto_start: bool,
+    /// How far ahead upcoming meetings are listed
+    pub lookahead_hours: u32,
+}
+
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        Self {
+            auto_start: false,
+            lookahead_hours: 24,
+        }
+    }
+}
+
+/// What happens to segments the engine wasn't sure about
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LowConfidenceAction {
+    /// Keep them, marked in the transcript and left out of summaries
+    #[default]
+    Flag,
+    /// Leave them out of the transcript
+    Drop,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct LowConfidenceConfig {
+    /// Segments below this confidence (0..1) are low-confidence; 0 turns this off
+    pub threshold: f32,
+    pub action: LowConfidenceAction,
+}
+
+/// What translates meeting segments
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationEngine {
+    /// The configured LLM provider, for any target language
+    #[default]
+    Llm,
+    /// Whisper's translate task on live audio; English targets only, else the LLM
+    Whisper,
+}
+
+/// Translation of meeting transcripts next to the original text
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct MeetingTranslationConfig {
+    /// Language code new meetings are translated into (e.g. "en", "de"); None for none
+    pub language: Option<String>,
+    pub engine: TranslationEngine,
+}
+
+/// Hosted speech-to-text service used instead of the local model
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CloudAsrProvider {
+    #[default]
+    Deepgram,
+    AssemblyAi,
+}
+
+/// Cloud transcription for meetings; the local model takes over offline or on errors
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CloudAsrConfig {
+    pub provider: CloudAsrProvider,
+    /// New meetings start with cloud transcription on
+    pub use_for_new_meetings: bool,
+    /// Provider model name (e.g. "nova-2"); empty for the provider's default
+    pub model: String,
+}
+
+/// A folder whose new recordings are imported as offline meetings
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct WatchFolderConfig {
+    pub enabled: bool,
+    /// Absolute path of the folder, e.g. where Zoom saves local recordings
+    pub folder: String,
+}
+
+/// Events that can be sent to outgoing webhooks
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEvent {
+    #[serde(rename = "meeting.started")]
+    MeetingStarted,
+    #[serde(rename = "meeting.ended")]
+    MeetingEnded,
+    #[serde(rename = "summary.ready")]
+    SummaryReady,
+    #[serde(rename = "prd.version")]
+    PrdVersion,
+    #[serde(rename = "pr.created")]
+    PrCreated,
+}
+
+/// A URL that receives signed JSON for the events it subscribes to
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct WebhookEndpoint {
+    /// Assigned when the endpoint is saved
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+/// Outgoing webhooks for automation tools; the signing secret lives in the keychain
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct WebhooksConfig {
+    pub endpoints: Vec<WebhookEndpoint>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum OverlayPosition {
@@ -20,6 +418,86 @@ pub enum OverlayPosition {
     Bottom,
 }
 
+/// Lowest level written to the log files
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+/// How git clones and pushes authenticate to GitHub
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GitAuthMethod {
+    /// The stored GitHub token, handed to libgit2 and never written into a URL
+    #[default]
+    Token,
+    /// An SSH key from the agent, `git_ssh_key_path`, or `~/.ssh`
+    Ssh,
+    /// Git's configured credential helper (osxkeychain, manager, libsecret…)
+    CredentialHelper,
+}
+
+/// Which API summaries and PRDs are generated with
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LlmProviderKind {
+    #[default]
+    Anthropic,
+    /// OpenAI or any server speaking its chat completions API (Gemini, LM Studio, vLLM…)
+    OpenAi,
+    /// A local Ollama server
+    Ollama,
+}
+
+/// Where the overlay goes on one display configuration
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct OverlayPlacement {
+    /// Monitor name; `None` follows the cursor
+    pub monitor: Option<String>,
+    /// Logical pixels added to the top/bottom position
+    pub offset_x: i32,
+    pub offset_y: i32,
+}
+
+/// What the recording overlay shows while visible
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayContent {
+    /// Spectrum level bars
+    Levels,
+    /// Rolling amplitude waveform
+    Waveform,
+    /// Latest transcribed text
+    Captions,
+    /// Compact status and elapsed time
+    StatusPill,
+}
+
+impl Default for OverlayContent {
+    fn default() -> Self {
+        OverlayContent::Levels
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelUnloadTimeout {
@@ -47,6 +525,25 @@ pub enum ClipboardHandling {
     CopyToClipboard,
 }
 
+/// How queue workers share a GPU-backed (Metal/CUDA) model
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GpuScheduling {
+    /// Run inference one job at a time across all model instances; decoding, silence
+    /// detection, and transcript writes still run in parallel
+    Serialized,
+    /// Limit each worker pool to a single worker
+    SingleWorker,
+    /// No restrictions (large-VRAM machines)
+    Unrestricted,
+}
+
+impl Default for GpuScheduling {
+    fn default() -> Self {
+        GpuScheduling::Serialized
+    }
+}
+
 impl Default for ModelUnloadTimeout {
     fn default() -> Self {
         ModelUnloadTimeout::Never
@@ -143,10 +640,35 @@ pub struct AppSettings {
     pub selected_output_device: Option<String>,
     #[serde(default = "default_translate_to_english")]
     pub translate_to_english: bool,
+    /// Prompt Whisper with the custom words and the end of the previous chunk's text, for
+    /// consistent casing and spelling across chunks
+    #[serde(default = "default_whisper_prompt_context")]
+    pub whisper_prompt_context: bool,
+    /// Whisper beam search width; 1 decodes greedily, which is fastest
+    #[serde(default = "default_whisper_beam_size")]
+    pub whisper_beam_size: u32,
+    /// Whisper's first decoding temperature
+    #[serde(default = "default_whisper_temperature")]
+    pub whisper_temperature: f32,
+    /// Added to the temperature for each retry of a failed decode; 0 disables retries
+    #[serde(default = "default_whisper_temperature_increment")]
+    pub whisper_temperature_increment: f32,
+    /// Probability of "no speech" above which Whisper returns nothing for a window
+    #[serde(default = "default_whisper_no_speech_threshold")]
+    pub whisper_no_speech_threshold: f32,
+    /// Let Whisper use the text of earlier 30 s windows within one chunk
+    #[serde(default = "default_whisper_condition_on_previous_text")]
+    pub whisper_condition_on_previous_text: bool,
     #[serde(default = "default_selected_language")]
     pub selected_language: String,
     #[serde(default = "default_overlay_position")]
     pub overlay_position: OverlayPosition,
+    #[serde(default)]
+    pub overlay_content: OverlayContent,
+    /// Keyed by display configuration (see `overlay::display_configuration`) so
+    /// docking and undocking each keep their own placement
+    #[serde(default)]
+    pub overlay_placements: HashMap<String, OverlayPlacement>,
     #[serde(default = "default_debug_mode")]
     pub debug_mode: bool,
     #[serde(default)]
@@ -163,12 +685,50 @@ pub struct AppSettings {
     pub clipboard_handling: ClipboardHandling,
     #[serde(default)]
     pub mute_while_recording: bool,
+    /// Turn on the OS Do Not Disturb mode while a meeting records
+    #[serde(default)]
+    pub focus_mode_while_recording: bool,
+    /// Refuse all outbound network requests (LLM, GitHub, model downloads, cloud sync,
+    /// update checks)
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// HTTP(S) proxy for all outbound requests; `None` uses `HTTPS_PROXY` or the system
+    /// proxy
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+    /// PEM bundle of extra root certificates to trust (e.g. a corporate TLS proxy's CA)
+    #[serde(default)]
+    pub ca_bundle_path: Option<String>,
+    /// Replace emails, phone numbers, card numbers, and `redaction_patterns` matches with
+    /// placeholders in LLM prompts and GitHub comments, issues, and PR bodies
+    #[serde(default)]
+    pub redact_pii: bool,
+    /// Extra regular expressions to redact (e.g. customer names, internal hostnames)
+    #[serde(default)]
+    pub redaction_patterns: Vec<String>,
+    /// Opt-in to sending aggregate local metrics to `metrics_share_url`
+    #[serde(default)]
+    pub metrics_sharing_enabled: bool,
+    #[serde(default)]
+    pub metrics_share_url: Option<String>,
+    #[serde(default)]
+    pub log_level: LogLevel,
     #[serde(default = "default_transcription_chunk_seconds")]
     pub transcription_chunk_seconds: u32,
+    /// Transcribe the chunk in progress every `streaming_interval_ms` and send the
+    /// interim text as `transcript-partial` events, for live captions
+    #[serde(default)]
+    pub streaming_transcription: bool,
+    #[serde(default = "default_streaming_interval_ms")]
+    pub streaming_interval_ms: u32,
     #[serde(default = "default_system_audio_buffer_seconds")]
     pub system_audio_buffer_seconds: u32,
     #[serde(default = "default_system_audio_silence_threshold")]
     pub system_audio_silence_threshold: f32, // dBFS
+    /// Run DTLN noise suppression over microphone and system audio before VAD, for keyboard
+    /// and fan noise. Its models are downloaded the first time this is turned on.
+    #[serde(default = "default_noise_suppression_enabled")]
+    pub noise_suppression_enabled: bool,
     #[serde(default = "default_meeting_update_interval_seconds")]
     pub meeting_update_interval_seconds: u32,
     #[serde(default = "default_auto_trigger_meeting_command")]
@@ -187,6 +747,11 @@ pub struct AppSettings {
     pub github_branch_pattern: String,
     #[serde(default = "default_github_enabled")]
     pub github_enabled: bool,
+    #[serde(default)]
+    pub git_auth_method: GitAuthMethod,
+    /// Private key for `GitAuthMethod::Ssh`; `None` tries the agent and `~/.ssh/id_*`
+    #[serde(default)]
+    pub git_ssh_key_path: Option<String>,
     #[serde(default = "default_github_auto_commit_push")]
     pub github_auto_commit_push: bool,
     #[serde(default = "default_github_auto_create_pr")]
@@ -207,10 +772,90 @@ pub struct AppSettings {
     pub use_llm_summarization: bool,
     #[serde(default = "default_llm_model")]
     pub llm_model: String,
+    #[serde(default)]
+    pub llm_provider: LlmProviderKind,
+    /// Endpoint of the LLM provider; `None` uses the provider's default
+    #[serde(default)]
+    pub llm_base_url: Option<String>,
     #[serde(default = "default_use_queue_transcription")]
     pub use_queue_transcription: bool,
     #[serde(default = "default_queue_worker_count")]
     pub queue_worker_count: u32,
+    /// Workers for import and re-transcription jobs, separate from live-meeting workers
+    #[serde(default = "default_import_worker_count")]
+    pub import_worker_count: u32,
+    /// Load a second model instance for imports so they never swap or block the live model
+    #[serde(default = "default_separate_import_model")]
+    pub separate_import_model: bool,
+    #[serde(default)]
+    pub gpu_scheduling: GpuScheduling,
+    /// Show the meeting name and elapsed time as the tray tooltip
+    #[serde(default = "default_tray_meeting_tooltip")]
+    pub tray_meeting_tooltip: bool,
+    /// Labels the bookmark shortcut cycles through on repeated presses
+    #[serde(default = "default_marker_label_presets")]
+    pub marker_label_presets: Vec<String>,
+    #[serde(default = "default_agent_max_files_touched")]
+    pub agent_max_files_touched: u32,
+    #[serde(default = "default_agent_max_loc_changed")]
+    pub agent_max_loc_changed: u32,
+    #[serde(default = "default_agent_allowed_path_globs")]
+    pub agent_allowed_path_globs: Vec<String>,
+    #[serde(default = "default_keyword_triggers_enabled")]
+    pub keyword_triggers_enabled: bool,
+    #[serde(default = "default_agent_trigger_phrases")]
+    pub agent_trigger_phrases: Vec<String>,
+    /// Extra gitignore-style patterns excluded from codebase analysis
+    #[serde(default = "default_codebase_ignore_patterns")]
+    pub codebase_ignore_patterns: Vec<String>,
+    /// Build a local embedding index of the project and include relevant snippets in prompts
+    #[serde(default = "default_codebase_embeddings_enabled")]
+    pub codebase_embeddings_enabled: bool,
+    /// Template fragments merged into each project's `.claudeignore`
+    #[serde(default = "default_claudeignore_fragments")]
+    pub claudeignore_fragments: Vec<ClaudeignoreFragment>,
+    /// Upload finalized meeting artifacts to S3 or WebDAV
+    #[serde(default = "default_cloud_sync")]
+    pub cloud_sync: CloudSyncConfig,
+    /// Root for meetings, projects, repo clones, and exports; None means ~/MeetingCoder
+    #[serde(default = "default_storage_root")]
+    pub storage_root: Option<String>,
+    #[serde(default = "default_retention")]
+    pub retention: RetentionPolicy,
+    #[serde(default = "default_notifications")]
+    pub notifications: NotificationSettings,
+    #[serde(default = "default_local_api")]
+    pub local_api: LocalApiConfig,
+    #[serde(default = "default_slack")]
+    pub slack: SlackConfig,
+    #[serde(default = "default_teams")]
+    pub teams: TeamsConfig,
+    #[serde(default = "default_discord")]
+    pub discord: DiscordConfig,
+    #[serde(default = "default_email")]
+    pub email: EmailConfig,
+    #[serde(default = "default_obsidian")]
+    pub obsidian: ObsidianConfig,
+    #[serde(default = "default_asana")]
+    pub asana: AsanaConfig,
+    #[serde(default = "default_trello")]
+    pub trello: TrelloConfig,
+    #[serde(default = "default_webhooks")]
+    pub webhooks: WebhooksConfig,
+    #[serde(default = "default_jira")]
+    pub jira: JiraConfig,
+    #[serde(default = "default_calendar")]
+    pub calendar: CalendarConfig,
+    #[serde(default = "default_diarization")]
+    pub diarization: DiarizationConfig,
+    #[serde(default = "default_watch_folder")]
+    pub watch_folder: WatchFolderConfig,
+    #[serde(default = "default_low_confidence")]
+    pub low_confidence: LowConfidenceConfig,
+    #[serde(default = "default_meeting_translation")]
+    pub meeting_translation: MeetingTranslationConfig,
+    #[serde(default = "default_cloud_asr")]
+    pub cloud_asr: CloudAsrConfig,
 }
 
 fn default_model() -> String {
@@ -225,6 +870,30 @@ fn default_translate_to_english() -> bool {
     false
 }
 
+fn default_whisper_prompt_context() -> bool {
+    false
+}
+
+fn default_whisper_beam_size() -> u32 {
+    5
+}
+
+fn default_whisper_temperature() -> f32 {
+    0.0
+}
+
+fn default_whisper_temperature_increment() -> f32 {
+    0.2
+}
+
+fn default_whisper_no_speech_threshold() -> f32 {
+    0.6
+}
+
+fn default_whisper_condition_on_previous_text() -> bool {
+    true
+}
+
 fn default_start_hidden() -> bool {
     false
 }
@@ -268,8 +937,16 @@ fn default_transcription_chunk_seconds() -> u32 {
     10
 }
 
+fn default_streaming_interval_ms() -> u32 {
+    1000
+}
+
 fn default_system_audio_silence_threshold() -> f32 { -50.0 }
 
+fn default_noise_suppression_enabled() -> bool {
+    false
+}
+
 // Lower default buffer size to reduce RAM footprint and backlog risk.
 // 90s @ 16kHz mono float32 ≈ 5.8 MB
 fn default_system_audio_buffer_seconds() -> u32 { 90 }
@@ -301,6 +978,51 @@ fn default_use_llm_summarization() -> bool { false }
 fn default_llm_model() -> String { "claude-sonnet-4-5-20250929".to_string() }
 fn default_use_queue_transcription() -> bool { true }
 fn default_queue_worker_count() -> u32 { 2 }
+fn default_import_worker_count() -> u32 { 1 }
+fn default_separate_import_model() -> bool { false }
+fn default_tray_meeting_tooltip() -> bool { true }
+fn default_marker_label_presets() -> Vec<String> {
+    vec!["decision".to_string(), "action item".to_string(), "question".to_string()]
+}
+fn default_agent_max_files_touched() -> u32 { 20 }
+fn default_agent_max_loc_changed() -> u32 { 800 }
+// Mirrors the writable areas left open by the generated .claudeignore
+fn default_agent_allowed_path_globs() -> Vec<String> {
+    vec![
+        "experiments/**".to_string(),
+        ".claude/**".to_string(),
+        "tests/**".to_string(),
+        "test/**".to_string(),
+        "__tests__/**".to_string(),
+    ]
+}
+fn default_keyword_triggers_enabled() -> bool { false }
+fn default_agent_trigger_phrases() -> Vec<String> {
+    vec!["let's build that".to_string(), "make a ticket".to_string()]
+}
+fn default_codebase_ignore_patterns() -> Vec<String> { Vec::new() }
+fn default_codebase_embeddings_enabled() -> bool { false }
+fn default_claudeignore_fragments() -> Vec<ClaudeignoreFragment> { Vec::new() }
+fn default_cloud_sync() -> CloudSyncConfig { CloudSyncConfig::default() }
+fn default_storage_root() -> Option<String> { None }
+fn default_retention() -> RetentionPolicy { RetentionPolicy::default() }
+fn default_notifications() -> NotificationSettings { NotificationSettings::default() }
+fn default_local_api() -> LocalApiConfig { LocalApiConfig::default() }
+fn default_slack() -> SlackConfig { SlackConfig::default() }
+fn default_teams() -> TeamsConfig { TeamsConfig::default() }
+fn default_discord() -> DiscordConfig { DiscordConfig::default() }
+fn default_email() -> EmailConfig { EmailConfig::default() }
+fn default_obsidian() -> ObsidianConfig { ObsidianConfig::default() }
+fn default_asana() -> AsanaConfig { AsanaConfig::default() }
+fn default_trello() -> TrelloConfig { TrelloConfig::default() }
+fn default_webhooks() -> WebhooksConfig { WebhooksConfig::default() }
+fn default_jira() -> JiraConfig { JiraConfig::default() }
+fn default_calendar() -> CalendarConfig { CalendarConfig::default() }
+fn default_diarization() -> DiarizationConfig { DiarizationConfig::default() }
+fn default_watch_folder() -> WatchFolderConfig { WatchFolderConfig::default() }
+fn default_low_confidence() -> LowConfidenceConfig { LowConfidenceConfig::default() }
+fn default_meeting_translation() -> MeetingTranslationConfig { MeetingTranslationConfig::default() }
+fn default_cloud_asr() -> CloudAsrConfig { CloudAsrConfig::default() }
 
 pub const SETTINGS_STORE_PATH: &str = "settings_store.json";
 
@@ -314,6 +1036,13 @@ pub fn get_default_settings() -> AppSettings {
     #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     let default_shortcut = "alt+space";
 
+    #[cfg(target_os = "macos")]
+    let (meeting_toggle_shortcut, meeting_pause_shortcut, meeting_bookmark_shortcut) =
+        ("option+shift+m", "option+shift+p", "option+shift+b");
+    #[cfg(not(target_os = "macos"))]
+    let (meeting_toggle_shortcut, meeting_pause_shortcut, meeting_bookmark_shortcut) =
+        ("ctrl+shift+m", "ctrl+shift+p", "ctrl+shift+b");
+
     let mut bindings = HashMap::new();
     bindings.insert(
         "transcribe".to_string(),
@@ -325,6 +1054,37 @@ pub fn get_default_settings() -> AppSettings {
             current_binding: default_shortcut.to_string(),
         },
     );
+    bindings.insert(
+        "meeting_toggle".to_string(),
+        ShortcutBinding {
+            id: "meeting_toggle".to_string(),
+            name: "Start/Stop Meeting".to_string(),
+            description: "Starts a meeting, or ends the meeting in progress.".to_string(),
+            default_binding: meeting_toggle_shortcut.to_string(),
+            current_binding: meeting_toggle_shortcut.to_string(),
+        },
+    );
+    bindings.insert(
+        "meeting_pause".to_string(),
+        ShortcutBinding {
+            id: "meeting_pause".to_string(),
+            name: "Pause/Resume Meeting".to_string(),
+            description: "Pauses or resumes the meeting in progress.".to_string(),
+            default_binding: meeting_pause_shortcut.to_string(),
+            current_binding: meeting_pause_shortcut.to_string(),
+        },
+    );
+    bindings.insert(
+        "meeting_bookmark".to_string(),
+        ShortcutBinding {
+            id: "meeting_bookmark".to_string(),
+            name: "Bookmark Meeting".to_string(),
+            description: "Marks the current moment of the meeting; press again to cycle labels."
+                .to_string(),
+            default_binding: meeting_bookmark_shortcut.to_string(),
+            current_binding: meeting_bookmark_shortcut.to_string(),
+        },
+    );
 
     AppSettings {
         bindings,
@@ -339,8 +1099,16 @@ pub fn get_default_settings() -> AppSettings {
         selected_microphone: None,
         selected_output_device: None,
         translate_to_english: false,
+        whisper_prompt_context: default_whisper_prompt_context(),
+        whisper_beam_size: default_whisper_beam_size(),
+        whisper_temperature: default_whisper_temperature(),
+        whisper_temperature_increment: default_whisper_temperature_increment(),
+        whisper_no_speech_threshold: default_whisper_no_speech_threshold(),
+        whisper_condition_on_previous_text: default_whisper_condition_on_previous_text(),
         selected_language: "auto".to_string(),
         overlay_position: OverlayPosition::Bottom,
+        overlay_content: OverlayContent::default(),
+        overlay_placements: HashMap::new(),
         debug_mode: false,
         custom_words: Vec::new(),
         model_unload_timeout: ModelUnloadTimeout::Never,
@@ -349,9 +1117,21 @@ pub fn get_default_settings() -> AppSettings {
         paste_method: PasteMethod::default(),
         clipboard_handling: ClipboardHandling::default(),
         mute_while_recording: false,
+        focus_mode_while_recording: false,
+        offline_mode: false,
+        proxy_url: None,
+        ca_bundle_path: None,
+        redact_pii: false,
+        redaction_patterns: Vec::new(),
+        metrics_sharing_enabled: false,
+        metrics_share_url: None,
+        log_level: LogLevel::default(),
         transcription_chunk_seconds: default_transcription_chunk_seconds(),
+        streaming_transcription: false,
+        streaming_interval_ms: default_streaming_interval_ms(),
         system_audio_buffer_seconds: default_system_audio_buffer_seconds(),
         system_audio_silence_threshold: default_system_audio_silence_threshold(),
+        noise_suppression_enabled: default_noise_suppression_enabled(),
         meeting_update_interval_seconds: default_meeting_update_interval_seconds(),
         auto_trigger_meeting_command: default_auto_trigger_meeting_command(),
         auto_accept_changes: default_auto_accept_changes(),
@@ -361,6 +1141,8 @@ pub fn get_default_settings() -> AppSettings {
         github_default_branch: default_github_default_branch(),
         github_branch_pattern: default_github_branch_pattern(),
         github_enabled: default_github_enabled(),
+        git_auth_method: GitAuthMethod::default(),
+        git_ssh_key_path: None,
         github_auto_commit_push: default_github_auto_commit_push(),
         github_auto_create_pr: default_github_auto_create_pr(),
         github_auto_update_pr: default_github_auto_update_pr(),
@@ -371,8 +1153,43 @@ pub fn get_default_settings() -> AppSettings {
         ffmpeg_fallback_for_imports: default_ffmpeg_fallback_for_imports(),
         use_llm_summarization: default_use_llm_summarization(),
         llm_model: default_llm_model(),
+        llm_provider: LlmProviderKind::default(),
+        llm_base_url: None,
         use_queue_transcription: default_use_queue_transcription(),
         queue_worker_count: default_queue_worker_count(),
+        import_worker_count: default_import_worker_count(),
+        separate_import_model: default_separate_import_model(),
+        gpu_scheduling: GpuScheduling::default(),
+        tray_meeting_tooltip: default_tray_meeting_tooltip(),
+        marker_label_presets: default_marker_label_presets(),
+        agent_max_files_touched: default_agent_max_files_touched(),
+        agent_max_loc_changed: default_agent_max_loc_changed(),
+        agent_allowed_path_globs: default_agent_allowed_path_globs(),
+        keyword_triggers_enabled: default_keyword_triggers_enabled(),
+        agent_trigger_phrases: default_agent_trigger_phrases(),
+        codebase_ignore_patterns: default_codebase_ignore_patterns(),
+        codebase_embeddings_enabled: default_codebase_embeddings_enabled(),
+        claudeignore_fragments: default_claudeignore_fragments(),
+        cloud_sync: default_cloud_sync(),
+        storage_root: default_storage_root(),
+        retention: default_retention(),
+        notifications: default_notifications(),
+        local_api: default_local_api(),
+        slack: default_slack(),
+        teams: default_teams(),
+        discord: default_discord(),
+        email: default_email(),
+        obsidian: default_obsidian(),
+        asana: default_asana(),
+        trello: default_trello(),
+        webhooks: default_webhooks(),
+        jira: default_jira(),
+        calendar: default_calendar(),
+        diarization: default_diarization(),
+        watch_folder: default_watch_folder(),
+        low_confidence: default_low_confidence(),
+        meeting_translation: default_meeting_translation(),
+        cloud_asr: default_cloud_asr(),
     }
 }
 
@@ -385,9 +1202,21 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
     let settings = if let Some(settings_value) = store.get("settings") {
         // Parse the entire settings object
         match serde_json::from_value::<AppSettings>(settings_value) {
-            Ok(settings) => {
+            Ok(mut settings) => {
                 println!("Found existing settings: {:?}", settings);
 
+                // Add bindings introduced since the settings were first stored
+                let mut added_binding = false;
+                for (id, binding) in get_default_settings().bindings {
+                    if !settings.bindings.contains_key(&id) {
+                        settings.bindings.insert(id, binding);
+                        added_binding = true;
+                    }
+                }
+                if added_binding {
+                    store.set("settings", serde_json::to_value(&settings).unwrap());
+                }
+
                 settings
             }
             Err(e) => {
@@ -0,0 +1,223 @@
+//! Settings export files for moving a setup to another machine. Secrets are never part
+//! of the file (they live in the keychain); the export only names them so they can be
+//! re-entered. Settings tied to this machine's paths, devices, displays, or downloaded
+//! models are left out as well.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::settings::AppSettings;
+use crate::settings_update::DEDICATED;
+
+pub const SETTINGS_FORMAT: &str = "meetingcoder-settings";
+/// Bump when a setting is renamed or reshaped, and add a step to `migrate`
+pub const SETTINGS_SCHEMA_VERSION: u32 = 1;
+
+/// Not exported, and ignored when importing
+const MACHINE_SPECIFIC: &[&str] = &[
+    "storage_root",
+    "selected_model",
+    "selected_microphone",
+    "selected_output_device",
+    "overlay_placements",
+    "ca_bundle_path",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsExport {
+    pub format: String,
+    pub schema_version: u32,
+    pub app_version: String,
+    pub exported_at: String,
+    pub settings: Map<String, Value>,
+    /// Keychain entries set on the exporting machine, to re-enter after importing
+    #[serde(default)]
+    pub secrets: Vec<String>,
+}
+
+pub fn build_export(
+    settings: &AppSettings,
+    app_version: &str,
+    secrets: Vec<String>,
+) -> Result<SettingsExport, String> {
+    let Value::Object(mut map) = serde_json::to_value(settings).map_err(|e| e.to_string())? else {
+        return Err("Settings are not an object".to_string());
+    };
+    for key in MACHINE_SPECIFIC {
+        map.remove(*key);
+    }
+    Ok(SettingsExport {
+        format: SETTINGS_FORMAT.to_string(),
+        schema_version: SETTINGS_SCHEMA_VERSION,
+        app_version: app_version.to_string(),
+        exported_at: Utc::now().to_rfc3339(),
+        settings: map,
+        secrets,
+    })
+}
+
+/// `MIGRATIONS[n]` upgrades settings from schema `n` to `n + 1`
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[
+    // 0: a bare settings object or a copy of settings_store.json; the fields are the
+    // same as schema 1, only the envelope is new
+    |_| {},
+];
+
+/// Bring settings written by an older schema up to `SETTINGS_SCHEMA_VERSION`
+fn migrate(from: u32, settings: &mut Map<String, Value>) -> Result<(), String> {
+    if from > SETTINGS_SCHEMA_VERSION {
+        return Err(format!(
+            "Settings were exported by a newer version (schema {}); update the app first",
+            from
+        ));
+    }
+    for step in &MIGRATIONS[from as usize..] {
+        step(settings);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedExport {
+    pub settings: Map<String, Value>,
+    pub secrets: Vec<String>,
+    /// Schema the file was written with, when older than the current one
+    pub migrated_from: Option<u32>,
+}
+
+/// Read an export file. Also accepts a copy of `settings_store.json` or a bare settings
+/// object, which are treated as schema 0.
+pub fn parse_export(json: &str) -> Result<ParsedExport, String> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| format!("Not a settings file: {}", e))?;
+    let (version, mut settings, secrets) = match value {
+        Value::Object(map)
+            if map.get("format").and_then(Value::as_str) == Some(SETTINGS_FORMAT) =>
+        {
+            let export: SettingsExport = serde_json::from_value(Value::Object(map))
+                .map_err(|e| format!("Invalid settings export: {}", e))?;
+            (export.schema_version, export.settings, export.secrets)
+        }
+        Value::Object(mut map) => match map.remove("settings") {
+            Some(Value::Object(settings)) => (0, settings, Vec::new()),
+            Some(_) => return Err("Invalid settings store: 'settings' is not an object".into()),
+            None => (0, map, Vec::new()),
+        },
+        _ => return Err("Not a settings file: expected a JSON object".to_string()),
+    };
+    migrate(version, &mut settings)?;
+    Ok(ParsedExport {
+        settings,
+        secrets,
+        migrated_from: (version < SETTINGS_SCHEMA_VERSION).then_some(version),
+    })
+}
+
+/// How an imported settings object maps onto this install
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    /// Goes through `settings_update::apply_patch`
+    pub patch: Map<String, Value>,
+    /// Binding id -> shortcut, for bindings that differ from the current ones
+    pub bindings: HashMap<String, String>,
+    pub always_on_microphone: Option<bool>,
+    /// Not settings this version knows about
+    pub unknown_fields: Vec<String>,
+    /// Known but not imported (machine-specific)
+    pub skipped: Vec<String>,
+}
+
+pub fn plan_import(imported: &Map<String, Value>, current: &AppSettings) -> ImportPlan {
+    let known = serde_json::to_value(current)
+        .ok()
+        .and_then(|v| v.as_object().cloned())
+        .unwrap_or_default();
+    let mut plan = ImportPlan::default();
+    for (key, value) in imported {
+        if !known.contains_key(key) {
+            plan.unknown_fields.push(key.clone());
+        } else if MACHINE_SPECIFIC.contains(&key.as_str()) {
+            plan.skipped.push(key.clone());
+        } else if key == "bindings" {
+            let Some(bindings) = value.as_object() else {
+                plan.skipped.push(key.clone());
+                continue;
+            };
+            for (id, binding) in bindings {
+                let Some(shortcut) = binding.get("current_binding").and_then(Value::as_str) else {
+                    continue;
+                };
+                if current
+                    .bindings
+                    .get(id)
+                    .is_some_and(|b| b.current_binding != shortcut)
+                {
+                    plan.bindings.insert(id.clone(), shortcut.to_string());
+                }
+            }
+        } else if key == "always_on_microphone" {
+            plan.always_on_microphone = value.as_bool();
+        } else if DEDICATED.iter().any(|(k, _)| k == key) {
+            plan.skipped.push(key.clone());
+        } else {
+            plan.patch.insert(key.clone(), value.clone());
+        }
+    }
+    plan.unknown_fields.sort();
+    plan.skipped.sort();
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn test_export_round_trip_and_import_plan() {
+        let mut settings = get_default_settings();
+        settings.storage_root = Some("/Volumes/Work".to_string());
+        settings.transcription_chunk_seconds = 12;
+        let export = build_export(&settings, "1.0.0", vec!["GitHub token".to_string()]).unwrap();
+        assert!(!export.settings.contains_key("storage_root"));
+
+        let parsed = parse_export(&serde_json::to_string(&export).unwrap()).unwrap();
+        assert_eq!(parsed.migrated_from, None);
+        assert_eq!(parsed.secrets, vec!["GitHub token".to_string()]);
+
+        let mut imported = parsed.settings;
+        imported.insert("from_the_future".to_string(), Value::Bool(true));
+        imported.insert("storage_root".to_string(), Value::Null);
+        let id = settings.bindings.keys().next().unwrap().clone();
+        imported["bindings"][&id]["current_binding"] = Value::from("ctrl+alt+f9");
+
+        let plan = plan_import(&imported, &get_default_settings());
+        assert_eq!(plan.unknown_fields, vec!["from_the_future".to_string()]);
+        assert_eq!(plan.skipped, vec!["storage_root".to_string()]);
+        assert_eq!(plan.patch["transcription_chunk_seconds"], Value::from(12));
+        assert!(!plan.patch.contains_key("bindings"));
+        assert_eq!(
+            plan.bindings.get(&id).map(String::as_str),
+            Some("ctrl+alt+f9")
+        );
+    }
+
+    #[test]
+    fn test_parse_legacy_and_newer_files() {
+        assert_eq!(MIGRATIONS.len(), SETTINGS_SCHEMA_VERSION as usize);
+
+        let store = r#"{"settings":{"push_to_talk":false}}"#;
+        let parsed = parse_export(store).unwrap();
+        assert_eq!(parsed.migrated_from, Some(0));
+        assert_eq!(parsed.settings["push_to_talk"], Value::Bool(false));
+
+        let newer = format!(
+            r#"{{"format":"{}","schema_version":99,"app_version":"9","exported_at":"","settings":{{}}}}"#,
+            SETTINGS_FORMAT
+        );
+        assert!(parse_export(&newer).unwrap_err().contains("newer version"));
+        assert!(parse_export("[1,2]").is_err());
+    }
+}
@@ -0,0 +1,637 @@
+//! One command for changing any number of settings at once. The patch is merged into the
+//! current settings, type-checked by deserializing, normalized by the rules below (the
+//! same clamps and cleanup the `change_*_setting` commands apply), written, and then each
+//! changed setting's side effect runs and a `settings-changed` event goes out for it.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_autostart::ManagerExt;
+
+use crate::settings::{self, AppSettings};
+
+/// Settings that must go through their own command because changing them does more
+/// than write the value
+pub(crate) const DEDICATED: &[(&str, &str)] = &[
+    ("bindings", "change_binding"),
+    ("selected_model", "set_active_model"),
+    ("always_on_microphone", "update_microphone_mode"),
+    ("selected_microphone", "set_selected_microphone"),
+    ("selected_output_device", "set_selected_output_device"),
+];
+
+struct SettingRule {
+    key: &'static str,
+    /// Clamp or clean up the new value; an error rejects the whole patch
+    normalize: fn(&mut AppSettings) -> Result<(), String>,
+    /// Runs after the settings are written
+    apply: Option<fn(&AppHandle, &AppSettings)>,
+}
+
+fn trimmed(values: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+const NO_NORMALIZE: fn(&mut AppSettings) -> Result<(), String> = |_| Ok(());
+
+static RULES: &[SettingRule] = &[
+    SettingRule {
+        key: "audio_feedback_volume",
+        normalize: |s| {
+            s.audio_feedback_volume = s.audio_feedback_volume.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "word_correction_threshold",
+        normalize: |s| {
+            s.word_correction_threshold = s.word_correction_threshold.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "auto_trigger_min_interval_seconds",
+        normalize: |s| {
+            s.auto_trigger_min_interval_seconds =
+                s.auto_trigger_min_interval_seconds.clamp(30, 600);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "whisper_beam_size",
+        normalize: |s| {
+            s.whisper_beam_size = s.whisper_beam_size.clamp(1, 10);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "whisper_temperature",
+        normalize: |s| {
+            s.whisper_temperature = s.whisper_temperature.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "whisper_temperature_increment",
+        normalize: |s| {
+            s.whisper_temperature_increment = s.whisper_temperature_increment.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "whisper_no_speech_threshold",
+        normalize: |s| {
+            s.whisper_no_speech_threshold = s.whisper_no_speech_threshold.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "transcription_chunk_seconds",
+        normalize: |s| {
+            s.transcription_chunk_seconds = s.transcription_chunk_seconds.clamp(2, 60);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "streaming_interval_ms",
+        normalize: |s| {
+            s.streaming_interval_ms = s.streaming_interval_ms.clamp(250, 5000);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "system_audio_silence_threshold",
+        normalize: |s| {
+            s.system_audio_silence_threshold = s.system_audio_silence_threshold.clamp(-80.0, 0.0);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "noise_suppression_enabled",
+        normalize: |_| Ok(()),
+        apply: Some(crate::managers::audio::apply_settings),
+    },
+    SettingRule {
+        key: "system_audio_buffer_seconds",
+        normalize: |s| {
+            s.system_audio_buffer_seconds = s.system_audio_buffer_seconds.clamp(30, 600);
+            Ok(())
+        },
+        apply: Some(|app, s| {
+            if let Some(rm) = app.try_state::<Arc<crate::managers::audio::AudioRecordingManager>>()
+            {
+                if let Err(e) = rm.reconfigure_system_audio_buffer(s.system_audio_buffer_seconds) {
+                    log::warn!("Failed to reconfigure system audio buffer: {}", e);
+                }
+            }
+        }),
+    },
+    SettingRule {
+        key: "meeting_update_interval_seconds",
+        normalize: |s| {
+            s.meeting_update_interval_seconds = s.meeting_update_interval_seconds.clamp(5, 300);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "min_segment_duration_for_imports",
+        normalize: |s| {
+            s.min_segment_duration_for_imports = s.min_segment_duration_for_imports.clamp(5, 15);
+            Ok(())
+        },
+        apply: None,
+    },
+    // Worker pools read these on every autoscale tick, so no restart is needed
+    SettingRule {
+        key: "queue_worker_count",
+        normalize: |s| {
+            s.queue_worker_count = s.queue_worker_count.clamp(1, 8);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "import_worker_count",
+        normalize: |s| {
+            s.import_worker_count = s.import_worker_count.clamp(1, 8);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "agent_allowed_path_globs",
+        normalize: |s| {
+            s.agent_allowed_path_globs = trimmed(&s.agent_allowed_path_globs);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "agent_trigger_phrases",
+        normalize: |s| {
+            s.agent_trigger_phrases = trimmed(&s.agent_trigger_phrases);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "codebase_ignore_patterns",
+        normalize: |s| {
+            s.codebase_ignore_patterns = trimmed(&s.codebase_ignore_patterns);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "marker_label_presets",
+        normalize: |s| {
+            s.marker_label_presets = trimmed(&s.marker_label_presets);
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "claudeignore_fragments",
+        normalize: |s| {
+            s.claudeignore_fragments
+                .retain(|f| !f.name.trim().is_empty());
+            for fragment in &mut s.claudeignore_fragments {
+                fragment.patterns = trimmed(&fragment.patterns);
+            }
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "overlay_position",
+        normalize: NO_NORMALIZE,
+        apply: Some(|app, _| crate::utils::update_overlay_position(app)),
+    },
+    SettingRule {
+        key: "overlay_placements",
+        normalize: NO_NORMALIZE,
+        apply: Some(|app, _| crate::utils::update_overlay_position(app)),
+    },
+    SettingRule {
+        key: "overlay_content",
+        normalize: NO_NORMALIZE,
+        apply: Some(|app, s| crate::utils::apply_overlay_content(app, s.overlay_content)),
+    },
+    SettingRule {
+        key: "autostart_enabled",
+        normalize: NO_NORMALIZE,
+        apply: Some(|app, s| {
+            let autostart = app.autolaunch();
+            let _ = if s.autostart_enabled {
+                autostart.enable()
+            } else {
+                autostart.disable()
+            };
+        }),
+    },
+    SettingRule {
+        key: "focus_mode_while_recording",
+        normalize: NO_NORMALIZE,
+        apply: Some(|_, s| {
+            if !s.focus_mode_while_recording {
+                crate::focus_mode::restore();
+            }
+        }),
+    },
+    SettingRule {
+        key: "log_level",
+        normalize: NO_NORMALIZE,
+        apply: Some(|_, s| crate::logging::set_file_level(s.log_level.into())),
+    },
+    SettingRule {
+        key: "redact_pii",
+        normalize: NO_NORMALIZE,
+        apply: Some(|_, s| crate::redaction::apply_settings(s)),
+    },
+    SettingRule {
+        key: "redaction_patterns",
+        normalize: |s| crate::redaction::normalize_patterns(&mut s.redaction_patterns),
+        apply: Some(|_, s| crate::redaction::apply_settings(s)),
+    },
+    SettingRule {
+        key: "offline_mode",
+        normalize: NO_NORMALIZE,
+        apply: Some(|_, s| crate::network::set_offline(s.offline_mode)),
+    },
+    SettingRule {
+        key: "proxy_url",
+        normalize: |s| {
+            s.proxy_url = s
+                .proxy_url
+                .as_ref()
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty());
+            match &s.proxy_url {
+                Some(url) => crate::network::validate_proxy_url(url),
+                None => Ok(()),
+            }
+        },
+        apply: Some(|_, s| crate::network::apply_settings(s)),
+    },
+    SettingRule {
+        key: "llm_provider",
+        normalize: NO_NORMALIZE,
+        apply: Some(|_, s| crate::summarization::llm::apply_settings(s)),
+    },
+    SettingRule {
+        key: "llm_base_url",
+        normalize: |s| {
+            s.llm_base_url = s
+                .llm_base_url
+                .as_ref()
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty());
+            match &s.llm_base_url {
+                Some(url) => crate::summarization::providers::validate_base_url(url),
+                None => Ok(()),
+            }
+        },
+        apply: Some(|_, s| crate::summarization::llm::apply_settings(s)),
+    },
+    SettingRule {
+        key: "llm_model",
+        normalize: |s| {
+            s.llm_model = s.llm_model.trim().to_string();
+            Ok(())
+        },
+        apply: Some(|_, s| crate::summarization::llm::apply_settings(s)),
+    },
+    SettingRule {
+        key: "git_ssh_key_path",
+        normalize: |s| {
+            s.git_ssh_key_path = s
+                .git_ssh_key_path
+                .as_ref()
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty());
+            match &s.git_ssh_key_path {
+                Some(path) if !std::path::Path::new(path).is_file() => {
+                    Err(format!("SSH key not found: {}", path))
+                }
+                _ => Ok(()),
+            }
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "metrics_share_url",
+        normalize: |s| {
+            s.metrics_share_url = s
+                .metrics_share_url
+                .as_ref()
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty());
+            match s.metrics_share_url.as_deref().map(reqwest::Url::parse) {
+                Some(Ok(url)) if matches!(url.scheme(), "http" | "https") => Ok(()),
+                Some(_) => Err("Metrics sharing URL must be an http(s) URL".to_string()),
+                None => Ok(()),
+            }
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "ca_bundle_path",
+        normalize: |s| {
+            s.ca_bundle_path = s
+                .ca_bundle_path
+                .as_ref()
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty());
+            match &s.ca_bundle_path {
+                Some(path) => crate::network::load_ca_bundle(path).map(|_| ()),
+                None => Ok(()),
+            }
+        },
+        apply: Some(|_, s| crate::network::apply_settings(s)),
+    },
+    SettingRule {
+        key: "storage_root",
+        normalize: |s| {
+            s.storage_root = s
+                .storage_root
+                .as_ref()
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty());
+            match &s.storage_root {
+                Some(r) if !std::path::Path::new(r).is_absolute() => {
+                    Err("Storage root must be an absolute path".to_string())
+                }
+                _ => Ok(()),
+            }
+        },
+        apply: Some(|_, s| {
+            crate::storage::root::set_storage_root(
+                s.storage_root.clone().map(std::path::PathBuf::from),
+            )
+        }),
+    },
+    SettingRule {
+        key: "retention",
+        normalize: |s| {
+            if s.retention.audio_days == Some(0) || s.retention.transcript_days == Some(0) {
+                return Err("Retention periods must be at least one day".to_string());
+            }
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "cloud_sync",
+        normalize: |s| {
+            let config = &s.cloud_sync;
+            if !matches!(config.provider.as_str(), "s3" | "webdav") {
+                return Err(format!(
+                    "Unsupported cloud sync provider: {}",
+                    config.provider
+                ));
+            }
+            if config.enabled && config.endpoint.trim().is_empty() {
+                return Err("Cloud sync endpoint is required".to_string());
+            }
+            if config.enabled && config.provider == "s3" && config.bucket.trim().is_empty() {
+                return Err("S3 bucket is required".to_string());
+            }
+            Ok(())
+        },
+        apply: None,
+    },
+    SettingRule {
+        key: "local_api",
+        normalize: |s| {
+            if s.local_api.port < 1024 {
+                return Err("Local API port must be 1024 or higher".to_string());
+            }
+            Ok(())
+        },
+        apply: Some(|app, _| crate::integrations::local_api::restart(app)),
+    },
+    SettingRule {
+        key: "email",
+        normalize: |s| crate::integrations::email::normalize_config(&mut s.email),
+        apply: None,
+    },
+    SettingRule {
+        key: "obsidian",
+        normalize: |s| crate::integrations::obsidian::normalize_config(&mut s.obsidian),
+        apply: None,
+    },
+    SettingRule {
+        key: "webhooks",
+        normalize: |s| crate::integrations::webhooks::normalize_config(&mut s.webhooks),
+        apply: None,
+    },
+    SettingRule {
+        key: "jira",
+        normalize: |s| crate::integrations::jira::normalize_config(&mut s.jira),
+        apply: None,
+    },
+    SettingRule {
+        key: "calendar",
+        normalize: |s| crate::integrations::calendar::normalize_config(&mut s.calendar),
+        apply: None,
+    },
+    SettingRule {
+        key: "diarization",
+        normalize: |s| crate::diarization::normalize_config(&mut s.diarization),
+        apply: None,
+    },
+    SettingRule {
+        key: "low_confidence",
+        normalize: |s| {
+            s.low_confidence.threshold = s.low_confidence.threshold.clamp(0.0, 1.0);
+            Ok(())
+        },
+        apply: Some(|_, s| crate::managers::transcription::apply_settings(s)),
+    },
+    SettingRule {
+        key: "meeting_translation",
+        normalize: |s| crate::meeting::translation::normalize_config(&mut s.meeting_translation),
+        apply: None,
+    },
+    SettingRule {
+        key: "cloud_asr",
+        normalize: |s| crate::managers::cloud_asr::normalize_config(&mut s.cloud_asr),
+        apply: None,
+    },
+    SettingRule {
+        key: "watch_folder",
+        normalize: |s| crate::watch_folder::normalize_config(&mut s.watch_folder),
+        apply: Some(crate::watch_folder::apply_settings),
+    },
+];
+
+fn rule(key: &str) -> Option<&'static SettingRule> {
+    RULES.iter().find(|r| r.key == key)
+}
+
+/// One setting the patch changed, also the payload of its `settings-changed` event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SettingChange {
+    pub setting: String,
+    pub value: Value,
+    pub previous: Value,
+}
+
+/// Merge `patch` into `current` and normalize the result; returns the new settings and
+/// the settings whose stored value actually changed
+pub(crate) fn apply_patch(
+    current: &AppSettings,
+    patch: &Map<String, Value>,
+) -> Result<(AppSettings, Vec<SettingChange>), String> {
+    let before = serde_json::to_value(current).map_err(|e| e.to_string())?;
+    let Value::Object(mut merged) = before.clone() else {
+        return Err("Settings are not an object".to_string());
+    };
+    for (key, value) in patch {
+        if let Some((_, command)) = DEDICATED.iter().find(|(k, _)| k == key) {
+            return Err(format!("'{}' can't be patched; use {}", key, command));
+        }
+        if !merged.contains_key(key) {
+            return Err(format!("Unknown setting '{}'", key));
+        }
+        // Type-check each value on its own so the error names the setting
+        let mut single = merged.clone();
+        single.insert(key.clone(), value.clone());
+        serde_json::from_value::<AppSettings>(Value::Object(single))
+            .map_err(|e| format!("Invalid value for '{}': {}", key, e))?;
+        merged.insert(key.clone(), value.clone());
+    }
+
+    let mut updated: AppSettings =
+        serde_json::from_value(Value::Object(merged)).map_err(|e| e.to_string())?;
+    for key in patch.keys() {
+        if let Some(rule) = rule(key) {
+            (rule.normalize)(&mut updated)?;
+        }
+    }
+
+    let after = serde_json::to_value(&updated).map_err(|e| e.to_string())?;
+    let changes = patch
+        .keys()
+        .filter(|key| before[key.as_str()] != after[key.as_str()])
+        .map(|key| SettingChange {
+            setting: key.clone(),
+            value: after[key.as_str()].clone(),
+            previous: before[key.as_str()].clone(),
+        })
+        .collect();
+    Ok((updated, changes))
+}
+
+/// Change several settings at once, e.g. `{ "transcription_chunk_seconds": 15 }`.
+/// Nothing is written if any key is unknown or any value is invalid. Out-of-range
+/// numbers are clamped; the returned changes show the values actually stored.
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    patch: Map<String, Value>,
+) -> Result<Vec<SettingChange>, String> {
+    let current = settings::get_settings(&app);
+    let (updated, changes) = apply_patch(&current, &patch)?;
+    commit(&app, updated, &changes);
+    Ok(changes)
+}
+
+/// Write settings produced by `apply_patch`, run side effects, and notify the frontend
+pub(crate) fn commit(app: &AppHandle, updated: AppSettings, changes: &[SettingChange]) {
+    if changes.is_empty() {
+        return;
+    }
+    settings::write_settings(app, updated.clone());
+
+    for change in changes {
+        if let Some(apply) = rule(&change.setting).and_then(|r| r.apply) {
+            apply(app, &updated);
+        }
+        let _ = app.emit("settings-changed", change);
+    }
+    log::info!(
+        "Settings updated: {}",
+        changes
+            .iter()
+            .map(|c| c.setting.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_apply_patch_validates_and_clamps() {
+        let current = settings::get_default_settings();
+
+        let (updated, changes) = apply_patch(
+            &current,
+            &patch(serde_json::json!({
+                "transcription_chunk_seconds": 500,
+                "agent_trigger_phrases": ["  ship it ", ""],
+                "debug_mode": current.debug_mode,
+            })),
+        )
+        .unwrap();
+        assert_eq!(updated.transcription_chunk_seconds, 60);
+        assert_eq!(updated.agent_trigger_phrases, vec!["ship it".to_string()]);
+        // Unchanged values are left out of the diff
+        assert_eq!(changes.len(), 2);
+        let chunk = changes
+            .iter()
+            .find(|c| c.setting == "transcription_chunk_seconds")
+            .unwrap();
+        assert_eq!(chunk.value, serde_json::json!(60));
+        assert_eq!(
+            chunk.previous,
+            serde_json::json!(current.transcription_chunk_seconds)
+        );
+
+        assert!(apply_patch(&current, &patch(serde_json::json!({ "nope": 1 }))).is_err());
+        assert!(apply_patch(
+            &current,
+            &patch(serde_json::json!({ "transcription_chunk_seconds": "ten" }))
+        )
+        .unwrap_err()
+        .contains("transcription_chunk_seconds"));
+        assert!(apply_patch(
+            &current,
+            &patch(serde_json::json!({ "paste_method": "shout" }))
+        )
+        .is_err());
+        assert!(
+            apply_patch(&current, &patch(serde_json::json!({ "bindings": {} })))
+                .unwrap_err()
+                .contains("change_binding")
+        );
+        assert!(apply_patch(
+            &current,
+            &patch(serde_json::json!({ "storage_root": "relative/dir" }))
+        )
+        .is_err());
+    }
+}
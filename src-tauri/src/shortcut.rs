@@ -1,11 +1,22 @@
+use once_cell::sync::Lazy;
 use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_autostart::ManagerExt;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
-use crate::actions::ACTION_MAP;
-use crate::settings::ShortcutBinding;
-use crate::settings::{self, get_settings, ClipboardHandling, OverlayPosition, PasteMethod, SoundTheme};
+use crate::actions::{ShortcutAction, ACTION_MAP};
+use crate::settings::{
+    AsanaConfig, CalendarConfig, CloudAsrConfig, ClaudeignoreFragment, CloudSyncConfig, DiarizationConfig,
+    DiscordConfig,
+    EmailConfig, GpuScheduling, JiraConfig, LocalApiConfig, NotificationSettings, ObsidianConfig,
+    OverlayPlacement, RetentionPolicy, ShortcutBinding, SlackConfig, TeamsConfig, TrelloConfig,
+    LowConfidenceConfig, MeetingTranslationConfig, WatchFolderConfig, WebhooksConfig,
+};
+use crate::settings::{self, get_settings, ClipboardHandling, OverlayContent, OverlayPosition, PasteMethod, SoundTheme};
 use crate::ManagedToggleState;
 
 pub fn init_shortcuts(app: &AppHandle) {
@@ -21,9 +32,9 @@ pub fn init_shortcuts(app: &AppHandle) {
 
 #[derive(Serialize)]
 pub struct BindingResponse {
-    success: bool,
-    binding: Option<ShortcutBinding>,
-    error: Option<String>,
+    pub(crate) success: bool,
+    pub(crate) binding: Option<ShortcutBinding>,
+    pub(crate) error: Option<String>,
 }
 
 #[tauri::command]
@@ -179,6 +190,79 @@ pub fn change_translate_to_english_setting(app: AppHandle, enabled: bool) -> Res
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_whisper_prompt_context_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.whisper_prompt_context = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_whisper_beam_size_setting(app: AppHandle, beam_size: u32) -> Result<(), String> {
+    let value = beam_size.clamp(1, 10);
+    let mut s = settings::get_settings(&app);
+    s.whisper_beam_size = value;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "whisper_beam_size", "value": value }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_whisper_temperature_setting(app: AppHandle, temperature: f32) -> Result<(), String> {
+    let value = temperature.clamp(0.0, 1.0);
+    let mut s = settings::get_settings(&app);
+    s.whisper_temperature = value;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "whisper_temperature", "value": value }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_whisper_temperature_increment_setting(app: AppHandle, increment: f32) -> Result<(), String> {
+    let value = increment.clamp(0.0, 1.0);
+    let mut s = settings::get_settings(&app);
+    s.whisper_temperature_increment = value;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "whisper_temperature_increment", "value": value }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_whisper_no_speech_threshold_setting(app: AppHandle, threshold: f32) -> Result<(), String> {
+    let value = threshold.clamp(0.0, 1.0);
+    let mut s = settings::get_settings(&app);
+    s.whisper_no_speech_threshold = value;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "whisper_no_speech_threshold", "value": value }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_whisper_condition_on_previous_text_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let value = enabled;
+    let mut s = settings::get_settings(&app);
+    s.whisper_condition_on_previous_text = value;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "whisper_condition_on_previous_text", "value": value }),
+    );
+    Ok(())
+}
+
 #[tauri::command]
 pub fn change_selected_language_setting(app: AppHandle, language: String) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
@@ -208,6 +292,42 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_overlay_content_setting(app: AppHandle, mode: OverlayContent) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_content = mode;
+    settings::write_settings(&app, settings);
+
+    crate::utils::apply_overlay_content(&app, mode);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "overlay_content", "value": mode }),
+    );
+    Ok(())
+}
+
+/// Placement for the display configuration currently connected
+#[tauri::command]
+pub fn change_overlay_placement_setting(
+    app: AppHandle,
+    placement: OverlayPlacement,
+) -> Result<(), String> {
+    let key = crate::utils::display_configuration(&app);
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_placements.insert(key.clone(), placement.clone());
+    settings::write_settings(&app, settings);
+
+    crate::utils::update_overlay_position(&app);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "overlay_placements",
+            "value": { "display_configuration": key, "placement": placement },
+        }),
+    );
+    Ok(())
+}
+
 #[tauri::command]
 pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
@@ -330,6 +450,21 @@ pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Res
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_focus_mode_while_recording_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.focus_mode_while_recording = enabled;
+    settings::write_settings(&app, settings);
+    if !enabled {
+        crate::focus_mode::restore();
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub fn change_transcription_chunk_seconds_setting(
     app: AppHandle,
@@ -366,6 +501,19 @@ pub fn change_system_audio_silence_threshold_setting(
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_noise_suppression_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.noise_suppression_enabled = enabled;
+    crate::managers::audio::apply_settings(&app, &s);
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "noise_suppression_enabled", "value": enabled }),
+    );
+    Ok(())
+}
+
 #[tauri::command]
 pub fn change_system_audio_buffer_seconds_setting(
     app: AppHandle,
@@ -576,22 +724,492 @@ pub fn change_ffmpeg_fallback_for_imports_setting(
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_agent_max_files_touched_setting(
+    app: AppHandle,
+    count: u32,
+) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.agent_max_files_touched = count;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "agent_max_files_touched", "value": count }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_agent_max_loc_changed_setting(
+    app: AppHandle,
+    lines: u32,
+) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.agent_max_loc_changed = lines;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "agent_max_loc_changed", "value": lines }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_agent_allowed_path_globs_setting(
+    app: AppHandle,
+    globs: Vec<String>,
+) -> Result<(), String> {
+    let globs: Vec<String> = globs
+        .into_iter()
+        .map(|g| g.trim().to_string())
+        .filter(|g| !g.is_empty())
+        .collect();
+    let mut s = settings::get_settings(&app);
+    s.agent_allowed_path_globs = globs.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "agent_allowed_path_globs", "value": globs }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_keyword_triggers_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.keyword_triggers_enabled = enabled;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "keyword_triggers_enabled", "value": enabled }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_agent_trigger_phrases_setting(
+    app: AppHandle,
+    phrases: Vec<String>,
+) -> Result<(), String> {
+    let phrases: Vec<String> = phrases
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let mut s = settings::get_settings(&app);
+    s.agent_trigger_phrases = phrases.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "agent_trigger_phrases", "value": phrases }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_codebase_ignore_patterns_setting(
+    app: AppHandle,
+    patterns: Vec<String>,
+) -> Result<(), String> {
+    let patterns: Vec<String> = patterns
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let mut s = settings::get_settings(&app);
+    s.codebase_ignore_patterns = patterns.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "codebase_ignore_patterns", "value": patterns }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_codebase_embeddings_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.codebase_embeddings_enabled = enabled;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "codebase_embeddings_enabled", "value": enabled }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_claudeignore_fragments_setting(
+    app: AppHandle,
+    fragments: Vec<ClaudeignoreFragment>,
+) -> Result<(), String> {
+    let fragments: Vec<ClaudeignoreFragment> = fragments
+        .into_iter()
+        .map(|mut f| {
+            f.patterns = f
+                .patterns
+                .iter()
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect();
+            f
+        })
+        .filter(|f| !f.name.trim().is_empty())
+        .collect();
+    let mut s = settings::get_settings(&app);
+    s.claudeignore_fragments = fragments.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "claudeignore_fragments", "value": fragments }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_cloud_sync_settings(app: AppHandle, config: CloudSyncConfig) -> Result<(), String> {
+    if !matches!(config.provider.as_str(), "s3" | "webdav") {
+        return Err(format!("Unsupported cloud sync provider: {}", config.provider));
+    }
+    if config.enabled && config.endpoint.trim().is_empty() {
+        return Err("Cloud sync endpoint is required".to_string());
+    }
+    if config.enabled && config.provider == "s3" && config.bucket.trim().is_empty() {
+        return Err("S3 bucket is required".to_string());
+    }
+    let mut s = settings::get_settings(&app);
+    s.cloud_sync = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "cloud_sync", "value": config }),
+    );
+    Ok(())
+}
+
+/// Point storage at a different root without moving existing data
+/// (use `migrate_storage_root` to move it)
+#[tauri::command]
+pub fn change_storage_root_setting(app: AppHandle, root: Option<String>) -> Result<(), String> {
+    let root = root.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+    if let Some(ref r) = root {
+        if !std::path::Path::new(r).is_absolute() {
+            return Err("Storage root must be an absolute path".to_string());
+        }
+    }
+    let mut s = settings::get_settings(&app);
+    s.storage_root = root.clone();
+    settings::write_settings(&app, s);
+    crate::storage::root::set_storage_root(root.clone().map(std::path::PathBuf::from));
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "storage_root", "value": root }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_retention_policy_setting(app: AppHandle, policy: RetentionPolicy) -> Result<(), String> {
+    if policy.audio_days == Some(0) || policy.transcript_days == Some(0) {
+        return Err("Retention periods must be at least one day".to_string());
+    }
+    let mut s = settings::get_settings(&app);
+    s.retention = policy.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "retention", "value": policy }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_notification_settings(
+    app: AppHandle,
+    notifications: NotificationSettings,
+) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.notifications = notifications.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "notifications", "value": notifications }),
+    );
+    Ok(())
+}
+
+/// Also starts, stops, or rebinds the localhost API server
+#[tauri::command]
+pub fn change_local_api_settings(app: AppHandle, config: LocalApiConfig) -> Result<(), String> {
+    if config.port < 1024 {
+        return Err("Local API port must be 1024 or higher".to_string());
+    }
+    let mut s = settings::get_settings(&app);
+    s.local_api = config.clone();
+    settings::write_settings(&app, s);
+    crate::integrations::local_api::restart(&app);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "local_api", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_slack_settings(app: AppHandle, config: SlackConfig) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.slack = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "slack", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_teams_settings(app: AppHandle, config: TeamsConfig) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.teams = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "teams", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_discord_settings(app: AppHandle, config: DiscordConfig) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.discord = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "discord", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_email_settings(app: AppHandle, mut config: EmailConfig) -> Result<(), String> {
+    crate::integrations::email::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.email = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "email", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_obsidian_settings(app: AppHandle, mut config: ObsidianConfig) -> Result<(), String> {
+    crate::integrations::obsidian::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.obsidian = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "obsidian", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_asana_settings(app: AppHandle, config: AsanaConfig) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.asana = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "asana", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_trello_settings(app: AppHandle, config: TrelloConfig) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.trello = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "trello", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_webhooks_settings(app: AppHandle, mut config: WebhooksConfig) -> Result<(), String> {
+    crate::integrations::webhooks::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.webhooks = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "webhooks", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_jira_settings(app: AppHandle, mut config: JiraConfig) -> Result<(), String> {
+    crate::integrations::jira::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.jira = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "jira", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_calendar_settings(app: AppHandle, mut config: CalendarConfig) -> Result<(), String> {
+    crate::integrations::calendar::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.calendar = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "calendar", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_diarization_settings(
+    app: AppHandle,
+    mut config: DiarizationConfig,
+) -> Result<(), String> {
+    crate::diarization::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.diarization = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "diarization", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_low_confidence_settings(
+    app: AppHandle,
+    mut config: LowConfidenceConfig,
+) -> Result<(), String> {
+    config.threshold = config.threshold.clamp(0.0, 1.0);
+    let mut s = settings::get_settings(&app);
+    s.low_confidence = config.clone();
+    settings::write_settings(&app, s.clone());
+    crate::managers::transcription::apply_settings(&s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "low_confidence", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_meeting_translation_settings(
+    app: AppHandle,
+    mut config: MeetingTranslationConfig,
+) -> Result<(), String> {
+    crate::meeting::translation::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.meeting_translation = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "meeting_translation", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_cloud_asr_settings(app: AppHandle, mut config: CloudAsrConfig) -> Result<(), String> {
+    crate::managers::cloud_asr::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.cloud_asr = config.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "cloud_asr", "value": config }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_watch_folder_settings(
+    app: AppHandle,
+    mut config: WatchFolderConfig,
+) -> Result<(), String> {
+    crate::watch_folder::normalize_config(&mut config)?;
+    let mut s = settings::get_settings(&app);
+    s.watch_folder = config.clone();
+    settings::write_settings(&app, s.clone());
+    crate::watch_folder::apply_settings(&app, &s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "watch_folder", "value": config }),
+    );
+    Ok(())
+}
+
+/// Separates the two steps of a chorded binding, e.g. "option+m s"
+const CHORD_SEPARATOR: char = ' ';
+/// How long the second step of a chord is listened for after its leader is pressed
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Second steps of chorded bindings, keyed by leader: (step, binding id)
+static CHORDS: Lazy<Mutex<HashMap<String, Vec<(String, String)>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+/// Second steps registered while a chord is pending, tagged with its generation so a
+/// stale timeout doesn't cancel a newer chord
+static PENDING_CHORD: Lazy<Mutex<Option<(u64, Vec<String>)>>> = Lazy::new(|| Mutex::new(None));
+static CHORD_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Split a binding into its leader and second step when it is a chord
+fn split_chord(raw: &str) -> Result<Option<(String, String)>, String> {
+    let steps: Vec<&str> = raw
+        .split(CHORD_SEPARATOR)
+        .filter(|s| !s.is_empty())
+        .collect();
+    match steps.as_slice() {
+        [_] => Ok(None),
+        [leader, second] => Ok(Some((leader.to_string(), second.to_string()))),
+        _ => Err("Chorded shortcuts must have exactly two steps".into()),
+    }
+}
+
 /// Determine whether a shortcut string contains at least one non-modifier key.
 /// We allow single non-modifier keys (e.g. "f5" or "space") but disallow
-/// modifier-only combos (e.g. "ctrl" or "ctrl+shift").
+/// modifier-only combos (e.g. "ctrl" or "ctrl+shift"). Each step of a chord is
+/// checked on its own.
 fn validate_shortcut_string(raw: &str) -> Result<(), String> {
     let modifiers = [
         "ctrl", "control", "shift", "alt", "option", "meta", "command", "cmd", "super", "win",
         "windows",
     ];
-    let has_non_modifier = raw
-        .split('+')
-        .any(|part| !modifiers.contains(&part.trim().to_lowercase().as_str()));
-    if has_non_modifier {
-        Ok(())
-    } else {
-        Err("Shortcut must contain at least one non-modifier key".into())
+    if raw.trim().is_empty() {
+        return Err("Shortcut must contain at least one non-modifier key".into());
     }
+    split_chord(raw)?;
+    for step in raw.split(CHORD_SEPARATOR).filter(|s| !s.is_empty()) {
+        let has_non_modifier = step
+            .split('+')
+            .any(|part| !modifiers.contains(&part.trim().to_lowercase().as_str()));
+        if !has_non_modifier {
+            return Err("Shortcut must contain at least one non-modifier key".into());
+        }
+    }
+    Ok(())
 }
 
 /// Temporarily unregister a binding while the user is editing it in the UI.
@@ -629,6 +1247,10 @@ fn _register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), S
         return Err(e);
     }
 
+    if let Some((leader, second)) = split_chord(&binding.current_binding)? {
+        return register_chord(app, &binding.id, &leader, &second);
+    }
+
     // Parse shortcut and return error if it fails
     let shortcut = match binding.current_binding.parse::<Shortcut>() {
         Ok(s) => s,
@@ -659,34 +1281,18 @@ fn _register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), S
                 let settings = get_settings(ah);
 
                 if let Some(action) = ACTION_MAP.get(&binding_id_for_closure) {
-                    if settings.push_to_talk {
+                    if action.is_instant() {
                         if event.state == ShortcutState::Pressed {
                             action.start(ah, &binding_id_for_closure, &shortcut_string);
-                        } else if event.state == ShortcutState::Released {
-                            action.stop(ah, &binding_id_for_closure, &shortcut_string);
                         }
-                    } else {
+                    } else if settings.push_to_talk {
                         if event.state == ShortcutState::Pressed {
-                            let toggle_state_manager = ah.state::<ManagedToggleState>();
-
-                            let mut states = toggle_state_manager.lock().expect("Failed to lock toggle state manager");
-
-                            let is_currently_active = states.active_toggles
-                                .entry(binding_id_for_closure.clone())
-                                .or_insert(false);
-
-                            if *is_currently_active {
-                                action.stop(
-                                    ah,
-                                    &binding_id_for_closure,
-                                    &shortcut_string,
-                                );
-                                *is_currently_active = false; // Update state to inactive
-                            } else {
-                                action.start(ah, &binding_id_for_closure, &shortcut_string);
-                                *is_currently_active = true; // Update state to active
-                            }
+                            action.start(ah, &binding_id_for_closure, &shortcut_string);
+                        } else if event.state == ShortcutState::Released {
+                            action.stop(ah, &binding_id_for_closure, &shortcut_string);
                         }
+                    } else if event.state == ShortcutState::Pressed {
+                        toggle_action(ah, action.as_ref(), &binding_id_for_closure, &shortcut_string);
                     }
                 } else {
                     println!(
@@ -706,6 +1312,10 @@ fn _register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), S
 }
 
 fn _unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    if let Ok(Some((leader, _))) = split_chord(&binding.current_binding) {
+        return unregister_chord(app, &binding.id, &leader);
+    }
+
     let shortcut = match binding.current_binding.parse::<Shortcut>() {
         Ok(s) => s,
         Err(e) => {
@@ -729,3 +1339,266 @@ fn _unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(),
 
     Ok(())
 }
+
+/// Toggle-mode dispatch: alternate between starting and stopping the action
+fn toggle_action(
+    ah: &AppHandle,
+    action: &dyn ShortcutAction,
+    binding_id: &str,
+    shortcut_str: &str,
+) {
+    let toggle_state_manager = ah.state::<ManagedToggleState>();
+    let mut states = toggle_state_manager
+        .lock()
+        .expect("Failed to lock toggle state manager");
+    let is_currently_active = states
+        .active_toggles
+        .entry(binding_id.to_string())
+        .or_insert(false);
+
+    if *is_currently_active {
+        action.stop(ah, binding_id, shortcut_str);
+        *is_currently_active = false;
+    } else {
+        action.start(ah, binding_id, shortcut_str);
+        *is_currently_active = true;
+    }
+}
+
+/// Register a two-step binding. Only the leader is registered globally; second steps
+/// are registered while a chord is pending so they don't swallow keys the rest of the time.
+/// Several chords can share a leader.
+fn register_chord(
+    app: &AppHandle,
+    binding_id: &str,
+    leader: &str,
+    second: &str,
+) -> Result<(), String> {
+    let leader_shortcut = leader
+        .parse::<Shortcut>()
+        .map_err(|e| format!("Failed to parse shortcut '{}': {}", leader, e))?;
+    second
+        .parse::<Shortcut>()
+        .map_err(|e| format!("Failed to parse shortcut '{}': {}", second, e))?;
+
+    let mut chords = CHORDS.lock().unwrap();
+    let shares_leader = chords.get(leader).map(|s| !s.is_empty()).unwrap_or(false);
+    if shares_leader {
+        if chords[leader].iter().any(|(step, _)| step == second) {
+            return Err(format!(
+                "Shortcut '{} {}' is already in use",
+                leader, second
+            ));
+        }
+    } else {
+        if app.global_shortcut().is_registered(leader_shortcut) {
+            return Err(format!("Shortcut '{}' is already in use", leader));
+        }
+        let leader_for_closure = leader.to_string();
+        app.global_shortcut()
+            .on_shortcut(leader_shortcut, move |ah, _scut, event| {
+                if event.state == ShortcutState::Pressed {
+                    // Registering shortcuts from inside a shortcut handler deadlocks the plugin
+                    let ah = ah.clone();
+                    let leader = leader_for_closure.clone();
+                    std::thread::spawn(move || begin_chord(&ah, &leader));
+                }
+            })
+            .map_err(|e| format!("Couldn't register shortcut '{}': {}", leader, e))?;
+    }
+    chords
+        .entry(leader.to_string())
+        .or_default()
+        .push((second.to_string(), binding_id.to_string()));
+    Ok(())
+}
+
+fn unregister_chord(app: &AppHandle, binding_id: &str, leader: &str) -> Result<(), String> {
+    let mut chords = CHORDS.lock().unwrap();
+    let Some(steps) = chords.get_mut(leader) else {
+        return Ok(());
+    };
+    steps.retain(|(_, id)| id != binding_id);
+    if steps.is_empty() {
+        chords.remove(leader);
+        let shortcut = leader
+            .parse::<Shortcut>()
+            .map_err(|e| format!("Failed to parse shortcut '{}': {}", leader, e))?;
+        app.global_shortcut()
+            .unregister(shortcut)
+            .map_err(|e| format!("Failed to unregister shortcut '{}': {}", leader, e))?;
+    }
+    Ok(())
+}
+
+/// Leader pressed: listen for the second steps of its chords until one fires or the
+/// timeout passes
+fn begin_chord(app: &AppHandle, leader: &str) {
+    cancel_chord(app);
+    let steps = CHORDS
+        .lock()
+        .unwrap()
+        .get(leader)
+        .cloned()
+        .unwrap_or_default();
+    let generation = CHORD_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let mut registered = Vec::new();
+    for (step, binding_id) in steps {
+        let Ok(shortcut) = step.parse::<Shortcut>() else {
+            continue;
+        };
+        if app.global_shortcut().is_registered(shortcut) {
+            log::warn!("Chord step '{}' is bound elsewhere; skipping", step);
+            continue;
+        }
+        let chord_string = format!("{}{}{}", leader, CHORD_SEPARATOR, step);
+        let result = app
+            .global_shortcut()
+            .on_shortcut(shortcut, move |ah, _scut, event| {
+                if event.state == ShortcutState::Pressed {
+                    let ah = ah.clone();
+                    let binding_id = binding_id.clone();
+                    let chord_string = chord_string.clone();
+                    std::thread::spawn(move || {
+                        cancel_chord(&ah);
+                        fire_chord(&ah, &binding_id, &chord_string);
+                    });
+                }
+            });
+        match result {
+            Ok(()) => registered.push(step),
+            Err(e) => log::warn!("Couldn't register chord step '{}': {}", step, e),
+        }
+    }
+    *PENDING_CHORD.lock().unwrap() = Some((generation, registered));
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(CHORD_TIMEOUT);
+        let expired = PENDING_CHORD
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|(g, _)| *g == generation)
+            .unwrap_or(false);
+        if expired {
+            cancel_chord(&app);
+        }
+    });
+}
+
+/// Release the second steps of a pending chord
+fn cancel_chord(app: &AppHandle) {
+    let Some((_, steps)) = PENDING_CHORD.lock().unwrap().take() else {
+        return;
+    };
+    for step in steps {
+        if let Ok(shortcut) = step.parse::<Shortcut>() {
+            let _ = app.global_shortcut().unregister(shortcut);
+        }
+    }
+}
+
+/// A completed chord is a single key press: instant actions start, others toggle
+fn fire_chord(app: &AppHandle, binding_id: &str, chord_string: &str) {
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        log::warn!("No action defined for chorded shortcut '{}'", binding_id);
+        return;
+    };
+    if action.is_instant() {
+        action.start(app, binding_id, chord_string);
+    } else {
+        toggle_action(app, action.as_ref(), binding_id, chord_string);
+    }
+}
+
+/// Upper bounds for the live-meeting and import worker pools (each 1..=8)
+#[tauri::command]
+pub fn change_queue_worker_counts_setting(app: AppHandle, live: u32, import: u32) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.queue_worker_count = live.clamp(1, 8);
+    s.import_worker_count = import.clamp(1, 8);
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "queue_worker_counts", "value": { "live": live, "import": import } }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_separate_import_model_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.separate_import_model = enabled;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "separate_import_model", "value": enabled }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_gpu_scheduling_setting(app: AppHandle, mode: GpuScheduling) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.gpu_scheduling = mode;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "gpu_scheduling", "value": mode }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_tray_meeting_tooltip_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = settings::get_settings(&app);
+    s.tray_meeting_tooltip = enabled;
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "tray_meeting_tooltip", "value": enabled }),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_marker_label_presets_setting(
+    app: AppHandle,
+    presets: Vec<String>,
+) -> Result<(), String> {
+    let presets: Vec<String> = presets
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let mut s = settings::get_settings(&app);
+    s.marker_label_presets = presets.clone();
+    settings::write_settings(&app, s);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "marker_label_presets", "value": presets }),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chord_parsing_and_validation() {
+        assert_eq!(split_chord("ctrl+shift+m"), Ok(None));
+        assert_eq!(
+            split_chord("option+m s"),
+            Ok(Some(("option+m".to_string(), "s".to_string())))
+        );
+        assert!(split_chord("option+m s p").is_err());
+
+        assert!(validate_shortcut_string("option+m s").is_ok());
+        assert!(validate_shortcut_string("option+m shift").is_err());
+        assert!(validate_shortcut_string("ctrl+shift").is_err());
+        assert!(validate_shortcut_string("").is_err());
+    }
+}
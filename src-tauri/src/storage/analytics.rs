@@ -0,0 +1,214 @@
+//! Conversation analytics of a meeting (talk time, monologues, interruptions, speaking
+//! pace per speaker), computed from its segments and kept in `analytics.json` next to
+//! the transcript.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::managers::meeting::TranscriptSegment;
+
+pub const ANALYTICS_FILE: &str = "analytics.json";
+/// Pauses up to this long (seconds) don't end a monologue
+const MONOLOGUE_GAP: f64 = 2.0;
+/// A speaker change this soon (seconds) after an unfinished sentence counts as cutting in
+const INTERRUPTION_GAP: f64 = 0.3;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerAnalytics {
+    pub speaker: String,
+    pub talk_seconds: f64,
+    /// Fraction (0..1) of all talk time in the meeting
+    pub talk_share: f64,
+    pub word_count: usize,
+    pub words_per_minute: f64,
+    pub segment_count: usize,
+    /// Longest stretch of uninterrupted speech
+    pub longest_monologue_seconds: f64,
+    /// Times this speaker cut someone else off
+    pub interruptions: usize,
+    /// Times someone else cut this speaker off
+    pub interrupted: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MeetingAnalytics {
+    pub meeting_id: String,
+    /// RFC3339
+    pub generated_at: String,
+    pub talk_seconds: f64,
+    pub word_count: usize,
+    pub interruptions: usize,
+    /// Longest monologue of the meeting and who gave it
+    pub longest_monologue_seconds: f64,
+    pub longest_monologue_speaker: Option<String>,
+    /// Most talk time first
+    pub speakers: Vec<SpeakerAnalytics>,
+}
+
+fn ends_sentence(text: &str) -> bool {
+    text.trim_end()
+        .ends_with(|c: char| matches!(c, '.' | '?' | '!' | '…'))
+}
+
+/// Analytics of a meeting's segments. A segment interrupts the one before it when it
+/// starts before that one ends (overlapping capture), or follows an unfinished sentence
+/// by another speaker with almost no pause.
+pub fn compute(meeting_id: &str, segments: &[TranscriptSegment]) -> MeetingAnalytics {
+    let mut ordered: Vec<&TranscriptSegment> = segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .collect();
+    ordered.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+
+    let mut order: Vec<String> = Vec::new();
+    let mut stats: HashMap<String, SpeakerAnalytics> = HashMap::new();
+    // Current monologue: speaker, start, end
+    let mut run: Option<(&str, f64, f64)> = None;
+    let mut previous: Option<&TranscriptSegment> = None;
+
+    for segment in &ordered {
+        let speaker = segment.speaker.as_str();
+        if !stats.contains_key(speaker) {
+            order.push(speaker.to_string());
+        }
+        let entry = stats
+            .entry(speaker.to_string())
+            .or_insert_with(|| SpeakerAnalytics {
+                speaker: speaker.to_string(),
+                ..Default::default()
+            });
+        entry.talk_seconds += (segment.end_time - segment.start_time).max(0.0);
+        entry.word_count += segment.text.split_whitespace().count();
+        entry.segment_count += 1;
+
+        run = match run {
+            Some((current, start, end))
+                if current == speaker && segment.start_time - end <= MONOLOGUE_GAP =>
+            {
+                Some((current, start, end.max(segment.end_time)))
+            }
+            _ => Some((speaker, segment.start_time, segment.end_time)),
+        };
+        if let Some((_, start, end)) = run {
+            entry.longest_monologue_seconds = entry.longest_monologue_seconds.max(end - start);
+        }
+
+        if let Some(prev) = previous.filter(|p| p.speaker != speaker) {
+            let overlaps = segment.start_time < prev.end_time;
+            let cut_off = segment.start_time - prev.end_time <= INTERRUPTION_GAP
+                && !ends_sentence(&prev.text);
+            if overlaps || cut_off {
+                entry.interruptions += 1;
+                if let Some(other) = stats.get_mut(&prev.speaker) {
+                    other.interrupted += 1;
+                }
+            }
+        }
+        previous = Some(segment);
+    }
+
+    let talk_seconds: f64 = stats.values().map(|s| s.talk_seconds).sum();
+    let mut speakers: Vec<SpeakerAnalytics> = order
+        .iter()
+        .filter_map(|name| stats.remove(name))
+        .map(|mut s| {
+            if talk_seconds > 0.0 {
+                s.talk_share = s.talk_seconds / talk_seconds;
+            }
+            if s.talk_seconds > 0.0 {
+                s.words_per_minute = s.word_count as f64 / (s.talk_seconds / 60.0);
+            }
+            s
+        })
+        .collect();
+    speakers.sort_by(|a, b| b.talk_seconds.total_cmp(&a.talk_seconds));
+
+    let longest = speakers.iter().max_by(|a, b| {
+        a.longest_monologue_seconds
+            .total_cmp(&b.longest_monologue_seconds)
+    });
+    MeetingAnalytics {
+        meeting_id: meeting_id.to_string(),
+        generated_at: String::new(),
+        talk_seconds,
+        word_count: speakers.iter().map(|s| s.word_count).sum(),
+        interruptions: speakers.iter().map(|s| s.interruptions).sum(),
+        longest_monologue_seconds: longest.map_or(0.0, |s| s.longest_monologue_seconds),
+        longest_monologue_speaker: longest.map(|s| s.speaker.clone()),
+        speakers,
+    }
+}
+
+/// The meeting's analytics, or None when they haven't been computed yet
+pub fn load(meeting_dir: &Path) -> Result<Option<MeetingAnalytics>> {
+    let path = meeting_dir.join(ANALYTICS_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&std::fs::read(path)?)?))
+}
+
+/// Compute the analytics of a saved meeting and write them to `analytics.json`
+pub fn sync(
+    meeting_dir: &Path,
+    meeting_id: &str,
+    segments: &[TranscriptSegment],
+) -> Result<MeetingAnalytics> {
+    let mut analytics = compute(meeting_id, segments);
+    analytics.generated_at = chrono::Utc::now().to_rfc3339();
+    std::fs::write(
+        meeting_dir.join(ANALYTICS_FILE),
+        serde_json::to_vec_pretty(&analytics)?,
+    )?;
+    Ok(analytics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn segment(speaker: &str, start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time: start,
+            end_time: end,
+            text: text.to_string(),
+            confidence: 0.9,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_talk_time_monologues_and_interruptions() {
+        let segments = vec![
+            segment("Alice", 0.0, 10.0, "We should ship the export first."),
+            segment("Alice", 11.0, 20.0, "Then the importer, because"),
+            segment("Bob", 20.0, 25.0, "Wait, the importer is blocked."),
+            segment("Alice", 24.0, 30.0, "Right, so"),
+        ];
+        let analytics = compute("m-1", &segments);
+
+        assert_eq!(analytics.talk_seconds, 30.0);
+        assert_eq!(analytics.interruptions, 2);
+        assert_eq!(
+            analytics.longest_monologue_speaker.as_deref(),
+            Some("Alice")
+        );
+        assert_eq!(analytics.longest_monologue_seconds, 20.0);
+
+        let alice = &analytics.speakers[0];
+        assert_eq!(alice.speaker, "Alice");
+        assert_eq!(alice.talk_seconds, 25.0);
+        assert_eq!(alice.word_count, 12);
+        assert!((alice.words_per_minute - 28.8).abs() < 1e-9);
+        assert_eq!((alice.interruptions, alice.interrupted), (1, 1));
+        let bob = &analytics.speakers[1];
+        assert_eq!((bob.interruptions, bob.interrupted), (1, 1));
+        assert!((bob.talk_share - 5.0 / 30.0).abs() < 1e-9);
+    }
+}
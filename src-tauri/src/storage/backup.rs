@@ -0,0 +1,355 @@
+use anyhow::{anyhow, Context, Result};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Bumped when the archive layout changes incompatibly
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+/// App data files (settings store, status files) stored under `app/`
+const APP_FILES: &[&str] = &["settings_store.json", "cloud_sync_status.json"];
+/// SQLite databases in app data, snapshotted with `VACUUM INTO`
+const APP_DATABASES: &[&str] = &["history.db", "meetings.db"];
+const APP_PREFIX: &str = "app/";
+const RECORDINGS_PREFIX: &str = "app/recordings/";
+const MEETINGS_PREFIX: &str = "meetings/";
+const PRDS_PREFIX: &str = "prds/";
+/// App data directory where restored settings and databases wait for the next launch
+const PENDING_RESTORE_DIR: &str = "restore_pending";
+
+/// A secret kept in the OS keychain. Only its presence is recorded; values never leave
+/// the keychain, so secrets must be re-entered after restoring on another machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretReference {
+    pub name: String,
+    pub service: String,
+    pub account: String,
+    pub present: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    pub secrets: Vec<SecretReference>,
+    /// Number of files per section (e.g. "meetings" -> 42)
+    pub counts: std::collections::BTreeMap<String, usize>,
+}
+
+/// Where backed-up data lives on this machine
+pub struct BackupSources {
+    pub app_data_dir: PathBuf,
+    /// Transcript storage root (`meetings/` under the storage root)
+    pub meetings_dir: PathBuf,
+    /// Per-meeting PRD directories (`~/.handy/meetings`)
+    pub prds_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RestoreReport {
+    pub files_restored: usize,
+    pub secrets_to_reenter: Vec<String>,
+    /// Settings and databases are re-read on next launch
+    pub restart_required: bool,
+}
+
+fn options() -> FileOptions {
+    FileOptions::default().compression_method(CompressionMethod::Deflated)
+}
+
+/// Add every file under `dir` (recursively) with `prefix` + relative path
+fn add_tree<W: Write + Seek>(zip: &mut ZipWriter<W>, dir: &Path, prefix: &str) -> Result<usize> {
+    fn walk<W: Write + Seek>(
+        zip: &mut ZipWriter<W>,
+        root: &Path,
+        dir: &Path,
+        prefix: &str,
+        count: &mut usize,
+    ) -> Result<()> {
+        let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .collect();
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                walk(zip, root, &path, prefix, count)?;
+            } else if path.is_file() {
+                let relative = path
+                    .strip_prefix(root)?
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                zip.start_file(format!("{}{}", prefix, relative), options())?;
+                std::io::copy(&mut File::open(&path)?, zip)?;
+                *count += 1;
+            }
+        }
+        Ok(())
+    }
+    let mut count = 0;
+    if dir.is_dir() {
+        walk(zip, dir, dir, prefix, &mut count)?;
+    }
+    Ok(count)
+}
+
+/// Consistent copy of a live SQLite database (includes WAL contents)
+fn snapshot_database(db: &Path, dest: &Path) -> Result<()> {
+    let conn = Connection::open(db)?;
+    conn.execute("VACUUM INTO ?1", [dest.to_string_lossy()])?;
+    Ok(())
+}
+
+/// Write a backup archive of settings, databases, recordings, meeting storage, and PRDs.
+/// Models and project/repository checkouts are not included.
+pub fn create_backup(
+    target: &Path,
+    sources: &BackupSources,
+    app_version: &str,
+    secrets: Vec<SecretReference>,
+) -> Result<BackupManifest> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut zip = ZipWriter::new(
+        File::create(target).with_context(|| format!("Failed to create backup {:?}", target))?,
+    );
+    let mut counts = std::collections::BTreeMap::new();
+
+    let mut app_count = 0;
+    for name in APP_FILES {
+        let path = sources.app_data_dir.join(name);
+        if path.is_file() {
+            zip.start_file(format!("{}{}", APP_PREFIX, name), options())?;
+            std::io::copy(&mut File::open(&path)?, &mut zip)?;
+            app_count += 1;
+        }
+    }
+    for name in APP_DATABASES {
+        let path = sources.app_data_dir.join(name);
+        if path.is_file() {
+            // Staged next to the archive; VACUUM INTO refuses to overwrite
+            let snapshot = target.with_file_name(format!(".{}.snapshot", name));
+            let _ = fs::remove_file(&snapshot);
+            snapshot_database(&path, &snapshot)?;
+            zip.start_file(format!("{}{}", APP_PREFIX, name), options())?;
+            let copied = File::open(&snapshot).and_then(|mut f| std::io::copy(&mut f, &mut zip));
+            let _ = fs::remove_file(&snapshot);
+            copied?;
+            app_count += 1;
+        }
+    }
+    counts.insert("app".to_string(), app_count);
+    counts.insert(
+        "recordings".to_string(),
+        add_tree(
+            &mut zip,
+            &sources.app_data_dir.join("recordings"),
+            RECORDINGS_PREFIX,
+        )?,
+    );
+    counts.insert(
+        "meetings".to_string(),
+        add_tree(&mut zip, &sources.meetings_dir, MEETINGS_PREFIX)?,
+    );
+    counts.insert(
+        "prds".to_string(),
+        add_tree(&mut zip, &sources.prds_dir, PRDS_PREFIX)?,
+    );
+
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: app_version.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        secrets,
+        counts,
+    };
+    zip.start_file(MANIFEST_FILE, options())?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+    zip.finish()?;
+    Ok(manifest)
+}
+
+pub fn read_backup_manifest(archive: &Path) -> Result<BackupManifest> {
+    let mut zip =
+        ZipArchive::new(File::open(archive)?).context("Not a backup archive (invalid zip)")?;
+    let mut content = String::new();
+    zip.by_name(MANIFEST_FILE)
+        .context("Not a backup archive (missing manifest.json)")?
+        .read_to_string(&mut content)?;
+    let manifest: BackupManifest =
+        serde_json::from_str(&content).context("Invalid backup manifest")?;
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(anyhow!(
+            "Backup format {} is newer than supported ({}); update MeetingCoder",
+            manifest.format_version,
+            BACKUP_FORMAT_VERSION
+        ));
+    }
+    Ok(manifest)
+}
+
+/// Restore an archive over the current data. Files in the archive replace existing ones;
+/// files not in the archive are left alone.
+pub fn restore_backup(archive: &Path, sources: &BackupSources) -> Result<RestoreReport> {
+    let manifest = read_backup_manifest(archive)?;
+    let mut zip = ZipArchive::new(File::open(archive)?)?;
+    let mut report = RestoreReport {
+        restart_required: true,
+        secrets_to_reenter: manifest
+            .secrets
+            .iter()
+            .filter(|s| s.present)
+            .map(|s| s.name.clone())
+            .collect(),
+        ..Default::default()
+    };
+
+    let pending = sources.app_data_dir.join(PENDING_RESTORE_DIR);
+    if pending.exists() {
+        fs::remove_dir_all(&pending)?;
+    }
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+        // Security: enclosed_name rejects absolute paths and `..` components
+        let Some(name) = entry
+            .enclosed_name()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+        else {
+            log::warn!("Skipping unsafe backup entry: {}", entry.name());
+            continue;
+        };
+        let target = if let Some(rest) = name.strip_prefix(RECORDINGS_PREFIX) {
+            sources.app_data_dir.join("recordings").join(rest)
+        } else if let Some(rest) = name.strip_prefix(APP_PREFIX) {
+            if !APP_FILES.contains(&rest) && !APP_DATABASES.contains(&rest) {
+                continue;
+            }
+            // The running app holds these open, so they are swapped in at next launch
+            pending.join(rest)
+        } else if let Some(rest) = name.strip_prefix(MEETINGS_PREFIX) {
+            sources.meetings_dir.join(rest)
+        } else if let Some(rest) = name.strip_prefix(PRDS_PREFIX) {
+            sources.prds_dir.join(rest)
+        } else {
+            continue;
+        };
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out =
+            File::create(&target).with_context(|| format!("Failed to write {:?}", target))?;
+        std::io::copy(&mut entry, &mut out)?;
+        report.files_restored += 1;
+    }
+    Ok(report)
+}
+
+/// Move settings and databases staged by `restore_backup` over the live copies. Must run
+/// before the settings store or any database is opened. Returns the number of files moved.
+pub fn apply_pending_restore(app_data_dir: &Path) -> Result<usize> {
+    let pending = app_data_dir.join(PENDING_RESTORE_DIR);
+    if !pending.is_dir() {
+        return Ok(0);
+    }
+    let mut applied = 0;
+    for name in APP_FILES.iter().chain(APP_DATABASES) {
+        let staged = pending.join(name);
+        if !staged.is_file() {
+            continue;
+        }
+        if APP_DATABASES.contains(name) {
+            // Stale WAL files would be replayed over the restored database
+            for suffix in ["-wal", "-shm"] {
+                let _ = fs::remove_file(app_data_dir.join(format!("{}{}", name, suffix)));
+            }
+        }
+        let target = app_data_dir.join(name);
+        fs::rename(&staged, &target)
+            .with_context(|| format!("Failed to move {:?} into place", staged))?;
+        applied += 1;
+    }
+    fs::remove_dir_all(&pending)?;
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sources(root: &Path) -> BackupSources {
+        BackupSources {
+            app_data_dir: root.join("app"),
+            meetings_dir: root.join("MeetingCoder/meetings"),
+            prds_dir: root.join(".handy/meetings"),
+        }
+    }
+
+    #[test]
+    fn test_backup_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let src = sources(&temp_dir.path().join("old"));
+        fs::create_dir_all(src.app_data_dir.join("recordings")).unwrap();
+        fs::write(
+            src.app_data_dir.join("settings_store.json"),
+            "{\"settings\":{}}",
+        )
+        .unwrap();
+        fs::write(src.app_data_dir.join("recordings/handy-1.wav"), "RIFF").unwrap();
+        let conn = Connection::open(src.app_data_dir.join("history.db")).unwrap();
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL; CREATE TABLE t (v TEXT); INSERT INTO t VALUES ('kept');",
+        )
+        .unwrap();
+        fs::create_dir_all(src.meetings_dir.join("2026-01-01_planning")).unwrap();
+        fs::write(
+            src.meetings_dir.join("2026-01-01_planning/transcript.json"),
+            "{}",
+        )
+        .unwrap();
+        fs::create_dir_all(src.prds_dir.join("m-1/prds")).unwrap();
+        fs::write(src.prds_dir.join("m-1/prds/v1_initial.md"), "# PRD").unwrap();
+
+        let archive = temp_dir.path().join("backup.zip");
+        let secrets = vec![SecretReference {
+            name: "GitHub token".to_string(),
+            service: "com.handy.github".to_string(),
+            account: "github_token".to_string(),
+            present: true,
+        }];
+        let manifest = create_backup(&archive, &src, "0.0.0", secrets).unwrap();
+        assert_eq!(manifest.counts["meetings"], 1);
+        assert_eq!(manifest.counts["app"], 2);
+
+        let dest = sources(&temp_dir.path().join("new"));
+        let report = restore_backup(&archive, &dest).unwrap();
+        assert_eq!(report.files_restored, 5);
+        assert_eq!(report.secrets_to_reenter, vec!["GitHub token"]);
+        assert!(dest
+            .meetings_dir
+            .join("2026-01-01_planning/transcript.json")
+            .exists());
+        assert!(dest.prds_dir.join("m-1/prds/v1_initial.md").exists());
+        // Databases and settings wait until the next launch
+        assert!(!dest.app_data_dir.join("history.db").exists());
+        assert_eq!(apply_pending_restore(&dest.app_data_dir).unwrap(), 2);
+        assert!(!dest.app_data_dir.join(PENDING_RESTORE_DIR).exists());
+        assert!(dest.app_data_dir.join("settings_store.json").exists());
+        assert_eq!(apply_pending_restore(&dest.app_data_dir).unwrap(), 0);
+        let restored = Connection::open(dest.app_data_dir.join("history.db")).unwrap();
+        let value: String = restored
+            .query_row("SELECT v FROM t", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(value, "kept");
+    }
+}
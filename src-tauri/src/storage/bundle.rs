@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::storage::db::MeetingMarker;
+
+/// Bumped when the bundle layout changes incompatibly
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+const MARKERS_FILE: &str = "markers.json";
+const TRANSCRIPT_PREFIX: &str = "transcript/";
+const PRDS_PREFIX: &str = "prds/";
+/// Audio files left out unless explicitly requested (they dominate bundle size)
+const AUDIO_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg"];
+
+/// Describes the meeting inside a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub meeting_id: String,
+    pub name: String,
+    /// Transcript directory name on the exporting machine
+    pub dir_name: String,
+    pub exported_at: String,
+    pub includes_audio: bool,
+}
+
+fn is_audio(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| AUDIO_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Add every regular file in `dir` (non-recursive) under `prefix`
+fn add_dir<W: Write + std::io::Seek>(
+    zip: &mut ZipWriter<W>,
+    dir: &Path,
+    prefix: &str,
+    include_audio: bool,
+) -> Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && (include_audio || !is_audio(p)))
+        .collect();
+    entries.sort();
+    for path in entries {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        // Audio is already compressed or large PCM; storing it avoids slow deflate passes
+        let method = if is_audio(&path) {
+            CompressionMethod::Stored
+        } else {
+            CompressionMethod::Deflated
+        };
+        zip.start_file(
+            format!("{}{}", prefix, name),
+            FileOptions::default().compression_method(method),
+        )?;
+        let mut file = File::open(&path)?;
+        std::io::copy(&mut file, zip)?;
+    }
+    Ok(())
+}
+
+/// Write a meeting bundle zip containing the transcript directory (metadata, transcript,
+/// summary, and optionally audio), PRD versions, and markers
+pub fn write_bundle(
+    destination: &Path,
+    manifest: &BundleManifest,
+    transcript_dir: &Path,
+    prd_dir: Option<&Path>,
+    markers: &[MeetingMarker],
+) -> Result<()> {
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = File::create(destination)
+        .with_context(|| format!("Failed to create bundle {:?}", destination))?;
+    let mut zip = ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file(MANIFEST_FILE, options)?;
+    zip.write_all(serde_json::to_string_pretty(manifest)?.as_bytes())?;
+    zip.start_file(MARKERS_FILE, options)?;
+    zip.write_all(serde_json::to_string_pretty(markers)?.as_bytes())?;
+
+    add_dir(&mut zip, transcript_dir, TRANSCRIPT_PREFIX, manifest.includes_audio)?;
+    if let Some(prd_dir) = prd_dir {
+        add_dir(&mut zip, prd_dir, PRDS_PREFIX, false)?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// Read and validate the manifest of a bundle
+pub fn read_bundle_manifest(bundle: &Path) -> Result<BundleManifest> {
+    let mut archive = ZipArchive::new(File::open(bundle)?).context("Not a meeting bundle (invalid zip)")?;
+    let mut content = String::new();
+    archive
+        .by_name(MANIFEST_FILE)
+        .context("Not a meeting bundle (missing manifest.json)")?
+        .read_to_string(&mut content)?;
+    let manifest: BundleManifest = serde_json::from_str(&content).context("Invalid bundle manifest")?;
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(anyhow!(
+            "Bundle format {} is newer than supported ({}); update MeetingCoder",
+            manifest.format_version,
+            BUNDLE_FORMAT_VERSION
+        ));
+    }
+    Ok(manifest)
+}
+
+/// Markers recorded in a bundle (empty for bundles without any)
+pub fn read_bundle_markers(bundle: &Path) -> Result<Vec<MeetingMarker>> {
+    let mut archive = ZipArchive::new(File::open(bundle)?)?;
+    let mut content = String::new();
+    match archive.by_name(MARKERS_FILE) {
+        Ok(mut entry) => entry.read_to_string(&mut content)?,
+        Err(_) => return Ok(Vec::new()),
+    };
+    Ok(serde_json::from_str(&content).unwrap_or_default())
+}
+
+/// Extract transcript files into `transcript_dir` and PRD files into `prd_dir`.
+/// Entries with paths outside those prefixes or containing traversal are skipped.
+pub fn extract_bundle(bundle: &Path, transcript_dir: &Path, prd_dir: &Path) -> Result<usize> {
+    let mut archive = ZipArchive::new(File::open(bundle)?)?;
+    let mut extracted = 0;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        // Security: enclosed_name rejects absolute paths and `..` components
+        let Some(name) = entry.enclosed_name().map(|p| p.to_path_buf()) else {
+            log::warn!("Skipping unsafe bundle entry: {}", entry.name());
+            continue;
+        };
+        let name = name.to_string_lossy().replace('\\', "/");
+        let (target_dir, file_name) = if let Some(rest) = name.strip_prefix(TRANSCRIPT_PREFIX) {
+            (transcript_dir, rest.to_string())
+        } else if let Some(rest) = name.strip_prefix(PRDS_PREFIX) {
+            (prd_dir, rest.to_string())
+        } else {
+            continue;
+        };
+        if file_name.is_empty() || file_name.contains('/') || entry.is_dir() {
+            continue;
+        }
+        fs::create_dir_all(target_dir)?;
+        let mut out = File::create(target_dir.join(&file_name))?;
+        std::io::copy(&mut entry, &mut out)?;
+        extracted += 1;
+    }
+    Ok(extracted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let transcript_dir = root.join("meetings/2026-01-01_planning");
+        let prd_dir = root.join("prds");
+        fs::create_dir_all(&transcript_dir).unwrap();
+        fs::create_dir_all(&prd_dir).unwrap();
+        fs::write(transcript_dir.join("metadata.json"), "{}").unwrap();
+        fs::write(transcript_dir.join("summary.md"), "# Summary").unwrap();
+        fs::write(transcript_dir.join("full_audio.wav"), "RIFF").unwrap();
+        fs::write(prd_dir.join("v1_initial.md"), "# PRD").unwrap();
+
+        let manifest = BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            meeting_id: "m-1".to_string(),
+            name: "Planning".to_string(),
+            dir_name: "2026-01-01_planning".to_string(),
+            exported_at: String::new(),
+            includes_audio: false,
+        };
+        let bundle = root.join("out/planning.zip");
+        write_bundle(&bundle, &manifest, &transcript_dir, Some(&prd_dir), &[]).unwrap();
+
+        assert_eq!(read_bundle_manifest(&bundle).unwrap().meeting_id, "m-1");
+        assert!(read_bundle_markers(&bundle).unwrap().is_empty());
+
+        let target = root.join("imported");
+        let count = extract_bundle(&bundle, &target.join("t"), &target.join("p")).unwrap();
+        assert_eq!(count, 3);
+        assert!(target.join("t/summary.md").exists());
+        assert!(!target.join("t/full_audio.wav").exists());
+        assert_eq!(fs::read_to_string(target.join("p/v1_initial.md")).unwrap(), "# PRD");
+    }
+
+    #[test]
+    fn test_rejects_non_bundle_zip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("other.zip");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        zip.start_file("readme.txt", FileOptions::default()).unwrap();
+        zip.finish().unwrap();
+        assert!(read_bundle_manifest(&path).is_err());
+    }
+}
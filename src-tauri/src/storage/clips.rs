@@ -0,0 +1,271 @@
+//! Highlight clips: a time range of a meeting's recording cut into a shareable audio file,
+//! with a Markdown snippet of what was said in it.
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::managers::meeting::TranscriptSegment;
+use crate::storage::db::MeetingMarker;
+
+/// The meeting a clip is cut from
+pub struct ClipSource<'a> {
+    pub meeting_id: &'a str,
+    pub title: &'a str,
+    /// Shown under the heading, e.g. "2026-03-04 10:00"
+    pub date: &'a str,
+    pub segments: &'a [TranscriptSegment],
+    pub markers: &'a [MeetingMarker],
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClipExport {
+    pub audio_path: PathBuf,
+    /// "mp3", or "wav" when ffmpeg isn't available to encode the clip
+    pub audio_format: String,
+    pub markdown_path: PathBuf,
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+    pub segment_count: usize,
+}
+
+fn timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+/// Segments overlapping `start..end`
+pub fn clip_segments(
+    segments: &[TranscriptSegment],
+    start: f64,
+    end: f64,
+) -> Vec<&TranscriptSegment> {
+    segments
+        .iter()
+        .filter(|s| s.end_time > start && s.start_time < end)
+        .collect()
+}
+
+/// Markdown snippet for a clip; `audio_file` is linked relative to the snippet
+pub fn render_clip_markdown(source: &ClipSource, start: f64, end: f64, audio_file: &str) -> String {
+    let markers: Vec<&MeetingMarker> = source
+        .markers
+        .iter()
+        .filter(|m| m.at_seconds >= start && m.at_seconds <= end)
+        .collect();
+    // A labelled bookmark in the range names the clip
+    let label = markers
+        .iter()
+        .map(|m| m.label.trim())
+        .find(|l| !l.is_empty())
+        .unwrap_or("Highlight");
+
+    let mut md = String::new();
+    let _ = writeln!(
+        md,
+        "# {}: {} ({}–{})\n",
+        source.title,
+        label,
+        timestamp(start),
+        timestamp(end)
+    );
+    if !source.date.is_empty() {
+        let _ = writeln!(md, "_{}_\n", source.date);
+    }
+    let _ = writeln!(md, "Audio: [{}]({})\n", audio_file, audio_file);
+    let segments = clip_segments(source.segments, start, end);
+    for (i, s) in segments.iter().enumerate() {
+        if i > 0 {
+            let _ = writeln!(md, ">");
+        }
+        let _ = writeln!(
+            md,
+            "> **{}** ({}): {}",
+            s.speaker,
+            timestamp(s.start_time),
+            s.text.trim()
+        );
+    }
+    if !segments.is_empty() {
+        let _ = writeln!(md);
+    }
+    if !markers.is_empty() {
+        let _ = writeln!(md, "## Bookmarks");
+        for m in &markers {
+            let _ = writeln!(md, "- {} {}", timestamp(m.at_seconds), m.label.trim());
+        }
+        let _ = writeln!(md);
+    }
+    let _ = writeln!(
+        md,
+        "Open the meeting: meetingcoder://open-meeting/{}",
+        source.meeting_id
+    );
+    md
+}
+
+/// Copy `start..end` of a WAV file into a new WAV with the same format
+pub fn cut_wav(src: &Path, dest: &Path, start: f64, end: f64) -> Result<()> {
+    let mut reader =
+        hound::WavReader::open(src).with_context(|| format!("Failed to open {:?}", src))?;
+    let spec = reader.spec();
+    let frames = reader.duration();
+    let to_frame = |seconds: f64| ((seconds.max(0.0) * spec.sample_rate as f64) as u32).min(frames);
+    let (first, last) = (to_frame(start), to_frame(end));
+    if last <= first {
+        return Err(anyhow!("The clip range is outside the recording"));
+    }
+    reader.seek(first)?;
+    let count = (last - first) as usize * spec.channels as usize;
+    let mut writer = hound::WavWriter::create(dest, spec)?;
+    match spec.sample_format {
+        hound::SampleFormat::Float => {
+            for s in reader.samples::<f32>().take(count) {
+                writer.write_sample(s?)?;
+            }
+        }
+        hound::SampleFormat::Int => {
+            for s in reader.samples::<i32>().take(count) {
+                writer.write_sample(s?)?;
+            }
+        }
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Encode a WAV file as MP3 with ffmpeg
+fn encode_mp3(wav: &Path, mp3: &Path) -> Result<()> {
+    // ffmpeg -v error -y -i <in.wav> -codec:a libmp3lame -q:a 4 <out.mp3>
+    let output = std::process::Command::new("ffmpeg")
+        .arg("-v")
+        .arg("error")
+        .arg("-y")
+        .arg("-i")
+        .arg(wav)
+        .args(["-codec:a", "libmp3lame", "-q:a", "4"])
+        .arg(mp3)
+        .output()
+        .context("Failed to execute ffmpeg")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "ffmpeg returned error code {:?}: {}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Cut `start..end` of `audio_path` into `dir/<base_name>.mp3` (or `.wav` without ffmpeg)
+/// and write the transcript excerpt next to it as `dir/<base_name>.md`
+pub fn export_clip(
+    source: &ClipSource,
+    audio_path: &Path,
+    dir: &Path,
+    base_name: &str,
+    start: f64,
+    end: f64,
+) -> Result<ClipExport> {
+    std::fs::create_dir_all(dir)?;
+    let wav_path = dir.join(format!("{}.wav", base_name));
+    cut_wav(audio_path, &wav_path, start, end)?;
+    let mp3_path = dir.join(format!("{}.mp3", base_name));
+    let (audio_path, audio_format) = match encode_mp3(&wav_path, &mp3_path) {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&wav_path);
+            (mp3_path, "mp3")
+        }
+        Err(e) => {
+            log::warn!("Clip MP3 encoding failed, keeping WAV: {}", e);
+            let _ = std::fs::remove_file(&mp3_path);
+            (wav_path, "wav")
+        }
+    };
+    let audio_file = audio_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let markdown_path = dir.join(format!("{}.md", base_name));
+    std::fs::write(
+        &markdown_path,
+        render_clip_markdown(source, start, end, &audio_file),
+    )?;
+    Ok(ClipExport {
+        audio_path,
+        audio_format: audio_format.to_string(),
+        markdown_path,
+        start_seconds: start,
+        end_seconds: end,
+        segment_count: clip_segments(source.segments, start, end).len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+    use tempfile::TempDir;
+
+    fn segment(speaker: &str, start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time: start,
+            end_time: end,
+            text: text.to_string(),
+            confidence: 1.0,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_clip_markdown_and_audio() {
+        let segments = vec![
+            segment("Alice", 0.0, 1.0, "Hi all"),
+            segment("Bob", 1.0, 2.5, " We'll use SQLite "),
+            segment("Alice", 2.5, 4.0, "Agreed"),
+        ];
+        let markers = vec![MeetingMarker {
+            id: 1,
+            meeting_id: "m-1".to_string(),
+            at_seconds: 1.2,
+            label: "decision".to_string(),
+            created_at: 0,
+        }];
+        let source = ClipSource {
+            meeting_id: "m-1",
+            title: "Planning",
+            date: "2026-03-04 10:00",
+            segments: &segments,
+            markers: &markers,
+        };
+        let md = render_clip_markdown(&source, 1.0, 2.0, "clip.mp3");
+        assert!(md.starts_with("# Planning: decision (00:01–00:02)\n"));
+        assert!(md.contains("> **Bob** (00:01): We'll use SQLite\n\n"));
+        assert!(!md.contains("Hi all") && !md.contains("Agreed"));
+        assert!(md.contains("## Bookmarks\n- 00:01 decision\n"));
+
+        let temp_dir = TempDir::new().unwrap();
+        let src = temp_dir.path().join("full_audio.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&src, spec).unwrap();
+        for i in 0..64_000 {
+            writer.write_sample((i % 100) as i16).unwrap();
+        }
+        writer.finalize().unwrap();
+        let dest = temp_dir.path().join("clip.wav");
+        cut_wav(&src, &dest, 1.0, 2.0).unwrap();
+        let mut reader = hound::WavReader::open(&dest).unwrap();
+        assert_eq!(reader.duration(), 16_000);
+        assert_eq!(reader.samples::<i16>().next().unwrap().unwrap(), 0);
+        assert!(cut_wav(&src, &dest, 5.0, 6.0).is_err());
+    }
+}
@@ -0,0 +1,984 @@
+use crate::managers::meeting::{MeetingSession, TranscriptSegment};
+use crate::storage::transcript::TranscriptStorage;
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_sql::{Migration, MigrationKind};
+
+/// Meetings, transcript segments, markers, and summaries
+const SCHEMA_V1: &str = r#"
+CREATE TABLE IF NOT EXISTS meetings (
+  id TEXT PRIMARY KEY,
+  name TEXT NOT NULL,
+  start_time INTEGER NOT NULL,
+  end_time INTEGER,
+  participants TEXT NOT NULL DEFAULT '[]',
+  project_path TEXT,
+  dir_name TEXT,
+  updated_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS segments (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  meeting_id TEXT NOT NULL REFERENCES meetings(id) ON DELETE CASCADE,
+  seq INTEGER NOT NULL,
+  speaker TEXT NOT NULL,
+  start_time REAL NOT NULL,
+  end_time REAL NOT NULL,
+  text TEXT NOT NULL,
+  confidence REAL NOT NULL,
+  timestamp INTEGER NOT NULL,
+  UNIQUE(meeting_id, seq)
+);
+CREATE INDEX IF NOT EXISTS idx_segments_meeting ON segments(meeting_id, start_time);
+CREATE TABLE IF NOT EXISTS markers (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  meeting_id TEXT NOT NULL REFERENCES meetings(id) ON DELETE CASCADE,
+  at_seconds REAL NOT NULL,
+  label TEXT NOT NULL,
+  created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_markers_meeting ON markers(meeting_id);
+CREATE TABLE IF NOT EXISTS summaries (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  meeting_id TEXT NOT NULL REFERENCES meetings(id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  content TEXT NOT NULL,
+  created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_summaries_meeting ON summaries(meeting_id);
+"#;
+
+/// Full-text index over segment text, kept in sync with `segments` by triggers
+const SCHEMA_V2: &str = r#"
+CREATE VIRTUAL TABLE IF NOT EXISTS segments_fts USING fts5(
+  text, speaker, content='segments', content_rowid='id', tokenize='porter unicode61'
+);
+CREATE TRIGGER IF NOT EXISTS segments_fts_insert AFTER INSERT ON segments BEGIN
+  INSERT INTO segments_fts(rowid, text, speaker) VALUES (new.id, new.text, new.speaker);
+END;
+CREATE TRIGGER IF NOT EXISTS segments_fts_delete AFTER DELETE ON segments BEGIN
+  INSERT INTO segments_fts(segments_fts, rowid, text, speaker) VALUES ('delete', old.id, old.text, old.speaker);
+END;
+CREATE TRIGGER IF NOT EXISTS segments_fts_update AFTER UPDATE ON segments BEGIN
+  INSERT INTO segments_fts(segments_fts, rowid, text, speaker) VALUES ('delete', old.id, old.text, old.speaker);
+  INSERT INTO segments_fts(rowid, text, speaker) VALUES (new.id, new.text, new.speaker);
+END;
+INSERT INTO segments_fts(segments_fts) VALUES ('rebuild');
+"#;
+
+/// Starred meetings are exempt from retention cleanup
+const SCHEMA_V3: &str = r#"
+ALTER TABLE meetings ADD COLUMN starred INTEGER NOT NULL DEFAULT 0;
+"#;
+
+/// Anonymous local usage and performance samples, see `crate::metrics`
+const SCHEMA_V4: &str = r#"
+CREATE TABLE IF NOT EXISTS metrics (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  kind TEXT NOT NULL,
+  value REAL NOT NULL,
+  recorded_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_metrics_kind_time ON metrics(kind, recorded_at);
+"#;
+
+/// Tasks and cards created in other tools from a meeting's items, so re-syncing updates
+/// nothing twice and each item can be traced to what it became
+const SCHEMA_V5: &str = r#"
+CREATE TABLE IF NOT EXISTS external_links (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  meeting_id TEXT NOT NULL REFERENCES meetings(id) ON DELETE CASCADE,
+  service TEXT NOT NULL,
+  item_id TEXT NOT NULL,
+  external_id TEXT NOT NULL,
+  url TEXT,
+  created_at INTEGER NOT NULL,
+  UNIQUE(meeting_id, service, item_id)
+);
+"#;
+
+/// Outgoing webhook deliveries, the log behind `list_webhook_deliveries`
+const SCHEMA_V6: &str = r#"
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+  id TEXT PRIMARY KEY,
+  endpoint_id TEXT NOT NULL,
+  url TEXT NOT NULL,
+  event TEXT NOT NULL,
+  payload TEXT NOT NULL,
+  status TEXT NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  response_status INTEGER,
+  error TEXT,
+  created_at INTEGER NOT NULL,
+  updated_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_created ON webhook_deliveries(created_at);
+"#;
+
+/// Per-word timings of a segment as JSON, NULL when the engine gave none
+const SCHEMA_V7: &str = r#"
+ALTER TABLE segments ADD COLUMN words TEXT;
+"#;
+
+/// Schema versions shared by the tauri-plugin-sql migrations and the Rust-side connection
+const MIGRATIONS: &[(i64, &str, &str)] = &[
+    (1, "create_meeting_tables", SCHEMA_V1),
+    (2, "create_segments_fts", SCHEMA_V2),
+    (3, "add_meeting_starred", SCHEMA_V3),
+    (4, "create_metrics", SCHEMA_V4),
+    (5, "create_external_links", SCHEMA_V5),
+    (6, "create_webhook_deliveries", SCHEMA_V6),
+    (7, "add_segment_words", SCHEMA_V7),
+];
+
+/// Columns read by `MeetingDb::meeting_from_row`
+const MEETING_SELECT: &str = "SELECT m.id, m.name, m.start_time, m.end_time, m.participants, m.project_path, m.dir_name,
+        (SELECT COUNT(*) FROM segments s WHERE s.meeting_id = m.id), m.starred
+ FROM meetings m";
+
+/// A meeting row
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredMeeting {
+    pub id: String,
+    pub name: String,
+    /// Unix milliseconds
+    pub start_time: i64,
+    pub end_time: Option<i64>,
+    pub participants: Vec<String>,
+    pub project_path: Option<String>,
+    /// Directory under the transcript storage root, when saved to disk
+    pub dir_name: Option<String>,
+    pub segment_count: i64,
+    pub starred: bool,
+}
+
+/// A user-placed bookmark within a meeting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingMarker {
+    pub id: i64,
+    pub meeting_id: String,
+    pub at_seconds: f64,
+    pub label: String,
+    pub created_at: i64,
+}
+
+/// A stored summary (e.g. the end-of-meeting `summary.md`)
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredSummary {
+    pub id: i64,
+    pub kind: String,
+    pub content: String,
+    pub created_at: i64,
+}
+
+/// Something created in another tool (an Asana task, a Trello card) from a meeting item
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalLink {
+    pub meeting_id: String,
+    /// e.g. "asana", "trello"
+    pub service: String,
+    /// The meeting-side item, e.g. an action item's feature id
+    pub item_id: String,
+    pub external_id: String,
+    pub url: Option<String>,
+    pub created_at: i64,
+}
+
+/// One event sent (or being sent) to a webhook endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub endpoint_id: String,
+    pub url: String,
+    pub event: String,
+    /// The JSON body, exactly as signed
+    pub payload: String,
+    /// "pending", "delivered", or "failed"
+    pub status: String,
+    pub attempts: u32,
+    /// HTTP status of the last attempt, when it got a response
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Result of importing on-disk meetings into the database
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    pub imported: usize,
+    /// Meetings already present in the database
+    pub skipped: usize,
+    /// Directories that could not be read, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+fn to_ms(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+}
+
+fn from_ms(ms: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(ms.max(0) as u64)
+}
+
+/// Optional constraints for transcript search
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TranscriptSearchFilters {
+    pub meeting_id: Option<String>,
+    pub speaker: Option<String>,
+    /// Only meetings starting at or after this time (Unix milliseconds)
+    pub from: Option<i64>,
+    /// Only meetings starting at or before this time (Unix milliseconds)
+    pub to: Option<i64>,
+    /// Maximum number of matching segments (default 100)
+    pub limit: Option<usize>,
+}
+
+/// A matching segment with `<mark>`-highlighted snippet
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentMatch {
+    pub segment_start: f64,
+    pub segment_end: f64,
+    pub speaker: String,
+    pub snippet: String,
+}
+
+/// A meeting with its matching segments, best matches first
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptSearchHit {
+    pub meeting_id: String,
+    pub meeting_name: String,
+    pub start_time: i64,
+    pub dir_name: Option<String>,
+    pub matches: Vec<SegmentMatch>,
+}
+
+/// Turn free text into an FTS5 query: every word must match, the last as a prefix.
+/// Quoting each term keeps user punctuation from being parsed as FTS syntax.
+pub fn fts_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|t| t.replace('"', ""))
+        .filter(|t| !t.is_empty())
+        .map(|t| format!("\"{}\"", t))
+        .collect();
+    let mut query = terms.join(" ");
+    if query.is_empty() {
+        return None;
+    }
+    query.push('*');
+    Some(query)
+}
+
+/// SQLite store for meetings, transcript segments, markers, and summaries
+pub struct MeetingDb {
+    db_path: PathBuf,
+}
+
+impl MeetingDb {
+    pub fn new(app: &AppHandle) -> Result<Arc<Self>> {
+        let db_path = app.path().app_data_dir()?.join("meetings.db");
+        Ok(Arc::new(Self::open_at(db_path)?))
+    }
+
+    /// Open (creating if needed) a database at an explicit path
+    pub fn open_at(db_path: PathBuf) -> Result<Self> {
+        fs::create_dir_all(db_path.parent().unwrap_or_else(|| Path::new(".")))?;
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        let current: i64 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
+        for &(version, description, sql) in MIGRATIONS.iter().filter(|(v, _, _)| *v > current) {
+            conn.execute_batch(sql)?;
+            conn.execute_batch(&format!("PRAGMA user_version = {};", version))?;
+            log::debug!("Applied meeting database migration {} ({})", version, description);
+        }
+        Ok(Self { db_path })
+    }
+
+    pub fn get_migrations() -> Vec<Migration> {
+        MIGRATIONS
+            .iter()
+            .map(|&(version, description, sql)| Migration {
+                version,
+                description,
+                sql,
+                kind: MigrationKind::Up,
+            })
+            .collect()
+    }
+
+    fn open(&self) -> Result<Connection> {
+        let conn = Connection::open(&self.db_path)?;
+        conn.execute_batch("PRAGMA foreign_keys=ON;")?;
+        Ok(conn)
+    }
+
+    fn now_ms() -> i64 {
+        to_ms(SystemTime::now())
+    }
+
+    /// Insert or update a meeting and replace its segments
+    pub fn save_meeting(&self, meeting: &MeetingSession, dir_name: Option<&str>) -> Result<()> {
+        let mut conn = self.open()?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT INTO meetings (id, name, start_time, end_time, participants, project_path, dir_name, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET name=excluded.name, start_time=excluded.start_time,
+               end_time=excluded.end_time, participants=excluded.participants,
+               project_path=excluded.project_path, dir_name=COALESCE(excluded.dir_name, meetings.dir_name),
+               updated_at=excluded.updated_at",
+            params![
+                meeting.id,
+                meeting.name,
+                to_ms(meeting.start_time),
+                meeting.end_time.map(to_ms),
+                serde_json::to_string(&meeting.participants)?,
+                meeting.project_path,
+                dir_name,
+                Self::now_ms(),
+            ],
+        )?;
+        tx.execute("DELETE FROM segments WHERE meeting_id = ?1", params![meeting.id])?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO segments (meeting_id, seq, speaker, start_time, end_time, text, confidence, timestamp, words)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            )?;
+            for (seq, s) in meeting.transcript_segments.iter().enumerate() {
+                stmt.execute(params![
+                    meeting.id,
+                    seq as i64,
+                    s.speaker,
+                    s.start_time,
+                    s.end_time,
+                    s.text,
+                    s.confidence as f64,
+                    to_ms(s.timestamp),
+                    (!s.words.is_empty()).then(|| serde_json::to_string(&s.words)).transpose()?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn meeting_exists(&self, meeting_id: &str) -> Result<bool> {
+        let conn = self.open()?;
+        let found: Option<i64> = conn
+            .query_row("SELECT 1 FROM meetings WHERE id = ?1", params![meeting_id], |r| r.get(0))
+            .optional()?;
+        Ok(found.is_some())
+    }
+
+    fn meeting_from_row(r: &rusqlite::Row) -> rusqlite::Result<StoredMeeting> {
+        let participants: String = r.get(4)?;
+        Ok(StoredMeeting {
+            id: r.get(0)?,
+            name: r.get(1)?,
+            start_time: r.get(2)?,
+            end_time: r.get(3)?,
+            participants: serde_json::from_str(&participants).unwrap_or_default(),
+            project_path: r.get(5)?,
+            dir_name: r.get(6)?,
+            segment_count: r.get(7)?,
+            starred: r.get::<_, i64>(8)? != 0,
+        })
+    }
+
+    /// All meetings, most recent first
+    pub fn list_meetings(&self) -> Result<Vec<StoredMeeting>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(&format!("{} ORDER BY m.start_time DESC", MEETING_SELECT))?;
+        let rows = stmt.query_map([], Self::meeting_from_row)?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn get_meeting(&self, meeting_id: &str) -> Result<Option<StoredMeeting>> {
+        let conn = self.open()?;
+        Ok(conn
+            .query_row(
+                &format!("{} WHERE m.id = ?1", MEETING_SELECT),
+                params![meeting_id],
+                Self::meeting_from_row,
+            )
+            .optional()?)
+    }
+
+    /// Transcript segments of a meeting in order
+    pub fn get_segments(&self, meeting_id: &str) -> Result<Vec<TranscriptSegment>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT speaker, start_time, end_time, text, confidence, timestamp, words
+             FROM segments WHERE meeting_id = ?1 ORDER BY seq",
+        )?;
+        let rows = stmt.query_map(params![meeting_id], |r| {
+            Ok(TranscriptSegment {
+                speaker: r.get(0)?,
+                start_time: r.get(1)?,
+                end_time: r.get(2)?,
+                text: r.get(3)?,
+                confidence: r.get::<_, f64>(4)? as f32,
+                timestamp: from_ms(r.get(5)?),
+                words: r
+                    .get::<_, Option<String>>(6)?
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or_default(),
+                translated_text: None,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Remove a meeting saved under `dir_name` (segments, markers, and summaries cascade)
+    pub fn delete_by_dir_name(&self, dir_name: &str) -> Result<usize> {
+        let conn = self.open()?;
+        Ok(conn.execute("DELETE FROM meetings WHERE dir_name = ?1", params![dir_name])?)
+    }
+
+    /// Delete a meeting and (via cascade) its segments, markers, and summaries
+    pub fn delete_meeting(&self, meeting_id: &str) -> Result<usize> {
+        let conn = self.open()?;
+        Ok(conn.execute("DELETE FROM meetings WHERE id = ?1", params![meeting_id])?)
+    }
+
+    /// Point a meeting at its project directory (where its audio segments live)
+    pub fn set_project_path(&self, meeting_id: &str, project_path: &str) -> Result<bool> {
+        let conn = self.open()?;
+        let changed = conn.execute(
+            "UPDATE meetings SET project_path = ?2 WHERE id = ?1",
+            params![meeting_id, project_path],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Problems reported by SQLite's integrity and foreign key checks (empty when healthy)
+    pub fn integrity_check(&self) -> Result<Vec<String>> {
+        let conn = self.open()?;
+        let mut problems = crate::storage::integrity::sqlite_integrity(&conn)?;
+        let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+        let rows = stmt.query_map([], |r| {
+            Ok(format!(
+                "{} row {} references missing {}",
+                r.get::<_, String>(0)?,
+                r.get::<_, Option<i64>>(1)?.unwrap_or(0),
+                r.get::<_, String>(2)?
+            ))
+        })?;
+        for row in rows {
+            problems.push(row?);
+        }
+        Ok(problems)
+    }
+
+    /// Rebuild the full-text index and all b-tree indexes
+    pub fn rebuild_index(&self) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute_batch("INSERT INTO segments_fts(segments_fts) VALUES ('rebuild'); REINDEX;")?;
+        Ok(())
+    }
+
+    pub fn vacuum(&self) -> Result<()> {
+        self.open()?.execute_batch("VACUUM;")?;
+        Ok(())
+    }
+
+    /// Star or unstar a meeting; returns false if the meeting is unknown
+    pub fn set_starred(&self, meeting_id: &str, starred: bool) -> Result<bool> {
+        let conn = self.open()?;
+        let changed = conn.execute(
+            "UPDATE meetings SET starred = ?2 WHERE id = ?1",
+            params![meeting_id, starred as i64],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Full-text search over segment text, grouped by meeting in order of best match
+    pub fn search_transcripts(
+        &self,
+        query: &str,
+        filters: &TranscriptSearchFilters,
+    ) -> Result<Vec<TranscriptSearchHit>> {
+        let Some(fts) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT m.id, m.name, m.start_time, m.dir_name, s.start_time, s.end_time, s.speaker,
+                    snippet(segments_fts, 0, '<mark>', '</mark>', '…', 12)
+             FROM segments_fts
+             JOIN segments s ON s.id = segments_fts.rowid
+             JOIN meetings m ON m.id = s.meeting_id
+             WHERE segments_fts MATCH ?1
+               AND (?2 IS NULL OR m.id = ?2)
+               AND (?3 IS NULL OR s.speaker = ?3)
+               AND (?4 IS NULL OR m.start_time >= ?4)
+               AND (?5 IS NULL OR m.start_time <= ?5)
+             ORDER BY bm25(segments_fts)
+             LIMIT ?6",
+        )?;
+        let rows = stmt.query_map(
+            params![
+                fts,
+                filters.meeting_id,
+                filters.speaker,
+                filters.from,
+                filters.to,
+                filters.limit.unwrap_or(100) as i64,
+            ],
+            |r| {
+                Ok((
+                    r.get::<_, String>(0)?,
+                    r.get::<_, String>(1)?,
+                    r.get::<_, i64>(2)?,
+                    r.get::<_, Option<String>>(3)?,
+                    SegmentMatch {
+                        segment_start: r.get(4)?,
+                        segment_end: r.get(5)?,
+                        speaker: r.get(6)?,
+                        snippet: r.get(7)?,
+                    },
+                ))
+            },
+        )?;
+
+        let mut hits: Vec<TranscriptSearchHit> = Vec::new();
+        for row in rows {
+            let (meeting_id, meeting_name, start_time, dir_name, segment) = row?;
+            match hits.iter_mut().find(|h| h.meeting_id == meeting_id) {
+                Some(hit) => hit.matches.push(segment),
+                None => hits.push(TranscriptSearchHit {
+                    meeting_id,
+                    meeting_name,
+                    start_time,
+                    dir_name,
+                    matches: vec![segment],
+                }),
+            }
+        }
+        Ok(hits)
+    }
+
+    /// Insert the meeting row if it isn't stored yet, so markers can reference a meeting
+    /// that is still in progress. Existing rows are left untouched.
+    pub fn ensure_meeting(&self, meeting: &MeetingSession) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "INSERT INTO meetings (id, name, start_time, end_time, participants, project_path, dir_name, updated_at)
+             VALUES (?1, ?2, ?3, NULL, ?4, ?5, NULL, ?6)
+             ON CONFLICT(id) DO NOTHING",
+            params![
+                meeting.id,
+                meeting.name,
+                to_ms(meeting.start_time),
+                serde_json::to_string(&meeting.participants)?,
+                meeting.project_path,
+                Self::now_ms(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn add_marker(&self, meeting_id: &str, at_seconds: f64, label: &str) -> Result<i64> {
+        let conn = self.open()?;
+        conn.execute(
+            "INSERT INTO markers (meeting_id, at_seconds, label, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![meeting_id, at_seconds, label, Self::now_ms()],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    pub fn set_marker_label(&self, marker_id: i64, label: &str) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "UPDATE markers SET label = ?1 WHERE id = ?2",
+            params![label, marker_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn list_markers(&self, meeting_id: &str) -> Result<Vec<MeetingMarker>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, meeting_id, at_seconds, label, created_at FROM markers
+             WHERE meeting_id = ?1 ORDER BY at_seconds",
+        )?;
+        let rows = stmt.query_map(params![meeting_id], |r| {
+            Ok(MeetingMarker {
+                id: r.get(0)?,
+                meeting_id: r.get(1)?,
+                at_seconds: r.get(2)?,
+                label: r.get(3)?,
+                created_at: r.get(4)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Store a summary, replacing any previous summary of the same kind
+    pub fn save_summary(&self, meeting_id: &str, kind: &str, content: &str) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "DELETE FROM summaries WHERE meeting_id = ?1 AND kind = ?2",
+            params![meeting_id, kind],
+        )?;
+        conn.execute(
+            "INSERT INTO summaries (meeting_id, kind, content, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![meeting_id, kind, content, Self::now_ms()],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_metric(&self, kind: &str, value: f64) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "INSERT INTO metrics (kind, value, recorded_at) VALUES (?1, ?2, ?3)",
+            params![kind, value, Self::now_ms()],
+        )?;
+        Ok(())
+    }
+
+    /// `(kind, value, recorded_at)` for samples recorded at or after `since_ms`
+    pub fn metric_samples(&self, since_ms: i64) -> Result<Vec<(String, f64, i64)>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT kind, value, recorded_at FROM metrics WHERE recorded_at >= ?1 ORDER BY recorded_at",
+        )?;
+        let rows = stmt.query_map(params![since_ms], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn prune_metrics(&self, before_ms: i64) -> Result<usize> {
+        let conn = self.open()?;
+        Ok(conn.execute("DELETE FROM metrics WHERE recorded_at < ?1", params![before_ms])?)
+    }
+
+    pub fn list_summaries(&self, meeting_id: &str) -> Result<Vec<StoredSummary>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, kind, content, created_at FROM summaries WHERE meeting_id = ?1 ORDER BY created_at",
+        )?;
+        let rows = stmt.query_map(params![meeting_id], |r| {
+            Ok(StoredSummary {
+                id: r.get(0)?,
+                kind: r.get(1)?,
+                content: r.get(2)?,
+                created_at: r.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Record what an item became in another tool, replacing any earlier link
+    pub fn save_external_link(
+        &self,
+        meeting_id: &str,
+        service: &str,
+        item_id: &str,
+        external_id: &str,
+        url: Option<&str>,
+    ) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "INSERT INTO external_links (meeting_id, service, item_id, external_id, url, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(meeting_id, service, item_id) DO UPDATE SET
+               external_id=excluded.external_id, url=excluded.url, created_at=excluded.created_at",
+            params![meeting_id, service, item_id, external_id, url, Self::now_ms()],
+        )?;
+        Ok(())
+    }
+
+    /// A meeting's links to one service, oldest first
+    pub fn list_external_links(&self, meeting_id: &str, service: &str) -> Result<Vec<ExternalLink>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT meeting_id, service, item_id, external_id, url, created_at FROM external_links
+             WHERE meeting_id = ?1 AND service = ?2 ORDER BY id",
+        )?;
+        let rows = stmt.query_map(params![meeting_id, service], |r| {
+            Ok(ExternalLink {
+                meeting_id: r.get(0)?,
+                service: r.get(1)?,
+                item_id: r.get(2)?,
+                external_id: r.get(3)?,
+                url: r.get(4)?,
+                created_at: r.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn insert_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "INSERT INTO webhook_deliveries (id, endpoint_id, url, event, payload, status, attempts,
+               response_status, error, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                delivery.id,
+                delivery.endpoint_id,
+                delivery.url,
+                delivery.event,
+                delivery.payload,
+                delivery.status,
+                delivery.attempts,
+                delivery.response_status,
+                delivery.error,
+                delivery.created_at,
+                delivery.updated_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Record the outcome of a delivery attempt
+    pub fn update_webhook_delivery(
+        &self,
+        id: &str,
+        status: &str,
+        attempts: u32,
+        response_status: Option<u16>,
+        error: Option<&str>,
+    ) -> Result<()> {
+        let conn = self.open()?;
+        conn.execute(
+            "UPDATE webhook_deliveries SET status = ?2, attempts = ?3, response_status = ?4,
+               error = ?5, updated_at = ?6 WHERE id = ?1",
+            params![id, status, attempts, response_status, error, Self::now_ms()],
+        )?;
+        Ok(())
+    }
+
+    fn webhook_delivery_from_row(r: &rusqlite::Row) -> rusqlite::Result<WebhookDelivery> {
+        Ok(WebhookDelivery {
+            id: r.get(0)?,
+            endpoint_id: r.get(1)?,
+            url: r.get(2)?,
+            event: r.get(3)?,
+            payload: r.get(4)?,
+            status: r.get(5)?,
+            attempts: r.get(6)?,
+            response_status: r.get(7)?,
+            error: r.get(8)?,
+            created_at: r.get(9)?,
+            updated_at: r.get(10)?,
+        })
+    }
+
+    pub fn get_webhook_delivery(&self, id: &str) -> Result<Option<WebhookDelivery>> {
+        let conn = self.open()?;
+        Ok(conn
+            .query_row(
+                "SELECT id, endpoint_id, url, event, payload, status, attempts, response_status,
+                   error, created_at, updated_at FROM webhook_deliveries WHERE id = ?1",
+                params![id],
+                Self::webhook_delivery_from_row,
+            )
+            .optional()?)
+    }
+
+    /// Most recent deliveries first
+    pub fn list_webhook_deliveries(&self, limit: usize) -> Result<Vec<WebhookDelivery>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, endpoint_id, url, event, payload, status, attempts, response_status,
+               error, created_at, updated_at FROM webhook_deliveries
+             ORDER BY created_at DESC, rowid DESC LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit as i64], Self::webhook_delivery_from_row)?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Drop all but the `keep` most recent deliveries
+    pub fn prune_webhook_deliveries(&self, keep: usize) -> Result<usize> {
+        let conn = self.open()?;
+        Ok(conn.execute(
+            "DELETE FROM webhook_deliveries WHERE rowid NOT IN
+               (SELECT rowid FROM webhook_deliveries ORDER BY created_at DESC, rowid DESC LIMIT ?1)",
+            params![keep as i64],
+        )?)
+    }
+
+    /// Import meetings saved as `metadata.json`/`transcript.json` (and `summary.md`)
+    /// directories. Meetings already in the database are left untouched.
+    pub fn import_from_disk(&self, storage: &TranscriptStorage, base_path: &Path) -> Result<ImportReport> {
+        let mut report = ImportReport::default();
+        for dir_name in storage.list_meetings()? {
+            match self.import_saved_dir(storage, base_path, &dir_name) {
+                Ok(Some(_)) => report.imported += 1,
+                Ok(None) => report.skipped += 1,
+                Err(e) => report.failed.push((dir_name, e.to_string())),
+            }
+        }
+        log::info!(
+            "Imported {} meetings into SQLite ({} already present, {} failed)",
+            report.imported,
+            report.skipped,
+            report.failed.len()
+        );
+        Ok(report)
+    }
+
+    /// Import one saved transcript directory; returns the meeting id, or `None`
+    /// when the meeting is already in the database
+    pub fn import_saved_dir(
+        &self,
+        storage: &TranscriptStorage,
+        base_path: &Path,
+        dir_name: &str,
+    ) -> Result<Option<String>> {
+        let session = storage.load_session(dir_name)?;
+        if self.meeting_exists(&session.id)? {
+            return Ok(None);
+        }
+        self.save_meeting(&session, Some(dir_name))?;
+        if let Ok(summary) = fs::read_to_string(base_path.join(dir_name).join("summary.md")) {
+            self.save_summary(&session.id, "markdown", &summary)?;
+        }
+        Ok(Some(session.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::meeting::{MeetingStatus, WordTiming};
+    use tempfile::TempDir;
+
+    fn sample_meeting() -> MeetingSession {
+        let start = SystemTime::now();
+        MeetingSession {
+            id: "m-1".to_string(),
+            name: "Planning".to_string(),
+            start_time: start,
+            end_time: Some(start + Duration::from_secs(60)),
+            status: MeetingStatus::Completed,
+            participants: vec!["Speaker 1".to_string()],
+            project_path: None,
+            transcript_segments: vec![TranscriptSegment {
+                speaker: "Speaker 1".to_string(),
+                start_time: 0.0,
+                end_time: 2.0,
+                text: "Let's ship the export".to_string(),
+                confidence: 0.9,
+                timestamp: start,
+                words: Vec::new(),
+                translated_text: None,
+            }],
+            translation_language: None,
+            cloud_asr: false,
+        }
+    }
+
+    #[test]
+    fn test_save_meeting_markers_and_summaries() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = MeetingDb::open_at(temp_dir.path().join("meetings.db")).unwrap();
+
+        let mut meeting = sample_meeting();
+        db.save_meeting(&meeting, None).unwrap();
+        meeting.transcript_segments.push(TranscriptSegment {
+            text: "Agreed".to_string(),
+            start_time: 2.0,
+            end_time: 3.0,
+            words: vec![WordTiming { word: "Agreed".to_string(), start: 2.1, end: 2.8 }],
+            ..meeting.transcript_segments[0].clone()
+        });
+        db.save_meeting(&meeting, Some("2026-01-01_planning")).unwrap();
+
+        let meetings = db.list_meetings().unwrap();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].segment_count, 2);
+        assert_eq!(meetings[0].dir_name.as_deref(), Some("2026-01-01_planning"));
+        let segments = db.get_segments("m-1").unwrap();
+        assert_eq!(segments[1].text, "Agreed");
+        assert_eq!(segments[1].words[0].end, 2.8);
+        assert!(segments[0].words.is_empty());
+
+        db.add_marker("m-1", 1.5, "decision").unwrap();
+        assert_eq!(db.list_markers("m-1").unwrap()[0].label, "decision");
+
+        db.save_summary("m-1", "markdown", "# Old").unwrap();
+        db.save_summary("m-1", "markdown", "# New").unwrap();
+        let summaries = db.list_summaries("m-1").unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].content, "# New");
+
+        db.save_external_link("m-1", "asana", "f1", "111", None).unwrap();
+        db.save_external_link("m-1", "asana", "f1", "222", Some("https://app.asana.com/0/1/222"))
+            .unwrap();
+        let links = db.list_external_links("m-1", "asana").unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].external_id, "222");
+        assert!(db.list_external_links("m-1", "trello").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_markers_on_meeting_in_progress() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = MeetingDb::open_at(temp_dir.path().join("meetings.db")).unwrap();
+        let mut meeting = sample_meeting();
+        meeting.end_time = None;
+
+        db.ensure_meeting(&meeting).unwrap();
+        let id = db.add_marker("m-1", 12.0, "").unwrap();
+        db.set_marker_label(id, "question").unwrap();
+        // Saving the finished meeting keeps the marker and fills in the row
+        meeting.end_time = Some(meeting.start_time + Duration::from_secs(60));
+        db.save_meeting(&meeting, Some("2026-01-01_planning")).unwrap();
+        db.ensure_meeting(&meeting).unwrap();
+
+        assert_eq!(db.list_markers("m-1").unwrap()[0].label, "question");
+        assert!(db.list_meetings().unwrap()[0].end_time.is_some());
+    }
+
+    #[test]
+    fn test_search_transcripts() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = MeetingDb::open_at(temp_dir.path().join("meetings.db")).unwrap();
+        let mut meeting = sample_meeting();
+        db.save_meeting(&meeting, None).unwrap();
+
+        let hits = db.search_transcripts("shipping exp", &TranscriptSearchFilters::default()).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].matches[0].snippet.contains("<mark>export</mark>"));
+
+        // Re-saving replaces indexed segments rather than duplicating them
+        meeting.transcript_segments[0].text = "Let's review the budget".to_string();
+        db.save_meeting(&meeting, None).unwrap();
+        assert!(db.search_transcripts("export", &Default::default()).unwrap().is_empty());
+
+        let filters = TranscriptSearchFilters {
+            speaker: Some("Speaker 2".to_string()),
+            ..Default::default()
+        };
+        assert!(db.search_transcripts("budget", &filters).unwrap().is_empty());
+        assert_eq!(fts_query("  \"quoted\" term "), Some("\"quoted\" \"term\"*".to_string()));
+        assert_eq!(fts_query("   "), None);
+    }
+
+    #[test]
+    fn test_import_from_disk_is_idempotent() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path().join("meetings");
+        let storage = TranscriptStorage::new(base.clone()).unwrap();
+        let dir = storage.save_transcript(&sample_meeting()).unwrap();
+        fs::write(dir.join("summary.md"), "# Meeting Summary").unwrap();
+        fs::create_dir_all(base.join("broken")).unwrap();
+
+        let db = MeetingDb::open_at(temp_dir.path().join("meetings.db")).unwrap();
+        let report = db.import_from_disk(&storage, &base).unwrap();
+        assert_eq!((report.imported, report.skipped, report.failed.len()), (1, 0, 1));
+        assert_eq!(db.get_segments("m-1").unwrap().len(), 1);
+        assert_eq!(db.list_summaries("m-1").unwrap()[0].kind, "markdown");
+
+        let report = db.import_from_disk(&storage, &base).unwrap();
+        assert_eq!((report.imported, report.skipped), (0, 1));
+    }
+}
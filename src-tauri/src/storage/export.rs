@@ -0,0 +1,474 @@
+use anyhow::{anyhow, Result};
+use std::fmt::Write as _;
+use std::io::{Cursor, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::managers::meeting::TranscriptSegment;
+
+/// Supported transcript export formats (also the file extensions)
+pub const EXPORT_FORMATS: &[&str] = &["srt", "vtt", "docx", "pdf", "html"];
+
+/// Consecutive segments by the same speaker, merged for document formats
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeakerTurn {
+    pub speaker: String,
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+pub fn speaker_turns(segments: &[TranscriptSegment]) -> Vec<SpeakerTurn> {
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+    for s in segments {
+        let text = s.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        match turns.last_mut() {
+            Some(turn) if turn.speaker == s.speaker => {
+                turn.end = s.end_time;
+                turn.text.push(' ');
+                turn.text.push_str(text);
+            }
+            _ => turns.push(SpeakerTurn {
+                speaker: s.speaker.clone(),
+                start: s.start_time,
+                end: s.end_time,
+                text: text.to_string(),
+            }),
+        }
+    }
+    turns
+}
+
+/// `HH:MM:SS` + separator + milliseconds (`,` for SRT, `.` for VTT)
+fn cue_time(seconds: f64, separator: char) -> String {
+    let ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        separator,
+        ms % 1000
+    )
+}
+
+/// `H:MM:SS` or `MM:SS` for display next to speaker names
+fn clock(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    if total >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            total / 3600,
+            (total / 60) % 60,
+            total % 60
+        )
+    } else {
+        format!("{:02}:{:02}", total / 60, total % 60)
+    }
+}
+
+fn cue_segments(segments: &[TranscriptSegment]) -> impl Iterator<Item = &TranscriptSegment> {
+    segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty() && s.end_time > s.start_time)
+}
+
+pub fn to_srt(segments: &[TranscriptSegment]) -> String {
+    let mut out = String::new();
+    for (i, s) in cue_segments(segments).enumerate() {
+        let _ = writeln!(
+            out,
+            "{}\n{} --> {}\n{}: {}\n",
+            i + 1,
+            cue_time(s.start_time, ','),
+            cue_time(s.end_time, ','),
+            s.speaker,
+            s.text.trim()
+        );
+    }
+    out
+}
+
+pub fn to_vtt(segments: &[TranscriptSegment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for s in cue_segments(segments) {
+        // Cue text must not contain "-->"; voice tags carry the speaker
+        let _ = writeln!(
+            out,
+            "{} --> {}\n<v {}>{}\n",
+            cue_time(s.start_time, '.'),
+            cue_time(s.end_time, '.'),
+            escape_xml(&s.speaker),
+            vtt_cue_text(s).replace("--&gt;", "-&gt;")
+        );
+    }
+    out
+}
+
+/// Cue text with a timestamp tag before each word after the first, for karaoke-style
+/// highlighting. Segments without word timings keep their plain text.
+fn vtt_cue_text(s: &TranscriptSegment) -> String {
+    if s.words.is_empty() {
+        return escape_xml(s.text.trim());
+    }
+    let mut text = String::new();
+    for (i, w) in s.words.iter().enumerate() {
+        if i > 0 {
+            let at = w.start.clamp(s.start_time, s.end_time);
+            let _ = write!(text, " <{}>", cue_time(at, '.'));
+        }
+        text.push_str(&escape_xml(&w.word));
+    }
+    text
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn to_html(title: &str, subtitle: &str, segments: &[TranscriptSegment]) -> String {
+    let mut body = String::new();
+    for turn in speaker_turns(segments) {
+        let _ = writeln!(
+            body,
+            "<div class=\"turn\"><div class=\"meta\"><span class=\"speaker\">{}</span> <span class=\"time\">{}</span></div><p>{}</p></div>",
+            escape_xml(&turn.speaker),
+            clock(turn.start),
+            escape_xml(&turn.text)
+        );
+    }
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; max-width: 46rem; margin: 2rem auto; padding: 0 1rem; color: #1f2328; line-height: 1.5; }}
+h1 {{ margin-bottom: 0.25rem; }}
+.subtitle {{ color: #656d76; margin-top: 0; }}
+.turn {{ margin: 1rem 0; }}
+.speaker {{ font-weight: 600; }}
+.time {{ color: #656d76; font-size: 0.85em; font-variant-numeric: tabular-nums; }}
+.turn p {{ margin: 0.25rem 0 0; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="subtitle">{subtitle}</p>
+{body}</body>
+</html>
+"#,
+        title = escape_xml(title),
+        subtitle = escape_xml(subtitle),
+        body = body
+    )
+}
+
+fn docx_paragraph(runs: &[(&str, bool, Option<u32>)]) -> String {
+    let mut p = String::from("<w:p>");
+    for (text, bold, half_points) in runs {
+        p.push_str("<w:r><w:rPr>");
+        if *bold {
+            p.push_str("<w:b/>");
+        }
+        if let Some(size) = half_points {
+            let _ = write!(p, "<w:sz w:val=\"{}\"/>", size);
+        }
+        let _ = write!(
+            p,
+            "</w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r>",
+            escape_xml(text)
+        );
+    }
+    p.push_str("</w:p>");
+    p
+}
+
+/// Minimal WordprocessingML package: title, subtitle, then one paragraph per speaker turn
+pub fn to_docx(title: &str, subtitle: &str, segments: &[TranscriptSegment]) -> Result<Vec<u8>> {
+    let mut body = String::new();
+    body.push_str(&docx_paragraph(&[(title, true, Some(36))]));
+    body.push_str(&docx_paragraph(&[(subtitle, false, Some(20))]));
+    for turn in speaker_turns(segments) {
+        let time = format!("  {}", clock(turn.start));
+        body.push_str(&docx_paragraph(&[
+            (&turn.speaker, true, None),
+            (&time, false, Some(18)),
+        ]));
+        body.push_str(&docx_paragraph(&[(&turn.text, false, None)]));
+    }
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr/></w:body></w:document>"#,
+        body
+    );
+    let content_types = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+    let rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, content) in [
+        ("[Content_Types].xml", content_types),
+        ("_rels/.rels", rels),
+        ("word/document.xml", document.as_str()),
+    ] {
+        zip.start_file(name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+/// Escape text for a PDF string literal using WinAnsi (Latin-1) bytes; other characters become `?`
+fn pdf_text(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            '\u{a0}'..='\u{ff}' => {
+                let _ = write!(out, "\\{:03o}", c as u32);
+            }
+            '\u{2018}' | '\u{2019}' => out.push('\''),
+            '\u{201c}' | '\u{201d}' => out.push('"'),
+            '\u{2013}' | '\u{2014}' => out.push('-'),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+/// Greedy word wrap by character count (Helvetica averages ~0.5em per glyph)
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Single-font US Letter PDF with speaker turns, paginated
+pub fn to_pdf(title: &str, subtitle: &str, segments: &[TranscriptSegment]) -> Vec<u8> {
+    const LINE_HEIGHT: f32 = 14.0;
+    const TOP: f32 = 738.0;
+    const BOTTOM: f32 = 54.0;
+
+    // (font resource, size, text); an empty text is a blank spacer line
+    let mut lines: Vec<(&str, f32, String)> = vec![
+        ("F2", 16.0, title.to_string()),
+        ("F1", 9.0, subtitle.to_string()),
+        ("F1", 10.0, String::new()),
+    ];
+    for turn in speaker_turns(segments) {
+        lines.push((
+            "F2",
+            10.0,
+            format!("{}  {}", turn.speaker, clock(turn.start)),
+        ));
+        for l in wrap(&turn.text, 95) {
+            lines.push(("F1", 10.0, l));
+        }
+        lines.push(("F1", 10.0, String::new()));
+    }
+
+    let mut pages: Vec<String> = Vec::new();
+    let mut content = String::new();
+    let mut y = TOP;
+    for (font, size, text) in lines {
+        if y < BOTTOM {
+            pages.push(std::mem::take(&mut content));
+            y = TOP;
+        }
+        if !text.is_empty() {
+            let _ = writeln!(
+                content,
+                "BT /{} {} Tf 54 {:.1} Td ({}) Tj ET",
+                font,
+                size,
+                y,
+                pdf_text(&text)
+            );
+        }
+        y -= LINE_HEIGHT.max(size + 4.0);
+    }
+    pages.push(content);
+
+    // Objects: 1 catalog, 2 page tree, 3-4 fonts, then a (page, content) pair per page
+    let mut objects: Vec<String> = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        String::new(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    let mut kids = Vec::new();
+    for page in &pages {
+        let page_id = objects.len() + 1;
+        kids.push(format!("{} 0 R", page_id));
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            page_id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            page.len(),
+            page
+        ));
+    }
+    objects[1] = format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        kids.join(" "),
+        pages.len()
+    );
+
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        let _ = write!(out, "{} 0 obj\n{}\nendobj\n", i + 1, object);
+    }
+    let xref = out.len();
+    let _ = write!(out, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(out, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(
+        out,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+    out.into_bytes()
+}
+
+/// Render a transcript in `format` (one of `EXPORT_FORMATS`)
+pub fn render_transcript(
+    format: &str,
+    title: &str,
+    subtitle: &str,
+    segments: &[TranscriptSegment],
+) -> Result<Vec<u8>> {
+    match format {
+        "srt" => Ok(to_srt(segments).into_bytes()),
+        "vtt" => Ok(to_vtt(segments).into_bytes()),
+        "html" => Ok(to_html(title, subtitle, segments).into_bytes()),
+        "docx" => to_docx(title, subtitle, segments),
+        "pdf" => Ok(to_pdf(title, subtitle, segments)),
+        other => Err(anyhow!(
+            "Unsupported export format '{}' (expected one of {})",
+            other,
+            EXPORT_FORMATS.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::meeting::WordTiming;
+    use std::time::SystemTime;
+
+    fn segment(speaker: &str, start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time: start,
+            end_time: end,
+            text: text.to_string(),
+            confidence: 0.9,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_subtitles_and_turns() {
+        let segments = vec![
+            segment("Speaker 1", 0.0, 2.5, "Let's ship it"),
+            segment("Speaker 1", 2.5, 4.0, "today."),
+            segment("Speaker 2", 3661.2, 3663.0, "A <b> & c"),
+        ];
+        let srt = to_srt(&segments);
+        assert!(
+            srt.starts_with("1\n00:00:00,000 --> 00:00:02,500\nSpeaker 1: Let's ship it\n\n2\n")
+        );
+        assert!(srt.contains("3\n01:01:01,200 --> 01:01:03,000\n"));
+
+        let vtt = to_vtt(&segments);
+        assert!(vtt
+            .starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:02.500\n<v Speaker 1>Let's ship it\n"));
+        assert!(vtt.contains("<v Speaker 2>A &lt;b&gt; &amp; c"));
+
+        let mut timed = segment("Speaker 1", 1.0, 2.0, "go now");
+        timed.words = vec![
+            WordTiming {
+                word: "go".to_string(),
+                start: 1.0,
+                end: 1.4,
+            },
+            WordTiming {
+                word: "now".to_string(),
+                start: 1.5,
+                end: 2.0,
+            },
+        ];
+        assert!(to_vtt(&[timed]).contains("<v Speaker 1>go <00:00:01.500>now\n"));
+
+        let turns = speaker_turns(&segments);
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].text, "Let's ship it today.");
+        assert_eq!(turns[0].end, 4.0);
+    }
+
+    #[test]
+    fn test_pdf_xref_offsets_point_at_objects() {
+        let segments: Vec<TranscriptSegment> = (0..120)
+            .map(|i| segment("Speaker 1", i as f64, i as f64 + 1.0, "Café (draft) notes"))
+            .chain(std::iter::once(segment("Speaker 2", 200.0, 201.0, "done")))
+            .collect();
+        let pdf = String::from_utf8(to_pdf("Planning", "2026-01-01", &segments)).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("(Caf\\351 \\(draft\\) notes"));
+        let xref_at: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(pdf[xref_at..].starts_with("xref"));
+        for (i, line) in pdf[xref_at..]
+            .lines()
+            .skip(3)
+            .take_while(|l| l.ends_with(" n "))
+            .enumerate()
+        {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+    }
+}
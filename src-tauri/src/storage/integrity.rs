@@ -0,0 +1,419 @@
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::history::HistoryManager;
+use crate::storage::db::{MeetingDb, StoredMeeting};
+use crate::storage::transcript::{TranscriptData, TranscriptMetadata, TranscriptStorage};
+
+/// Repair actions accepted by `repair`
+pub const REPAIR_ACTIONS: &[&str] = &["rebuild_index", "relink_segments", "vacuum"];
+
+/// One problem found by a check
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityIssue {
+    /// "database", "transcript", "unindexed_meeting", "missing_meeting_dir",
+    /// "missing_recording", or "orphaned_segments"
+    pub kind: String,
+    /// Database file, meeting directory, or project path the issue is about
+    pub target: String,
+    pub detail: String,
+    /// Repair action that fixes the issue, if any
+    pub repair: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntegrityReport {
+    pub databases_checked: usize,
+    pub transcripts_checked: usize,
+    pub projects_checked: usize,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RepairReport {
+    pub action: String,
+    pub changed: usize,
+    pub errors: Vec<String>,
+}
+
+/// A project's `audio_segments/` that no stored meeting points at
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrphanedSegments {
+    pub project_path: PathBuf,
+    pub segment_count: usize,
+    /// Meeting named in the project's `.meeting-updates.jsonl`, when it is still stored
+    pub meeting_id: Option<String>,
+}
+
+fn issue(
+    kind: &str,
+    target: impl Into<String>,
+    detail: impl Into<String>,
+    repair: Option<&str>,
+) -> IntegrityIssue {
+    IntegrityIssue {
+        kind: kind.to_string(),
+        target: target.into(),
+        detail: detail.into(),
+        repair: repair.map(str::to_string),
+    }
+}
+
+/// Rows of `PRAGMA integrity_check`, without the single "ok" a healthy database reports
+pub fn sqlite_integrity(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let rows = stmt.query_map([], |r| r.get::<_, String>(0))?;
+    Ok(rows
+        .collect::<rusqlite::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|row| row != "ok")
+        .collect())
+}
+
+/// Check a saved meeting directory against the transcript file formats
+pub fn validate_transcript_dir(dir: &Path) -> Vec<String> {
+    let mut problems = Vec::new();
+    let metadata = match fs::read_to_string(dir.join("metadata.json")) {
+        Ok(s) => match serde_json::from_str::<TranscriptMetadata>(&s) {
+            Ok(m) => Some(m),
+            Err(e) => {
+                problems.push(format!("metadata.json: {}", e));
+                None
+            }
+        },
+        Err(e) => {
+            problems.push(format!("metadata.json: {}", e));
+            None
+        }
+    };
+    let transcript = match fs::read_to_string(dir.join("transcript.json")) {
+        Ok(s) => match serde_json::from_str::<TranscriptData>(&s) {
+            Ok(t) => Some(t),
+            Err(e) => {
+                problems.push(format!("transcript.json: {}", e));
+                None
+            }
+        },
+        Err(e) => {
+            problems.push(format!("transcript.json: {}", e));
+            None
+        }
+    };
+
+    if let Some(ref m) = metadata {
+        for (field, value) in [("start_time", &m.start_time), ("end_time", &m.end_time)] {
+            if chrono::DateTime::parse_from_rfc3339(value).is_err() {
+                problems.push(format!(
+                    "metadata.json: {} is not an RFC 3339 timestamp",
+                    field
+                ));
+            }
+        }
+    }
+    if let (Some(m), Some(t)) = (&metadata, &transcript) {
+        if m.meeting_id != t.meeting_id {
+            problems.push(format!(
+                "meeting id mismatch: metadata.json has {}, transcript.json has {}",
+                m.meeting_id, t.meeting_id
+            ));
+        }
+    }
+    if let Some(t) = transcript {
+        for (i, s) in t.segments.iter().enumerate() {
+            if !s.start_time.is_finite() || !s.end_time.is_finite() || s.end_time < s.start_time {
+                problems.push(format!(
+                    "segment {} has invalid timing ({} - {})",
+                    i, s.start_time, s.end_time
+                ));
+            }
+        }
+    }
+    problems
+}
+
+fn segment_count(dir: &Path) -> usize {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    name.starts_with("segment_") && name.ends_with(".wav")
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Meeting id recorded by the first live update written to a project
+fn project_meeting_id(project: &Path) -> Option<String> {
+    let content = fs::read_to_string(project.join(".meeting-updates.jsonl")).ok()?;
+    content.lines().find_map(|line| {
+        serde_json::from_str::<serde_json::Value>(line)
+            .ok()?
+            .get("meeting_id")?
+            .as_str()
+            .map(str::to_string)
+    })
+}
+
+/// Projects under `projects_root` holding segment WAVs that no meeting references
+pub fn find_orphaned_segments(
+    projects_root: &Path,
+    meetings: &[StoredMeeting],
+) -> Vec<OrphanedSegments> {
+    let linked: HashSet<PathBuf> = meetings
+        .iter()
+        .filter_map(|m| m.project_path.as_ref().map(PathBuf::from))
+        .collect();
+    let known: HashSet<&str> = meetings.iter().map(|m| m.id.as_str()).collect();
+    let mut projects: Vec<PathBuf> = fs::read_dir(projects_root)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    projects.sort();
+
+    projects
+        .into_iter()
+        .filter(|p| !linked.contains(p))
+        .filter_map(|project| {
+            let count = segment_count(&project.join("audio_segments"));
+            if count == 0 {
+                return None;
+            }
+            let meeting_id = project_meeting_id(&project).filter(|id| known.contains(id.as_str()));
+            Some(OrphanedSegments {
+                project_path: project,
+                segment_count: count,
+                meeting_id,
+            })
+        })
+        .collect()
+}
+
+fn managed<T: Send + Sync + 'static>(app: &AppHandle, what: &str) -> Result<Arc<T>> {
+    app.try_state::<Arc<T>>()
+        .map(|s| s.inner().clone())
+        .ok_or_else(|| anyhow!("{} is not initialized", what))
+}
+
+/// Verify both databases, every saved transcript, and project audio segments
+pub fn run_check(app: &AppHandle) -> Result<IntegrityReport> {
+    let db = managed::<MeetingDb>(app, "Meeting database")?;
+    let history = managed::<HistoryManager>(app, "History database")?;
+    let mut report = IntegrityReport::default();
+
+    // VACUUM rewrites the history database; the meetings database also has REINDEX and FTS rebuild
+    for (label, problems, fix) in [
+        ("history.db", history.integrity_check(), "vacuum"),
+        ("meetings.db", db.integrity_check(), "rebuild_index"),
+    ] {
+        report.databases_checked += 1;
+        match problems {
+            Ok(problems) => report.issues.extend(
+                problems
+                    .into_iter()
+                    .map(|p| issue("database", label, p, Some(fix))),
+            ),
+            Err(e) => report
+                .issues
+                .push(issue("database", label, e.to_string(), None)),
+        }
+    }
+    for (id, file_name) in history.missing_recordings()? {
+        report.issues.push(issue(
+            "missing_recording",
+            file_name,
+            format!("History entry {} has no recording on disk", id),
+            None,
+        ));
+    }
+
+    let storage = TranscriptStorage::with_default_path()?;
+    let meetings = db.list_meetings()?;
+    let indexed: HashSet<&str> = meetings
+        .iter()
+        .filter_map(|m| m.dir_name.as_deref())
+        .collect();
+    let dir_names = storage.list_meetings()?;
+    for dir_name in &dir_names {
+        report.transcripts_checked += 1;
+        let problems = validate_transcript_dir(&storage.base_path().join(dir_name));
+        let valid = problems.is_empty();
+        report.issues.extend(
+            problems
+                .into_iter()
+                .map(|p| issue("transcript", dir_name.as_str(), p, None)),
+        );
+        if valid && !indexed.contains(dir_name.as_str()) {
+            report.issues.push(issue(
+                "unindexed_meeting",
+                dir_name.as_str(),
+                "Saved meeting is missing from the database",
+                Some("rebuild_index"),
+            ));
+        }
+    }
+    for meeting in &meetings {
+        if let Some(ref dir_name) = meeting.dir_name {
+            if !storage.base_path().join(dir_name).is_dir() {
+                report.issues.push(issue(
+                    "missing_meeting_dir",
+                    dir_name.as_str(),
+                    format!("Meeting {} has no directory on disk", meeting.id),
+                    None,
+                ));
+            }
+        }
+    }
+
+    let projects_root = crate::storage::root::storage_dir("projects")?;
+    report.projects_checked = fs::read_dir(&projects_root).map(|e| e.count()).unwrap_or(0);
+    for orphan in find_orphaned_segments(&projects_root, &meetings) {
+        let detail = match orphan.meeting_id {
+            Some(ref id) => format!("{} segments belong to meeting {}", orphan.segment_count, id),
+            None => format!(
+                "{} segments are not linked to any meeting",
+                orphan.segment_count
+            ),
+        };
+        let repair = orphan.meeting_id.as_ref().map(|_| "relink_segments");
+        report.issues.push(issue(
+            "orphaned_segments",
+            orphan.project_path.to_string_lossy(),
+            detail,
+            repair,
+        ));
+    }
+    log::info!(
+        "Integrity check: {} issues across {} transcripts",
+        report.issues.len(),
+        report.transcripts_checked
+    );
+    Ok(report)
+}
+
+/// Apply one of `REPAIR_ACTIONS`
+pub fn repair(app: &AppHandle, action: &str) -> Result<RepairReport> {
+    let db = managed::<MeetingDb>(app, "Meeting database")?;
+    let mut report = RepairReport {
+        action: action.to_string(),
+        ..Default::default()
+    };
+    match action {
+        "rebuild_index" => {
+            let storage = TranscriptStorage::with_default_path()?;
+            let imported = db.import_from_disk(&storage, storage.base_path())?;
+            report.changed = imported.imported;
+            report.errors.extend(
+                imported
+                    .failed
+                    .into_iter()
+                    .map(|(dir, e)| format!("{}: {}", dir, e)),
+            );
+            db.rebuild_index()?;
+        }
+        "relink_segments" => {
+            let projects_root = crate::storage::root::storage_dir("projects")?;
+            for orphan in find_orphaned_segments(&projects_root, &db.list_meetings()?) {
+                let Some(meeting_id) = orphan.meeting_id else {
+                    continue;
+                };
+                match db.set_project_path(&meeting_id, &orphan.project_path.to_string_lossy()) {
+                    Ok(true) => report.changed += 1,
+                    Ok(false) => {}
+                    Err(e) => report.errors.push(format!("{}: {}", meeting_id, e)),
+                }
+            }
+        }
+        "vacuum" => {
+            managed::<HistoryManager>(app, "History database")?.vacuum()?;
+            db.vacuum()?;
+            report.changed = 2;
+        }
+        other => return Err(anyhow!("Unknown repair action: {}", other)),
+    }
+    log::info!("Repair {} changed {} items", report.action, report.changed);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_validate_transcript_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(
+            dir.join("metadata.json"),
+            r#"{"meeting_id":"m-1","name":"Sync","start_time":"2026-01-01T10:00:00+00:00",
+               "end_time":"yesterday","duration_seconds":60,"participants":[]}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("transcript.json"),
+            r#"{"meeting_id":"m-2","segments":[]}"#,
+        )
+        .unwrap();
+
+        let problems = validate_transcript_dir(dir);
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].contains("end_time"));
+        assert!(problems[1].contains("mismatch"));
+
+        fs::write(dir.join("transcript.json"), "{\"segments\":").unwrap();
+        assert!(validate_transcript_dir(dir)
+            .iter()
+            .any(|p| p.starts_with("transcript.json")));
+    }
+
+    #[test]
+    fn test_find_orphaned_segments() {
+        let temp_dir = TempDir::new().unwrap();
+        let projects = temp_dir.path();
+        for name in ["linked", "orphan", "relinkable"] {
+            fs::create_dir_all(projects.join(name).join("audio_segments")).unwrap();
+            fs::write(
+                projects
+                    .join(name)
+                    .join("audio_segments/segment_000001_0-1000.wav"),
+                "RIFF",
+            )
+            .unwrap();
+        }
+        fs::write(
+            projects.join("relinkable/.meeting-updates.jsonl"),
+            "{\"meeting_id\":\"m-2\",\"update_id\":\"u1\"}\n",
+        )
+        .unwrap();
+        let meeting = |id: &str, project: Option<&Path>| StoredMeeting {
+            id: id.to_string(),
+            name: id.to_string(),
+            start_time: 0,
+            end_time: Some(0),
+            participants: Vec::new(),
+            project_path: project.map(|p| p.to_string_lossy().to_string()),
+            dir_name: None,
+            segment_count: 0,
+            starred: false,
+        };
+        let meetings = vec![
+            meeting("m-1", Some(&projects.join("linked"))),
+            meeting("m-2", None),
+        ];
+
+        let orphans = find_orphaned_segments(projects, &meetings);
+        assert_eq!(orphans.len(), 2);
+        assert_eq!(orphans[0].project_path, projects.join("orphan"));
+        assert_eq!(orphans[0].meeting_id, None);
+        assert_eq!(orphans[1].meeting_id.as_deref(), Some("m-2"));
+        assert_eq!(orphans[1].segment_count, 1);
+    }
+}
@@ -1 +1,16 @@
+pub mod analytics;
+pub mod backup;
+pub mod bundle;
+pub mod clips;
+pub mod db;
+pub mod export;
+pub mod integrity;
+pub mod playback;
+pub mod project_index;
+pub mod retention;
+pub mod root;
+pub mod speakers;
+pub mod tasks;
 pub mod transcript;
+pub mod trash;
+pub mod usage;
@@ -0,0 +1,299 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Composed recording written to the meeting directory by `end_meeting`
+pub const FULL_AUDIO_FILE: &str = "full_audio.wav";
+/// Upper bound for a single `get_audio_chunk` read
+pub const MAX_CHUNK_BYTES: u64 = 4 * 1024 * 1024;
+/// Cached peaks, stored next to the audio
+const WAVEFORM_CACHE_FILE: &str = "waveform.json";
+pub const DEFAULT_WAVEFORM_BUCKETS: usize = 1000;
+
+/// Layout of a WAV file, enough to seek by time without decoding
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioInfo {
+    pub path: PathBuf,
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bits_per_sample: u16,
+    /// Byte offset of the first sample (start of the `data` chunk payload)
+    pub data_offset: u64,
+    pub data_len: u64,
+    pub file_len: u64,
+    pub duration_seconds: f64,
+}
+
+impl AudioInfo {
+    fn block_align(&self) -> u64 {
+        (self.channels as u64 * self.bits_per_sample as u64 / 8).max(1)
+    }
+
+    fn bytes_per_second(&self) -> f64 {
+        self.sample_rate as f64 * self.block_align() as f64
+    }
+
+    /// File byte offset of `seconds`, aligned to a sample frame and clamped to the data
+    pub fn byte_offset(&self, seconds: f64) -> u64 {
+        let frames = (seconds.max(0.0) * self.sample_rate as f64).floor() as u64;
+        let relative = (frames * self.block_align()).min(self.data_len);
+        self.data_offset + relative
+    }
+}
+
+/// Time and byte span of one transcript segment within the meeting audio
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioRange {
+    pub segment_index: usize,
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+    pub byte_start: u64,
+    pub byte_end: u64,
+}
+
+/// Read the `fmt ` and `data` chunk headers of a RIFF/WAVE file
+pub fn read_wav_info(path: &Path) -> Result<AudioInfo> {
+    let mut file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+    let file_len = file.metadata()?.len();
+    let mut header = [0u8; 12];
+    file.read_exact(&mut header)?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err(anyhow!("Not a WAV file: {:?}", path));
+    }
+
+    let mut format: Option<(u16, u32, u16)> = None;
+    let mut pos = 12u64;
+    loop {
+        let mut chunk = [0u8; 8];
+        if file.read_exact(&mut chunk).is_err() {
+            return Err(anyhow!("WAV file has no data chunk: {:?}", path));
+        }
+        let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as u64;
+        let body = pos + 8;
+        match &chunk[0..4] {
+            b"fmt " => {
+                let mut fmt = [0u8; 16];
+                file.read_exact(&mut fmt)?;
+                format = Some((
+                    u16::from_le_bytes([fmt[2], fmt[3]]),
+                    u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]),
+                    u16::from_le_bytes([fmt[14], fmt[15]]),
+                ));
+            }
+            b"data" => {
+                let (channels, sample_rate, bits_per_sample) =
+                    format.ok_or_else(|| anyhow!("WAV data chunk precedes fmt chunk"))?;
+                // Writers that never finalized leave size 0 or 0xFFFFFFFF; trust the file length
+                let data_len = if size == 0 || body + size > file_len {
+                    file_len.saturating_sub(body)
+                } else {
+                    size
+                };
+                let mut info = AudioInfo {
+                    path: path.to_path_buf(),
+                    sample_rate,
+                    channels,
+                    bits_per_sample,
+                    data_offset: body,
+                    data_len,
+                    file_len,
+                    duration_seconds: 0.0,
+                };
+                info.duration_seconds = data_len as f64 / info.bytes_per_second().max(1.0);
+                return Ok(info);
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length
+        pos = body + size + (size & 1);
+        file.seek(SeekFrom::Start(pos))?;
+    }
+}
+
+/// Read up to `len` bytes at `offset` (capped at `MAX_CHUNK_BYTES`)
+pub fn read_chunk(path: &Path, offset: u64, len: u64) -> Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    let file_len = file.metadata()?.len();
+    if offset >= file_len {
+        return Ok(Vec::new());
+    }
+    let len = len.min(MAX_CHUNK_BYTES).min(file_len - offset);
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buf = vec![0u8; len as usize];
+    file.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+/// Map a segment's timing onto the audio file
+pub fn segment_range(info: &AudioInfo, segment_index: usize, start: f64, end: f64) -> AudioRange {
+    let end = end.max(start).min(info.duration_seconds.max(start));
+    AudioRange {
+        segment_index,
+        start_seconds: start,
+        end_seconds: end,
+        byte_start: info.byte_offset(start),
+        byte_end: info.byte_offset(end),
+    }
+}
+
+/// Downsampled min/max envelope of a recording, one pair per bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveformPeaks {
+    pub buckets: usize,
+    pub duration_seconds: f64,
+    pub min: Vec<f32>,
+    pub max: Vec<f32>,
+    /// Size and mtime of the audio the peaks were computed from (cache validation)
+    source_len: u64,
+    source_modified: u64,
+}
+
+fn source_stamp(path: &Path) -> Result<(u64, u64)> {
+    let meta = fs::metadata(path)?;
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok((meta.len(), modified))
+}
+
+/// Decode the WAV (mixing channels) and reduce it to `buckets` min/max pairs
+pub fn compute_peaks(path: &Path, buckets: usize) -> Result<WaveformPeaks> {
+    let buckets = buckets.max(1);
+    let mut reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let frames = reader.duration() as usize;
+    // Stream samples: an hour of 16 kHz float audio would be ~230 MB if collected
+    let samples: Box<dyn Iterator<Item = hound::Result<f32>> + '_> =
+        match (spec.sample_format, spec.bits_per_sample) {
+            (hound::SampleFormat::Float, _) => Box::new(reader.samples::<f32>()),
+            (hound::SampleFormat::Int, bits) => {
+                let scale = (1i64 << (bits.clamp(1, 32) - 1)) as f32;
+                Box::new(
+                    reader
+                        .samples::<i32>()
+                        .map(move |s| s.map(|v| v as f32 / scale)),
+                )
+            }
+        };
+
+    let mut min = vec![0.0f32; buckets];
+    let mut max = vec![0.0f32; buckets];
+    let (mut frame, mut sum, mut count) = (0usize, 0.0f32, 0usize);
+    for sample in samples {
+        sum += sample?;
+        count += 1;
+        if count == channels {
+            let value = sum / channels as f32;
+            let bucket = (frame * buckets / frames.max(1)).min(buckets - 1);
+            min[bucket] = min[bucket].min(value);
+            max[bucket] = max[bucket].max(value);
+            frame += 1;
+            sum = 0.0;
+            count = 0;
+        }
+    }
+    let (source_len, source_modified) = source_stamp(path)?;
+    Ok(WaveformPeaks {
+        buckets,
+        duration_seconds: frames as f64 / spec.sample_rate.max(1) as f64,
+        min,
+        max,
+        source_len,
+        source_modified,
+    })
+}
+
+/// Peaks for `audio_path`, served from the cache next to it when the audio is unchanged
+pub fn cached_peaks(audio_path: &Path, buckets: usize) -> Result<WaveformPeaks> {
+    let cache_path = audio_path.with_file_name(WAVEFORM_CACHE_FILE);
+    let stamp = source_stamp(audio_path)?;
+    if let Some(cached) = fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|s| serde_json::from_str::<WaveformPeaks>(&s).ok())
+    {
+        if cached.buckets == buckets && (cached.source_len, cached.source_modified) == stamp {
+            return Ok(cached);
+        }
+    }
+    let peaks = compute_peaks(audio_path, buckets)?;
+    if let Err(e) = fs::write(&cache_path, serde_json::to_string(&peaks)?) {
+        log::warn!("Failed to cache waveform peaks at {:?}: {}", cache_path, e);
+    }
+    Ok(peaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_wav_info_and_segment_offsets() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(FULL_AUDIO_FILE);
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for _ in 0..32_000 {
+            writer.write_sample(0.0f32).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let info = read_wav_info(&path).unwrap();
+        assert_eq!(info.sample_rate, 16_000);
+        assert_eq!(info.data_len, 128_000);
+        assert!((info.duration_seconds - 2.0).abs() < 1e-9);
+
+        let range = segment_range(&info, 3, 0.5, 5.0);
+        assert_eq!(range.byte_start, info.data_offset + 32_000);
+        assert_eq!(range.byte_end, info.data_offset + 128_000);
+        assert_eq!(range.end_seconds, 2.0);
+
+        let chunk = read_chunk(&path, range.byte_start, 16).unwrap();
+        assert_eq!(chunk.len(), 16);
+        assert!(read_chunk(&path, info.file_len, 16).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_waveform_peaks_are_cached() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(FULL_AUDIO_FILE);
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for i in 0..16_000 {
+            // Silent first half, full-scale square wave second half
+            let v = if i < 8_000 {
+                0
+            } else if i % 2 == 0 {
+                i16::MAX
+            } else {
+                -i16::MAX
+            };
+            writer.write_sample(v).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let peaks = cached_peaks(&path, 10).unwrap();
+        assert_eq!(peaks.max.len(), 10);
+        assert_eq!(peaks.max[0], 0.0);
+        assert!(peaks.max[9] > 0.99 && peaks.min[9] < -0.99);
+        assert!(temp_dir.path().join(WAVEFORM_CACHE_FILE).exists());
+        assert_eq!(cached_peaks(&path, 10).unwrap().max, peaks.max);
+        assert_eq!(cached_peaks(&path, 20).unwrap().buckets, 20);
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::document_generation::prd_storage;
+use crate::integrations::github;
+use crate::storage::db::{MeetingDb, StoredMeeting};
+
+/// Index file written into each project, next to the other meeting state in `.claude/`
+pub const PROJECT_INDEX_FILE: &str = ".claude/meeting-index.json";
+
+/// One meeting that targeted the project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMeetingEntry {
+    pub meeting_id: String,
+    pub name: String,
+    pub dir_name: Option<String>,
+    /// Local date (YYYY-MM-DD) the meeting started
+    pub date: String,
+    /// Unix milliseconds
+    pub start_time: i64,
+    pub end_time: Option<i64>,
+    /// Latest stored summary (Markdown)
+    pub summary: Option<String>,
+    /// Head version of the meeting's PRD, if one was generated
+    pub prd_version: Option<u32>,
+    /// Branch the GitHub workflow uses for this meeting
+    pub branch: Option<String>,
+}
+
+/// Everything known about the meetings targeting one project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectIndex {
+    pub project_path: String,
+    /// "owner/repo" when the project is linked to GitHub
+    pub repo: Option<String>,
+    pub last_branch: Option<String>,
+    pub last_pr_url: Option<String>,
+    pub last_pr_number: Option<u32>,
+    pub updated_at: String,
+    /// Most recent first
+    pub meetings: Vec<ProjectMeetingEntry>,
+}
+
+/// Compare project paths, tolerating trailing separators and symlinked locations
+fn same_project(a: &Path, b: &Path) -> bool {
+    let canonical = |p: &Path| fs::canonicalize(p).unwrap_or_else(|_| p.components().collect());
+    canonical(a) == canonical(b)
+}
+
+/// Build the index from stored meetings. Summary and PRD lookups are passed in so the
+/// aggregation doesn't depend on where those are stored.
+pub fn build_index(
+    project_path: &Path,
+    meetings: &[StoredMeeting],
+    state: &github::GitHubState,
+    summary_for: impl Fn(&str) -> Option<String>,
+    prd_version_for: impl Fn(&str) -> Option<u32>,
+) -> ProjectIndex {
+    let repo = match (&state.repo_owner, &state.repo_name) {
+        (Some(owner), Some(name)) => Some(format!("{}/{}", owner, name)),
+        _ => None,
+    };
+    let mut entries: Vec<ProjectMeetingEntry> = meetings
+        .iter()
+        .filter(|m| {
+            m.project_path
+                .as_deref()
+                .map(|p| same_project(Path::new(p), project_path))
+                .unwrap_or(false)
+        })
+        .map(|m| ProjectMeetingEntry {
+            meeting_id: m.id.clone(),
+            name: m.name.clone(),
+            dir_name: m.dir_name.clone(),
+            date: Local
+                .timestamp_millis_opt(m.start_time)
+                .single()
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            start_time: m.start_time,
+            end_time: m.end_time,
+            summary: summary_for(&m.id),
+            prd_version: prd_version_for(&m.id),
+            branch: repo
+                .as_ref()
+                .map(|_| github::generate_branch_name(&state.branch_pattern, &m.id, &m.name)),
+        })
+        .collect();
+    entries.sort_by(|a, b| b.start_time.cmp(&a.start_time));
+
+    ProjectIndex {
+        project_path: project_path.to_string_lossy().to_string(),
+        repo,
+        last_branch: state.last_branch.clone(),
+        last_pr_url: state.last_pr_url.clone(),
+        last_pr_number: state.last_pr_number,
+        updated_at: chrono::Utc::now().to_rfc3339(),
+        meetings: entries,
+    }
+}
+
+/// Rebuild a project's index from the database and write it to `PROJECT_INDEX_FILE`
+pub fn refresh_index(db: &MeetingDb, project_path: &Path) -> Result<ProjectIndex> {
+    let meetings = db.list_meetings()?;
+    let state = github::read_github_state(&project_path.to_string_lossy());
+    let index = build_index(
+        project_path,
+        &meetings,
+        &state,
+        |id| {
+            let summaries = db.list_summaries(id).ok()?;
+            summaries
+                .iter()
+                .rev()
+                .find(|s| s.kind == "markdown")
+                .or_else(|| summaries.last())
+                .map(|s| s.content.clone())
+        },
+        prd_storage::latest_version,
+    );
+    if project_path.is_dir() {
+        let path = project_path.join(PROJECT_INDEX_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&index)?)?;
+    }
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn meeting(id: &str, start_time: i64, project: Option<String>) -> StoredMeeting {
+        StoredMeeting {
+            id: id.to_string(),
+            name: format!("Meeting {}", id),
+            start_time,
+            end_time: Some(start_time + 60_000),
+            participants: Vec::new(),
+            project_path: project,
+            dir_name: None,
+            segment_count: 0,
+            starred: false,
+        }
+    }
+
+    #[test]
+    fn test_build_index_filters_and_orders_project_meetings() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("app");
+        fs::create_dir_all(&project).unwrap();
+        let with_slash = format!("{}/", project.to_string_lossy());
+        let meetings = vec![
+            meeting("a", 1_000, Some(project.to_string_lossy().to_string())),
+            meeting("b", 2_000, Some(with_slash)),
+            meeting("c", 3_000, Some("/elsewhere".to_string())),
+            meeting("d", 4_000, None),
+        ];
+        let state = github::GitHubState {
+            repo_owner: Some("acme".to_string()),
+            repo_name: Some("app".to_string()),
+            last_pr_number: Some(7),
+            ..github::GitHubState::new()
+        };
+
+        let index = build_index(
+            &project,
+            &meetings,
+            &state,
+            |id| (id == "a").then(|| "# Summary".to_string()),
+            |id| (id == "b").then_some(3),
+        );
+        let ids: Vec<&str> = index
+            .meetings
+            .iter()
+            .map(|m| m.meeting_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["b", "a"]);
+        assert_eq!(index.repo.as_deref(), Some("acme/app"));
+        assert_eq!(index.last_pr_number, Some(7));
+        assert_eq!(index.meetings[0].prd_version, Some(3));
+        assert_eq!(index.meetings[1].summary.as_deref(), Some("# Summary"));
+        assert!(index.meetings[0].branch.is_some());
+    }
+}
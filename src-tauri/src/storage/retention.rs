@@ -0,0 +1,308 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::events::{self, AppEvent};
+use crate::managers::history::HistoryManager;
+use crate::settings::RetentionPolicy;
+use crate::storage::db::{MeetingDb, StoredMeeting};
+use crate::storage::transcript::TranscriptStorage;
+use crate::storage::usage::segment_files;
+
+const AUDIO_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg"];
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+/// How often the background task enforces the policy
+const SWEEP_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// One cleanup step for a meeting
+#[derive(Debug, Clone, Serialize)]
+pub struct RetentionAction {
+    pub meeting_id: String,
+    pub meeting_name: String,
+    /// "audio" (recordings only) or "meeting" (transcript directory and database rows);
+    /// both include the chunk WAVs the meeting left in its project's `audio_segments/`
+    pub kind: String,
+    pub age_days: i64,
+    pub paths: Vec<PathBuf>,
+    pub bytes: u64,
+}
+
+/// What a retention pass deleted, or would delete when `dry_run` is set
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RetentionReport {
+    pub dry_run: bool,
+    pub actions: Vec<RetentionAction>,
+    pub bytes_freed: u64,
+    /// Failures while applying actions, as (meeting_id, reason)
+    pub errors: Vec<(String, String)>,
+}
+
+fn is_audio(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| AUDIO_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+fn path_size(path: &Path) -> u64 {
+    if path.is_dir() {
+        fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| path_size(&e.path()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    } else {
+        fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+/// Decide what the policy removes. A meeting past the transcript limit is removed
+/// whole, so it never also gets an audio action. Meetings still in progress are skipped.
+pub fn plan_retention(
+    policy: &RetentionPolicy,
+    meetings: &[StoredMeeting],
+    transcript_base: &Path,
+    now_ms: i64,
+) -> Vec<RetentionAction> {
+    let mut actions = Vec::new();
+    for meeting in meetings {
+        let Some(end_time) = meeting.end_time else {
+            continue;
+        };
+        if policy.keep_starred && meeting.starred {
+            continue;
+        }
+        let age_days = (now_ms - end_time) / DAY_MS;
+        let dir = meeting.dir_name.as_ref().map(|d| transcript_base.join(d));
+        let mut segments = segment_files(meeting, meetings);
+        segments.sort();
+
+        if policy
+            .transcript_days
+            .map(|d| age_days >= d as i64)
+            .unwrap_or(false)
+        {
+            let mut paths: Vec<PathBuf> = dir.into_iter().filter(|d| d.is_dir()).collect();
+            paths.extend(segments);
+            actions.push(RetentionAction {
+                meeting_id: meeting.id.clone(),
+                meeting_name: meeting.name.clone(),
+                kind: "meeting".to_string(),
+                age_days,
+                bytes: paths.iter().map(|p| path_size(p)).sum(),
+                paths,
+            });
+            continue;
+        }
+
+        if policy
+            .audio_days
+            .map(|d| age_days >= d as i64)
+            .unwrap_or(false)
+        {
+            let mut paths: Vec<PathBuf> = dir
+                .and_then(|dir| fs::read_dir(dir).ok())
+                .map(|entries| {
+                    entries
+                        .filter_map(|e| e.ok())
+                        .map(|e| e.path())
+                        .filter(|p| p.is_file() && is_audio(p))
+                        .collect()
+                })
+                .unwrap_or_default();
+            paths.sort();
+            paths.extend(segments);
+            if paths.is_empty() {
+                continue;
+            }
+            actions.push(RetentionAction {
+                meeting_id: meeting.id.clone(),
+                meeting_name: meeting.name.clone(),
+                kind: "audio".to_string(),
+                age_days,
+                bytes: paths.iter().map(|p| path_size(p)).sum(),
+                paths,
+            });
+        }
+    }
+    actions
+}
+
+fn apply_action(db: &MeetingDb, action: &RetentionAction) -> Result<()> {
+    for path in &action.paths {
+        if path.is_dir() {
+            fs::remove_dir_all(path)?;
+        } else if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+    if action.kind == "meeting" {
+        db.delete_meeting(&action.meeting_id)?;
+    }
+    Ok(())
+}
+
+/// Evaluate the retention policy against stored meetings; deletes only when `dry_run` is false.
+/// Meetings on disk but not yet imported into the database are not considered.
+pub fn run_retention(app: &AppHandle, dry_run: bool) -> Result<RetentionReport> {
+    let policy = crate::settings::get_settings(app).retention;
+    let db = app
+        .try_state::<Arc<MeetingDb>>()
+        .ok_or_else(|| anyhow::anyhow!("Meeting database is not initialized"))?
+        .inner()
+        .clone();
+    let meetings = db.list_meetings()?;
+    let base = TranscriptStorage::default_path()?;
+    let now_ms = chrono::Utc::now().timestamp_millis();
+
+    let mut report = RetentionReport {
+        dry_run,
+        ..Default::default()
+    };
+    for action in plan_retention(&policy, &meetings, &base, now_ms) {
+        if !dry_run {
+            if let Err(e) = apply_action(&db, &action) {
+                log::warn!("RETENTION failed for meeting {}: {}", action.meeting_id, e);
+                report
+                    .errors
+                    .push((action.meeting_id.clone(), e.to_string()));
+                continue;
+            }
+            if action.kind == "meeting" {
+                if let Some(history) = app.try_state::<Arc<HistoryManager>>() {
+                    for dir_name in action.paths.iter().filter_map(|p| p.file_name()) {
+                        let _ = history.delete_meeting_entries_by_dir(&dir_name.to_string_lossy());
+                    }
+                }
+            }
+            log::info!(
+                "RETENTION removed {} of meeting {} ({} days old)",
+                action.kind,
+                action.meeting_id,
+                action.age_days
+            );
+        }
+        report.bytes_freed += action.bytes;
+        report.actions.push(action);
+    }
+    if !dry_run && !report.actions.is_empty() {
+        events::emit(app, AppEvent::RetentionApplied(report.clone()));
+    }
+    Ok(report)
+}
+
+/// Periodically enforce the retention policy while it is enabled
+pub fn spawn_retention_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        // Let startup work (database import, model loading) settle first
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        loop {
+            let policy = crate::settings::get_settings(&app).retention;
+            // Trash expiry applies even when the retention policy itself is disabled
+            if let Ok(trash_base) = crate::storage::trash::trash_dir() {
+                let now_ms = chrono::Utc::now().timestamp_millis();
+                if let Err(e) = crate::storage::trash::purge_expired(&trash_base, policy.trash_days, now_ms) {
+                    log::warn!("RETENTION trash purge failed: {}", e);
+                }
+            }
+            if policy.enabled {
+                let app_for_run = app.clone();
+                match tauri::async_runtime::spawn_blocking(move || {
+                    run_retention(&app_for_run, false)
+                })
+                .await
+                {
+                    Ok(Err(e)) => log::warn!("RETENTION sweep failed: {}", e),
+                    Err(e) => log::warn!("RETENTION sweep panicked: {}", e),
+                    Ok(Ok(_)) => {}
+                }
+            }
+            tokio::time::sleep(SWEEP_INTERVAL).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+    use tempfile::TempDir;
+
+    fn meeting(id: &str, dir: &str, age_days: i64, starred: bool, now_ms: i64) -> StoredMeeting {
+        StoredMeeting {
+            id: id.to_string(),
+            name: id.to_string(),
+            start_time: now_ms - age_days * DAY_MS,
+            end_time: Some(now_ms - age_days * DAY_MS),
+            participants: Vec::new(),
+            project_path: None,
+            dir_name: Some(dir.to_string()),
+            segment_count: 0,
+            starred,
+        }
+    }
+
+    #[test]
+    fn test_plan_retention() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        for dir in ["old", "middle", "recent", "starred"] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+            fs::write(base.join(dir).join("full_audio.wav"), "RIFF").unwrap();
+            fs::write(base.join(dir).join("transcript.json"), "{}").unwrap();
+        }
+        let now = 1_800_000_000_000;
+        let mut meetings = vec![
+            meeting("old", "old", 400, false, now),
+            meeting("middle", "middle", 45, false, now),
+            meeting("recent", "recent", 3, false, now),
+            meeting("starred", "starred", 400, true, now),
+        ];
+        // "middle" and "recent" recorded into the same project
+        let project = base.join("project");
+        let segments_dir = project.join("audio_segments");
+        fs::create_dir_all(&segments_dir).unwrap();
+        let mut segments = Vec::new();
+        for (i, meeting) in meetings.iter_mut().enumerate().skip(1).take(2) {
+            meeting.start_time -= 60_000;
+            meeting.project_path = Some(project.to_string_lossy().to_string());
+            let segment = segments_dir.join(format!("segment_00000{}_0-1000.wav", i));
+            fs::write(&segment, "RIFF").unwrap();
+            fs::File::options()
+                .write(true)
+                .open(&segment)
+                .unwrap()
+                .set_modified(UNIX_EPOCH + Duration::from_millis(meeting.start_time as u64 + 1000))
+                .unwrap();
+            segments.push(segment);
+        }
+
+        let actions = plan_retention(&RetentionPolicy::default(), &meetings, base, now);
+        let summary: Vec<(&str, &str)> = actions
+            .iter()
+            .map(|a| (a.meeting_id.as_str(), a.kind.as_str()))
+            .collect();
+        assert_eq!(summary, vec![("old", "meeting"), ("middle", "audio")]);
+        assert_eq!(
+            actions[1].paths,
+            vec![base.join("middle/full_audio.wav"), segments[0].clone()]
+        );
+        assert_eq!(actions[1].bytes, 8);
+
+        let keep_all = RetentionPolicy {
+            keep_starred: false,
+            audio_days: None,
+            ..Default::default()
+        };
+        let actions = plan_retention(&keep_all, &meetings, base, now);
+        assert_eq!(actions.len(), 2);
+        assert!(actions.iter().all(|a| a.kind == "meeting"));
+    }
+}
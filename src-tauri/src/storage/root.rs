@@ -0,0 +1,195 @@
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Subdirectories of the storage root that hold user data
+pub const STORAGE_SUBDIRS: &[&str] = &[
+    "meetings",
+    "projects",
+    "repos",
+    "exports",
+    "downloads",
+    "trash",
+];
+
+/// Configured root, mirrored from settings so path helpers don't need an AppHandle
+static STORAGE_ROOT: Lazy<RwLock<Option<PathBuf>>> = Lazy::new(|| RwLock::new(None));
+
+/// Default root when none is configured: ~/MeetingCoder
+pub fn default_storage_root() -> Result<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not determine home directory"))?;
+    Ok(home.join("MeetingCoder"))
+}
+
+/// Set (or clear) the storage root used by transcripts, projects, clones, and exports
+pub fn set_storage_root(root: Option<PathBuf>) {
+    let mut guard = STORAGE_ROOT.write().unwrap_or_else(|e| e.into_inner());
+    *guard = root.filter(|p| !p.as_os_str().is_empty());
+}
+
+/// Current storage root
+pub fn storage_root() -> Result<PathBuf> {
+    let configured = STORAGE_ROOT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    match configured {
+        Some(root) => Ok(root),
+        None => default_storage_root(),
+    }
+}
+
+/// Resolve a subdirectory (e.g. "meetings") of the storage root
+pub fn storage_dir(name: &str) -> Result<PathBuf> {
+    Ok(storage_root()?.join(name))
+}
+
+/// Result of moving data between storage roots
+#[derive(Debug, Default, Serialize)]
+pub struct StorageMigrationReport {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// Entries moved, relative to the root (e.g. "meetings/2026-01-01_planning")
+    pub moved: Vec<String>,
+    /// Entries left in place because the destination already has them
+    pub skipped: Vec<String>,
+}
+
+impl StorageMigrationReport {
+    /// Where a path recorded under the old root lives now. None when it is outside the
+    /// old root or its entry was skipped and stayed behind.
+    pub fn relocate(&self, path: &str) -> Option<String> {
+        let rel = Path::new(path).strip_prefix(&self.from).ok()?;
+        let mut components = rel.components();
+        let label = format!(
+            "{}/{}",
+            components.next()?.as_os_str().to_string_lossy(),
+            components.next()?.as_os_str().to_string_lossy()
+        );
+        if !self.moved.contains(&label) {
+            return None;
+        }
+        Some(self.to.join(rel).to_string_lossy().to_string())
+    }
+}
+
+/// Move `from` to `to`, copying when a rename is not possible (different volumes)
+pub(crate) fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    copy_recursive(from, to)?;
+    if from.is_dir() {
+        fs::remove_dir_all(from)?;
+    } else {
+        fs::remove_file(from)?;
+    }
+    Ok(())
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> Result<()> {
+    if from.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(from, to).with_context(|| format!("Failed to copy {:?}", from))?;
+        // Keep modification times: audio segments are attributed to meetings by them
+        if let Ok(modified) = fs::metadata(from).and_then(|m| m.modified()) {
+            let _ = fs::File::options()
+                .write(true)
+                .open(to)
+                .and_then(|f| f.set_modified(modified));
+        }
+    }
+    Ok(())
+}
+
+/// Move the data subdirectories from one root to another. Entries are moved one at a
+/// time so a partially populated destination is merged rather than overwritten.
+pub fn migrate_storage(from: &Path, to: &Path) -> Result<StorageMigrationReport> {
+    if to.starts_with(from) && to != from {
+        return Err(anyhow!("New storage root cannot be inside the current one"));
+    }
+    let mut report = StorageMigrationReport {
+        from: from.to_path_buf(),
+        to: to.to_path_buf(),
+        ..Default::default()
+    };
+    if from == to {
+        return Ok(report);
+    }
+    for sub in STORAGE_SUBDIRS {
+        let src = from.join(sub);
+        if !src.is_dir() {
+            continue;
+        }
+        let dest = to.join(sub);
+        fs::create_dir_all(&dest).with_context(|| format!("Failed to create {:?}", dest))?;
+        let mut entries: Vec<_> = fs::read_dir(&src)?.filter_map(|e| e.ok()).collect();
+        entries.sort_by_key(|e| e.file_name());
+        for entry in entries {
+            let label = format!("{}/{}", sub, entry.file_name().to_string_lossy());
+            let target = dest.join(entry.file_name());
+            if target.exists() {
+                report.skipped.push(label);
+                continue;
+            }
+            move_path(&entry.path(), &target)?;
+            report.moved.push(label);
+        }
+        // Remove the now-empty source directory; leftovers (skipped entries) keep it alive
+        let _ = fs::remove_dir(&src);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_migrate_storage_merges_and_skips_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+        let from = temp_dir.path().join("old");
+        let to = temp_dir.path().join("new");
+        fs::create_dir_all(from.join("meetings/a")).unwrap();
+        fs::write(from.join("meetings/a/transcript.json"), "{}").unwrap();
+        fs::create_dir_all(from.join("meetings/b")).unwrap();
+        fs::create_dir_all(from.join("repos/owner/repo")).unwrap();
+        fs::create_dir_all(to.join("meetings/b")).unwrap();
+
+        let report = migrate_storage(&from, &to).unwrap();
+        assert_eq!(report.moved, vec!["meetings/a", "repos/owner"]);
+        assert_eq!(report.skipped, vec!["meetings/b"]);
+        assert!(to.join("meetings/a/transcript.json").exists());
+        assert!(to.join("repos/owner/repo").is_dir());
+        assert!(from.join("meetings/b").exists());
+        assert!(!from.join("repos").exists());
+
+        assert!(migrate_storage(&from, &from.join("nested")).is_err());
+
+        let moved = |p: &str| from.join(p).to_string_lossy().to_string();
+        assert_eq!(
+            report.relocate(&moved("meetings/a/transcript.json")),
+            Some(
+                to.join("meetings/a/transcript.json")
+                    .to_string_lossy()
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            report.relocate(&moved("repos/owner/repo")),
+            Some(to.join("repos/owner/repo").to_string_lossy().to_string())
+        );
+        assert_eq!(report.relocate(&moved("meetings/b")), None);
+        assert_eq!(report.relocate(&moved("meetings")), None);
+        assert_eq!(report.relocate("/elsewhere/project"), None);
+    }
+}
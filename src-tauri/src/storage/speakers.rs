@@ -0,0 +1,188 @@
+//! Enrolled voiceprints in `speakers.db`: one averaged voice embedding per named person,
+//! matched against meeting audio so speakers are labelled by name.
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS speakers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    embedding BLOB NOT NULL,
+    sample_count INTEGER NOT NULL,
+    sample_seconds REAL NOT NULL,
+    created_at INTEGER NOT NULL
+);";
+
+/// An enrolled person, without their embedding
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SpeakerProfile {
+    pub id: i64,
+    pub name: String,
+    /// Audio samples the voiceprint was averaged from
+    pub sample_count: usize,
+    pub sample_seconds: f64,
+    /// Milliseconds since the Unix epoch
+    pub created_at: i64,
+}
+
+/// A name and its L2-normalized voice embedding
+#[derive(Debug, Clone)]
+pub struct Voiceprint {
+    pub name: String,
+    pub embedding: Vec<f32>,
+}
+
+pub struct SpeakerDb {
+    db_path: PathBuf,
+}
+
+fn to_blob(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn from_blob(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn profile_from_row(row: &rusqlite::Row) -> rusqlite::Result<SpeakerProfile> {
+    Ok(SpeakerProfile {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        sample_count: row.get::<_, i64>(2)? as usize,
+        sample_seconds: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+impl SpeakerDb {
+    pub fn new(app: &AppHandle) -> Result<Arc<Self>> {
+        let db_path = app.path().app_data_dir()?.join("speakers.db");
+        Ok(Arc::new(Self::open_at(db_path)?))
+    }
+
+    /// Open (creating if needed) a database at an explicit path
+    pub fn open_at(db_path: PathBuf) -> Result<Self> {
+        fs::create_dir_all(db_path.parent().unwrap_or_else(|| Path::new(".")))?;
+        Connection::open(&db_path)?.execute_batch(SCHEMA)?;
+        Ok(Self { db_path })
+    }
+
+    fn open(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    /// Store a voiceprint, replacing any earlier one under the same name
+    pub fn enroll(
+        &self,
+        name: &str,
+        embedding: &[f32],
+        sample_count: usize,
+        sample_seconds: f64,
+    ) -> Result<SpeakerProfile> {
+        let conn = self.open()?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        conn.execute(
+            "INSERT INTO speakers (name, embedding, sample_count, sample_seconds, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(name) DO UPDATE SET name=excluded.name, embedding=excluded.embedding,
+               sample_count=excluded.sample_count, sample_seconds=excluded.sample_seconds,
+               created_at=excluded.created_at",
+            params![
+                name,
+                to_blob(embedding),
+                sample_count as i64,
+                sample_seconds,
+                now
+            ],
+        )?;
+        Ok(conn.query_row(
+            "SELECT id, name, sample_count, sample_seconds, created_at FROM speakers
+             WHERE name = ?1",
+            params![name],
+            profile_from_row,
+        )?)
+    }
+
+    /// Enrolled people, by name
+    pub fn list(&self) -> Result<Vec<SpeakerProfile>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, name, sample_count, sample_seconds, created_at FROM speakers
+             ORDER BY name COLLATE NOCASE",
+        )?;
+        let profiles = stmt
+            .query_map([], profile_from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(profiles)
+    }
+
+    /// Remove a voiceprint; returns its name, or None when the id is unknown
+    pub fn delete(&self, id: i64) -> Result<Option<String>> {
+        let conn = self.open()?;
+        let name: Option<String> = conn
+            .query_row(
+                "SELECT name FROM speakers WHERE id = ?1",
+                params![id],
+                |r| r.get(0),
+            )
+            .optional()?;
+        if name.is_some() {
+            conn.execute("DELETE FROM speakers WHERE id = ?1", params![id])?;
+        }
+        Ok(name)
+    }
+
+    /// Every enrolled voiceprint, for matching against meeting audio
+    pub fn voiceprints(&self) -> Result<Vec<Voiceprint>> {
+        let conn = self.open()?;
+        let mut stmt = conn.prepare("SELECT name, embedding FROM speakers")?;
+        let voiceprints = stmt
+            .query_map([], |row| {
+                Ok(Voiceprint {
+                    name: row.get(0)?,
+                    embedding: from_blob(&row.get::<_, Vec<u8>>(1)?),
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(voiceprints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn enroll_replaces_by_name_and_deletes() {
+        let dir = TempDir::new().unwrap();
+        let db = SpeakerDb::open_at(dir.path().join("speakers.db")).unwrap();
+
+        let alice = db.enroll("Alice", &[1.0, 0.0], 2, 8.5).unwrap();
+        db.enroll("Damon", &[0.0, 1.0], 1, 4.0).unwrap();
+        let again = db.enroll("alice", &[0.6, 0.8], 3, 12.0).unwrap();
+        assert_eq!(again.id, alice.id);
+        assert_eq!(again.sample_count, 3);
+
+        let names: Vec<String> = db.list().unwrap().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["alice", "Damon"]);
+        let prints = db.voiceprints().unwrap();
+        let print = prints.iter().find(|v| v.name == "alice").unwrap();
+        assert_eq!(print.embedding, vec![0.6, 0.8]);
+
+        assert_eq!(db.delete(alice.id).unwrap().as_deref(), Some("alice"));
+        assert_eq!(db.delete(alice.id).unwrap(), None);
+        assert_eq!(db.list().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,218 @@
+//! Per-meeting task tracker: the meeting's action items in `tasks.json` next to its
+//! transcript, with their done state, and exports of them as a checklist or CSV.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::summarization::action_items::{self, ActionItem};
+
+pub const TASKS_FILE: &str = "tasks.json";
+/// Supported task export formats (also the file extensions)
+pub const TASK_EXPORT_FORMATS: &[&str] = &["md", "csv"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskList {
+    pub meeting_id: String,
+    /// RFC3339
+    pub updated_at: String,
+    pub items: Vec<ActionItem>,
+}
+
+/// The meeting's tasks, or None when they haven't been extracted yet
+pub fn load(meeting_dir: &Path) -> Result<Option<TaskList>> {
+    let path = meeting_dir.join(TASKS_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&std::fs::read(path)?)?))
+}
+
+pub fn save(meeting_dir: &Path, list: &mut TaskList) -> Result<()> {
+    list.updated_at = chrono::Utc::now().to_rfc3339();
+    std::fs::write(
+        meeting_dir.join(TASKS_FILE),
+        serde_json::to_vec_pretty(list)?,
+    )?;
+    Ok(())
+}
+
+/// Replace the tasks with a fresh extraction, keeping what was already marked done.
+/// Done tasks whose sentence no longer appears (e.g. after a transcript edit) are kept.
+pub fn merge(existing: Option<TaskList>, meeting_id: &str, fresh: Vec<ActionItem>) -> TaskList {
+    let previous = existing.map(|l| l.items).unwrap_or_default();
+    let mut items: Vec<ActionItem> = fresh
+        .into_iter()
+        .map(|mut item| {
+            if let Some(old) = previous.iter().find(|o| o.id == item.id) {
+                item.done = old.done;
+                item.completed_at = old.completed_at.clone();
+            }
+            item
+        })
+        .collect();
+    for old in previous {
+        if old.done && !items.iter().any(|i| i.id == old.id) {
+            items.push(old);
+        }
+    }
+    items.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+    TaskList {
+        meeting_id: meeting_id.to_string(),
+        updated_at: String::new(),
+        items,
+    }
+}
+
+/// Extract the action items of a saved meeting and write them to `tasks.json`
+pub fn sync(
+    meeting_dir: &Path,
+    meeting_id: &str,
+    segments: &[crate::managers::meeting::TranscriptSegment],
+) -> Result<TaskList> {
+    let existing = load(meeting_dir).unwrap_or_else(|e| {
+        log::warn!(
+            "Ignoring unreadable {:?}: {}",
+            meeting_dir.join(TASKS_FILE),
+            e
+        );
+        None
+    });
+    let mut list = merge(existing, meeting_id, action_items::extract(segments, 0));
+    save(meeting_dir, &mut list)?;
+    Ok(list)
+}
+
+/// Mark a task done or not done
+pub fn set_done(list: &mut TaskList, task_id: &str, done: bool) -> Result<ActionItem> {
+    let item = list
+        .items
+        .iter_mut()
+        .find(|i| i.id == task_id)
+        .ok_or_else(|| anyhow!("Task {} not found", task_id))?;
+    item.done = done;
+    item.completed_at = done.then(|| chrono::Utc::now().to_rfc3339());
+    Ok(item.clone())
+}
+
+fn clock(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+/// Markdown checklist, for `summary.md` and exports
+pub fn checklist(items: &[ActionItem]) -> String {
+    let mut md = String::new();
+    for item in items {
+        let _ = writeln!(
+            md,
+            "- [{}] {}",
+            if item.done { "x" } else { " " },
+            action_items::describe(item)
+        );
+    }
+    md
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_csv(items: &[ActionItem]) -> String {
+    let mut csv = String::from("id,task,owner,due,speaker,time,done\n");
+    for item in items {
+        let time = clock(item.timestamp);
+        let fields = [
+            item.id.as_str(),
+            item.text.as_str(),
+            item.owner.as_deref().unwrap_or(""),
+            item.due.as_deref().unwrap_or(""),
+            item.speaker.as_str(),
+            &time,
+            if item.done { "yes" } else { "no" },
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        let _ = writeln!(csv, "{}", row.join(","));
+    }
+    csv
+}
+
+/// A meeting's tasks as a standalone file in `format` ("md" or "csv")
+pub fn render_export(
+    title: &str,
+    meeting_id: &str,
+    items: &[ActionItem],
+    format: &str,
+) -> Result<String> {
+    match format {
+        "md" => Ok(format!(
+            "# {}: Action Items\n\n{}\nOpen the meeting: meetingcoder://open-meeting/{}\n",
+            title,
+            checklist(items),
+            meeting_id
+        )),
+        "csv" => Ok(to_csv(items)),
+        other => Err(anyhow!(
+            "Unsupported task export format '{}'; expected one of {}",
+            other,
+            TASK_EXPORT_FORMATS.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, text: &str, at: f64) -> ActionItem {
+        ActionItem {
+            id: id.to_string(),
+            text: text.to_string(),
+            owner: Some("Dana".to_string()),
+            due: None,
+            speaker: "Dana".to_string(),
+            segment_index: 0,
+            timestamp: at,
+            done: false,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_done_state_and_exports() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(load(temp_dir.path()).unwrap().is_none());
+
+        let mut list = merge(
+            None,
+            "m-1",
+            vec![item("a", "Ship it", 1.0), item("b", "Old, \"x\"", 2.0)],
+        );
+        set_done(&mut list, "b", true).unwrap();
+        assert!(set_done(&mut list, "zzz", true).is_err());
+        save(temp_dir.path(), &mut list).unwrap();
+
+        // "b" is gone from the transcript but was done, so it stays; "c" is new
+        let fresh = vec![item("c", "Write docs", 0.5), item("a", "Ship it", 1.0)];
+        let merged = merge(load(temp_dir.path()).unwrap(), "m-1", fresh);
+        let ids: Vec<&str> = merged.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "a", "b"]);
+        assert!(merged.items[2].done && merged.items[2].completed_at.is_some());
+
+        assert_eq!(
+            checklist(&merged.items[1..]),
+            "- [ ] Ship it (Dana)\n- [x] Old, \"x\" (Dana)\n"
+        );
+        let csv = to_csv(&merged.items[2..]);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("b,\"Old, \"\"x\"\"\",Dana,,Dana,00:02,yes")
+        );
+        assert!(render_export("Planning", "m-1", &merged.items, "pdf").is_err());
+    }
+}
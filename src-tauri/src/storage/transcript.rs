@@ -1,9 +1,22 @@
 use crate::managers::meeting::{MeetingSession, TranscriptSegment};
+use crate::summarization::chapters::{self, Chapter};
 use anyhow::Result;
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Earlier transcripts of a meeting, one subdirectory per replaced version
+pub const VERSIONS_DIR: &str = "versions";
+/// Files that describe a transcript and are kept when it's replaced
+const VERSIONED_FILES: &[&str] = &[
+    "metadata.json",
+    "transcript.json",
+    "transcript.md",
+    "summary.md",
+    crate::storage::tasks::TASKS_FILE,
+];
 
 /// Metadata for a meeting transcript
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +27,9 @@ pub struct TranscriptMetadata {
     pub end_time: String,   // ISO 8601 format
     pub duration_seconds: u64,
     pub participants: Vec<String>,
+    /// Topic chapters of long meetings, with times in seconds from meeting start
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chapters: Vec<Chapter>,
 }
 
 /// Full transcript with metadata and segments
@@ -54,11 +70,9 @@ impl TranscriptStorage {
         Ok(Self { base_path })
     }
 
-    /// Get the default storage path in the user's home directory
+    /// Get the default storage path (`meetings/` under the configured storage root)
     pub fn default_path() -> Result<PathBuf> {
-        let home = dirs::home_dir()
-            .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        Ok(home.join("MeetingCoder").join("meetings"))
+        crate::storage::root::storage_dir("meetings")
     }
 
     /// Create a new instance with the default path
@@ -119,6 +133,26 @@ impl TranscriptStorage {
             }
         }
 
+        self.write_transcript_files(&meeting_dir, meeting)?;
+        log::info!("Saved transcript to: {}", meeting_dir.display());
+
+        Ok(meeting_dir)
+    }
+
+    /// Rewrite an existing meeting directory after its transcript was edited. The directory
+    /// keeps its name even if the meeting was renamed.
+    pub fn rewrite_transcript(&self, meeting_dir_name: &str, meeting: &MeetingSession) -> Result<PathBuf> {
+        let meeting_dir = self.base_path.join(meeting_dir_name);
+        if !meeting_dir.is_dir() {
+            return Err(anyhow::anyhow!("Meeting directory not found: {}", meeting_dir.display()));
+        }
+        self.write_transcript_files(&meeting_dir, meeting)?;
+        log::info!("Rewrote transcript in: {}", meeting_dir.display());
+        Ok(meeting_dir)
+    }
+
+    /// Write metadata.json, transcript.json, and transcript.md into `meeting_dir`
+    fn write_transcript_files(&self, meeting_dir: &Path, meeting: &MeetingSession) -> Result<()> {
         // Save metadata.json
         let metadata = self.create_metadata(meeting)?;
         let metadata_path = meeting_dir.join("metadata.json");
@@ -138,10 +172,7 @@ impl TranscriptStorage {
         let markdown_path = meeting_dir.join("transcript.md");
         let markdown = self.generate_markdown(meeting)?;
         fs::write(&markdown_path, markdown)?;
-
-        log::info!("Saved transcript to: {}", meeting_dir.display());
-
-        Ok(meeting_dir)
+        Ok(())
     }
 
     /// Create metadata from a meeting session
@@ -163,6 +194,7 @@ impl TranscriptStorage {
             end_time: end_datetime.to_rfc3339(),
             duration_seconds: duration.as_secs(),
             participants: meeting.participants.clone(),
+            chapters: chapters::detect(&meeting.transcript_segments),
         })
     }
 
@@ -190,7 +222,14 @@ impl TranscriptStorage {
         for segment in &meeting.transcript_segments {
             let timestamp = self.format_timestamp(segment.start_time);
             markdown.push_str(&format!("**[{}] {}:**\n", timestamp, segment.speaker));
-            markdown.push_str(&format!("{}\n\n", segment.text));
+            if crate::managers::transcription::is_low_confidence(segment.confidence) {
+                markdown.push_str(&format!("{} _(low confidence)_\n\n", segment.text));
+            } else {
+                markdown.push_str(&format!("{}\n\n", segment.text));
+            }
+            if let Some(translated) = &segment.translated_text {
+                markdown.push_str(&format!("> {}\n\n", translated));
+            }
         }
 
         Ok(markdown)
@@ -206,6 +245,25 @@ impl TranscriptStorage {
         format!("{:02}:{:02}:{:02}", hours, minutes, secs)
     }
 
+    /// Copy a meeting's transcript, summary, and tasks into `versions/<label>/` before
+    /// they are replaced. Returns the version directory.
+    pub fn snapshot_transcript(&self, meeting_dir_name: &str, label: &str) -> Result<PathBuf> {
+        let meeting_dir = self.base_path.join(meeting_dir_name);
+        let label: String = label
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+            .collect();
+        let version_dir = meeting_dir.join(VERSIONS_DIR).join(label);
+        fs::create_dir_all(&version_dir)?;
+        for name in VERSIONED_FILES {
+            let source = meeting_dir.join(name);
+            if source.is_file() {
+                fs::copy(&source, version_dir.join(name))?;
+            }
+        }
+        Ok(version_dir)
+    }
+
     /// Load a transcript from disk
     pub fn load_transcript(&self, meeting_dir_name: &str) -> Result<(TranscriptMetadata, TranscriptData)> {
         let meeting_dir = self.base_path.join(meeting_dir_name);
@@ -227,6 +285,24 @@ impl TranscriptStorage {
         Ok((metadata, transcript))
     }
 
+    /// Load a saved meeting back into a completed session (without project path)
+    pub fn load_session(&self, meeting_dir_name: &str) -> Result<MeetingSession> {
+        let (metadata, transcript) = self.load_transcript(meeting_dir_name)?;
+        let parse = |value: &str| DateTime::parse_from_rfc3339(value).ok().map(SystemTime::from);
+        Ok(MeetingSession {
+            id: metadata.meeting_id,
+            name: metadata.name,
+            start_time: parse(&metadata.start_time).unwrap_or(UNIX_EPOCH),
+            end_time: parse(&metadata.end_time),
+            transcript_segments: transcript.segments,
+            status: crate::managers::meeting::MeetingStatus::Completed,
+            participants: metadata.participants,
+            project_path: None,
+            translation_language: None,
+            cloud_asr: false,
+        })
+    }
+
     /// List all saved meeting directories
     pub fn list_meetings(&self) -> Result<Vec<String>> {
         if !self.base_path.exists() {
@@ -252,6 +328,25 @@ impl TranscriptStorage {
         Ok(meetings)
     }
 
+    /// Root directory holding one subdirectory per meeting
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    /// Find the directory of a saved meeting by its id
+    pub fn find_meeting_dir(&self, meeting_id: &str) -> Result<Option<String>> {
+        for dir_name in self.list_meetings()? {
+            let metadata_path = self.base_path.join(&dir_name).join("metadata.json");
+            let Ok(content) = fs::read_to_string(&metadata_path) else { continue; };
+            if let Ok(metadata) = serde_json::from_str::<TranscriptMetadata>(&content) {
+                if metadata.meeting_id == meeting_id {
+                    return Ok(Some(dir_name));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     /// Delete a meeting transcript
     pub fn delete_transcript(&self, meeting_dir_name: &str) -> Result<()> {
         let meeting_dir = self.base_path.join(meeting_dir_name);
@@ -292,6 +387,8 @@ mod tests {
                     text: "Hello, welcome to the meeting.".to_string(),
                     confidence: 0.95,
                     timestamp: start,
+                    words: Vec::new(),
+                    translated_text: None,
                 },
                 TranscriptSegment {
                     speaker: "Speaker 2".to_string(),
@@ -300,8 +397,12 @@ mod tests {
                     text: "Thanks, glad to be here.".to_string(),
                     confidence: 0.92,
                     timestamp: start + Duration::from_secs(3),
+                    words: Vec::new(),
+                    translated_text: None,
                 },
             ],
+            translation_language: None,
+            cloud_asr: false,
         }
     }
 
@@ -327,6 +428,20 @@ mod tests {
         assert_eq!(transcript.segments.len(), 2);
     }
 
+    #[test]
+    fn test_snapshot_transcript() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = TranscriptStorage::new(temp_dir.path().to_path_buf()).unwrap();
+        let saved_path = storage.save_transcript(&create_test_meeting()).unwrap();
+        let dir_name = saved_path.file_name().unwrap().to_str().unwrap();
+
+        let version = storage.snapshot_transcript(dir_name, "2026-10-16 09:00").unwrap();
+        assert_eq!(version, saved_path.join(VERSIONS_DIR).join("2026-10-16-09-00"));
+        assert!(version.join("transcript.json").is_file());
+        // Files the meeting doesn't have yet are skipped
+        assert!(!version.join("summary.md").exists());
+    }
+
     #[test]
     fn test_list_meetings() {
         let temp_dir = TempDir::new().unwrap();
@@ -361,6 +476,30 @@ mod tests {
         assert!(markdown_content.contains("[00:00:00] Speaker 1:"));
     }
 
+    #[test]
+    fn test_edit_saved_transcript_keeps_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = TranscriptStorage::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let saved_path = storage.save_transcript(&create_test_meeting()).unwrap();
+        let dir_name = saved_path.file_name().unwrap().to_str().unwrap();
+
+        let mut session = storage.load_session(dir_name).unwrap();
+        assert_eq!(session.id, "test-123");
+        assert!(session.end_time.is_some());
+        let mapping = [("Speaker 1".to_string(), "Alice".to_string())].into_iter().collect();
+        session.relabel_speakers(&mapping);
+        session.name = "Renamed Meeting".to_string();
+        storage.rewrite_transcript(dir_name, &session).unwrap();
+
+        let (metadata, transcript) = storage.load_transcript(dir_name).unwrap();
+        assert_eq!(metadata.name, "Renamed Meeting");
+        assert_eq!(metadata.participants, vec!["Alice", "Speaker 2"]);
+        assert_eq!(transcript.segments[0].speaker, "Alice");
+        assert_eq!(storage.list_meetings().unwrap().len(), 1);
+        assert!(storage.rewrite_transcript("missing", &session).is_err());
+    }
+
     #[test]
     fn test_delete_transcript() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,202 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::storage::root::move_path;
+use crate::storage::usage::dir_size;
+
+/// Written into each trashed meeting directory; removed again on restore
+const TRASH_INFO_FILE: &str = ".trash.json";
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// A deleted meeting that can still be restored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedMeeting {
+    /// Directory name inside the trash; identifies the entry for restore
+    pub trash_id: String,
+    /// Original directory name under the transcript storage root
+    pub dir_name: String,
+    pub meeting_id: Option<String>,
+    pub name: Option<String>,
+    /// Unix milliseconds
+    pub deleted_at: i64,
+    #[serde(skip_deserializing)]
+    pub expires_at: i64,
+    #[serde(skip_deserializing)]
+    pub bytes: u64,
+}
+
+/// Trash location under the configured storage root
+pub fn trash_dir() -> Result<PathBuf> {
+    crate::storage::root::storage_dir("trash")
+}
+
+/// Security: names come from the frontend; only accept a single path component
+fn is_plain_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['/', '\\']) && name != "." && name != ".."
+}
+
+fn read_info(entry_dir: &Path) -> Option<TrashedMeeting> {
+    let content = fs::read_to_string(entry_dir.join(TRASH_INFO_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Move `meetings_base/dir_name` into the trash. A meeting deleted twice under the same
+/// directory name gets a numbered trash entry instead of replacing the earlier one.
+pub fn move_to_trash(
+    meetings_base: &Path,
+    trash_base: &Path,
+    dir_name: &str,
+    meeting_id: Option<String>,
+    name: Option<String>,
+    now_ms: i64,
+) -> Result<TrashedMeeting> {
+    if !is_plain_name(dir_name) {
+        return Err(anyhow!("Invalid meeting directory: {}", dir_name));
+    }
+    let source = meetings_base.join(dir_name);
+    if !source.is_dir() {
+        return Err(anyhow!("Meeting directory not found: {}", dir_name));
+    }
+    fs::create_dir_all(trash_base)?;
+    let mut trash_id = dir_name.to_string();
+    let mut n = 1;
+    while trash_base.join(&trash_id).exists() {
+        n += 1;
+        trash_id = format!("{}_{}", dir_name, n);
+    }
+    let target = trash_base.join(&trash_id);
+    move_path(&source, &target).with_context(|| format!("Failed to move {:?} to trash", source))?;
+
+    let entry = TrashedMeeting {
+        trash_id,
+        dir_name: dir_name.to_string(),
+        meeting_id,
+        name,
+        deleted_at: now_ms,
+        expires_at: 0,
+        bytes: 0,
+    };
+    fs::write(
+        target.join(TRASH_INFO_FILE),
+        serde_json::to_string_pretty(&entry)?,
+    )?;
+    log::info!("Moved meeting {} to trash", dir_name);
+    Ok(entry)
+}
+
+/// Trashed meetings, most recently deleted first. Directories without trash info
+/// (e.g. copied in by hand) are skipped.
+pub fn list_trash(trash_base: &Path, retention_days: u32) -> Result<Vec<TrashedMeeting>> {
+    if !trash_base.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(trash_base)?.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let Some(mut info) = read_info(&path) else {
+            continue;
+        };
+        info.trash_id = entry.file_name().to_string_lossy().to_string();
+        info.expires_at = info.deleted_at + retention_days as i64 * DAY_MS;
+        info.bytes = dir_size(&path);
+        entries.push(info);
+    }
+    entries.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    Ok(entries)
+}
+
+/// Move a trashed meeting back to its original directory
+pub fn restore_from_trash(
+    trash_base: &Path,
+    meetings_base: &Path,
+    trash_id: &str,
+) -> Result<TrashedMeeting> {
+    if !is_plain_name(trash_id) {
+        return Err(anyhow!("Invalid trash entry: {}", trash_id));
+    }
+    let source = trash_base.join(trash_id);
+    let info = read_info(&source).ok_or_else(|| anyhow!("Trash entry not found: {}", trash_id))?;
+    let target = meetings_base.join(&info.dir_name);
+    if target.exists() {
+        return Err(anyhow!(
+            "A meeting directory named {} already exists",
+            info.dir_name
+        ));
+    }
+    fs::create_dir_all(meetings_base)?;
+    move_path(&source, &target)?;
+    let _ = fs::remove_file(target.join(TRASH_INFO_FILE));
+    log::info!("Restored meeting {} from trash", info.dir_name);
+    Ok(info)
+}
+
+/// Permanently delete trash entries older than `retention_days`; returns how many were removed
+pub fn purge_expired(trash_base: &Path, retention_days: u32, now_ms: i64) -> Result<usize> {
+    let mut removed = 0;
+    for entry in list_trash(trash_base, retention_days)? {
+        if entry.expires_at <= now_ms {
+            fs::remove_dir_all(trash_base.join(&entry.trash_id))?;
+            log::info!("Purged trashed meeting {}", entry.dir_name);
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_trash_restore_and_purge() {
+        let temp_dir = TempDir::new().unwrap();
+        let meetings = temp_dir.path().join("meetings");
+        let trash = temp_dir.path().join("trash");
+        let now = 1_800_000_000_000;
+        fs::create_dir_all(meetings.join("2026-01-01_standup")).unwrap();
+        fs::write(meetings.join("2026-01-01_standup/transcript.json"), "{}").unwrap();
+
+        let first = move_to_trash(
+            &meetings,
+            &trash,
+            "2026-01-01_standup",
+            Some("m-1".into()),
+            None,
+            now,
+        )
+        .unwrap();
+        assert!(!meetings.join("2026-01-01_standup").exists());
+
+        // Same directory name deleted again gets its own entry
+        fs::create_dir_all(meetings.join("2026-01-01_standup")).unwrap();
+        let second =
+            move_to_trash(&meetings, &trash, "2026-01-01_standup", None, None, now + 1).unwrap();
+        assert_eq!(second.trash_id, "2026-01-01_standup_2");
+
+        let listed = list_trash(&trash, 30).unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].trash_id, second.trash_id);
+        assert_eq!(listed[1].expires_at, now + 30 * DAY_MS);
+
+        let restored = restore_from_trash(&trash, &meetings, &first.trash_id).unwrap();
+        assert_eq!(restored.meeting_id.as_deref(), Some("m-1"));
+        assert!(meetings.join("2026-01-01_standup/transcript.json").exists());
+        assert!(!meetings
+            .join("2026-01-01_standup")
+            .join(TRASH_INFO_FILE)
+            .exists());
+        // Original location is occupied again
+        assert!(restore_from_trash(&trash, &meetings, &second.trash_id).is_err());
+        assert!(restore_from_trash(&trash, &meetings, "../meetings").is_err());
+
+        assert_eq!(purge_expired(&trash, 30, now).unwrap(), 0);
+        assert_eq!(purge_expired(&trash, 30, now + 31 * DAY_MS).unwrap(), 1);
+        assert!(list_trash(&trash, 30).unwrap().is_empty());
+    }
+}
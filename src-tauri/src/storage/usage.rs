@@ -0,0 +1,349 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::storage::db::StoredMeeting;
+use crate::storage::playback::FULL_AUDIO_FILE;
+
+const AUDIO_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg"];
+
+/// Bytes used by one saved meeting
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MeetingUsage {
+    pub meeting_id: String,
+    pub name: String,
+    pub dir_name: Option<String>,
+    /// Transcript, metadata, and summary files
+    pub transcript_bytes: u64,
+    /// Composed audio in the meeting directory
+    pub full_audio_bytes: u64,
+    /// Per-chunk WAVs in the project's `audio_segments/`
+    pub segment_bytes: u64,
+    pub segment_count: usize,
+}
+
+/// Disk usage across everything the app stores
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageUsage {
+    pub total_bytes: u64,
+    pub transcripts_bytes: u64,
+    pub full_audio_bytes: u64,
+    pub segment_bytes: u64,
+    pub models_bytes: u64,
+    /// Exported bundles
+    pub downloads_bytes: u64,
+    /// Dictation recordings kept by history
+    pub recordings_bytes: u64,
+    pub meetings: Vec<MeetingUsage>,
+}
+
+/// Locations outside the transcript root that count toward usage
+pub struct UsageRoots {
+    pub transcripts: PathBuf,
+    pub models: PathBuf,
+    pub exports: PathBuf,
+    pub recordings: PathBuf,
+}
+
+/// Result of a cleanup action
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CleanupReport {
+    pub files_removed: usize,
+    pub bytes_freed: u64,
+}
+
+pub fn dir_size(path: &Path) -> u64 {
+    if path.is_dir() {
+        fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| dir_size(&e.path()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    } else {
+        fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+fn is_audio(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| AUDIO_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+fn files_in(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// How long after a meeting ends its last chunk may still be written
+const SEGMENT_GRACE_MS: i64 = 60_000;
+
+fn modified_ms(path: &Path) -> Option<i64> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_millis() as i64)
+}
+
+/// Chunk WAVs in a project directory (`audio_segments/segment_*.wav`)
+fn project_segment_files(project_path: &Path) -> Vec<PathBuf> {
+    files_in(&project_path.join("audio_segments"))
+        .into_iter()
+        .filter(|p| {
+            p.extension().map(|e| e == "wav").unwrap_or(false)
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|n| n.starts_with("segment_"))
+                    .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Chunk WAVs written while `meeting` was recording. Meetings of a project share its
+/// `audio_segments/` and file names carry no meeting id, so segments are matched by
+/// modification time: from the meeting's start to its end plus a grace period, cut short
+/// where the project's next meeting starts. A meeting in progress has none yet.
+pub fn segment_files(meeting: &StoredMeeting, meetings: &[StoredMeeting]) -> Vec<PathBuf> {
+    let (Some(project_path), Some(end_time)) = (&meeting.project_path, meeting.end_time) else {
+        return Vec::new();
+    };
+    let next_start = meetings
+        .iter()
+        .filter(|m| {
+            m.id != meeting.id
+                && m.project_path.as_ref() == Some(project_path)
+                && m.start_time > meeting.start_time
+        })
+        .map(|m| m.start_time)
+        .min();
+    let until = next_start.map_or(end_time + SEGMENT_GRACE_MS, |next| {
+        next.min(end_time + SEGMENT_GRACE_MS)
+    });
+    project_segment_files(Path::new(project_path))
+        .into_iter()
+        .filter(|p| modified_ms(p).is_some_and(|t| t >= meeting.start_time && t < until))
+        .collect()
+}
+
+/// Summarize usage. Each meeting is charged the segments it recorded; the segment total
+/// covers every project directory, including segments no saved meeting claims.
+pub fn compute_usage(meetings: &[StoredMeeting], roots: &UsageRoots) -> StorageUsage {
+    let mut usage = StorageUsage {
+        models_bytes: dir_size(&roots.models),
+        downloads_bytes: dir_size(&roots.exports),
+        recordings_bytes: dir_size(&roots.recordings),
+        ..Default::default()
+    };
+    let projects: HashSet<&String> = meetings
+        .iter()
+        .filter_map(|m| m.project_path.as_ref())
+        .collect();
+    usage.segment_bytes = projects
+        .into_iter()
+        .flat_map(|p| project_segment_files(Path::new(p)))
+        .map(|p| dir_size(&p))
+        .sum();
+    for meeting in meetings {
+        let mut entry = MeetingUsage {
+            meeting_id: meeting.id.clone(),
+            name: meeting.name.clone(),
+            dir_name: meeting.dir_name.clone(),
+            ..Default::default()
+        };
+        if let Some(ref dir_name) = meeting.dir_name {
+            for file in files_in(&roots.transcripts.join(dir_name)) {
+                let size = dir_size(&file);
+                if is_audio(&file) {
+                    entry.full_audio_bytes += size;
+                } else {
+                    entry.transcript_bytes += size;
+                }
+            }
+        }
+        let segments = segment_files(meeting, meetings);
+        entry.segment_count = segments.len();
+        entry.segment_bytes = segments.iter().map(|p| dir_size(p)).sum();
+        usage.transcripts_bytes += entry.transcript_bytes;
+        usage.full_audio_bytes += entry.full_audio_bytes;
+        usage.meetings.push(entry);
+    }
+    usage.total_bytes = usage.transcripts_bytes
+        + usage.full_audio_bytes
+        + usage.segment_bytes
+        + usage.models_bytes
+        + usage.downloads_bytes
+        + usage.recordings_bytes;
+    usage
+}
+
+fn remove_files(files: &[PathBuf]) -> Result<CleanupReport> {
+    let mut report = CleanupReport::default();
+    for file in files {
+        let size = dir_size(file);
+        fs::remove_file(file)?;
+        report.files_removed += 1;
+        report.bytes_freed += size;
+    }
+    Ok(report)
+}
+
+/// Run a targeted cleanup, optionally restricted to one meeting:
+/// - `segment_wavs`: the chunk WAVs each meeting recorded, only for meetings whose
+///   composed `full_audio.wav` exists
+/// - `full_audio`: composed audio in meeting directories
+/// - `exports`: exported bundles (never meeting-scoped)
+/// - `partial_models`: interrupted model downloads (never meeting-scoped)
+pub fn cleanup(
+    action: &str,
+    meetings: &[StoredMeeting],
+    meeting_id: Option<&str>,
+    roots: &UsageRoots,
+) -> Result<CleanupReport> {
+    let selected: Vec<&StoredMeeting> = meetings
+        .iter()
+        .filter(|m| meeting_id.map(|id| m.id == id).unwrap_or(true))
+        .collect();
+    if meeting_id.is_some() && selected.is_empty() {
+        return Err(anyhow!("Meeting not found"));
+    }
+    let files: Vec<PathBuf> = match action {
+        "segment_wavs" => selected
+            .iter()
+            .filter(|m| {
+                m.dir_name
+                    .as_ref()
+                    .map(|d| roots.transcripts.join(d).join(FULL_AUDIO_FILE).is_file())
+                    .unwrap_or(false)
+            })
+            .flat_map(|m| segment_files(m, meetings))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect(),
+        "full_audio" => selected
+            .iter()
+            .filter_map(|m| m.dir_name.as_ref())
+            .flat_map(|d| files_in(&roots.transcripts.join(d)))
+            .filter(|p| is_audio(p))
+            .collect(),
+        "exports" => files_in(&roots.exports)
+            .into_iter()
+            .filter(|p| p.extension().map(|e| e == "zip").unwrap_or(false))
+            .collect(),
+        "partial_models" => files_in(&roots.models)
+            .into_iter()
+            .filter(|p| p.extension().map(|e| e == "partial").unwrap_or(false))
+            .collect(),
+        other => return Err(anyhow!("Unknown cleanup action: {}", other)),
+    };
+    remove_files(&files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use tempfile::TempDir;
+
+    const MINUTE_MS: i64 = 60_000;
+
+    fn meeting(id: &str, project: &Path, start_ms: i64, end_ms: i64) -> StoredMeeting {
+        StoredMeeting {
+            id: id.to_string(),
+            name: id.to_string(),
+            start_time: start_ms,
+            end_time: Some(end_ms),
+            participants: Vec::new(),
+            project_path: Some(project.to_string_lossy().to_string()),
+            dir_name: Some(id.to_string()),
+            segment_count: 0,
+            starred: false,
+        }
+    }
+
+    fn write_segment(path: &Path, modified_ms: i64) {
+        fs::write(path, "1234").unwrap();
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(UNIX_EPOCH + Duration::from_millis(modified_ms as u64))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_usage_and_segment_cleanup_keeps_composed_audio() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let roots = UsageRoots {
+            transcripts: root.join("meetings"),
+            models: root.join("models"),
+            exports: root.join("exports"),
+            recordings: root.join("recordings"),
+        };
+        let composed = roots.transcripts.join("a");
+        fs::create_dir_all(&composed).unwrap();
+        fs::write(composed.join("transcript.json"), "{}").unwrap();
+        fs::write(composed.join(FULL_AUDIO_FILE), "12345678").unwrap();
+        fs::create_dir_all(roots.transcripts.join("b")).unwrap();
+        // Both meetings recorded into the same project
+        let project = root.join("projects/app");
+        let segments = project.join("audio_segments");
+        fs::create_dir_all(&segments).unwrap();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let segment_a = segments.join("segment_000000_0-1000.wav");
+        let segment_b = segments.join("segment_000001_0-1000.wav");
+        write_segment(&segment_a, now - 90 * MINUTE_MS);
+        write_segment(&segment_b, now - 20 * MINUTE_MS);
+        fs::create_dir_all(&roots.models).unwrap();
+        fs::write(roots.models.join("small.bin.partial"), "12").unwrap();
+
+        let meetings = vec![
+            meeting("a", &project, now - 120 * MINUTE_MS, now - 60 * MINUTE_MS),
+            meeting("b", &project, now - 30 * MINUTE_MS, now - 10 * MINUTE_MS),
+        ];
+        assert_eq!(
+            segment_files(&meetings[0], &meetings),
+            vec![segment_a.clone()]
+        );
+        assert_eq!(
+            segment_files(&meetings[1], &meetings),
+            vec![segment_b.clone()]
+        );
+        let usage = compute_usage(&meetings, &roots);
+        assert_eq!(usage.full_audio_bytes, 8);
+        assert_eq!(usage.transcripts_bytes, 2);
+        assert_eq!(usage.segment_bytes, 8);
+        assert_eq!(usage.meetings[0].segment_bytes, 4);
+        assert_eq!(usage.total_bytes, 20);
+
+        // Meeting b has no composed audio, so its segments are kept even though they sit
+        // next to meeting a's
+        let report = cleanup("segment_wavs", &meetings, Some("a"), &roots).unwrap();
+        assert_eq!(report.files_removed, 1);
+        assert!(!segment_a.exists());
+        assert!(segment_b.exists());
+        assert!(composed.join(FULL_AUDIO_FILE).exists());
+        let report = cleanup("segment_wavs", &meetings, None, &roots).unwrap();
+        assert_eq!(report.files_removed, 0);
+
+        assert_eq!(
+            cleanup("partial_models", &meetings, None, &roots)
+                .unwrap()
+                .bytes_freed,
+            2
+        );
+        assert!(cleanup("everything", &meetings, None, &roots).is_err());
+    }
+}
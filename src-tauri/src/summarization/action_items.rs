@@ -0,0 +1,327 @@
+//! Action items: commitments and requests spoken in a meeting ("I'll send the notes by
+//! Friday", "Alice, can you review the PR?"), each with who owns it, when it's due, and
+//! the segment it came from.
+
+use serde::{Deserialize, Serialize};
+
+use crate::managers::meeting::TranscriptSegment;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionItem {
+    /// Stable across re-extraction: a hash of the normalized sentence
+    pub id: String,
+    pub text: String,
+    /// Who is on the hook, when the sentence says so
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The deadline as spoken, e.g. "by Friday" or "end of the week"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// Who said it
+    pub speaker: String,
+    /// Index of the source segment in the full transcript
+    pub segment_index: usize,
+    /// Start of the source segment, in seconds from meeting start
+    pub timestamp: f64,
+    #[serde(default)]
+    pub done: bool,
+    /// RFC3339, set when marked done
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+}
+
+/// The speaker commits to doing it themselves
+const SELF_PHRASES: &[&str] = &[
+    "i'll ",
+    "i will ",
+    "i'm going to ",
+    "i am going to ",
+    "i can take ",
+];
+/// Someone else (named or not) is asked or assigned to do it
+const REQUEST_PHRASES: &[&str] = &[
+    "can you ",
+    "could you ",
+    "would you ",
+    "please ",
+    "we'll ",
+    "we will ",
+    "we need to ",
+    "action item",
+    "todo",
+    "to-do",
+    "follow up",
+    "follow-up",
+    "assigned to ",
+];
+/// Words that start a sentence without naming a person
+const NOT_NAMES: &[&str] = &[
+    "I", "We", "You", "They", "He", "She", "It", "This", "That", "Let", "Let's", "So", "And",
+    "But", "Then", "The", "Okay", "OK", "Yes", "Yeah", "No", "Please", "Can", "Could", "Would",
+    "Action", "Todo", "Also", "Maybe", "Someone", "Somebody",
+];
+/// Deadlines, longest first so "next week" wins over "week"
+const DUE_WORDS: &[&str] = &[
+    "end of the sprint",
+    "end of the month",
+    "end of the week",
+    "end of the day",
+    "end of sprint",
+    "end of month",
+    "end of week",
+    "end of day",
+    "next sprint",
+    "next month",
+    "next week",
+    "this week",
+    "tomorrow",
+    "tonight",
+    "today",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "asap",
+    "eod",
+    "eow",
+];
+const DUE_PREPOSITIONS: &[&str] = &["by ", "before ", "until ", "on ", "due "];
+
+/// Stable id of a sentence, ignoring case, spacing, and punctuation
+pub fn text_id(s: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let normalized: String = s
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ')
+        .collect();
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    normalized
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .hash(&mut hasher);
+    format!("t{:016x}", hasher.finish())
+}
+
+/// Byte offset of `needle` in `haystack` as a whole word (or phrase)
+fn find_word(haystack: &str, needle: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(pos) = haystack[from..].find(needle) {
+        let start = from + pos;
+        let end = start + needle.len();
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[end..].chars().next();
+        if !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric) {
+            return Some(start);
+        }
+        from = end;
+    }
+    None
+}
+
+/// The deadline phrase in a sentence, with its preposition ("by Friday")
+pub fn due_phrase(sentence: &str) -> Option<String> {
+    // Lowercasing ASCII keeps byte offsets valid for slicing the original
+    let lower = sentence.to_ascii_lowercase();
+    let (start, word) = DUE_WORDS
+        .iter()
+        .filter_map(|w| find_word(&lower, w).map(|i| (i, *w)))
+        .min_by_key(|(i, w)| (*i, std::cmp::Reverse(w.len())))?;
+    let start = DUE_PREPOSITIONS
+        .iter()
+        .find(|p| lower[..start].ends_with(*p))
+        .map(|p| start - p.len())
+        .unwrap_or(start);
+    let end = sentence
+        .len()
+        .min(start + (lower[start..].find(word)? + word.len()));
+    Some(sentence[start..end].to_string())
+}
+
+/// A capitalized first word that reads like a name: "Alice will…", "Bob, can you…"
+fn leading_name(sentence: &str) -> Option<String> {
+    let first = sentence.split_whitespace().next()?;
+    let name = first.trim_end_matches([',', ':']);
+    let is_name = name.chars().next().is_some_and(char::is_uppercase)
+        && name.chars().all(|c| c.is_alphabetic() || c == '-')
+        && !NOT_NAMES.contains(&name);
+    is_name.then(|| name.to_string())
+}
+
+/// The name after "assigned to"
+fn assignee(sentence: &str) -> Option<String> {
+    let lower = sentence.to_ascii_lowercase();
+    let at = find_word(&lower, "assigned to")? + "assigned to".len();
+    let name = sentence[at..]
+        .split_whitespace()
+        .next()?
+        .trim_matches(|c: char| !c.is_alphanumeric());
+    name.chars()
+        .next()
+        .is_some_and(char::is_uppercase)
+        .then(|| name.to_string())
+}
+
+/// Owner and due phrase of the action item in one sentence, if it is one
+fn parse_sentence(sentence: &str, speaker: &str) -> Option<(Option<String>, Option<String>)> {
+    let lower = format!("{} ", sentence.to_ascii_lowercase());
+    let has = |phrases: &[&str]| {
+        phrases
+            .iter()
+            .any(|p| find_word(&lower, p.trim_end()).is_some())
+    };
+    let is_self = has(SELF_PHRASES);
+    // "Alice will…", "Bob to…", "Priya, can you…"
+    let name = leading_name(sentence).filter(|name| {
+        let rest = lower[name.len()..].trim_start_matches([',', ':', ' ']);
+        [
+            "will ",
+            "is going to ",
+            "to ",
+            "can you ",
+            "could you ",
+            "please ",
+        ]
+        .iter()
+        .any(|p| rest.starts_with(p))
+    });
+    if !is_self && name.is_none() && !has(REQUEST_PHRASES) {
+        return None;
+    }
+    // Other questions are open questions, not tasks
+    if sentence.ends_with('?') && !has(&["can you ", "could you ", "would you "]) {
+        return None;
+    }
+    let owner = assignee(sentence)
+        .or(name)
+        .or_else(|| is_self.then(|| speaker.to_string()));
+    Some((owner, due_phrase(sentence)))
+}
+
+/// Action items in `segments`, whose first element is segment `start_index` of the meeting
+pub fn extract(segments: &[TranscriptSegment], start_index: usize) -> Vec<ActionItem> {
+    let mut items: Vec<ActionItem> = Vec::new();
+    for (offset, seg) in segments.iter().enumerate() {
+        let mut rest = seg.text.as_str();
+        while !rest.trim().is_empty() {
+            let end = rest
+                .find(['.', '!', '?'])
+                .map(|i| i + 1)
+                .unwrap_or(rest.len());
+            let sentence = rest[..end].trim();
+            rest = &rest[end..];
+            // Too short to say what needs doing
+            if sentence.split_whitespace().count() < 3 {
+                continue;
+            }
+            let Some((owner, due)) = parse_sentence(sentence, &seg.speaker) else {
+                continue;
+            };
+            let id = text_id(sentence);
+            if items.iter().any(|i| i.id == id) {
+                continue;
+            }
+            items.push(ActionItem {
+                id,
+                text: sentence.to_string(),
+                owner,
+                due,
+                speaker: seg.speaker.clone(),
+                segment_index: start_index + offset,
+                timestamp: seg.start_time,
+                done: false,
+                completed_at: None,
+            });
+        }
+    }
+    items
+}
+
+/// "I'll send the notes by Friday (Dana, by Friday)" for lists
+pub fn describe(item: &ActionItem) -> String {
+    let text = item.text.trim_end_matches('.');
+    let details: Vec<&str> = [item.owner.as_deref(), item.due.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    if details.is_empty() {
+        text.to_string()
+    } else {
+        format!("{} ({})", text, details.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn segment(speaker: &str, start: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: speaker.to_string(),
+            start_time: start,
+            end_time: start + 5.0,
+            text: text.to_string(),
+            confidence: 1.0,
+            timestamp: SystemTime::now(),
+            words: Vec::new(),
+            translated_text: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_action_items() {
+        let segments = vec![
+            segment(
+                "Dana",
+                0.0,
+                "Thanks for joining. I'll send the notes by Friday.",
+            ),
+            segment(
+                "Sam",
+                5.0,
+                "Bob, can you review the login PR? What do we ship?",
+            ),
+            segment(
+                "Dana",
+                10.0,
+                "Priya will update the roadmap before next week.",
+            ),
+            segment(
+                "Sam",
+                15.0,
+                "Action item: migrate the CI config, assigned to Lee.",
+            ),
+        ];
+        let items = extract(&segments, 3);
+        assert_eq!(items.len(), 4);
+
+        assert_eq!(items[0].text, "I'll send the notes by Friday.");
+        assert_eq!(items[0].owner.as_deref(), Some("Dana"));
+        assert_eq!(items[0].due.as_deref(), Some("by Friday"));
+        assert_eq!(items[0].segment_index, 3);
+
+        assert_eq!(items[1].owner.as_deref(), Some("Bob"));
+        assert_eq!(items[1].due, None);
+        assert_eq!(items[1].speaker, "Sam");
+
+        assert_eq!(items[2].owner.as_deref(), Some("Priya"));
+        assert_eq!(items[2].due.as_deref(), Some("before next week"));
+
+        assert_eq!(items[3].owner.as_deref(), Some("Lee"));
+        assert_eq!(items[3].segment_index, 6);
+        assert_eq!(
+            describe(&items[0]),
+            "I'll send the notes by Friday (Dana, by Friday)"
+        );
+
+        // Stable ids regardless of spacing and case
+        assert_eq!(
+            extract(&[segment("X", 0.0, "i'll  send the NOTES by friday")], 0)[0].id,
+            items[0].id
+        );
+    }
+}
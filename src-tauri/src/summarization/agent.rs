@@ -1,4 +1,5 @@
 use crate::managers::meeting::TranscriptSegment;
+use crate::summarization::action_items::{self, ActionItem};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -46,6 +47,9 @@ pub struct SummarizationOutput {
     // Code-aware: files mentioned in transcript
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub target_files: Vec<String>,
+    // Commitments and requests with owner and due phrase
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub action_items: Vec<ActionItem>,
 }
 
 fn contains_any(haystack: &str, needles: &[&str]) -> bool {
@@ -189,6 +193,10 @@ pub fn summarize_segments_with_context(
     let mut seen = load_seen_feature_ids(project_path, 50);
 
     for seg in segments {
+        // Text the engine wasn't sure of stays out of summaries
+        if crate::managers::transcription::is_low_confidence(seg.confidence) {
+            continue;
+        }
         // Split into rough sentences
         let mut sentence = String::new();
         for ch in seg.text.chars() {
@@ -231,5 +239,6 @@ pub fn summarize_segments_with_context(
         modified_features: None,
         clarifications: None,
         target_files: Vec::new(),  // Will be populated by LLM or file extraction logic
+        action_items: action_items::extract(segments, start_index),
     }
 }
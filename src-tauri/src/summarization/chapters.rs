@@ -0,0 +1,245 @@
+//! Chapters for long meetings. Each gap between segments is scored by how similar the
+//! words of the few minutes before it are to the few minutes after it; the sharpest
+//! drops in similarity become topic boundaries, and each chapter is titled with the
+//! words that set it apart from the rest of the meeting.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::managers::meeting::TranscriptSegment;
+
+/// Meetings shorter than this (seconds) are a single topic
+const MIN_MEETING_SECONDS: f64 = 600.0;
+/// No chapter is shorter than this (seconds)
+const MIN_CHAPTER_SECONDS: f64 = 180.0;
+/// Speech compared on each side of a candidate boundary (seconds)
+const WINDOW_SECONDS: f64 = 120.0;
+const MAX_CHAPTERS: usize = 12;
+const TITLE_WORDS: usize = 3;
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "that", "this", "with", "you", "are", "was", "were", "but", "not", "have",
+    "has", "had", "just", "like", "yeah", "okay", "know", "think", "going", "get", "got", "can",
+    "will", "would", "could", "should", "what", "when", "where", "which", "who", "how", "there",
+    "their", "they", "them", "then", "than", "from", "about", "into", "our", "out", "all", "some",
+    "one", "also", "really", "right", "well", "mean", "kind", "sort", "thing", "things", "lot",
+    "let", "it's", "that's", "don't", "i'm", "we're", "you're", "gonna", "want", "need", "make",
+    "see", "say", "said", "been", "being", "its", "his", "her", "she", "him", "any", "yes", "now",
+    "here", "because", "maybe", "actually",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    pub title: String,
+    /// Seconds from meeting start
+    pub start_time: f64,
+    pub end_time: f64,
+    /// Indices of the first and last segment in the chapter (inclusive)
+    pub first_segment: usize,
+    pub last_segment: usize,
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .map(|w| w.trim_matches('\'').to_lowercase())
+        .filter(|w| w.chars().count() >= 3 && !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+fn counts<'a>(bags: impl Iterator<Item = &'a Vec<String>>) -> HashMap<&'a str, f32> {
+    let mut counts = HashMap::new();
+    for word in bags.flatten() {
+        *counts.entry(word.as_str()).or_default() += 1.0;
+    }
+    counts
+}
+
+fn cosine(a: &HashMap<&str, f32>, b: &HashMap<&str, f32>) -> f32 {
+    let dot: f32 = a.iter().filter_map(|(k, v)| b.get(k).map(|w| v * w)).sum();
+    let norm = |m: &HashMap<&str, f32>| m.values().map(|v| v * v).sum::<f32>().sqrt();
+    let denominator = norm(a) * norm(b);
+    if denominator <= f32::EPSILON {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+/// Chapters of a meeting's segments, in order; empty for short meetings
+pub fn detect(segments: &[TranscriptSegment]) -> Vec<Chapter> {
+    let (Some(first), Some(last)) = (segments.first(), segments.last()) else {
+        return Vec::new();
+    };
+    let (meeting_start, meeting_end) = (first.start_time, last.end_time);
+    if meeting_end - meeting_start < MIN_MEETING_SECONDS {
+        return Vec::new();
+    }
+    let bags: Vec<Vec<String>> = segments.iter().map(|s| words(&s.text)).collect();
+
+    // Similarity across each gap; boundary `b` starts a chapter at segment `b`
+    let mut candidates: Vec<(usize, f32)> = (1..segments.len())
+        .filter_map(|b| {
+            let at = segments[b].start_time;
+            if at - meeting_start < MIN_CHAPTER_SECONDS || meeting_end - at < MIN_CHAPTER_SECONDS {
+                return None;
+            }
+            let before = counts(
+                (0..b)
+                    .filter(|&i| segments[i].start_time >= at - WINDOW_SECONDS)
+                    .map(|i| &bags[i]),
+            );
+            let after = counts(
+                (b..segments.len())
+                    .filter(|&i| segments[i].start_time < at + WINDOW_SECONDS)
+                    .map(|i| &bags[i]),
+            );
+            Some((b, cosine(&before, &after)))
+        })
+        .collect();
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+    let n = candidates.len() as f32;
+    let mean = candidates.iter().map(|(_, s)| s).sum::<f32>() / n;
+    let std = (candidates
+        .iter()
+        .map(|(_, s)| (s - mean).powi(2))
+        .sum::<f32>()
+        / n)
+        .sqrt();
+    let cutoff = mean - std / 2.0;
+
+    // Deepest drops first, keeping chapters apart
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    let mut boundaries: Vec<usize> = Vec::new();
+    for (b, similarity) in candidates {
+        if similarity >= cutoff || boundaries.len() + 1 >= MAX_CHAPTERS {
+            break;
+        }
+        let at = segments[b].start_time;
+        if boundaries
+            .iter()
+            .all(|&o| (segments[o].start_time - at).abs() >= MIN_CHAPTER_SECONDS)
+        {
+            boundaries.push(b);
+        }
+    }
+    boundaries.sort_unstable();
+
+    let mut starts = vec![0];
+    starts.extend(boundaries);
+    let spans: Vec<(usize, usize)> = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).map_or(segments.len(), |&next| next);
+            (start, end - 1)
+        })
+        .collect();
+    let titles = titles(&bags, &spans);
+    spans
+        .into_iter()
+        .zip(titles)
+        .map(|((first, last), title)| Chapter {
+            title,
+            start_time: segments[first].start_time,
+            end_time: segments[last].end_time,
+            first_segment: first,
+            last_segment: last,
+        })
+        .collect()
+}
+
+/// Title per chapter from its most frequent words, favouring words the other chapters
+/// don't use
+fn titles(bags: &[Vec<String>], spans: &[(usize, usize)]) -> Vec<String> {
+    let per_chapter: Vec<HashMap<&str, f32>> = spans
+        .iter()
+        .map(|&(first, last)| counts(bags[first..=last].iter()))
+        .collect();
+    let mut chapters_with: HashMap<&str, f32> = HashMap::new();
+    for chapter in &per_chapter {
+        for word in chapter.keys() {
+            *chapters_with.entry(*word).or_default() += 1.0;
+        }
+    }
+    let total = per_chapter.len() as f32;
+    per_chapter
+        .iter()
+        .enumerate()
+        .map(|(i, chapter)| {
+            let mut scored: Vec<(&str, f32)> = chapter
+                .iter()
+                .map(|(&w, &count)| (w, count * (1.0 + total / chapters_with[w]).ln()))
+                .collect();
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+            let top: Vec<&str> = scored
+                .into_iter()
+                .map(|(w, _)| w)
+                .take(TITLE_WORDS)
+                .collect();
+            match top.split_first() {
+                Some((head, rest)) => {
+                    let mut title: String = head
+                        .chars()
+                        .take(1)
+                        .flat_map(char::to_uppercase)
+                        .chain(head.chars().skip(1))
+                        .collect();
+                    for word in rest {
+                        title.push_str(", ");
+                        title.push_str(word);
+                    }
+                    title
+                }
+                None => format!("Chapter {}", i + 1),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn meeting(topics: &[(&str, usize)]) -> Vec<TranscriptSegment> {
+        let mut segments = Vec::new();
+        for (text, count) in topics {
+            for _ in 0..*count {
+                let start = segments.len() as f64 * 30.0;
+                segments.push(TranscriptSegment {
+                    speaker: "Speaker 1".to_string(),
+                    start_time: start,
+                    end_time: start + 30.0,
+                    text: text.to_string(),
+                    confidence: 0.9,
+                    timestamp: SystemTime::now(),
+                    words: Vec::new(),
+                    translated_text: None,
+                });
+            }
+        }
+        segments
+    }
+
+    #[test]
+    fn test_splits_at_topic_change() {
+        let segments = meeting(&[
+            ("The database migration changes the schema", 20),
+            ("The landing page design uses brighter colors", 20),
+        ]);
+        let chapters = detect(&segments);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(
+            (chapters[0].first_segment, chapters[0].last_segment),
+            (0, 19)
+        );
+        assert_eq!(chapters[1].start_time, 600.0);
+        assert_eq!(chapters[1].end_time, 1200.0);
+        assert!(chapters[0].title.to_lowercase().contains("database"));
+        assert!(chapters[1].title.to_lowercase().contains("design"));
+
+        assert!(detect(&segments[..10]).is_empty());
+    }
+}
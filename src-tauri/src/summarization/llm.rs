@@ -1,96 +1,53 @@
 use anyhow::{anyhow, Result};
-use serde::{Deserialize, Serialize};
-use std::env;
-use std::fs;
-use std::path::Path;
-
-const KEYCHAIN_SERVICE: &str = "com.meetingcoder.app";
-const KEYCHAIN_ACCOUNT: &str = "claude_api_key";
-
-/// Fallback API key storage path for when keyring fails (development mode)
-fn get_api_key_fallback_path() -> Result<std::path::PathBuf> {
-    let home = env::var("HOME")
-        .or_else(|_| env::var("USERPROFILE"))
-        .map_err(|_| anyhow!("Could not determine home directory"))?;
-    let config_dir = Path::new(&home).join(".handy");
-    fs::create_dir_all(&config_dir)?;
-    Ok(config_dir.join(".claude-api-key"))
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::sync::RwLock;
+
+use crate::secrets;
+use crate::settings::{AppSettings, LlmProviderKind};
+use crate::summarization::providers::{self, LlmProvider};
+
+pub(crate) const KEYCHAIN_SERVICE: &str = "com.meetingcoder.app";
+pub(crate) const KEYCHAIN_ACCOUNT: &str = "claude_api_key";
+pub(crate) const OPENAI_KEYCHAIN_ACCOUNT: &str = "openai_api_key";
+
+/// Store a secret securely using keyring with fallback
+fn store_secret(account: &str, file_name: &str, label: &str, value: &str) -> Result<()> {
+    log::info!("Attempting to store {} (length: {})", label, value.len());
+    secrets::store(KEYCHAIN_SERVICE, account, file_name, value)
 }
 
-/// Store Claude API key securely using keyring with fallback
-pub fn store_api_key(api_key: &str) -> Result<()> {
-    log::info!("Attempting to store Claude API key (length: {})", api_key.len());
-
-    // Try keyring first
-    let keyring_result = (|| -> Result<()> {
-        let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)
-            .map_err(|e| anyhow!("Failed to create keyring entry: {}", e))?;
-        entry
-            .set_password(api_key)
-            .map_err(|e| anyhow!("Failed to store API key in keyring: {}", e))?;
-        log::info!("Successfully stored Claude API key in system keyring");
-        Ok(())
-    })();
-
-    if keyring_result.is_ok() {
-        return Ok(());
-    }
+/// Retrieve a secret from keyring or fallback
+fn get_secret(account: &str, file_name: &str, label: &str) -> Result<String> {
+    secrets::load(KEYCHAIN_SERVICE, account, file_name)
+        .map(|key| key.trim().to_string())
+        .map_err(|_| anyhow!("No {} found. Please configure one first.", label))
+}
 
-    // Fallback to file-based storage
-    log::warn!(
-        "Keyring storage failed: {:?}, falling back to file storage",
-        keyring_result
-    );
-    let fallback_path = get_api_key_fallback_path()?;
-    fs::write(&fallback_path, api_key)?;
-    log::info!(
-        "Stored Claude API key in fallback file: {}",
-        fallback_path.display()
-    );
+/// Delete a secret from both keyring and fallback
+fn delete_secret(account: &str, file_name: &str) -> Result<()> {
+    secrets::delete(KEYCHAIN_SERVICE, account, file_name);
     Ok(())
 }
 
+/// Store Claude API key securely using keyring with fallback
+pub fn store_api_key(api_key: &str) -> Result<()> {
+    store_secret(
+        KEYCHAIN_ACCOUNT,
+        ".claude-api-key",
+        "Claude API key",
+        api_key,
+    )
+}
+
 /// Retrieve Claude API key from keyring or fallback
 pub fn get_api_key() -> Result<String> {
-    // Try keyring first
-    let keyring_result = (|| -> Result<String> {
-        let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)
-            .map_err(|e| anyhow!("Failed to create keyring entry: {}", e))?;
-        let key = entry
-            .get_password()
-            .map_err(|e| anyhow!("Failed to retrieve API key from keyring: {}", e))?;
-        Ok(key)
-    })();
-
-    if let Ok(key) = keyring_result {
-        return Ok(key);
-    }
-
-    // Try fallback file
-    let fallback_path = get_api_key_fallback_path()?;
-    if fallback_path.exists() {
-        let key = fs::read_to_string(&fallback_path)?;
-        return Ok(key.trim().to_string());
-    }
-
-    Err(anyhow!("No Claude API key found. Please configure one first."))
+    get_secret(KEYCHAIN_ACCOUNT, ".claude-api-key", "Claude API key")
 }
 
 /// Delete Claude API key from both keyring and fallback
 pub fn delete_api_key() -> Result<()> {
-    // Try to delete from keyring
-    let _ = (|| -> Result<()> {
-        let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)?;
-        entry.delete_credential()?;
-        Ok(())
-    })();
-
-    // Try to delete fallback file
-    if let Ok(fallback_path) = get_api_key_fallback_path() {
-        let _ = fs::remove_file(fallback_path);
-    }
-
-    Ok(())
+    delete_secret(KEYCHAIN_ACCOUNT, ".claude-api-key")
 }
 
 /// Check if API key is configured
@@ -98,35 +55,97 @@ pub fn has_api_key() -> bool {
     get_api_key().is_ok()
 }
 
-// ===== Claude API Structures =====
+/// Store the key for OpenAI-compatible endpoints
+pub fn store_openai_api_key(api_key: &str) -> Result<()> {
+    store_secret(
+        OPENAI_KEYCHAIN_ACCOUNT,
+        ".openai-api-key",
+        "OpenAI API key",
+        api_key,
+    )
+}
+
+pub fn get_openai_api_key() -> Result<String> {
+    get_secret(OPENAI_KEYCHAIN_ACCOUNT, ".openai-api-key", "OpenAI API key")
+}
+
+pub fn delete_openai_api_key() -> Result<()> {
+    delete_secret(OPENAI_KEYCHAIN_ACCOUNT, ".openai-api-key")
+}
 
-#[derive(Debug, Serialize)]
-struct ClaudeRequest {
+pub fn has_openai_api_key() -> bool {
+    get_openai_api_key().is_ok()
+}
+
+// ===== Provider selection =====
+
+/// The `llm_*` settings, read by summarization and PRD generation without an AppHandle
+struct LlmConfig {
+    provider: LlmProviderKind,
+    base_url: Option<String>,
     model: String,
-    max_tokens: u32,
-    messages: Vec<ClaudeMessage>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    system: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct ClaudeMessage {
-    role: String, // "user" or "assistant"
-    content: String,
+static CONFIG: Lazy<RwLock<LlmConfig>> = Lazy::new(|| {
+    RwLock::new(LlmConfig {
+        provider: LlmProviderKind::default(),
+        base_url: None,
+        model: String::new(),
+    })
+});
+
+/// Apply the LLM provider settings; called at startup and whenever they change
+pub fn apply_settings(settings: &AppSettings) {
+    let mut config = CONFIG.write().unwrap();
+    config.provider = settings.llm_provider;
+    config.base_url = settings
+        .llm_base_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty())
+        .map(str::to_string);
+    config.model = providers::effective_model(settings.llm_provider, &settings.llm_model);
+    log::info!(
+        "LLM provider: {:?} (model {}, endpoint {})",
+        config.provider,
+        config.model,
+        config
+            .base_url
+            .as_deref()
+            .unwrap_or(providers::default_base_url(config.provider))
+    );
 }
 
-#[derive(Debug, Deserialize)]
-struct ClaudeResponse {
-    content: Vec<ClaudeContent>,
-    #[allow(dead_code)]
-    stop_reason: Option<String>,
+/// The configured provider and the model to ask it for
+pub fn provider() -> (Box<dyn LlmProvider>, String) {
+    let config = CONFIG.read().unwrap();
+    (
+        providers::for_kind(config.provider, config.base_url.as_deref()),
+        providers::effective_model(config.provider, &config.model),
+    )
 }
 
-#[derive(Debug, Deserialize)]
-struct ClaudeContent {
-    #[serde(rename = "type")]
-    content_type: String,
-    text: String,
+/// Whether the configured provider can be called (e.g. has its API key)
+pub fn is_available() -> bool {
+    provider().0.is_configured()
+}
+
+/// Send a prompt to the configured provider and return its reply
+pub async fn complete(system_prompt: &str, user_prompt: &str) -> Result<String> {
+    let (provider, model) = provider();
+    log::info!("Calling {} ({})", provider.name(), model);
+    let user_prompt = crate::redaction::outgoing(user_prompt);
+    provider.complete(&model, system_prompt, &user_prompt).await
+}
+
+/// The JSON in a model reply; local models often wrap it in a ```json fence
+pub fn json_body(text: &str) -> &str {
+    let text = text.trim();
+    let Some(rest) = text.strip_prefix("```") else {
+        return text;
+    };
+    let rest = rest.strip_prefix("json").unwrap_or(rest);
+    rest.strip_suffix("```").unwrap_or(rest).trim()
 }
 
 #[derive(Debug, Deserialize)]
@@ -148,58 +167,6 @@ pub struct ExtractedFeature {
     #[serde(default)]
     pub confidence: f64,
 }
-
-// ===== Claude API Client =====
-
-pub async fn call_claude_api(
-    model: &str,
-    system_prompt: &str,
-    user_prompt: &str,
-) -> Result<String> {
-    let api_key = get_api_key()?;
-
-    let request = ClaudeRequest {
-        model: model.to_string(),
-        max_tokens: 4096,
-        messages: vec![ClaudeMessage {
-            role: "user".to_string(),
-            content: user_prompt.to_string(),
-        }],
-        system: Some(system_prompt.to_string()),
-    };
-
-    let client = reqwest::Client::new();
-    let response = client
-        .post("https://api.anthropic.com/v1/messages")
-        .header("x-api-key", api_key)
-        .header("anthropic-version", "2023-06-01")
-        .header("content-type", "application/json")
-        .json(&request)
-        .send()
-        .await
-        .map_err(|e| anyhow!("Failed to send request to Claude API: {}", e))?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let error_text = response.text().await.unwrap_or_default();
-        return Err(anyhow!("Claude API error {}: {}", status, error_text));
-    }
-
-    let claude_response: ClaudeResponse = response
-        .json()
-        .await
-        .map_err(|e| anyhow!("Failed to parse Claude API response: {}", e))?;
-
-    // Extract text from first content block
-    let text = claude_response
-        .content
-        .first()
-        .map(|c| c.text.clone())
-        .ok_or_else(|| anyhow!("No content in Claude API response"))?;
-
-    Ok(text)
-}
-
 // ===== Prompt Templates =====
 
 pub fn get_system_prompt() -> &'static str {
@@ -235,17 +202,38 @@ Guidelines:
 Output valid JSON only (no markdown, no explanation)."#
 }
 
-pub fn build_extraction_prompt(transcript_text: &str, is_first_update: bool) -> String {
+pub fn build_extraction_prompt(
+    transcript_text: &str,
+    is_first_update: bool,
+    code_context: Option<&str>,
+) -> String {
     let mut prompt = format!(
         r#"Extract requirements from this meeting transcript segment:
 
 <transcript>
 {}
 </transcript>
+"#,
+        transcript_text
+    );
+
+    if let Some(code) = code_context.filter(|c| !c.trim().is_empty()) {
+        prompt.push_str(&format!(
+            r#"
+Code from the project that may relate to this discussion (use it to name target_files precisely):
+
+<code_context>
+{}
+</code_context>
+"#,
+            code
+        ));
+    }
 
+    prompt.push_str(
+        r#"
 Return JSON in this format:
-{{"#,
-        transcript_text
+{"#,
     );
 
     if is_first_update {
@@ -289,11 +277,11 @@ use crate::summarization::agent::{Feature, Priority, SummarizationOutput};
 use std::collections::HashSet;
 
 pub async fn summarize_with_llm(
-    model: &str,
     segments: &[TranscriptSegment],
     start_index: usize,
     end_index: usize,
     is_first_update: bool,
+    code_context: Option<&str>,
 ) -> Result<SummarizationOutput> {
     // Combine transcript segments into a single text
     let mut transcript_text = String::new();
@@ -305,16 +293,16 @@ pub async fn summarize_with_llm(
     }
 
     let system_prompt = get_system_prompt();
-    let user_prompt = build_extraction_prompt(&transcript_text, is_first_update);
+    let user_prompt = build_extraction_prompt(&transcript_text, is_first_update, code_context);
 
-    log::info!("Calling Claude API for summarization...");
-    let response_text = call_claude_api(model, system_prompt, &user_prompt).await?;
+    log::info!("Calling LLM for summarization...");
+    let response_text = complete(system_prompt, &user_prompt).await?;
 
-    log::debug!("Claude API response: {}", response_text);
+    log::debug!("LLM response: {}", response_text);
 
     // Parse JSON response
-    let extraction: ExtractionResult = serde_json::from_str(&response_text)
-        .map_err(|e| anyhow!("Failed to parse Claude API JSON response: {}\nResponse: {}", e, response_text))?;
+    let extraction: ExtractionResult = serde_json::from_str(json_body(&response_text))
+        .map_err(|e| anyhow!("Failed to parse LLM JSON response: {}\nResponse: {}", e, response_text))?;
 
     log::info!(
         "LLM extracted {} features, {} decisions, {} questions",
@@ -361,5 +349,18 @@ pub async fn summarize_with_llm(
         modified_features: None,
         clarifications: None,
         target_files: extraction.target_files,
+        action_items: crate::summarization::action_items::extract(segments, start_index),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_body_strips_code_fences() {
+        assert_eq!(json_body(" {\"a\": 1} "), "{\"a\": 1}");
+        assert_eq!(json_body("```json\n{\"a\": 1}\n```"), "{\"a\": 1}");
+        assert_eq!(json_body("```\n[]\n```"), "[]");
+    }
+}
@@ -1,2 +1,5 @@
+pub mod action_items;
 pub mod agent;
+pub mod chapters;
 pub mod llm;
+pub mod providers;
@@ -0,0 +1,279 @@
+//! LLM backends for summaries and PRDs. Each takes a system and a user prompt and returns
+//! the reply text: Claude through the Anthropic API, anything speaking OpenAI's chat
+//! completions API (OpenAI, Gemini, LM Studio, vLLM…), and a local Ollama server, which
+//! keeps transcripts on the machine and works in offline mode.
+
+use anyhow::{anyhow, Result};
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::network::{self, Service};
+use crate::settings::LlmProviderKind;
+
+const MAX_TOKENS: u32 = 4096;
+
+pub trait LlmProvider: Send + Sync {
+    /// For logs and errors, e.g. "Ollama"
+    fn name(&self) -> &'static str;
+    /// Whether requests can be made, i.e. an API key is stored where one is required
+    fn is_configured(&self) -> bool;
+    fn complete<'a>(
+        &'a self,
+        model: &'a str,
+        system_prompt: &'a str,
+        user_prompt: &'a str,
+    ) -> BoxFuture<'a, Result<String>>;
+}
+
+/// Endpoint used when `llm_base_url` is unset
+pub fn default_base_url(kind: LlmProviderKind) -> &'static str {
+    match kind {
+        LlmProviderKind::Anthropic => "https://api.anthropic.com",
+        LlmProviderKind::OpenAi => "https://api.openai.com/v1",
+        LlmProviderKind::Ollama => "http://localhost:11434",
+    }
+}
+
+/// Model used when `llm_model` is unset or names another provider's model
+pub fn default_model(kind: LlmProviderKind) -> &'static str {
+    match kind {
+        LlmProviderKind::Anthropic => "claude-sonnet-4-5-20250929",
+        LlmProviderKind::OpenAi => "gpt-4o-mini",
+        LlmProviderKind::Ollama => "llama3.1",
+    }
+}
+
+/// The model to request: `model` unless it's empty or a Claude model sent elsewhere
+pub fn effective_model(kind: LlmProviderKind, model: &str) -> String {
+    let model = model.trim();
+    if model.is_empty() || (kind != LlmProviderKind::Anthropic && model.starts_with("claude-")) {
+        default_model(kind).to_string()
+    } else {
+        model.to_string()
+    }
+}
+
+/// Check a base URL from settings; only HTTP(S) endpoints are supported
+pub fn validate_base_url(url: &str) -> Result<(), String> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+        _ => Err(format!(
+            "LLM base URL must be an http(s) URL, got '{}'",
+            url
+        )),
+    }
+}
+
+pub fn for_kind(kind: LlmProviderKind, base_url: Option<&str>) -> Box<dyn LlmProvider> {
+    let base_url = base_url
+        .unwrap_or(default_base_url(kind))
+        .trim_end_matches('/')
+        .to_string();
+    match kind {
+        LlmProviderKind::Anthropic => Box::new(Anthropic { base_url }),
+        LlmProviderKind::OpenAi => Box::new(OpenAiCompatible { base_url }),
+        LlmProviderKind::Ollama => Box::new(Ollama { base_url }),
+    }
+}
+
+async fn post_json(
+    url: &str,
+    name: &str,
+    request: reqwest::RequestBuilder,
+) -> Result<serde_json::Value> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to send request to {} at {}: {}", name, url, e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(anyhow!("{} API error {}: {}", name, status, error_text));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| anyhow!("Failed to parse {} API response: {}", name, e))
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+/// Claude through the Anthropic Messages API
+pub struct Anthropic {
+    base_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeResponse {
+    content: Vec<ClaudeContent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeContent {
+    #[serde(default)]
+    text: String,
+}
+
+impl LlmProvider for Anthropic {
+    fn name(&self) -> &'static str {
+        "Claude"
+    }
+
+    fn is_configured(&self) -> bool {
+        super::llm::has_api_key()
+    }
+
+    fn complete<'a>(
+        &'a self,
+        model: &'a str,
+        system_prompt: &'a str,
+        user_prompt: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let api_key = super::llm::get_api_key()?;
+            let url = format!("{}/v1/messages", self.base_url);
+            let request = network::client(Service::Llm, &url)?
+                .post(&url)
+                .header("x-api-key", api_key)
+                .header("anthropic-version", "2023-06-01")
+                .json(&json!({
+                    "model": model,
+                    "max_tokens": MAX_TOKENS,
+                    "system": system_prompt,
+                    "messages": [ChatMessage { role: "user", content: user_prompt }],
+                }));
+            let body = post_json(&url, self.name(), request).await?;
+            let response: ClaudeResponse = serde_json::from_value(body)?;
+            response
+                .content
+                .into_iter()
+                .next()
+                .map(|c| c.text)
+                .ok_or_else(|| anyhow!("No content in Claude API response"))
+        })
+    }
+}
+
+/// OpenAI's chat completions API, or a server compatible with it
+pub struct OpenAiCompatible {
+    base_url: String,
+}
+
+impl LlmProvider for OpenAiCompatible {
+    fn name(&self) -> &'static str {
+        "OpenAI-compatible"
+    }
+
+    /// Self-hosted servers usually don't need a key; openai.com does
+    fn is_configured(&self) -> bool {
+        super::llm::has_openai_api_key()
+            || self.base_url != default_base_url(LlmProviderKind::OpenAi)
+    }
+
+    fn complete<'a>(
+        &'a self,
+        model: &'a str,
+        system_prompt: &'a str,
+        user_prompt: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let url = format!("{}/chat/completions", self.base_url);
+            let mut request = network::client(Service::Llm, &url)?
+                .post(&url)
+                .json(&json!({
+                    "model": model,
+                    "max_tokens": MAX_TOKENS,
+                    "messages": [
+                        ChatMessage { role: "system", content: system_prompt },
+                        ChatMessage { role: "user", content: user_prompt },
+                    ],
+                }));
+            if let Ok(key) = super::llm::get_openai_api_key() {
+                request = request.bearer_auth(key);
+            }
+            let body = post_json(&url, self.name(), request).await?;
+            body["choices"][0]["message"]["content"]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("No content in {} API response", self.name()))
+        })
+    }
+}
+
+/// A local Ollama server
+pub struct Ollama {
+    base_url: String,
+}
+
+impl LlmProvider for Ollama {
+    fn name(&self) -> &'static str {
+        "Ollama"
+    }
+
+    fn is_configured(&self) -> bool {
+        true
+    }
+
+    fn complete<'a>(
+        &'a self,
+        model: &'a str,
+        system_prompt: &'a str,
+        user_prompt: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let url = format!("{}/api/chat", self.base_url);
+            let request = network::client(Service::Llm, &url)?
+                .post(&url)
+                .json(&json!({
+                    "model": model,
+                    "stream": false,
+                    "messages": [
+                        ChatMessage { role: "system", content: system_prompt },
+                        ChatMessage { role: "user", content: user_prompt },
+                    ],
+                }));
+            let body = post_json(&url, self.name(), request).await?;
+            body["message"]["content"]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("No content in Ollama response"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_defaults() {
+        assert_eq!(
+            effective_model(LlmProviderKind::Ollama, "claude-sonnet-4-5-20250929"),
+            "llama3.1"
+        );
+        assert_eq!(
+            effective_model(LlmProviderKind::OpenAi, " gpt-4.1 "),
+            "gpt-4.1"
+        );
+        assert_eq!(
+            effective_model(LlmProviderKind::Anthropic, ""),
+            default_model(LlmProviderKind::Anthropic)
+        );
+
+        let ollama = for_kind(LlmProviderKind::Ollama, None);
+        assert_eq!(ollama.name(), "Ollama");
+        assert!(ollama.is_configured());
+        // A self-hosted endpoint needs no key
+        assert!(
+            for_kind(LlmProviderKind::OpenAi, Some("http://localhost:1234/v1/")).is_configured()
+        );
+
+        assert!(validate_base_url("http://localhost:11434").is_ok());
+        assert!(validate_base_url("ftp://example.com").is_err());
+    }
+}
@@ -104,6 +104,16 @@ impl SpscRingBuffer {
 
     /// Drain up to n samples from the ring into a Vec. Single consumer only.
     pub fn drain_n(&self, n: usize) -> Vec<f32> {
+        self.read_n(n, true)
+    }
+
+    /// Copy up to n samples without consuming them, so the next drain still returns them.
+    /// Single consumer only.
+    pub fn peek_n(&self, n: usize) -> Vec<f32> {
+        self.read_n(n, false)
+    }
+
+    fn read_n(&self, n: usize, consume: bool) -> Vec<f32> {
         if n == 0 { return Vec::new(); }
 
         let cap = self.cap;
@@ -131,7 +141,9 @@ impl SpscRingBuffer {
         }
 
         // Publish new tail
-        self.tail.store(tail + to_read, Ordering::Release);
+        if consume {
+            self.tail.store(tail + to_read, Ordering::Release);
+        }
         out
     }
 }
@@ -5,6 +5,8 @@
 //! via channels.
 
 use super::{ring_buffer::SpscRingBuffer, SystemAudioCapture, SystemAudioCaptureDevice, VirtualDeviceInfo};
+use crate::audio_toolkit::audio::NoiseSuppressor;
+use crate::managers::audio::{self, NOISE_SUPPRESSION};
 use anyhow::Result;
 use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
 use std::sync::mpsc::{channel, Sender};
@@ -134,6 +136,8 @@ impl SendableSystemAudio {
 
                         // Set up callback with resampling. Buffer input to meet rubato's required frame size.
                         let mut in_accumulator: Vec<f32> = Vec::new();
+                        let mut suppressor: Option<NoiseSuppressor> = None;
+                        let mut suppressing = false;
                         let callback = Box::new(move |chunk: Vec<f32>| {
                             let mut out_to_push: Vec<f32> = Vec::new();
 
@@ -171,6 +175,16 @@ impl SendableSystemAudio {
                                 out_to_push = chunk;
                             }
 
+                            // Denoise at 16 kHz before the samples reach the buffer and VAD
+                            let denoise = NOISE_SUPPRESSION.load(Ordering::Relaxed);
+                            if denoise != suppressing {
+                                suppressing = denoise;
+                                suppressor = if denoise { audio::noise_suppressor() } else { None };
+                            }
+                            if let Some(s) = suppressor.as_mut() {
+                                out_to_push = s.process(&out_to_push);
+                            }
+
                             if !out_to_push.is_empty() {
                                 buffer.push(&out_to_push);
                             }
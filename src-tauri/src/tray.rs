@@ -1,13 +1,50 @@
+use crate::events::{self, AppEvent};
+use crate::managers::meeting::{
+    default_meeting_name, ActiveMeetingInfo, MeetingManager, MeetingStatus, TranscriptSegment,
+};
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIcon;
-use tauri::{AppHandle, Manager, Theme};
+use tauri::{AppHandle, Manager, Theme, Wry};
+
+/// What the current tray menu was built for: icon state and (meeting id, paused)
+type MenuKey = (TrayIconState, Option<(String, bool)>);
+
+static MENU_STATE: Lazy<Mutex<MenuKey>> = Lazy::new(|| Mutex::new((TrayIconState::Idle, None)));
+/// Elapsed-time label of the meeting section, updated in place every second
+static MEETING_LABEL: Lazy<Mutex<Option<MenuItem<Wry>>>> = Lazy::new(|| Mutex::new(None));
+/// "Live Transcript" submenu of the meeting section and the lines it currently shows
+static TRANSCRIPT_MENU: Lazy<Mutex<Option<(Submenu<Wry>, Vec<String>)>>> =
+    Lazy::new(|| Mutex::new(None));
+/// Transcript lines shown in the tray, newest last
+const TRAY_TRANSCRIPT_LINES: usize = 4;
+/// Longer lines are cut so the menu stays a sensible width
+const TRAY_LINE_CHARS: usize = 80;
+/// Icon currently shown, so the refresher only swaps images when something changed
+static CURRENT_ICON: Lazy<Mutex<&'static str>> = Lazy::new(|| Mutex::new(""));
+/// Error icon is shown until this instant
+static ERROR_UNTIL: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+const ERROR_ICON_DURATION: Duration = Duration::from_secs(60);
+/// Refresher tick; also the recording icon's pulse period
+const REFRESH_INTERVAL: Duration = Duration::from_millis(600);
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TrayIconState {
     Idle,
+    /// Push-to-talk recording
     Recording,
+    /// Push-to-talk transcription
     Transcribing,
+    /// A meeting is being recorded (pulses)
+    MeetingRecording,
+    MeetingPaused,
+    /// Queued meeting audio still being transcribed
+    Backlog,
+    /// Recent audio or transcription failure
+    Error,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -42,24 +79,49 @@ pub fn get_icon_path(theme: AppTheme, state: TrayIconState) -> &'static str {
         // Dark theme uses light icons
         (AppTheme::Dark, TrayIconState::Idle) => "resources/tray_idle.png",
         (AppTheme::Dark, TrayIconState::Recording) => "resources/tray_recording.png",
-        (AppTheme::Dark, TrayIconState::Transcribing) => "resources/tray_transcribing.png",
+        (AppTheme::Dark, TrayIconState::Transcribing | TrayIconState::Backlog) => {
+            "resources/tray_transcribing.png"
+        }
+        (AppTheme::Dark, TrayIconState::MeetingRecording) => "resources/tray_recording.png",
+        (AppTheme::Dark, TrayIconState::MeetingPaused) => "resources/tray_paused.png",
+        (AppTheme::Dark, TrayIconState::Error) => "resources/tray_error.png",
         // Light theme uses dark icons
         (AppTheme::Light, TrayIconState::Idle) => "resources/tray_idle_dark.png",
         (AppTheme::Light, TrayIconState::Recording) => "resources/tray_recording_dark.png",
-        (AppTheme::Light, TrayIconState::Transcribing) => "resources/tray_transcribing_dark.png",
+        (AppTheme::Light, TrayIconState::Transcribing | TrayIconState::Backlog) => {
+            "resources/tray_transcribing_dark.png"
+        }
+        (AppTheme::Light, TrayIconState::MeetingRecording) => "resources/tray_recording_dark.png",
+        (AppTheme::Light, TrayIconState::MeetingPaused) => "resources/tray_paused_dark.png",
+        (AppTheme::Light, TrayIconState::Error) => "resources/tray_error_dark.png",
         // Colored theme uses pink icons (for Linux)
         (AppTheme::Colored, TrayIconState::Idle) => "resources/handy.png",
         (AppTheme::Colored, TrayIconState::Recording) => "resources/recording.png",
-        (AppTheme::Colored, TrayIconState::Transcribing) => "resources/transcribing.png",
+        (AppTheme::Colored, TrayIconState::Transcribing | TrayIconState::Backlog) => {
+            "resources/transcribing.png"
+        }
+        (AppTheme::Colored, TrayIconState::MeetingRecording) => "resources/recording.png",
+        (AppTheme::Colored, TrayIconState::MeetingPaused) => "resources/paused.png",
+        (AppTheme::Colored, TrayIconState::Error) => "resources/error.png",
     }
 }
 
-pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
-    let tray = app.state::<TrayIcon>();
-    let theme = get_current_theme(app);
-
-    let icon_path = get_icon_path(theme, icon.clone());
+/// Dimmed frame alternated with the recording icon while a meeting records
+fn pulse_icon_path(theme: AppTheme) -> &'static str {
+    match theme {
+        AppTheme::Dark => "resources/tray_recording_pulse.png",
+        AppTheme::Light => "resources/tray_recording_pulse_dark.png",
+        AppTheme::Colored => "resources/recording_pulse.png",
+    }
+}
 
+fn set_icon_path(app: &AppHandle, icon_path: &'static str) {
+    let mut current = CURRENT_ICON.lock().unwrap();
+    if *current == icon_path {
+        return;
+    }
+    *current = icon_path;
+    let tray = app.state::<TrayIcon>();
     let _ = tray.set_icon(Some(
         Image::from_path(
             app.path()
@@ -68,11 +130,244 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
         )
         .expect("failed to set icon"),
     ));
+}
+
+/// Show the error icon for a while (audio stream failures, dead-lettered jobs)
+pub fn flag_tray_error() {
+    *ERROR_UNTIL.lock().unwrap() = Some(Instant::now() + ERROR_ICON_DURATION);
+}
+
+pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
+    let theme = get_current_theme(app);
+    set_icon_path(app, get_icon_path(theme, icon.clone()));
 
     // Update menu based on state
     update_tray_menu(app, &icon);
 }
 
+/// "12:34" or "1:02:03"
+fn format_elapsed(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
+fn meeting_label(meeting: &ActiveMeetingInfo) -> String {
+    let elapsed = meeting
+        .start_time
+        .elapsed()
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let marker = if meeting.status == MeetingStatus::Paused {
+        "Paused"
+    } else {
+        "Recording"
+    };
+    format!("{}: {} ({})", marker, meeting.name, format_elapsed(elapsed))
+}
+
+fn transcript_line(segment: &TranscriptSegment) -> String {
+    let line = format!("{}: {}", segment.speaker, segment.text.trim());
+    if line.chars().count() <= TRAY_LINE_CHARS {
+        line
+    } else {
+        let cut: String = line.chars().take(TRAY_LINE_CHARS - 1).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
+
+fn transcript_lines(app: &AppHandle) -> Option<Vec<String>> {
+    let segments = app
+        .try_state::<Arc<MeetingManager>>()?
+        .try_recent_transcript_lines(TRAY_TRANSCRIPT_LINES)?;
+    if segments.is_empty() {
+        return Some(vec!["Waiting for transcription…".to_string()]);
+    }
+    Some(segments.iter().map(transcript_line).collect())
+}
+
+/// Replace the submenu's items when the shown lines changed
+fn refresh_transcript_menu(app: &AppHandle) {
+    let Some(lines) = transcript_lines(app) else {
+        return;
+    };
+    let mut guard = TRANSCRIPT_MENU.lock().unwrap();
+    let Some((submenu, shown)) = guard.as_mut() else {
+        return;
+    };
+    if *shown == lines {
+        return;
+    }
+    if let Ok(items) = submenu.items() {
+        for item in items {
+            let _ = submenu.remove(&item);
+        }
+    }
+    for line in &lines {
+        if let Ok(item) = MenuItem::new(app, line, false, None::<&str>) {
+            let _ = submenu.append(&item);
+        }
+    }
+    *shown = lines;
+}
+
+fn current_meeting(app: &AppHandle) -> Option<Option<ActiveMeetingInfo>> {
+    app.try_state::<Arc<MeetingManager>>()?
+        .try_current_meeting()
+}
+
+fn meeting_key(meeting: &Option<ActiveMeetingInfo>) -> Option<(String, bool)> {
+    meeting
+        .as_ref()
+        .map(|m| (m.id.clone(), m.status == MeetingStatus::Paused))
+}
+
+/// Start / Pause / Resume / End items for the tray, plus the elapsed-time label
+fn meeting_items(app: &AppHandle, meeting: &Option<ActiveMeetingInfo>) -> Vec<MenuItem<Wry>> {
+    let item = |id: &str, text: &str, enabled: bool| {
+        MenuItem::with_id(app, id, text, enabled, None::<&str>)
+            .expect("failed to create meeting item")
+    };
+    match meeting {
+        None => {
+            *MEETING_LABEL.lock().unwrap() = None;
+            vec![item("meeting_start", "Start Meeting", true)]
+        }
+        Some(m) => {
+            let label = item("meeting_elapsed", &meeting_label(m), false);
+            *MEETING_LABEL.lock().unwrap() = Some(label.clone());
+            let toggle = if m.status == MeetingStatus::Paused {
+                item("meeting_resume", "Resume Meeting", true)
+            } else {
+                item("meeting_pause", "Pause Meeting", true)
+            };
+            vec![label, toggle, item("meeting_end", "End Meeting", true)]
+        }
+    }
+}
+
+/// Run a meeting item from the tray menu; the frontend is told via `meeting-state-changed`
+pub fn handle_meeting_menu_event(app: &AppHandle, id: &str) {
+    let Some(manager) = app
+        .try_state::<Arc<MeetingManager>>()
+        .map(|m| m.inner().clone())
+    else {
+        return;
+    };
+    let app = app.clone();
+    let id = id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let current = manager.current_meeting().await;
+        let result = match (id.as_str(), &current) {
+            ("meeting_start", None) => manager
+                .start_meeting(default_meeting_name())
+                .await
+                .map(|id| (id, "recording")),
+            ("meeting_pause", Some(m)) => manager
+                .pause_meeting(&m.id)
+                .await
+                .map(|_| (m.id.clone(), "paused")),
+            ("meeting_resume", Some(m)) => manager
+                .resume_meeting(&m.id)
+                .await
+                .map(|_| (m.id.clone(), "recording")),
+            ("meeting_end", Some(m)) => manager
+                .end_meeting(&m.id)
+                .await
+                .map(|_| (m.id.clone(), "completed")),
+            // Menu was stale (meeting started or ended elsewhere)
+            _ => Err(anyhow::anyhow!(
+                "No matching meeting for tray action {}",
+                id
+            )),
+        };
+        match result {
+            Ok((meeting_id, status)) => {
+                events::emit(&app, AppEvent::meeting_state(&meeting_id, status, "tray"));
+            }
+            Err(e) => log::warn!("Tray meeting action failed: {}", e),
+        }
+        let state = MENU_STATE.lock().unwrap().0.clone();
+        update_tray_menu(&app, &state);
+    });
+}
+
+/// Icon for meeting/queue activity; push-to-talk states take precedence over it
+fn background_icon_state(app: &AppHandle, meeting: &Option<ActiveMeetingInfo>) -> TrayIconState {
+    let error_active = ERROR_UNTIL
+        .lock()
+        .unwrap()
+        .map(|until| Instant::now() < until)
+        .unwrap_or(false);
+    if error_active {
+        return TrayIconState::Error;
+    }
+    match meeting.as_ref().map(|m| &m.status) {
+        Some(MeetingStatus::Paused) => return TrayIconState::MeetingPaused,
+        Some(_) => return TrayIconState::MeetingRecording,
+        None => {}
+    }
+    let backlog = app
+        .try_state::<Arc<crate::queue::Queue>>()
+        .and_then(|q| q.counts().ok())
+        .map(|(queued, processing, _)| queued + processing > 0)
+        .unwrap_or(false);
+    if backlog {
+        TrayIconState::Backlog
+    } else {
+        TrayIconState::Idle
+    }
+}
+
+/// Keep the tray in step with meetings and the queue: rebuild the menu when a meeting
+/// starts, pauses, resumes, or ends, tick the elapsed-time label, live transcript lines,
+/// and tooltip, and update (and pulse) the icon
+pub fn spawn_meeting_menu_refresher(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut pulse = false;
+        loop {
+            std::thread::sleep(REFRESH_INTERVAL);
+            // Meeting table busy: try again next tick
+            let Some(current) = current_meeting(&app) else {
+                continue;
+            };
+            let (state, built_for) = MENU_STATE.lock().unwrap().clone();
+            if meeting_key(&current) != built_for {
+                update_tray_menu(&app, &state);
+            } else if let Some(meeting) = &current {
+                if let Some(label) = MEETING_LABEL.lock().unwrap().as_ref() {
+                    let _ = label.set_text(meeting_label(meeting));
+                }
+                refresh_transcript_menu(&app);
+            }
+
+            if crate::settings::get_settings(&app).tray_meeting_tooltip {
+                let tooltip = current
+                    .as_ref()
+                    .map(meeting_label)
+                    .unwrap_or_else(|| "MeetingCoder".to_string());
+                let _ = app.state::<TrayIcon>().set_tooltip(Some(tooltip));
+            }
+
+            if state != TrayIconState::Idle {
+                continue;
+            }
+            let theme = get_current_theme(&app);
+            let icon = background_icon_state(&app, &current);
+            pulse = icon == TrayIconState::MeetingRecording && !pulse;
+            let icon_path = if pulse {
+                pulse_icon_path(theme)
+            } else {
+                get_icon_path(theme, icon)
+            };
+            set_icon_path(&app, icon_path);
+        }
+    });
+}
+
 pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
     // Platform-specific accelerators
     #[cfg(target_os = "macos")]
@@ -97,39 +392,41 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
     let quit_i = MenuItem::with_id(app, "quit", "Quit", true, quit_accelerator)
         .expect("failed to create quit item");
     let separator = || PredefinedMenuItem::separator(app).expect("failed to create separator");
+    // If the meeting table is busy this shows no meeting; the refresher rebuilds the
+    // menu once it can read it
+    let meeting = current_meeting(app).flatten();
+    let meeting_i = meeting_items(app, &meeting);
+    let transcript_i = meeting.as_ref().map(|_| {
+        Submenu::with_id(app, "meeting_transcript", "Live Transcript", true)
+            .expect("failed to create transcript submenu")
+    });
+    *TRANSCRIPT_MENU.lock().unwrap() = transcript_i.clone().map(|submenu| (submenu, Vec::new()));
+    refresh_transcript_menu(app);
 
-    let menu = match state {
-        TrayIconState::Recording | TrayIconState::Transcribing => {
-            let cancel_i = MenuItem::with_id(app, "cancel", "Cancel", true, None::<&str>)
-                .expect("failed to create cancel item");
-            Menu::with_items(
-                app,
-                &[
-                    &version_i,
-                    &separator(),
-                    &cancel_i,
-                    &separator(),
-                    &settings_i,
-                    &check_updates_i,
-                    &separator(),
-                    &quit_i,
-                ],
-            )
-            .expect("failed to create menu")
-        }
-        TrayIconState::Idle => Menu::with_items(
-            app,
-            &[
-                &version_i,
-                &separator(),
-                &settings_i,
-                &check_updates_i,
-                &separator(),
-                &quit_i,
-            ],
-        )
-        .expect("failed to create menu"),
-    };
+    let cancel_i = MenuItem::with_id(app, "cancel", "Cancel", true, None::<&str>)
+        .expect("failed to create cancel item");
+    let (sep_1, sep_2, sep_3, sep_4) = (separator(), separator(), separator(), separator());
+    let mut items: Vec<&dyn IsMenuItem<Wry>> = vec![&version_i, &sep_1];
+    items.extend(meeting_i.iter().map(|i| i as &dyn IsMenuItem<Wry>));
+    if let Some(submenu) = &transcript_i {
+        items.push(submenu);
+    }
+    items.push(&sep_2);
+    if matches!(
+        state,
+        TrayIconState::Recording | TrayIconState::Transcribing
+    ) {
+        items.push(&cancel_i);
+        items.push(&sep_3);
+    }
+    items.extend([
+        &settings_i as &dyn IsMenuItem<Wry>,
+        &check_updates_i,
+        &sep_4,
+        &quit_i,
+    ]);
+    let menu = Menu::with_items(app, &items).expect("failed to create menu");
+    *MENU_STATE.lock().unwrap() = (state.clone(), meeting_key(&meeting));
 
     let tray = app.state::<TrayIcon>();
     let _ = tray.set_menu(Some(menu));
@@ -44,6 +44,9 @@ pub fn cancel_current_operation(app: &AppHandle) {
         eprintln!("Warning: Failed to lock toggle state manager during cancellation");
     }
 
+    // Stop imports after their current chunk
+    crate::commands::import::cancel_imports();
+
     // Cancel any ongoing recording
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.cancel_recording();
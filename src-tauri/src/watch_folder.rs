@@ -0,0 +1,347 @@
+//! Auto-import from a watched folder, e.g. where Zoom saves local recordings. Audio files
+//! that appear there are imported as offline meetings once they stop growing. Contents
+//! are hashed, so a recording copied or renamed within the folder is imported once.
+
+use anyhow::Result;
+use chrono::Utc;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::commands::import::{import_audio_from_path_as_meeting, is_supported_audio_extension};
+use crate::events::{self, AppEvent};
+use crate::settings::{AppSettings, WatchFolderConfig};
+
+const LEDGER_FILE: &str = "watch_folder_imports.json";
+/// A file is imported once its size hasn't changed for this long
+const SETTLE: Duration = Duration::from_secs(10);
+const TICK: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatchFileStatus {
+    /// Seen; waiting for it to finish being written
+    Detected,
+    Importing,
+    Imported,
+    /// Same contents as a file imported before
+    Duplicate,
+    Failed,
+}
+
+/// What has been imported, kept in the app data folder
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Ledger {
+    /// Folder being watched
+    folder: String,
+    /// Unix milliseconds when watching `folder` began; older files are left alone
+    watching_since: i64,
+    /// Content hash -> meeting id
+    imported: HashMap<String, String>,
+    /// Path -> content hash of files already handled
+    seen: HashMap<String, String>,
+}
+
+static WATCHER: Lazy<Mutex<Option<RecommendedWatcher>>> = Lazy::new(|| Mutex::new(None));
+static SENDER: OnceCell<UnboundedSender<PathBuf>> = OnceCell::new();
+static LEDGER_LOCK: Mutex<()> = Mutex::new(());
+
+fn ledger_path(app: &AppHandle) -> Result<PathBuf> {
+    Ok(app
+        .path()
+        .resolve(LEDGER_FILE, tauri::path::BaseDirectory::AppData)?)
+}
+
+fn read_ledger(app: &AppHandle) -> Ledger {
+    ledger_path(app)
+        .ok()
+        .and_then(|p| fs::read(p).ok())
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn update_ledger<T>(app: &AppHandle, update: impl FnOnce(&mut Ledger) -> T) -> Result<T> {
+    let _guard = LEDGER_LOCK.lock().unwrap();
+    let mut ledger = read_ledger(app);
+    let result = update(&mut ledger);
+    let path = ledger_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_vec_pretty(&ledger)?)?;
+    Ok(result)
+}
+
+pub fn normalize_config(config: &mut WatchFolderConfig) -> Result<(), String> {
+    config.folder = config.folder.trim().to_string();
+    if config.enabled {
+        if config.folder.is_empty() {
+            return Err("Choose a folder to watch".to_string());
+        }
+        if !Path::new(&config.folder).is_dir() {
+            return Err(format!("{} is not a folder", config.folder));
+        }
+    }
+    Ok(())
+}
+
+/// Start the import worker and watch the configured folder
+pub fn spawn(app: AppHandle) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    if SENDER.set(sender).is_err() {
+        return;
+    }
+    tauri::async_runtime::spawn(run(app.clone(), receiver));
+    apply_settings(&app, &crate::settings::get_settings(&app));
+}
+
+/// Watch the folder the settings name, or stop watching
+pub fn apply_settings(app: &AppHandle, settings: &AppSettings) {
+    let mut watcher = WATCHER.lock().unwrap();
+    *watcher = None;
+    let config = &settings.watch_folder;
+    let Some(sender) = SENDER.get() else {
+        return;
+    };
+    if !config.enabled || config.folder.is_empty() {
+        return;
+    }
+    let folder = PathBuf::from(&config.folder);
+    let since = update_ledger(app, |ledger| {
+        if ledger.folder != config.folder {
+            ledger.folder = config.folder.clone();
+            ledger.watching_since = Utc::now().timestamp_millis();
+        }
+        ledger.watching_since
+    })
+    .unwrap_or_else(|_| Utc::now().timestamp_millis());
+
+    let events = sender.clone();
+    let started = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                for path in event.paths {
+                    let _ = events.send(path);
+                }
+            }
+        }
+    })
+    .and_then(|mut w| w.watch(&folder, RecursiveMode::NonRecursive).map(|_| w));
+    match started {
+        Ok(w) => {
+            log::info!("Watching {} for recordings to import", folder.display());
+            *watcher = Some(w);
+        }
+        Err(e) => {
+            log::warn!("Failed to watch {}: {}", folder.display(), e);
+            return;
+        }
+    }
+
+    // Files that arrived while the app wasn't running
+    for entry in fs::read_dir(&folder).into_iter().flatten().flatten() {
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        if modified >= since {
+            let _ = sender.send(entry.path());
+        }
+    }
+}
+
+fn is_candidate(path: &Path) -> bool {
+    let visible = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| !n.starts_with('.'));
+    visible && path.is_file() && is_supported_audio_extension(&path.to_path_buf())
+}
+
+fn emit(app: &AppHandle, path: &Path, status: WatchFileStatus) {
+    emit_with(app, path, status, None, None);
+}
+
+fn emit_with(
+    app: &AppHandle,
+    path: &Path,
+    status: WatchFileStatus,
+    meeting_id: Option<String>,
+    error: Option<String>,
+) {
+    events::emit(
+        app,
+        AppEvent::WatchFolderFile {
+            path: path.to_string_lossy().to_string(),
+            status,
+            meeting_id,
+            error,
+        },
+    );
+}
+
+/// Collect changed files and import each once it has settled, one at a time
+async fn run(app: AppHandle, mut changes: UnboundedReceiver<PathBuf>) {
+    // Path -> (size, when the size last changed)
+    let mut pending: HashMap<PathBuf, (u64, Instant)> = HashMap::new();
+    let mut tick = tokio::time::interval(TICK);
+    loop {
+        tokio::select! {
+            Some(path) = changes.recv() => {
+                if pending.contains_key(&path) || !is_candidate(&path) {
+                    continue;
+                }
+                if read_ledger(&app).seen.contains_key(&path.to_string_lossy().to_string()) {
+                    continue;
+                }
+                let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                pending.insert(path.clone(), (size, Instant::now()));
+                emit(&app, &path, WatchFileStatus::Detected);
+            }
+            _ = tick.tick() => {
+                let ready = settled(&mut pending);
+                for path in ready {
+                    import(&app, &path).await;
+                }
+            }
+        }
+    }
+}
+
+/// Remove and return the pending files whose size held still for `SETTLE`
+fn settled(pending: &mut HashMap<PathBuf, (u64, Instant)>) -> Vec<PathBuf> {
+    let mut ready = Vec::new();
+    pending.retain(|path, (size, changed_at)| {
+        let Ok(meta) = fs::metadata(path) else {
+            // Moved away or deleted before it settled
+            return false;
+        };
+        if meta.len() != *size {
+            *size = meta.len();
+            *changed_at = Instant::now();
+        } else if changed_at.elapsed() >= SETTLE && *size > 0 {
+            ready.push(path.clone());
+            return false;
+        }
+        true
+    });
+    ready.sort();
+    ready
+}
+
+fn hash_file(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+async fn import(app: &AppHandle, path: &Path) {
+    let key = path.to_string_lossy().to_string();
+    let hash = {
+        let path = path.to_path_buf();
+        tauri::async_runtime::spawn_blocking(move || hash_file(&path)).await
+    };
+    let hash = match hash.map_err(anyhow::Error::from).and_then(|r| r) {
+        Ok(hash) => hash,
+        Err(e) => {
+            let error = Some(e.to_string());
+            return emit_with(app, path, WatchFileStatus::Failed, None, error);
+        }
+    };
+    let earlier = update_ledger(app, |ledger| {
+        let earlier = ledger.imported.get(&hash).cloned();
+        if earlier.is_some() {
+            ledger.seen.insert(key.clone(), hash.clone());
+        }
+        earlier
+    });
+    if let Ok(Some(meeting_id)) = earlier {
+        log::info!("{} was already imported as meeting {}", key, meeting_id);
+        return emit_with(
+            app,
+            path,
+            WatchFileStatus::Duplicate,
+            Some(meeting_id),
+            None,
+        );
+    }
+
+    emit(app, path, WatchFileStatus::Importing);
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| key.clone());
+    let result = import_audio_from_path_as_meeting(
+        app.clone(),
+        name,
+        key.clone(),
+        "import:watch-folder",
+        app.state(),
+        app.state(),
+        app.state(),
+    )
+    .await;
+    match result {
+        Ok(summary) => {
+            let recorded = update_ledger(app, |ledger| {
+                ledger
+                    .imported
+                    .insert(hash.clone(), summary.meeting_id.clone());
+                ledger.seen.insert(key.clone(), hash);
+            });
+            if let Err(e) = recorded {
+                log::warn!("Failed to record import of {}: {}", key, e);
+            }
+            emit_with(
+                app,
+                path,
+                WatchFileStatus::Imported,
+                Some(summary.meeting_id),
+                None,
+            );
+        }
+        // Left out of the ledger, so it's tried again next launch
+        Err(e) => {
+            log::warn!("Auto-import of {} failed: {}", key, e);
+            emit_with(app, path, WatchFileStatus::Failed, None, Some(e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settled_waits_for_size_to_hold() {
+        let dir = std::env::temp_dir().join(format!("watch-folder-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let growing = dir.join("growing.m4a");
+        let done = dir.join("done.m4a");
+        fs::write(&growing, b"12345").unwrap();
+        fs::write(&done, b"12345").unwrap();
+        let long_ago = Instant::now() - SETTLE - Duration::from_secs(1);
+        let mut pending = HashMap::from([
+            (growing.clone(), (3, long_ago)),
+            (done.clone(), (5, long_ago)),
+            (dir.join("gone.m4a"), (5, long_ago)),
+        ]);
+
+        assert_eq!(settled(&mut pending), vec![done]);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[&growing].0, 5);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,13 +1,35 @@
 use crate::managers::meeting::{MeetingManager, TranscriptSegment};
-use crate::queue::{Queue, QueueItem};
-use crate::managers::transcription::TranscriptionManager;
+use crate::queue::{JobPriority, Queue, QueueItem, WorkLane};
+use crate::events::{self, AppEvent};
+use crate::metrics::{self, Metric};
+use crate::managers::transcription::{ImportTranscriptionManager, Transcription, TranscriptionManager};
 use anyhow::Result;
 use log::{info, warn, error};
-use std::collections::HashMap;
+use crate::settings;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
-use tauri::{AppHandle, Emitter};
+use std::time::{Duration, Instant};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::oneshot;
+
+/// App data folder holding the audio of queued background jobs
+const BACKGROUND_CHUNK_DIR: &str = "background_chunks";
+
+/// A chunk queued by an import or re-transcription, waiting for its text
+struct BackgroundJob {
+    /// Engine the caller loaded for this work (e.g. the model picked for a re-transcription)
+    transcriber: Arc<TranscriptionManager>,
+    previous_text: String,
+    reply: oneshot::Sender<Result<Transcription, String>>,
+}
+
+/// Background jobs by queue id. A job missing here was queued by an earlier run.
+static BACKGROUND_JOBS: Lazy<Mutex<HashMap<i64, BackgroundJob>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 fn load_wav_16k_mono_f32(path: &std::path::Path) -> Result<Vec<f32>> {
     let mut r = hound::WavReader::open(path)?;
@@ -26,6 +48,8 @@ fn load_wav_16k_mono_f32(path: &std::path::Path) -> Result<Vec<f32>> {
 struct DiarState {
     /// Last assigned speaker index (1 or 2). 0 means uninitialized.
     last_speaker: u8,
+    /// Next rolling-transcript index for a meeting that is no longer active
+    recovered_index: Option<usize>,
 }
 
 fn next_speaker_label(state: &mut DiarState, turn_boundary: bool) -> String {
@@ -48,39 +72,66 @@ fn silence_fraction(samples: &[f32], thresh: f32) -> f32 {
     silent as f32 / samples.len() as f32
 }
 
+/// Transcribe one queued chunk; returns the time spent in ASR
 fn process_item(
+    queue: &Queue,
     item: QueueItem,
     meeting_manager: Arc<MeetingManager>,
     transcription_manager: Arc<TranscriptionManager>,
     app: &AppHandle,
     diar_map: &Arc<Mutex<HashMap<String, DiarState>>>,
-) -> Result<()> {
+) -> Result<Duration> {
     let p = std::path::Path::new(&item.file_path);
     let samples = load_wav_16k_mono_f32(p)?;
     if samples.is_empty() { return Err(anyhow::anyhow!("empty samples")); }
 
-    let text = transcription_manager.transcribe(samples.clone())?;
+    let asr_started = Instant::now();
+    // The meeting's latest text, as context for the model, and where to send the audio
+    let meeting = tauri::async_runtime::block_on(meeting_manager.get_meeting(&item.meeting_id)).ok();
+    let previous_text = meeting
+        .as_ref()
+        .and_then(|m| m.transcript_segments.last().map(|s| s.text.clone()))
+        .unwrap_or_default();
+    let cloud_asr = meeting.is_some_and(|m| m.cloud_asr);
+    let transcription =
+        transcription_manager.transcribe_for_meeting(samples.clone(), &previous_text, cloud_asr)?;
+    let confidence = transcription.segment_confidence();
+    let words = transcription.words_at((item.start_ms as f64) / 1000.0);
+    let text = transcription.text;
+    let asr_time = asr_started.elapsed();
+    // The meeting may have been deleted while the chunk was being transcribed
+    if queue.is_cancelled(item.id)? {
+        info!("Discarding result of cancelled job {}", item.id);
+        return Ok(asr_time);
+    }
     if text.trim().is_empty() {
         info!("ASR produced empty text for {:?}", p);
-        return Ok(());
+        return Ok(asr_time);
+    }
+    if crate::managers::transcription::should_drop(confidence) {
+        info!("Dropping low-confidence text ({:.2}) for {:?}", confidence, p);
+        return Ok(asr_time);
     }
 
-    // Determine speaker label using a simple turn heuristic per meeting
-    // Toggle speaker only if we observe notable silence inside the chunk
-    let turn_boundary = silence_fraction(&samples, 1e-3) > 0.20; // coarse threshold
-    let speaker_label = {
-        let mut map = diar_map.lock().unwrap();
-        let state = map.entry(item.meeting_id.clone()).or_default();
-        next_speaker_label(state, turn_boundary)
-    };
+    // Label the speaker by voice when diarization is on; otherwise use a simple turn
+    // heuristic, toggling only if we observe notable silence inside the chunk
+    let speaker_label = crate::diarization::live_label(app, &item.meeting_id, &samples)
+        .unwrap_or_else(|| {
+            let turn_boundary = silence_fraction(&samples, 1e-3) > 0.20; // coarse threshold
+            let mut map = diar_map.lock().unwrap();
+            let state = map.entry(item.meeting_id.clone()).or_default();
+            next_speaker_label(state, turn_boundary)
+        });
 
     let segment = TranscriptSegment {
         speaker: speaker_label,
         start_time: (item.start_ms as f64) / 1000.0,
         end_time: (item.end_ms as f64) / 1000.0,
         text: text.clone(),
-        confidence: 0.95,
+        confidence,
         timestamp: std::time::SystemTime::now(),
+        words,
+        translated_text: None,
     };
 
     // Determine segment index as current length before appending
@@ -91,13 +142,17 @@ fn process_item(
 
     // Try to append to active meeting
     if let Err(e) = tauri::async_runtime::block_on(meeting_manager.add_segment(&item.meeting_id, segment.clone())) {
-        warn!("add_segment failed (meeting may have ended): {}", e);
+        let gone = tauri::async_runtime::block_on(meeting_manager.get_meeting(&item.meeting_id)).is_err();
+        if gone {
+            recover_segment(app, &item, &segment, diar_map)?;
+        } else {
+            warn!("add_segment failed: {}", e);
+        }
     }
 
     // Emit UI event
-    #[derive(Clone, serde::Serialize)]
-    struct SegmentAddedPayload { meeting_id: String, segment: TranscriptSegment }
-    let _ = app.emit("transcript-segment-added", SegmentAddedPayload { meeting_id: item.meeting_id.clone(), segment: segment.clone() });
+    crate::overlay::emit_caption(&app, &segment.text);
+    events::emit(&app, AppEvent::TranscriptSegmentAdded { meeting_id: item.meeting_id.clone(), segment: segment.clone() });
 
     // Append rolling transcript on disk if we can get project path
     if let Ok(m) = tauri::async_runtime::block_on(meeting_manager.get_meeting(&item.meeting_id)) {
@@ -105,35 +160,553 @@ fn process_item(
             if let Err(e) = crate::meeting::transcript_writer::append_segment(&pp, &item.meeting_id, next_index, &segment) {
                 warn!("append_segment failed: {}", e);
             }
+            // Fire a targeted agent prompt if a trigger phrase was spoken
+            crate::automation::keyword_trigger::maybe_fire(app, &pp, &item.meeting_id, &m.transcript_segments);
+            // Surface (never run) shell commands for spoken "run X / deploy Y" requests
+            crate::automation::command_suggestions::observe_segment(app, &pp, &item.meeting_id, &m.transcript_segments);
         }
     }
 
-    Ok(())
+    Ok(asr_time)
 }
 
-pub fn spawn(queue: Arc<Queue>, meeting_manager: Arc<MeetingManager>, transcription_manager: Arc<TranscriptionManager>, app: AppHandle) {
-    // Map to track simple per-meeting diarization state for alternation
-    let diar_map: Arc<Mutex<HashMap<String, DiarState>>> = Arc::new(Mutex::new(HashMap::new()));
-    thread::spawn(move || {
+/// Keep the text of a job whose meeting is no longer in memory (jobs recovered after a
+/// restart, or finished after the meeting was saved). A saved meeting gets it in its
+/// transcript right away; otherwise it goes to the project's rolling transcript, which
+/// is merged back in when the meeting is resumed or finalized.
+fn recover_segment(
+    app: &AppHandle,
+    item: &QueueItem,
+    segment: &TranscriptSegment,
+    diar_map: &Arc<Mutex<HashMap<String, DiarState>>>,
+) -> Result<()> {
+    let saved = crate::commands::meeting::attach_segments_to_saved(
+        app,
+        &item.meeting_id,
+        vec![segment.clone()],
+    );
+    match saved {
+        Ok(true) => return Ok(()),
+        Ok(false) => {}
+        Err(e) => warn!("Failed to add segment to saved meeting {}: {}", item.meeting_id, e),
+    }
+    let Some(pp) = crate::queue::recovery_project_path(&item.file_path) else {
+        warn!("Dropping segment of meeting {}: it is neither active nor saved", item.meeting_id);
+        return Ok(());
+    };
+    // Continue the meeting's segment numbering in the rolling transcript
+    let index = {
+        let mut map = diar_map.lock().unwrap();
+        let state = map.entry(item.meeting_id.clone()).or_default();
+        let index = state.recovered_index.unwrap_or_else(|| {
+            crate::meeting::transcript_writer::segment_count(&pp, &item.meeting_id)
+        });
+        state.recovered_index = Some(index + 1);
+        index
+    };
+    crate::meeting::transcript_writer::append_segment(&pp, &item.meeting_id, index, segment)
+}
+
+/// Transcribe a job for the import or re-transcription waiting on it
+fn process_background_item(item: &QueueItem) -> Result<Duration> {
+    let job = BACKGROUND_JOBS
+        .lock()
+        .unwrap()
+        .get(&item.id)
+        .map(|job| (job.transcriber.clone(), job.previous_text.clone()));
+    let Some((transcriber, previous_text)) = job else {
+        // Nobody is waiting any more; an interrupted import resumes from its checkpoint
+        info!("Discarding orphaned background job {}", item.id);
+        let _ = std::fs::remove_file(&item.file_path);
+        return Ok(Duration::ZERO);
+    };
+    let samples = load_wav_16k_mono_f32(std::path::Path::new(&item.file_path))?;
+    let asr_started = Instant::now();
+    let transcription = transcriber.transcribe_with_context(samples, &previous_text)?;
+    let asr_time = asr_started.elapsed();
+    if let Some(job) = BACKGROUND_JOBS.lock().unwrap().remove(&item.id) {
+        let _ = job.reply.send(Ok(transcription));
+    }
+    Ok(asr_time)
+}
+
+/// Tell the caller of a dead-lettered background job that it failed
+fn fail_background_job(id: i64, error: &str) {
+    if let Some(job) = BACKGROUND_JOBS.lock().unwrap().remove(&id) {
+        let _ = job.reply.send(Err(error.to_string()));
+    }
+}
+
+/// Transcribe a chunk of an import or re-transcription (16 kHz mono, starting `start_ms`
+/// into the meeting) on the background pool. The chunk is queued at `priority`, so live
+/// meeting chunks always go first, and pausing the queue pauses it too.
+pub async fn transcribe_in_background(
+    app: &AppHandle,
+    transcriber: Arc<TranscriptionManager>,
+    meeting_id: &str,
+    start_ms: u64,
+    samples: &[f32],
+    previous_text: &str,
+    priority: JobPriority,
+) -> Result<Transcription, String> {
+    let queue = app
+        .try_state::<Arc<Queue>>()
+        .ok_or_else(|| "Transcription queue is not available".to_string())?
+        .inner()
+        .clone();
+    let dir = app
+        .path()
+        .resolve(BACKGROUND_CHUNK_DIR, BaseDirectory::AppData)
+        .map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("{}.wav", uuid::Uuid::new_v4()));
+    crate::audio_toolkit::save_wav_file(&path, samples)
+        .await
+        .map_err(|e| e.to_string())?;
+    let end_ms = start_ms + samples.len() as u64 * 1000 / 16_000;
+
+    let (reply, mut result) = oneshot::channel();
+    let id = {
+        // Registered under the lock so no worker claims the job before it is known
+        let mut jobs = BACKGROUND_JOBS.lock().unwrap();
+        let enqueued = queue.enqueue_with_priority(
+            meeting_id,
+            start_ms,
+            end_ms,
+            &path.to_string_lossy(),
+            priority,
+        );
+        let id = match enqueued {
+            Ok(id) => id,
+            Err(e) => {
+                let _ = std::fs::remove_file(&path);
+                return Err(e.to_string());
+            }
+        };
+        jobs.insert(
+            id,
+            BackgroundJob {
+                transcriber,
+                previous_text: previous_text.to_string(),
+                reply,
+            },
+        );
+        id
+    };
+    let outcome = loop {
+        match tokio::time::timeout(Duration::from_secs(1), &mut result).await {
+            Ok(Ok(outcome)) => break outcome,
+            Ok(Err(_)) => break Err("Transcription job was dropped".to_string()),
+            // Deleting the meeting cancels its queued jobs
+            Err(_) if queue.is_cancelled(id).unwrap_or(false) => {
+                BACKGROUND_JOBS.lock().unwrap().remove(&id);
+                break Err("Transcription job was cancelled".to_string());
+            }
+            Err(_) => {}
+        }
+    };
+    let _ = std::fs::remove_file(&path);
+    outcome
+}
+
+/// Queued audio (seconds) per running worker above which another worker is added
+const SCALE_UP_BACKLOG_SECS: f32 = 20.0;
+/// How long the queue must stay empty before a worker is retired
+const SCALE_DOWN_IDLE: Duration = Duration::from_secs(60);
+const AUTOSCALE_INTERVAL: Duration = Duration::from_secs(2);
+const MIN_WORKERS: usize = 1;
+/// Performance metrics cover jobs finished within this window
+const METRICS_WINDOW: Duration = Duration::from_secs(5 * 60);
+const METRICS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// One finished (or failed) job attempt
+#[derive(Clone, Copy, Debug)]
+struct JobSample {
+    finished_at: Instant,
+    ok: bool,
+    /// ASR time divided by audio duration; only for successful jobs
+    rtf: Option<f32>,
+    /// Time between enqueue and pickup
+    wait_ms: i64,
+}
+
+/// Worker throughput of one lane over the last `window_secs`, sent as `queue-metrics`
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct QueueMetrics {
+    pub lane: WorkLane,
+    pub window_secs: u64,
+    pub jobs_per_min: f32,
+    pub avg_rtf: Option<f32>,
+    pub avg_wait_ms: Option<f32>,
+    /// Failed attempts / all attempts
+    pub failure_rate: f32,
+    pub completed: usize,
+    pub failed: usize,
+    pub workers: usize,
+}
+
+fn summarize(lane: WorkLane, samples: &[JobSample], window: Duration) -> QueueMetrics {
+    let completed = samples.iter().filter(|s| s.ok).count();
+    let failed = samples.len() - completed;
+    let mean = |values: Vec<f32>| {
+        (!values.is_empty()).then(|| values.iter().sum::<f32>() / values.len() as f32)
+    };
+    QueueMetrics {
+        lane,
+        window_secs: window.as_secs(),
+        jobs_per_min: completed as f32 / (window.as_secs_f32() / 60.0),
+        avg_rtf: mean(samples.iter().filter_map(|s| s.rtf).collect()),
+        avg_wait_ms: mean(samples.iter().map(|s| s.wait_ms as f32).collect()),
+        failure_rate: if samples.is_empty() { 0.0 } else { failed as f32 / samples.len() as f32 },
+        completed,
+        failed,
+        workers: 0,
+    }
+}
+
+/// Worker count change for one autoscaler tick: +1, -1, or 0
+pub fn scale_step(running: usize, max_workers: usize, backlog_secs: f32, idle_for: Duration) -> i32 {
+    let max_workers = max_workers.max(MIN_WORKERS);
+    if running > max_workers {
+        -1
+    } else if running < max_workers && backlog_secs > SCALE_UP_BACKLOG_SECS * running.max(1) as f32 {
+        1
+    } else if running > MIN_WORKERS && idle_for >= SCALE_DOWN_IDLE {
+        -1
+    } else {
+        0
+    }
+}
+
+/// ASR worker threads for one lane, grown and shrunk with the queue backlog
+pub struct WorkerPool {
+    lane: WorkLane,
+    queue: Arc<Queue>,
+    meeting_manager: Arc<MeetingManager>,
+    transcription_manager: Arc<TranscriptionManager>,
+    app: AppHandle,
+    /// Per-meeting diarization state, shared so any worker can continue a meeting
+    diar_map: Arc<Mutex<HashMap<String, DiarState>>>,
+    running: AtomicUsize,
+    /// Workers asked to exit at their next idle poll
+    retiring: AtomicUsize,
+    /// Recent job attempts, oldest first
+    samples: Mutex<VecDeque<JobSample>>,
+    /// While set, workers stop claiming jobs; enqueueing continues
+    paused: AtomicBool,
+}
+
+impl WorkerPool {
+    /// Start `initial` workers and the autoscaler
+    pub fn start(
+        lane: WorkLane,
+        queue: Arc<Queue>,
+        meeting_manager: Arc<MeetingManager>,
+        transcription_manager: Arc<TranscriptionManager>,
+        app: AppHandle,
+        initial: usize,
+    ) -> Arc<Self> {
+        let pool = Arc::new(Self {
+            lane,
+            queue,
+            meeting_manager,
+            transcription_manager,
+            app,
+            diar_map: Arc::new(Mutex::new(HashMap::new())),
+            running: AtomicUsize::new(0),
+            retiring: AtomicUsize::new(0),
+            samples: Mutex::new(VecDeque::new()),
+            paused: AtomicBool::new(false),
+        });
+        for _ in 0..initial.max(MIN_WORKERS) {
+            pool.spawn_worker();
+        }
+        pool.spawn_autoscaler();
+        pool
+    }
+
+    /// Workers currently running (including any about to retire)
+    pub fn worker_count(&self) -> usize {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Whether the pause state changed
+    fn set_paused(&self, paused: bool) -> bool {
+        self.paused.swap(paused, Ordering::SeqCst) != paused
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Throughput, RTF, wait time, and failure rate over the metrics window
+    pub fn metrics(&self) -> QueueMetrics {
+        let mut samples = self.samples.lock().unwrap();
+        while samples
+            .front()
+            .map(|s| s.finished_at.elapsed() > METRICS_WINDOW)
+            .unwrap_or(false)
+        {
+            samples.pop_front();
+        }
+        let samples: Vec<JobSample> = samples.iter().copied().collect();
+        QueueMetrics {
+            workers: self.worker_count(),
+            ..summarize(self.lane, &samples, METRICS_WINDOW)
+        }
+    }
+
+    fn record(&self, item: &QueueItem, wait_ms: i64, asr_time: Option<Duration>) {
+        let audio_secs = item.end_ms.saturating_sub(item.start_ms) as f32 / 1000.0;
+        let sample = JobSample {
+            finished_at: Instant::now(),
+            ok: asr_time.is_some(),
+            rtf: asr_time.filter(|_| audio_secs > 0.0).map(|t| t.as_secs_f32() / audio_secs),
+            wait_ms,
+        };
+        self.samples.lock().unwrap().push_back(sample);
+
+        metrics::record(&self.app, Metric::QueueWaitMs, wait_ms as f64);
+        match (sample.ok, sample.rtf) {
+            (true, Some(rtf)) => metrics::record(&self.app, Metric::AsrRtf, rtf as f64),
+            (true, None) => {}
+            (false, _) => metrics::record(&self.app, Metric::AsrFailure, 1.0),
+        }
+    }
+
+    /// Configured upper bound on workers for this lane
+    fn configured_max(&self) -> usize {
+        let settings = settings::get_settings(&self.app);
+        let count = match self.lane {
+            WorkLane::Live => settings.queue_worker_count,
+            WorkLane::Background => settings.import_worker_count,
+        };
+        count.clamp(1, 8) as usize
+    }
+
+    /// Engine for this lane: the dedicated import model when one is enabled. Background
+    /// jobs bring their own engine; this one decides the GPU cap.
+    fn transcriber(&self) -> Arc<TranscriptionManager> {
+        match self.lane {
+            WorkLane::Background => ImportTranscriptionManager::for_imports(&self.app)
+                .unwrap_or_else(|| self.transcription_manager.clone()),
+            WorkLane::Live => self.transcription_manager.clone(),
+        }
+    }
+
+    fn spawn_worker(self: &Arc<Self>) {
+        let count = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        info!("ASR {:?} worker started ({} running)", self.lane, count);
+        let pool = self.clone();
+        thread::spawn(move || pool.run_worker());
+    }
+
+    /// Claim one pending retirement, if any
+    fn try_retire(&self) -> bool {
+        self.retiring
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    fn run_worker(&self) {
+        let queue = &self.queue;
+        let app = &self.app;
         loop {
-            match queue.fetch_next() {
+            if self.is_paused() {
+                if self.try_retire() {
+                    self.running.fetch_sub(1, Ordering::SeqCst);
+                    return;
+                }
+                thread::sleep(Duration::from_millis(250));
+                continue;
+            }
+            match queue.fetch_next(self.lane) {
                 Ok(Some(item)) => {
-                    info!("ASR worker picked item {} {:?}", item.id, item.file_path);
-                    let res = process_item(
-                        item.clone(),
-                        meeting_manager.clone(),
-                        transcription_manager.clone(),
-                        &app,
-                        &diar_map,
-                    );
+                    info!("ASR worker picked item {} ({:?}) {:?}", item.id, item.priority, item.file_path);
+                    let wait_ms = Queue::now_ms() - item.created_at;
+                    let res = match item.priority {
+                        JobPriority::Live => {
+                            let transcriber = self.transcriber();
+                            // Loads on first use; transcribe() waits for an in-progress load
+                            transcriber.initiate_model_load();
+                            process_item(
+                                queue,
+                                item.clone(),
+                                self.meeting_manager.clone(),
+                                transcriber,
+                                app,
+                                &self.diar_map,
+                            )
+                        }
+                        // The waiting import or re-transcription already loaded its engine
+                        JobPriority::Import | JobPriority::Retranscribe => {
+                            process_background_item(&item)
+                        }
+                    };
                     match res {
-                        Ok(()) => { let _ = queue.mark_done(item.id); }
-                        Err(e) => { error!("ASR worker failed: {}", e); let _ = queue.mark_failed(item.id, &format!("{}", e)); }
+                        Ok(asr_time) => {
+                            self.record(&item, wait_ms, Some(asr_time));
+                            let _ = queue.mark_done(item.id);
+                        }
+                        Err(e) => {
+                            error!("ASR worker failed: {}", e);
+                            self.record(&item, wait_ms, None);
+                            if let Ok(true) = queue.mark_failed(item.id, &format!("{}", e)) {
+                                warn!("Queue job {} moved to dead letters after repeated failures", item.id);
+                                fail_background_job(item.id, &e.to_string());
+                                metrics::record(app, Metric::QueueDeadLetter, 1.0);
+                                crate::tray::flag_tray_error();
+                                events::emit(&app, AppEvent::QueueJobDeadLettered { id: item.id, meeting_id: item.meeting_id.clone(), error: e.to_string() });
+                            }
+                        }
                     }
                 }
-                Ok(None) => { thread::sleep(Duration::from_millis(250)); }
+                Ok(None) => {
+                    if self.try_retire() {
+                        let count = self.running.fetch_sub(1, Ordering::SeqCst) - 1;
+                        info!("ASR {:?} worker retired ({} running)", self.lane, count);
+                        return;
+                    }
+                    thread::sleep(Duration::from_millis(250));
+                }
                 Err(e) => { error!("Queue fetch error: {}", e); thread::sleep(Duration::from_millis(500)); }
             }
         }
-    });
+    }
+
+    /// Every few seconds, add a worker while the backlog is high or retire one after the
+    /// queue has been idle. With a GPU model and `SingleWorker` scheduling the pool is held
+    /// at one worker. Also emits `queue-metrics` periodically.
+    fn spawn_autoscaler(self: &Arc<Self>) {
+        let pool = self.clone();
+        thread::spawn(move || {
+            let mut idle_since = Instant::now();
+            let mut last_metrics = Instant::now();
+            loop {
+                thread::sleep(AUTOSCALE_INTERVAL);
+                let configured = pool.configured_max();
+                let single_worker = settings::get_settings(&pool.app).gpu_scheduling
+                    == settings::GpuScheduling::SingleWorker
+                    && pool.transcriber().uses_gpu();
+                let max_workers = if single_worker { 1 } else { configured };
+                let backlog = pool.queue.backlog_seconds(pool.lane).unwrap_or(0.0);
+                if backlog > 0.0 {
+                    idle_since = Instant::now();
+                }
+                // Paused: queued work isn't being drained, so don't scale on it
+                if !pool.is_paused() {
+                    let running = pool
+                        .worker_count()
+                        .saturating_sub(pool.retiring.load(Ordering::SeqCst));
+                    match scale_step(running, max_workers, backlog, idle_since.elapsed()) {
+                        1 => pool.spawn_worker(),
+                        -1 => {
+                            pool.retiring.fetch_add(1, Ordering::SeqCst);
+                            // Retire at most one worker per idle period
+                            idle_since = Instant::now();
+                        }
+                        _ => {}
+                    }
+                }
+                if last_metrics.elapsed() >= METRICS_INTERVAL {
+                    last_metrics = Instant::now();
+                    let _ = pool.app.emit("queue-metrics", pool.metrics());
+                }
+            }
+        });
+    }
+}
+
+/// The live-meeting and background (import / re-transcription) pools. Each has its own
+/// workers and limits, so a long import never delays live captions.
+pub struct WorkerPools {
+    live: Arc<WorkerPool>,
+    background: Arc<WorkerPool>,
+}
+
+impl WorkerPools {
+    pub fn start(
+        queue: Arc<Queue>,
+        meeting_manager: Arc<MeetingManager>,
+        transcription_manager: Arc<TranscriptionManager>,
+        app: AppHandle,
+    ) -> Arc<Self> {
+        let settings = settings::get_settings(&app);
+        let start = |lane, initial: u32| {
+            WorkerPool::start(
+                lane,
+                queue.clone(),
+                meeting_manager.clone(),
+                transcription_manager.clone(),
+                app.clone(),
+                initial.clamp(1, 8) as usize,
+            )
+        };
+        Arc::new(Self {
+            live: start(WorkLane::Live, settings.queue_worker_count),
+            // Imports are bursty; start small and let the autoscaler grow the pool
+            background: start(WorkLane::Background, 1),
+        })
+    }
+
+    pub fn worker_count(&self) -> usize {
+        self.live.worker_count() + self.background.worker_count()
+    }
+
+    /// Stop or restart claiming jobs in both pools. Jobs already being transcribed finish.
+    pub fn set_paused(&self, paused: bool) {
+        let changed = self.live.set_paused(paused) | self.background.set_paused(paused);
+        if changed {
+            info!("ASR queue processing {}", if paused { "paused" } else { "resumed" });
+            let _ = self.live.app.emit("queue-paused-changed", paused);
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.live.is_paused()
+    }
+
+    pub fn metrics(&self) -> Vec<QueueMetrics> {
+        vec![self.live.metrics(), self.background.metrics()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_step() {
+        let idle = Duration::from_secs(0);
+        // Backlog above the per-worker threshold adds a worker, up to the cap
+        assert_eq!(scale_step(1, 4, 25.0, idle), 1);
+        assert_eq!(scale_step(2, 4, 25.0, idle), 0);
+        assert_eq!(scale_step(4, 4, 500.0, idle), 0);
+        // GPU cap (or a lowered setting) shrinks the pool
+        assert_eq!(scale_step(3, 1, 500.0, idle), -1);
+        // Idle long enough retires workers down to the minimum
+        assert_eq!(scale_step(2, 4, 0.0, SCALE_DOWN_IDLE), -1);
+        assert_eq!(scale_step(1, 4, 0.0, SCALE_DOWN_IDLE), 0);
+    }
+
+    #[test]
+    fn test_summarize_metrics() {
+        let now = Instant::now();
+        let sample = |ok, rtf, wait_ms| JobSample { finished_at: now, ok, rtf, wait_ms };
+        let samples = vec![
+            sample(true, Some(0.2), 1_000),
+            sample(true, Some(0.4), 3_000),
+            sample(false, None, 2_000),
+            sample(true, None, 2_000),
+        ];
+        let metrics = summarize(WorkLane::Live, &samples, Duration::from_secs(60));
+        assert_eq!((metrics.completed, metrics.failed), (3, 1));
+        assert_eq!(metrics.jobs_per_min, 3.0);
+        assert!((metrics.avg_rtf.unwrap() - 0.3).abs() < 1e-6);
+        assert_eq!(metrics.avg_wait_ms, Some(2_000.0));
+        assert_eq!(metrics.failure_rate, 0.25);
+
+        let empty = summarize(WorkLane::Background, &[], METRICS_WINDOW);
+        assert_eq!((empty.avg_rtf, empty.failure_rate), (None, 0.0));
+    }
 }
@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { useEffect, useState } from "react";
-import { Toaster } from "sonner";
+import { Toaster, toast } from "sonner";
 import "./App.css";
 import AccessibilityPermissions from "./components/AccessibilityPermissions";
 import Footer from "./components/footer";
@@ -82,7 +83,20 @@ function App() {
       }
     };
     window.addEventListener("navigate-to-section", handler);
-    return () => window.removeEventListener("navigate-to-section", handler);
+    // Backend requests (deep links) arrive as Tauri events
+    const unlistenNavigate = listen<string>("navigate-to-section", (ev) => {
+      if (SECTIONS_CONFIG[ev.payload as SidebarSection]) {
+        setCurrentSection(ev.payload as SidebarSection);
+      }
+    });
+    const unlistenDeepLinkError = listen<string>("deep-link-error", (ev) => {
+      toast.error("Couldn't open link", { description: ev.payload });
+    });
+    return () => {
+      window.removeEventListener("navigate-to-section", handler);
+      unlistenNavigate.then((fn) => fn());
+      unlistenDeepLinkError.then((fn) => fn());
+    };
   }, []);
 
   return (
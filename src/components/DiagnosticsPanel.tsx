@@ -1,6 +1,8 @@
 import React, { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { SelfTest } from "./SelfTest";
+import { MetricsDashboard } from "./MetricsDashboard";
 
 interface AudioMetrics {
   buffer_size_samples: number;
@@ -20,6 +22,21 @@ interface AudioMetrics {
   queue_queued: number;
   queue_processing: number;
   queue_backlog_seconds: number;
+  queue_dead_letters: number;
+  queue_workers: number;
+  queue_paused: boolean;
+}
+
+interface QueueMetrics {
+  lane: "live" | "background";
+  window_secs: number;
+  jobs_per_min: number;
+  avg_rtf?: number | null;
+  avg_wait_ms?: number | null;
+  failure_rate: number;
+  completed: number;
+  failed: number;
+  workers: number;
 }
 
 interface ModelStatus { is_loaded: boolean; current_model?: string | null }
@@ -29,6 +46,7 @@ export const DiagnosticsPanel: React.FC = () => {
   const [modelStatus, setModelStatus] = useState<ModelStatus | null>(null);
   const [restartAttempts, setRestartAttempts] = useState<number>(0);
   const [lastErrors, setLastErrors] = useState<string[]>([]);
+  const [queueMetrics, setQueueMetrics] = useState<Record<string, QueueMetrics>>({});
 
   useEffect(() => {
     let cancel = false;
@@ -51,7 +69,13 @@ export const DiagnosticsPanel: React.FC = () => {
   }, []);
 
   useEffect(() => {
+    invoke<QueueMetrics[]>("get_queue_metrics")
+      .then((list) => setQueueMetrics(Object.fromEntries(list.map((m) => [m.lane, m]))))
+      .catch(() => {});
     const unsubs: (() => void)[] = [];
+    listen<QueueMetrics>("queue-metrics", (ev) => {
+      setQueueMetrics((prev) => ({ ...prev, [ev.payload.lane]: ev.payload }));
+    }).then((u) => unsubs.push(u));
     listen("audio-stream-restarting", (_ev) => {
       setRestartAttempts((c) => c + 1);
     }).then((u) => unsubs.push(u));
@@ -88,7 +112,31 @@ export const DiagnosticsPanel: React.FC = () => {
           <div className="text-sm">Queued: {metrics?.queue_queued ?? 0}</div>
           <div className="text-sm">Processing: {metrics?.queue_processing ?? 0}</div>
           <div className="text-sm">Backlog: {metrics ? metrics.queue_backlog_seconds.toFixed(1) : "-"}s</div>
+          <div className="text-sm">Dead letters: {metrics?.queue_dead_letters ?? 0}</div>
+          <div className="text-sm">Workers: {metrics?.queue_workers ?? 0}</div>
+          <div className="text-sm">Status: {metrics?.queue_paused ? "Paused" : "Running"}</div>
+          <button
+            className="mt-2 px-2 py-1 text-xs rounded border border-mid-gray/30"
+            onClick={() => invoke(metrics?.queue_paused ? "resume_queue_processing" : "pause_queue_processing").catch(() => {})}
+          >
+            {metrics?.queue_paused ? "Resume processing" : "Pause processing"}
+          </button>
         </div>
+        {(["live", "background"] as const).map((lane) => {
+          const qm = queueMetrics[lane];
+          return (
+            <div key={lane} className="p-3 rounded border border-mid-gray/30">
+              <div className="font-medium mb-2">
+                {lane === "live" ? "Live" : "Import"} throughput (last {Math.round((qm?.window_secs ?? 300) / 60)} min)
+              </div>
+              <div className="text-sm">Workers: {qm?.workers ?? "-"}</div>
+              <div className="text-sm">Jobs/min: {qm ? qm.jobs_per_min.toFixed(1) : "-"}</div>
+              <div className="text-sm">Avg RTF: {qm?.avg_rtf != null ? qm.avg_rtf.toFixed(2) : "-"}</div>
+              <div className="text-sm">Avg wait: {qm?.avg_wait_ms != null ? (qm.avg_wait_ms / 1000).toFixed(1) : "-"}s</div>
+              <div className="text-sm">Failure rate: {qm ? (qm.failure_rate * 100).toFixed(0) : "-"}%</div>
+            </div>
+          );
+        })}
         <div className="p-3 rounded border border-mid-gray/30">
           <div className="font-medium mb-2">Model</div>
           <div className="text-sm">Loaded: {modelStatus?.is_loaded ? "Yes" : "No"}</div>
@@ -110,6 +158,8 @@ export const DiagnosticsPanel: React.FC = () => {
           </ul>
         </div>
       </div>
+      <MetricsDashboard />
+      <SelfTest />
     </div>
   );
 };
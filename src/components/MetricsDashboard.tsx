@@ -0,0 +1,176 @@
+import React, { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useSettings } from "../hooks/useSettings";
+import { formatError } from "../lib/errors";
+
+interface Distribution {
+  count: number;
+  mean: number;
+  p50: number;
+  p95: number;
+}
+
+interface MetricsDashboardData {
+  window_days: number;
+  meetings_recorded: number;
+  meeting_minutes?: Distribution | null;
+  asr_rtf?: Distribution | null;
+  asr_failures: number;
+  asr_failure_rate: number;
+  queue_wait_ms?: Distribution | null;
+  dead_lettered: number;
+  llm_summaries: number;
+  llm_summary_failures: number;
+  meetings_per_day: number[];
+  sharing_enabled: boolean;
+}
+
+const WINDOWS = [7, 30, 90];
+
+const fmt = (d: Distribution | null | undefined, scale = 1, digits = 2) =>
+  d
+    ? `${(d.p50 * scale).toFixed(digits)} median · ${(d.p95 * scale).toFixed(digits)} p95`
+    : "-";
+
+export const MetricsDashboard: React.FC = () => {
+  const { getSetting, updateSetting, refreshSettings } = useSettings();
+  const sharingEnabled = getSetting("metrics_sharing_enabled") ?? false;
+  const shareUrl = getSetting("metrics_share_url") ?? "";
+  const [days, setDays] = useState(30);
+  const [data, setData] = useState<MetricsDashboardData | null>(null);
+  const [urlDraft, setUrlDraft] = useState(shareUrl);
+  const [preview, setPreview] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  useEffect(() => setUrlDraft(shareUrl), [shareUrl]);
+
+  useEffect(() => {
+    invoke<MetricsDashboardData>("get_metrics_dashboard", { days })
+      .then(setData)
+      .catch((e) => setMessage(formatError(e)));
+  }, [days]);
+
+  const saveUrl = async () => {
+    const value = urlDraft.trim();
+    if (value === shareUrl) return;
+    try {
+      await invoke("update_settings", {
+        patch: { metrics_share_url: value || null },
+      });
+      setMessage(null);
+      await refreshSettings();
+    } catch (e) {
+      setMessage(formatError(e));
+    }
+  };
+
+  const showPreview = async () => {
+    try {
+      const payload = await invoke("preview_shared_metrics");
+      setPreview(JSON.stringify(payload, null, 2));
+    } catch (e) {
+      setMessage(formatError(e));
+    }
+  };
+
+  const share = async () => {
+    try {
+      await invoke("share_metrics");
+      setMessage("Aggregate stats shared");
+    } catch (e) {
+      setMessage(formatError(e));
+    }
+  };
+
+  const maxPerDay = Math.max(1, ...(data?.meetings_per_day ?? [0]));
+
+  return (
+    <div className="p-3 rounded border border-mid-gray/30 space-y-3">
+      <div className="flex items-center justify-between">
+        <div className="font-medium">Usage metrics (local only)</div>
+        <select
+          value={days}
+          onChange={(e) => setDays(Number(e.target.value))}
+          className="text-xs px-2 py-1 rounded border border-mid-gray/30 bg-transparent"
+        >
+          {WINDOWS.map((w) => (
+            <option key={w} value={w}>
+              Last {w} days
+            </option>
+          ))}
+        </select>
+      </div>
+      <div className="grid grid-cols-2 gap-x-4 text-sm">
+        <div>Meetings: {data?.meetings_recorded ?? "-"}</div>
+        <div>Length (min): {fmt(data?.meeting_minutes, 1, 0)}</div>
+        <div>ASR RTF: {fmt(data?.asr_rtf)}</div>
+        <div>
+          ASR failures: {data?.asr_failures ?? "-"}
+          {data ? ` (${(data.asr_failure_rate * 100).toFixed(1)}%)` : ""}
+        </div>
+        <div>Queue wait (s): {fmt(data?.queue_wait_ms, 0.001, 1)}</div>
+        <div>Dead letters: {data?.dead_lettered ?? "-"}</div>
+        <div>
+          LLM summary failures: {data?.llm_summary_failures ?? "-"} /{" "}
+          {data?.llm_summaries ?? "-"}
+        </div>
+      </div>
+      {data && (
+        <div className="flex items-end gap-px h-10" title="Meetings per day">
+          {data.meetings_per_day.map((count, i) => (
+            <div
+              key={i}
+              className="flex-1 bg-logo-primary/60"
+              style={{ height: `${(count / maxPerDay) * 100}%` }}
+            />
+          ))}
+        </div>
+      )}
+      <div className="space-y-2 border-t border-mid-gray/20 pt-2">
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={sharingEnabled}
+            onChange={(e) =>
+              updateSetting("metrics_sharing_enabled", e.target.checked)
+            }
+          />
+          Share anonymous aggregate stats (no meeting names, text, or paths)
+        </label>
+        {sharingEnabled && (
+          <>
+            <input
+              type="text"
+              value={urlDraft}
+              onChange={(e) => setUrlDraft(e.target.value)}
+              onBlur={saveUrl}
+              placeholder="https://metrics.example.com/meetingcoder"
+              className="w-full px-3 py-2 border rounded-md font-mono text-xs"
+            />
+            <div className="flex gap-2">
+              <button
+                className="px-2 py-1 text-xs rounded border border-mid-gray/30"
+                onClick={showPreview}
+              >
+                Preview
+              </button>
+              <button
+                className="px-2 py-1 text-xs rounded border border-mid-gray/30 disabled:opacity-50"
+                onClick={share}
+                disabled={!shareUrl}
+              >
+                Share now
+              </button>
+            </div>
+          </>
+        )}
+        {preview && (
+          <pre className="text-xs bg-mid-gray/10 p-2 rounded overflow-auto max-h-48">
+            {preview}
+          </pre>
+        )}
+        {message && <div className="text-xs text-text/70">{message}</div>}
+      </div>
+    </div>
+  );
+};
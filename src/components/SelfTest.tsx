@@ -0,0 +1,99 @@
+import React, { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { formatError } from "../lib/errors";
+
+interface SelfTestStep {
+  name: string;
+  status: "passed" | "failed" | "skipped";
+  detail: string;
+  duration_ms: number;
+}
+
+interface SelfTestReport {
+  passed: boolean;
+  steps: SelfTestStep[];
+  model?: string | null;
+  expected_text: string;
+  transcript?: string | null;
+  app_version: string;
+  os: string;
+}
+
+const statusLabel: Record<SelfTestStep["status"], string> = {
+  passed: "Pass",
+  failed: "Fail",
+  skipped: "Skipped",
+};
+
+export const SelfTest: React.FC = () => {
+  const [running, setRunning] = useState(false);
+  const [report, setReport] = useState<SelfTestReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [copied, setCopied] = useState(false);
+
+  const run = async () => {
+    setRunning(true);
+    setError(null);
+    setCopied(false);
+    try {
+      setReport(await invoke<SelfTestReport>("run_self_test"));
+    } catch (e) {
+      setError(formatError(e));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  const copy = async () => {
+    if (!report) return;
+    await navigator.clipboard.writeText(JSON.stringify(report, null, 2));
+    setCopied(true);
+  };
+
+  return (
+    <div className="p-3 rounded border border-mid-gray/30">
+      <div className="font-medium mb-2">Self-test</div>
+      <div className="text-xs text-mid-gray mb-2">
+        Speaks a known phrase, transcribes it with the current model, and checks summary
+        generation and file writes. Nothing is added to your meetings.
+      </div>
+      <div className="flex gap-2">
+        <button
+          className="px-2 py-1 text-xs rounded border border-mid-gray/30 disabled:opacity-50"
+          onClick={run}
+          disabled={running}
+        >
+          {running ? "Running…" : "Run self-test"}
+        </button>
+        {report && (
+          <button
+            className="px-2 py-1 text-xs rounded border border-mid-gray/30"
+            onClick={copy}
+          >
+            {copied ? "Copied" : "Copy report"}
+          </button>
+        )}
+      </div>
+      {error && <div className="text-xs text-red-400 mt-2">{error}</div>}
+      {report && (
+        <div className="mt-2 space-y-1">
+          <div className="text-sm">
+            Result: {report.passed ? "Passed" : "Failed"}
+            {report.model ? ` (model: ${report.model})` : ""}
+          </div>
+          <ul className="text-xs space-y-1">
+            {report.steps.map((step) => (
+              <li key={step.name}>
+                <span className="font-mono">{step.name}</span>: {statusLabel[step.status]} —{" "}
+                {step.detail} ({step.duration_ms} ms)
+              </li>
+            ))}
+          </ul>
+          {report.transcript != null && (
+            <div className="text-xs text-mid-gray">Heard: “{report.transcript}”</div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+};
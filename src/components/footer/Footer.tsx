@@ -3,6 +3,7 @@ import { getVersion } from "@tauri-apps/api/app";
 
 import ModelSelector from "../model-selector";
 import UpdateChecker from "../update-checker";
+import OfflineIndicator from "./OfflineIndicator";
 
 const Footer: React.FC = () => {
   const [version, setVersion] = useState("");
@@ -30,6 +31,7 @@ const Footer: React.FC = () => {
 
         {/* Update Status */}
         <div className="flex items-center gap-1">
+          <OfflineIndicator />
           <UpdateChecker />
           <span>•</span>
           <span>v{version}</span>
@@ -0,0 +1,63 @@
+import React, { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useSettings } from "../../hooks/useSettings";
+
+interface BlockedRequest {
+  service: string;
+  target: string;
+  blocked_at: string;
+}
+
+interface NetworkStatus {
+  offline: boolean;
+  blocked: BlockedRequest[];
+}
+
+const OfflineIndicator: React.FC = () => {
+  const { getSetting } = useSettings();
+  const offline = getSetting("offline_mode") ?? false;
+  const [blocked, setBlocked] = useState<BlockedRequest[]>([]);
+
+  useEffect(() => {
+    if (!offline) {
+      setBlocked([]);
+      return;
+    }
+    invoke<NetworkStatus>("get_network_status")
+      .then((status) => setBlocked(status.blocked))
+      .catch((error) => console.error("Failed to get network status:", error));
+
+    const unlisten = listen<BlockedRequest>(
+      "network-request-blocked",
+      (event) => setBlocked((prev) => [event.payload, ...prev].slice(0, 50)),
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [offline]);
+
+  if (!offline) return null;
+
+  const title = blocked.length
+    ? blocked
+        .slice(0, 10)
+        .map(
+          (b) =>
+            `${new Date(b.blocked_at).toLocaleTimeString()} ${b.service}: ${b.target}`,
+        )
+        .join("\n")
+    : "No requests blocked yet";
+
+  return (
+    <span
+      className="px-1.5 py-0.5 rounded bg-mid-gray/20 text-text/80"
+      title={title}
+    >
+      Offline
+      {blocked.length > 0 && ` · ${blocked.length} blocked`}
+    </span>
+  );
+};
+
+export default OfflineIndicator;
@@ -2,11 +2,13 @@ import React, { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import {
   GitHubRepoStatus,
+  IssuesResult,
   PushResult,
   PRResult,
 } from "../../lib/types";
 import { useSettings } from "../../hooks/useSettings";
 import { toast } from "sonner";
+import { formatError } from "../../lib/errors";
 
 export const GitHubActions: React.FC<{
   meetingId: string;
@@ -18,6 +20,7 @@ export const GitHubActions: React.FC<{
   const [isPushing, setIsPushing] = useState(false);
   const [isCreatingPR, setIsCreatingPR] = useState(false);
   const [isPostingComment, setIsPostingComment] = useState(false);
+  const [isCreatingIssues, setIsCreatingIssues] = useState(false);
 
   useEffect(() => {
     if (!githubEnabled) return;
@@ -57,7 +60,7 @@ export const GitHubActions: React.FC<{
     } catch (error) {
       console.error("Failed to push:", error);
       toast.error("Push failed", {
-        description: String(error),
+        description: formatError(error),
       });
     } finally {
       setIsPushing(false);
@@ -90,7 +93,7 @@ export const GitHubActions: React.FC<{
     } catch (error) {
       console.error("Failed to create PR:", error);
       toast.error("PR creation failed", {
-        description: String(error),
+        description: formatError(error),
       });
     } finally {
       setIsCreatingPR(false);
@@ -109,13 +112,33 @@ export const GitHubActions: React.FC<{
     } catch (error) {
       console.error("Failed to post comment:", error);
       toast.error("Failed to post comment", {
-        description: String(error),
+        description: formatError(error),
       });
     } finally {
       setIsPostingComment(false);
     }
   };
 
+  const handleCreateIssues = async () => {
+    setIsCreatingIssues(true);
+    try {
+      const result = await invoke<IssuesResult>("create_issues_from_meeting", {
+        meetingId,
+      });
+      const skipped = result.already_created
+        ? ` (${result.already_created} already filed)`
+        : "";
+      toast.success(`Created ${result.created.length} issue(s)${skipped}`);
+    } catch (error) {
+      console.error("Failed to create issues:", error);
+      toast.error("Failed to create issues", {
+        description: formatError(error),
+      });
+    } finally {
+      setIsCreatingIssues(false);
+    }
+  };
+
   if (!githubEnabled) {
     return (
       <div className="bg-gray-50 border border-gray-200 rounded-lg p-4">
@@ -220,6 +243,14 @@ export const GitHubActions: React.FC<{
             : "Create PR"}
         </button>
 
+        <button
+          onClick={handleCreateIssues}
+          disabled={isCreatingIssues}
+          className="px-4 py-2 bg-gray-700 text-white rounded-md hover:bg-gray-800 disabled:bg-gray-300 disabled:cursor-not-allowed text-sm"
+        >
+          {isCreatingIssues ? "Creating..." : "Create Issues"}
+        </button>
+
         {status.last_pr_number && (
           <button
             onClick={handlePostComment}
@@ -1,5 +1,6 @@
-import { useEffect, useRef } from "react";
-import { TranscriptSegment } from "../../lib/types";
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { PartialTranscript, TranscriptSegment } from "../../lib/types";
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { Clock } from "lucide-react";
 
@@ -11,11 +12,24 @@ interface LiveTranscriptProps {
 
 export const LiveTranscript = ({ meetingId, meetingName, segments }: LiveTranscriptProps) => {
   const transcriptEndRef = useRef<HTMLDivElement>(null);
+  const [partial, setPartial] = useState<PartialTranscript | null>(null);
+
+  // Interim words of the chunk being recorded (streaming mode); cleared once finalized
+  useEffect(() => {
+    setPartial(null);
+    const unlisten = listen<PartialTranscript>("transcript-partial", (event) => {
+      if (event.payload.meeting_id !== meetingId) return;
+      setPartial(event.payload.is_final ? null : event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [meetingId]);
 
   // Auto-scroll to bottom when new segments arrive
   useEffect(() => {
     transcriptEndRef.current?.scrollIntoView({ behavior: "smooth" });
-  }, [segments]);
+  }, [segments, partial]);
 
   const formatTime = (seconds: number) => {
     const mins = Math.floor(seconds / 60);
@@ -44,7 +58,7 @@ export const LiveTranscript = ({ meetingId, meetingName, segments }: LiveTranscr
     >
       <div className="space-y-4">
         <div className="max-h-[600px] overflow-y-auto border border-gray-300 dark:border-gray-700 rounded-lg p-4 bg-white dark:bg-gray-900">
-          {segments.length === 0 ? (
+          {segments.length === 0 && !partial ? (
             <div className="text-center text-gray-500 dark:text-gray-400 py-12">
               <Clock className="w-12 h-12 mx-auto mb-3 opacity-50" />
               <p className="font-medium">Waiting for first transcription...</p>
@@ -76,6 +90,22 @@ export const LiveTranscript = ({ meetingId, meetingName, segments }: LiveTranscr
                   </div>
                 </div>
               ))}
+              {partial && (
+                <div className="border-l-4 border-dashed border-gray-300 dark:border-gray-700 pl-4 py-2">
+                  <span className="text-xs text-gray-500 dark:text-gray-400 flex items-center gap-1 mb-1">
+                    <Clock className="w-3 h-3" />
+                    {formatTime(partial.start_time)} - listening…
+                  </span>
+                  <p className="leading-relaxed">
+                    <span className="text-gray-800 dark:text-gray-200">
+                      {partial.words.slice(0, partial.stable_words).join(" ")}
+                    </span>{" "}
+                    <span className="text-gray-400 italic">
+                      {partial.words.slice(partial.stable_words).join(" ")}
+                    </span>
+                  </p>
+                </div>
+              )}
               <div ref={transcriptEndRef} />
             </div>
           )}
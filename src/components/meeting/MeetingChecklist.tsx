@@ -2,6 +2,7 @@ import React, { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { toast } from "sonner";
 import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "../../lib/errors";
 
 type Props = {
   meetingId: string | null;
@@ -25,19 +26,19 @@ export const MeetingChecklist: React.FC<Props> = ({ meetingId, meetingName }) =>
         {meetingId && (
           <div className="flex items-center gap-2">
             <button
-              onClick={() => invoke("open_meeting_vscode_with_meeting", { meetingId }).catch((e) => toast.error(String(e)))}
+              onClick={() => invoke("open_meeting_vscode_with_meeting", { meetingId }).catch((e) => toast.error(formatError(e)))}
               className="px-2 py-1 text-xs rounded bg-blue-600 text-white hover:bg-blue-700"
             >
               Open VS Code + Claude
             </button>
             <button
-              onClick={() => invoke("open_meeting_cursor_with_meeting", { meetingId }).catch((e) => toast.error(String(e)))}
+              onClick={() => invoke("open_meeting_cursor_with_meeting", { meetingId }).catch((e) => toast.error(formatError(e)))}
               className="px-2 py-1 text-xs rounded bg-indigo-600 text-white hover:bg-indigo-700"
             >
               Open Cursor + Claude
             </button>
             <button
-              onClick={() => invoke("open_meeting_terminal", { meetingId }).catch((e) => toast.error(String(e)))}
+              onClick={() => invoke("open_meeting_terminal", { meetingId }).catch((e) => toast.error(formatError(e)))}
               className="px-2 py-1 text-xs rounded bg-gray-700 text-white hover:bg-gray-800"
             >
               Open Terminal
@@ -8,6 +8,7 @@ import { MeetingChecklist } from "./MeetingChecklist";
 import { AudioSetupSection } from "./AudioSetupSection";
 import { TranscriptSegment, MeetingSummary } from "../../lib/types";
 import { toast } from "sonner";
+import { formatError } from "../../lib/errors";
 
 export const MeetingView = () => {
   const [activeMeetingId, setActiveMeetingId] = useState<string | null>(null);
@@ -223,7 +224,7 @@ export const MeetingView = () => {
     } catch (error) {
       console.error("Failed to start meeting:", error);
       toast.error("Failed to start meeting", {
-        description: String(error),
+        description: formatError(error),
       });
     } finally {
       setIsStarting(false);
@@ -252,7 +253,7 @@ export const MeetingView = () => {
     } catch (error) {
       console.error("Failed to end meeting:", error);
       toast.error("Failed to end meeting", {
-        description: String(error),
+        description: formatError(error),
       });
     } finally {
       setIsEnding(false);
@@ -2,6 +2,7 @@ import React from "react";
 import { ModelInfo } from "../../lib/types";
 import { formatModelSize } from "../../lib/utils/format";
 import { ProgressBar } from "../shared";
+import { formatError } from "../../lib/errors";
 
 interface DownloadProgress {
   model_id: string;
@@ -40,7 +41,7 @@ const ModelDropdown: React.FC<ModelDropdownProps> = ({
     try {
       await onModelDelete(modelId);
     } catch (err) {
-      const errorMsg = `Failed to delete model: ${err}`;
+      const errorMsg = `Failed to delete model: ${formatError(err)}`;
       onError?.(errorMsg);
     }
   };
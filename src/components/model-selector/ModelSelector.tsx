@@ -5,6 +5,7 @@ import { ModelInfo } from "../../lib/types";
 import ModelStatusButton from "./ModelStatusButton";
 import ModelDropdown from "./ModelDropdown";
 import DownloadProgressDisplay from "./DownloadProgressDisplay";
+import { formatError } from "../../lib/errors";
 
 interface ModelStateEvent {
   event_type: string;
@@ -277,7 +278,7 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({ onError }) => {
       await invoke("set_active_model", { modelId });
       setCurrentModelId(modelId);
     } catch (err) {
-      const errorMsg = `${err}`;
+      const errorMsg = formatError(err);
       setModelError(errorMsg);
       setModelStatus("error");
       onError?.(errorMsg);
@@ -289,7 +290,7 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({ onError }) => {
       setModelError(null);
       await invoke("download_model", { modelId });
     } catch (err) {
-      const errorMsg = `${err}`;
+      const errorMsg = formatError(err);
       setModelError(errorMsg);
       setModelStatus("error");
       onError?.(errorMsg);
@@ -3,6 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { ModelInfo } from "../../lib/types";
 import ModelCard from "./ModelCard";
 import HandyTextLogo from "../icons/HandyTextLogo";
+import { formatError } from "../../lib/errors";
 
 interface OnboardingProps {
   onModelSelected: () => void;
@@ -39,7 +40,7 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
       await invoke("download_model", { modelId });
     } catch (err) {
       console.error("Download failed:", err);
-      setError(`Failed to download model: ${err}`);
+      setError(`Failed to download model: ${formatError(err)}`);
       setDownloading(false);
     }
   };
@@ -1,6 +1,10 @@
 import React from "react";
 import { ShowOverlay } from "./ShowOverlay";
+import { OverlayContentSelector } from "./OverlayContentSelector";
+import { OverlayPlacement } from "./OverlayPlacement";
 import { TranslateToEnglish } from "./TranslateToEnglish";
+import { WhisperPromptContext } from "./WhisperPromptContext";
+import { WhisperDecoding } from "./WhisperDecoding";
 import { ModelUnloadTimeoutSetting } from "./ModelUnloadTimeout";
 import { CustomWords } from "./CustomWords";
 import { SettingsGroup } from "../ui/SettingsGroup";
@@ -13,6 +17,8 @@ import { UseFfmpegFallbackForImports } from "./UseFfmpegFallbackForImports";
 import { AutostartToggle } from "./AutostartToggle";
 import { SystemAudioSilenceThreshold } from "./SystemAudioSilenceThreshold";
 import { SystemAudioBufferSeconds } from "./SystemAudioBufferSeconds";
+import { NoiseSuppression } from "./NoiseSuppression";
+import { NetworkProxySettings } from "./NetworkProxySettings";
 
 export const AdvancedSettings: React.FC = () => {
   return (
@@ -21,7 +27,11 @@ export const AdvancedSettings: React.FC = () => {
         <StartHidden descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
+        <OverlayContentSelector descriptionMode="tooltip" grouped={true} />
+        <OverlayPlacement descriptionMode="tooltip" grouped={true} />
         <TranslateToEnglish descriptionMode="tooltip" grouped={true} />
+        <WhisperPromptContext descriptionMode="tooltip" grouped={true} />
+        <WhisperDecoding descriptionMode="tooltip" />
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
         <CustomWords descriptionMode="tooltip" grouped />
         <PreferWhisperForImports descriptionMode="tooltip" />
@@ -31,6 +41,10 @@ export const AdvancedSettings: React.FC = () => {
         <UseFfmpegFallbackForImports descriptionMode="tooltip" />
         <SystemAudioSilenceThreshold descriptionMode="tooltip" />
         <SystemAudioBufferSeconds descriptionMode="tooltip" />
+        <NoiseSuppression descriptionMode="tooltip" grouped={true} />
+      </SettingsGroup>
+      <SettingsGroup title="Network">
+        <NetworkProxySettings grouped={true} />
       </SettingsGroup>
     </div>
   );
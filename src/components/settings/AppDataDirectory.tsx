@@ -1,6 +1,7 @@
 import React, { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { TextDisplay } from "../ui";
+import { formatError } from "../../lib/errors";
 
 interface AppDataDirectoryProps {
   descriptionMode?: "tooltip" | "inline";
@@ -21,9 +22,7 @@ export const AppDataDirectory: React.FC<AppDataDirectoryProps> = ({
         const result = await invoke<string>("get_app_dir_path");
         setAppDirPath(result);
       } catch (err) {
-        setError(
-          err instanceof Error ? err.message : "Failed to load app directory",
-        );
+        setError(formatError(err));
       } finally {
         setLoading(false);
       }
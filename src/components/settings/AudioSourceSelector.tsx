@@ -50,6 +50,8 @@ export const AudioSourceSelector: React.FC<AudioSourceSelectorProps> = ({
       ? "microphone"
       : currentSource.startsWith("system:")
       ? currentSource.slice("system:".length)
+      : currentSource.startsWith("dual:")
+      ? currentSource
       : null;
 
   const handleSelect = async (value: string) => {
@@ -58,6 +60,11 @@ export const AudioSourceSelector: React.FC<AudioSourceSelectorProps> = ({
       if (value === "microphone") {
         await invoke("set_microphone_source");
         setCurrentSource("microphone");
+      } else if (value.startsWith("dual:")) {
+        await invoke("set_dual_audio_source", {
+          deviceName: value.slice("dual:".length),
+        });
+        setCurrentSource(value);
       } else {
         await invoke("set_system_audio_source", { deviceName: value });
         setCurrentSource(`system:${value}`);
@@ -72,12 +79,16 @@ export const AudioSourceSelector: React.FC<AudioSourceSelectorProps> = ({
   const options = [
     { value: "microphone", label: "Microphone (use selected input)" },
     ...devices.map((d) => ({ value: d.name, label: `System: ${d.name}` })),
+    ...devices.map((d) => ({
+      value: `dual:${d.name}`,
+      label: `Microphone + System: ${d.name} (Me / Remote)`,
+    })),
   ];
 
   return (
     <SettingContainer
       title="Audio Source"
-      description="Choose to capture from your microphone or a system audio device (e.g., BlackHole on macOS)"
+      description="Choose to capture from your microphone, a system audio device (e.g., BlackHole on macOS), or both at once to label your voice and the call separately"
       descriptionMode={descriptionMode}
       grouped={grouped}
     >
@@ -8,6 +8,8 @@ import { AlwaysOnMicrophone } from "./AlwaysOnMicrophone";
 import { SoundPicker } from "./SoundPicker";
 import { MuteWhileRecording } from "./MuteWhileRecording";
 import SystemAudioTest from "./SystemAudioTest";
+import { LogLevelSelector } from "./LogLevelSelector";
+import { DiagnosticsBundleExport } from "./DiagnosticsBundleExport";
 
 export const DebugSettings: React.FC = () => {
   return (
@@ -25,6 +27,11 @@ export const DebugSettings: React.FC = () => {
         <MuteWhileRecording descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
+      <SettingsGroup title="Logs">
+        <LogLevelSelector descriptionMode="tooltip" grouped={true} />
+        <DiagnosticsBundleExport descriptionMode="tooltip" grouped={true} />
+      </SettingsGroup>
+
       <SettingsGroup title="System Audio Testing">
         <SystemAudioTest />
       </SettingsGroup>
@@ -0,0 +1,67 @@
+import React, { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { save } from "@tauri-apps/plugin-dialog";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+import { formatError } from "../../lib/errors";
+
+interface DiagnosticsBundle {
+  path: string;
+  files: string[];
+}
+
+interface DiagnosticsBundleExportProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const DiagnosticsBundleExport: React.FC<DiagnosticsBundleExportProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const [exporting, setExporting] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+
+  const handleExport = async () => {
+    const stamp = new Date().toISOString().slice(0, 10);
+    const targetPath = await save({
+      defaultPath: `meetingcoder-diagnostics-${stamp}.zip`,
+      filters: [{ name: "Zip archive", extensions: ["zip"] }],
+    });
+    if (!targetPath) return;
+    setExporting(true);
+    try {
+      const bundle = await invoke<DiagnosticsBundle>(
+        "export_diagnostics_bundle",
+        { targetPath },
+      );
+      setMessage(`Saved ${bundle.files.length} files to ${bundle.path}`);
+    } catch (error) {
+      setMessage(`Export failed: ${formatError(error)}`);
+    } finally {
+      setExporting(false);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title="Diagnostics Bundle"
+      description="Zip recent logs, settings (with credentials and your home folder removed), audio metrics, and queue state to attach to a bug report"
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="flex items-center gap-3">
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={handleExport}
+          disabled={exporting}
+        >
+          {exporting ? "Exporting..." : "Export..."}
+        </Button>
+        {message && <span className="text-xs text-mid-gray">{message}</span>}
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,27 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface FocusModeWhileRecordingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const FocusModeWhileRecording: React.FC<FocusModeWhileRecordingProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("focus_mode_while_recording") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("focus_mode_while_recording", value)}
+        isUpdating={isUpdating("focus_mode_while_recording")}
+        label="Do Not Disturb While Recording"
+        description='Turn on Do Not Disturb while a meeting records so notification sounds stay out of captured audio, then restore it afterwards. On macOS, create Shortcuts named "MeetingCoder Focus On" and "MeetingCoder Focus Off" that use the Set Focus action.'
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
@@ -9,11 +9,16 @@ import { PushToTalk } from "./PushToTalk";
 import { AudioFeedback } from "./AudioFeedback";
 import { useSettings } from "../../hooks/useSettings";
 import { VolumeSlider } from "./VolumeSlider";
+import { FocusModeWhileRecording } from "./FocusModeWhileRecording";
 import { ChunkDuration } from "./ChunkDuration";
+import { StreamingTranscription } from "./StreamingTranscription";
 import { UpdateInterval } from "./UpdateInterval";
 import { AutoTriggerToggle } from "./AutoTriggerToggle";
 import { AutoAcceptChanges } from "./AutoAcceptChanges";
 import { AutomationDebounce } from "./AutomationDebounce";
+import { OfflineMode } from "./OfflineMode";
+import { RedactPii } from "./RedactPii";
+import { PermissionsStatus } from "./PermissionsStatus";
 
 export const GeneralSettings: React.FC = () => {
   const { audioFeedbackEnabled } = useSettings();
@@ -22,13 +27,19 @@ export const GeneralSettings: React.FC = () => {
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title="General">
         <HandyShortcut descriptionMode="tooltip" grouped={true} />
+        <HandyShortcut shortcutId="meeting_toggle" descriptionMode="tooltip" grouped={true} />
+        <HandyShortcut shortcutId="meeting_pause" descriptionMode="tooltip" grouped={true} />
+        <HandyShortcut shortcutId="meeting_bookmark" descriptionMode="tooltip" grouped={true} />
         <LanguageSelector descriptionMode="tooltip" grouped={true} />
         <PushToTalk descriptionMode="tooltip" grouped={true} />
         <ChunkDuration descriptionMode="tooltip" grouped={true} />
+        <StreamingTranscription descriptionMode="tooltip" grouped={true} />
         <UpdateInterval descriptionMode="tooltip" grouped={true} />
         <AutoTriggerToggle descriptionMode="tooltip" grouped={true} />
         <AutomationDebounce descriptionMode="tooltip" grouped={true} />
         <AutoAcceptChanges descriptionMode="tooltip" grouped={true} />
+        <OfflineMode descriptionMode="tooltip" grouped={true} />
+        <RedactPii descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
       <SettingsGroup title="Sound">
         <AudioSourceSelector descriptionMode="tooltip" grouped={true} />
@@ -40,6 +51,10 @@ export const GeneralSettings: React.FC = () => {
           disabled={!audioFeedbackEnabled}
         />
         <VolumeSlider disabled={!audioFeedbackEnabled} />
+        <FocusModeWhileRecording descriptionMode="tooltip" grouped={true} />
+      </SettingsGroup>
+      <SettingsGroup title="Permissions">
+        <PermissionsStatus descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
       {/* GitHub moved under Integrations */}
     </div>
@@ -0,0 +1,41 @@
+import React from "react";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { GitAuthMethod } from "../../lib/types";
+
+interface GitAuthMethodSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const methodOptions = [
+  { value: "token", label: "GitHub token" },
+  { value: "ssh", label: "SSH key" },
+  { value: "credential_helper", label: "Git credential helper" },
+];
+
+export const GitAuthMethodSelector: React.FC<GitAuthMethodSelectorProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const method = (getSetting("git_auth_method") || "token") as GitAuthMethod;
+
+    return (
+      <SettingContainer
+        title="Git Authentication"
+        description="How clones and pushes sign in to GitHub. SSH uses your agent or ~/.ssh keys; the credential helper uses git's configured one (Keychain, Credential Manager, libsecret)."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={methodOptions}
+          selectedValue={method}
+          onSelect={(value) =>
+            updateSetting("git_auth_method", value as GitAuthMethod)
+          }
+          disabled={isUpdating("git_auth_method")}
+        />
+      </SettingContainer>
+    );
+  });
@@ -1,6 +1,7 @@
 import React, { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { openUrl } from "@tauri-apps/plugin-opener";
+import { formatError } from "../../lib/errors";
 
 interface DeviceCodeResponse {
   device_code: string;
@@ -36,7 +37,7 @@ export const GitHubOAuth: React.FC = () => {
         }
       } catch (err) {
         console.error("Poll error:", err);
-        setError(String(err));
+        setError(formatError(err));
         setIsAuthenticating(false);
         setDeviceCode(null);
         clearInterval(pollInterval);
@@ -60,7 +61,7 @@ export const GitHubOAuth: React.FC = () => {
       await openUrl(response.verification_uri);
     } catch (err) {
       console.error("Failed to begin device auth:", err);
-      setError(String(err));
+      setError(formatError(err));
       setIsAuthenticating(false);
     }
   };
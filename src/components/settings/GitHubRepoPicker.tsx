@@ -2,6 +2,7 @@ import React, { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { RepoInfo } from "../../lib/types";
 import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "../../lib/errors";
 
 export const GitHubRepoPicker: React.FC<{
   descriptionMode?: "tooltip" | "inline";
@@ -28,7 +29,7 @@ export const GitHubRepoPicker: React.FC<{
       setShowPicker(true);
     } catch (err) {
       console.error("Failed to load repos:", err);
-      setError(String(err));
+      setError(formatError(err));
     } finally {
       setIsLoading(false);
     }
@@ -1,6 +1,7 @@
 import React, { useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { GitHubConnectionTest } from "../../lib/types";
+import { formatError } from "../../lib/errors";
 
 export const GitHubToken: React.FC<{
   descriptionMode?: "tooltip" | "inline";
@@ -29,7 +30,7 @@ export const GitHubToken: React.FC<{
       alert("GitHub token saved successfully!");
     } catch (error) {
       console.error("Failed to save token:", error);
-      alert(`Failed to save token: ${error}`);
+      alert(`Failed to save token: ${formatError(error)}`);
     } finally {
       setIsSaving(false);
     }
@@ -48,7 +49,7 @@ export const GitHubToken: React.FC<{
       setTestResult({
         success: false,
         username: null,
-        error: String(error),
+        error: formatError(error),
       });
     } finally {
       setIsTesting(false);
@@ -64,7 +65,7 @@ export const GitHubToken: React.FC<{
       alert("GitHub token removed successfully!");
     } catch (error) {
       console.error("Failed to remove token:", error);
-      alert(`Failed to remove token: ${error}`);
+      alert(`Failed to remove token: ${formatError(error)}`);
     }
   };
 
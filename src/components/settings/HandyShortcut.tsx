@@ -11,13 +11,17 @@ import { SettingContainer } from "../ui/SettingContainer";
 import { useSettings } from "../../hooks/useSettings";
 import { invoke } from "@tauri-apps/api/core";
 import { toast } from "sonner";
+import { formatError } from "../../lib/errors";
 
 interface HandyShortcutProps {
+  /** Binding to edit; defaults to the dictation shortcut */
+  shortcutId?: string;
   descriptionMode?: "inline" | "tooltip";
   grouped?: boolean;
 }
 
 export const HandyShortcut: React.FC<HandyShortcutProps> = ({
+  shortcutId = "transcribe",
   descriptionMode = "tooltip",
   grouped = false,
 }) => {
@@ -139,7 +143,7 @@ export const HandyShortcut: React.FC<HandyShortcutProps> = ({
             );
           } catch (error) {
             console.error("Failed to change binding:", error);
-            toast.error(`Failed to set shortcut: ${error}`);
+            toast.error(`Failed to set shortcut: ${formatError(error)}`);
 
             // Reset to original binding on error
             if (originalBinding) {
@@ -267,16 +271,21 @@ export const HandyShortcut: React.FC<HandyShortcutProps> = ({
     );
   }
 
+  const isDictation = shortcutId === "transcribe";
   return (
     <SettingContainer
-      title="Handy Shortcut"
-      description="Set the keyboard shortcut to start and stop speech-to-text recording"
+      title={isDictation ? "Handy Shortcut" : (bindings[shortcutId]?.name ?? shortcutId)}
+      description={
+        isDictation
+          ? "Set the keyboard shortcut to start and stop speech-to-text recording"
+          : (bindings[shortcutId]?.description ?? "")
+      }
       descriptionMode={descriptionMode}
       grouped={grouped}
     >
       {(() => {
-        const primaryBinding = Object.values(bindings)[0];
-        const primaryId = Object.keys(bindings)[0];
+        const primaryBinding = bindings[shortcutId];
+        const primaryId = shortcutId;
 
         if (!primaryBinding) {
           return (
@@ -6,6 +6,7 @@ import { convertFileSrc, invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { MeetingHistoryEntry } from "../../lib/types";
 import { toast } from "sonner";
+import { formatError } from "../../lib/errors";
 
 interface HistoryEntry {
   id: number;
@@ -153,7 +154,7 @@ export const HistorySettings: React.FC = () => {
                         toast.success("Meeting deleted");
                         loadMeetingHistory();
                       })
-                      .catch((e) => toast.error(`Failed to delete: ${e}`));
+                      .catch((e) => toast.error(`Failed to delete: ${formatError(e)}`));
                   }}
                 />
               ))}
@@ -213,7 +214,7 @@ const MeetingHistoryEntryComponent: React.FC<MeetingHistoryEntryProps> = ({
     try {
       await invoke("open_meeting_folder", { dirPath: meeting.dir_path });
     } catch (error) {
-      toast.error(`Failed to open folder: ${error}`);
+      toast.error(`Failed to open folder: ${formatError(error)}`);
     }
   };
 
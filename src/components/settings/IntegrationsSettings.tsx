@@ -1,4 +1,5 @@
 import React, { useState } from "react";
+import { type } from "@tauri-apps/plugin-os";
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { GitHubEnabled } from "./GitHubEnabled";
 import { GitHubOAuth } from "./GitHubOAuth";
@@ -6,6 +7,8 @@ import { GitHubToken } from "./GitHubToken";
 import { GitHubRepoPicker } from "./GitHubRepoPicker";
 import { GitHubRepo } from "./GitHubRepo";
 import { GitHubBranchSettings } from "./GitHubBranchSettings";
+import { GitAuthMethodSelector } from "./GitAuthMethodSelector";
+import { MacAutomation } from "./MacAutomation";
 import { useSettings } from "../../hooks/useSettings";
 
 export const IntegrationsSettings: React.FC = () => {
@@ -81,10 +84,16 @@ export const IntegrationsSettings: React.FC = () => {
             <div className="border-t pt-4 mt-4">
               <h4 className="text-sm font-medium text-gray-700 mb-3">Step 3: Configure Branch Settings</h4>
               <GitHubBranchSettings descriptionMode="tooltip" grouped={true} />
+              <GitAuthMethodSelector descriptionMode="tooltip" grouped={true} />
             </div>
           </>
         )}
       </SettingsGroup>
+      {type() === "macos" && (
+        <SettingsGroup title="macOS Automation">
+          <MacAutomation descriptionMode="tooltip" grouped={true} />
+        </SettingsGroup>
+      )}
     </div>
   );
 };
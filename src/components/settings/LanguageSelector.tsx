@@ -11,7 +11,11 @@ interface LanguageSelectorProps {
   grouped?: boolean;
 }
 
-const unsupportedModels = ["parakeet-tdt-0.6b-v2", "parakeet-tdt-0.6b-v3"];
+const unsupportedModels = [
+  "parakeet-tdt-0.6b-v2",
+  "parakeet-tdt-0.6b-v3",
+  "moonshine-base",
+];
 
 export const LanguageSelector: React.FC<LanguageSelectorProps> = ({
   descriptionMode = "tooltip",
@@ -108,7 +112,7 @@ export const LanguageSelector: React.FC<LanguageSelectorProps> = ({
       title="Language"
       description={
         isUnsupported
-          ? "Parakeet model automatically detects the language. No manual selection is needed."
+          ? "Parakeet and Moonshine models choose the language themselves. No manual selection is needed."
           : "Select the language for speech recognition. Auto will automatically determine the language, while selecting a specific language can improve accuracy for that language."
       }
       descriptionMode={descriptionMode}
@@ -0,0 +1,42 @@
+import React from "react";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { LogLevel } from "../../lib/types";
+
+interface LogLevelSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const levelOptions = [
+  { value: "error", label: "Errors only" },
+  { value: "warn", label: "Warnings" },
+  { value: "info", label: "Info" },
+  { value: "debug", label: "Debug" },
+  { value: "trace", label: "Trace" },
+];
+
+export const LogLevelSelector: React.FC<LogLevelSelectorProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const selectedLevel = (getSetting("log_level") || "info") as LogLevel;
+
+    return (
+      <SettingContainer
+        title="Log Level"
+        description="Lowest level written to the log files in the app data folder. Debug and Trace are verbose; use them while reproducing a problem."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={levelOptions}
+          selectedValue={selectedLevel}
+          onSelect={(value) => updateSetting("log_level", value as LogLevel)}
+          disabled={isUpdating("log_level")}
+        />
+      </SettingContainer>
+    );
+  },
+);
@@ -0,0 +1,47 @@
+import React, { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { toast } from "sonner";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Button } from "../ui/Button";
+import { formatError } from "../../lib/errors";
+
+interface MacAutomationProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const MacAutomation: React.FC<MacAutomationProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const [installing, setInstalling] = useState(false);
+
+  const install = async () => {
+    setInstalling(true);
+    try {
+      const path = await invoke<string>("install_automation_script");
+      toast.success("AppleScript library installed", {
+        description: `Use tell script "MeetingCoder" in Script Editor or Shortcuts (${path})`,
+      });
+    } catch (err) {
+      toast.error("Couldn't install AppleScript library", {
+        description: formatError(err),
+      });
+    } finally {
+      setInstalling(false);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title="AppleScript & Shortcuts"
+      description="Install a script library with startMeeting, endMeeting, importFile, and exportSummary handlers. Shortcuts can also use Open URL with meetingcoder://start, meetingcoder://end, meetingcoder://import?path=..., and meetingcoder://export-summary/latest."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <Button variant="secondary" size="sm" onClick={install} disabled={installing}>
+        {installing ? "Installing..." : "Install"}
+      </Button>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,85 @@
+import React, { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "../../lib/errors";
+
+type NetworkKey = "proxy_url" | "ca_bundle_path";
+
+export const NetworkProxySettings: React.FC<{
+  grouped?: boolean;
+}> = ({ grouped = false }) => {
+  const { getSetting, refreshSettings } = useSettings();
+  const proxyUrl = getSetting("proxy_url") ?? "";
+  const caBundlePath = getSetting("ca_bundle_path") ?? "";
+  const [drafts, setDrafts] = useState({
+    proxy_url: proxyUrl,
+    ca_bundle_path: caBundlePath,
+  });
+  const [errors, setErrors] = useState<Partial<Record<NetworkKey, string>>>({});
+
+  useEffect(() => {
+    setDrafts({ proxy_url: proxyUrl, ca_bundle_path: caBundlePath });
+  }, [proxyUrl, caBundlePath]);
+
+  // Saved on blur so the backend only validates finished values
+  const save = async (key: NetworkKey) => {
+    const value = drafts[key].trim();
+    const current = key === "proxy_url" ? proxyUrl : caBundlePath;
+    if (value === current) return;
+    try {
+      await invoke("update_settings", { patch: { [key]: value || null } });
+      setErrors((prev) => ({ ...prev, [key]: undefined }));
+      await refreshSettings();
+    } catch (error) {
+      setErrors((prev) => ({ ...prev, [key]: formatError(error) }));
+    }
+  };
+
+  return (
+    <div className={`space-y-3 ${grouped ? "py-3" : "py-4"}`}>
+      <div>
+        <label className="block text-sm font-medium mb-2">Proxy</label>
+        <input
+          type="text"
+          value={drafts.proxy_url}
+          onChange={(e) =>
+            setDrafts((prev) => ({ ...prev, proxy_url: e.target.value }))
+          }
+          onBlur={() => save("proxy_url")}
+          placeholder="http://proxy.example.com:3128"
+          className="w-full px-3 py-2 border rounded-md font-mono text-sm"
+        />
+        <p className="text-xs text-gray-500 mt-1">
+          Used for model downloads, GitHub, the LLM, cloud sync, git, and
+          yt-dlp. Leave empty to use HTTPS_PROXY or the system proxy.
+        </p>
+        {errors.proxy_url && (
+          <p className="text-xs text-red-500 mt-1">{errors.proxy_url}</p>
+        )}
+      </div>
+
+      <div>
+        <label className="block text-sm font-medium mb-2">
+          Custom CA Bundle
+        </label>
+        <input
+          type="text"
+          value={drafts.ca_bundle_path}
+          onChange={(e) =>
+            setDrafts((prev) => ({ ...prev, ca_bundle_path: e.target.value }))
+          }
+          onBlur={() => save("ca_bundle_path")}
+          placeholder="/path/to/corporate-ca.pem"
+          className="w-full px-3 py-2 border rounded-md font-mono text-sm"
+        />
+        <p className="text-xs text-gray-500 mt-1">
+          PEM file with extra root certificates to trust, for networks that
+          inspect TLS traffic.
+        </p>
+        {errors.ca_bundle_path && (
+          <p className="text-xs text-red-500 mt-1">{errors.ca_bundle_path}</p>
+        )}
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,28 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface NoiseSuppressionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const NoiseSuppression: React.FC<NoiseSuppressionProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("noise_suppression_enabled") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("noise_suppression_enabled", value)}
+        isUpdating={isUpdating("noise_suppression_enabled")}
+        label="Noise Suppression"
+        description="Filter keyboard clatter, fans, and other background noise out of the microphone and system audio before transcription. A small model is downloaded the first time it is turned on."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
@@ -0,0 +1,28 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface OfflineModeProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const OfflineMode: React.FC<OfflineModeProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("offline_mode") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("offline_mode", value)}
+        isUpdating={isUpdating("offline_mode")}
+        label="Offline Mode"
+        description="Block every outbound request: LLM summaries, GitHub, model downloads, cloud sync, and update checks. Local servers on this machine are still reachable. Blocked requests are listed in the footer."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
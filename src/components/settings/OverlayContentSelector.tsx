@@ -0,0 +1,43 @@
+import React from "react";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { OverlayContent } from "../../lib/types";
+
+interface OverlayContentSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const contentOptions = [
+  { value: "levels", label: "Level Bars" },
+  { value: "waveform", label: "Waveform" },
+  { value: "captions", label: "Captions" },
+  { value: "status_pill", label: "Status Pill" },
+];
+
+export const OverlayContentSelector: React.FC<OverlayContentSelectorProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const selectedContent = (getSetting("overlay_content") ||
+      "levels") as OverlayContent;
+
+    return (
+      <SettingContainer
+        title="Overlay Content"
+        description="What the overlay shows while recording: audio levels, a live waveform, transcribed captions, or a compact status pill"
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={contentOptions}
+          selectedValue={selectedContent}
+          onSelect={(value) =>
+            updateSetting("overlay_content", value as OverlayContent)
+          }
+          disabled={isUpdating("overlay_content")}
+        />
+      </SettingContainer>
+    );
+  });
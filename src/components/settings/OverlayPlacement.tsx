@@ -0,0 +1,95 @@
+import React, { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface OverlayPlacementProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+interface Placement {
+  monitor: string | null;
+  offset_x: number;
+  offset_y: number;
+}
+
+interface PlacementInfo {
+  display_configuration: string;
+  monitors: string[];
+  placement: Placement;
+}
+
+const FOLLOW_CURSOR = "__cursor__";
+
+export const OverlayPlacement: React.FC<OverlayPlacementProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const [info, setInfo] = useState<PlacementInfo | null>(null);
+
+    useEffect(() => {
+      invoke<PlacementInfo>("get_overlay_placement")
+        .then(setInfo)
+        .catch((err) => console.error("Failed to load overlay placement:", err));
+    }, []);
+
+    if (!info) return null;
+
+    const save = async (placement: Placement) => {
+      setInfo({ ...info, placement });
+      try {
+        await invoke("change_overlay_placement_setting", { placement });
+      } catch (err) {
+        console.error("Failed to save overlay placement:", err);
+      }
+    };
+
+    const monitorOptions = [
+      { value: FOLLOW_CURSOR, label: "Follow cursor" },
+      ...info.monitors.map((name) => ({ value: name, label: name })),
+    ];
+
+    const offset = (key: "offset_x" | "offset_y", value: string) => {
+      const parsed = parseInt(value, 10);
+      save({ ...info.placement, [key]: Number.isNaN(parsed) ? 0 : parsed });
+    };
+
+    return (
+      <SettingContainer
+        title="Overlay Display"
+        description="Which monitor shows the overlay and how far to nudge it (in pixels) from the top/bottom position. Remembered separately for each monitor setup."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center gap-2">
+          <Dropdown
+            options={monitorOptions}
+            selectedValue={info.placement.monitor ?? FOLLOW_CURSOR}
+            onSelect={(value) =>
+              save({
+                ...info.placement,
+                monitor: value === FOLLOW_CURSOR ? null : value,
+              })
+            }
+          />
+          <Input
+            type="number"
+            variant="compact"
+            className="w-20"
+            title="Horizontal offset"
+            value={info.placement.offset_x}
+            onChange={(e) => offset("offset_x", e.target.value)}
+          />
+          <Input
+            type="number"
+            variant="compact"
+            className="w-20"
+            title="Vertical offset"
+            value={info.placement.offset_y}
+            onChange={(e) => offset("offset_y", e.target.value)}
+          />
+        </div>
+      </SettingContainer>
+    );
+  },
+);
@@ -0,0 +1,141 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { toast } from "sonner";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+import { formatError } from "../../lib/errors";
+
+type PermissionKind =
+  | "microphone"
+  | "screen_capture"
+  | "accessibility"
+  | "notifications";
+
+type PermissionStatus =
+  | "granted"
+  | "denied"
+  | "not_determined"
+  | "not_applicable"
+  | "unknown";
+
+interface PermissionCheck {
+  kind: PermissionKind;
+  label: string;
+  status: PermissionStatus;
+  detail: string;
+  can_open_settings: boolean;
+}
+
+interface PermissionsReport {
+  platform: string;
+  permissions: PermissionCheck[];
+  all_granted: boolean;
+}
+
+const STATUS_TEXT: Record<PermissionStatus, string> = {
+  granted: "Granted",
+  denied: "Denied",
+  not_determined: "Not asked yet",
+  not_applicable: "Not needed",
+  unknown: "Unknown",
+};
+
+const STATUS_CLASS: Record<PermissionStatus, string> = {
+  granted: "text-green-600",
+  denied: "text-red-600",
+  not_determined: "text-yellow-600",
+  not_applicable: "text-mid-gray",
+  unknown: "text-mid-gray",
+};
+
+interface PermissionsStatusProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PermissionsStatus: React.FC<PermissionsStatusProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const [report, setReport] = useState<PermissionsReport | null>(null);
+  const [checking, setChecking] = useState(false);
+
+  const refresh = useCallback(async () => {
+    setChecking(true);
+    try {
+      setReport(await invoke<PermissionsReport>("check_all_permissions"));
+    } catch (error) {
+      toast.error("Couldn't check permissions", {
+        description: formatError(error),
+      });
+    } finally {
+      setChecking(false);
+    }
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    // Permissions are usually granted in System Settings, so re-check on return
+    window.addEventListener("focus", refresh);
+    return () => window.removeEventListener("focus", refresh);
+  }, [refresh]);
+
+  const openSettings = async (kind: PermissionKind) => {
+    try {
+      await invoke("open_permission_settings", { kind });
+    } catch (error) {
+      toast.error("Couldn't open settings", {
+        description: formatError(error),
+      });
+    }
+  };
+
+  return (
+    <SettingContainer
+      title="Permissions"
+      description="Microphone, system audio capture, accessibility (for pasting and automation), and notification access for this app"
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="space-y-2">
+        {report?.permissions.map((permission) => (
+          <div
+            key={permission.kind}
+            className="flex items-center justify-between gap-3 text-sm"
+          >
+            <div className="min-w-0">
+              <div className="font-medium">{permission.label}</div>
+              <div className="text-xs text-mid-gray truncate">
+                {permission.detail}
+              </div>
+            </div>
+            <div className="flex items-center gap-2 shrink-0">
+              <span className={`text-xs ${STATUS_CLASS[permission.status]}`}>
+                {STATUS_TEXT[permission.status]}
+              </span>
+              {permission.can_open_settings &&
+                permission.status !== "granted" && (
+                  <Button
+                    variant="secondary"
+                    size="sm"
+                    onClick={() => openSettings(permission.kind)}
+                  >
+                    Open Settings
+                  </Button>
+                )}
+            </div>
+          </div>
+        ))}
+        <Button
+          variant="ghost"
+          size="sm"
+          onClick={refresh}
+          disabled={checking}
+        >
+          {checking ? "Checking..." : "Check again"}
+        </Button>
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,28 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface RedactPiiProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const RedactPii: React.FC<RedactPiiProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("redact_pii") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("redact_pii", value)}
+        isUpdating={isUpdating("redact_pii")}
+        label="Redact Personal Info"
+        description="Replace email addresses, phone numbers, card numbers, and your custom patterns with placeholders before transcript text is sent to the LLM or posted to GitHub. Saved transcripts keep the original text."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
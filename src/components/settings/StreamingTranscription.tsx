@@ -0,0 +1,27 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface StreamingTranscriptionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const StreamingTranscription: React.FC<StreamingTranscriptionProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("streaming_transcription") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("streaming_transcription", value)}
+        isUpdating={isUpdating("streaming_transcription")}
+        label="Live Captions While Recording"
+        description="Show interim text about once a second while each chunk is still being recorded. Uses more CPU/GPU."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import { useEffect, useState } from "react";
 import { Check, X, Loader2, RefreshCw, AlertCircle } from "lucide-react";
+import { formatError } from "../../lib/errors";
 
 interface VirtualDeviceInfo {
   name: string;
@@ -33,7 +34,7 @@ export default function SystemAudioTest() {
       const supported = await invoke<boolean>("is_system_audio_supported");
       setIsSupported(supported);
     } catch (err) {
-      setError(`Failed to check support: ${err}`);
+      setError(`Failed to check support: ${formatError(err)}`);
     }
   };
 
@@ -44,7 +45,7 @@ export default function SystemAudioTest() {
       const result = await invoke<DetectDeviceResponse>("detect_virtual_audio_device");
       setDetectedDevice(result);
     } catch (err) {
-      setError(`Failed to detect device: ${err}`);
+      setError(`Failed to detect device: ${formatError(err)}`);
     } finally {
       setLoading(false);
     }
@@ -57,7 +58,7 @@ export default function SystemAudioTest() {
       const devices = await invoke<VirtualDeviceInfo[]>("list_system_audio_devices");
       setAllDevices(devices);
     } catch (err) {
-      setError(`Failed to list devices: ${err}`);
+      setError(`Failed to list devices: ${formatError(err)}`);
     } finally {
       setLoading(false);
     }
@@ -68,7 +69,7 @@ export default function SystemAudioTest() {
       const instructions = await invoke<string>("get_system_audio_setup_instructions");
       setSetupInstructions(instructions);
     } catch (err) {
-      setError(`Failed to get instructions: ${err}`);
+      setError(`Failed to get instructions: ${formatError(err)}`);
     }
   };
 
@@ -99,7 +100,7 @@ export default function SystemAudioTest() {
       setBufferSize(0);
       setIsRecording(true);
     } catch (err) {
-      setError(`Failed to start recording: ${err}`);
+      setError(`Failed to start recording: ${formatError(err)}`);
     }
   };
 
@@ -118,7 +119,7 @@ export default function SystemAudioTest() {
 
       alert(`Recording saved to Desktop!\n\nFile: ${filename}\n\nSamples: ${bufferSize} (${(bufferSize / 16000).toFixed(1)}s)\n\nCheck your Desktop for the WAV file.`);
     } catch (err) {
-      setError(`Failed to save recording: ${err}`);
+      setError(`Failed to save recording: ${formatError(err)}`);
       setIsRecording(false);
     }
   };
@@ -129,7 +130,7 @@ export default function SystemAudioTest() {
       setBufferSize(0);
       setSavedFilePath(null);
     } catch (err) {
-      setError(`Failed to clear buffer: ${err}`);
+      setError(`Failed to clear buffer: ${formatError(err)}`);
     }
   };
 
@@ -140,7 +141,7 @@ export default function SystemAudioTest() {
       await invoke("set_microphone_source");
       await getCurrentSource();
     } catch (err) {
-      setError(`Failed to switch to microphone: ${err}`);
+      setError(`Failed to switch to microphone: ${formatError(err)}`);
     } finally {
       setSwitchingSource(false);
     }
@@ -153,7 +154,7 @@ export default function SystemAudioTest() {
       await invoke("set_system_audio_source", { deviceName });
       await getCurrentSource();
     } catch (err) {
-      setError(`Failed to switch to system audio: ${err}`);
+      setError(`Failed to switch to system audio: ${formatError(err)}`);
     } finally {
       setSwitchingSource(false);
     }
@@ -0,0 +1,73 @@
+import React from "react";
+import { useSettingsStore } from "../../stores/settingsStore";
+
+interface Props {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+type NumericKey =
+  | "whisper_beam_size"
+  | "whisper_temperature"
+  | "whisper_temperature_increment"
+  | "whisper_no_speech_threshold";
+
+const SLIDERS: {
+  key: NumericKey;
+  label: string;
+  min: number;
+  max: number;
+  step: number;
+  fallback: number;
+}[] = [
+  { key: "whisper_beam_size", label: "Beam size (1 = greedy)", min: 1, max: 10, step: 1, fallback: 5 },
+  { key: "whisper_temperature", label: "Temperature", min: 0, max: 1, step: 0.1, fallback: 0 },
+  { key: "whisper_temperature_increment", label: "Temperature fallback step", min: 0, max: 1, step: 0.1, fallback: 0.2 },
+  { key: "whisper_no_speech_threshold", label: "No-speech threshold", min: 0, max: 1, step: 0.05, fallback: 0.6 },
+];
+
+export const WhisperDecoding: React.FC<Props> = ({ descriptionMode = "inline" }) => {
+  const settings = useSettingsStore((s) => s.settings);
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const conditionOnPrevious = settings?.whisper_condition_on_previous_text ?? true;
+
+  return (
+    <div className="py-2 space-y-2">
+      <div>
+        <div className="font-medium">Whisper decoding</div>
+        {descriptionMode === "inline" && (
+          <div className="text-sm text-neutral-500">
+            Wider beams and temperature fallback are more accurate but slower.
+          </div>
+        )}
+      </div>
+      {SLIDERS.map(({ key, label, min, max, step, fallback }) => {
+        const value = settings?.[key] ?? fallback;
+        return (
+          <div key={key} className="flex items-center justify-between gap-3">
+            <div className="text-sm">{label}</div>
+            <div className="flex items-center gap-3">
+              <input
+                type="range"
+                min={min}
+                max={max}
+                step={step}
+                value={value}
+                onChange={(e) => updateSetting(key, Number(e.target.value))}
+              />
+              <div className="text-sm w-10 text-right">{value}</div>
+            </div>
+          </div>
+        );
+      })}
+      <label className="flex items-center justify-between text-sm">
+        <span>Condition on previous text</span>
+        <input
+          type="checkbox"
+          checked={conditionOnPrevious}
+          onChange={(e) => updateSetting("whisper_condition_on_previous_text", e.target.checked)}
+        />
+      </label>
+    </div>
+  );
+};
@@ -0,0 +1,28 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface WhisperPromptContextProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const WhisperPromptContext: React.FC<WhisperPromptContextProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("whisper_prompt_context") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("whisper_prompt_context", value)}
+        isUpdating={isUpdating("whisper_prompt_context")}
+        label="Carry Context Between Chunks"
+        description="Give Whisper your custom words and the end of the previous chunk, so names and casing stay consistent."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
@@ -3,6 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { toast } from "sonner";
 import { listen } from "@tauri-apps/api/event";
 import ProgressBar from "../shared/ProgressBar";
+import { errorMessage, formatError, isErrorCode } from "../../lib/errors";
 
 export const ImportAudio: React.FC = () => {
   const [meetingName, setMeetingName] = useState("");
@@ -72,8 +73,7 @@ export const ImportAudio: React.FC = () => {
       setProgressPercent(null);
     } catch (e) {
       console.error(e);
-      const msg = String(e);
-      toast.error("Import failed", { description: msg });
+      toast.error("Import failed", { description: formatError(e) });
     } finally {
       setIsImporting(false);
     }
@@ -112,13 +112,13 @@ export const ImportAudio: React.FC = () => {
       setProgressPercent(null);
     } catch (e) {
       console.error(e);
-      const msg = String(e);
+      const msg = errorMessage(e);
       if (msg.toLowerCase().includes("yt-dlp not found")) {
         const hint = navigator.userAgent.includes("Mac")
           ? "Install via: brew install yt-dlp"
           : "Install yt-dlp from https://github.com/yt-dlp/yt-dlp#installation";
         toast.error("yt-dlp not found", { description: hint });
-      } else if (msg.toLowerCase().includes("network")) {
+      } else if (isErrorCode(e, "network")) {
         toast.error("Network required for YouTube", {
           description: "Check your connection and try again.",
         });
@@ -136,7 +136,7 @@ export const ImportAudio: React.FC = () => {
       if (picked) setFilePath(picked);
     } catch (e) {
       console.error("File picker failed:", e);
-      toast.error("Could not open file picker", { description: String(e) });
+      toast.error("Could not open file picker", { description: formatError(e) });
     }
   };
 
@@ -221,7 +221,7 @@ export const ImportAudio: React.FC = () => {
               className="rounded border border-border px-2 py-0.5 hover:bg-background-ui"
               onClick={async () => {
                 try { await invoke("open_path_in_file_manager", { path: lastTranscriptDir }); }
-                catch (e) { toast.error("Open failed", { description: String(e) }); }
+                catch (e) { toast.error("Open failed", { description: formatError(e) }); }
               }}
             >
               Open Folder
@@ -230,7 +230,7 @@ export const ImportAudio: React.FC = () => {
               className="rounded border border-border px-2 py-0.5 hover:bg-background-ui"
               onClick={async () => {
                 try { await invoke("open_path_in_file_manager", { path: `${lastTranscriptDir}/transcript.md` }); }
-                catch (e) { toast.error("Open failed", { description: String(e) }); }
+                catch (e) { toast.error("Open failed", { description: formatError(e) }); }
               }}
             >
               Open Transcript
@@ -239,7 +239,7 @@ export const ImportAudio: React.FC = () => {
               className="rounded border border-border px-2 py-0.5 hover:bg-background-ui"
               onClick={async () => {
                 try { await invoke("open_path_in_file_manager", { path: `${lastTranscriptDir}/summary.md` }); }
-                catch (e) { toast.error("Open failed", { description: String(e) }); }
+                catch (e) { toast.error("Open failed", { description: formatError(e) }); }
               }}
             >
               Open Summary
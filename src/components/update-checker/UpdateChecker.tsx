@@ -1,9 +1,27 @@
 import React, { useState, useEffect, useRef } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import { check } from "@tauri-apps/plugin-updater";
 import { relaunch } from "@tauri-apps/plugin-process";
 import { listen } from "@tauri-apps/api/event";
 import { ProgressBar } from "../shared";
 
+// Matches plugins.updater.endpoints in tauri.conf.json
+const UPDATE_ENDPOINT =
+  "https://github.com/cjpais/Handy/releases/latest/download/latest.json";
+
+// The updater runs in the webview, so offline mode is checked here
+const updatesAllowed = async () => {
+  try {
+    await invoke("ensure_online", {
+      service: "updates",
+      target: UPDATE_ENDPOINT,
+    });
+    return true;
+  } catch {
+    return false;
+  }
+};
+
 interface UpdateCheckerProps {
   className?: string;
 }
@@ -15,6 +33,7 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
   const [isInstalling, setIsInstalling] = useState(false);
   const [downloadProgress, setDownloadProgress] = useState(0);
   const [showUpToDate, setShowUpToDate] = useState(false);
+  const [isOffline, setIsOffline] = useState(false);
 
   const upToDateTimeoutRef = useRef<ReturnType<typeof setTimeout>>();
   const isManualCheckRef = useRef(false);
@@ -43,6 +62,9 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
 
     try {
       setIsChecking(true);
+      const allowed = await updatesAllowed();
+      setIsOffline(!allowed);
+      if (!allowed) return;
       const update = await check();
 
       if (update) {
@@ -80,6 +102,10 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
       setDownloadProgress(0);
       downloadedBytesRef.current = 0;
       contentLengthRef.current = 0;
+      if (!(await updatesAllowed())) {
+        setIsOffline(true);
+        return;
+      }
       const update = await check();
 
       if (!update) {
@@ -127,6 +153,7 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
           : "Preparing...";
     }
     if (isChecking) return "Checking...";
+    if (isOffline) return "Updates paused (offline)";
     if (showUpToDate) return "Up to date";
     if (updateAvailable) return "Update available";
     return "Check for updates";
@@ -1,6 +1,7 @@
 import { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { formatError } from "../lib/errors";
 
 interface ModelInfo {
   id: string;
@@ -47,7 +48,7 @@ export const useModels = () => {
       setModels(modelList);
       setError(null);
     } catch (err) {
-      setError(`Failed to load models: ${err}`);
+      setError(`Failed to load models: ${formatError(err)}`);
     } finally {
       setLoading(false);
     }
@@ -83,7 +84,7 @@ export const useModels = () => {
       setHasAnyModels(true);
       return true;
     } catch (err) {
-      setError(`Failed to switch to model: ${err}`);
+      setError(`Failed to switch to model: ${formatError(err)}`);
       return false;
     }
   };
@@ -95,7 +96,7 @@ export const useModels = () => {
       await invoke("download_model", { modelId });
       return true;
     } catch (err) {
-      setError(`Failed to download model: ${err}`);
+      setError(`Failed to download model: ${formatError(err)}`);
       setDownloadingModels((prev) => {
         const next = new Set(prev);
         next.delete(modelId);
@@ -112,7 +113,7 @@ export const useModels = () => {
       await loadModels(); // Refresh the list
       return true;
     } catch (err) {
-      setError(`Failed to delete model: ${err}`);
+      setError(`Failed to delete model: ${formatError(err)}`);
       return false;
     }
   };
@@ -0,0 +1,120 @@
+import { z } from "zod";
+
+export const AppErrorCodeSchema = z.enum([
+  "not_found",
+  "invalid_input",
+  "not_configured",
+  "unauthorized",
+  "busy",
+  "offline",
+  "network",
+  "io",
+  "internal",
+]);
+
+export const AppErrorSchema = z.object({
+  code: AppErrorCodeSchema,
+  message: z.string(),
+  hint: z.string(),
+});
+
+export type AppErrorCode = z.infer<typeof AppErrorCodeSchema>;
+export type AppError = z.infer<typeof AppErrorSchema>;
+
+interface ErrorText {
+  title: string;
+  hint: string;
+}
+
+// Keyed by language, then by the code commands return. The backend's English hint is
+// used when the current language has no entry.
+const CATALOG: Record<string, Partial<Record<AppErrorCode, ErrorText>>> = {
+  en: {
+    not_found: {
+      title: "Not found",
+      hint: "It may have been deleted or moved. Refresh and try again.",
+    },
+    invalid_input: {
+      title: "Invalid input",
+      hint: "Check the value and try again.",
+    },
+    not_configured: {
+      title: "Setup needed",
+      hint: "Finish setting it up in Settings, then try again.",
+    },
+    unauthorized: {
+      title: "Not authorized",
+      hint: "Reconnect the account or update the key in Settings > Integrations.",
+    },
+    busy: {
+      title: "Busy",
+      hint: "Wait for it to finish or stop it, then try again.",
+    },
+    offline: {
+      title: "Offline mode",
+      hint: "Turn off offline mode in Settings > General to allow this.",
+    },
+    network: {
+      title: "Network error",
+      hint: "Check your connection and the proxy settings in Settings > Advanced.",
+    },
+    io: {
+      title: "File error",
+      hint: "Check that the path exists and MeetingCoder can write to it.",
+    },
+    internal: {
+      title: "Something went wrong",
+      hint: "If this keeps happening, export a diagnostics bundle from Settings > Debug and report it.",
+    },
+  },
+};
+
+const language = (): string =>
+  (typeof navigator !== "undefined" ? navigator.language : "en")
+    .split("-")[0]
+    .toLowerCase();
+
+/** Normalize anything a rejected `invoke` or thrown value can be into an AppError */
+export const toAppError = (error: unknown): AppError => {
+  const parsed = AppErrorSchema.safeParse(error);
+  if (parsed.success) {
+    return parsed.data;
+  }
+  // Commands outside `commands::*` still reject with a plain string
+  const message =
+    typeof error === "string"
+      ? error
+      : error instanceof Error
+        ? error.message
+        : String(error);
+  return { code: "internal", message, hint: "" };
+};
+
+/** Title and remediation hint in the user's language */
+export const describeError = (
+  error: unknown,
+): { code: AppErrorCode; title: string; message: string; hint: string } => {
+  const appError = toAppError(error);
+  const text = CATALOG[language()]?.[appError.code];
+  return {
+    code: appError.code,
+    title: text?.title ?? CATALOG.en[appError.code]?.title ?? "",
+    message: appError.message,
+    // Untyped string errors carry no hint, so don't invent one for them
+    hint: appError.hint ? (text?.hint ?? appError.hint) : "",
+  };
+};
+
+/** The error's message, for inline error text and toast descriptions */
+export const errorMessage = (error: unknown): string =>
+  toAppError(error).message;
+
+/** Message followed by what the user can do about it */
+export const formatError = (error: unknown): string => {
+  const { message, hint } = describeError(error);
+  return hint ? `${message.replace(/\.$/, "")}. ${hint}` : message;
+};
+
+export const isErrorCode = (error: unknown, code: AppErrorCode): boolean =>
+  AppErrorSchema.safeParse(error).success &&
+  (error as AppError).code === code;
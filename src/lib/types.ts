@@ -22,6 +22,17 @@ export const AudioDeviceSchema = z.object({
 export const OverlayPositionSchema = z.enum(["none", "top", "bottom"]);
 export type OverlayPosition = z.infer<typeof OverlayPositionSchema>;
 
+export const OverlayContentSchema = z.enum([
+  "levels",
+  "waveform",
+  "captions",
+  "status_pill",
+]);
+export type OverlayContent = z.infer<typeof OverlayContentSchema>;
+
+export const LogLevelSchema = z.enum(["error", "warn", "info", "debug", "trace"]);
+export type LogLevel = z.infer<typeof LogLevelSchema>;
+
 export const ModelUnloadTimeoutSchema = z.enum([
   "never",
   "immediately",
@@ -40,6 +51,9 @@ export type PasteMethod = z.infer<typeof PasteMethodSchema>;
 export const ClipboardHandlingSchema = z.enum(["dont_modify", "copy_to_clipboard"]);
 export type ClipboardHandling = z.infer<typeof ClipboardHandlingSchema>;
 
+export const GitAuthMethodSchema = z.enum(["token", "ssh", "credential_helper"]);
+export type GitAuthMethod = z.infer<typeof GitAuthMethodSchema>;
+
 export const SettingsSchema = z.object({
   bindings: ShortcutBindingsMapSchema,
   push_to_talk: z.boolean(),
@@ -56,8 +70,15 @@ export const SettingsSchema = z.object({
   selected_microphone: z.string().nullable().optional(),
   selected_output_device: z.string().nullable().optional(),
   translate_to_english: z.boolean(),
+  whisper_prompt_context: z.boolean().optional().default(false),
+  whisper_beam_size: z.number().optional().default(5),
+  whisper_temperature: z.number().optional().default(0),
+  whisper_temperature_increment: z.number().optional().default(0.2),
+  whisper_no_speech_threshold: z.number().optional().default(0.6),
+  whisper_condition_on_previous_text: z.boolean().optional().default(true),
   selected_language: z.string(),
   overlay_position: OverlayPositionSchema,
+  overlay_content: OverlayContentSchema.optional().default("levels"),
   debug_mode: z.boolean(),
   custom_words: z.array(z.string()).optional().default([]),
   model_unload_timeout: ModelUnloadTimeoutSchema.optional().default("never"),
@@ -66,10 +87,22 @@ export const SettingsSchema = z.object({
   paste_method: PasteMethodSchema.optional().default("ctrl_v"),
   clipboard_handling: ClipboardHandlingSchema.optional().default("dont_modify"),
   mute_while_recording: z.boolean().optional().default(false),
+  focus_mode_while_recording: z.boolean().optional().default(false),
+  offline_mode: z.boolean().optional().default(false),
+  proxy_url: z.string().nullable().optional(),
+  ca_bundle_path: z.string().nullable().optional(),
+  redact_pii: z.boolean().optional().default(false),
+  redaction_patterns: z.array(z.string()).optional().default([]),
+  metrics_sharing_enabled: z.boolean().optional().default(false),
+  metrics_share_url: z.string().nullable().optional(),
+  log_level: LogLevelSchema.optional().default("info"),
   transcription_chunk_seconds: z.number().optional().default(10),
+  streaming_transcription: z.boolean().optional().default(false),
+  streaming_interval_ms: z.number().optional().default(1000),
   // Default to 90 seconds to keep memory modest and reduce backlog risk
   system_audio_buffer_seconds: z.number().optional().default(90),
   system_audio_silence_threshold: z.number().optional().default(-50),
+  noise_suppression_enabled: z.boolean().optional().default(false),
   meeting_update_interval_seconds: z.number().optional().default(20),
   auto_trigger_meeting_command: z.boolean().optional().default(false),
   auto_accept_changes: z.boolean().optional().default(false),
@@ -79,6 +112,8 @@ export const SettingsSchema = z.object({
   github_default_branch: z.string().optional().default("main"),
   github_branch_pattern: z.string().optional().default("meeting/{meeting_id}"),
   github_enabled: z.boolean().optional().default(false),
+  git_auth_method: GitAuthMethodSchema.optional().default("token"),
+  git_ssh_key_path: z.string().nullable().optional(),
   prefer_whisper_for_imports: z.boolean().optional().default(false),
   fast_import_mode_for_imports: z.boolean().optional().default(true),
   use_fixed_windows_for_imports: z.boolean().optional().default(false),
@@ -119,6 +154,14 @@ export type ModelInfo = z.infer<typeof ModelInfoSchema>;
 export const MeetingStatusSchema = z.enum(["recording", "paused", "completed"]);
 export type MeetingStatus = z.infer<typeof MeetingStatusSchema>;
 
+export const WordTimingSchema = z.object({
+  word: z.string(),
+  start: z.number(), // seconds from meeting start
+  end: z.number(),
+});
+
+export type WordTiming = z.infer<typeof WordTimingSchema>;
+
 export const TranscriptSegmentSchema = z.object({
   speaker: z.string(),
   start_time: z.number(),
@@ -126,10 +169,24 @@ export const TranscriptSegmentSchema = z.object({
   text: z.string(),
   confidence: z.number(),
   timestamp: z.number(), // Unix timestamp in milliseconds
+  words: z.array(WordTimingSchema).optional(),
+  translated_text: z.string().optional(),
 });
 
 export type TranscriptSegment = z.infer<typeof TranscriptSegmentSchema>;
 
+// Payload of `transcript-partial`: interim words of the chunk being recorded
+export const PartialTranscriptSchema = z.object({
+  meeting_id: z.string(),
+  start_time: z.number(),
+  words: z.array(z.string()),
+  stable_words: z.number(),
+  text: z.string(),
+  is_final: z.boolean(),
+});
+
+export type PartialTranscript = z.infer<typeof PartialTranscriptSchema>;
+
 export const MeetingSessionSchema = z.object({
   id: z.string(),
   name: z.string(),
@@ -155,6 +212,16 @@ export const MeetingSummarySchema = z.object({
 export type MeetingSummary = z.infer<typeof MeetingSummarySchema>;
 
 // Meeting history types
+export const ChapterSchema = z.object({
+  title: z.string(),
+  start_time: z.number(),
+  end_time: z.number(),
+  first_segment: z.number(),
+  last_segment: z.number(),
+});
+
+export type Chapter = z.infer<typeof ChapterSchema>;
+
 export const TranscriptMetadataSchema = z.object({
   meeting_id: z.string(),
   name: z.string(),
@@ -162,6 +229,7 @@ export const TranscriptMetadataSchema = z.object({
   end_time: z.string(),   // ISO 8601 format
   duration_seconds: z.number(),
   participants: z.array(z.string()),
+  chapters: z.array(ChapterSchema).optional(),
 });
 
 export type TranscriptMetadata = z.infer<typeof TranscriptMetadataSchema>;
@@ -215,6 +283,21 @@ export const PRResultSchema = z.object({
 
 export type PRResult = z.infer<typeof PRResultSchema>;
 
+export const CreatedIssueSchema = z.object({
+  item_key: z.string(),
+  meeting_id: z.string(),
+  number: z.number(),
+  html_url: z.string(),
+  title: z.string(),
+});
+
+export const IssuesResultSchema = z.object({
+  created: z.array(CreatedIssueSchema),
+  already_created: z.number(),
+});
+
+export type IssuesResult = z.infer<typeof IssuesResultSchema>;
+
 export const RepoInfoSchema = z.object({
   id: z.number(),
   name: z.string(),
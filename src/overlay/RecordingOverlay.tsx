@@ -9,12 +9,21 @@ import {
 import "./RecordingOverlay.css";
 
 type OverlayState = "recording" | "transcribing";
+type OverlayContent = "levels" | "waveform" | "captions" | "status_pill";
+
+const formatElapsed = (seconds: number) =>
+  `${Math.floor(seconds / 60)}:${String(seconds % 60).padStart(2, "0")}`;
 
 const RecordingOverlay: React.FC = () => {
   const [isVisible, setIsVisible] = useState(false);
   const [state, setState] = useState<OverlayState>("recording");
   const [levels, setLevels] = useState<number[]>(Array(16).fill(0));
   const smoothedLevelsRef = useRef<number[]>(Array(16).fill(0));
+  const [content, setContent] = useState<OverlayContent>("levels");
+  const [waveform, setWaveform] = useState<number[]>([]);
+  const [caption, setCaption] = useState("");
+  const [shownAt, setShownAt] = useState(Date.now());
+  const [now, setNow] = useState(Date.now());
 
   useEffect(() => {
     const setupEventListeners = async () => {
@@ -22,9 +31,27 @@ const RecordingOverlay: React.FC = () => {
       const unlistenShow = await listen("show-overlay", (event) => {
         const overlayState = event.payload as OverlayState;
         setState(overlayState);
+        if (overlayState === "recording") {
+          setShownAt(Date.now());
+          setCaption("");
+        }
         setIsVisible(true);
       });
 
+      const unlistenContent = await listen<OverlayContent>(
+        "overlay-content",
+        (event) => setContent(event.payload),
+      );
+
+      const unlistenWaveform = await listen<number[]>(
+        "overlay-waveform",
+        (event) => setWaveform(event.payload),
+      );
+
+      const unlistenCaption = await listen<string>("overlay-caption", (event) =>
+        setCaption(event.payload),
+      );
+
       // Listen for hide-overlay event from Rust
       const unlistenHide = await listen("hide-overlay", () => {
         setIsVisible(false);
@@ -49,12 +76,22 @@ const RecordingOverlay: React.FC = () => {
         unlistenShow();
         unlistenHide();
         unlistenLevel();
+        unlistenContent();
+        unlistenWaveform();
+        unlistenCaption();
       };
     };
 
     setupEventListeners();
   }, []);
 
+  // Only the status pill shows a running timer
+  useEffect(() => {
+    if (!isVisible || content !== "status_pill") return;
+    const timer = setInterval(() => setNow(Date.now()), 1000);
+    return () => clearInterval(timer);
+  }, [isVisible, content]);
+
   const getIcon = () => {
     if (state === "recording") {
       return <MicrophoneIcon />;
@@ -68,7 +105,7 @@ const RecordingOverlay: React.FC = () => {
       <div className="overlay-left">{getIcon()}</div>
 
       <div className="overlay-middle">
-        {state === "recording" && (
+        {state === "recording" && content === "levels" && (
           <div className="bars-container">
             {levels.map((v, i) => (
               <div
@@ -83,6 +120,25 @@ const RecordingOverlay: React.FC = () => {
             ))}
           </div>
         )}
+        {state === "recording" && content === "waveform" && (
+          <div className="waveform-container">
+            {waveform.map((v, i) => (
+              <div
+                key={i}
+                className="waveform-line"
+                style={{ height: `${Math.max(1, Math.min(1, v * 2) * 22)}px` }}
+              />
+            ))}
+          </div>
+        )}
+        {state === "recording" && content === "captions" && (
+          <div className="caption-text">{caption || "Listening..."}</div>
+        )}
+        {state === "recording" && content === "status_pill" && (
+          <div className="status-pill-text">
+            {formatElapsed(Math.max(0, Math.floor((now - shownAt) / 1000)))}
+          </div>
+        )}
         {state === "transcribing" && (
           <div className="transcribing-text">Transcribing...</div>
         )}
@@ -48,16 +48,30 @@ const DEFAULT_SETTINGS: Partial<Settings> = {
   selected_microphone: "Default",
   selected_output_device: "Default",
   translate_to_english: false,
+  whisper_prompt_context: false,
+  whisper_beam_size: 5,
+  whisper_temperature: 0,
+  whisper_temperature_increment: 0.2,
+  whisper_no_speech_threshold: 0.6,
+  whisper_condition_on_previous_text: true,
   selected_language: "auto",
   overlay_position: "bottom",
+  overlay_content: "levels",
   debug_mode: false,
   custom_words: [],
   history_limit: 5,
   mute_while_recording: false,
+  focus_mode_while_recording: false,
+  offline_mode: false,
+  redact_pii: false,
+  redaction_patterns: [],
+  metrics_sharing_enabled: false,
+  log_level: "info",
   transcription_chunk_seconds: 10,
   // Lower default buffer size to reduce RAM/backlog by default
   system_audio_buffer_seconds: 90,
   system_audio_silence_threshold: -50,
+  noise_suppression_enabled: false,
   meeting_update_interval_seconds: 20,
   auto_trigger_meeting_command: false,
   auto_accept_changes: false,
@@ -106,10 +120,24 @@ const settingUpdaters: {
     }),
   translate_to_english: (value) =>
     invoke("change_translate_to_english_setting", { enabled: value }),
+  whisper_prompt_context: (value) =>
+    invoke("change_whisper_prompt_context_setting", { enabled: value }),
+  whisper_beam_size: (value) =>
+    invoke("change_whisper_beam_size_setting", { beamSize: value }),
+  whisper_temperature: (value) =>
+    invoke("change_whisper_temperature_setting", { temperature: value }),
+  whisper_temperature_increment: (value) =>
+    invoke("change_whisper_temperature_increment_setting", { increment: value }),
+  whisper_no_speech_threshold: (value) =>
+    invoke("change_whisper_no_speech_threshold_setting", { threshold: value }),
+  whisper_condition_on_previous_text: (value) =>
+    invoke("change_whisper_condition_on_previous_text_setting", { enabled: value }),
   selected_language: (value) =>
     invoke("change_selected_language_setting", { language: value }),
   overlay_position: (value) =>
     invoke("change_overlay_position_setting", { position: value }),
+  overlay_content: (value) =>
+    invoke("change_overlay_content_setting", { mode: value }),
   debug_mode: (value) =>
     invoke("change_debug_mode_setting", { enabled: value }),
   custom_words: (value) => invoke("update_custom_words", { words: value }),
@@ -122,12 +150,16 @@ const settingUpdaters: {
   history_limit: (value) => invoke("update_history_limit", { limit: value }),
   mute_while_recording: (value) =>
     invoke("change_mute_while_recording_setting", { enabled: value }),
+  focus_mode_while_recording: (value) =>
+    invoke("change_focus_mode_while_recording_setting", { enabled: value }),
   transcription_chunk_seconds: (value) =>
     invoke("change_transcription_chunk_seconds_setting", { seconds: value }),
   system_audio_buffer_seconds: (value) =>
     invoke("change_system_audio_buffer_seconds_setting", { seconds: value }),
   system_audio_silence_threshold: (value) =>
     invoke("change_system_audio_silence_threshold_setting", { dbfs: value }),
+  noise_suppression_enabled: (value) =>
+    invoke("change_noise_suppression_enabled_setting", { enabled: value }),
   meeting_update_interval_seconds: (value) =>
     invoke("change_meeting_update_interval_seconds_setting", { seconds: value }),
   auto_trigger_meeting_command: (value) =>
@@ -301,7 +333,19 @@ export const useSettingsStore = create<SettingsStore>()(
         if (updater) {
           await updater(value);
         } else if (key !== "bindings" && key !== "selected_model") {
-          console.warn(`No handler for setting: ${String(key)}`);
+          // Validated, clamped, and applied by the generic backend command;
+          // show the value that was actually stored
+          const changes = await invoke<{ setting: string; value: unknown }[]>(
+            "update_settings",
+            { patch: { [key]: value } },
+          );
+          for (const change of changes) {
+            set((state) => ({
+              settings: state.settings
+                ? { ...state.settings, [change.setting]: change.value }
+                : null,
+            }));
+          }
         }
       } catch (error) {
         console.error(`Failed to update setting ${String(key)}:`, error);